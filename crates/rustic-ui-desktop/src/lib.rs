@@ -0,0 +1,27 @@
+//! Desktop (Tauri) integration helpers for RusticUI applications.
+//!
+//! The webview half of a Tauri app is compiled to WebAssembly and talks to
+//! the native host over IPC, so this crate deliberately does **not** link
+//! `tauri`. Instead it defines the contract both halves share:
+//!
+//! * [`menu`] — serializable native menu and tray descriptors built from the
+//!   same [`rustic_ui_material::menu::MenuItem`] list that renders the
+//!   in-page menu, so selections dispatch through one command vocabulary.
+//! * [`window`] — `data-tauri-drag-region` helpers turning the Material
+//!   `AppBar` into window chrome for frameless windows.
+//! * [`theme_sync`] — translation between Tauri's `theme` event strings and
+//!   [`rustic_ui_system::theme::ColorScheme`] so the app tracks the OS
+//!   appearance (and can drive it back).
+//!
+//! Keeping everything as plain data means the crate tests on native targets
+//! and the host-side Tauri commands stay trivially small.
+
+#![forbid(unsafe_code)]
+
+pub mod menu;
+pub mod theme_sync;
+pub mod window;
+
+pub use menu::{NativeMenu, NativeMenuItem, TrayDescriptor};
+pub use theme_sync::{reconcile, scheme_from_os, scheme_to_os};
+pub use window::{drag_region_attribute, no_drag_attributes, title_bar_attributes};
@@ -0,0 +1,123 @@
+//! Native menu and tray descriptors derived from the Material menu model.
+//!
+//! Tauri windows host the UI as WebAssembly, so the webview cannot link the
+//! `tauri` crate directly — it talks to the host over IPC.  This module
+//! therefore expresses native menus as serializable descriptors: the
+//! frontend builds them from the same [`MenuItem`] list that powers the
+//! in-page [`rustic_ui_material::menu`] surface, serializes them through
+//! `invoke`, and the ~10 line Tauri command on the host side maps each
+//! descriptor onto `tauri::menu::MenuItem`.  Selections come back as the
+//! item's `command` string, which plugs straight into the same dispatch the
+//! web menu uses — one command vocabulary across web and native surfaces.
+
+use serde::Serialize;
+
+use rustic_ui_material::menu::MenuItem;
+
+/// One native menu entry mirroring a [`MenuItem`].
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct NativeMenuItem {
+    /// Identifier reported back on selection; equals the web item's
+    /// `command` so both surfaces share one dispatcher.
+    pub id: String,
+    /// Display label.
+    pub label: String,
+    /// Keyboard accelerator in Tauri syntax (e.g. `CmdOrCtrl+K`).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub accelerator: Option<String>,
+    /// Disabled entries render greyed out but keep their position.
+    pub enabled: bool,
+}
+
+/// Serializable native menu, usable for window menus and tray menus alike.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct NativeMenu {
+    /// Entries in display order.
+    pub items: Vec<NativeMenuItem>,
+}
+
+/// Tray icon descriptor: tooltip plus the attached menu.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct TrayDescriptor {
+    /// Tooltip shown on hover over the tray icon.
+    pub tooltip: String,
+    /// Menu opened from the tray icon.
+    pub menu: NativeMenu,
+}
+
+impl NativeMenu {
+    /// Build a native menu from the Material menu item list.
+    ///
+    /// Router-backed items are skipped: native menus cannot perform client
+    /// side navigation, and silently mapping them to commands would fork the
+    /// navigation behavior between surfaces.
+    pub fn from_items(items: &[MenuItem]) -> Self {
+        Self {
+            items: items
+                .iter()
+                .filter(|item| item.route.is_none())
+                .map(|item| NativeMenuItem {
+                    id: item.command.clone(),
+                    label: item.label.clone(),
+                    accelerator: None,
+                    enabled: true,
+                })
+                .collect(),
+        }
+    }
+
+    /// Attach an accelerator to the entry with the given command id.
+    pub fn with_accelerator(mut self, id: &str, accelerator: impl Into<String>) -> Self {
+        if let Some(item) = self.items.iter_mut().find(|item| item.id == id) {
+            item.accelerator = Some(accelerator.into());
+        }
+        self
+    }
+
+    /// IPC payload for the host-side `build_menu` command.
+    pub fn to_payload(&self) -> serde_json::Value {
+        serde_json::to_value(self).expect("native menu serializes")
+    }
+}
+
+impl TrayDescriptor {
+    /// Tray descriptor reusing an already-built menu.
+    pub fn new(tooltip: impl Into<String>, menu: NativeMenu) -> Self {
+        Self {
+            tooltip: tooltip.into(),
+            menu,
+        }
+    }
+
+    /// IPC payload for the host-side `build_tray` command.
+    pub fn to_payload(&self) -> serde_json::Value {
+        serde_json::to_value(self).expect("tray descriptor serializes")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn menus_mirror_commands_and_skip_router_items() {
+        let items = vec![
+            MenuItem::new("Check for updates", "app.update"),
+            MenuItem::new("Docs", "app.docs")
+                .with_route(rustic_ui_material::routing::RouterTarget::route("/docs")),
+            MenuItem::new("Quit", "app.quit"),
+        ];
+        let menu = NativeMenu::from_items(&items).with_accelerator("app.quit", "CmdOrCtrl+Q");
+        let ids: Vec<_> = menu.items.iter().map(|item| item.id.as_str()).collect();
+        assert_eq!(ids, ["app.update", "app.quit"]);
+        assert_eq!(menu.items[1].accelerator.as_deref(), Some("CmdOrCtrl+Q"));
+    }
+
+    #[test]
+    fn payloads_serialize_for_ipc() {
+        let menu = NativeMenu::from_items(&[MenuItem::new("Quit", "app.quit")]);
+        let payload = TrayDescriptor::new("RusticUI", menu).to_payload();
+        assert_eq!(payload["tooltip"], "RusticUI");
+        assert_eq!(payload["menu"]["items"][0]["id"], "app.quit");
+    }
+}
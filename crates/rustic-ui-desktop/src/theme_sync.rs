@@ -0,0 +1,75 @@
+//! Two-way color scheme synchronization with the host OS.
+//!
+//! Tauri surfaces the OS appearance through `window.theme()` and the `theme`
+//! changed event, both of which speak the strings `"light"` and `"dark"`.
+//! This module translates that vocabulary into
+//! [`rustic_ui_system::theme::ColorScheme`] so the frontend can feed OS
+//! changes straight into its theme provider, and back again so Rust code can
+//! drive `window.set_theme(..)` when the user picks a scheme in-app.
+
+use rustic_ui_system::theme::ColorScheme;
+
+/// Parse the payload of a Tauri `theme` event into a [`ColorScheme`].
+///
+/// Unknown payloads return `None` rather than defaulting: the caller should
+/// keep the current scheme instead of snapping back to light mode whenever a
+/// future Tauri release extends the vocabulary.
+#[must_use]
+pub fn scheme_from_os(theme: &str) -> Option<ColorScheme> {
+    match theme {
+        "light" => Some(ColorScheme::Light),
+        "dark" => Some(ColorScheme::Dark),
+        _ => None,
+    }
+}
+
+/// The string `window.set_theme(..)` expects for a [`ColorScheme`].
+///
+/// This is [`ColorScheme::as_str`] — Tauri happens to share the CSS
+/// `color-scheme` vocabulary — but routing it through a named helper keeps
+/// the IPC contract in one place should the vocabularies ever diverge.
+#[must_use]
+pub fn scheme_to_os(scheme: ColorScheme) -> &'static str {
+    scheme.as_str()
+}
+
+/// Reconcile an OS theme event against the scheme currently applied.
+///
+/// Returns the scheme the app should switch to, or `None` when no change is
+/// needed (already in sync, or the payload was unrecognized). Collapsing the
+/// no-op case here lets event handlers skip redundant theme provider updates
+/// that would otherwise re-render the whole tree.
+#[must_use]
+pub fn reconcile(current: ColorScheme, os_theme: &str) -> Option<ColorScheme> {
+    scheme_from_os(os_theme).filter(|scheme| *scheme != current)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn os_strings_round_trip() {
+        assert_eq!(scheme_from_os("dark"), Some(ColorScheme::Dark));
+        assert_eq!(scheme_to_os(ColorScheme::Dark), "dark");
+        assert_eq!(
+            scheme_from_os(scheme_to_os(ColorScheme::Light)),
+            Some(ColorScheme::Light)
+        );
+    }
+
+    #[test]
+    fn unknown_payloads_are_ignored() {
+        assert_eq!(scheme_from_os("sepia"), None);
+        assert_eq!(reconcile(ColorScheme::Dark, "sepia"), None);
+    }
+
+    #[test]
+    fn reconcile_skips_redundant_updates() {
+        assert_eq!(reconcile(ColorScheme::Light, "light"), None);
+        assert_eq!(
+            reconcile(ColorScheme::Light, "dark"),
+            Some(ColorScheme::Dark)
+        );
+    }
+}
@@ -0,0 +1,63 @@
+//! Window chrome helpers for frameless Tauri windows.
+//!
+//! Desktop apps usually hide the native title bar and promote the Material
+//! `AppBar` to window chrome. Tauri recognizes the `data-tauri-drag-region`
+//! attribute on any element and lets the user drag the window from it, so the
+//! helpers here produce that attribute in the same `(name, value)` pair shape
+//! the rest of the workspace threads into `attributes_to_html`. Interactive
+//! children (buttons, menus) must *not* carry the attribute — Tauri treats
+//! the whole subtree as a drag handle otherwise — hence the explicit
+//! opt-out helper for toolbars embedded in a draggable bar.
+
+/// Attribute marking an element as a window drag handle.
+///
+/// Tauri only checks for the attribute's presence; the empty value keeps the
+/// serialized HTML minimal (`data-tauri-drag-region=""`).
+#[must_use]
+pub fn drag_region_attribute() -> (String, String) {
+    ("data-tauri-drag-region".into(), String::new())
+}
+
+/// Attributes for an `AppBar` acting as the window title bar.
+///
+/// Besides the drag region this stamps `data-rustic-window-chrome` so desktop
+/// specific styling (e.g. extra padding clearing traffic-light buttons on
+/// macOS) can target the bar without a dedicated prop.
+#[must_use]
+pub fn title_bar_attributes() -> Vec<(String, String)> {
+    vec![
+        drag_region_attribute(),
+        ("data-rustic-window-chrome".into(), "title-bar".into()),
+    ]
+}
+
+/// Attributes for interactive regions nested inside a draggable bar.
+///
+/// Applying this to the action cluster of a title bar keeps its buttons
+/// clickable: the marker lets the ~3 line host-side script stop drag events
+/// from bubbling into the surrounding drag region.
+#[must_use]
+pub fn no_drag_attributes() -> Vec<(String, String)> {
+    vec![("data-rustic-window-chrome".into(), "no-drag".into())]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn title_bars_are_draggable_chrome() {
+        let attrs = title_bar_attributes();
+        assert!(attrs.contains(&("data-tauri-drag-region".into(), String::new())));
+        assert!(attrs.contains(&("data-rustic-window-chrome".into(), "title-bar".into())));
+    }
+
+    #[test]
+    fn action_clusters_opt_out_of_dragging() {
+        let attrs = no_drag_attributes();
+        assert!(!attrs
+            .iter()
+            .any(|(name, _)| name == "data-tauri-drag-region"));
+        assert_eq!(attrs[0].1, "no-drag");
+    }
+}
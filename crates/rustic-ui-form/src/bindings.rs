@@ -0,0 +1,91 @@
+//! Attribute bindings connecting form fields to input components.
+//!
+//! The Material and Joy text inputs consume plain attribute lists, so the
+//! binding layer stays framework agnostic: adapters call
+//! [`field_binding`] during render and spread the returned
+//! attributes onto their `<input>`/`<label>` elements, then forward change
+//! and blur events back into [`FormState::change`](crate::FormState::change)
+//! / [`commit`](crate::FormState::commit).
+
+use crate::state::{FieldHandle, FormState};
+
+/// Render-ready snapshot of one field.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FieldBinding {
+    /// Stable field name, used for event routing.
+    pub name: &'static str,
+    /// Label text for the input.
+    pub label: String,
+    /// Current value to render.
+    pub value: String,
+    /// Validation errors to display beneath the input.
+    pub errors: Vec<String>,
+    /// Attributes to spread onto the input element (ARIA wiring and
+    /// dirty/visited analytics from the headless state machine).
+    pub attributes: Vec<(String, String)>,
+}
+
+/// Build the binding for one registered field.
+///
+/// Returns `None` when the name is not registered, letting adapters fail
+/// softly during refactors instead of panicking mid-render.
+#[must_use]
+pub fn field_binding(form: &FormState, name: &str) -> Option<FieldBinding> {
+    form.field(name).map(binding_for)
+}
+
+/// Bindings for every registered field in declaration order, for adapters
+/// that render the whole form mechanically.
+#[must_use]
+pub fn all_bindings(form: &FormState) -> Vec<FieldBinding> {
+    form.fields().iter().map(binding_for).collect()
+}
+
+fn binding_for(field: &FieldHandle) -> FieldBinding {
+    let attrs = field.state().attributes();
+    let mut attributes = Vec::new();
+    if let Some((key, value)) = attrs.aria_invalid() {
+        attributes.push((key.to_string(), value.to_string()));
+    }
+    let (dirty_key, dirty_value) = attrs.data_dirty();
+    attributes.push((dirty_key.to_string(), dirty_value.to_string()));
+    let (visited_key, visited_value) = attrs.data_visited();
+    attributes.push((visited_key.to_string(), visited_value.to_string()));
+
+    FieldBinding {
+        name: field.schema().name,
+        label: field.schema().label.clone(),
+        value: field.state().value().to_string(),
+        errors: field.state().errors().to_vec(),
+        attributes,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::schema::{FieldSchema, FormSchema, Validator};
+
+    #[test]
+    fn bindings_reflect_value_and_errors() {
+        let schema = FormSchema::new()
+            .field(FieldSchema::new("email", "Email").with_validator(Validator::email("Email")));
+        let mut form = FormState::from_schema(schema);
+        form.change("email", "nope");
+        form.commit("email");
+
+        let binding = field_binding(&form, "email").expect("field registered");
+        assert_eq!(binding.value, "nope");
+        assert_eq!(binding.errors.len(), 1);
+        assert!(binding
+            .attributes
+            .iter()
+            .any(|(key, value)| key == "aria-invalid" && value == "true"));
+    }
+
+    #[test]
+    fn unknown_fields_return_none() {
+        let form = FormState::from_schema(FormSchema::new());
+        assert!(field_binding(&form, "missing").is_none());
+    }
+}
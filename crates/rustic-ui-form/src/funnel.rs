@@ -0,0 +1,259 @@
+//! Funnel analytics instrumentation for multi-step flows.
+//!
+//! Product teams measure wizards as funnels: how many users enter each step,
+//! where validation trips them up and where they walk away.  Instrumenting
+//! that by hand around every checkout and onboarding flow is repetitive, so
+//! [`FunnelTracker`] wraps the headless
+//! [`StepperState`](rustic_ui_headless::stepper::StepperState) together with
+//! the per-step [`FormState`] validation and emits structured events through
+//! the shared [`telemetry`](rustic_ui_utils::telemetry) sink:
+//!
+//! * `step_entered` — the active step changed (including the initial step).
+//! * `step_failed_validation` — a step's form failed [`FormState::validate`].
+//! * `abandoned` — the tracker was dropped (or [`abandon`](FunnelTracker::abandon)
+//!   was called) before [`finish`](FunnelTracker::finish).
+//!
+//! Events carry the flow identifier plus the zero based step index in their
+//! automation id (`<flow>-step-<index>`), matching the `data-*` hooks the
+//! renderers stamp on the markup so analytics joins markup-side and
+//! machine-side signals without extra mapping tables.
+
+use rustic_ui_headless::stepper::{StepperChange, StepperConfig, StepperState};
+use rustic_ui_utils::telemetry;
+
+use crate::state::FormState;
+
+/// Component name attached to every funnel event.
+const COMPONENT: &str = "funnel";
+
+/// Stepper wrapper emitting funnel telemetry for multi-step flows.
+#[derive(Debug)]
+pub struct FunnelTracker {
+    flow: String,
+    stepper: StepperState,
+    finished: bool,
+}
+
+impl FunnelTracker {
+    /// Creates the tracker and emits `step_entered` for the initial step.
+    pub fn new(flow: impl Into<String>, config: StepperConfig) -> Self {
+        let tracker = Self {
+            flow: flow.into(),
+            stepper: StepperState::new(config),
+            finished: false,
+        };
+        if let Some(index) = tracker.stepper.active() {
+            tracker.emit(index, "step_entered");
+        }
+        tracker
+    }
+
+    /// Read access to the wrapped stepper for rendering.
+    pub fn stepper(&self) -> &StepperState {
+        &self.stepper
+    }
+
+    /// Identifier of the instrumented flow.
+    pub fn flow(&self) -> &str {
+        &self.flow
+    }
+
+    /// Moves to the next enabled step, reporting `step_entered` on success.
+    pub fn advance(&mut self) -> StepperChange {
+        let change = self.stepper.advance();
+        self.report_entry(&change);
+        change
+    }
+
+    /// Moves to the previous enabled step, reporting `step_entered` on
+    /// success so backtracking shows up in the funnel.
+    pub fn previous(&mut self) -> StepperChange {
+        let change = self.stepper.previous();
+        self.report_entry(&change);
+        change
+    }
+
+    /// Activates a specific step, reporting `step_entered` when it changed.
+    pub fn set_active(&mut self, index: Option<usize>) -> StepperChange {
+        let change = self.stepper.set_active(index);
+        self.report_entry(&change);
+        change
+    }
+
+    /// Validates the active step's form.
+    ///
+    /// On success the active step is marked completed — which auto-advances
+    /// linear steppers, reporting `step_entered` for the next step. On
+    /// failure a `step_failed_validation` event is emitted and the stepper
+    /// stays put.
+    pub fn validate_step(&mut self, form: &mut FormState) -> bool {
+        let valid = form.validate();
+        if let Some(index) = self.stepper.active() {
+            if valid {
+                let change = self.stepper.complete_active();
+                self.report_entry(&change);
+            } else {
+                self.emit(index, "step_failed_validation");
+            }
+        }
+        valid
+    }
+
+    /// Marks the flow as converted, suppressing the `abandoned` event.
+    pub fn finish(&mut self) {
+        self.finished = true;
+    }
+
+    /// Explicitly reports the flow as abandoned at the current step.
+    ///
+    /// Dropping an unfinished tracker does the same, so route changes that
+    /// tear the wizard down are counted without extra wiring.
+    pub fn abandon(&mut self) {
+        if self.finished {
+            return;
+        }
+        self.finished = true;
+        if let Some(index) = self.stepper.active() {
+            self.emit(index, "abandoned");
+        }
+    }
+
+    fn report_entry(&self, change: &StepperChange) {
+        if let Some(index) = change.active {
+            self.emit(index, "step_entered");
+        }
+    }
+
+    fn emit(&self, step: usize, action: &str) {
+        telemetry::emit(
+            COMPONENT,
+            Some(&format!("{}-step-{step}", self.flow)),
+            action,
+        );
+    }
+}
+
+impl Drop for FunnelTracker {
+    fn drop(&mut self) {
+        self.abandon();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::schema::{FieldSchema, FormSchema, Validator};
+    use rustic_ui_utils::telemetry::MemorySink;
+    use std::sync::Arc;
+
+    fn actions(sink: &MemorySink, flow: &str) -> Vec<(String, String)> {
+        sink.events()
+            .into_iter()
+            .filter(|event| {
+                event.component == COMPONENT
+                    && event
+                        .automation_id
+                        .as_deref()
+                        .is_some_and(|id| id.starts_with(flow))
+            })
+            .map(|event| (event.automation_id.unwrap(), event.action))
+            .collect()
+    }
+
+    fn email_form() -> FormState {
+        FormState::from_schema(
+            FormSchema::new().field(
+                FieldSchema::new("email", "Email").with_validator(Validator::required("Email")),
+            ),
+        )
+    }
+
+    /// A form with no fields always validates, completing the active step so
+    /// the linear stepper lets tests advance.
+    fn complete_step(funnel: &mut FunnelTracker) {
+        let mut form = FormState::from_schema(FormSchema::new());
+        assert!(funnel.validate_step(&mut form));
+    }
+
+    #[test]
+    fn step_transitions_emit_entered_events() {
+        let sink = Arc::new(MemorySink::default());
+        telemetry::install_sink(sink.clone());
+
+        let mut funnel = FunnelTracker::new("checkout", StepperConfig::enterprise_defaults(3));
+        // Completing the step auto-advances the linear stepper to step 1.
+        complete_step(&mut funnel);
+        // Advancing past an incomplete step is refused and stays silent.
+        funnel.advance();
+        funnel.previous();
+        funnel.finish();
+        drop(funnel);
+
+        assert_eq!(
+            actions(&sink, "checkout"),
+            vec![
+                ("checkout-step-0".to_string(), "step_entered".to_string()),
+                ("checkout-step-1".to_string(), "step_entered".to_string()),
+                ("checkout-step-0".to_string(), "step_entered".to_string()),
+            ]
+        );
+        telemetry::reset_sink();
+    }
+
+    #[test]
+    fn failed_validation_is_reported_without_advancing() {
+        let sink = Arc::new(MemorySink::default());
+        telemetry::install_sink(sink.clone());
+
+        let mut funnel = FunnelTracker::new("signup", StepperConfig::enterprise_defaults(2));
+        let mut form = email_form();
+        assert!(!funnel.validate_step(&mut form));
+        assert_eq!(funnel.stepper().active(), Some(0));
+
+        form.change("email", "user@example.com");
+        form.commit("email");
+        assert!(funnel.validate_step(&mut form));
+        assert!(funnel.stepper().is_completed(0));
+        funnel.finish();
+
+        let actions = actions(&sink, "signup");
+        assert!(actions.contains(&(
+            "signup-step-0".to_string(),
+            "step_failed_validation".to_string()
+        )));
+        assert_eq!(
+            actions
+                .iter()
+                .filter(|(_, action)| action == "step_failed_validation")
+                .count(),
+            1
+        );
+        telemetry::reset_sink();
+    }
+
+    #[test]
+    fn dropping_an_unfinished_tracker_reports_abandonment() {
+        let sink = Arc::new(MemorySink::default());
+        telemetry::install_sink(sink.clone());
+
+        let mut funnel = FunnelTracker::new("kyc", StepperConfig::enterprise_defaults(3));
+        complete_step(&mut funnel);
+        drop(funnel);
+
+        let recorded = actions(&sink, "kyc");
+        assert_eq!(
+            recorded.last(),
+            Some(&("kyc-step-1".to_string(), "abandoned".to_string()))
+        );
+
+        // Finished flows stay silent, and explicit abandons never double.
+        let mut converted = FunnelTracker::new("kyc2", StepperConfig::enterprise_defaults(1));
+        converted.finish();
+        converted.abandon();
+        drop(converted);
+        assert!(actions(&sink, "kyc2")
+            .iter()
+            .all(|(_, action)| action != "abandoned"));
+        telemetry::reset_sink();
+    }
+}
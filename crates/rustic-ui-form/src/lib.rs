@@ -0,0 +1,39 @@
+#![forbid(unsafe_code)]
+//! Typed form management built on the headless primitives.
+//!
+//! Applications historically re-implemented the same plumbing around every
+//! form: tracking per-field values, wiring validators, coordinating async
+//! checks and juggling a submission flag. This crate centralizes that stack:
+//!
+//! # Modules
+//! * [`schema`] - declarative field schemas with sync and async validators.
+//! * [`state`] - the [`FormState`] orchestrator registering one headless
+//!   [`TextFieldState`](rustic_ui_headless::text_field::TextFieldState) per
+//!   field and coordinating validation plus submission phases.
+//! * [`bindings`] - render-ready attribute snapshots binding fields to the
+//!   Material/Joy input components in any supported framework.
+//! * [`funnel`] - stepper-driven funnel analytics emitting `step_entered`,
+//!   `step_failed_validation` and `abandoned` telemetry for wizard flows.
+//!
+//! # Examples
+//! ```
+//! use rustic_ui_form::{FieldSchema, FormSchema, FormState, Validator};
+//!
+//! let schema = FormSchema::new().field(
+//!     FieldSchema::new("email", "Email").with_validator(Validator::email("Email")),
+//! );
+//! let mut form = FormState::from_schema(schema);
+//! form.change("email", "user@example.com");
+//! form.commit("email");
+//! assert!(form.validate());
+//! ```
+
+pub mod bindings;
+pub mod funnel;
+pub mod schema;
+pub mod state;
+
+pub use bindings::{all_bindings, field_binding, FieldBinding};
+pub use funnel::FunnelTracker;
+pub use schema::{AsyncValidator, FieldSchema, FormSchema, ValidationResult, Validator};
+pub use state::{FieldHandle, FormState, SubmissionPhase};
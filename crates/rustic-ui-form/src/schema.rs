@@ -0,0 +1,223 @@
+//! Typed field schemas and validation rules.
+//!
+//! A [`FormSchema`] declares every field up front — name, label and
+//! validators — so the runtime state in [`crate::state`] can register fields
+//! mechanically and applications stop scattering per-field plumbing across
+//! views.  Validators come in two flavors:
+//!
+//! * Synchronous [`Validator`]s run on every commit and during submission.
+//! * [`AsyncValidator`]s cover server-backed checks (username availability,
+//!   VAT verification) and only run when the orchestration explicitly asks
+//!   for them, keeping keystroke latency flat.
+
+use std::fmt;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+
+/// Outcome of one validation rule.
+pub type ValidationResult = Result<(), String>;
+
+/// Synchronous validation rule evaluated against a field's string value.
+#[derive(Clone)]
+pub struct Validator {
+    check: Arc<dyn Fn(&str) -> ValidationResult + Send + Sync>,
+}
+
+impl Validator {
+    /// Wrap an arbitrary closure as a validator.
+    pub fn new(check: impl Fn(&str) -> ValidationResult + Send + Sync + 'static) -> Self {
+        Self {
+            check: Arc::new(check),
+        }
+    }
+
+    /// Run the rule against a value.
+    pub fn check(&self, value: &str) -> ValidationResult {
+        (self.check)(value)
+    }
+
+    /// Value must be non-empty after trimming.
+    pub fn required(label: impl Into<String>) -> Self {
+        let label = label.into();
+        Self::new(move |value| {
+            if value.trim().is_empty() {
+                Err(format!("{label} is required"))
+            } else {
+                Ok(())
+            }
+        })
+    }
+
+    /// Value must contain at least `min` characters.
+    pub fn min_length(label: impl Into<String>, min: usize) -> Self {
+        let label = label.into();
+        Self::new(move |value| {
+            if value.chars().count() < min {
+                Err(format!("{label} must be at least {min} characters"))
+            } else {
+                Ok(())
+            }
+        })
+    }
+
+    /// Value must look like an email address (`local@domain.tld`).
+    pub fn email(label: impl Into<String>) -> Self {
+        let label = label.into();
+        Self::new(move |value| {
+            let mut parts = value.split('@');
+            let valid = matches!(
+                (parts.next(), parts.next(), parts.next()),
+                (Some(local), Some(domain), None)
+                    if !local.is_empty() && domain.contains('.') && !domain.starts_with('.')
+            );
+            if valid {
+                Ok(())
+            } else {
+                Err(format!("{label} must be a valid email address"))
+            }
+        })
+    }
+}
+
+impl fmt::Debug for Validator {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Validator").finish_non_exhaustive()
+    }
+}
+
+/// Boxed future produced by asynchronous validators.
+pub type ValidationFuture = Pin<Box<dyn Future<Output = ValidationResult>>>;
+
+/// Asynchronous validation rule, typically backed by a network call.
+#[derive(Clone)]
+pub struct AsyncValidator {
+    check: Arc<dyn Fn(String) -> ValidationFuture + Send + Sync>,
+}
+
+impl AsyncValidator {
+    /// Wrap a future-returning closure as an async validator.
+    pub fn new(check: impl Fn(String) -> ValidationFuture + Send + Sync + 'static) -> Self {
+        Self {
+            check: Arc::new(check),
+        }
+    }
+
+    /// Start the rule against a value.
+    pub fn check(&self, value: &str) -> ValidationFuture {
+        (self.check)(value.to_string())
+    }
+}
+
+impl fmt::Debug for AsyncValidator {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("AsyncValidator").finish_non_exhaustive()
+    }
+}
+
+/// Declarative description of one form field.
+#[derive(Clone, Debug)]
+pub struct FieldSchema {
+    /// Stable machine name used for registration and value lookup.
+    pub name: &'static str,
+    /// Human readable label forwarded to the input component.
+    pub label: String,
+    /// Initial value seeded into the field state.
+    pub initial: String,
+    /// Synchronous rules evaluated on commit and submission.
+    pub validators: Vec<Validator>,
+    /// Asynchronous rules evaluated during submission orchestration.
+    pub async_validators: Vec<AsyncValidator>,
+}
+
+impl FieldSchema {
+    /// Create a field with no validation.
+    pub fn new(name: &'static str, label: impl Into<String>) -> Self {
+        Self {
+            name,
+            label: label.into(),
+            initial: String::new(),
+            validators: Vec::new(),
+            async_validators: Vec::new(),
+        }
+    }
+
+    /// Seed an initial value.
+    pub fn with_initial(mut self, initial: impl Into<String>) -> Self {
+        self.initial = initial.into();
+        self
+    }
+
+    /// Append a synchronous validator.
+    pub fn with_validator(mut self, validator: Validator) -> Self {
+        self.validators.push(validator);
+        self
+    }
+
+    /// Append an asynchronous validator.
+    pub fn with_async_validator(mut self, validator: AsyncValidator) -> Self {
+        self.async_validators.push(validator);
+        self
+    }
+}
+
+/// Ordered collection of field schemas describing one form.
+#[derive(Clone, Debug, Default)]
+pub struct FormSchema {
+    fields: Vec<FieldSchema>,
+}
+
+impl FormSchema {
+    /// Start an empty schema.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Append a field, preserving declaration order.
+    pub fn field(mut self, field: FieldSchema) -> Self {
+        debug_assert!(
+            !self
+                .fields
+                .iter()
+                .any(|existing| existing.name == field.name),
+            "duplicate field name {:?} in form schema",
+            field.name
+        );
+        self.fields.push(field);
+        self
+    }
+
+    /// Declared fields in order.
+    pub fn fields(&self) -> &[FieldSchema] {
+        &self.fields
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn required_rejects_blank_values() {
+        let validator = Validator::required("Email");
+        assert!(validator.check("   ").is_err());
+        assert!(validator.check("a@b.com").is_ok());
+    }
+
+    #[test]
+    fn email_accepts_plausible_addresses_only() {
+        let validator = Validator::email("Email");
+        assert!(validator.check("user@example.com").is_ok());
+        assert!(validator.check("user@invalid").is_err());
+        assert!(validator.check("not-an-email").is_err());
+    }
+
+    #[test]
+    fn schema_preserves_declaration_order() {
+        let schema = FormSchema::new()
+            .field(FieldSchema::new("email", "Email"))
+            .field(FieldSchema::new("name", "Name"));
+        let names: Vec<_> = schema.fields().iter().map(|field| field.name).collect();
+        assert_eq!(names, ["email", "name"]);
+    }
+}
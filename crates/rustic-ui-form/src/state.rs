@@ -0,0 +1,309 @@
+//! Runtime form state built on the headless text field machine.
+//!
+//! [`FormState`] registers one [`TextFieldState`] per schema field and
+//! orchestrates validation and submission on top of them.  The headless
+//! machine keeps dirty/visited analytics and error storage per field, so the
+//! Material and Joy text inputs bind to it without any extra glue — see
+//! [`crate::bindings`] for the attribute surface.
+
+use std::collections::HashMap;
+
+use rustic_ui_headless::text_field::TextFieldState;
+
+use crate::schema::{FormSchema, ValidationResult};
+
+/// Submission lifecycle surfaced to adapters (spinner, disabled buttons,
+/// error banners).
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub enum SubmissionPhase {
+    /// Nothing in flight.
+    #[default]
+    Idle,
+    /// Async validators are running.
+    Validating,
+    /// The submit handler is running.
+    Submitting,
+    /// The last submission succeeded.
+    Succeeded,
+    /// The last submission failed with a user-facing message.
+    Failed(String),
+}
+
+/// One registered field: its schema plus the headless input state.
+#[derive(Debug)]
+pub struct FieldHandle {
+    schema: crate::schema::FieldSchema,
+    state: TextFieldState,
+}
+
+impl FieldHandle {
+    /// Schema backing the field.
+    pub fn schema(&self) -> &crate::schema::FieldSchema {
+        &self.schema
+    }
+
+    /// Headless input state, for binding to text field components.
+    pub fn state(&self) -> &TextFieldState {
+        &self.state
+    }
+
+    /// Evaluate the synchronous validators against the current value.
+    fn evaluate(&self) -> Vec<String> {
+        self.schema
+            .validators
+            .iter()
+            .filter_map(|validator| validator.check(self.state.value()).err())
+            .collect()
+    }
+}
+
+/// Deterministic form orchestrator shared by every framework adapter.
+#[derive(Debug, Default)]
+pub struct FormState {
+    fields: Vec<FieldHandle>,
+    phase: SubmissionPhase,
+}
+
+impl FormState {
+    /// Register every field declared in the schema, in order.
+    pub fn from_schema(schema: FormSchema) -> Self {
+        let fields = schema
+            .fields()
+            .iter()
+            .map(|field| FieldHandle {
+                state: TextFieldState::uncontrolled(field.initial.clone(), None),
+                schema: field.clone(),
+            })
+            .collect();
+        Self {
+            fields,
+            phase: SubmissionPhase::Idle,
+        }
+    }
+
+    /// Registered fields in declaration order.
+    pub fn fields(&self) -> &[FieldHandle] {
+        &self.fields
+    }
+
+    /// Current submission phase.
+    pub fn phase(&self) -> &SubmissionPhase {
+        &self.phase
+    }
+
+    /// Look up a field handle by name.
+    pub fn field(&self, name: &str) -> Option<&FieldHandle> {
+        self.fields.iter().find(|field| field.schema.name == name)
+    }
+
+    fn field_mut(&mut self, name: &str) -> Option<&mut FieldHandle> {
+        self.fields
+            .iter_mut()
+            .find(|field| field.schema.name == name)
+    }
+
+    /// Update a field value on keystroke without validating — validation
+    /// runs on commit so users are not shouted at mid-word.
+    pub fn change(&mut self, name: &str, value: impl Into<String>) {
+        if let Some(field) = self.field_mut(name) {
+            field.state.change(value, |_| {});
+        }
+    }
+
+    /// Commit a field (blur) and run its synchronous validators.
+    pub fn commit(&mut self, name: &str) {
+        if let Some(field) = self.field_mut(name) {
+            field.state.commit(|_| {});
+            let errors = field.evaluate();
+            if errors.is_empty() {
+                field.state.clear_errors();
+            } else {
+                field.state.set_errors(errors);
+            }
+        }
+    }
+
+    /// Run every synchronous validator, storing errors on the fields.
+    /// Returns whether the whole form is valid.
+    pub fn validate(&mut self) -> bool {
+        let mut valid = true;
+        for field in &mut self.fields {
+            let errors = field.evaluate();
+            if errors.is_empty() {
+                field.state.clear_errors();
+            } else {
+                valid = false;
+                field.state.set_errors(errors);
+            }
+        }
+        valid
+    }
+
+    /// Run the asynchronous validators for every field, appending any
+    /// failures to the field errors. Returns whether all checks passed.
+    ///
+    /// The phase moves to [`SubmissionPhase::Validating`] for the duration
+    /// so adapters can disable the submit control.
+    pub async fn validate_async(&mut self) -> bool {
+        self.phase = SubmissionPhase::Validating;
+        let mut valid = true;
+        for field in &mut self.fields {
+            // Re-run the synchronous rules so stale errors from a previous
+            // pass never linger alongside fresh async results.
+            let mut errors = field.evaluate();
+            for validator in &field.schema.async_validators {
+                if let Err(error) = validator.check(field.state.value()).await {
+                    errors.push(error);
+                }
+            }
+            if errors.is_empty() {
+                field.state.clear_errors();
+            } else {
+                valid = false;
+                field.state.set_errors(errors);
+            }
+        }
+        self.phase = SubmissionPhase::Idle;
+        valid
+    }
+
+    /// Snapshot of every field value keyed by field name.
+    pub fn values(&self) -> HashMap<&'static str, String> {
+        self.fields
+            .iter()
+            .map(|field| (field.schema.name, field.state.value().to_string()))
+            .collect()
+    }
+
+    /// Full submission orchestration: synchronous validation, asynchronous
+    /// validation, then the provided submit handler. The phase tracks each
+    /// stage and lands on `Succeeded` or `Failed`.
+    pub async fn submit<F, Fut>(&mut self, handler: F) -> bool
+    where
+        F: FnOnce(HashMap<&'static str, String>) -> Fut,
+        Fut: std::future::Future<Output = ValidationResult>,
+    {
+        if !self.validate() {
+            self.phase = SubmissionPhase::Failed("Please fix the highlighted fields".into());
+            return false;
+        }
+        if !self.validate_async().await {
+            self.phase = SubmissionPhase::Failed("Please fix the highlighted fields".into());
+            return false;
+        }
+        self.phase = SubmissionPhase::Submitting;
+        match handler(self.values()).await {
+            Ok(()) => {
+                self.phase = SubmissionPhase::Succeeded;
+                true
+            }
+            Err(message) => {
+                self.phase = SubmissionPhase::Failed(message);
+                false
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::schema::{AsyncValidator, FieldSchema, Validator};
+    use std::task::{Context, Poll, Waker};
+
+    /// Drive a future to completion on a no-op waker; every future in these
+    /// tests is immediately ready so a poll loop suffices.
+    fn block_on<F: std::future::Future>(future: F) -> F::Output {
+        let mut future = Box::pin(future);
+        let mut context = Context::from_waker(Waker::noop());
+        loop {
+            if let Poll::Ready(output) = future.as_mut().poll(&mut context) {
+                return output;
+            }
+        }
+    }
+
+    fn sample_schema() -> FormSchema {
+        FormSchema::new()
+            .field(
+                FieldSchema::new("email", "Email")
+                    .with_validator(Validator::required("Email"))
+                    .with_validator(Validator::email("Email")),
+            )
+            .field(FieldSchema::new("name", "Name").with_validator(Validator::required("Name")))
+    }
+
+    #[test]
+    fn commit_validates_a_single_field() {
+        let mut form = FormState::from_schema(sample_schema());
+        form.change("email", "nope");
+        form.commit("email");
+        assert!(!form.field("email").unwrap().state().errors().is_empty());
+
+        form.change("email", "user@example.com");
+        form.commit("email");
+        assert!(form.field("email").unwrap().state().errors().is_empty());
+    }
+
+    #[test]
+    fn validate_reports_across_all_fields() {
+        let mut form = FormState::from_schema(sample_schema());
+        form.change("email", "user@example.com");
+        form.commit("email");
+        assert!(!form.validate(), "name is still blank");
+        form.change("name", "Ada");
+        form.commit("name");
+        assert!(form.validate());
+    }
+
+    #[test]
+    fn async_validators_append_errors() {
+        let schema = FormSchema::new().field(
+            FieldSchema::new("username", "Username").with_async_validator(AsyncValidator::new(
+                |value| {
+                    Box::pin(async move {
+                        if value == "taken" {
+                            Err("Username is already taken".to_string())
+                        } else {
+                            Ok(())
+                        }
+                    })
+                },
+            )),
+        );
+        let mut form = FormState::from_schema(schema);
+        form.change("username", "taken");
+        assert!(!block_on(form.validate_async()));
+        form.change("username", "free");
+        assert!(block_on(form.validate_async()));
+    }
+
+    #[test]
+    fn submit_orchestrates_validation_and_phases() {
+        let mut form = FormState::from_schema(sample_schema());
+        assert!(!block_on(form.submit(|_| async { Ok(()) })));
+        assert!(matches!(form.phase(), SubmissionPhase::Failed(_)));
+
+        form.change("email", "user@example.com");
+        form.change("name", "Ada");
+        assert!(block_on(form.submit(|values| async move {
+            assert_eq!(values["name"], "Ada");
+            Ok(())
+        })));
+        assert_eq!(form.phase(), &SubmissionPhase::Succeeded);
+    }
+
+    #[test]
+    fn failing_handler_surfaces_the_message() {
+        let mut form = FormState::from_schema(sample_schema());
+        form.change("email", "user@example.com");
+        form.change("name", "Ada");
+        assert!(!block_on(form.submit(|_| async {
+            Err("Server rejected the request".to_string())
+        })));
+        assert_eq!(
+            form.phase(),
+            &SubmissionPhase::Failed("Server rejected the request".into())
+        );
+    }
+}
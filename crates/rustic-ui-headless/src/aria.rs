@@ -125,6 +125,12 @@ pub const fn role_dialog() -> &'static str {
     "dialog"
 }
 
+/// Returns the ARIA role for toolbar containers grouping related controls.
+#[inline]
+pub const fn role_toolbar() -> &'static str {
+    "toolbar"
+}
+
 /// Compute the `aria-pressed` attribute for toggleable buttons.
 #[inline]
 pub const fn aria_pressed(pressed: bool) -> (&'static str, &'static str) {
@@ -0,0 +1,179 @@
+//! Carousel machine with CSS scroll-snap synchronization.
+//!
+//! Modern carousels lean on native `scroll-snap-type` for buttery swiping and
+//! only need a state machine for everything around it: which slide is active,
+//! arrow/keyboard navigation, and pagination dots.  The tricky part is keeping
+//! both sides in sync without feedback loops — a machine driven change
+//! programmatically scrolls the container, which fires scroll events, which
+//! would echo back into the machine.  This module owns that handshake:
+//! [`set_active`](CarouselState::set_active) (and the arrow helpers) record a
+//! pending snap target, [`sync_scroll_position`](CarouselState::sync_scroll_position)
+//! translates native scroll offsets into index changes while swallowing the
+//! echo until the programmatic scroll settles, and
+//! [`scroll_offset_for_active`](CarouselState::scroll_offset_for_active)
+//! reports where adapters should scroll to.  The machine stays the single
+//! source of truth either way.
+
+/// Outcome of feeding a native scroll position into the machine.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct CarouselChange {
+    /// `Some(index)` when the active slide moved.
+    pub active: Option<usize>,
+}
+
+/// Carousel state machine tracking the active slide.
+#[derive(Debug, Clone)]
+pub struct CarouselState {
+    count: usize,
+    active: usize,
+    /// Snap target of an in-flight programmatic scroll.  While set, scroll
+    /// events are treated as the echo of our own scrolling rather than user
+    /// input.
+    pending_snap: Option<usize>,
+}
+
+impl CarouselState {
+    /// Create a carousel over `count` slides starting at the first one.
+    pub fn new(count: usize) -> Self {
+        Self {
+            count,
+            active: 0,
+            pending_snap: None,
+        }
+    }
+
+    /// Number of slides.
+    #[inline]
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    /// Index of the active slide.
+    #[inline]
+    pub fn active(&self) -> usize {
+        self.active
+    }
+
+    /// Whether a programmatic scroll is still settling toward its target.
+    #[inline]
+    pub fn is_snapping(&self) -> bool {
+        self.pending_snap.is_some()
+    }
+
+    /// Activate a slide from the machine side (arrow button, pagination dot,
+    /// keyboard).
+    ///
+    /// Records the index as pending snap target so the scroll events fired by
+    /// the adapter's programmatic scroll do not echo back as user input.
+    /// Returns the change so adapters know when to scroll.
+    pub fn set_active(&mut self, index: usize) -> CarouselChange {
+        let clamped = index.min(self.count.saturating_sub(1));
+        if clamped == self.active {
+            return CarouselChange::default();
+        }
+        self.active = clamped;
+        self.pending_snap = Some(clamped);
+        CarouselChange {
+            active: Some(clamped),
+        }
+    }
+
+    /// Activate the next slide, stopping at the end.
+    pub fn advance(&mut self) -> CarouselChange {
+        self.set_active(self.active.saturating_add(1))
+    }
+
+    /// Activate the previous slide, stopping at the start.
+    pub fn previous(&mut self) -> CarouselChange {
+        self.set_active(self.active.saturating_sub(1))
+    }
+
+    /// Translate a native scroll position into an index change.
+    ///
+    /// `scroll_offset` is the container's `scrollLeft` (or `scrollTop` for
+    /// vertical carousels) and `item_extent` the per-slide width/height
+    /// including gaps.  The nearest snap point wins, mirroring what
+    /// `scroll-snap-align: start` settles on.  While a programmatic scroll is
+    /// in flight the offsets are swallowed until the container reaches the
+    /// pending target, so machine driven navigation never echoes.
+    pub fn sync_scroll_position(&mut self, scroll_offset: f64, item_extent: f64) -> CarouselChange {
+        if self.count == 0 || item_extent <= 0.0 {
+            return CarouselChange::default();
+        }
+        let index = ((scroll_offset / item_extent).round().max(0.0) as usize).min(self.count - 1);
+        if let Some(target) = self.pending_snap {
+            if index == target {
+                self.pending_snap = None;
+            }
+            return CarouselChange::default();
+        }
+        if index == self.active {
+            return CarouselChange::default();
+        }
+        self.active = index;
+        CarouselChange {
+            active: Some(index),
+        }
+    }
+
+    /// Scroll offset the container should settle at for the active slide.
+    ///
+    /// Adapters apply this (typically via `scrollTo` with smooth behaviour)
+    /// whenever a machine driven change reports a new active index.
+    #[inline]
+    pub fn scroll_offset_for_active(&self, item_extent: f64) -> f64 {
+        self.active as f64 * item_extent
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn native_scrolling_drives_the_active_index() {
+        let mut state = CarouselState::new(5);
+        // Mid-swipe offsets snap to the nearest slide.
+        assert_eq!(state.sync_scroll_position(130.0, 300.0).active, None);
+        assert_eq!(state.sync_scroll_position(170.0, 300.0).active, Some(1));
+        assert_eq!(state.sync_scroll_position(300.0, 300.0).active, None);
+        // Overscroll clamps to the last slide.
+        assert_eq!(state.sync_scroll_position(2400.0, 300.0).active, Some(4));
+    }
+
+    #[test]
+    fn machine_driven_changes_swallow_their_own_scroll_echo() {
+        let mut state = CarouselState::new(4);
+        assert_eq!(state.set_active(2).active, Some(2));
+        assert_eq!(state.scroll_offset_for_active(300.0), 600.0);
+        assert!(state.is_snapping());
+
+        // The smooth scroll passes slide 1 on the way; nothing echoes back.
+        assert_eq!(state.sync_scroll_position(300.0, 300.0).active, None);
+        assert_eq!(state.sync_scroll_position(600.0, 300.0).active, None);
+        assert!(!state.is_snapping());
+
+        // Once settled, user scrolling is live again.
+        assert_eq!(state.sync_scroll_position(900.0, 300.0).active, Some(3));
+    }
+
+    #[test]
+    fn arrow_navigation_stops_at_the_edges() {
+        let mut state = CarouselState::new(3);
+        assert_eq!(state.previous().active, None);
+        assert_eq!(state.advance().active, Some(1));
+        state.advance();
+        assert_eq!(state.advance().active, None);
+        assert_eq!(state.active(), 2);
+    }
+
+    #[test]
+    fn degenerate_inputs_are_ignored() {
+        let mut empty = CarouselState::new(0);
+        assert_eq!(empty.sync_scroll_position(500.0, 300.0).active, None);
+        assert_eq!(empty.set_active(3).active, None);
+
+        let mut state = CarouselState::new(3);
+        assert_eq!(state.sync_scroll_position(500.0, 0.0).active, None);
+    }
+}
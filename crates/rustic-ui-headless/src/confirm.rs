@@ -0,0 +1,218 @@
+//! "Are you sure?" confirmation flow composed on top of the dialog machine.
+//!
+//! Destructive and irreversible actions share the same choreography in every
+//! application: something requests the action, a dialog asks for
+//! confirmation, and the action either proceeds or is discarded.  Instead of
+//! each app re-wiring a [`DialogState`] plus an `Option<PendingThing>` by
+//! hand, [`ConfirmState`] owns the pairing — the typed payload describing the
+//! pending action travels with the dialog lifecycle and comes back out
+//! through [`ConfirmOutcome`] exactly once, so the action can never fire
+//! without the dialog having been open and never fires twice.
+//!
+//! Styling metadata rides along via [`ConfirmSeverity`]: renderers map
+//! `Destructive` onto danger-toned confirm buttons while `Standard` flows
+//! keep the primary palette, without the machine knowing anything about CSS.
+
+use crate::dialog::{DialogPhase, DialogState};
+
+/// How renderers should tone the confirmation surface.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ConfirmSeverity {
+    /// Reversible action; confirm renders with the primary palette.
+    #[default]
+    Standard,
+    /// Irreversible action; confirm renders with the danger palette and the
+    /// cancel affordance takes the visual default.
+    Destructive,
+}
+
+impl ConfirmSeverity {
+    /// Stable string for `data-*` attributes and telemetry payloads.
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Standard => "standard",
+            Self::Destructive => "destructive",
+        }
+    }
+}
+
+/// Resolution of a confirmation flow, carrying the pending payload back out.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConfirmOutcome<T> {
+    /// The user confirmed; run the action described by the payload.
+    Confirmed(T),
+    /// The user backed out; the payload is returned for cleanup/telemetry.
+    Cancelled(T),
+}
+
+/// Confirmation machine pairing a [`DialogState`] with a pending payload.
+///
+/// `T` describes the action awaiting confirmation — an enum of operations, a
+/// record id, a closure handle — whatever the application needs to resume
+/// once the user decides.
+#[derive(Debug, Clone)]
+pub struct ConfirmState<T> {
+    dialog: DialogState,
+    severity: ConfirmSeverity,
+    pending: Option<T>,
+}
+
+impl<T> Default for ConfirmState<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> ConfirmState<T> {
+    /// Construct an idle confirmation flow with standard severity.
+    pub fn new() -> Self {
+        Self {
+            dialog: DialogState::uncontrolled(false),
+            severity: ConfirmSeverity::Standard,
+            pending: None,
+        }
+    }
+
+    /// Mark the flow as destructive so renderers apply danger styling.
+    pub fn destructive(mut self) -> Self {
+        self.severity = ConfirmSeverity::Destructive;
+        self
+    }
+
+    /// Severity metadata for the rendering layer.
+    #[inline]
+    pub fn severity(&self) -> ConfirmSeverity {
+        self.severity
+    }
+
+    /// Borrow the underlying dialog machine for attribute builders and
+    /// animation bookkeeping ([`finish_open`](DialogState::finish_open) etc.).
+    #[inline]
+    pub fn dialog(&self) -> &DialogState {
+        &self.dialog
+    }
+
+    /// Mutable access to the dialog machine for transition callbacks.
+    #[inline]
+    pub fn dialog_mut(&mut self) -> &mut DialogState {
+        &mut self.dialog
+    }
+
+    /// The action currently awaiting confirmation, if any.
+    #[inline]
+    pub fn pending(&self) -> Option<&T> {
+        self.pending.as_ref()
+    }
+
+    /// Whether the confirmation dialog should be rendered.
+    #[inline]
+    pub fn is_open(&self) -> bool {
+        self.dialog.is_open()
+    }
+
+    /// Request confirmation for `action`, opening the dialog.
+    ///
+    /// If a previous request is still pending it is returned as
+    /// [`ConfirmOutcome::Cancelled`] so no payload is ever silently dropped.
+    pub fn request(&mut self, action: T) -> Option<ConfirmOutcome<T>> {
+        let displaced = self.pending.replace(action).map(ConfirmOutcome::Cancelled);
+        self.dialog.open(|_| {});
+        displaced
+    }
+
+    /// Resolve the flow as confirmed, closing the dialog and handing the
+    /// payload back so the caller can execute the action.
+    pub fn confirm(&mut self) -> Option<ConfirmOutcome<T>> {
+        self.resolve(true)
+    }
+
+    /// Resolve the flow as cancelled (cancel button, backdrop click).
+    pub fn cancel(&mut self) -> Option<ConfirmOutcome<T>> {
+        self.resolve(false)
+    }
+
+    /// Route an escape key press; cancels when the dialog honours escape.
+    pub fn handle_escape(&mut self) -> Option<ConfirmOutcome<T>> {
+        if self.dialog.phase() == DialogPhase::Closed {
+            return None;
+        }
+        let mut closed = false;
+        self.dialog.handle_escape(|open| closed = !open);
+        if closed {
+            self.pending.take().map(ConfirmOutcome::Cancelled)
+        } else {
+            None
+        }
+    }
+
+    fn resolve(&mut self, confirmed: bool) -> Option<ConfirmOutcome<T>> {
+        let payload = self.pending.take()?;
+        self.dialog.close(|_| {});
+        Some(if confirmed {
+            ConfirmOutcome::Confirmed(payload)
+        } else {
+            ConfirmOutcome::Cancelled(payload)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    enum Action {
+        DeleteRecord(u32),
+        ArchiveProject,
+    }
+
+    #[test]
+    fn confirmed_flows_hand_the_payload_back_exactly_once() {
+        let mut state = ConfirmState::new().destructive();
+        assert_eq!(state.severity(), ConfirmSeverity::Destructive);
+        assert!(state.request(Action::DeleteRecord(7)).is_none());
+        assert!(state.is_open());
+        assert_eq!(state.pending(), Some(&Action::DeleteRecord(7)));
+
+        assert_eq!(
+            state.confirm(),
+            Some(ConfirmOutcome::Confirmed(Action::DeleteRecord(7)))
+        );
+        assert!(state.pending().is_none());
+        // A second confirm cannot re-fire the action.
+        assert_eq!(state.confirm(), None);
+    }
+
+    #[test]
+    fn cancel_and_escape_return_the_payload_for_cleanup() {
+        let mut state = ConfirmState::new();
+        state.request(Action::ArchiveProject);
+        assert_eq!(
+            state.cancel(),
+            Some(ConfirmOutcome::Cancelled(Action::ArchiveProject))
+        );
+
+        state.request(Action::ArchiveProject);
+        state.dialog_mut().finish_open();
+        assert_eq!(
+            state.handle_escape(),
+            Some(ConfirmOutcome::Cancelled(Action::ArchiveProject))
+        );
+        assert_eq!(state.handle_escape(), None);
+    }
+
+    #[test]
+    fn displaced_requests_surface_as_cancellations() {
+        let mut state = ConfirmState::new();
+        state.request(Action::DeleteRecord(1));
+        // A second request before the first resolves must not drop payloads.
+        assert_eq!(
+            state.request(Action::DeleteRecord(2)),
+            Some(ConfirmOutcome::Cancelled(Action::DeleteRecord(1)))
+        );
+        assert_eq!(
+            state.confirm(),
+            Some(ConfirmOutcome::Confirmed(Action::DeleteRecord(2)))
+        );
+    }
+}
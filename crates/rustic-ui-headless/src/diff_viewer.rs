@@ -0,0 +1,258 @@
+//! State machine powering comparison/diff viewers.
+//!
+//! Audit-log and config-review screens share the same interaction core
+//! regardless of what produced the diff: the reviewer flips between unified
+//! and side-by-side layouts, expands collapsed hunks to see surrounding
+//! context and selects line ranges to reference in a comment or approval.
+//! The machine owns those three axes — view mode, per-hunk expansion and a
+//! normalised line selection — while renderers own the actual text, so SSR
+//! snapshots and hydrated trees always agree on what is visible.
+
+/// Layout of the comparison surface.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DiffViewMode {
+    /// Single column interleaving removed and added lines.
+    #[default]
+    Unified,
+    /// Two synchronised columns: old revision left, new revision right.
+    SideBySide,
+}
+
+impl DiffViewMode {
+    /// Stable identifier stamped into `data-diff-mode` hooks.
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Unified => "unified",
+            Self::SideBySide => "side-by-side",
+        }
+    }
+
+    /// Returns the other layout, used by mode toggle buttons.
+    pub const fn toggled(self) -> Self {
+        match self {
+            Self::Unified => Self::SideBySide,
+            Self::SideBySide => Self::Unified,
+        }
+    }
+}
+
+/// Address of one line within the diff: hunk index plus the line's position
+/// inside that hunk.  Ordered so selections can span hunk boundaries.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct DiffLineRef {
+    /// Index of the containing hunk.
+    pub hunk: usize,
+    /// Zero based line offset inside the hunk.
+    pub line: usize,
+}
+
+impl DiffLineRef {
+    /// Convenience constructor mirroring the field order.
+    pub const fn new(hunk: usize, line: usize) -> Self {
+        Self { hunk, line }
+    }
+}
+
+/// Headless diff viewer state machine.
+///
+/// Constructed from the number of hunks in the comparison; renderers keep
+/// the hunk contents themselves and query the machine for mode, expansion
+/// and selection verdicts.
+#[derive(Debug, Clone)]
+pub struct DiffViewerState {
+    mode: DiffViewMode,
+    /// Expansion flag per hunk.  Hunks start expanded — reviewers collapse
+    /// noise rather than digging changes out of collapsed sections.
+    expanded: Vec<bool>,
+    /// Selection anchor, set by the first selected line and kept while the
+    /// range is extended with shift-click or shift-arrow interactions.
+    anchor: Option<DiffLineRef>,
+    /// Focused end of the selection range.
+    cursor: Option<DiffLineRef>,
+}
+
+impl DiffViewerState {
+    /// Creates the machine for a diff with `hunk_count` hunks.
+    pub fn new(hunk_count: usize) -> Self {
+        Self {
+            mode: DiffViewMode::default(),
+            expanded: vec![true; hunk_count],
+            anchor: None,
+            cursor: None,
+        }
+    }
+
+    /// Currently active layout.
+    #[inline]
+    pub fn mode(&self) -> DiffViewMode {
+        self.mode
+    }
+
+    /// Switches to the requested layout.
+    pub fn set_mode(&mut self, mode: DiffViewMode) {
+        self.mode = mode;
+    }
+
+    /// Flips between unified and side-by-side, returning the new mode.
+    pub fn toggle_mode(&mut self) -> DiffViewMode {
+        self.mode = self.mode.toggled();
+        self.mode
+    }
+
+    /// Number of hunks tracked by the machine.
+    #[inline]
+    pub fn hunk_count(&self) -> usize {
+        self.expanded.len()
+    }
+
+    /// Whether the given hunk is expanded; out of range reads as collapsed.
+    pub fn is_expanded(&self, hunk: usize) -> bool {
+        self.expanded.get(hunk).copied().unwrap_or(false)
+    }
+
+    /// Toggles one hunk, returning its new expansion state.  Collapsing a
+    /// hunk drops any selection it contained so hidden lines can never stay
+    /// referenced by a pending comment.
+    pub fn toggle_hunk(&mut self, hunk: usize) -> bool {
+        let Some(flag) = self.expanded.get_mut(hunk) else {
+            return false;
+        };
+        *flag = !*flag;
+        let expanded = *flag;
+        if !expanded {
+            if let Some((start, end)) = self.selection() {
+                if start.hunk <= hunk && hunk <= end.hunk {
+                    self.clear_selection();
+                }
+            }
+        }
+        expanded
+    }
+
+    /// Expands every hunk (e.g. before printing or exporting the review).
+    pub fn expand_all(&mut self) {
+        self.expanded.fill(true);
+    }
+
+    /// Collapses every hunk and clears the selection.
+    pub fn collapse_all(&mut self) {
+        self.expanded.fill(false);
+        self.clear_selection();
+    }
+
+    /// Starts a fresh selection at the given line, anchoring future
+    /// extensions there.  Lines in collapsed or out-of-range hunks are
+    /// ignored.
+    pub fn select_line(&mut self, line: DiffLineRef) {
+        if !self.is_expanded(line.hunk) {
+            return;
+        }
+        self.anchor = Some(line);
+        self.cursor = Some(line);
+    }
+
+    /// Extends the selection from the anchor to the given line
+    /// (shift-click/shift-arrow).  Without an anchor this degrades to
+    /// [`DiffViewerState::select_line`].
+    pub fn extend_selection(&mut self, line: DiffLineRef) {
+        if !self.is_expanded(line.hunk) {
+            return;
+        }
+        if self.anchor.is_none() {
+            self.anchor = Some(line);
+        }
+        self.cursor = Some(line);
+    }
+
+    /// Clears the selected range.
+    pub fn clear_selection(&mut self) {
+        self.anchor = None;
+        self.cursor = None;
+    }
+
+    /// Selected range normalised to `(start, end)` in document order.
+    pub fn selection(&self) -> Option<(DiffLineRef, DiffLineRef)> {
+        let (anchor, cursor) = (self.anchor?, self.cursor?);
+        Some((anchor.min(cursor), anchor.max(cursor)))
+    }
+
+    /// Whether one line falls inside the selected range.  Renderers use the
+    /// verdict for `aria-selected` and row highlighting.
+    pub fn is_selected(&self, line: DiffLineRef) -> bool {
+        match self.selection() {
+            Some((start, end)) => start <= line && line <= end,
+            None => false,
+        }
+    }
+
+    /// Attributes for the viewer container element.
+    pub fn container_attributes(&self) -> Vec<(&'static str, String)> {
+        vec![("data-diff-mode", self.mode.as_str().to_string())]
+    }
+
+    /// Attributes for one hunk's disclosure header.
+    pub fn hunk_attributes(&self, hunk: usize) -> Vec<(&'static str, String)> {
+        vec![
+            ("aria-expanded", self.is_expanded(hunk).to_string()),
+            ("data-hunk-index", hunk.to_string()),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn mode_toggle_flips_between_layouts() {
+        let mut state = DiffViewerState::new(2);
+        assert_eq!(state.mode(), DiffViewMode::Unified);
+        assert_eq!(state.toggle_mode(), DiffViewMode::SideBySide);
+        assert_eq!(state.toggle_mode(), DiffViewMode::Unified);
+        assert_eq!(state.container_attributes()[0].1, "unified");
+    }
+
+    #[test]
+    fn hunks_start_expanded_and_toggle_individually() {
+        let mut state = DiffViewerState::new(3);
+        assert!(state.is_expanded(1));
+        assert!(!state.toggle_hunk(1));
+        assert!(!state.is_expanded(1));
+        assert!(state.is_expanded(0));
+        // Out of range hunks read as collapsed and refuse to toggle.
+        assert!(!state.toggle_hunk(9));
+        assert!(!state.is_expanded(9));
+    }
+
+    #[test]
+    fn selections_normalise_and_span_hunks() {
+        let mut state = DiffViewerState::new(3);
+        state.select_line(DiffLineRef::new(2, 1));
+        state.extend_selection(DiffLineRef::new(0, 4));
+        let (start, end) = state.selection().expect("range selected");
+        assert_eq!(start, DiffLineRef::new(0, 4));
+        assert_eq!(end, DiffLineRef::new(2, 1));
+        assert!(state.is_selected(DiffLineRef::new(1, 0)));
+        assert!(!state.is_selected(DiffLineRef::new(2, 2)));
+    }
+
+    #[test]
+    fn collapsing_a_hunk_drops_selections_it_contained() {
+        let mut state = DiffViewerState::new(3);
+        state.select_line(DiffLineRef::new(1, 0));
+        state.extend_selection(DiffLineRef::new(1, 3));
+        state.toggle_hunk(1);
+        assert_eq!(state.selection(), None);
+        // Lines in collapsed hunks cannot start a new selection.
+        state.select_line(DiffLineRef::new(1, 0));
+        assert_eq!(state.selection(), None);
+    }
+
+    #[test]
+    fn selection_in_untouched_hunks_survives_collapsing_elsewhere() {
+        let mut state = DiffViewerState::new(3);
+        state.select_line(DiffLineRef::new(0, 2));
+        state.toggle_hunk(2);
+        assert!(state.is_selected(DiffLineRef::new(0, 2)));
+    }
+}
@@ -251,6 +251,173 @@ impl<'a> DrawerBackdropAttributes<'a> {
     }
 }
 
+/// Tuning knobs for the touch gesture machine.
+#[derive(Debug, Clone)]
+pub struct DrawerSwipeConfig {
+    /// Size of the drawer along its gesture axis in pixels (width for
+    /// start/end anchors, height for top/bottom).
+    pub size: f64,
+    /// Width of the screen-edge region (in pixels) where a touch may begin an
+    /// opening swipe while the drawer is closed.
+    pub edge_width: f64,
+    /// Fraction of [`size`](Self::size) the surface must pass for a release
+    /// without momentum to settle open (or, mirrored, closed).
+    pub settle_threshold: f64,
+    /// Velocity in pixels per millisecond above which a fling settles in the
+    /// direction of travel regardless of position.
+    pub velocity_threshold: f64,
+}
+
+impl DrawerSwipeConfig {
+    /// Defaults matching typical mobile drawer tuning: a 24px edge region,
+    /// half-way settle point and a 0.3 px/ms fling threshold.
+    pub fn enterprise_defaults(size: f64) -> Self {
+        Self {
+            size,
+            edge_width: 24.0,
+            settle_threshold: 0.5,
+            velocity_threshold: 0.3,
+        }
+    }
+}
+
+/// Where a released swipe should settle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DrawerSwipeOutcome {
+    /// Animate fully open and call [`DrawerState::open`].
+    Open,
+    /// Animate fully closed and call [`DrawerState::close`].
+    Close,
+}
+
+/// Touch gesture machine translating swipes into drawer intents.
+///
+/// The machine is deliberately DOM free: adapters feed it the touch
+/// coordinate measured as *distance from the anchored edge* along the
+/// gesture axis (`clientX` for a start anchor, `viewport width - clientX`
+/// for an end anchor, and the vertical equivalents for top/bottom) together
+/// with the event timestamp in milliseconds.  That normalisation means
+/// "growing coordinate" always equals "opening" regardless of anchor, which
+/// keeps the settle logic testable without a browser.
+#[derive(Debug, Clone)]
+pub struct DrawerSwipeState {
+    config: DrawerSwipeConfig,
+    dragging: bool,
+    start_progress: f64,
+    start_position: f64,
+    progress: f64,
+    last_position: f64,
+    last_timestamp: f64,
+    velocity: f64,
+}
+
+impl DrawerSwipeState {
+    /// Create an idle gesture machine.
+    pub fn new(config: DrawerSwipeConfig) -> Self {
+        Self {
+            config,
+            dragging: false,
+            start_progress: 0.0,
+            start_position: 0.0,
+            progress: 0.0,
+            last_position: 0.0,
+            last_timestamp: 0.0,
+            velocity: 0.0,
+        }
+    }
+
+    /// Whether a drag is currently tracking the finger.
+    #[inline]
+    pub fn is_dragging(&self) -> bool {
+        self.dragging
+    }
+
+    /// Current open fraction between `0.0` (closed) and `1.0` (open).
+    #[inline]
+    pub fn progress(&self) -> f64 {
+        self.progress
+    }
+
+    /// How far the surface currently sits off screen toward its anchor, in
+    /// pixels.  Adapters turn this into the follow-finger translation.
+    #[inline]
+    pub fn surface_offset(&self) -> f64 {
+        (1.0 - self.progress) * self.config.size
+    }
+
+    /// Begin tracking a touch.
+    ///
+    /// While the drawer is closed only touches inside the configured edge
+    /// region start an opening drag; while open any touch on the surface may
+    /// start a closing drag.  Returns whether the gesture was accepted.
+    pub fn touch_start(&mut self, position: f64, timestamp: f64, drawer_open: bool) -> bool {
+        if !drawer_open && position > self.config.edge_width {
+            return false;
+        }
+        self.dragging = true;
+        self.start_progress = if drawer_open { 1.0 } else { 0.0 };
+        self.start_position = position;
+        self.progress = self.start_progress;
+        self.last_position = position;
+        self.last_timestamp = timestamp;
+        self.velocity = 0.0;
+        true
+    }
+
+    /// Track a finger movement, returning the updated open fraction so
+    /// adapters can translate the surface in lockstep with the finger.
+    pub fn touch_move(&mut self, position: f64, timestamp: f64) -> Option<f64> {
+        if !self.dragging {
+            return None;
+        }
+        let elapsed = timestamp - self.last_timestamp;
+        if elapsed > 0.0 {
+            self.velocity = (position - self.last_position) / elapsed;
+        }
+        self.last_position = position;
+        self.last_timestamp = timestamp;
+        let delta = (position - self.start_position) / self.config.size;
+        self.progress = (self.start_progress + delta).clamp(0.0, 1.0);
+        Some(self.progress)
+    }
+
+    /// Release the touch and decide where the surface settles.
+    ///
+    /// A fling above the velocity threshold wins in the direction of travel;
+    /// otherwise the settle threshold on the dragged distance decides.
+    /// Returns `None` when no drag was in progress.
+    pub fn touch_end(&mut self) -> Option<DrawerSwipeOutcome> {
+        if !self.dragging {
+            return None;
+        }
+        self.dragging = false;
+        let outcome = if self.velocity.abs() >= self.config.velocity_threshold {
+            if self.velocity > 0.0 {
+                DrawerSwipeOutcome::Open
+            } else {
+                DrawerSwipeOutcome::Close
+            }
+        } else if self.progress >= self.config.settle_threshold {
+            DrawerSwipeOutcome::Open
+        } else {
+            DrawerSwipeOutcome::Close
+        };
+        self.progress = match outcome {
+            DrawerSwipeOutcome::Open => 1.0,
+            DrawerSwipeOutcome::Close => 0.0,
+        };
+        Some(outcome)
+    }
+
+    /// Abort the gesture (e.g. `touchcancel`) and snap back to where the
+    /// drag started.
+    pub fn cancel(&mut self) {
+        self.dragging = false;
+        self.progress = self.start_progress;
+        self.velocity = 0.0;
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -331,6 +498,58 @@ mod tests {
         assert!(!persistent.backdrop_attributes().is_visible());
     }
 
+    fn swipe(size: f64) -> DrawerSwipeState {
+        DrawerSwipeState::new(DrawerSwipeConfig::enterprise_defaults(size))
+    }
+
+    #[test]
+    fn edge_swipe_follows_the_finger_and_settles_open() {
+        let mut gesture = swipe(300.0);
+        // Touches away from the edge never start an opening drag.
+        assert!(!gesture.touch_start(120.0, 0.0, false));
+        assert!(gesture.touch_start(10.0, 0.0, false));
+
+        // Slow drag past the halfway point: position decides.
+        assert_eq!(gesture.touch_move(100.0, 400.0), Some(0.3));
+        assert_eq!(gesture.touch_move(190.0, 900.0), Some(0.6));
+        assert_eq!(gesture.touch_end(), Some(DrawerSwipeOutcome::Open));
+        assert_eq!(gesture.progress(), 1.0);
+        assert_eq!(gesture.surface_offset(), 0.0);
+    }
+
+    #[test]
+    fn fling_velocity_beats_the_settle_threshold() {
+        let mut gesture = swipe(300.0);
+        gesture.touch_start(5.0, 0.0, false);
+        // Only a third of the way across, but fast (1 px/ms).
+        gesture.touch_move(55.0, 50.0);
+        gesture.touch_move(105.0, 100.0);
+        assert_eq!(gesture.touch_end(), Some(DrawerSwipeOutcome::Open));
+
+        // Mirrored: a fast drag toward the edge closes an open drawer even
+        // though it barely moved.
+        gesture.touch_start(280.0, 0.0, true);
+        gesture.touch_move(230.0, 50.0);
+        assert_eq!(gesture.touch_end(), Some(DrawerSwipeOutcome::Close));
+        assert_eq!(gesture.surface_offset(), 300.0);
+    }
+
+    #[test]
+    fn short_drags_revert_and_cancel_snaps_back() {
+        let mut gesture = swipe(300.0);
+        gesture.touch_start(250.0, 0.0, true);
+        gesture.touch_move(200.0, 500.0);
+        // Still above the settle threshold and slow: the drawer stays open.
+        assert_eq!(gesture.touch_end(), Some(DrawerSwipeOutcome::Open));
+
+        gesture.touch_start(250.0, 0.0, true);
+        gesture.touch_move(100.0, 500.0);
+        gesture.cancel();
+        assert!(!gesture.is_dragging());
+        assert_eq!(gesture.progress(), 1.0);
+        assert_eq!(gesture.touch_end(), None);
+    }
+
     #[test]
     fn persistent_drawer_surface_marks_modal_false() {
         // Persistent drawers remain interactive with the page so `aria-modal`
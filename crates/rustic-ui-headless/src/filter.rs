@@ -0,0 +1,352 @@
+//! Debounced search-filter state shared by lists and grids.
+//!
+//! Autocomplete popovers, data grid quick-filters and command palettes all
+//! narrow a collection from the same two inputs: a free-text query typed by
+//! the user and a set of discrete filter chips.  Rather than letting each
+//! surface reimplement (and slowly diverge on) the matching rules, this
+//! machine owns the query, the chips and the derived match set.  Typed input
+//! is debounced through the shared [`Timer`] primitives so large collections
+//! are not re-scanned on every keystroke, while chip toggles — which are
+//! discrete clicks — recompute immediately.
+//!
+//! The machine is deliberately index based: consumers register their rows via
+//! [`FilterState::set_items`] and read back matching indices, keeping the row
+//! payloads (and their rendering) entirely in the adapter's hands.
+
+use crate::timing::{Clock, SystemClock, Timer};
+use std::time::Duration;
+
+/// Configuration describing how the filter reacts to input.
+#[derive(Debug, Clone)]
+pub struct FilterConfig {
+    /// Quiet period after the last keystroke before the match set recomputes.
+    pub debounce: Duration,
+    /// Whether query matching distinguishes letter case.
+    pub case_sensitive: bool,
+}
+
+impl FilterConfig {
+    /// Defaults tuned for large enterprise collections: a 250ms debounce and
+    /// case insensitive matching.
+    pub fn enterprise_defaults() -> Self {
+        Self {
+            debounce: Duration::from_millis(250),
+            case_sensitive: false,
+        }
+    }
+}
+
+impl Default for FilterConfig {
+    fn default() -> Self {
+        Self::enterprise_defaults()
+    }
+}
+
+/// Discrete constraint narrowing the collection alongside the text query.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FilterChip {
+    /// Field or category the chip constrains, e.g. `"status"`.
+    pub field: String,
+    /// Value the field must carry, e.g. `"open"`.
+    pub value: String,
+}
+
+impl FilterChip {
+    /// Convenience constructor mirroring `field:value` filter syntax.
+    pub fn new(field: impl Into<String>, value: impl Into<String>) -> Self {
+        Self {
+            field: field.into(),
+            value: value.into(),
+        }
+    }
+}
+
+/// One filterable row as registered by the consumer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FilterItem {
+    haystack: String,
+    fields: Vec<(String, String)>,
+}
+
+impl FilterItem {
+    /// Create an item from the free-text content the query searches.
+    pub fn new(haystack: impl Into<String>) -> Self {
+        Self {
+            haystack: haystack.into(),
+            fields: Vec::new(),
+        }
+    }
+
+    /// Attach a field/value pair that chips can constrain on.
+    pub fn with_field(mut self, field: impl Into<String>, value: impl Into<String>) -> Self {
+        self.fields.push((field.into(), value.into()));
+        self
+    }
+}
+
+/// Outcome of processing an event or timer tick.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct FilterChange {
+    /// `true` when the derived match set was recomputed this call.
+    pub matches_recomputed: bool,
+}
+
+impl FilterChange {
+    fn recomputed() -> Self {
+        Self {
+            matches_recomputed: true,
+        }
+    }
+}
+
+/// Search-filter state machine parameterised over a [`Clock`].
+#[derive(Debug, Clone)]
+pub struct FilterState<C: Clock = SystemClock> {
+    clock: C,
+    config: FilterConfig,
+    items: Vec<FilterItem>,
+    query: String,
+    chips: Vec<FilterChip>,
+    matches: Vec<usize>,
+    debounce_timer: Timer<C>,
+    dirty: bool,
+}
+
+impl FilterState<SystemClock> {
+    /// Construct the machine using the real system clock.
+    pub fn new(config: FilterConfig) -> Self {
+        Self::with_clock(SystemClock, config)
+    }
+}
+
+impl<C: Clock> FilterState<C> {
+    /// Construct the machine using a custom clock (handy for tests).
+    pub fn with_clock(clock: C, config: FilterConfig) -> Self {
+        Self {
+            clock,
+            config,
+            items: Vec::new(),
+            query: String::new(),
+            chips: Vec::new(),
+            matches: Vec::new(),
+            debounce_timer: Timer::new(),
+            dirty: false,
+        }
+    }
+
+    /// Returns the configuration backing the machine.
+    #[inline]
+    pub fn config(&self) -> &FilterConfig {
+        &self.config
+    }
+
+    /// Latest query text, including keystrokes not yet committed to the match
+    /// set.
+    #[inline]
+    pub fn query(&self) -> &str {
+        &self.query
+    }
+
+    /// Active filter chips in insertion order.
+    #[inline]
+    pub fn chips(&self) -> &[FilterChip] {
+        &self.chips
+    }
+
+    /// Indices of the items matching the last committed query and chips.
+    #[inline]
+    pub fn matches(&self) -> &[usize] {
+        &self.matches
+    }
+
+    /// Whether the item at `index` is part of the committed match set.
+    pub fn is_match(&self, index: usize) -> bool {
+        self.matches.binary_search(&index).is_ok()
+    }
+
+    /// Whether keystrokes are waiting on the debounce window before the match
+    /// set recomputes.
+    #[inline]
+    pub fn is_pending(&self) -> bool {
+        self.dirty
+    }
+
+    /// Replace the filterable collection and recompute immediately.
+    ///
+    /// Structural changes (rows arriving from the server, grid refreshes)
+    /// bypass the debounce: the previous match set refers to indices that may
+    /// no longer exist, so stale results are worse than an eager scan.
+    pub fn set_items(&mut self, items: Vec<FilterItem>) -> FilterChange {
+        self.items = items;
+        self.commit()
+    }
+
+    /// Record a keystroke and (re)arm the debounce window.
+    pub fn set_query(&mut self, query: impl Into<String>) -> FilterChange {
+        self.query = query.into();
+        self.dirty = true;
+        self.debounce_timer
+            .schedule(&self.clock, self.config.debounce);
+        FilterChange::default()
+    }
+
+    /// Add a chip and recompute immediately — chip toggles are discrete
+    /// clicks, not streams of keystrokes.
+    pub fn push_chip(&mut self, chip: FilterChip) -> FilterChange {
+        if !self.chips.contains(&chip) {
+            self.chips.push(chip);
+        }
+        self.commit()
+    }
+
+    /// Remove the chip at `index`, recomputing immediately.
+    pub fn remove_chip(&mut self, index: usize) -> FilterChange {
+        if index < self.chips.len() {
+            self.chips.remove(index);
+        }
+        self.commit()
+    }
+
+    /// Drop every chip, recomputing immediately.
+    pub fn clear_chips(&mut self) -> FilterChange {
+        self.chips.clear();
+        self.commit()
+    }
+
+    /// Commit any pending query without waiting for the debounce window, e.g.
+    /// when the user presses Enter.
+    pub fn flush(&mut self) -> FilterChange {
+        self.commit()
+    }
+
+    /// Advance timer driven transitions.
+    ///
+    /// Call from an animation-frame loop or input-idle callback; the match
+    /// set recomputes once the debounce window elapses after the last
+    /// keystroke.
+    pub fn poll(&mut self) -> FilterChange {
+        if self.dirty && self.debounce_timer.fire_if_due(&self.clock) {
+            self.commit()
+        } else {
+            FilterChange::default()
+        }
+    }
+
+    fn commit(&mut self) -> FilterChange {
+        self.dirty = false;
+        self.debounce_timer.cancel();
+        let needle = self.fold_case(&self.query);
+        self.matches = self
+            .items
+            .iter()
+            .enumerate()
+            .filter(|(_, item)| self.item_matches(item, &needle))
+            .map(|(index, _)| index)
+            .collect();
+        FilterChange::recomputed()
+    }
+
+    fn item_matches(&self, item: &FilterItem, needle: &str) -> bool {
+        if !needle.is_empty() && !self.fold_case(&item.haystack).contains(needle) {
+            return false;
+        }
+        self.chips.iter().all(|chip| {
+            item.fields.iter().any(|(field, value)| {
+                *field == chip.field && self.fold_case(value) == self.fold_case(&chip.value)
+            })
+        })
+    }
+
+    fn fold_case(&self, text: &str) -> String {
+        if self.config.case_sensitive {
+            text.to_string()
+        } else {
+            text.to_lowercase()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::timing::MockClock;
+
+    fn machine(clock: &MockClock) -> FilterState<MockClock> {
+        let mut state = FilterState::with_clock(clock.clone(), FilterConfig::enterprise_defaults());
+        state.set_items(vec![
+            FilterItem::new("Deploy service").with_field("status", "open"),
+            FilterItem::new("Restart worker").with_field("status", "closed"),
+            FilterItem::new("Deploy database").with_field("status", "closed"),
+        ]);
+        state
+    }
+
+    #[test]
+    fn query_recomputes_after_the_debounce_window() {
+        let clock = MockClock::new();
+        let mut state = machine(&clock);
+        assert_eq!(state.matches(), &[0, 1, 2]);
+
+        state.set_query("deploy");
+        assert!(state.is_pending());
+        assert_eq!(state.matches(), &[0, 1, 2], "matches stay until commit");
+
+        clock.advance(Duration::from_millis(249));
+        assert!(!state.poll().matches_recomputed);
+        clock.advance(Duration::from_millis(1));
+        assert!(state.poll().matches_recomputed);
+        assert_eq!(state.matches(), &[0, 2]);
+        assert!(!state.is_pending());
+    }
+
+    #[test]
+    fn rapid_keystrokes_extend_the_debounce() {
+        let clock = MockClock::new();
+        let mut state = machine(&clock);
+        state.set_query("d");
+        clock.advance(Duration::from_millis(200));
+        state.set_query("de");
+        clock.advance(Duration::from_millis(200));
+        assert!(!state.poll().matches_recomputed);
+        clock.advance(Duration::from_millis(50));
+        assert!(state.poll().matches_recomputed);
+    }
+
+    #[test]
+    fn chips_apply_immediately_and_combine_with_the_query() {
+        let clock = MockClock::new();
+        let mut state = machine(&clock);
+        let change = state.push_chip(FilterChip::new("status", "closed"));
+        assert!(change.matches_recomputed);
+        assert_eq!(state.matches(), &[1, 2]);
+
+        state.set_query("deploy");
+        state.flush();
+        assert_eq!(state.matches(), &[2]);
+
+        state.remove_chip(0);
+        assert_eq!(state.matches(), &[0, 2]);
+    }
+
+    #[test]
+    fn matching_is_case_insensitive_by_default() {
+        let clock = MockClock::new();
+        let mut state = machine(&clock);
+        state.set_query("DEPLOY");
+        state.flush();
+        assert_eq!(state.matches(), &[0, 2]);
+        assert!(state.is_match(0));
+        assert!(!state.is_match(1));
+    }
+
+    #[test]
+    fn replacing_items_bypasses_the_debounce() {
+        let clock = MockClock::new();
+        let mut state = machine(&clock);
+        state.set_query("deploy");
+        state.flush();
+        let change = state.set_items(vec![FilterItem::new("deploy pipeline")]);
+        assert!(change.matches_recomputed);
+        assert_eq!(state.matches(), &[0]);
+    }
+}
@@ -0,0 +1,247 @@
+//! Two dimensional keyboard navigation shared by grid shaped widgets.
+//!
+//! Date picker calendars, emoji/icon pickers and the data grid cell focus
+//! model all answer the same question — "which cell owns the roving tabindex
+//! after this key press?" — yet differ subtly at the row edges: a calendar
+//! flows from Saturday onto the next week's Sunday while a data grid clamps
+//! at the row boundary.  This primitive centralizes the shared arithmetic
+//! (including ragged last rows when the item count is not a multiple of the
+//! column count) so each widget only declares its edge behaviour.
+
+use crate::interaction::ControlKey;
+use crate::selection::clamp_index;
+
+/// Behaviour of horizontal arrow keys at the edges of a row.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum GridFlow {
+    /// Stop at the row boundary.  This matches the data grid expectation
+    /// where rows represent records and horizontal movement never changes
+    /// the record.
+    #[default]
+    Clamp,
+    /// Continue onto the adjacent row, reading-order style.  Calendars and
+    /// pickers use this so repeated <ArrowRight> walks every cell.
+    Flow,
+}
+
+/// Headless 2D navigation state tracking the focused cell of a grid.
+#[derive(Debug, Clone)]
+pub struct GridNavigationState {
+    item_count: usize,
+    columns: usize,
+    flow: GridFlow,
+    focused: Option<usize>,
+}
+
+impl GridNavigationState {
+    /// Create a navigator over `item_count` cells laid out `columns` wide.
+    ///
+    /// A zero column count is coerced to one so the arithmetic below never
+    /// divides by zero; callers always get a well-formed single-column grid.
+    pub fn new(item_count: usize, columns: usize, flow: GridFlow) -> Self {
+        let focused = if item_count > 0 { Some(0) } else { None };
+        Self {
+            item_count,
+            columns: columns.max(1),
+            flow,
+            focused,
+        }
+    }
+
+    /// Returns the number of cells in the grid.
+    #[inline]
+    pub fn item_count(&self) -> usize {
+        self.item_count
+    }
+
+    /// Returns the configured column count.
+    #[inline]
+    pub fn columns(&self) -> usize {
+        self.columns
+    }
+
+    /// Returns the number of rows, counting a ragged trailing row.
+    #[inline]
+    pub fn rows(&self) -> usize {
+        self.item_count.div_ceil(self.columns)
+    }
+
+    /// Returns the linear index of the focused cell.
+    #[inline]
+    pub fn focused(&self) -> Option<usize> {
+        self.focused
+    }
+
+    /// Returns the focused cell as `(row, column)` coordinates.
+    pub fn focused_cell(&self) -> Option<(usize, usize)> {
+        self.focused
+            .map(|index| (index / self.columns, index % self.columns))
+    }
+
+    /// Imperatively focus a cell by linear index (pointer interaction).
+    pub fn focus_index(&mut self, index: usize) {
+        if index < self.item_count {
+            self.focused = Some(index);
+        }
+    }
+
+    /// Imperatively focus a cell by coordinates.
+    pub fn focus_cell(&mut self, row: usize, column: usize) {
+        if column < self.columns {
+            self.focus_index(row * self.columns + column);
+        }
+    }
+
+    /// Update the cell count, clamping focus back into bounds.
+    pub fn set_item_count(&mut self, item_count: usize) {
+        self.item_count = item_count;
+        self.focused =
+            clamp_index(self.focused, item_count).or(if item_count > 0 { Some(0) } else { None });
+    }
+
+    /// Update the column count (responsive pickers re-measure their grid).
+    pub fn set_columns(&mut self, columns: usize) {
+        self.columns = columns.max(1);
+    }
+
+    /// Process a keyboard event and return the cell that should receive
+    /// focus, or `None` when the key did not change anything.
+    ///
+    /// `ctrl` captures the platform primary modifier: <Ctrl+Home> jumps to
+    /// the first cell of the grid and <Ctrl+End> to the last, mirroring the
+    /// WAI-ARIA grid pattern.  Without the modifier <Home>/<End> stay within
+    /// the focused row.
+    pub fn on_key(&mut self, key: ControlKey, ctrl: bool) -> Option<usize> {
+        let current = self.focused?;
+        let last = self.item_count.checked_sub(1)?;
+        let row = current / self.columns;
+        let column = current % self.columns;
+        let next = match key {
+            ControlKey::ArrowRight => match self.flow {
+                GridFlow::Flow => (current < last).then_some(current + 1),
+                GridFlow::Clamp => {
+                    (column + 1 < self.columns && current < last).then_some(current + 1)
+                }
+            },
+            ControlKey::ArrowLeft => match self.flow {
+                GridFlow::Flow => current.checked_sub(1),
+                GridFlow::Clamp => {
+                    if column > 0 {
+                        current.checked_sub(1)
+                    } else {
+                        None
+                    }
+                }
+            },
+            // Vertical movement clamps to the last cell when the trailing
+            // row is ragged and the column does not exist there.
+            ControlKey::ArrowDown => {
+                let candidate = current + self.columns;
+                if candidate <= last {
+                    Some(candidate)
+                } else if row + 1 < self.rows() {
+                    Some(last)
+                } else {
+                    None
+                }
+            }
+            ControlKey::ArrowUp => current.checked_sub(self.columns),
+            ControlKey::Home => {
+                if ctrl {
+                    Some(0)
+                } else {
+                    Some(row * self.columns)
+                }
+            }
+            ControlKey::End => {
+                if ctrl {
+                    Some(last)
+                } else {
+                    Some(((row + 1) * self.columns - 1).min(last))
+                }
+            }
+            _ => None,
+        };
+        let next = next.filter(|index| *index != current);
+        if let Some(index) = next {
+            self.focused = Some(index);
+        }
+        next
+    }
+
+    /// Roving tabindex attribute for one cell.
+    pub fn cell_tabindex(&self, index: usize) -> (&'static str, &'static str) {
+        let value = if self.focused == Some(index) {
+            "0"
+        } else {
+            "-1"
+        };
+        ("tabindex", value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 31 cells, 7 wide — the shape of a calendar month with a ragged tail.
+    fn calendar() -> GridNavigationState {
+        GridNavigationState::new(31, 7, GridFlow::Flow)
+    }
+
+    #[test]
+    fn flow_grids_continue_across_row_edges() {
+        let mut grid = calendar();
+        grid.focus_index(6);
+        assert_eq!(grid.on_key(ControlKey::ArrowRight, false), Some(7));
+        assert_eq!(grid.on_key(ControlKey::ArrowLeft, false), Some(6));
+    }
+
+    #[test]
+    fn clamp_grids_stop_at_row_edges() {
+        let mut grid = GridNavigationState::new(12, 4, GridFlow::Clamp);
+        grid.focus_index(3);
+        assert_eq!(grid.on_key(ControlKey::ArrowRight, false), None);
+        assert_eq!(grid.focused(), Some(3));
+        grid.focus_index(4);
+        assert_eq!(grid.on_key(ControlKey::ArrowLeft, false), None);
+    }
+
+    #[test]
+    fn vertical_movement_handles_ragged_last_rows() {
+        let mut grid = calendar();
+        grid.focus_index(27);
+        // Row below only reaches index 30, so the column clamps onto it.
+        assert_eq!(grid.on_key(ControlKey::ArrowDown, false), Some(30));
+        assert_eq!(grid.on_key(ControlKey::ArrowDown, false), None);
+        assert_eq!(grid.on_key(ControlKey::ArrowUp, false), Some(23));
+    }
+
+    #[test]
+    fn home_and_end_respect_the_primary_modifier() {
+        let mut grid = calendar();
+        grid.focus_index(10);
+        assert_eq!(grid.on_key(ControlKey::Home, false), Some(7));
+        assert_eq!(grid.on_key(ControlKey::End, false), Some(13));
+        assert_eq!(grid.on_key(ControlKey::Home, true), Some(0));
+        assert_eq!(grid.on_key(ControlKey::End, true), Some(30));
+    }
+
+    #[test]
+    fn shrinking_the_grid_clamps_focus() {
+        let mut grid = calendar();
+        grid.focus_index(30);
+        grid.set_item_count(28);
+        assert_eq!(grid.focused(), Some(0));
+        grid.focus_cell(2, 3);
+        assert_eq!(grid.focused_cell(), Some((2, 3)));
+    }
+
+    #[test]
+    fn tabindex_roves_with_focus() {
+        let mut grid = calendar();
+        grid.focus_index(5);
+        assert_eq!(grid.cell_tabindex(5), ("tabindex", "0"));
+        assert_eq!(grid.cell_tabindex(4), ("tabindex", "-1"));
+    }
+}
@@ -0,0 +1,325 @@
+//! Sentinel driven infinite scroll machine.
+//!
+//! Feed, list and table surfaces share the same pagination choreography: an
+//! invisible sentinel row sits below the rendered items, an intersection
+//! observer reports when it scrolls into view, and the application fetches the
+//! next page.  This machine owns everything around that fetch — throttling so
+//! a visible sentinel cannot hammer the backend, the exhausted state once the
+//! final page arrived, and explicit error/retry handling — while staying
+//! completely DOM agnostic.  Adapters forward observer callbacks into
+//! [`set_sentinel_visible`](InfiniteScrollState::set_sentinel_visible), issue
+//! the fetch whenever a change reports
+//! [`request`](InfiniteScrollChange::request), and resolve it through
+//! [`loaded`](InfiniteScrollState::loaded) or
+//! [`failed`](InfiniteScrollState::failed).
+//!
+//! Failures never retry automatically: the sentinel swaps to a retry
+//! affordance and the user (or the application) calls
+//! [`retry`](InfiniteScrollState::retry), which keeps error loops out of the
+//! backend and the decision visible in the UI.
+
+use crate::timing::{Clock, SystemClock, Timer};
+use std::time::Duration;
+
+/// Lifecycle phase communicated to sentinel renderers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InfiniteScrollPhase {
+    /// Waiting for the sentinel to become visible (or for the throttle
+    /// window to elapse while it already is).
+    Idle,
+    /// A page request is in flight.
+    Loading,
+    /// The final page arrived; the sentinel should stop rendering.
+    Exhausted,
+    /// The last request failed and a retry affordance should show.
+    Error,
+}
+
+impl InfiniteScrollPhase {
+    /// Stable string for `data-*` attributes and telemetry payloads.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Idle => "idle",
+            Self::Loading => "loading",
+            Self::Exhausted => "exhausted",
+            Self::Error => "error",
+        }
+    }
+}
+
+/// Configuration describing the pagination cadence.
+#[derive(Debug, Clone)]
+pub struct InfiniteScrollConfig {
+    /// Minimum quiet period between consecutive page requests.  Guards the
+    /// backend when short pages leave the sentinel permanently visible.
+    pub throttle: Duration,
+}
+
+impl InfiniteScrollConfig {
+    /// Defaults tuned for feed style surfaces: at most two page requests per
+    /// second even while the sentinel stays on screen.
+    pub fn enterprise_defaults() -> Self {
+        Self {
+            throttle: Duration::from_millis(500),
+        }
+    }
+}
+
+impl Default for InfiniteScrollConfig {
+    fn default() -> Self {
+        Self::enterprise_defaults()
+    }
+}
+
+/// Outcome of processing an observer event or timer tick.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct InfiniteScrollChange {
+    /// `Some((from, to))` when the phase moved.
+    pub transition: Option<(InfiniteScrollPhase, InfiniteScrollPhase)>,
+    /// `Some(page)` when the application should fetch the given page index.
+    pub request: Option<usize>,
+}
+
+/// Infinite scroll state machine parameterised over a [`Clock`].
+#[derive(Debug, Clone)]
+pub struct InfiniteScrollState<C: Clock = SystemClock> {
+    clock: C,
+    config: InfiniteScrollConfig,
+    phase: InfiniteScrollPhase,
+    sentinel_visible: bool,
+    next_page: usize,
+    throttle_timer: Timer<C>,
+}
+
+impl InfiniteScrollState<SystemClock> {
+    /// Construct the machine using the real system clock.
+    pub fn new(config: InfiniteScrollConfig) -> Self {
+        Self::with_clock(SystemClock, config)
+    }
+}
+
+impl<C: Clock> InfiniteScrollState<C> {
+    /// Construct the machine using a custom clock (handy for tests).
+    ///
+    /// Machines start [`InfiniteScrollPhase::Idle`] with page `0` pending and
+    /// the sentinel assumed off screen.
+    pub fn with_clock(clock: C, config: InfiniteScrollConfig) -> Self {
+        Self {
+            clock,
+            config,
+            phase: InfiniteScrollPhase::Idle,
+            sentinel_visible: false,
+            next_page: 0,
+            throttle_timer: Timer::new(),
+        }
+    }
+
+    /// Returns the configuration backing the machine.
+    #[inline]
+    pub fn config(&self) -> &InfiniteScrollConfig {
+        &self.config
+    }
+
+    /// Current lifecycle phase.
+    #[inline]
+    pub fn phase(&self) -> InfiniteScrollPhase {
+        self.phase
+    }
+
+    /// Index of the page the next request will ask for.
+    #[inline]
+    pub fn next_page(&self) -> usize {
+        self.next_page
+    }
+
+    /// Whether the sentinel is currently reported as visible.
+    #[inline]
+    pub fn sentinel_visible(&self) -> bool {
+        self.sentinel_visible
+    }
+
+    /// Forward an intersection observer callback for the sentinel element.
+    ///
+    /// A sentinel scrolling into view requests the next page immediately
+    /// unless the throttle window from the previous request is still open —
+    /// in that case [`poll`](Self::poll) issues the request once the window
+    /// elapses.
+    pub fn set_sentinel_visible(&mut self, visible: bool) -> InfiniteScrollChange {
+        self.sentinel_visible = visible;
+        if visible {
+            self.try_request()
+        } else {
+            InfiniteScrollChange::default()
+        }
+    }
+
+    /// Advance the throttle timer.
+    ///
+    /// Call from a coarse interval; the machine is insensitive to polling
+    /// frequency and only issues a request when the sentinel is still visible
+    /// once the throttle window closes.
+    pub fn poll(&mut self) -> InfiniteScrollChange {
+        if self.sentinel_visible {
+            self.try_request()
+        } else {
+            InfiniteScrollChange::default()
+        }
+    }
+
+    /// Resolve the in-flight request after a page arrived.
+    ///
+    /// `has_more` mirrors the backend's cursor: `false` parks the machine in
+    /// [`InfiniteScrollPhase::Exhausted`] so the sentinel stops rendering,
+    /// while `true` re-arms the throttle window before the next request.
+    pub fn loaded(&mut self, has_more: bool) -> InfiniteScrollChange {
+        if self.phase != InfiniteScrollPhase::Loading {
+            return InfiniteScrollChange::default();
+        }
+        self.next_page += 1;
+        if has_more {
+            self.throttle_timer
+                .schedule(&self.clock, self.config.throttle);
+            self.transition_to(InfiniteScrollPhase::Idle)
+        } else {
+            self.transition_to(InfiniteScrollPhase::Exhausted)
+        }
+    }
+
+    /// Resolve the in-flight request after a failure.
+    ///
+    /// The machine parks in [`InfiniteScrollPhase::Error`] until
+    /// [`retry`](Self::retry) runs — sentinel visibility alone never
+    /// re-triggers a failed page.
+    pub fn failed(&mut self) -> InfiniteScrollChange {
+        if self.phase != InfiniteScrollPhase::Loading {
+            return InfiniteScrollChange::default();
+        }
+        self.transition_to(InfiniteScrollPhase::Error)
+    }
+
+    /// Re-request the failed page, e.g. from the sentinel's retry button.
+    pub fn retry(&mut self) -> InfiniteScrollChange {
+        if self.phase != InfiniteScrollPhase::Error {
+            return InfiniteScrollChange::default();
+        }
+        let mut change = self.transition_to(InfiniteScrollPhase::Loading);
+        change.request = Some(self.next_page);
+        change
+    }
+
+    /// Restart pagination from the first page, e.g. after filters changed.
+    pub fn reset(&mut self) -> InfiniteScrollChange {
+        self.next_page = 0;
+        self.throttle_timer.cancel();
+        self.transition_to(InfiniteScrollPhase::Idle)
+    }
+
+    /// Issue a request if the phase and throttle window allow one.
+    fn try_request(&mut self) -> InfiniteScrollChange {
+        if self.phase != InfiniteScrollPhase::Idle {
+            return InfiniteScrollChange::default();
+        }
+        if self.throttle_timer.is_scheduled() && !self.throttle_timer.fire_if_due(&self.clock) {
+            return InfiniteScrollChange::default();
+        }
+        let mut change = self.transition_to(InfiniteScrollPhase::Loading);
+        change.request = Some(self.next_page);
+        change
+    }
+
+    /// Move to `next` and report the transition.
+    fn transition_to(&mut self, next: InfiniteScrollPhase) -> InfiniteScrollChange {
+        let before = self.phase;
+        self.phase = next;
+        InfiniteScrollChange {
+            transition: (before != next).then_some((before, next)),
+            request: None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::timing::MockClock;
+
+    fn machine(clock: &MockClock) -> InfiniteScrollState<MockClock> {
+        InfiniteScrollState::with_clock(clock.clone(), InfiniteScrollConfig::enterprise_defaults())
+    }
+
+    #[test]
+    fn visible_sentinel_requests_sequential_pages() {
+        let clock = MockClock::new();
+        let mut state = machine(&clock);
+
+        let change = state.set_sentinel_visible(true);
+        assert_eq!(change.request, Some(0));
+        assert_eq!(
+            change.transition,
+            Some((InfiniteScrollPhase::Idle, InfiniteScrollPhase::Loading))
+        );
+
+        state.loaded(true);
+        clock.advance(Duration::from_millis(500));
+        assert_eq!(state.poll().request, Some(1));
+    }
+
+    #[test]
+    fn throttle_window_defers_back_to_back_requests() {
+        let clock = MockClock::new();
+        let mut state = machine(&clock);
+        state.set_sentinel_visible(true);
+        state.loaded(true);
+
+        // The sentinel never left the viewport but the window is still open.
+        assert_eq!(state.poll().request, None);
+        clock.advance(Duration::from_millis(499));
+        assert_eq!(state.poll().request, None);
+        clock.advance(Duration::from_millis(1));
+        assert_eq!(state.poll().request, Some(1));
+    }
+
+    #[test]
+    fn exhausted_machines_ignore_the_sentinel() {
+        let clock = MockClock::new();
+        let mut state = machine(&clock);
+        state.set_sentinel_visible(true);
+        assert_eq!(
+            state.loaded(false).transition,
+            Some((InfiniteScrollPhase::Loading, InfiniteScrollPhase::Exhausted))
+        );
+
+        clock.advance(Duration::from_secs(5));
+        assert_eq!(state.poll().request, None);
+
+        // New filters restart pagination from the first page.
+        state.reset();
+        assert_eq!(state.poll().request, Some(0));
+    }
+
+    #[test]
+    fn failures_wait_for_an_explicit_retry() {
+        let clock = MockClock::new();
+        let mut state = machine(&clock);
+        state.set_sentinel_visible(true);
+        state.loaded(true);
+        clock.advance(Duration::from_millis(500));
+        state.poll();
+        assert_eq!(
+            state.failed().transition,
+            Some((InfiniteScrollPhase::Loading, InfiniteScrollPhase::Error))
+        );
+
+        // Visibility alone never re-triggers the failed page.
+        clock.advance(Duration::from_secs(5));
+        assert_eq!(state.poll().request, None);
+        assert_eq!(state.set_sentinel_visible(true).request, None);
+
+        let change = state.retry();
+        assert_eq!(change.request, Some(1));
+        assert_eq!(
+            change.transition,
+            Some((InfiniteScrollPhase::Error, InfiniteScrollPhase::Loading))
+        );
+    }
+}
@@ -12,7 +12,11 @@
 //! shared across each state machine.  New Joy focused primitives including
 //! [`accordion`], [`autocomplete`], [`slider`], [`snackbar`], [`stepper`] and
 //! [`toggle_button_group`] build on the same deterministic rules so Material
-//! and Joy stay aligned.
+//! and Joy stay aligned.  The [`toolbar`] machine layers responsive overflow
+//! handling on top of the shared roving focus primitives, while the opt-in
+//! [`press_feedback`] machine drives Material style ripple animations and the
+//! debounced [`filter`] machine centralizes search semantics for lists and
+//! grids.
 //!
 //! The Material layer (`rustic_ui_material`) documents how these headless states are
 //! rendered with shared theming, automation identifiers, and SSR safe markup.
@@ -25,16 +29,27 @@ pub mod accordion;
 pub mod aria;
 pub mod autocomplete;
 pub mod button;
+pub mod carousel;
 pub mod checkbox;
 pub mod chip;
+pub mod confirm;
 pub mod dialog;
+pub mod diff_viewer;
 pub mod drawer;
+pub mod filter;
+pub mod grid_navigation;
+pub mod infinite_scroll;
 pub mod interaction;
 pub mod list;
+pub mod listbox;
 pub mod menu;
 pub mod popover;
+pub mod presence;
+pub mod press_feedback;
 pub mod radio;
+pub mod rating_summary;
 pub mod select;
+pub mod session_timeout;
 pub mod slider;
 pub mod snackbar;
 pub mod stepper;
@@ -45,11 +60,18 @@ pub mod tabs;
 pub mod text_field;
 pub mod timing;
 pub mod toggle_button_group;
+pub mod toolbar;
 pub mod tooltip;
+pub mod tour;
 
 mod selection;
 mod toggle;
 
+/// Re-export of the controlled/uncontrolled marker so adapters and example
+/// crates can construct state machines such as [`select::SelectState`] without
+/// reaching into the private `selection` module.
+pub use selection::ControlStrategy;
+
 #[cfg(feature = "compat-mui")]
 #[doc = "Deprecated compatibility shim exposing the crate under the legacy `mui_headless` name.\n\
 Enable the `compat-mui` feature only while migrating to `rustic_ui_headless`.\n\
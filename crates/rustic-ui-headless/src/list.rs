@@ -54,6 +54,7 @@ pub struct ListState {
     selection_strategy: ControlStrategy,
     highlight_strategy: ControlStrategy,
     typeahead: TypeaheadBuffer,
+    expanded: Vec<usize>,
 }
 
 impl ListState {
@@ -92,6 +93,7 @@ impl ListState {
             selection_strategy,
             highlight_strategy,
             typeahead: TypeaheadBuffer::new(TYPEAHEAD_TIMEOUT),
+            expanded: Vec::new(),
         }
     }
 
@@ -274,6 +276,48 @@ impl ListState {
         }
     }
 
+    /// Returns the currently expanded section indices.
+    ///
+    /// Sections are numbered by the renderer (for example Material's grouped
+    /// list) and are independent of `item_count`, so collapsing a section does
+    /// not invalidate the flattened item indices used for selection.
+    #[inline]
+    pub fn expanded(&self) -> &[usize] {
+        &self.expanded
+    }
+
+    /// Returns whether the given section index is expanded.
+    #[inline]
+    pub fn is_expanded(&self, section: usize) -> bool {
+        self.expanded.contains(&section)
+    }
+
+    /// Synchronize the expanded sections when the parent owns the state.
+    pub fn sync_expanded(&mut self, sections: &[usize]) {
+        let mut next = sections.to_vec();
+        next.sort_unstable();
+        next.dedup();
+        self.expanded = next;
+    }
+
+    /// Toggle the expansion of the provided section index. The closure is
+    /// invoked with the resulting expanded set so analytics and controlled
+    /// hosts stay informed, mirroring [`ListState::toggle`].
+    pub fn toggle_expansion<F>(&mut self, section: usize, mut notify: F)
+    where
+        F: FnMut(&[usize]),
+    {
+        let mut next = self.expanded.clone();
+        if let Some(pos) = next.iter().position(|value| *value == section) {
+            next.remove(pos);
+        } else {
+            next.push(section);
+            next.sort_unstable();
+        }
+        notify(&next);
+        self.expanded = next;
+    }
+
     /// Handle navigation keys returning the newly highlighted index. The
     /// caller is expected to ensure the item is visible (for example by
     /// scrolling it into view).
@@ -386,6 +430,23 @@ mod tests {
         assert_eq!(state.highlighted(), Some(2));
     }
 
+    #[test]
+    fn expansion_toggles_and_survives_item_count_changes() {
+        let mut state = ListState::uncontrolled(4, &[], SelectionMode::None);
+        let mut history = Vec::new();
+        state.toggle_expansion(1, |sections| history.push(sections.to_vec()));
+        state.toggle_expansion(0, |sections| history.push(sections.to_vec()));
+        assert_eq!(state.expanded(), &[0, 1]);
+        assert!(state.is_expanded(1));
+        // Sections are renderer scoped, not item scoped, so shrinking the
+        // flattened item list leaves the expansion set untouched.
+        state.set_item_count(1);
+        assert_eq!(state.expanded(), &[0, 1]);
+        state.toggle_expansion(1, |sections| history.push(sections.to_vec()));
+        assert!(!state.is_expanded(1));
+        assert_eq!(history, vec![vec![1], vec![0, 1], vec![0]]);
+    }
+
     #[test]
     fn set_item_count_prunes_selection() {
         let mut state = ListState::uncontrolled(4, &[1, 3], SelectionMode::Multiple);
@@ -0,0 +1,295 @@
+//! Multi-select listbox machine with range and select-all semantics.
+//!
+//! [`ListState`](crate::list::ListState) covers general list surfaces where
+//! selection is an accent; transfer list panes and permission-assignment
+//! screens instead revolve around bulk selection: shift-click extends a
+//! contiguous range from an anchor, a header checkbox exposes tri-state
+//! select-all, and every mutation must be announced to screen readers.
+//! This machine owns exactly that bookkeeping.  Selection mutations follow
+//! the [`ListState`] convention of invoking a notify closure with the
+//! resulting selection so controlled hosts and analytics pipelines stay
+//! informed.
+
+use std::collections::BTreeSet;
+
+/// Tri-state summary of the selection, mirroring the `aria-checked` values
+/// of a select-all checkbox.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SelectAllState {
+    /// No items are selected.
+    None,
+    /// Some but not all items are selected.
+    Partial,
+    /// Every item is selected.
+    All,
+}
+
+impl SelectAllState {
+    /// Value for the select-all checkbox's `aria-checked` attribute.
+    pub fn aria_checked(self) -> &'static str {
+        match self {
+            SelectAllState::None => "false",
+            SelectAllState::Partial => "mixed",
+            SelectAllState::All => "true",
+        }
+    }
+}
+
+/// Headless state for multi-select listboxes.
+#[derive(Debug, Clone)]
+pub struct ListboxState {
+    item_count: usize,
+    selection: Vec<usize>,
+    /// Index the next shift-range extends from: the most recent
+    /// non-range interaction.
+    anchor: Option<usize>,
+}
+
+impl ListboxState {
+    /// Construct the machine for `item_count` items with nothing selected.
+    pub fn new(item_count: usize) -> Self {
+        Self {
+            item_count,
+            selection: Vec::new(),
+            anchor: None,
+        }
+    }
+
+    /// Returns the number of items tracked by the state.
+    #[inline]
+    pub fn item_count(&self) -> usize {
+        self.item_count
+    }
+
+    /// Returns the current selection as a sorted slice of indices.
+    #[inline]
+    pub fn selection(&self) -> &[usize] {
+        &self.selection
+    }
+
+    /// Returns whether the selection includes the provided index.
+    #[inline]
+    pub fn is_selected(&self, index: usize) -> bool {
+        self.selection.contains(&index)
+    }
+
+    /// Returns the range anchor, if an anchoring interaction happened.
+    #[inline]
+    pub fn anchor(&self) -> Option<usize> {
+        self.anchor
+    }
+
+    /// Updates the number of tracked items, pruning out-of-range entries.
+    pub fn set_item_count(&mut self, count: usize) {
+        self.item_count = count;
+        self.selection.retain(|index| *index < count);
+        self.anchor = self.anchor.filter(|index| *index < count);
+    }
+
+    /// Plain click: the selection collapses to the clicked item, which
+    /// becomes the anchor for subsequent shift-ranges.
+    pub fn select_only<F>(&mut self, index: usize, notify: F)
+    where
+        F: FnMut(&[usize]),
+    {
+        if index >= self.item_count {
+            return;
+        }
+        self.anchor = Some(index);
+        self.apply(vec![index], notify);
+    }
+
+    /// Ctrl/Cmd click: toggles the item without disturbing the rest of the
+    /// selection and re-anchors on it.
+    pub fn toggle<F>(&mut self, index: usize, notify: F)
+    where
+        F: FnMut(&[usize]),
+    {
+        if index >= self.item_count {
+            return;
+        }
+        self.anchor = Some(index);
+        let mut next = self.selection.clone();
+        if let Some(pos) = next.iter().position(|value| *value == index) {
+            next.remove(pos);
+        } else {
+            next.push(index);
+            next.sort_unstable();
+        }
+        self.apply(next, notify);
+    }
+
+    /// Shift click or shift+arrow: replaces the selection with the
+    /// contiguous range between the anchor and `index`.  Without a prior
+    /// anchor the interaction degrades to [`ListboxState::select_only`].
+    /// The anchor is deliberately left untouched so repeated shift clicks
+    /// keep pivoting around the same item, matching native listboxes.
+    pub fn select_range<F>(&mut self, index: usize, notify: F)
+    where
+        F: FnMut(&[usize]),
+    {
+        if index >= self.item_count {
+            return;
+        }
+        let Some(anchor) = self.anchor else {
+            self.select_only(index, notify);
+            return;
+        };
+        let (start, end) = if anchor <= index {
+            (anchor, index)
+        } else {
+            (index, anchor)
+        };
+        self.apply((start..=end).collect(), notify);
+    }
+
+    /// Adds the contiguous range between the anchor and `index` to the
+    /// existing selection (ctrl+shift click) instead of replacing it.
+    pub fn extend_range<F>(&mut self, index: usize, notify: F)
+    where
+        F: FnMut(&[usize]),
+    {
+        if index >= self.item_count {
+            return;
+        }
+        let Some(anchor) = self.anchor else {
+            self.select_only(index, notify);
+            return;
+        };
+        let (start, end) = if anchor <= index {
+            (anchor, index)
+        } else {
+            (index, anchor)
+        };
+        let mut set: BTreeSet<usize> = self.selection.iter().copied().collect();
+        set.extend(start..=end);
+        self.apply(set.into_iter().collect(), notify);
+    }
+
+    /// Selects every item.
+    pub fn select_all<F>(&mut self, notify: F)
+    where
+        F: FnMut(&[usize]),
+    {
+        self.apply((0..self.item_count).collect(), notify);
+    }
+
+    /// Clears the entire selection.
+    pub fn clear<F>(&mut self, notify: F)
+    where
+        F: FnMut(&[usize]),
+    {
+        self.apply(Vec::new(), notify);
+    }
+
+    /// Header checkbox semantics: a fully selected listbox clears,
+    /// anything else (empty or partial) selects all.
+    pub fn toggle_select_all<F>(&mut self, notify: F)
+    where
+        F: FnMut(&[usize]),
+    {
+        match self.select_all_state() {
+            SelectAllState::All => self.clear(notify),
+            SelectAllState::None | SelectAllState::Partial => self.select_all(notify),
+        }
+    }
+
+    /// Tri-state summary for the select-all checkbox.
+    pub fn select_all_state(&self) -> SelectAllState {
+        if self.selection.is_empty() {
+            SelectAllState::None
+        } else if self.selection.len() == self.item_count {
+            SelectAllState::All
+        } else {
+            SelectAllState::Partial
+        }
+    }
+
+    /// Screen reader announcement describing the current selection, meant
+    /// for a polite live region so bulk mutations (select-all, ranges) are
+    /// voiced as one summary instead of per-item chatter.
+    pub fn announcement(&self) -> String {
+        match self.select_all_state() {
+            SelectAllState::None => "No items selected".to_string(),
+            SelectAllState::All => format!("All {} items selected", self.item_count),
+            SelectAllState::Partial => format!(
+                "{} of {} items selected",
+                self.selection.len(),
+                self.item_count
+            ),
+        }
+    }
+
+    fn apply<F>(&mut self, next: Vec<usize>, mut notify: F)
+    where
+        F: FnMut(&[usize]),
+    {
+        notify(&next);
+        self.selection = next;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn shift_range_pivots_around_the_anchor() {
+        let mut state = ListboxState::new(10);
+        state.select_only(4, |_| {});
+        state.select_range(7, |_| {});
+        assert_eq!(state.selection(), &[4, 5, 6, 7]);
+        // A second shift click replaces the range but keeps the pivot.
+        state.select_range(2, |_| {});
+        assert_eq!(state.selection(), &[2, 3, 4]);
+        assert_eq!(state.anchor(), Some(4));
+    }
+
+    #[test]
+    fn extend_range_unions_with_the_existing_selection() {
+        let mut state = ListboxState::new(10);
+        state.toggle(0, |_| {});
+        state.toggle(8, |_| {});
+        state.extend_range(6, |_| {});
+        assert_eq!(state.selection(), &[0, 6, 7, 8]);
+    }
+
+    #[test]
+    fn select_all_checkbox_walks_the_tri_state() {
+        let mut state = ListboxState::new(3);
+        assert_eq!(state.select_all_state().aria_checked(), "false");
+        state.toggle(1, |_| {});
+        assert_eq!(state.select_all_state(), SelectAllState::Partial);
+        assert_eq!(state.select_all_state().aria_checked(), "mixed");
+        // Partial selections promote to all before clearing.
+        state.toggle_select_all(|_| {});
+        assert_eq!(state.select_all_state(), SelectAllState::All);
+        state.toggle_select_all(|_| {});
+        assert_eq!(state.select_all_state(), SelectAllState::None);
+    }
+
+    #[test]
+    fn announcements_summarize_bulk_mutations() {
+        let mut state = ListboxState::new(12);
+        assert_eq!(state.announcement(), "No items selected");
+        state.select_only(0, |_| {});
+        state.select_range(2, |_| {});
+        assert_eq!(state.announcement(), "3 of 12 items selected");
+        state.select_all(|_| {});
+        assert_eq!(state.announcement(), "All 12 items selected");
+    }
+
+    #[test]
+    fn shrinking_the_item_count_prunes_selection_and_anchor() {
+        let mut state = ListboxState::new(6);
+        state.select_only(5, |_| {});
+        state.extend_range(3, |_| {});
+        state.set_item_count(4);
+        assert_eq!(state.selection(), &[3]);
+        assert_eq!(state.anchor(), None);
+        // With the anchor gone the next range interaction re-anchors.
+        state.select_range(1, |_| {});
+        assert_eq!(state.selection(), &[1]);
+        assert_eq!(state.anchor(), Some(1));
+    }
+}
@@ -0,0 +1,287 @@
+//! Presence status machine with staleness driven decay.
+//!
+//! Collaboration surfaces show a user's availability on avatars and rosters:
+//! online, away, busy or offline.  The information usually arrives as
+//! heartbeats from a realtime channel, and the tricky part is decaying the
+//! status once those heartbeats stop — a user whose tab went to sleep should
+//! drift from online to away to offline without any server round trip.  This
+//! machine owns that decay through the shared [`Timer`] primitives so the
+//! thresholds stay deterministic in tests, while a manual busy overlay models
+//! do-not-disturb without fighting the staleness timers.
+//!
+//! Renderers such as the Material and Joy `status_badge` components consume
+//! [`PresenceState::status`] and re-render on the transitions reported by
+//! [`PresenceChange`].
+
+use crate::timing::{Clock, SystemClock, Timer};
+use std::time::Duration;
+
+/// Availability communicated to rosters and avatar badges.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PresenceStatus {
+    /// Heartbeats are fresh and the user is reachable.
+    Online,
+    /// No activity within the away threshold.
+    Away,
+    /// The user enabled do-not-disturb while still connected.
+    Busy,
+    /// Heartbeats stopped entirely (or never arrived).
+    Offline,
+}
+
+impl PresenceStatus {
+    /// Stable string for `data-*` attributes and telemetry payloads.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Online => "online",
+            Self::Away => "away",
+            Self::Busy => "busy",
+            Self::Offline => "offline",
+        }
+    }
+}
+
+impl Default for PresenceStatus {
+    /// Users are offline until their first heartbeat arrives.
+    fn default() -> Self {
+        Self::Offline
+    }
+}
+
+/// Configuration describing the staleness thresholds.
+#[derive(Debug, Clone)]
+pub struct PresenceConfig {
+    /// Quiet period after the last heartbeat before an online user turns away.
+    pub away_after: Duration,
+    /// Additional quiet period after turning away before the user turns
+    /// offline.
+    pub offline_after: Duration,
+}
+
+impl PresenceConfig {
+    /// Defaults matching common roster behaviour: away after five minutes of
+    /// silence, offline ten minutes later.
+    pub fn enterprise_defaults() -> Self {
+        Self {
+            away_after: Duration::from_secs(300),
+            offline_after: Duration::from_secs(600),
+        }
+    }
+}
+
+impl Default for PresenceConfig {
+    fn default() -> Self {
+        Self::enterprise_defaults()
+    }
+}
+
+/// Outcome of processing an event or timer tick.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct PresenceChange {
+    /// `Some((from, to))` when the visible status moved.
+    pub transition: Option<(PresenceStatus, PresenceStatus)>,
+}
+
+/// Presence state machine parameterised over a [`Clock`].
+#[derive(Debug, Clone)]
+pub struct PresenceState<C: Clock = SystemClock> {
+    clock: C,
+    config: PresenceConfig,
+    /// Connectivity derived purely from heartbeats and staleness timers.
+    connectivity: PresenceStatus,
+    busy: bool,
+    away_timer: Timer<C>,
+    offline_timer: Timer<C>,
+}
+
+impl PresenceState<SystemClock> {
+    /// Construct the machine using the real system clock.
+    pub fn new(config: PresenceConfig) -> Self {
+        Self::with_clock(SystemClock, config)
+    }
+}
+
+impl<C: Clock> PresenceState<C> {
+    /// Construct the machine using a custom clock (handy for tests).
+    ///
+    /// Users start [`PresenceStatus::Offline`] until the first heartbeat.
+    pub fn with_clock(clock: C, config: PresenceConfig) -> Self {
+        Self {
+            clock,
+            config,
+            connectivity: PresenceStatus::Offline,
+            busy: false,
+            away_timer: Timer::new(),
+            offline_timer: Timer::new(),
+        }
+    }
+
+    /// Returns the configuration backing the machine.
+    #[inline]
+    pub fn config(&self) -> &PresenceConfig {
+        &self.config
+    }
+
+    /// Currently visible status: the busy overlay wins while the user is
+    /// reachable, otherwise the staleness derived connectivity shows.
+    pub fn status(&self) -> PresenceStatus {
+        if self.busy && self.connectivity != PresenceStatus::Offline {
+            PresenceStatus::Busy
+        } else {
+            self.connectivity
+        }
+    }
+
+    /// Whether the user enabled the do-not-disturb overlay.
+    #[inline]
+    pub fn is_busy(&self) -> bool {
+        self.busy
+    }
+
+    /// Record activity from the realtime channel, restarting the decay.
+    pub fn heartbeat(&mut self) -> PresenceChange {
+        self.with_transition(|state| {
+            state.connectivity = PresenceStatus::Online;
+            state
+                .away_timer
+                .schedule(&state.clock, state.config.away_after);
+            state.offline_timer.cancel();
+        })
+    }
+
+    /// Toggle the do-not-disturb overlay.
+    ///
+    /// Busy only masks the visible status; the staleness timers keep running
+    /// so an abandoned busy session still decays to offline.
+    pub fn set_busy(&mut self, busy: bool) -> PresenceChange {
+        self.with_transition(|state| state.busy = busy)
+    }
+
+    /// Mark the user offline immediately, e.g. on an explicit sign-out or a
+    /// dropped connection.
+    pub fn disconnect(&mut self) -> PresenceChange {
+        self.with_transition(|state| {
+            state.connectivity = PresenceStatus::Offline;
+            state.away_timer.cancel();
+            state.offline_timer.cancel();
+        })
+    }
+
+    /// Advance the staleness timers.
+    ///
+    /// Call from a coarse interval; the machine is insensitive to polling
+    /// frequency and reports at most one transition per call.
+    pub fn poll(&mut self) -> PresenceChange {
+        self.with_transition(|state| {
+            if state.away_timer.fire_if_due(&state.clock) {
+                state.connectivity = PresenceStatus::Away;
+                state
+                    .offline_timer
+                    .schedule(&state.clock, state.config.offline_after);
+            } else if state.offline_timer.fire_if_due(&state.clock) {
+                state.connectivity = PresenceStatus::Offline;
+            }
+        })
+    }
+
+    /// Run `mutate` and report the visible status transition it caused.
+    fn with_transition(&mut self, mutate: impl FnOnce(&mut Self)) -> PresenceChange {
+        let before = self.status();
+        mutate(self);
+        let after = self.status();
+        PresenceChange {
+            transition: (before != after).then_some((before, after)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::timing::MockClock;
+
+    fn machine(clock: &MockClock) -> PresenceState<MockClock> {
+        PresenceState::with_clock(clock.clone(), PresenceConfig::enterprise_defaults())
+    }
+
+    #[test]
+    fn heartbeats_decay_through_away_to_offline() {
+        let clock = MockClock::new();
+        let mut state = machine(&clock);
+        assert_eq!(state.status(), PresenceStatus::Offline);
+
+        let change = state.heartbeat();
+        assert_eq!(
+            change.transition,
+            Some((PresenceStatus::Offline, PresenceStatus::Online))
+        );
+
+        clock.advance(Duration::from_secs(299));
+        assert_eq!(state.poll().transition, None);
+
+        clock.advance(Duration::from_secs(1));
+        assert_eq!(
+            state.poll().transition,
+            Some((PresenceStatus::Online, PresenceStatus::Away))
+        );
+
+        clock.advance(Duration::from_secs(600));
+        assert_eq!(
+            state.poll().transition,
+            Some((PresenceStatus::Away, PresenceStatus::Offline))
+        );
+    }
+
+    #[test]
+    fn fresh_heartbeats_keep_the_user_online() {
+        let clock = MockClock::new();
+        let mut state = machine(&clock);
+        state.heartbeat();
+        for _ in 0..5 {
+            clock.advance(Duration::from_secs(200));
+            assert_eq!(state.poll().transition, None);
+            state.heartbeat();
+        }
+        assert_eq!(state.status(), PresenceStatus::Online);
+    }
+
+    #[test]
+    fn busy_overlays_connectivity_but_not_offline() {
+        let clock = MockClock::new();
+        let mut state = machine(&clock);
+        state.heartbeat();
+        let change = state.set_busy(true);
+        assert_eq!(
+            change.transition,
+            Some((PresenceStatus::Online, PresenceStatus::Busy))
+        );
+
+        // The decay continues underneath the overlay.
+        clock.advance(Duration::from_secs(300));
+        assert_eq!(state.poll().transition, None, "busy masks away");
+        clock.advance(Duration::from_secs(600));
+        assert_eq!(
+            state.poll().transition,
+            Some((PresenceStatus::Busy, PresenceStatus::Offline))
+        );
+
+        // Reconnecting while still busy resumes the overlay.
+        assert_eq!(
+            state.heartbeat().transition,
+            Some((PresenceStatus::Offline, PresenceStatus::Busy))
+        );
+    }
+
+    #[test]
+    fn disconnect_is_immediate() {
+        let clock = MockClock::new();
+        let mut state = machine(&clock);
+        state.heartbeat();
+        assert_eq!(
+            state.disconnect().transition,
+            Some((PresenceStatus::Online, PresenceStatus::Offline))
+        );
+        clock.advance(Duration::from_secs(3_600));
+        assert_eq!(state.poll().transition, None);
+    }
+}
@@ -0,0 +1,304 @@
+//! Press feedback (ripple) lifecycle machine.
+//!
+//! Material surfaces acknowledge presses with an expanding ripple anchored at
+//! the pointer position.  The animation itself is plain CSS; what needs
+//! centralised state is the lifecycle — where the press originated, how far
+//! the ripple must grow to cover the surface, and when a released ripple may
+//! be removed from the DOM.  This machine tracks exactly that, parameterised
+//! over a [`Clock`] so the expand/fade timings stay deterministic in tests.
+//!
+//! The machine never touches the DOM: renderers (see
+//! `rustic_ui_material::ripple`) translate the active [`Ripple`] entries into
+//! spans carrying CSS variables, and adapters drive [`PressFeedbackState::poll`]
+//! from their animation-frame or timer primitives to garbage collect finished
+//! ripples.
+
+use crate::timing::{Clock, SystemClock, Timer};
+use std::time::Duration;
+
+/// Configuration describing the ripple animation timings.
+#[derive(Debug, Clone)]
+pub struct PressFeedbackConfig {
+    /// Time the ripple takes to expand to its full radius.
+    pub expand_duration: Duration,
+    /// Time a released ripple takes to fade out before removal.
+    pub fade_duration: Duration,
+}
+
+impl PressFeedbackConfig {
+    /// Defaults mirroring the Material ripple timings.
+    pub fn enterprise_defaults() -> Self {
+        Self {
+            expand_duration: Duration::from_millis(450),
+            fade_duration: Duration::from_millis(300),
+        }
+    }
+}
+
+impl Default for PressFeedbackConfig {
+    fn default() -> Self {
+        Self::enterprise_defaults()
+    }
+}
+
+/// Animation phase of a single ripple.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RipplePhase {
+    /// Growing from the press origin towards the full radius.
+    Expanding,
+    /// Released and fading out prior to removal.
+    Fading,
+}
+
+impl RipplePhase {
+    /// Stable string for `data-*` automation attributes.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Expanding => "expanding",
+            Self::Fading => "fading",
+        }
+    }
+}
+
+/// One active ripple as exposed to renderers.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Ripple {
+    id: u64,
+    origin_x: f64,
+    origin_y: f64,
+    radius: f64,
+    phase: RipplePhase,
+}
+
+impl Ripple {
+    /// Identifier handed back by [`PressFeedbackState::press`].
+    #[inline]
+    pub fn id(&self) -> u64 {
+        self.id
+    }
+
+    /// Press origin relative to the surface's top-left corner, in pixels.
+    #[inline]
+    pub fn origin(&self) -> (f64, f64) {
+        (self.origin_x, self.origin_y)
+    }
+
+    /// Radius the ripple grows to — the distance from the origin to the
+    /// farthest corner, so the surface is fully covered.
+    #[inline]
+    pub fn radius(&self) -> f64 {
+        self.radius
+    }
+
+    /// Current animation phase.
+    #[inline]
+    pub fn phase(&self) -> RipplePhase {
+        self.phase
+    }
+}
+
+#[derive(Debug, Clone)]
+struct RippleEntry<C: Clock> {
+    ripple: Ripple,
+    timer: Timer<C>,
+    released: bool,
+}
+
+/// Ripple lifecycle machine parameterised over a [`Clock`].
+#[derive(Debug, Clone)]
+pub struct PressFeedbackState<C: Clock = SystemClock> {
+    clock: C,
+    config: PressFeedbackConfig,
+    next_id: u64,
+    entries: Vec<RippleEntry<C>>,
+}
+
+impl PressFeedbackState<SystemClock> {
+    /// Construct the machine using the real system clock.
+    pub fn new(config: PressFeedbackConfig) -> Self {
+        Self::with_clock(SystemClock, config)
+    }
+}
+
+impl<C: Clock> PressFeedbackState<C> {
+    /// Construct the machine using a custom clock (handy for tests).
+    pub fn with_clock(clock: C, config: PressFeedbackConfig) -> Self {
+        Self {
+            clock,
+            config,
+            next_id: 0,
+            entries: Vec::new(),
+        }
+    }
+
+    /// Returns the configuration backing the machine.
+    #[inline]
+    pub fn config(&self) -> &PressFeedbackConfig {
+        &self.config
+    }
+
+    /// Active ripples in press order, ready for rendering.
+    pub fn ripples(&self) -> impl Iterator<Item = &Ripple> {
+        self.entries.iter().map(|entry| &entry.ripple)
+    }
+
+    /// Returns whether no ripple is currently animating.
+    #[inline]
+    pub fn is_idle(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Record a press at `(origin_x, origin_y)` on a surface measuring
+    /// `width` × `height` pixels and start the expansion.  Returns the ripple
+    /// id which adapters pass back into [`PressFeedbackState::release`].
+    pub fn press(&mut self, origin_x: f64, origin_y: f64, width: f64, height: f64) -> u64 {
+        let id = self.next_id;
+        self.next_id += 1;
+        let span_x = origin_x.max(width - origin_x);
+        let span_y = origin_y.max(height - origin_y);
+        let radius = (span_x * span_x + span_y * span_y).sqrt();
+        let mut timer = Timer::new();
+        timer.schedule(&self.clock, self.config.expand_duration);
+        self.entries.push(RippleEntry {
+            ripple: Ripple {
+                id,
+                origin_x,
+                origin_y,
+                radius,
+                phase: RipplePhase::Expanding,
+            },
+            timer,
+            released: false,
+        });
+        id
+    }
+
+    /// Record the pointer release for a ripple.  Expansion always completes;
+    /// the fade begins once both the expansion finished and the press ended.
+    pub fn release(&mut self, id: u64) {
+        if let Some(entry) = self.entries.iter_mut().find(|entry| entry.ripple.id == id) {
+            entry.released = true;
+        }
+    }
+
+    /// Release every held ripple, e.g. when the pointer leaves the surface.
+    pub fn release_all(&mut self) {
+        for entry in &mut self.entries {
+            entry.released = true;
+        }
+    }
+
+    /// Advance timer driven transitions and drop finished ripples.
+    ///
+    /// Returns the ids removed this tick so adapters can trim any bookkeeping
+    /// of their own.  Call this from an animation-frame loop or a coarse
+    /// timer; the machine is insensitive to polling frequency.
+    pub fn poll(&mut self) -> Vec<u64> {
+        let clock = self.clock.clone();
+        let fade = self.config.fade_duration;
+        let mut completed = Vec::new();
+        for entry in &mut self.entries {
+            if entry.ripple.phase == RipplePhase::Expanding
+                && entry.released
+                && entry.timer.fire_if_due(&clock)
+            {
+                entry.ripple.phase = RipplePhase::Fading;
+                entry.timer.schedule(&clock, fade);
+            }
+        }
+        self.entries.retain(|entry| {
+            let finished =
+                entry.ripple.phase == RipplePhase::Fading && entry.timer.should_fire(&clock);
+            if finished {
+                completed.push(entry.ripple.id);
+            }
+            !finished
+        });
+        completed
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::timing::MockClock;
+
+    fn machine(clock: &MockClock) -> PressFeedbackState<MockClock> {
+        PressFeedbackState::with_clock(clock.clone(), PressFeedbackConfig::enterprise_defaults())
+    }
+
+    #[test]
+    fn press_computes_the_covering_radius() {
+        let clock = MockClock::new();
+        let mut state = machine(&clock);
+        let id = state.press(10.0, 10.0, 100.0, 40.0);
+        let ripple = state.ripples().next().expect("ripple recorded");
+        assert_eq!(ripple.id(), id);
+        assert_eq!(ripple.origin(), (10.0, 10.0));
+        // Farthest corner is (100, 40): sqrt(90^2 + 30^2).
+        assert!((ripple.radius() - (90.0f64 * 90.0 + 30.0 * 30.0).sqrt()).abs() < 1e-9);
+        assert_eq!(ripple.phase(), RipplePhase::Expanding);
+    }
+
+    #[test]
+    fn ripple_fades_after_expansion_and_release() {
+        let clock = MockClock::new();
+        let mut state = machine(&clock);
+        let id = state.press(5.0, 5.0, 10.0, 10.0);
+        state.release(id);
+
+        clock.advance(Duration::from_millis(449));
+        assert!(state.poll().is_empty());
+        assert_eq!(
+            state.ripples().next().unwrap().phase(),
+            RipplePhase::Expanding
+        );
+
+        clock.advance(Duration::from_millis(1));
+        assert!(state.poll().is_empty());
+        assert_eq!(state.ripples().next().unwrap().phase(), RipplePhase::Fading);
+
+        clock.advance(Duration::from_millis(300));
+        assert_eq!(state.poll(), vec![id]);
+        assert!(state.is_idle());
+    }
+
+    #[test]
+    fn held_presses_keep_the_ripple_visible() {
+        let clock = MockClock::new();
+        let mut state = machine(&clock);
+        let id = state.press(0.0, 0.0, 10.0, 10.0);
+
+        clock.advance(Duration::from_millis(1_000));
+        assert!(state.poll().is_empty(), "held ripple must not fade");
+        assert_eq!(
+            state.ripples().next().unwrap().phase(),
+            RipplePhase::Expanding
+        );
+
+        state.release(id);
+        assert!(state.poll().is_empty());
+        assert_eq!(state.ripples().next().unwrap().phase(), RipplePhase::Fading);
+    }
+
+    #[test]
+    fn overlapping_presses_animate_independently() {
+        let clock = MockClock::new();
+        let mut state = machine(&clock);
+        let first = state.press(1.0, 1.0, 10.0, 10.0);
+        clock.advance(Duration::from_millis(200));
+        let second = state.press(9.0, 9.0, 10.0, 10.0);
+        state.release_all();
+
+        clock.advance(Duration::from_millis(250));
+        assert!(state.poll().is_empty());
+        let phases: Vec<_> = state.ripples().map(Ripple::phase).collect();
+        assert_eq!(phases, vec![RipplePhase::Fading, RipplePhase::Expanding]);
+
+        clock.advance(Duration::from_millis(300));
+        assert_eq!(state.poll(), vec![first]);
+        clock.advance(Duration::from_millis(300));
+        assert_eq!(state.poll(), vec![second]);
+        assert!(state.is_idle());
+    }
+}
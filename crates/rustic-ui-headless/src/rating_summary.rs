@@ -0,0 +1,133 @@
+//! Read-only aggregate state for review/rating summaries.
+//!
+//! Review screens show the same aggregate everywhere: the average with one
+//! decimal, the total count, and a distribution bar per star level ("5★
+//! 60%, 4★ 20%, …").  The arithmetic is easy to get subtly wrong — empty
+//! data dividing by zero, percentages computed against the wrong total,
+//! floating point noise leaking into display strings — so this helper owns
+//! it once and renderers (such as the Material `rating_summary` component)
+//! just lay the numbers out.
+
+/// One distribution bar of the summary.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RatingBar {
+    /// Star level the bar describes (1 through the configured maximum).
+    pub stars: u8,
+    /// Raw number of reviews at this level.
+    pub count: u64,
+    /// Share of all reviews at this level, rounded to whole percent.
+    pub percent: u8,
+}
+
+/// Aggregate computed from raw per-star counts.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RatingSummary {
+    counts: Vec<u64>,
+}
+
+impl RatingSummary {
+    /// Build the summary from raw counts where `counts[0]` is the number of
+    /// one-star reviews, `counts[1]` two stars, and so on.  Five entries give
+    /// the conventional five-star scale but any length works.
+    pub fn from_counts(counts: &[u64]) -> Self {
+        Self {
+            counts: counts.to_vec(),
+        }
+    }
+
+    /// Total number of reviews across all levels.
+    pub fn total(&self) -> u64 {
+        self.counts.iter().sum()
+    }
+
+    /// Mean rating, `0.0` when no reviews exist.
+    pub fn average(&self) -> f64 {
+        let total = self.total();
+        if total == 0 {
+            return 0.0;
+        }
+        let weighted: u64 = self
+            .counts
+            .iter()
+            .enumerate()
+            .map(|(index, count)| (index as u64 + 1) * count)
+            .sum();
+        weighted as f64 / total as f64
+    }
+
+    /// Average formatted with the single decimal review screens expect.
+    pub fn format_average(&self) -> String {
+        format!("{:.1}", self.average())
+    }
+
+    /// Distribution bars ordered highest star level first, matching how
+    /// review summaries conventionally stack.
+    pub fn bars(&self) -> Vec<RatingBar> {
+        let total = self.total();
+        self.counts
+            .iter()
+            .enumerate()
+            .rev()
+            .map(|(index, &count)| RatingBar {
+                stars: index as u8 + 1,
+                count,
+                percent: if total == 0 {
+                    0
+                } else {
+                    ((count as f64 / total as f64) * 100.0).round() as u8
+                },
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bars_report_shares_highest_stars_first() {
+        let summary = RatingSummary::from_counts(&[5, 0, 15, 20, 60]);
+        let bars = summary.bars();
+        assert_eq!(bars.len(), 5);
+        assert_eq!(
+            bars[0],
+            RatingBar {
+                stars: 5,
+                count: 60,
+                percent: 60
+            }
+        );
+        assert_eq!(
+            bars[1],
+            RatingBar {
+                stars: 4,
+                count: 20,
+                percent: 20
+            }
+        );
+        assert_eq!(
+            bars[4],
+            RatingBar {
+                stars: 1,
+                count: 5,
+                percent: 5
+            }
+        );
+    }
+
+    #[test]
+    fn average_formats_with_one_decimal() {
+        let summary = RatingSummary::from_counts(&[5, 0, 15, 20, 60]);
+        assert_eq!(summary.total(), 100);
+        assert_eq!(summary.format_average(), "4.3");
+    }
+
+    #[test]
+    fn empty_data_stays_well_defined() {
+        let summary = RatingSummary::from_counts(&[0, 0, 0, 0, 0]);
+        assert_eq!(summary.total(), 0);
+        assert_eq!(summary.format_average(), "0.0");
+        assert!(summary.bars().iter().all(|bar| bar.percent == 0));
+    }
+}
@@ -0,0 +1,311 @@
+//! Inactivity driven session timeout machine.
+//!
+//! Regulated enterprise applications must sign users out after a period of
+//! inactivity, and audits expect the same UX everywhere: activity keeps the
+//! session alive, a warning countdown offers one last chance to stay signed
+//! in, and silence expires the session.  This machine owns that lifecycle
+//! through the shared [`Timer`] primitives so the thresholds stay
+//! deterministic in tests, leaving renderers (such as the Material
+//! `session_dialog` blueprint) to mirror [`SessionTimeoutState::phase`] and
+//! the countdown reported by [`SessionTimeoutState::warning_remaining`].
+//!
+//! Background activity deliberately stops counting once the warning dialog is
+//! up: staying signed in requires the explicit [`extend`](SessionTimeoutState::extend)
+//! triggered by the dialog's confirm button, which is what compliance reviews
+//! typically require.
+
+use crate::timing::{Clock, SystemClock, Timer};
+use std::time::Duration;
+
+/// Lifecycle phase communicated to idle-logout surfaces.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SessionPhase {
+    /// Activity is recent; no UI shows.
+    Active,
+    /// The warning countdown is running and the dialog should be visible.
+    Warning,
+    /// The session expired; the application should sign the user out.
+    Expired,
+}
+
+impl SessionPhase {
+    /// Stable string for `data-*` attributes and telemetry payloads.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Active => "active",
+            Self::Warning => "warning",
+            Self::Expired => "expired",
+        }
+    }
+}
+
+/// Configuration describing the inactivity thresholds.
+#[derive(Debug, Clone)]
+pub struct SessionTimeoutConfig {
+    /// Quiet period after the last recorded activity before the warning
+    /// countdown starts.
+    pub idle_after: Duration,
+    /// Length of the warning countdown before the session expires.
+    pub warning_for: Duration,
+}
+
+impl SessionTimeoutConfig {
+    /// Defaults matching common regulated deployments: the warning appears
+    /// after thirteen minutes of silence and the session expires two minutes
+    /// later, totalling a fifteen minute idle window.
+    pub fn enterprise_defaults() -> Self {
+        Self {
+            idle_after: Duration::from_secs(780),
+            warning_for: Duration::from_secs(120),
+        }
+    }
+}
+
+impl Default for SessionTimeoutConfig {
+    fn default() -> Self {
+        Self::enterprise_defaults()
+    }
+}
+
+/// Outcome of processing an event or timer tick.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct SessionTimeoutChange {
+    /// `Some((from, to))` when the phase moved.
+    pub transition: Option<(SessionPhase, SessionPhase)>,
+}
+
+/// Session timeout state machine parameterised over a [`Clock`].
+#[derive(Debug, Clone)]
+pub struct SessionTimeoutState<C: Clock = SystemClock> {
+    clock: C,
+    config: SessionTimeoutConfig,
+    phase: SessionPhase,
+    idle_timer: Timer<C>,
+    warning_timer: Timer<C>,
+}
+
+impl SessionTimeoutState<SystemClock> {
+    /// Construct the machine using the real system clock.
+    pub fn new(config: SessionTimeoutConfig) -> Self {
+        Self::with_clock(SystemClock, config)
+    }
+}
+
+impl<C: Clock> SessionTimeoutState<C> {
+    /// Construct the machine using a custom clock (handy for tests).
+    ///
+    /// Sessions start [`SessionPhase::Active`] with the idle timer running.
+    pub fn with_clock(clock: C, config: SessionTimeoutConfig) -> Self {
+        let mut idle_timer = Timer::new();
+        idle_timer.schedule(&clock, config.idle_after);
+        Self {
+            clock,
+            config,
+            phase: SessionPhase::Active,
+            idle_timer,
+            warning_timer: Timer::new(),
+        }
+    }
+
+    /// Returns the configuration backing the machine.
+    #[inline]
+    pub fn config(&self) -> &SessionTimeoutConfig {
+        &self.config
+    }
+
+    /// Current lifecycle phase.
+    #[inline]
+    pub fn phase(&self) -> SessionPhase {
+        self.phase
+    }
+
+    /// Remaining countdown while the warning dialog is up.
+    ///
+    /// Returns `None` outside [`SessionPhase::Warning`] so renderers can use
+    /// the value both as visibility signal and countdown source.
+    pub fn warning_remaining(&self) -> Option<Duration> {
+        if self.phase == SessionPhase::Warning {
+            self.warning_timer.remaining(&self.clock)
+        } else {
+            None
+        }
+    }
+
+    /// Record user activity (pointer, key or network events).
+    ///
+    /// Only restarts the idle timer while the session is
+    /// [`SessionPhase::Active`]; once the warning is showing, staying signed
+    /// in requires the explicit [`extend`](Self::extend) so background
+    /// activity cannot silently dismiss the dialog.
+    pub fn record_activity(&mut self) -> SessionTimeoutChange {
+        self.with_transition(|state| {
+            if state.phase == SessionPhase::Active {
+                state
+                    .idle_timer
+                    .schedule(&state.clock, state.config.idle_after);
+            }
+        })
+    }
+
+    /// Explicitly continue the session from the warning dialog.
+    ///
+    /// Returns to [`SessionPhase::Active`] and restarts the idle timer; a
+    /// no-op once the session already expired — recovery from expiry goes
+    /// through [`restart`](Self::restart) after re-authentication.
+    pub fn extend(&mut self) -> SessionTimeoutChange {
+        self.with_transition(|state| {
+            if state.phase != SessionPhase::Expired {
+                state.phase = SessionPhase::Active;
+                state.warning_timer.cancel();
+                state
+                    .idle_timer
+                    .schedule(&state.clock, state.config.idle_after);
+            }
+        })
+    }
+
+    /// Expire the session immediately, e.g. the dialog's sign-out button.
+    pub fn expire_now(&mut self) -> SessionTimeoutChange {
+        self.with_transition(|state| {
+            state.phase = SessionPhase::Expired;
+            state.idle_timer.cancel();
+            state.warning_timer.cancel();
+        })
+    }
+
+    /// Start a fresh session after re-authentication.
+    pub fn restart(&mut self) -> SessionTimeoutChange {
+        self.with_transition(|state| {
+            state.phase = SessionPhase::Active;
+            state.warning_timer.cancel();
+            state
+                .idle_timer
+                .schedule(&state.clock, state.config.idle_after);
+        })
+    }
+
+    /// Advance the inactivity timers.
+    ///
+    /// Call from a coarse interval; the machine is insensitive to polling
+    /// frequency and reports at most one transition per call.
+    pub fn poll(&mut self) -> SessionTimeoutChange {
+        self.with_transition(|state| match state.phase {
+            SessionPhase::Active => {
+                if state.idle_timer.fire_if_due(&state.clock) {
+                    state.phase = SessionPhase::Warning;
+                    state
+                        .warning_timer
+                        .schedule(&state.clock, state.config.warning_for);
+                }
+            }
+            SessionPhase::Warning => {
+                if state.warning_timer.fire_if_due(&state.clock) {
+                    state.phase = SessionPhase::Expired;
+                }
+            }
+            SessionPhase::Expired => {}
+        })
+    }
+
+    /// Run `mutate` and report the phase transition it caused.
+    fn with_transition(&mut self, mutate: impl FnOnce(&mut Self)) -> SessionTimeoutChange {
+        let before = self.phase;
+        mutate(self);
+        let after = self.phase;
+        SessionTimeoutChange {
+            transition: (before != after).then_some((before, after)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::timing::MockClock;
+
+    fn machine(clock: &MockClock) -> SessionTimeoutState<MockClock> {
+        SessionTimeoutState::with_clock(clock.clone(), SessionTimeoutConfig::enterprise_defaults())
+    }
+
+    #[test]
+    fn silence_decays_through_warning_to_expiry() {
+        let clock = MockClock::new();
+        let mut state = machine(&clock);
+        assert_eq!(state.phase(), SessionPhase::Active);
+
+        clock.advance(Duration::from_secs(779));
+        assert_eq!(state.poll().transition, None);
+
+        clock.advance(Duration::from_secs(1));
+        assert_eq!(
+            state.poll().transition,
+            Some((SessionPhase::Active, SessionPhase::Warning))
+        );
+        assert_eq!(state.warning_remaining(), Some(Duration::from_secs(120)));
+
+        clock.advance(Duration::from_secs(120));
+        assert_eq!(
+            state.poll().transition,
+            Some((SessionPhase::Warning, SessionPhase::Expired))
+        );
+        assert_eq!(state.warning_remaining(), None);
+    }
+
+    #[test]
+    fn activity_keeps_the_session_alive_only_while_active() {
+        let clock = MockClock::new();
+        let mut state = machine(&clock);
+        for _ in 0..5 {
+            clock.advance(Duration::from_secs(700));
+            assert_eq!(state.poll().transition, None);
+            state.record_activity();
+        }
+
+        // Once the warning is up, background activity no longer counts.
+        clock.advance(Duration::from_secs(780));
+        state.poll();
+        assert_eq!(state.phase(), SessionPhase::Warning);
+        state.record_activity();
+        clock.advance(Duration::from_secs(120));
+        state.poll();
+        assert_eq!(state.phase(), SessionPhase::Expired);
+    }
+
+    #[test]
+    fn extend_returns_to_active_and_restarts_the_idle_window() {
+        let clock = MockClock::new();
+        let mut state = machine(&clock);
+        clock.advance(Duration::from_secs(780));
+        state.poll();
+        clock.advance(Duration::from_secs(60));
+        assert_eq!(state.warning_remaining(), Some(Duration::from_secs(60)));
+
+        assert_eq!(
+            state.extend().transition,
+            Some((SessionPhase::Warning, SessionPhase::Active))
+        );
+        clock.advance(Duration::from_secs(779));
+        assert_eq!(state.poll().transition, None);
+    }
+
+    #[test]
+    fn expiry_requires_an_explicit_restart() {
+        let clock = MockClock::new();
+        let mut state = machine(&clock);
+        assert_eq!(
+            state.expire_now().transition,
+            Some((SessionPhase::Active, SessionPhase::Expired))
+        );
+        // Neither activity nor extend revives an expired session.
+        assert_eq!(state.record_activity().transition, None);
+        assert_eq!(state.extend().transition, None);
+
+        assert_eq!(
+            state.restart().transition,
+            Some((SessionPhase::Expired, SessionPhase::Active))
+        );
+        clock.advance(Duration::from_secs(780));
+        state.poll();
+        assert_eq!(state.phase(), SessionPhase::Warning);
+    }
+}
@@ -115,6 +115,24 @@ impl SliderState {
         ((self.value - self.config.min) / denom).clamp(0.0, 1.0) * 100.0
     }
 
+    /// Returns the configured minimum logical value.
+    #[inline]
+    pub fn min(&self) -> f64 {
+        self.config.min
+    }
+
+    /// Returns the configured maximum logical value.
+    #[inline]
+    pub fn max(&self) -> f64 {
+        self.config.max
+    }
+
+    /// Returns the configured orientation.
+    #[inline]
+    pub fn orientation(&self) -> SliderOrientation {
+        self.config.orientation
+    }
+
     /// Returns whether the slider is currently disabled.
     #[inline]
     pub fn is_disabled(&self) -> bool {
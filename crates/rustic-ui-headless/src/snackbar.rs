@@ -124,6 +124,25 @@ impl<T: Clone, C: Clock> SnackbarState<T, C> {
         self.current.is_none() && self.queue.is_empty()
     }
 
+    /// Fraction (0.0–1.0) of the auto-hide window already elapsed for the
+    /// visible message.
+    ///
+    /// Returns `None` while no message is showing or when auto-hide is
+    /// disabled, so renderers can omit progress indicators entirely.  Paused
+    /// snackbars report the frozen progress captured by [`pause`](Self::pause).
+    pub fn auto_hide_progress(&self) -> Option<f64> {
+        self.current.as_ref()?;
+        let total = self.config.auto_hide;
+        if total.is_zero() {
+            return None;
+        }
+        let remaining = self
+            .paused_remaining
+            .or_else(|| self.timer.remaining(&self.clock))?
+            .min(total);
+        Some(1.0 - remaining.as_secs_f64() / total.as_secs_f64())
+    }
+
     /// Enqueue a new message.
     pub fn enqueue(&mut self, payload: T) -> SnackbarChange<T> {
         let message = SnackbarMessage {
@@ -246,6 +265,27 @@ mod tests {
         assert_eq!(change.shown.unwrap().payload, "second");
     }
 
+    #[test]
+    fn auto_hide_progress_tracks_the_timer() {
+        let clock = MockClock::new();
+        let mut state = SnackbarState::with_clock(
+            clock.clone(),
+            SnackbarConfig {
+                auto_hide: Duration::from_millis(100),
+                max_queue: 5,
+            },
+        );
+        assert_eq!(state.auto_hide_progress(), None);
+        state.enqueue("first");
+        clock.advance(Duration::from_millis(50));
+        let progress = state.auto_hide_progress().unwrap();
+        assert!((progress - 0.5).abs() < 0.01);
+        state.pause();
+        clock.advance(Duration::from_millis(200));
+        let frozen = state.auto_hide_progress().unwrap();
+        assert!((frozen - 0.5).abs() < 0.01);
+    }
+
     #[test]
     fn pause_and_resume_preserves_timeout() {
         let clock = MockClock::new();
@@ -0,0 +1,404 @@
+//! State machine powering headless toolbars with responsive overflow.
+//!
+//! Toolbars group related controls — AppBar action rows, data grid header
+//! actions, rich text editor chrome — behind a single tab stop with roving
+//! focus, per the WAI-ARIA toolbar pattern.  On top of the keyboard contract
+//! the machine solves the responsive problem those surfaces share: when the
+//! container shrinks, lower priority items automatically relocate into an
+//! overflow menu instead of wrapping or clipping.  Adapters feed measured
+//! widths in and render the machine's verdict; the eviction order is
+//! deterministic so SSR and hydration agree on which items are visible.
+
+use crate::aria;
+use crate::interaction::ControlKey;
+use crate::selection::wrap_index;
+
+/// Orientation of the toolbar which controls arrow key semantics.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ToolbarOrientation {
+    /// Horizontal toolbars respond to left/right keys.
+    Horizontal,
+    /// Vertical toolbars respond to up/down keys.
+    Vertical,
+}
+
+impl ToolbarOrientation {
+    /// Returns the ARIA string describing the orientation.
+    #[inline]
+    pub const fn as_aria(self) -> &'static str {
+        match self {
+            Self::Horizontal => "horizontal",
+            Self::Vertical => "vertical",
+        }
+    }
+
+    /// Returns whether a key represents forward movement for this orientation.
+    #[inline]
+    fn is_forward(self, key: ControlKey) -> bool {
+        matches!(
+            (self, key),
+            (Self::Horizontal, ControlKey::ArrowRight) | (Self::Vertical, ControlKey::ArrowDown)
+        )
+    }
+
+    /// Returns whether a key represents backward movement for this orientation.
+    #[inline]
+    fn is_backward(self, key: ControlKey) -> bool {
+        matches!(
+            (self, key),
+            (Self::Horizontal, ControlKey::ArrowLeft) | (Self::Vertical, ControlKey::ArrowUp)
+        )
+    }
+}
+
+/// Declarative description of one toolbar control.
+#[derive(Debug, Clone)]
+pub struct ToolbarItem {
+    /// Stable identifier surfaced through attribute builders and overflow
+    /// menu callbacks.
+    pub id: String,
+    /// Group tag used by adapters to render separators between clusters of
+    /// related controls.  Groups do not affect keyboard navigation — focus
+    /// roves across the whole toolbar as WAI-ARIA prescribes.
+    pub group: usize,
+    /// Relative importance.  Higher priority items stay visible longer; ties
+    /// are broken by evicting the rightmost item first so the leading
+    /// (usually primary) actions are the last to overflow.
+    pub priority: u8,
+    /// Measured width in pixels including surrounding gaps.  Adapters update
+    /// this from `ResizeObserver` measurements; SSR passes design-time
+    /// estimates for a deterministic first paint.
+    pub width: f64,
+}
+
+impl ToolbarItem {
+    /// Convenience constructor for a default-priority item.
+    pub fn new(id: impl Into<String>, width: f64) -> Self {
+        Self {
+            id: id.into(),
+            group: 0,
+            priority: 0,
+            width,
+        }
+    }
+
+    /// Assign the item to a separator-delimited group.
+    pub fn with_group(mut self, group: usize) -> Self {
+        self.group = group;
+        self
+    }
+
+    /// Override the overflow priority.
+    pub fn with_priority(mut self, priority: u8) -> Self {
+        self.priority = priority;
+        self
+    }
+}
+
+/// Layout verdict computed from the measured widths.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct ToolbarLayout {
+    /// Indices of items rendered inline, in display order.
+    pub visible: Vec<usize>,
+    /// Indices of items relocated to the overflow menu, in display order.
+    pub overflow: Vec<usize>,
+}
+
+/// Headless toolbar state machine.
+#[derive(Debug, Clone)]
+pub struct ToolbarState {
+    items: Vec<ToolbarItem>,
+    orientation: ToolbarOrientation,
+    /// Available container width; `None` until the first measurement keeps
+    /// every item visible so SSR never renders a spurious overflow trigger.
+    available_width: Option<f64>,
+    /// Width reserved for the overflow trigger once any item overflows.
+    trigger_width: f64,
+    focused: Option<usize>,
+    overflow_open: bool,
+}
+
+impl ToolbarState {
+    /// Create a toolbar machine from the declarative item list.
+    pub fn new(items: Vec<ToolbarItem>, orientation: ToolbarOrientation) -> Self {
+        let focused = if items.is_empty() { None } else { Some(0) };
+        Self {
+            items,
+            orientation,
+            available_width: None,
+            trigger_width: 0.0,
+            focused,
+            overflow_open: false,
+        }
+    }
+
+    /// Width reserved for the overflow trigger button when it is shown.
+    pub fn with_trigger_width(mut self, width: f64) -> Self {
+        self.trigger_width = width;
+        self
+    }
+
+    /// Returns the toolbar orientation.
+    #[inline]
+    pub fn orientation(&self) -> ToolbarOrientation {
+        self.orientation
+    }
+
+    /// Returns the registered items.
+    #[inline]
+    pub fn items(&self) -> &[ToolbarItem] {
+        &self.items
+    }
+
+    /// Returns the index currently owning the roving tabindex.
+    #[inline]
+    pub fn focused(&self) -> Option<usize> {
+        self.focused
+    }
+
+    /// Returns whether the overflow menu is currently open.
+    #[inline]
+    pub fn is_overflow_open(&self) -> bool {
+        self.overflow_open
+    }
+
+    /// Record the latest container measurement.  Focus is pulled back onto a
+    /// visible item when the previously focused control just overflowed so
+    /// the toolbar never strands its tab stop on a hidden element.
+    pub fn set_available_width(&mut self, width: f64) {
+        self.available_width = Some(width);
+        let layout = self.layout();
+        if layout.overflow.is_empty() {
+            self.overflow_open = false;
+        }
+        if let Some(focused) = self.focused {
+            if !layout.visible.contains(&focused) {
+                self.focused = layout.visible.last().copied();
+            }
+        }
+    }
+
+    /// Update a single item's measured width (e.g. after a label change).
+    pub fn set_item_width(&mut self, index: usize, width: f64) {
+        if let Some(item) = self.items.get_mut(index) {
+            item.width = width;
+        }
+    }
+
+    /// Compute which items stay inline and which relocate to overflow.
+    ///
+    /// Items are evicted lowest priority first (rightmost first within a
+    /// priority) until the remainder plus the overflow trigger fit.  The
+    /// verdict preserves display order on both sides so adapters can render
+    /// the two lists without re-sorting.
+    pub fn layout(&self) -> ToolbarLayout {
+        let all: Vec<usize> = (0..self.items.len()).collect();
+        let Some(available) = self.available_width else {
+            return ToolbarLayout {
+                visible: all,
+                overflow: Vec::new(),
+            };
+        };
+        let total: f64 = self.items.iter().map(|item| item.width).sum();
+        if total <= available {
+            return ToolbarLayout {
+                visible: all,
+                overflow: Vec::new(),
+            };
+        }
+
+        // Eviction order: lowest priority first, then rightmost first.
+        let mut candidates: Vec<usize> = all.clone();
+        candidates.sort_by(|a, b| {
+            self.items[*a]
+                .priority
+                .cmp(&self.items[*b].priority)
+                .then(b.cmp(a))
+        });
+
+        let budget = available - self.trigger_width;
+        let mut remaining = total;
+        let mut overflowed = vec![false; self.items.len()];
+        for index in candidates {
+            if remaining <= budget {
+                break;
+            }
+            remaining -= self.items[index].width;
+            overflowed[index] = true;
+        }
+
+        let mut layout = ToolbarLayout::default();
+        for index in all {
+            if overflowed[index] {
+                layout.overflow.push(index);
+            } else {
+                layout.visible.push(index);
+            }
+        }
+        layout
+    }
+
+    /// Imperatively focus the provided item when it is visible.
+    pub fn focus(&mut self, index: usize) {
+        if self.layout().visible.contains(&index) {
+            self.focused = Some(index);
+        }
+    }
+
+    /// Process a keyboard control key and return the item that should receive
+    /// focus, if any.  Arrow keys rove across visible items with wrap-around;
+    /// <Home>/<End> jump to the edges.  Activation keys are intentionally left
+    /// to the individual controls — the toolbar only orchestrates focus.
+    pub fn on_key(&mut self, key: ControlKey) -> Option<usize> {
+        let visible = self.layout().visible;
+        if visible.is_empty() {
+            return None;
+        }
+        let position = self
+            .focused
+            .and_then(|focused| visible.iter().position(|index| *index == focused));
+        let next_position = if self.orientation.is_forward(key) {
+            wrap_index(position, 1, visible.len())
+        } else if self.orientation.is_backward(key) {
+            wrap_index(position, -1, visible.len())
+        } else {
+            match key {
+                ControlKey::Home => Some(0),
+                ControlKey::End => Some(visible.len() - 1),
+                _ => return None,
+            }
+        };
+        self.focused = next_position.map(|position| visible[position]);
+        self.focused
+    }
+
+    /// Toggle the overflow menu, returning the new open state.  Opening is a
+    /// no-op while the layout has nothing overflowed.
+    pub fn toggle_overflow(&mut self) -> bool {
+        if self.overflow_open {
+            self.overflow_open = false;
+        } else if !self.layout().overflow.is_empty() {
+            self.overflow_open = true;
+        }
+        self.overflow_open
+    }
+
+    /// Close the overflow menu (escape key, outside click, item activation).
+    pub fn close_overflow(&mut self) {
+        self.overflow_open = false;
+    }
+
+    /// Attributes for the toolbar container element.
+    pub fn container_attributes(&self) -> Vec<(&'static str, String)> {
+        vec![
+            ("role", aria::role_toolbar().to_string()),
+            ("aria-orientation", self.orientation.as_aria().to_string()),
+        ]
+    }
+
+    /// Attributes for one toolbar item, wiring the roving tabindex and the
+    /// automation identifier.
+    pub fn item_attributes(&self, index: usize) -> Vec<(&'static str, String)> {
+        let tabindex = if self.focused == Some(index) {
+            "0"
+        } else {
+            "-1"
+        };
+        let mut attrs = vec![("tabindex", tabindex.to_string())];
+        if let Some(item) = self.items.get(index) {
+            attrs.push(("data-toolbar-item", item.id.clone()));
+        }
+        attrs
+    }
+
+    /// Attributes for the overflow trigger button.
+    pub fn overflow_trigger_attributes(&self) -> Vec<(&'static str, String)> {
+        let (haspopup_name, haspopup_value) = aria::aria_haspopup("menu");
+        let (expanded_name, expanded_value) = aria::aria_expanded(self.overflow_open);
+        vec![
+            (haspopup_name, haspopup_value.to_string()),
+            (expanded_name, expanded_value.to_string()),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn toolbar() -> ToolbarState {
+        ToolbarState::new(
+            vec![
+                ToolbarItem::new("save", 40.0).with_priority(2),
+                ToolbarItem::new("undo", 40.0).with_priority(1),
+                ToolbarItem::new("redo", 40.0).with_priority(1),
+                ToolbarItem::new("export", 40.0).with_group(1),
+                ToolbarItem::new("share", 40.0).with_group(1),
+            ],
+            ToolbarOrientation::Horizontal,
+        )
+        .with_trigger_width(20.0)
+    }
+
+    #[test]
+    fn everything_visible_before_first_measurement() {
+        let state = toolbar();
+        let layout = state.layout();
+        assert_eq!(layout.visible, vec![0, 1, 2, 3, 4]);
+        assert!(layout.overflow.is_empty());
+    }
+
+    #[test]
+    fn low_priority_rightmost_items_overflow_first() {
+        let mut state = toolbar();
+        state.set_available_width(140.0);
+        let layout = state.layout();
+        // Budget after the trigger is 120px: the three highest ranked items
+        // (save plus the leading zero-priority pair evicted last) stay inline.
+        assert_eq!(layout.visible, vec![0, 1, 2]);
+        assert_eq!(layout.overflow, vec![3, 4]);
+    }
+
+    #[test]
+    fn focus_roves_across_visible_items_and_wraps() {
+        let mut state = toolbar();
+        state.set_available_width(140.0);
+        assert_eq!(state.on_key(ControlKey::ArrowRight), Some(1));
+        assert_eq!(state.on_key(ControlKey::End), Some(2));
+        assert_eq!(state.on_key(ControlKey::ArrowRight), Some(0));
+        assert_eq!(state.on_key(ControlKey::ArrowLeft), Some(2));
+    }
+
+    #[test]
+    fn shrinking_pulls_focus_back_onto_a_visible_item() {
+        let mut state = toolbar();
+        state.set_available_width(300.0);
+        state.focus(4);
+        state.set_available_width(140.0);
+        assert_eq!(state.focused(), Some(2));
+    }
+
+    #[test]
+    fn overflow_menu_only_opens_when_populated() {
+        let mut state = toolbar();
+        state.set_available_width(300.0);
+        assert!(!state.toggle_overflow());
+        state.set_available_width(140.0);
+        assert!(state.toggle_overflow());
+        state.set_available_width(300.0);
+        assert!(!state.is_overflow_open());
+    }
+
+    #[test]
+    fn attribute_builders_expose_the_aria_contract() {
+        let mut state = toolbar();
+        state.set_available_width(140.0);
+        let container = state.container_attributes();
+        assert!(container.contains(&("role", "toolbar".to_string())));
+        assert_eq!(state.item_attributes(0)[0], ("tabindex", "0".to_string()));
+        assert_eq!(state.item_attributes(1)[0], ("tabindex", "-1".to_string()));
+        state.toggle_overflow();
+        let trigger = state.overflow_trigger_attributes();
+        assert!(trigger.contains(&("aria-expanded", "true".to_string())));
+    }
+}
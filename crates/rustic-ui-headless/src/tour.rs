@@ -0,0 +1,250 @@
+//! Onboarding tour machine sequencing anchored popovers.
+//!
+//! Product tours walk users through a fixed sequence of UI anchors, each
+//! highlighted by a floating card.  The machine owns the sequencing rules —
+//! which step is active, whether the user may go back, what happens when
+//! the last step advances — and reuses the popover's
+//! [`AnchorGeometry`](crate::popover::AnchorGeometry) so renderers position
+//! the card and spotlight from the same measurements they already report
+//! for popovers.  Completion (finished or skipped) is surfaced as an
+//! explicit [`TourCompletion`] event; persistence lives with the caller
+//! because this crate is deliberately free of platform dependencies — the
+//! Material layer forwards the event to the storage utility so a finished
+//! tour never replays.
+
+use crate::popover::AnchorGeometry;
+
+/// Lifecycle of the tour as a whole.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TourStatus {
+    /// The tour has not started yet.
+    Pending,
+    /// A step is currently presented.
+    Active,
+    /// The user dismissed the tour before the final step.
+    Skipped,
+    /// The user walked through every step.
+    Finished,
+}
+
+impl TourStatus {
+    /// String representation used by persistence keys and analytics hooks.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TourStatus::Pending => "pending",
+            TourStatus::Active => "active",
+            TourStatus::Skipped => "skipped",
+            TourStatus::Finished => "finished",
+        }
+    }
+}
+
+/// Terminal event emitted when the tour ends, carrying enough context for
+/// hosts to persist completion and feed funnels.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TourCompletion {
+    /// The user skipped out at the given step index.
+    Skipped {
+        /// Zero-based index of the step that was visible when skipping.
+        at_step: usize,
+    },
+    /// The user advanced past the final step.
+    Finished,
+}
+
+/// One step of the tour: a stable identifier plus the latest reported
+/// geometry of its anchor element.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TourStep {
+    id: String,
+    anchor: Option<AnchorGeometry>,
+}
+
+impl TourStep {
+    /// Creates a step for the element identified by `id`.  Geometry is
+    /// reported later, once the renderer has measured the anchor.
+    pub fn new(id: impl Into<String>) -> Self {
+        Self {
+            id: id.into(),
+            anchor: None,
+        }
+    }
+
+    /// Stable identifier of the anchored element.
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    /// Latest reported anchor geometry, if measured.
+    pub fn anchor(&self) -> Option<AnchorGeometry> {
+        self.anchor
+    }
+}
+
+/// State machine sequencing the tour steps.
+#[derive(Debug, Clone)]
+pub struct TourState {
+    steps: Vec<TourStep>,
+    active: usize,
+    status: TourStatus,
+}
+
+impl TourState {
+    /// Construct the machine over an ordered list of steps.
+    pub fn new(steps: Vec<TourStep>) -> Self {
+        Self {
+            steps,
+            active: 0,
+            status: TourStatus::Pending,
+        }
+    }
+
+    /// Returns the tour lifecycle status.
+    #[inline]
+    pub fn status(&self) -> TourStatus {
+        self.status
+    }
+
+    /// Returns all configured steps.
+    #[inline]
+    pub fn steps(&self) -> &[TourStep] {
+        &self.steps
+    }
+
+    /// Returns the active step while the tour is running.
+    pub fn active_step(&self) -> Option<&TourStep> {
+        matches!(self.status, TourStatus::Active).then(|| &self.steps[self.active])
+    }
+
+    /// Zero-based index of the active step while the tour is running.
+    pub fn active_index(&self) -> Option<usize> {
+        matches!(self.status, TourStatus::Active).then_some(self.active)
+    }
+
+    /// Human readable progress ("2 of 5") for the card footer and the
+    /// screen reader announcement.
+    pub fn progress(&self) -> String {
+        format!("{} of {}", self.active + 1, self.steps.len())
+    }
+
+    /// Begins the tour at the first step.  No-op for empty tours or when
+    /// the tour already completed.
+    pub fn start(&mut self) {
+        if matches!(self.status, TourStatus::Pending) && !self.steps.is_empty() {
+            self.active = 0;
+            self.status = TourStatus::Active;
+        }
+    }
+
+    /// Records the measured geometry for the step anchored at `step_id`.
+    /// Renderers call this from their layout observers; unknown ids are
+    /// ignored so stale measurements cannot corrupt the sequence.
+    pub fn set_anchor(&mut self, step_id: &str, geometry: AnchorGeometry) {
+        if let Some(step) = self.steps.iter_mut().find(|step| step.id == step_id) {
+            step.anchor = Some(geometry);
+        }
+    }
+
+    /// Advances to the next step.  Advancing past the final step finishes
+    /// the tour and returns the completion event for persistence.
+    pub fn advance(&mut self) -> Option<TourCompletion> {
+        if !matches!(self.status, TourStatus::Active) {
+            return None;
+        }
+        if self.active + 1 < self.steps.len() {
+            self.active += 1;
+            None
+        } else {
+            self.status = TourStatus::Finished;
+            Some(TourCompletion::Finished)
+        }
+    }
+
+    /// Steps back to the previous step; no-op on the first step so the
+    /// back affordance can stay rendered but inert.
+    pub fn previous(&mut self) {
+        if matches!(self.status, TourStatus::Active) && self.active > 0 {
+            self.active -= 1;
+        }
+    }
+
+    /// Dismisses the tour early, returning the completion event carrying
+    /// the step the user bailed out on.
+    pub fn skip(&mut self) -> Option<TourCompletion> {
+        if !matches!(self.status, TourStatus::Active) {
+            return None;
+        }
+        self.status = TourStatus::Skipped;
+        Some(TourCompletion::Skipped {
+            at_step: self.active,
+        })
+    }
+
+    /// Returns whether the tour reached a terminal state.
+    pub fn is_complete(&self) -> bool {
+        matches!(self.status, TourStatus::Skipped | TourStatus::Finished)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn three_steps() -> TourState {
+        TourState::new(vec![
+            TourStep::new("search"),
+            TourStep::new("filters"),
+            TourStep::new("export"),
+        ])
+    }
+
+    #[test]
+    fn advancing_past_the_last_step_finishes() {
+        let mut tour = three_steps();
+        tour.start();
+        assert_eq!(tour.advance(), None);
+        assert_eq!(tour.advance(), None);
+        assert_eq!(tour.progress(), "3 of 3");
+        assert_eq!(tour.advance(), Some(TourCompletion::Finished));
+        assert!(tour.is_complete());
+        assert!(tour.active_step().is_none());
+    }
+
+    #[test]
+    fn skip_reports_the_abandoned_step() {
+        let mut tour = three_steps();
+        tour.start();
+        tour.advance();
+        assert_eq!(tour.skip(), Some(TourCompletion::Skipped { at_step: 1 }));
+        assert_eq!(tour.status(), TourStatus::Skipped);
+        // Terminal states swallow further intents.
+        assert_eq!(tour.advance(), None);
+        assert_eq!(tour.skip(), None);
+    }
+
+    #[test]
+    fn previous_is_inert_on_the_first_step() {
+        let mut tour = three_steps();
+        tour.start();
+        tour.previous();
+        assert_eq!(tour.active_index(), Some(0));
+        tour.advance();
+        tour.previous();
+        assert_eq!(tour.active_index(), Some(0));
+    }
+
+    #[test]
+    fn anchors_attach_to_steps_by_id() {
+        let mut tour = three_steps();
+        let geometry = AnchorGeometry {
+            x: 10.0,
+            y: 20.0,
+            width: 100.0,
+            height: 40.0,
+        };
+        tour.set_anchor("filters", geometry);
+        tour.set_anchor("unknown", geometry);
+        assert_eq!(tour.steps()[1].anchor(), Some(geometry));
+        assert_eq!(tour.steps()[0].anchor(), None);
+    }
+}
@@ -0,0 +1,179 @@
+//! Key chord representation and parsing.
+//!
+//! A [`KeyChord`] is the canonical form of a shortcut: the logical key plus
+//! its modifier set.  Chords parse from the familiar `"Ctrl+Shift+P"` syntax
+//! used in configuration files and render back to it for command palette and
+//! tooltip display, so the string form round-trips.
+
+use std::fmt;
+use std::str::FromStr;
+
+/// One keyboard shortcut: a logical key plus modifiers.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct KeyChord {
+    /// Logical key as reported by `KeyboardEvent::key`, lowercased for
+    /// single characters (`"k"`, `"enter"`, `"arrowdown"`).
+    pub key: String,
+    pub ctrl: bool,
+    pub alt: bool,
+    pub shift: bool,
+    pub meta: bool,
+}
+
+impl KeyChord {
+    /// Chord with no modifiers.
+    pub fn key(key: impl Into<String>) -> Self {
+        Self {
+            key: normalize_key(&key.into()),
+            ctrl: false,
+            alt: false,
+            shift: false,
+            meta: false,
+        }
+    }
+
+    /// Builder-style modifier toggles used by programmatic registrations.
+    pub fn with_ctrl(mut self) -> Self {
+        self.ctrl = true;
+        self
+    }
+
+    pub fn with_alt(mut self) -> Self {
+        self.alt = true;
+        self
+    }
+
+    pub fn with_shift(mut self) -> Self {
+        self.shift = true;
+        self
+    }
+
+    pub fn with_meta(mut self) -> Self {
+        self.meta = true;
+        self
+    }
+
+    /// Build a chord from the fields of a DOM `KeyboardEvent`.  Framework
+    /// bindings call this in their keydown listeners before dispatching.
+    pub fn from_event(key: &str, ctrl: bool, alt: bool, shift: bool, meta: bool) -> Self {
+        Self {
+            key: normalize_key(key),
+            ctrl,
+            alt,
+            shift,
+            meta,
+        }
+    }
+}
+
+/// Lowercase single characters so `Shift+K` and `Shift+k` match; named keys
+/// (`Enter`, `ArrowDown`) are lowercased wholesale for the same reason.
+fn normalize_key(key: &str) -> String {
+    key.to_lowercase()
+}
+
+/// Error returned when a chord string cannot be parsed.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseChordError(String);
+
+impl fmt::Display for ParseChordError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid key chord `{}`", self.0)
+    }
+}
+
+impl std::error::Error for ParseChordError {}
+
+impl FromStr for KeyChord {
+    type Err = ParseChordError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let mut chord = Self::key("");
+        let mut key = None;
+        for part in input.split('+') {
+            match part.trim().to_lowercase().as_str() {
+                "ctrl" | "control" => chord.ctrl = true,
+                "alt" | "option" => chord.alt = true,
+                "shift" => chord.shift = true,
+                "meta" | "cmd" | "super" => chord.meta = true,
+                "" => return Err(ParseChordError(input.to_string())),
+                part => {
+                    if key.replace(part.to_string()).is_some() {
+                        // Two non-modifier segments (e.g. "K+J") are not a
+                        // chord.
+                        return Err(ParseChordError(input.to_string()));
+                    }
+                }
+            }
+        }
+        match key {
+            Some(key) => {
+                chord.key = key;
+                Ok(chord)
+            }
+            None => Err(ParseChordError(input.to_string())),
+        }
+    }
+}
+
+impl fmt::Display for KeyChord {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut parts: Vec<&str> = Vec::new();
+        if self.ctrl {
+            parts.push("Ctrl");
+        }
+        if self.alt {
+            parts.push("Alt");
+        }
+        if self.shift {
+            parts.push("Shift");
+        }
+        if self.meta {
+            parts.push("Meta");
+        }
+        for part in parts {
+            write!(f, "{part}+")?;
+        }
+        // Named keys render capitalized for display parity with upstream
+        // command palettes.
+        let mut chars = self.key.chars();
+        match chars.next() {
+            Some(first) => write!(f, "{}{}", first.to_uppercase(), chars.as_str()),
+            None => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_modifiers_in_any_order() {
+        let chord: KeyChord = "Shift+Ctrl+p".parse().expect("valid chord");
+        assert!(chord.ctrl && chord.shift && !chord.alt && !chord.meta);
+        assert_eq!(chord.key, "p");
+    }
+
+    #[test]
+    fn display_round_trips_through_parse() {
+        for source in ["Ctrl+Shift+P", "Meta+K", "Enter", "Alt+Arrowdown"] {
+            let chord: KeyChord = source.parse().expect("valid chord");
+            assert_eq!(chord.to_string().parse::<KeyChord>(), Ok(chord));
+        }
+    }
+
+    #[test]
+    fn rejects_chords_without_a_key_or_with_two_keys() {
+        assert!("Ctrl+Shift".parse::<KeyChord>().is_err());
+        assert!("K+J".parse::<KeyChord>().is_err());
+        assert!("".parse::<KeyChord>().is_err());
+    }
+
+    #[test]
+    fn event_construction_matches_parsed_chords() {
+        let from_event = KeyChord::from_event("P", true, false, true, false);
+        let parsed: KeyChord = "Ctrl+Shift+p".parse().expect("valid chord");
+        assert_eq!(from_event, parsed);
+    }
+}
@@ -0,0 +1,73 @@
+#![forbid(unsafe_code)]
+//! Application level keyboard shortcuts with scope stacking.
+//!
+//! * [`chord`] - [`KeyChord`] parsing (`"Ctrl+Shift+P"`) and display.
+//! * [`registry`] - [`ShortcutRegistry`] mapping chords to application
+//!   commands, shared by the command palette and help overlays.
+//! * [`manager`] - [`HotkeyManager`] stacking scopes so dialog and data
+//!   grid shortcuts compose without stealing each other's keys.
+//!
+//! The manager is a pure state machine: framework bindings attach one
+//! document level keydown listener, build a chord via
+//! [`KeyChord::from_event`] and forward whatever [`HotkeyManager::dispatch`]
+//! returns into their message loop.  That keeps every adapter's behavior
+//! identical and makes the whole system unit testable without a DOM.
+//!
+//! # Examples
+//! ```
+//! use rustic_ui_hotkeys::{HotkeyManager, KeyChord, ShortcutRegistry};
+//!
+//! #[derive(Clone, PartialEq, Debug)]
+//! enum Command {
+//!     OpenPalette,
+//! }
+//!
+//! let registry = ShortcutRegistry::new().with(
+//!     "Ctrl+K".parse().unwrap(),
+//!     Command::OpenPalette,
+//!     "Open the command palette",
+//! );
+//! let manager = HotkeyManager::new(registry);
+//! let chord = KeyChord::from_event("k", true, false, false, false);
+//! assert_eq!(manager.dispatch(&chord), Some(Command::OpenPalette));
+//! ```
+
+pub mod chord;
+pub mod manager;
+pub mod registry;
+
+pub use chord::{KeyChord, ParseChordError};
+pub use manager::{HotkeyManager, ScopeToken};
+pub use registry::{Shortcut, ShortcutRegistry};
+
+/// Binding for Yew applications.
+///
+/// Attach a `keydown` listener on `document` during mount, translate the
+/// event with [`KeyChord::from_event`] and send the dispatched command into
+/// the component's callback.  Kept behind the `yew` feature so server-only
+/// consumers avoid the extra surface.
+#[cfg(feature = "yew")]
+pub mod yew {
+    pub use crate::{HotkeyManager, KeyChord, ShortcutRegistry};
+}
+
+/// Binding for Leptos applications; mirrors the [`yew`](self::yew) module
+/// with the manager stored in a signal and `window_event_listener`.
+#[cfg(feature = "leptos")]
+pub mod leptos {
+    pub use crate::{HotkeyManager, KeyChord, ShortcutRegistry};
+}
+
+/// Binding for Dioxus applications; mirrors the [`yew`](self::yew) module
+/// with the manager held in `use_ref`.
+#[cfg(feature = "dioxus")]
+pub mod dioxus {
+    pub use crate::{HotkeyManager, KeyChord, ShortcutRegistry};
+}
+
+/// Binding for Sycamore applications; mirrors the [`yew`](self::yew)
+/// module with the manager behind a reactive signal.
+#[cfg(feature = "sycamore")]
+pub mod sycamore {
+    pub use crate::{HotkeyManager, KeyChord, ShortcutRegistry};
+}
@@ -0,0 +1,178 @@
+//! Application level hotkey manager with scope stacking.
+//!
+//! Shortcuts live in scopes so overlays compose safely: the application
+//! pushes a scope when a dialog or command palette opens and pops it on
+//! close.  Dispatch walks the stack top-down, which gives the innermost
+//! surface first refusal; a *modal* scope additionally stops the walk so a
+//! dialog's `Escape` cannot simultaneously trigger a data grid shortcut
+//! underneath it.  The global scope (the one the manager is created with)
+//! sits at the bottom of the stack.
+
+use crate::chord::KeyChord;
+use crate::registry::ShortcutRegistry;
+
+/// Token identifying a pushed scope, used to guard against unbalanced pops.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ScopeToken(usize);
+
+struct Scope<C> {
+    registry: ShortcutRegistry<C>,
+    /// Modal scopes swallow unmatched chords instead of letting them reach
+    /// scopes beneath them.
+    modal: bool,
+    token: usize,
+}
+
+/// Stack of shortcut scopes dispatching chords to application commands.
+pub struct HotkeyManager<C> {
+    scopes: Vec<Scope<C>>,
+    next_token: usize,
+}
+
+impl<C: Clone> HotkeyManager<C> {
+    /// Manager seeded with the application's global shortcuts.
+    pub fn new(global: ShortcutRegistry<C>) -> Self {
+        Self {
+            scopes: vec![Scope {
+                registry: global,
+                modal: false,
+                token: 0,
+            }],
+            next_token: 1,
+        }
+    }
+
+    /// Push a transparent scope: unmatched chords keep falling through to
+    /// the scopes (and global shortcuts) beneath it.
+    pub fn push_scope(&mut self, registry: ShortcutRegistry<C>) -> ScopeToken {
+        self.push(registry, false)
+    }
+
+    /// Push a modal scope: unmatched chords stop here, isolating everything
+    /// underneath while a dialog or menu is open.
+    pub fn push_modal_scope(&mut self, registry: ShortcutRegistry<C>) -> ScopeToken {
+        self.push(registry, true)
+    }
+
+    fn push(&mut self, registry: ShortcutRegistry<C>, modal: bool) -> ScopeToken {
+        let token = self.next_token;
+        self.next_token += 1;
+        self.scopes.push(Scope {
+            registry,
+            modal,
+            token,
+        });
+        ScopeToken(token)
+    }
+
+    /// Pop the scope identified by the token.  Popping out of order removes
+    /// the scope wherever it sits, so an overlay closed by unusual means
+    /// (router navigation, error path) cannot strand stale shortcuts.
+    pub fn pop_scope(&mut self, token: ScopeToken) {
+        self.scopes
+            .retain(|scope| scope.token == 0 || scope.token != token.0);
+    }
+
+    /// Number of scopes currently stacked, including the global scope.
+    pub fn depth(&self) -> usize {
+        self.scopes.len()
+    }
+
+    /// Resolve a chord against the scope stack, innermost scope first.
+    /// Returns the matched command or `None` when nothing (reachable)
+    /// claims the chord.
+    pub fn dispatch(&self, chord: &KeyChord) -> Option<C> {
+        for scope in self.scopes.iter().rev() {
+            if let Some(command) = scope.registry.lookup(chord) {
+                return Some(command.clone());
+            }
+            if scope.modal {
+                return None;
+            }
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Clone, Debug, PartialEq)]
+    enum Command {
+        OpenPalette,
+        CloseDialog,
+        GridNextPage,
+    }
+
+    fn manager() -> HotkeyManager<Command> {
+        HotkeyManager::new(ShortcutRegistry::new().with(
+            "Ctrl+K".parse().unwrap(),
+            Command::OpenPalette,
+            "Palette",
+        ))
+    }
+
+    #[test]
+    fn global_shortcuts_dispatch_without_scopes() {
+        let manager = manager();
+        assert_eq!(
+            manager.dispatch(&"Ctrl+K".parse().unwrap()),
+            Some(Command::OpenPalette)
+        );
+        assert_eq!(manager.dispatch(&"Escape".parse().unwrap()), None);
+    }
+
+    #[test]
+    fn transparent_scopes_fall_through_to_global() {
+        let mut manager = manager();
+        manager.push_scope(ShortcutRegistry::new().with(
+            "Arrowright".parse().unwrap(),
+            Command::GridNextPage,
+            "Next page",
+        ));
+        assert_eq!(
+            manager.dispatch(&"Arrowright".parse().unwrap()),
+            Some(Command::GridNextPage)
+        );
+        assert_eq!(
+            manager.dispatch(&"Ctrl+K".parse().unwrap()),
+            Some(Command::OpenPalette)
+        );
+    }
+
+    #[test]
+    fn modal_scopes_isolate_everything_beneath() {
+        let mut manager = manager();
+        let token = manager.push_modal_scope(ShortcutRegistry::new().with(
+            "Escape".parse().unwrap(),
+            Command::CloseDialog,
+            "Close",
+        ));
+        assert_eq!(
+            manager.dispatch(&"Escape".parse().unwrap()),
+            Some(Command::CloseDialog)
+        );
+        // The global palette shortcut is unreachable while the dialog is up.
+        assert_eq!(manager.dispatch(&"Ctrl+K".parse().unwrap()), None);
+
+        manager.pop_scope(token);
+        assert_eq!(
+            manager.dispatch(&"Ctrl+K".parse().unwrap()),
+            Some(Command::OpenPalette)
+        );
+    }
+
+    #[test]
+    fn out_of_order_pops_remove_the_right_scope() {
+        let mut manager = manager();
+        let first = manager.push_scope(ShortcutRegistry::new());
+        let second = manager.push_modal_scope(ShortcutRegistry::new());
+        manager.pop_scope(first);
+        assert_eq!(manager.depth(), 2);
+        // The modal scope is still in place and keeps blocking.
+        assert_eq!(manager.dispatch(&"Ctrl+K".parse().unwrap()), None);
+        manager.pop_scope(second);
+        assert_eq!(manager.depth(), 1);
+    }
+}
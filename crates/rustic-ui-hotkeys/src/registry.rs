@@ -0,0 +1,117 @@
+//! Shortcut registry mapping chords to application commands.
+//!
+//! The registry stays generic over the command type so applications dispatch
+//! their own enums (or message types) instead of closures.  Keeping handlers
+//! out of the registry makes it cheaply cloneable, serializable to help
+//! screens, and safe to share between the command palette and the
+//! components that surface the same shortcuts.
+
+use crate::chord::KeyChord;
+
+/// One registered shortcut.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Shortcut<C> {
+    /// Chord that triggers the command.
+    pub chord: KeyChord,
+    /// Application command dispatched on match.
+    pub command: C,
+    /// Human readable description surfaced by command palettes and help
+    /// overlays.
+    pub description: String,
+}
+
+/// Ordered collection of shortcuts for one scope.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ShortcutRegistry<C> {
+    shortcuts: Vec<Shortcut<C>>,
+}
+
+impl<C> ShortcutRegistry<C> {
+    /// Empty registry.
+    pub fn new() -> Self {
+        Self {
+            shortcuts: Vec::new(),
+        }
+    }
+
+    /// Register a shortcut.  Re-registering an existing chord replaces the
+    /// previous command so feature modules can override defaults
+    /// deterministically.
+    pub fn register(&mut self, chord: KeyChord, command: C, description: impl Into<String>) {
+        let description = description.into();
+        if let Some(existing) = self
+            .shortcuts
+            .iter_mut()
+            .find(|shortcut| shortcut.chord == chord)
+        {
+            existing.command = command;
+            existing.description = description;
+        } else {
+            self.shortcuts.push(Shortcut {
+                chord,
+                command,
+                description,
+            });
+        }
+    }
+
+    /// Builder-style [`ShortcutRegistry::register`] for declarative setup.
+    pub fn with(mut self, chord: KeyChord, command: C, description: impl Into<String>) -> Self {
+        self.register(chord, command, description);
+        self
+    }
+
+    /// Command registered for the chord, if any.
+    pub fn lookup(&self, chord: &KeyChord) -> Option<&C> {
+        self.shortcuts
+            .iter()
+            .find(|shortcut| &shortcut.chord == chord)
+            .map(|shortcut| &shortcut.command)
+    }
+
+    /// Registered shortcuts in registration order, for help screens.
+    pub fn shortcuts(&self) -> &[Shortcut<C>] {
+        &self.shortcuts
+    }
+
+    /// Whether the chord is already taken in this scope.
+    pub fn conflicts_with(&self, chord: &KeyChord) -> bool {
+        self.lookup(chord).is_some()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Clone, Debug, PartialEq)]
+    enum Command {
+        OpenPalette,
+        Save,
+    }
+
+    #[test]
+    fn lookup_returns_registered_commands() {
+        let registry = ShortcutRegistry::new()
+            .with("Ctrl+K".parse().unwrap(), Command::OpenPalette, "Palette")
+            .with("Ctrl+S".parse().unwrap(), Command::Save, "Save");
+        assert_eq!(
+            registry.lookup(&"Ctrl+K".parse().unwrap()),
+            Some(&Command::OpenPalette)
+        );
+        assert_eq!(registry.lookup(&"Ctrl+Q".parse().unwrap()), None);
+    }
+
+    #[test]
+    fn re_registering_a_chord_replaces_the_command() {
+        let mut registry =
+            ShortcutRegistry::new().with("Ctrl+S".parse().unwrap(), Command::OpenPalette, "Old");
+        registry.register("Ctrl+S".parse().unwrap(), Command::Save, "Save");
+        assert_eq!(registry.shortcuts().len(), 1);
+        assert_eq!(
+            registry.lookup(&"Ctrl+S".parse().unwrap()),
+            Some(&Command::Save)
+        );
+        assert!(registry.conflicts_with(&"Ctrl+S".parse().unwrap()));
+    }
+}
@@ -1,9 +1,19 @@
-//! Joy accordion scaffolding that wraps the shared headless state machine.
+//! Joy accordion built on the shared headless state machine.
 //!
-//! Rendering adapters can construct [`AccordionController`] and wire its
-//! [`AccordionGroupState`](rustic_ui_headless::accordion::AccordionGroupState) into
-//! component templates.  Centralising the boilerplate here keeps future Joy
-//! components focused on styling rather than state orchestration.
+//! [`AccordionController`] wraps the
+//! [`AccordionGroupState`](rustic_ui_headless::accordion::AccordionGroupState)
+//! for adapters that orchestrate their own markup, while the
+//! [`accordion_group`]/[`accordion_summary`]/[`accordion_details`] renderers
+//! emit complete Joy-styled HTML strings for every framework.  Styling flows
+//! through [`resolve_surface_tokens`](crate::helpers::resolve_surface_tokens)
+//! so variant/color pairings stay aligned with the rest of the Joy set, and
+//! expansion is mirrored into `data-expanded`/`data-state` hooks so CSS
+//! transitions and automation suites key off the same markup in SSR and
+//! hydration.
+
+use crate::helpers::{attributes_html, compose_inline_style, resolve_surface_tokens};
+use crate::{Color, Variant};
+use rustic_ui_system::theme::Theme;
 
 pub use rustic_ui_headless::accordion::{AccordionGroupState, AccordionItemChange};
 
@@ -24,3 +34,292 @@ impl AccordionController {
         }
     }
 }
+
+/// Summary label and detail markup for one accordion item.
+#[derive(Clone, Debug, PartialEq)]
+pub struct AccordionItemProps {
+    /// Label rendered inside the summary button.
+    pub summary: String,
+    /// Pre-rendered HTML hosted inside the details region.
+    pub details_html: String,
+}
+
+impl AccordionItemProps {
+    /// Convenience constructor for tests and demos.
+    pub fn new(summary: impl Into<String>, details_html: impl Into<String>) -> Self {
+        Self {
+            summary: summary.into(),
+            details_html: details_html.into(),
+        }
+    }
+}
+
+/// Shared configuration consumed by every accordion framework adapter.
+#[derive(Clone, Debug, PartialEq)]
+pub struct AccordionProps {
+    /// Items rendered in order.
+    pub items: Vec<AccordionItemProps>,
+    /// Joy palette entry colouring the summaries.
+    pub color: Color,
+    /// Joy variant applied to the summaries.
+    pub variant: Variant,
+    /// Prefix used for the deterministic summary/details element ids.
+    pub id_prefix: String,
+}
+
+impl AccordionProps {
+    /// Create accordion props with Joy's plain/neutral defaults.
+    pub fn new(items: Vec<AccordionItemProps>) -> Self {
+        Self {
+            items,
+            color: Color::Neutral,
+            variant: Variant::Plain,
+            id_prefix: "joy-accordion".into(),
+        }
+    }
+
+    /// Overrides the palette color.
+    pub fn with_color(mut self, color: Color) -> Self {
+        self.color = color;
+        self
+    }
+
+    /// Overrides the variant.
+    pub fn with_variant(mut self, variant: Variant) -> Self {
+        self.variant = variant;
+        self
+    }
+
+    /// Overrides the id prefix so multiple accordions coexist on one page.
+    pub fn with_id_prefix(mut self, prefix: impl Into<String>) -> Self {
+        self.id_prefix = prefix.into();
+        self
+    }
+
+    fn summary_id(&self, index: usize) -> String {
+        format!("{}-summary-{index}", self.id_prefix)
+    }
+
+    fn details_id(&self, index: usize) -> String {
+        format!("{}-details-{index}", self.id_prefix)
+    }
+}
+
+/// Render one summary button including the rotating expansion indicator.
+///
+/// The indicator rotation and all hover/expand transitions are driven by the
+/// theme's motion tokens; `data-expanded`/`data-state` mirror the machine so
+/// CSS and automation observe the same truth.
+pub fn accordion_summary(
+    theme: &Theme,
+    props: &AccordionProps,
+    state: &AccordionGroupState,
+    index: usize,
+) -> String {
+    let expanded = state.is_expanded(index);
+    let disabled = state.is_disabled(index);
+    let mut attrs = state.summary_accessibility_attributes(index, &props.details_id(index));
+    attrs.push(("id", props.summary_id(index)));
+    attrs.push(("data-joy-accordion-summary", index.to_string()));
+    attrs.push(("data-expanded", expanded.to_string()));
+    attrs.push((
+        "data-state",
+        if expanded { "open" } else { "closed" }.to_string(),
+    ));
+
+    let surface = resolve_surface_tokens(theme, props.color, props.variant);
+    let mut extra = vec![
+        ("display", "flex".to_string()),
+        ("align-items", "center".to_string()),
+        ("justify-content", "space-between".to_string()),
+        ("width", "100%".to_string()),
+        ("gap", format!("{}px", theme.spacing(1))),
+        (
+            "padding",
+            format!("{}px {}px", theme.spacing(1), theme.spacing(2)),
+        ),
+        ("font-family", theme.typography.font_family.clone()),
+        (
+            "transition",
+            theme.motion.transition(&["background", "color"]),
+        ),
+        ("cursor", "pointer".to_string()),
+    ];
+    if disabled {
+        extra.push(("opacity", "0.6".to_string()));
+        extra.push(("cursor", "not-allowed".to_string()));
+    }
+    let style = surface.compose(extra);
+
+    let indicator_style = compose_inline_style([
+        ("display", "inline-flex".to_string()),
+        ("transition", theme.motion.transition(&["transform"])),
+        (
+            "transform",
+            format!("rotate({}deg)", if expanded { 90 } else { 0 }),
+        ),
+    ]);
+    format!(
+        "<button {} style=\"{style}\">{}<span aria-hidden=\"true\" data-joy-accordion-indicator=\"true\" style=\"{indicator_style}\">\u{25B8}</span></button>",
+        attributes_html(&attrs),
+        props.items[index].summary
+    )
+}
+
+/// Render one details region.  Collapsed regions stay in the DOM carrying the
+/// `hidden` attribute so SSR output matches the hydrated tree exactly.
+pub fn accordion_details(
+    theme: &Theme,
+    props: &AccordionProps,
+    state: &AccordionGroupState,
+    index: usize,
+) -> String {
+    let expanded = state.is_expanded(index);
+    let mut attrs = state.details_accessibility_attributes(index, &props.summary_id(index));
+    attrs.push(("id", props.details_id(index)));
+    attrs.push(("data-joy-accordion-details", index.to_string()));
+    attrs.push(("data-expanded", expanded.to_string()));
+    attrs.push((
+        "data-state",
+        if expanded { "open" } else { "closed" }.to_string(),
+    ));
+    let style = compose_inline_style([
+        (
+            "padding",
+            format!("{}px {}px", theme.spacing(1), theme.spacing(2)),
+        ),
+        ("font-family", theme.typography.font_family.clone()),
+        ("transition", theme.motion.transition(&["opacity"])),
+        ("opacity", if expanded { "1" } else { "0" }.to_string()),
+    ]);
+    format!(
+        "<div {} style=\"{style}\">{}</div>",
+        attributes_html(&attrs),
+        props.items[index].details_html
+    )
+}
+
+/// Render the full accordion group: every summary/details pair wrapped in an
+/// item container carrying expansion and disabled hooks.
+pub fn accordion_group(
+    theme: &Theme,
+    props: &AccordionProps,
+    state: &AccordionGroupState,
+) -> String {
+    let group_style = compose_inline_style([
+        ("display", "flex".to_string()),
+        ("flex-direction", "column".to_string()),
+        ("border-radius", format!("{}px", theme.joy.radius)),
+        ("overflow", "hidden".to_string()),
+    ]);
+    let mut items_html = String::new();
+    for index in 0..props.items.len() {
+        let expanded = state.is_expanded(index);
+        items_html.push_str(&format!(
+            "<div data-joy-accordion-item=\"{index}\" data-expanded=\"{expanded}\" data-disabled=\"{}\">{}{}</div>",
+            state.is_disabled(index),
+            accordion_summary(theme, props, state, index),
+            accordion_details(theme, props, state, index),
+        ));
+    }
+    format!(
+        "<div data-joy-accordion-group=\"{}\" style=\"{group_style}\">{items_html}</div>",
+        props.id_prefix
+    )
+}
+
+/// Adapter targeting the `yew` framework.
+pub mod yew {
+    use super::*;
+
+    /// Render the accordion group into an HTML string using the shared renderer.
+    pub fn render(theme: &Theme, props: &AccordionProps, state: &AccordionGroupState) -> String {
+        super::accordion_group(theme, props, state)
+    }
+}
+
+/// Adapter targeting the `leptos` framework.
+pub mod leptos {
+    use super::*;
+
+    /// Render the accordion group into an HTML string using the shared renderer.
+    pub fn render(theme: &Theme, props: &AccordionProps, state: &AccordionGroupState) -> String {
+        super::accordion_group(theme, props, state)
+    }
+}
+
+/// Adapter targeting the `dioxus` framework.
+pub mod dioxus {
+    use super::*;
+
+    /// Render the accordion group into an HTML string using the shared renderer.
+    pub fn render(theme: &Theme, props: &AccordionProps, state: &AccordionGroupState) -> String {
+        super::accordion_group(theme, props, state)
+    }
+}
+
+/// Adapter targeting the `sycamore` framework.
+pub mod sycamore {
+    use super::*;
+
+    /// Render the accordion group into an HTML string using the shared renderer.
+    pub fn render(theme: &Theme, props: &AccordionProps, state: &AccordionGroupState) -> String {
+        super::accordion_group(theme, props, state)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fixture() -> (AccordionProps, AccordionGroupState) {
+        let props = AccordionProps::new(vec![
+            AccordionItemProps::new("Billing", "<p>Invoices</p>"),
+            AccordionItemProps::new("Security", "<p>Keys</p>"),
+        ])
+        .with_id_prefix("settings");
+        let state = AccordionGroupState::new(2, false, &[0]);
+        (props, state)
+    }
+
+    #[test]
+    fn group_renders_linked_summary_and_details_pairs() {
+        let (props, state) = fixture();
+        let html = accordion_group(&Theme::default(), &props, &state);
+        assert!(html.contains("id=\"settings-summary-0\""));
+        assert!(html.contains("aria-controls=\"settings-details-0\""));
+        assert!(html.contains("aria-labelledby=\"settings-summary-1\""));
+        assert!(html.contains("data-joy-accordion-group=\"settings\""));
+    }
+
+    #[test]
+    fn indicator_rotates_with_expansion() {
+        let (props, state) = fixture();
+        let expanded = accordion_summary(&Theme::default(), &props, &state, 0);
+        let collapsed = accordion_summary(&Theme::default(), &props, &state, 1);
+        assert!(expanded.contains("rotate(90deg)"));
+        assert!(expanded.contains("aria-expanded=\"true\""));
+        assert!(collapsed.contains("rotate(0deg)"));
+        assert!(collapsed.contains("data-state=\"closed\""));
+    }
+
+    #[test]
+    fn collapsed_details_stay_in_the_dom_but_hidden() {
+        let (props, state) = fixture();
+        let html = accordion_details(&Theme::default(), &props, &state, 1);
+        assert!(html.contains("hidden=\"true\""));
+        assert!(html.contains("<p>Keys</p>"));
+        assert!(html.contains("data-expanded=\"false\""));
+    }
+
+    #[test]
+    fn disabled_items_surface_joy_styling_hooks() {
+        let (props, mut state) = fixture();
+        state.set_disabled(1, true);
+        let html = accordion_group(&Theme::default(), &props, &state);
+        assert!(html.contains("data-disabled=\"true\""));
+        let summary = accordion_summary(&Theme::default(), &props, &state, 1);
+        assert!(summary.contains("aria-disabled=\"true\""));
+        assert!(summary.contains("cursor:not-allowed;"));
+    }
+}
@@ -1,9 +1,17 @@
-//! Joy autocomplete scaffolding built on top of the shared headless state.
+//! Joy autocomplete built on top of the shared headless state.
 //!
-//! The wrapper exposes the strongly typed configuration and change stream so
-//! renderers can focus on layout/theming.  When the visual implementation is
-//! ready we simply plug the controller into the adapter without re-threading
-//! business logic.
+//! [`AutocompleteController`] exposes the strongly typed configuration and
+//! change stream for adapters that orchestrate their own markup, while
+//! [`autocomplete`] renders the complete Joy widget — decorated input,
+//! multiple-selection chips, listbox with loading and empty slots — as a
+//! deterministic HTML string shared by all four frameworks.  ARIA wiring
+//! (`role="combobox"`, `aria-activedescendant`, option selection) comes
+//! straight from the headless machine's accessibility builders so SSR,
+//! hydration and automation always agree.
+
+use crate::helpers::{attributes_html, compose_inline_style, resolve_surface_tokens};
+use crate::{Color, Variant};
+use rustic_ui_system::theme::Theme;
 
 pub use rustic_ui_headless::autocomplete::{
     AutocompleteChange, AutocompleteConfig, AutocompleteControlStrategy, AutocompleteState,
@@ -31,3 +39,390 @@ impl AutocompleteController {
         Self::new(AutocompleteConfig::enterprise_defaults(option_count))
     }
 }
+
+/// Shared configuration consumed by every autocomplete framework adapter.
+#[derive(Clone, Debug, PartialEq)]
+pub struct AutocompleteProps {
+    /// Option labels rendered inside the listbox, in order.
+    pub options: Vec<String>,
+    /// Placeholder shown while the input is empty.
+    pub placeholder: Option<String>,
+    /// Optional pre-rendered HTML placed before the input (search icons,
+    /// avatars).
+    pub start_decorator: Option<String>,
+    /// Optional pre-rendered HTML placed after the input (spinners, clear
+    /// buttons).
+    pub end_decorator: Option<String>,
+    /// Labels rendered as dismissible chips ahead of the input when the
+    /// widget operates in multiple-selection mode.
+    pub chips: Vec<String>,
+    /// Whether the data source is still resolving; swaps the listbox contents
+    /// for the loading slot.
+    pub loading: bool,
+    /// Text shown in the loading slot.
+    pub loading_text: String,
+    /// Text shown when no options match the current input.
+    pub empty_text: String,
+    /// Joy palette entry colouring the input surface.
+    pub color: Color,
+    /// Joy variant applied to the input surface.
+    pub variant: Variant,
+    /// Prefix used for the deterministic listbox/option element ids.
+    pub id_prefix: String,
+}
+
+impl AutocompleteProps {
+    /// Create autocomplete props with Joy's outlined/neutral defaults.
+    pub fn new(options: Vec<String>) -> Self {
+        Self {
+            options,
+            placeholder: None,
+            start_decorator: None,
+            end_decorator: None,
+            chips: Vec::new(),
+            loading: false,
+            loading_text: "Loading…".into(),
+            empty_text: "No options".into(),
+            color: Color::Neutral,
+            variant: Variant::Outlined,
+            id_prefix: "joy-autocomplete".into(),
+        }
+    }
+
+    /// Sets the input placeholder.
+    pub fn with_placeholder(mut self, placeholder: impl Into<String>) -> Self {
+        self.placeholder = Some(placeholder.into());
+        self
+    }
+
+    /// Sets the leading input decorator markup.
+    pub fn with_start_decorator(mut self, html: impl Into<String>) -> Self {
+        self.start_decorator = Some(html.into());
+        self
+    }
+
+    /// Sets the trailing input decorator markup.
+    pub fn with_end_decorator(mut self, html: impl Into<String>) -> Self {
+        self.end_decorator = Some(html.into());
+        self
+    }
+
+    /// Renders the provided labels as multiple-selection chips.
+    pub fn with_chips(mut self, chips: Vec<String>) -> Self {
+        self.chips = chips;
+        self
+    }
+
+    /// Toggles the loading slot.
+    pub fn with_loading(mut self, loading: bool) -> Self {
+        self.loading = loading;
+        self
+    }
+
+    /// Overrides the loading slot text.
+    pub fn with_loading_text(mut self, text: impl Into<String>) -> Self {
+        self.loading_text = text.into();
+        self
+    }
+
+    /// Overrides the empty slot text.
+    pub fn with_empty_text(mut self, text: impl Into<String>) -> Self {
+        self.empty_text = text.into();
+        self
+    }
+
+    /// Overrides the palette color.
+    pub fn with_color(mut self, color: Color) -> Self {
+        self.color = color;
+        self
+    }
+
+    /// Overrides the variant.
+    pub fn with_variant(mut self, variant: Variant) -> Self {
+        self.variant = variant;
+        self
+    }
+
+    /// Overrides the id prefix so multiple widgets coexist on one page.
+    pub fn with_id_prefix(mut self, prefix: impl Into<String>) -> Self {
+        self.id_prefix = prefix.into();
+        self
+    }
+
+    fn listbox_id(&self) -> String {
+        format!("{}-listbox", self.id_prefix)
+    }
+
+    fn option_id(&self, index: usize) -> String {
+        format!("{}-option-{index}", self.id_prefix)
+    }
+}
+
+/// Render the full Joy autocomplete widget as an HTML string.
+///
+/// The listbox stays in the DOM when closed (`hidden` attribute) so the SSR
+/// payload and the hydrated tree are byte-identical; loading and empty slots
+/// replace the options rather than collapsing the popup so the geometry stays
+/// stable while results stream in.
+pub fn autocomplete(theme: &Theme, props: &AutocompleteProps, state: &AutocompleteState) -> String {
+    let root_style = compose_inline_style([
+        ("position", "relative".to_string()),
+        ("display", "inline-flex".to_string()),
+        ("flex-direction", "column".to_string()),
+        ("font-family", theme.typography.font_family.clone()),
+    ]);
+    format!(
+        "<div data-joy-autocomplete=\"{}\" style=\"{root_style}\">{}{}</div>",
+        props.id_prefix,
+        input_decorator(theme, props, state),
+        listbox(theme, props, state),
+    )
+}
+
+/// Render the decorated input row: start decorator, selection chips, the
+/// combobox input and the end decorator.
+fn input_decorator(theme: &Theme, props: &AutocompleteProps, state: &AutocompleteState) -> String {
+    let surface = resolve_surface_tokens(theme, props.color, props.variant);
+    let wrapper_style = surface.compose([
+        ("display", "inline-flex".to_string()),
+        ("align-items", "center".to_string()),
+        ("flex-wrap", "wrap".to_string()),
+        ("gap", format!("{}px", theme.spacing(1))),
+        (
+            "padding",
+            format!("{}px {}px", theme.spacing(1), theme.spacing(2)),
+        ),
+        (
+            "transition",
+            theme.motion.transition(&["border", "outline"]),
+        ),
+    ]);
+
+    let mut inner = String::new();
+    if let Some(decorator) = &props.start_decorator {
+        inner.push_str(&format!(
+            "<span data-joy-autocomplete-decorator=\"start\">{decorator}</span>"
+        ));
+    }
+    for (index, chip) in props.chips.iter().enumerate() {
+        inner.push_str(&chip_html(theme, index, chip));
+    }
+
+    let active_id = state.highlighted().map(|index| props.option_id(index));
+    let mut input_attrs =
+        state.input_accessibility_attributes(&props.listbox_id(), active_id.as_deref());
+    input_attrs.push(("type", "text".into()));
+    input_attrs.push(("value", state.input_value().to_string()));
+    if let Some(placeholder) = &props.placeholder {
+        input_attrs.push(("placeholder", placeholder.clone()));
+    }
+    let input_style = compose_inline_style([
+        ("border", "none".to_string()),
+        ("outline", "none".to_string()),
+        ("background", "transparent".to_string()),
+        ("flex", "1".to_string()),
+        ("min-width", "4ch".to_string()),
+        ("font", "inherit".to_string()),
+        ("color", "inherit".to_string()),
+    ]);
+    inner.push_str(&format!(
+        "<input {} style=\"{input_style}\"/>",
+        attributes_html(&input_attrs)
+    ));
+
+    if let Some(decorator) = &props.end_decorator {
+        inner.push_str(&format!(
+            "<span data-joy-autocomplete-decorator=\"end\">{decorator}</span>"
+        ));
+    }
+    format!("<div data-joy-autocomplete-input=\"true\" style=\"{wrapper_style}\">{inner}</div>")
+}
+
+/// Render one dismissible selection chip.  The remove button carries the chip
+/// index so adapters can map clicks back onto their selection model.
+fn chip_html(theme: &Theme, index: usize, label: &str) -> String {
+    let style = compose_inline_style([
+        ("display", "inline-flex".to_string()),
+        ("align-items", "center".to_string()),
+        ("gap", format!("{}px", theme.spacing(0).max(4))),
+        ("padding", format!("2px {}px", theme.spacing(1))),
+        ("border-radius", format!("{}px", theme.joy.radius)),
+        (
+            "background",
+            format!("{}22", theme.palette.active().neutral),
+        ),
+    ]);
+    format!(
+        "<span data-joy-autocomplete-chip=\"{index}\" style=\"{style}\">{label}<button type=\"button\" aria-label=\"Remove {label}\" data-chip-remove=\"{index}\">\u{00D7}</button></span>"
+    )
+}
+
+/// Render the listbox including its loading and empty slots.
+fn listbox(theme: &Theme, props: &AutocompleteProps, state: &AutocompleteState) -> String {
+    let mut attrs = state.listbox_accessibility_attributes();
+    attrs.push(("id", props.listbox_id()));
+    attrs.push(("data-joy-autocomplete-listbox", "true".into()));
+    let style = compose_inline_style([
+        ("list-style", "none".to_string()),
+        ("margin", "0".to_string()),
+        ("padding", format!("{}px 0", theme.spacing(1))),
+        ("border-radius", format!("{}px", theme.joy.radius)),
+        (
+            "background",
+            theme.palette.active().background_paper.clone(),
+        ),
+    ]);
+
+    let mut items = String::new();
+    if props.loading {
+        items.push_str(&format!(
+            "<li role=\"presentation\" data-joy-autocomplete-slot=\"loading\">{}</li>",
+            props.loading_text
+        ));
+    } else if props.options.is_empty() {
+        items.push_str(&format!(
+            "<li role=\"presentation\" data-joy-autocomplete-slot=\"empty\">{}</li>",
+            props.empty_text
+        ));
+    } else {
+        let select = state.select_state();
+        for (index, label) in props.options.iter().enumerate() {
+            let mut option_attrs = select.option_accessibility_attributes(index);
+            option_attrs.push(("id", props.option_id(index)));
+            option_attrs.push((
+                "aria-selected",
+                (select.selected() == Some(index)).to_string(),
+            ));
+            option_attrs.push((
+                "data-highlighted",
+                (state.highlighted() == Some(index)).to_string(),
+            ));
+            let option_style = compose_inline_style([
+                (
+                    "padding",
+                    format!("{}px {}px", theme.spacing(1), theme.spacing(2)),
+                ),
+                ("cursor", "pointer".to_string()),
+                ("transition", theme.motion.transition(&["background"])),
+            ]);
+            items.push_str(&format!(
+                "<li {} style=\"{option_style}\">{label}</li>",
+                attributes_html(&option_attrs)
+            ));
+        }
+    }
+    format!(
+        "<ul {} style=\"{style}\">{items}</ul>",
+        attributes_html(&attrs)
+    )
+}
+
+/// Adapter targeting the `yew` framework.
+pub mod yew {
+    use super::*;
+
+    /// Render the autocomplete into an HTML string using the shared renderer.
+    pub fn render(theme: &Theme, props: &AutocompleteProps, state: &AutocompleteState) -> String {
+        super::autocomplete(theme, props, state)
+    }
+}
+
+/// Adapter targeting the `leptos` framework.
+pub mod leptos {
+    use super::*;
+
+    /// Render the autocomplete into an HTML string using the shared renderer.
+    pub fn render(theme: &Theme, props: &AutocompleteProps, state: &AutocompleteState) -> String {
+        super::autocomplete(theme, props, state)
+    }
+}
+
+/// Adapter targeting the `dioxus` framework.
+pub mod dioxus {
+    use super::*;
+
+    /// Render the autocomplete into an HTML string using the shared renderer.
+    pub fn render(theme: &Theme, props: &AutocompleteProps, state: &AutocompleteState) -> String {
+        super::autocomplete(theme, props, state)
+    }
+}
+
+/// Adapter targeting the `sycamore` framework.
+pub mod sycamore {
+    use super::*;
+
+    /// Render the autocomplete into an HTML string using the shared renderer.
+    pub fn render(theme: &Theme, props: &AutocompleteProps, state: &AutocompleteState) -> String {
+        super::autocomplete(theme, props, state)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fixture() -> (AutocompleteProps, AutocompleteState) {
+        let props = AutocompleteProps::new(vec!["Alpha".into(), "Beta".into(), "Gamma".into()])
+            .with_placeholder("Search teams")
+            .with_id_prefix("teams");
+        let state = AutocompleteState::new(AutocompleteConfig::enterprise_defaults(3));
+        (props, state)
+    }
+
+    #[test]
+    fn ssr_snapshot_is_deterministic_and_carries_combobox_wiring() {
+        let (props, mut state) = fixture();
+        state.focus();
+        state.set_highlighted(Some(1));
+        let theme = Theme::default();
+        let first = autocomplete(&theme, &props, &state);
+        let second = autocomplete(&theme, &props, &state);
+        assert_eq!(first, second, "SSR output must be reproducible");
+        assert!(first.contains("role=\"combobox\""));
+        assert!(first.contains("aria-controls=\"teams-listbox\""));
+        assert!(first.contains("aria-activedescendant=\"teams-option-1\""));
+        assert!(first.contains("placeholder=\"Search teams\""));
+    }
+
+    #[test]
+    fn listbox_renders_options_with_selection_state() {
+        let (props, mut state) = fixture();
+        state.focus();
+        state.select_index(2, |_| None);
+        let html = autocomplete(&Theme::default(), &props, &state);
+        assert!(html.contains("id=\"teams-option-2\""));
+        assert!(html.contains("Gamma"));
+        assert!(html.contains("aria-selected=\"true\""));
+    }
+
+    #[test]
+    fn loading_slot_replaces_options() {
+        let (props, state) = fixture();
+        let props = props.with_loading(true).with_loading_text("Fetching…");
+        let html = autocomplete(&Theme::default(), &props, &state);
+        assert!(html.contains("data-joy-autocomplete-slot=\"loading\""));
+        assert!(html.contains("Fetching…"));
+        assert!(!html.contains("Alpha"));
+    }
+
+    #[test]
+    fn empty_slot_renders_when_no_options_match() {
+        let (props, state) = fixture();
+        let props = AutocompleteProps::new(Vec::new())
+            .with_empty_text("Nothing here")
+            .with_id_prefix(props.id_prefix);
+        let html = autocomplete(&Theme::default(), &props, &state);
+        assert!(html.contains("data-joy-autocomplete-slot=\"empty\""));
+        assert!(html.contains("Nothing here"));
+    }
+
+    #[test]
+    fn chips_render_with_remove_affordances() {
+        let (props, state) = fixture();
+        let props = props.with_chips(vec!["Core".into(), "Infra".into()]);
+        let html = autocomplete(&Theme::default(), &props, &state);
+        assert!(html.contains("data-joy-autocomplete-chip=\"0\""));
+        assert!(html.contains("data-chip-remove=\"1\""));
+        assert!(html.contains("aria-label=\"Remove Infra\""));
+    }
+}
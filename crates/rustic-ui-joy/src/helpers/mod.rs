@@ -78,6 +78,16 @@ impl SurfaceTokens {
     }
 }
 
+/// Serialize attribute tuples (as emitted by the headless accessibility
+/// builders) into HTML attribute syntax for the string renderers.
+pub fn attributes_html(attrs: &[(&'static str, String)]) -> String {
+    attrs
+        .iter()
+        .map(|(key, value)| format!("{key}=\"{value}\""))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
 /// Convenience helper for turning key/value pairs into an inline CSS string.
 pub fn compose_inline_style<I>(pairs: I) -> String
 where
@@ -119,10 +129,9 @@ pub fn resolve_surface_tokens(theme: &Theme, color: Color, variant: Variant) ->
     let palette_color = palette_color(theme, color);
     let radius = theme.joy.radius;
     let focus_outline = Some(theme.joy.focus_outline_for_color(&palette_color));
-    let focus_outline_offset = Some(format!(
-        "-{}px",
-        theme.joy.focus.thickness.min(theme.joy.radius.max(1))
-    ));
+    // Joy rings sit inside the surface, so the shared offset token is applied
+    // as an inset rather than the outward gap Material uses.
+    let focus_outline_offset = Some(format!("-{}px", theme.joy.focus.offset));
 
     match variant {
         Variant::Solid => SurfaceTokens {
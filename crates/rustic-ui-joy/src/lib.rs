@@ -29,11 +29,9 @@
 //! primitives behave consistently across frameworks. This design avoids manual repetitive
 //! glue code and ensures future adapters reuse the exact same prop contracts.
 
-#[cfg(feature = "yew")]
 pub mod accordion;
 #[cfg(feature = "yew")]
 pub mod aspect_ratio;
-#[cfg(feature = "yew")]
 pub mod autocomplete;
 #[cfg(feature = "yew")]
 pub mod button;
@@ -43,23 +41,23 @@ pub mod card;
 pub mod chip;
 pub mod helpers;
 pub mod macros;
-#[cfg(feature = "yew")]
 pub mod slider;
-#[cfg(feature = "yew")]
 pub mod snackbar;
 #[cfg(feature = "yew")]
+pub mod status_badge;
 pub mod stepper;
 #[cfg(feature = "yew")]
 pub mod toggle_button_group;
 
-#[cfg(feature = "yew")]
-pub use accordion::{AccordionController, AccordionGroupState, AccordionItemChange};
+pub use accordion::{
+    AccordionController, AccordionGroupState, AccordionItemChange, AccordionItemProps,
+    AccordionProps,
+};
 #[cfg(feature = "yew")]
 pub use aspect_ratio::{AspectRatio, AspectRatioProps};
-#[cfg(feature = "yew")]
 pub use autocomplete::{
     AutocompleteChange, AutocompleteConfig, AutocompleteControlStrategy, AutocompleteController,
-    AutocompleteState,
+    AutocompleteProps, AutocompleteState,
 };
 #[cfg(feature = "yew")]
 pub use button::{Button, ButtonProps};
@@ -68,14 +66,20 @@ pub use card::{Card, CardProps};
 #[cfg(feature = "yew")]
 pub use chip::{Chip, ChipProps};
 pub use macros::{Color, Variant};
-#[cfg(feature = "yew")]
-pub use slider::{SliderChange, SliderConfig, SliderController, SliderOrientation, SliderState};
-#[cfg(feature = "yew")]
+pub use slider::{
+    SliderChange, SliderConfig, SliderController, SliderMark, SliderOrientation, SliderProps,
+    SliderState,
+};
 pub use snackbar::{
-    SnackbarChange, SnackbarConfig, SnackbarController, SnackbarMessage, SnackbarState,
+    SnackbarAnchor, SnackbarChange, SnackbarConfig, SnackbarController, SnackbarMessage,
+    SnackbarProps, SnackbarState,
 };
 #[cfg(feature = "yew")]
-pub use stepper::{StepStatus, StepperChange, StepperConfig, StepperController, StepperState};
+pub use status_badge::{StatusBadge, StatusBadgeProps};
+pub use stepper::{
+    StepProps, StepStatus, StepperChange, StepperConfig, StepperController, StepperOrientation,
+    StepperProps, StepperState,
+};
 #[cfg(feature = "yew")]
 pub use toggle_button_group::{
     ToggleButtonGroupChange, ToggleButtonGroupConfig, ToggleButtonGroupController,
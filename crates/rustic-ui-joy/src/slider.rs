@@ -1,9 +1,16 @@
-//! Joy slider scaffolding mirroring the shared headless state machine.
+//! Joy slider mirroring the shared headless state machine.
 //!
-//! While the visual rendering is pending, adapters can start integrating the
-//! [`SliderController`] to wire up keyboard/pointer handling and analytics.  The
-//! controller simply wraps the reusable [`SliderState`] so state transitions stay
-//! centralised.
+//! [`SliderController`] wires up keyboard/pointer handling and analytics for
+//! adapters that own their markup, while [`slider`] renders the complete Joy
+//! control — rail, filled track, marks and a thumb with a value label tooltip
+//! — as a deterministic HTML string shared by all four frameworks.  The
+//! geometry is expressed in percentages derived from
+//! [`SliderState::percent`], so the same markup positions correctly for both
+//! orientations without measuring the DOM.
+
+use crate::helpers::{attributes_html, compose_inline_style, resolve_surface_tokens};
+use crate::{Color, Variant};
+use rustic_ui_system::theme::Theme;
 
 pub use rustic_ui_headless::slider::{SliderChange, SliderConfig, SliderOrientation, SliderState};
 
@@ -27,3 +34,337 @@ impl SliderController {
         Self::new(SliderConfig::enterprise_defaults(min, max))
     }
 }
+
+/// A tick rendered along the rail, optionally labelled.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SliderMark {
+    /// Logical value the mark sits at.
+    pub value: f64,
+    /// Optional label rendered beneath (or beside) the tick.
+    pub label: Option<String>,
+}
+
+impl SliderMark {
+    /// Create an unlabelled mark.
+    pub fn new(value: f64) -> Self {
+        Self { value, label: None }
+    }
+
+    /// Attach a label to the mark.
+    pub fn with_label(mut self, label: impl Into<String>) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+}
+
+/// Shared configuration consumed by every slider framework adapter.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SliderProps {
+    /// Marks rendered along the rail.
+    pub marks: Vec<SliderMark>,
+    /// When `true` the value label renders permanently; otherwise it only
+    /// appears while the thumb is dragged.
+    pub always_show_value_label: bool,
+    /// Joy palette entry colouring the active track and thumb.
+    pub color: Color,
+    /// Joy variant applied to the thumb surface.
+    pub variant: Variant,
+}
+
+impl SliderProps {
+    /// Create slider props with Joy's solid/primary defaults.
+    pub fn new() -> Self {
+        Self {
+            marks: Vec::new(),
+            always_show_value_label: false,
+            color: Color::Primary,
+            variant: Variant::Solid,
+        }
+    }
+
+    /// Adds rail marks.
+    pub fn with_marks(mut self, marks: Vec<SliderMark>) -> Self {
+        self.marks = marks;
+        self
+    }
+
+    /// Keeps the value label visible outside drag gestures.
+    pub fn with_always_show_value_label(mut self, always: bool) -> Self {
+        self.always_show_value_label = always;
+        self
+    }
+
+    /// Overrides the palette color.
+    pub fn with_color(mut self, color: Color) -> Self {
+        self.color = color;
+        self
+    }
+
+    /// Overrides the variant.
+    pub fn with_variant(mut self, variant: Variant) -> Self {
+        self.variant = variant;
+        self
+    }
+}
+
+impl Default for SliderProps {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Percentage position of a logical value along the rail.
+fn percent_of(state: &SliderState, value: f64) -> f64 {
+    let denom = (state.max() - state.min()).abs();
+    if denom == 0.0 {
+        return 0.0;
+    }
+    ((value - state.min()) / denom).clamp(0.0, 1.0) * 100.0
+}
+
+/// CSS position declaration for the given percentage, axis-aware.
+fn position_style(orientation: SliderOrientation, percent: f64) -> (&'static str, String) {
+    match orientation {
+        SliderOrientation::Horizontal => ("left", format!("{percent:.2}%")),
+        SliderOrientation::Vertical => ("bottom", format!("{percent:.2}%")),
+    }
+}
+
+/// Render the full Joy slider as an HTML string.
+///
+/// All geometry is percentage based so adapters translate pointer events into
+/// [`SliderState::set_value`] calls and re-render; nothing here reads layout.
+pub fn slider(theme: &Theme, props: &SliderProps, state: &SliderState) -> String {
+    let orientation = state.orientation();
+    let horizontal = orientation == SliderOrientation::Horizontal;
+    let percent = state.percent();
+    let accent = resolve_surface_tokens(theme, props.color, Variant::Solid)
+        .background
+        .unwrap_or_else(|| theme.palette.active().primary.clone());
+    let rail_color = format!("{}33", theme.palette.active().neutral);
+
+    let root_style = compose_inline_style([
+        ("position", "relative".to_string()),
+        ("display", "inline-flex".to_string()),
+        ("align-items", "center".to_string()),
+        (
+            if horizontal { "width" } else { "height" },
+            "100%".to_string(),
+        ),
+        (
+            if horizontal { "height" } else { "width" },
+            format!("{}px", theme.spacing(4)),
+        ),
+        (
+            "cursor",
+            if state.is_disabled() {
+                "not-allowed"
+            } else {
+                "pointer"
+            }
+            .to_string(),
+        ),
+        (
+            "opacity",
+            if state.is_disabled() { "0.6" } else { "1" }.to_string(),
+        ),
+    ]);
+
+    let rail_style = compose_inline_style([
+        ("position", "absolute".to_string()),
+        (
+            if horizontal { "width" } else { "height" },
+            "100%".to_string(),
+        ),
+        (
+            if horizontal { "height" } else { "width" },
+            "4px".to_string(),
+        ),
+        ("border-radius", "2px".to_string()),
+        ("background", rail_color),
+    ]);
+
+    let track_style = compose_inline_style([
+        ("position", "absolute".to_string()),
+        (
+            if horizontal { "width" } else { "height" },
+            format!("{percent:.2}%"),
+        ),
+        (
+            if horizontal { "height" } else { "width" },
+            "4px".to_string(),
+        ),
+        ("border-radius", "2px".to_string()),
+        ("background", accent.clone()),
+        ("transition", theme.motion.transition(&["width", "height"])),
+    ]);
+
+    let mut marks_html = String::new();
+    for (index, mark) in props.marks.iter().enumerate() {
+        let mark_percent = percent_of(state, mark.value);
+        let active = mark.value <= state.value();
+        let mark_style = compose_inline_style([
+            ("position", "absolute".to_string()),
+            position_style(orientation, mark_percent),
+            ("width", "2px".to_string()),
+            ("height", "2px".to_string()),
+            ("border-radius", "50%".to_string()),
+            (
+                "background",
+                if active {
+                    "#fff".to_string()
+                } else {
+                    accent.clone()
+                },
+            ),
+        ]);
+        let label_html = mark
+            .label
+            .as_ref()
+            .map(|label| format!("<span data-joy-slider-mark-label=\"{index}\">{label}</span>"))
+            .unwrap_or_default();
+        marks_html.push_str(&format!(
+            "<span data-joy-slider-mark=\"{index}\" data-active=\"{active}\" style=\"{mark_style}\">{label_html}</span>"
+        ));
+    }
+
+    let mut thumb_attrs = state.thumb_accessibility_attributes();
+    thumb_attrs.push((
+        "tabindex",
+        if state.is_disabled() { "-1" } else { "0" }.into(),
+    ));
+    thumb_attrs.push(("data-joy-slider-thumb", "true".into()));
+    thumb_attrs.push(("data-dragging", state.is_dragging().to_string()));
+    let thumb_surface = resolve_surface_tokens(theme, props.color, props.variant);
+    let thumb_style = thumb_surface.compose([
+        ("position", "absolute".to_string()),
+        position_style(orientation, percent),
+        ("transform", "translate(-50%, 0)".to_string()),
+        ("width", format!("{}px", theme.spacing(2))),
+        ("height", format!("{}px", theme.spacing(2))),
+        ("border-radius", "50%".to_string()),
+        ("transition", theme.motion.transition(&["left", "bottom"])),
+    ]);
+
+    let label_visible = props.always_show_value_label || state.is_dragging();
+    let value_label = format!(
+        "<span data-joy-slider-value-label=\"true\" aria-hidden=\"true\" data-visible=\"{label_visible}\">{}</span>",
+        state.value()
+    );
+
+    format!(
+        "<div data-joy-slider=\"true\" data-orientation=\"{}\" data-disabled=\"{}\" style=\"{root_style}\"><span data-joy-slider-rail=\"true\" style=\"{rail_style}\"></span><span data-joy-slider-track=\"true\" style=\"{track_style}\"></span>{marks_html}<span {} style=\"{thumb_style}\">{value_label}</span></div>",
+        match orientation {
+            SliderOrientation::Horizontal => "horizontal",
+            SliderOrientation::Vertical => "vertical",
+        },
+        state.is_disabled(),
+        attributes_html(&thumb_attrs),
+    )
+}
+
+/// Adapter targeting the `yew` framework.
+pub mod yew {
+    use super::*;
+
+    /// Render the slider into an HTML string using the shared renderer.
+    pub fn render(theme: &Theme, props: &SliderProps, state: &SliderState) -> String {
+        super::slider(theme, props, state)
+    }
+}
+
+/// Adapter targeting the `leptos` framework.
+pub mod leptos {
+    use super::*;
+
+    /// Render the slider into an HTML string using the shared renderer.
+    pub fn render(theme: &Theme, props: &SliderProps, state: &SliderState) -> String {
+        super::slider(theme, props, state)
+    }
+}
+
+/// Adapter targeting the `dioxus` framework.
+pub mod dioxus {
+    use super::*;
+
+    /// Render the slider into an HTML string using the shared renderer.
+    pub fn render(theme: &Theme, props: &SliderProps, state: &SliderState) -> String {
+        super::slider(theme, props, state)
+    }
+}
+
+/// Adapter targeting the `sycamore` framework.
+pub mod sycamore {
+    use super::*;
+
+    /// Render the slider into an HTML string using the shared renderer.
+    pub fn render(theme: &Theme, props: &SliderProps, state: &SliderState) -> String {
+        super::slider(theme, props, state)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn state_with_value(value: f64) -> SliderState {
+        let mut state = SliderState::new(SliderConfig {
+            min: 0.0,
+            max: 100.0,
+            step: 1.0,
+            page: 10.0,
+            default_value: 0.0,
+            disabled: false,
+            orientation: SliderOrientation::Horizontal,
+        });
+        state.set_value(value);
+        state
+    }
+
+    #[test]
+    fn track_and_thumb_follow_the_value_percentage() {
+        let state = state_with_value(25.0);
+        let html = slider(&Theme::default(), &SliderProps::new(), &state);
+        assert!(html.contains("width:25.00%"));
+        assert!(html.contains("left:25.00%"));
+        assert!(html.contains("aria-valuenow=\"25\""));
+        assert!(html.contains("role=\"slider\""));
+    }
+
+    #[test]
+    fn marks_flag_their_active_state() {
+        let state = state_with_value(50.0);
+        let props = SliderProps::new().with_marks(vec![
+            SliderMark::new(0.0).with_label("Min"),
+            SliderMark::new(75.0).with_label("High"),
+        ]);
+        let html = slider(&Theme::default(), &props, &state);
+        assert!(html.contains("data-joy-slider-mark=\"0\" data-active=\"true\""));
+        assert!(html.contains("data-joy-slider-mark=\"1\" data-active=\"false\""));
+        assert!(html.contains("data-joy-slider-mark-label=\"1\">High</span>"));
+    }
+
+    #[test]
+    fn value_label_appears_while_dragging() {
+        let mut state = state_with_value(10.0);
+        let props = SliderProps::new();
+        let idle = slider(&Theme::default(), &props, &state);
+        assert!(idle.contains("data-visible=\"false\""));
+        state.begin_drag();
+        let dragging = slider(&Theme::default(), &props, &state);
+        assert!(dragging.contains("data-visible=\"true\""));
+        assert!(dragging.contains("data-dragging=\"true\""));
+    }
+
+    #[test]
+    fn vertical_orientation_positions_along_the_block_axis() {
+        let mut config = SliderConfig::enterprise_defaults(0.0, 10.0);
+        config.orientation = SliderOrientation::Vertical;
+        config.default_value = 5.0;
+        let state = SliderState::new(config);
+        let html = slider(&Theme::default(), &SliderProps::new(), &state);
+        assert!(html.contains("data-orientation=\"vertical\""));
+        assert!(html.contains("bottom:50.00%"));
+        assert!(html.contains("aria-orientation=\"vertical\""));
+    }
+}
@@ -1,9 +1,19 @@
-//! Joy snackbar scaffolding exposing the shared queue/state machine.
+//! Joy snackbar bound to the shared queue/state machine.
 //!
 //! Enterprises frequently coordinate snackbar usage across micro-frontends.  The
 //! [`SnackbarController`] centralises queue management so individual renderers
 //! simply forward events into the controller and map the emitted
-//! [`SnackbarChange`] into UI updates.
+//! [`SnackbarChange`] into UI updates, while [`snackbar`] renders the complete
+//! Joy surface — anchor positioning, decorators and the auto-hide progress bar
+//! — directly from [`SnackbarState`] so examples no longer hand-style
+//! transient feedback with `resolve_surface_tokens` inline.
+
+use std::fmt;
+
+use crate::helpers::{compose_inline_style, resolve_surface_tokens};
+use crate::{Color, Variant};
+use rustic_ui_headless::timing::Clock;
+use rustic_ui_system::theme::Theme;
 
 pub use rustic_ui_headless::snackbar::{
     SnackbarChange, SnackbarConfig, SnackbarMessage, SnackbarState,
@@ -24,3 +34,332 @@ impl<T: Clone> SnackbarController<T> {
         }
     }
 }
+
+/// Viewport corner (or edge centre) the snackbar docks against.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SnackbarAnchor {
+    /// Top left in LTR layouts.
+    TopStart,
+    /// Horizontally centred along the top edge.
+    TopCenter,
+    /// Top right in LTR layouts.
+    TopEnd,
+    /// Bottom left in LTR layouts (Joy's default).
+    BottomStart,
+    /// Horizontally centred along the bottom edge.
+    BottomCenter,
+    /// Bottom right in LTR layouts.
+    BottomEnd,
+}
+
+impl SnackbarAnchor {
+    /// Stable identifier mirrored into the `data-anchor` hook.
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::TopStart => "top-start",
+            Self::TopCenter => "top-center",
+            Self::TopEnd => "top-end",
+            Self::BottomStart => "bottom-start",
+            Self::BottomCenter => "bottom-center",
+            Self::BottomEnd => "bottom-end",
+        }
+    }
+
+    /// Fixed-position inset declarations for the anchor.
+    fn position_pairs(self, theme: &Theme) -> Vec<(&'static str, String)> {
+        let inset = format!("{}px", theme.spacing(2));
+        let mut pairs = Vec::with_capacity(3);
+        match self {
+            Self::TopStart | Self::TopCenter | Self::TopEnd => pairs.push(("top", inset.clone())),
+            Self::BottomStart | Self::BottomCenter | Self::BottomEnd => {
+                pairs.push(("bottom", inset.clone()))
+            }
+        }
+        match self {
+            Self::TopStart | Self::BottomStart => pairs.push(("left", inset)),
+            Self::TopEnd | Self::BottomEnd => pairs.push(("right", inset)),
+            Self::TopCenter | Self::BottomCenter => {
+                pairs.push(("left", "50%".to_string()));
+                pairs.push(("transform", "translateX(-50%)".to_string()));
+            }
+        }
+        pairs
+    }
+}
+
+/// Shared configuration consumed by every snackbar framework adapter.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SnackbarProps {
+    /// Viewport corner the surface docks against.
+    pub anchor: SnackbarAnchor,
+    /// Pre-rendered HTML hosted before the message (icons, severity glyphs).
+    pub start_decorator: Option<String>,
+    /// Pre-rendered HTML hosted after the message (dismiss buttons, actions).
+    pub end_decorator: Option<String>,
+    /// When `true` the auto-hide countdown renders as a progress bar.
+    pub show_progress: bool,
+    /// Joy palette entry colouring the surface.
+    pub color: Color,
+    /// Joy variant applied to the surface.
+    pub variant: Variant,
+}
+
+impl SnackbarProps {
+    /// Create snackbar props with Joy's outlined/neutral defaults.
+    pub fn new() -> Self {
+        Self {
+            anchor: SnackbarAnchor::BottomStart,
+            start_decorator: None,
+            end_decorator: None,
+            show_progress: true,
+            color: Color::Neutral,
+            variant: Variant::Outlined,
+        }
+    }
+
+    /// Overrides the anchor origin.
+    pub fn with_anchor(mut self, anchor: SnackbarAnchor) -> Self {
+        self.anchor = anchor;
+        self
+    }
+
+    /// Adds markup rendered before the message.
+    pub fn with_start_decorator(mut self, html: impl Into<String>) -> Self {
+        self.start_decorator = Some(html.into());
+        self
+    }
+
+    /// Adds markup rendered after the message.
+    pub fn with_end_decorator(mut self, html: impl Into<String>) -> Self {
+        self.end_decorator = Some(html.into());
+        self
+    }
+
+    /// Toggles the auto-hide progress bar.
+    pub fn with_show_progress(mut self, show: bool) -> Self {
+        self.show_progress = show;
+        self
+    }
+
+    /// Overrides the palette color.
+    pub fn with_color(mut self, color: Color) -> Self {
+        self.color = color;
+        self
+    }
+
+    /// Overrides the variant.
+    pub fn with_variant(mut self, variant: Variant) -> Self {
+        self.variant = variant;
+        self
+    }
+}
+
+impl Default for SnackbarProps {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Render the Joy snackbar surface as an HTML string.
+///
+/// The visible message comes straight from the queue manager: adapters enqueue
+/// payloads, tick the state and re-render.  An idle snackbar still emits its
+/// container (hidden via `display:none`) so SSR output and the hydrated tree
+/// stay byte-identical across open/close cycles.
+pub fn snackbar<T: fmt::Display + Clone, C: Clock>(
+    theme: &Theme,
+    props: &SnackbarProps,
+    state: &SnackbarState<T, C>,
+) -> String {
+    let open = state.current().is_some();
+    let surface = resolve_surface_tokens(theme, props.color, props.variant);
+    let mut style_pairs = vec![
+        ("position", "fixed".to_string()),
+        ("display", if open { "flex" } else { "none" }.to_string()),
+        ("align-items", "center".to_string()),
+        ("gap", format!("{}px", theme.spacing(1))),
+        (
+            "padding",
+            format!("{}px {}px", theme.spacing(1), theme.spacing(2)),
+        ),
+        ("border-radius", format!("{}px", theme.joy.radius)),
+        ("font-family", theme.typography.font_family.clone()),
+        ("overflow", "hidden".to_string()),
+        ("z-index", "1400".to_string()),
+    ];
+    style_pairs.extend(props.anchor.position_pairs(theme));
+    let style = surface.compose(style_pairs);
+
+    let mut body = String::new();
+    if let Some(decorator) = &props.start_decorator {
+        body.push_str(&format!(
+            "<span data-joy-snackbar-decorator=\"start\">{decorator}</span>"
+        ));
+    }
+    if let Some(message) = state.current() {
+        body.push_str(&format!(
+            "<span data-joy-snackbar-message=\"{}\">{}</span>",
+            message.id, message.payload
+        ));
+    }
+    if let Some(decorator) = &props.end_decorator {
+        body.push_str(&format!(
+            "<span data-joy-snackbar-decorator=\"end\">{decorator}</span>"
+        ));
+    }
+    if props.show_progress {
+        if let Some(progress) = state.auto_hide_progress() {
+            let accent = resolve_surface_tokens(theme, props.color, Variant::Solid)
+                .background
+                .unwrap_or_else(|| theme.palette.active().primary.clone());
+            let progress_style = compose_inline_style([
+                ("position", "absolute".to_string()),
+                ("left", "0".to_string()),
+                ("bottom", "0".to_string()),
+                ("height", "2px".to_string()),
+                ("width", format!("{:.2}%", progress.clamp(0.0, 1.0) * 100.0)),
+                ("background", accent),
+            ]);
+            body.push_str(&format!(
+                "<span data-joy-snackbar-progress=\"true\" aria-hidden=\"true\" style=\"{progress_style}\"></span>"
+            ));
+        }
+    }
+
+    format!(
+        "<div role=\"status\" aria-live=\"polite\" data-joy-snackbar=\"true\" data-anchor=\"{}\" data-open=\"{open}\" data-queue=\"{}\" style=\"{style}\">{body}</div>",
+        props.anchor.as_str(),
+        state.queue_len(),
+    )
+}
+
+/// Adapter targeting the `yew` framework.
+pub mod yew {
+    use super::*;
+
+    /// Render the snackbar into an HTML string using the shared renderer.
+    pub fn render<T: fmt::Display + Clone, C: Clock>(
+        theme: &Theme,
+        props: &SnackbarProps,
+        state: &SnackbarState<T, C>,
+    ) -> String {
+        super::snackbar(theme, props, state)
+    }
+}
+
+/// Adapter targeting the `leptos` framework.
+pub mod leptos {
+    use super::*;
+
+    /// Render the snackbar into an HTML string using the shared renderer.
+    pub fn render<T: fmt::Display + Clone, C: Clock>(
+        theme: &Theme,
+        props: &SnackbarProps,
+        state: &SnackbarState<T, C>,
+    ) -> String {
+        super::snackbar(theme, props, state)
+    }
+}
+
+/// Adapter targeting the `dioxus` framework.
+pub mod dioxus {
+    use super::*;
+
+    /// Render the snackbar into an HTML string using the shared renderer.
+    pub fn render<T: fmt::Display + Clone, C: Clock>(
+        theme: &Theme,
+        props: &SnackbarProps,
+        state: &SnackbarState<T, C>,
+    ) -> String {
+        super::snackbar(theme, props, state)
+    }
+}
+
+/// Adapter targeting the `sycamore` framework.
+pub mod sycamore {
+    use super::*;
+
+    /// Render the snackbar into an HTML string using the shared renderer.
+    pub fn render<T: fmt::Display + Clone, C: Clock>(
+        theme: &Theme,
+        props: &SnackbarProps,
+        state: &SnackbarState<T, C>,
+    ) -> String {
+        super::snackbar(theme, props, state)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rustic_ui_headless::timing::MockClock;
+    use std::time::Duration;
+
+    fn state_with_clock(clock: MockClock) -> SnackbarState<String, MockClock> {
+        SnackbarState::with_clock(
+            clock,
+            SnackbarConfig {
+                auto_hide: Duration::from_millis(100),
+                max_queue: 3,
+            },
+        )
+    }
+
+    #[test]
+    fn idle_snackbar_stays_in_the_dom_but_hidden() {
+        let state = state_with_clock(MockClock::new());
+        let html = snackbar(&Theme::default(), &SnackbarProps::new(), &state);
+        assert!(html.contains("data-open=\"false\""));
+        assert!(html.contains("display:none;"));
+        assert!(html.contains("role=\"status\""));
+    }
+
+    #[test]
+    fn visible_message_renders_with_queue_depth() {
+        let mut state = state_with_clock(MockClock::new());
+        state.enqueue("deployed".to_string());
+        state.enqueue("queued".to_string());
+        let html = snackbar(&Theme::default(), &SnackbarProps::new(), &state);
+        assert!(html.contains("data-open=\"true\""));
+        assert!(html.contains(">deployed</span>"));
+        assert!(html.contains("data-queue=\"1\""));
+    }
+
+    #[test]
+    fn anchor_origin_drives_positioning() {
+        let mut state = state_with_clock(MockClock::new());
+        state.enqueue("hello".to_string());
+        let props = SnackbarProps::new().with_anchor(SnackbarAnchor::TopCenter);
+        let html = snackbar(&Theme::default(), &props, &state);
+        assert!(html.contains("data-anchor=\"top-center\""));
+        assert!(html.contains("left:50%;"));
+        assert!(html.contains("transform:translateX(-50%);"));
+    }
+
+    #[test]
+    fn auto_hide_progress_bar_tracks_elapsed_time() {
+        let clock = MockClock::new();
+        let mut state = state_with_clock(clock.clone());
+        state.enqueue("hello".to_string());
+        clock.advance(Duration::from_millis(50));
+        let html = snackbar(&Theme::default(), &SnackbarProps::new(), &state);
+        assert!(html.contains("data-joy-snackbar-progress=\"true\""));
+        assert!(html.contains("width:50.00%"));
+        let quiet = SnackbarProps::new().with_show_progress(false);
+        let without = snackbar(&Theme::default(), &quiet, &state);
+        assert!(!without.contains("data-joy-snackbar-progress"));
+    }
+
+    #[test]
+    fn decorators_render_around_the_message() {
+        let mut state = state_with_clock(MockClock::new());
+        state.enqueue("saved".to_string());
+        let props = SnackbarProps::new()
+            .with_start_decorator("<svg data-icon=\"check\"></svg>")
+            .with_end_decorator("<button type=\"button\">Dismiss</button>");
+        let html = snackbar(&Theme::default(), &props, &state);
+        assert!(html.contains("data-joy-snackbar-decorator=\"start\""));
+        assert!(html.contains("data-icon=\"check\""));
+        assert!(html.contains("data-joy-snackbar-decorator=\"end\""));
+    }
+}
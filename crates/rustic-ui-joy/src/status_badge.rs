@@ -0,0 +1,67 @@
+use rustic_ui_headless::presence::PresenceStatus;
+use rustic_ui_system::theme_provider::use_theme;
+use yew::prelude::*;
+
+use crate::helpers::{compose_inline_style, resolve_surface_tokens};
+use crate::{joy_props, Color, Variant};
+
+joy_props!(StatusBadgeProps {
+    /// Presence status to display, typically sourced from
+    /// [`rustic_ui_headless::presence::PresenceState::status`].
+    status: PresenceStatus,
+    /// Optional visible label rendered next to the indicator dot.
+    label: Option<String>,
+});
+
+/// Maps a presence status onto the Joy palette so the dot recolors through the
+/// same tokens as every other component.
+fn status_color(status: PresenceStatus) -> Color {
+    match status {
+        PresenceStatus::Online => Color::Success,
+        PresenceStatus::Away => Color::Warning,
+        PresenceStatus::Busy => Color::Danger,
+        PresenceStatus::Offline => Color::Neutral,
+    }
+}
+
+/// Joy UI presence badge driven by the headless
+/// [`PresenceState`](rustic_ui_headless::presence::PresenceState) machine.
+///
+/// The component is intentionally stateless: callers own the presence machine,
+/// feed heartbeats into it and re-render with the current
+/// [`PresenceStatus`] whenever a
+/// [`PresenceChange`](rustic_ui_headless::presence::PresenceChange) reports a
+/// transition. That keeps staleness decay deterministic and testable instead
+/// of being baked into the view layer.
+#[function_component(StatusBadge)]
+pub fn status_badge(props: &StatusBadgeProps) -> Html {
+    let theme = use_theme();
+
+    let dot_tokens = resolve_surface_tokens(&theme, status_color(props.status), Variant::Solid);
+    let dot_style = dot_tokens.compose(vec![
+        ("display", "inline-block".to_string()),
+        ("width", "8px".to_string()),
+        ("height", "8px".to_string()),
+        ("border-radius", "9999px".to_string()),
+    ]);
+
+    let root_style = compose_inline_style(vec![
+        ("display", "inline-flex".to_string()),
+        ("align-items", "center".to_string()),
+        ("gap", "6px".to_string()),
+    ]);
+
+    html! {
+        <span
+            style={root_style}
+            role="status"
+            aria-label={props.status.as_str()}
+            data-status={props.status.as_str()}
+        >
+            <span style={dot_style} aria-hidden="true"></span>
+            if let Some(label) = &props.label {
+                <span>{ label }</span>
+            }
+        </span>
+    }
+}
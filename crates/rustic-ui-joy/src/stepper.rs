@@ -1,8 +1,16 @@
-//! Joy stepper scaffolding bridging the shared headless workflow engine.
+//! Joy stepper bridging the shared headless workflow engine.
 //!
 //! The [`StepperController`] exposes the same state machine used by Material so
-//! teams can consolidate automation and analytics.  Once visual components are
-//! added they simply render according to the controller’s change stream.
+//! teams can consolidate automation and analytics, while [`stepper`] renders
+//! the complete Joy control — numbered indicators, completed checkmarks,
+//! connectors and optional per-step indicator slots — as a deterministic HTML
+//! string shared by all four frameworks.  Status flows through
+//! [`StepperState::step_status`] so the markup, CSS hooks and automation
+//! suites all observe the same truth.
+
+use crate::helpers::{attributes_html, compose_inline_style, resolve_surface_tokens};
+use crate::{Color, Variant};
+use rustic_ui_system::theme::Theme;
 
 pub use rustic_ui_headless::stepper::{StepStatus, StepperChange, StepperConfig, StepperState};
 
@@ -26,3 +34,346 @@ impl StepperController {
         Self::new(StepperConfig::enterprise_defaults(step_count))
     }
 }
+
+/// Axis the steps flow along.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum StepperOrientation {
+    /// Steps flow left-to-right (Joy's default).
+    Horizontal,
+    /// Steps stack top-to-bottom.
+    Vertical,
+}
+
+impl StepperOrientation {
+    /// Stable identifier mirrored into the `data-orientation` hook.
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Horizontal => "horizontal",
+            Self::Vertical => "vertical",
+        }
+    }
+}
+
+/// Label, helper text and optional indicator slot for one step.
+#[derive(Clone, Debug, PartialEq)]
+pub struct StepProps {
+    /// Title rendered beside the indicator.
+    pub label: String,
+    /// Optional supporting copy rendered beneath the label.
+    pub description: Option<String>,
+    /// Optional pre-rendered HTML replacing the default numbered indicator.
+    /// Completed steps still render the checkmark unless a slot is provided.
+    pub indicator_html: Option<String>,
+}
+
+impl StepProps {
+    /// Create a step with just a label.
+    pub fn new(label: impl Into<String>) -> Self {
+        Self {
+            label: label.into(),
+            description: None,
+            indicator_html: None,
+        }
+    }
+
+    /// Adds helper text beneath the label.
+    pub fn with_description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// Replaces the default indicator content (number/checkmark).
+    pub fn with_indicator(mut self, html: impl Into<String>) -> Self {
+        self.indicator_html = Some(html.into());
+        self
+    }
+}
+
+/// Shared configuration consumed by every stepper framework adapter.
+#[derive(Clone, Debug, PartialEq)]
+pub struct StepperProps {
+    /// Steps rendered in order.
+    pub steps: Vec<StepProps>,
+    /// Axis the steps flow along.
+    pub orientation: StepperOrientation,
+    /// Joy palette entry colouring active/completed indicators.
+    pub color: Color,
+    /// Joy variant applied to pending indicators.
+    pub variant: Variant,
+}
+
+impl StepperProps {
+    /// Create stepper props with Joy's horizontal/primary defaults.
+    pub fn new(steps: Vec<StepProps>) -> Self {
+        Self {
+            steps,
+            orientation: StepperOrientation::Horizontal,
+            color: Color::Primary,
+            variant: Variant::Soft,
+        }
+    }
+
+    /// Overrides the orientation.
+    pub fn with_orientation(mut self, orientation: StepperOrientation) -> Self {
+        self.orientation = orientation;
+        self
+    }
+
+    /// Overrides the palette color.
+    pub fn with_color(mut self, color: Color) -> Self {
+        self.color = color;
+        self
+    }
+
+    /// Overrides the variant used for pending indicators.
+    pub fn with_variant(mut self, variant: Variant) -> Self {
+        self.variant = variant;
+        self
+    }
+}
+
+/// Render one step indicator: a custom slot when provided, otherwise the step
+/// number (pending/active) or a checkmark (completed).
+fn indicator_html(
+    theme: &Theme,
+    props: &StepperProps,
+    status: StepStatus,
+    index: usize,
+    slot: Option<&String>,
+) -> String {
+    let emphasised = matches!(status, StepStatus::Active | StepStatus::Completed);
+    let surface = if emphasised {
+        resolve_surface_tokens(theme, props.color, Variant::Solid)
+    } else {
+        resolve_surface_tokens(theme, Color::Neutral, props.variant)
+    };
+    let mut extra = vec![
+        ("display", "inline-flex".to_string()),
+        ("align-items", "center".to_string()),
+        ("justify-content", "center".to_string()),
+        ("width", format!("{}px", theme.spacing(3))),
+        ("height", format!("{}px", theme.spacing(3))),
+        ("border-radius", "50%".to_string()),
+        ("font-weight", "600".to_string()),
+        (
+            "transition",
+            theme.motion.transition(&["background", "color"]),
+        ),
+    ];
+    if status == StepStatus::Disabled {
+        extra.push(("opacity", "0.6".to_string()));
+    }
+    let style = surface.compose(extra);
+    let content = match slot {
+        Some(html) => html.clone(),
+        None if status == StepStatus::Completed => "\u{2713}".to_string(),
+        None => (index + 1).to_string(),
+    };
+    format!(
+        "<span data-joy-step-indicator=\"{index}\" aria-hidden=\"true\" style=\"{style}\">{content}</span>"
+    )
+}
+
+/// Render the full Joy stepper as an HTML string.
+///
+/// Every step carries `data-status` mirroring [`StepperState::step_status`]
+/// alongside the button attributes emitted by the machine, so adapters attach
+/// click handlers to the rendered buttons and re-render after transitions.
+pub fn stepper(theme: &Theme, props: &StepperProps, state: &StepperState) -> String {
+    let horizontal = props.orientation == StepperOrientation::Horizontal;
+    let list_style = compose_inline_style([
+        ("display", "flex".to_string()),
+        (
+            "flex-direction",
+            if horizontal { "row" } else { "column" }.to_string(),
+        ),
+        (
+            "align-items",
+            if horizontal { "center" } else { "stretch" }.to_string(),
+        ),
+        ("gap", format!("{}px", theme.spacing(1))),
+        ("margin", "0".to_string()),
+        ("padding", "0".to_string()),
+        ("list-style", "none".to_string()),
+        ("font-family", theme.typography.font_family.clone()),
+    ]);
+    let connector_style = compose_inline_style([
+        ("flex", "1".to_string()),
+        (
+            if horizontal { "height" } else { "width" },
+            "2px".to_string(),
+        ),
+        (
+            if horizontal {
+                "min-width"
+            } else {
+                "min-height"
+            },
+            format!("{}px", theme.spacing(2)),
+        ),
+        (
+            "background",
+            format!("{}55", theme.palette.active().neutral),
+        ),
+    ]);
+
+    let mut items = String::new();
+    for (index, step) in props.steps.iter().enumerate() {
+        let status = state.step_status(index);
+        let status_str = match status {
+            StepStatus::Pending => "pending",
+            StepStatus::Active => "active",
+            StepStatus::Completed => "completed",
+            StepStatus::Disabled => "disabled",
+        };
+        if index > 0 {
+            items.push_str(&format!(
+                "<span data-joy-step-connector=\"{index}\" aria-hidden=\"true\" style=\"{connector_style}\"></span>"
+            ));
+        }
+        let mut button_attrs = state.step_button_attributes(index);
+        button_attrs.push(("type", "button".to_string()));
+        button_attrs.push(("data-joy-step-button", index.to_string()));
+        let button_style = compose_inline_style([
+            ("display", "flex".to_string()),
+            ("align-items", "center".to_string()),
+            ("gap", format!("{}px", theme.spacing(1))),
+            ("background", "none".to_string()),
+            ("border", "none".to_string()),
+            ("padding", "0".to_string()),
+            ("font", "inherit".to_string()),
+            ("text-align", "start".to_string()),
+            (
+                "cursor",
+                if status == StepStatus::Disabled {
+                    "not-allowed"
+                } else {
+                    "pointer"
+                }
+                .to_string(),
+            ),
+        ]);
+        let description = step
+            .description
+            .as_ref()
+            .map(|text| {
+                format!(
+                    "<span data-joy-step-description=\"{index}\" style=\"opacity:0.8;\">{text}</span>"
+                )
+            })
+            .unwrap_or_default();
+        items.push_str(&format!(
+            "<li data-joy-step=\"{index}\" data-status=\"{status_str}\"><button {} style=\"{button_style}\">{}<span style=\"display:flex;flex-direction:column;\"><span data-joy-step-label=\"{index}\">{}</span>{description}</span></button></li>",
+            attributes_html(&button_attrs),
+            indicator_html(theme, props, status, index, step.indicator_html.as_ref()),
+            step.label,
+        ));
+    }
+
+    format!(
+        "<ol data-joy-stepper=\"true\" data-orientation=\"{}\" style=\"{list_style}\">{items}</ol>",
+        props.orientation.as_str()
+    )
+}
+
+/// Adapter targeting the `yew` framework.
+pub mod yew {
+    use super::*;
+
+    /// Render the stepper into an HTML string using the shared renderer.
+    pub fn render(theme: &Theme, props: &StepperProps, state: &StepperState) -> String {
+        super::stepper(theme, props, state)
+    }
+}
+
+/// Adapter targeting the `leptos` framework.
+pub mod leptos {
+    use super::*;
+
+    /// Render the stepper into an HTML string using the shared renderer.
+    pub fn render(theme: &Theme, props: &StepperProps, state: &StepperState) -> String {
+        super::stepper(theme, props, state)
+    }
+}
+
+/// Adapter targeting the `dioxus` framework.
+pub mod dioxus {
+    use super::*;
+
+    /// Render the stepper into an HTML string using the shared renderer.
+    pub fn render(theme: &Theme, props: &StepperProps, state: &StepperState) -> String {
+        super::stepper(theme, props, state)
+    }
+}
+
+/// Adapter targeting the `sycamore` framework.
+pub mod sycamore {
+    use super::*;
+
+    /// Render the stepper into an HTML string using the shared renderer.
+    pub fn render(theme: &Theme, props: &StepperProps, state: &StepperState) -> String {
+        super::stepper(theme, props, state)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fixture() -> (StepperProps, StepperState) {
+        let props = StepperProps::new(vec![
+            StepProps::new("Plan").with_description("Scope the release"),
+            StepProps::new("Verify"),
+            StepProps::new("Ship"),
+        ]);
+        let mut state = StepperState::new(StepperConfig::enterprise_defaults(3));
+        state.complete_active();
+        (props, state)
+    }
+
+    #[test]
+    fn statuses_surface_as_data_hooks() {
+        let (props, state) = fixture();
+        let html = stepper(&Theme::default(), &props, &state);
+        assert!(html.contains("data-joy-step=\"0\" data-status=\"completed\""));
+        assert!(html.contains("data-joy-step=\"1\" data-status=\"active\""));
+        assert!(html.contains("data-joy-step=\"2\" data-status=\"pending\""));
+        assert!(html.contains("aria-selected=\"true\""));
+    }
+
+    #[test]
+    fn completed_steps_render_a_checkmark() {
+        let (props, state) = fixture();
+        let html = stepper(&Theme::default(), &props, &state);
+        assert!(html.contains(">\u{2713}</span>"));
+        // Pending steps keep their one-based number.
+        assert!(html.contains(">3</span>"));
+    }
+
+    #[test]
+    fn custom_indicator_slot_replaces_the_number() {
+        let (mut props, state) = fixture();
+        props.steps[2] = StepProps::new("Ship").with_indicator("<svg data-icon=\"rocket\"></svg>");
+        let html = stepper(&Theme::default(), &props, &state);
+        assert!(html.contains("data-icon=\"rocket\""));
+        assert!(!html.contains(">3</span>"));
+    }
+
+    #[test]
+    fn connectors_join_adjacent_steps() {
+        let (props, state) = fixture();
+        let html = stepper(&Theme::default(), &props, &state);
+        assert!(html.contains("data-joy-step-connector=\"1\""));
+        assert!(html.contains("data-joy-step-connector=\"2\""));
+        assert!(!html.contains("data-joy-step-connector=\"0\""));
+    }
+
+    #[test]
+    fn vertical_orientation_stacks_the_list() {
+        let (props, state) = fixture();
+        let props = props.with_orientation(StepperOrientation::Vertical);
+        let html = stepper(&Theme::default(), &props, &state);
+        assert!(html.contains("data-orientation=\"vertical\""));
+        assert!(html.contains("flex-direction:column;"));
+    }
+}
@@ -4,6 +4,7 @@
 //! surface.  This module is behind the `chrono` feature flag.
 
 use super::{DateAdapter, TimeAdapter};
+use chrono::Datelike;
 
 /// Adapter that delegates to the [`chrono`] crate for all date and time
 /// computations.  Chrono is widely used and battle tested which makes it a
@@ -24,6 +25,22 @@ impl DateAdapter for AdapterChrono {
     fn format(&self, date: &Self::Date) -> String {
         date.to_string()
     }
+
+    fn days_between(&self, from: &Self::Date, to: &Self::Date) -> i32 {
+        (*to - *from).num_days() as i32
+    }
+
+    fn start_of_month(&self, date: &Self::Date) -> Self::Date {
+        date.with_day(1).expect("day 1 exists in every month")
+    }
+
+    fn add_months(&self, date: &Self::Date, months: i32) -> Self::Date {
+        if months >= 0 {
+            *date + chrono::Months::new(months as u32)
+        } else {
+            *date - chrono::Months::new((-months) as u32)
+        }
+    }
 }
 
 impl TimeAdapter for AdapterChrono {
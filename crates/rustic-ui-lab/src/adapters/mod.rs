@@ -24,6 +24,19 @@ pub trait DateAdapter {
     /// Formats the date into a user visible string using the adapter's
     /// default locale.
     fn format(&self, date: &Self::Date) -> String;
+
+    /// Returns the number of whole days from `from` to `to`.  Negative when
+    /// `to` precedes `from`.  Range widgets use this for ordering dates and
+    /// measuring span lengths without requiring `Ord` on the date type.
+    fn days_between(&self, from: &Self::Date, to: &Self::Date) -> i32;
+
+    /// Returns the first day of the month containing `date`.  Used by
+    /// calendar layouts to anchor the visible month grid.
+    fn start_of_month(&self, date: &Self::Date) -> Self::Date;
+
+    /// Adds the specified number of calendar months to `date`, clamping the
+    /// day-of-month when the target month is shorter.
+    fn add_months(&self, date: &Self::Date, months: i32) -> Self::Date;
 }
 
 /// Abstraction over a time library.  The interface mirrors [`DateAdapter`]
@@ -23,6 +23,26 @@ impl DateAdapter for AdapterTime {
     fn format(&self, date: &Self::Date) -> String {
         date.to_string()
     }
+
+    fn days_between(&self, from: &Self::Date, to: &Self::Date) -> i32 {
+        (*to - *from).whole_days() as i32
+    }
+
+    fn start_of_month(&self, date: &Self::Date) -> Self::Date {
+        time::Date::from_calendar_date(date.year(), date.month(), 1)
+            .expect("day 1 exists in every month")
+    }
+
+    fn add_months(&self, date: &Self::Date, months: i32) -> Self::Date {
+        // `time` has no month arithmetic so we normalize the zero based month
+        // index manually and clamp the day to the target month's length.
+        let zero_based = date.year() * 12 + (date.month() as i32 - 1) + months;
+        let year = zero_based.div_euclid(12);
+        let month = time::Month::try_from((zero_based.rem_euclid(12) + 1) as u8)
+            .expect("normalized month is in 1..=12");
+        let day = date.day().min(month.length(year));
+        time::Date::from_calendar_date(year, month, day).expect("clamped day is valid")
+    }
 }
 
 impl TimeAdapter for AdapterTime {
@@ -0,0 +1,106 @@
+//! Theme-driven styling for chart widgets.
+//!
+//! **Unstable:** This module is an early preview.  It resolves the
+//! [`ChartsScheme`](rustic_ui_system::theme::ChartsScheme) tokens carried by
+//! the theme into the strings SVG chart renderers interpolate — series
+//! strokes, grid lines, tooltip chrome — so visualization colours follow the
+//! brand palette instead of per-chart literals.  The default ramp is the
+//! colour-vision-deficiency safe Okabe-Ito palette, keeping dashboards
+//! accessible before any brand override lands.
+
+use rustic_ui_system::theme::Theme;
+
+/// Resolved chart styling snapshot taken from a [`Theme`].
+///
+/// Chart renderers capture the snapshot once per render instead of threading
+/// the full theme through every drawing routine; the owned strings also keep
+/// the snapshot `'static`-friendly for retained-mode canvases.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ChartStyle {
+    categorical: Vec<String>,
+    grid_line: String,
+    tooltip_surface: String,
+    tooltip_text: String,
+}
+
+impl ChartStyle {
+    /// Captures the chart tokens from the given theme.
+    pub fn from_theme(theme: &Theme) -> Self {
+        Self {
+            categorical: theme.charts.categorical.clone(),
+            grid_line: theme.charts.grid_line.clone(),
+            tooltip_surface: theme.charts.tooltip_surface.clone(),
+            tooltip_text: theme.charts.tooltip_text.clone(),
+        }
+    }
+
+    /// Stroke/fill for the n-th series, cycling through the categorical
+    /// ramp exactly like
+    /// [`ChartsScheme::series_color`](rustic_ui_system::theme::ChartsScheme::series_color).
+    pub fn series_color(&self, index: usize) -> &str {
+        if self.categorical.is_empty() {
+            return &self.grid_line;
+        }
+        &self.categorical[index % self.categorical.len()]
+    }
+
+    /// Colours for a legend with `count` entries, in series order.
+    pub fn legend_colors(&self, count: usize) -> Vec<&str> {
+        (0..count).map(|index| self.series_color(index)).collect()
+    }
+
+    /// Stroke colour for grid lines and axis rules.
+    pub fn grid_stroke(&self) -> &str {
+        &self.grid_line
+    }
+
+    /// Inline CSS declarations for the tooltip container, pairing the
+    /// surface and text tokens so readouts stay readable on either scheme.
+    pub fn tooltip_style(&self) -> String {
+        format!(
+            "background:{};color:{};",
+            self.tooltip_surface, self.tooltip_text
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn series_colors_cycle_through_the_categorical_ramp() {
+        let style = ChartStyle::from_theme(&Theme::default());
+        let ramp_len = Theme::default().charts.categorical.len();
+        assert_eq!(style.series_color(0), style.series_color(ramp_len));
+        assert_ne!(style.series_color(0), style.series_color(1));
+    }
+
+    #[test]
+    fn brand_overrides_flow_into_every_series() {
+        let mut theme = Theme::default();
+        theme.charts.categorical = vec!["#111111".into(), "#222222".into()];
+        let style = ChartStyle::from_theme(&theme);
+        assert_eq!(
+            style.legend_colors(3),
+            vec!["#111111", "#222222", "#111111"]
+        );
+    }
+
+    #[test]
+    fn tooltip_style_pairs_surface_and_text_tokens() {
+        let mut theme = Theme::default();
+        theme.charts.tooltip_surface = "#000000".into();
+        theme.charts.tooltip_text = "#ffffff".into();
+        let style = ChartStyle::from_theme(&theme);
+        assert_eq!(style.tooltip_style(), "background:#000000;color:#ffffff;");
+    }
+
+    #[test]
+    fn empty_ramps_degrade_to_the_grid_stroke() {
+        let mut theme = Theme::default();
+        theme.charts.categorical.clear();
+        let style = ChartStyle::from_theme(&theme);
+        assert_eq!(style.series_color(7), style.grid_stroke());
+    }
+}
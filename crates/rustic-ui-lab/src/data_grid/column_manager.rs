@@ -0,0 +1,354 @@
+//! Column management machine with serde persistence.
+//!
+//! Grids let users hide noisy columns and drag the remaining ones into a
+//! personally meaningful order; losing that customization on every reload is
+//! a perennial enterprise complaint.  [`ColumnManager`] owns the show/hide
+//! and reorder state, snapshots it as a serde friendly [`ColumnSettings`] and
+//! persists it through the pluggable
+//! [`storage`](rustic_ui_utils::storage) utility so web builds land in
+//! `localStorage` while desktop shells write a settings file.
+//!
+//! Restored settings are reconciled defensively: ids that no longer exist in
+//! the column set are dropped and newly added columns are appended visible,
+//! so shipping a new column never breaks returning users.
+//!
+//! [`render_html`] produces the Material style management panel (checkbox per
+//! column, move up/down affordances and a reset action) with `data-*` hooks
+//! matching the conventions used across the stable renderers.
+
+use serde::{Deserialize, Serialize};
+
+use rustic_ui_utils::storage::{self, StorageError};
+
+use super::ColumnDef;
+
+/// Serializable snapshot of the user's column customizations.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ColumnSettings {
+    /// Column ids in display order.
+    pub order: Vec<String>,
+    /// Ids of hidden columns.
+    pub hidden: Vec<String>,
+}
+
+#[derive(Debug, Clone)]
+struct ManagedColumn {
+    id: String,
+    header: String,
+    hidden: bool,
+}
+
+/// Show/hide and reorder machine for a typed column set.
+#[derive(Debug, Clone)]
+pub struct ColumnManager {
+    columns: Vec<ManagedColumn>,
+    default_order: Vec<String>,
+}
+
+impl ColumnManager {
+    /// Captures the declared column set as the default layout.
+    pub fn new<T>(columns: &[ColumnDef<T>]) -> Self {
+        let columns: Vec<ManagedColumn> = columns
+            .iter()
+            .map(|column| ManagedColumn {
+                id: column.id.to_string(),
+                header: column.header.to_string(),
+                hidden: !column.is_visible(),
+            })
+            .collect();
+        let default_order = columns.iter().map(|column| column.id.clone()).collect();
+        Self {
+            columns,
+            default_order,
+        }
+    }
+
+    /// Column ids in the current display order, including hidden columns.
+    pub fn order(&self) -> Vec<&str> {
+        self.columns
+            .iter()
+            .map(|column| column.id.as_str())
+            .collect()
+    }
+
+    /// Whether the column is currently hidden.
+    pub fn is_hidden(&self, id: &str) -> bool {
+        self.columns
+            .iter()
+            .any(|column| column.id == id && column.hidden)
+    }
+
+    /// Shows or hides a column. Unknown ids are ignored.
+    pub fn set_hidden(&mut self, id: &str, hidden: bool) {
+        if let Some(column) = self.columns.iter_mut().find(|column| column.id == id) {
+            column.hidden = hidden;
+        }
+    }
+
+    /// Flips a column between shown and hidden. Unknown ids are ignored.
+    pub fn toggle(&mut self, id: &str) {
+        if let Some(column) = self.columns.iter_mut().find(|column| column.id == id) {
+            column.hidden = !column.hidden;
+        }
+    }
+
+    /// Moves a column one slot towards the front. Returns `false` when the
+    /// column is unknown or already first.
+    pub fn move_up(&mut self, id: &str) -> bool {
+        match self.columns.iter().position(|column| column.id == id) {
+            Some(index) if index > 0 => {
+                self.columns.swap(index - 1, index);
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Moves a column one slot towards the back. Returns `false` when the
+    /// column is unknown or already last.
+    pub fn move_down(&mut self, id: &str) -> bool {
+        match self.columns.iter().position(|column| column.id == id) {
+            Some(index) if index + 1 < self.columns.len() => {
+                self.columns.swap(index, index + 1);
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Restores the declared order and shows every column.
+    pub fn reset(&mut self) {
+        self.columns.sort_by_key(|column| {
+            self.default_order
+                .iter()
+                .position(|id| *id == column.id)
+                .unwrap_or(usize::MAX)
+        });
+        for column in &mut self.columns {
+            column.hidden = false;
+        }
+    }
+
+    /// Snapshot of the current customizations for persistence.
+    pub fn settings(&self) -> ColumnSettings {
+        ColumnSettings {
+            order: self
+                .columns
+                .iter()
+                .map(|column| column.id.clone())
+                .collect(),
+            hidden: self
+                .columns
+                .iter()
+                .filter(|column| column.hidden)
+                .map(|column| column.id.clone())
+                .collect(),
+        }
+    }
+
+    /// Applies persisted settings, reconciling against the live column set.
+    ///
+    /// Ids missing from the settings keep their relative declared order and
+    /// are appended after the persisted ones; unknown persisted ids are
+    /// dropped silently so stale snapshots never error.
+    pub fn apply_settings(&mut self, settings: &ColumnSettings) {
+        self.columns.sort_by_key(|column| {
+            settings
+                .order
+                .iter()
+                .position(|id| *id == column.id)
+                .unwrap_or(usize::MAX)
+        });
+        for column in &mut self.columns {
+            column.hidden = settings.hidden.contains(&column.id);
+        }
+    }
+
+    /// Persists the current settings as JSON under `key` through the
+    /// installed [`storage`] provider.
+    pub fn save(&self, key: &str) -> Result<(), StorageError> {
+        let json =
+            serde_json::to_string(&self.settings()).expect("column settings serialize infallibly");
+        storage::set_item(key, &json)
+    }
+
+    /// Restores settings persisted under `key`.
+    ///
+    /// Returns `Ok(false)` when nothing was persisted yet. Corrupt payloads
+    /// surface as [`StorageError::Backend`] so callers can fall back to the
+    /// declared layout.
+    pub fn load(&mut self, key: &str) -> Result<bool, StorageError> {
+        match storage::get_item(key)? {
+            Some(json) => {
+                let settings: ColumnSettings = serde_json::from_str(&json)
+                    .map_err(|error| StorageError::Backend(error.to_string()))?;
+                self.apply_settings(&settings);
+                Ok(true)
+            }
+            None => Ok(false),
+        }
+    }
+
+    /// Projects the managed order and visibility onto a typed column set.
+    ///
+    /// Columns are reordered in place and their visibility flags updated so
+    /// rendering and [`export`](super::export) immediately reflect the
+    /// user's customizations.
+    pub fn apply_to<T>(&self, columns: &mut [ColumnDef<T>]) {
+        columns.sort_by_key(|column| {
+            self.columns
+                .iter()
+                .position(|managed| managed.id == column.id)
+                .unwrap_or(usize::MAX)
+        });
+        for column in columns.iter_mut() {
+            column.set_visible(!self.is_hidden(column.id));
+        }
+    }
+}
+
+/// Renders the Material style column management panel.
+///
+/// Every interactive element carries a `data-column-action` hook
+/// (`toggle`, `move-up`, `move-down`, `reset`) plus the target
+/// `data-column-id`, letting adapters delegate a single click listener the
+/// same way the stable renderers do.
+pub fn render_html(manager: &ColumnManager) -> String {
+    let mut rows = String::new();
+    for column in &manager.columns {
+        let checked = if column.hidden { "" } else { " checked" };
+        rows.push_str(&format!(
+            "<li data-column-id=\"{id}\">\
+             <label><input type=\"checkbox\"{checked} \
+             data-column-action=\"toggle\" data-column-id=\"{id}\"/>{header}</label>\
+             <button type=\"button\" aria-label=\"Move {header} up\" \
+             data-column-action=\"move-up\" data-column-id=\"{id}\">\u{2191}</button>\
+             <button type=\"button\" aria-label=\"Move {header} down\" \
+             data-column-action=\"move-down\" data-column-id=\"{id}\">\u{2193}</button>\
+             </li>",
+            id = column.id,
+            header = column.header,
+        ));
+    }
+    format!(
+        "<div role=\"group\" aria-label=\"Column settings\" \
+         data-component=\"data-grid-column-panel\">\
+         <ul>{rows}</ul>\
+         <button type=\"button\" data-column-action=\"reset\">Reset columns</button>\
+         </div>"
+    )
+}
+
+pub mod yew {
+    /// Render the column management panel for SSR/hydration.
+    pub fn render(manager: &super::ColumnManager) -> String {
+        super::render_html(manager)
+    }
+}
+
+pub mod leptos {
+    /// Render the column management panel for SSR/hydration.
+    pub fn render(manager: &super::ColumnManager) -> String {
+        super::render_html(manager)
+    }
+}
+
+pub mod dioxus {
+    /// Render the column management panel for SSR/hydration.
+    pub fn render(manager: &super::ColumnManager) -> String {
+        super::render_html(manager)
+    }
+}
+
+pub mod sycamore {
+    /// Render the column management panel for SSR/hydration.
+    pub fn render(manager: &super::ColumnManager) -> String {
+        super::render_html(manager)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rustic_ui_utils::storage::MemoryStorage;
+    use std::sync::Arc;
+
+    struct Row;
+
+    fn columns() -> Vec<ColumnDef<Row>> {
+        crate::columns!(Row => [
+            name { header: "Name", value: |_| String::new() },
+            age { header: "Age", value: |_| String::new() },
+            team { header: "Team", value: |_| String::new() },
+        ])
+    }
+
+    #[test]
+    fn toggle_reorder_and_reset_manage_the_layout() {
+        let mut manager = ColumnManager::new(&columns());
+        manager.toggle("age");
+        assert!(manager.is_hidden("age"));
+        assert!(manager.move_down("name"));
+        assert_eq!(manager.order(), vec!["age", "name", "team"]);
+        assert!(!manager.move_up("age"));
+
+        manager.reset();
+        assert_eq!(manager.order(), vec!["name", "age", "team"]);
+        assert!(!manager.is_hidden("age"));
+    }
+
+    #[test]
+    fn settings_survive_a_storage_round_trip() {
+        storage::install_provider(Arc::new(MemoryStorage::default()));
+        let mut manager = ColumnManager::new(&columns());
+        manager.toggle("team");
+        manager.move_up("age");
+        manager.save("grid.demo.columns").unwrap();
+
+        let mut restored = ColumnManager::new(&columns());
+        assert!(restored.load("grid.demo.columns").unwrap());
+        assert_eq!(restored.order(), vec!["age", "name", "team"]);
+        assert!(restored.is_hidden("team"));
+        storage::reset_provider();
+    }
+
+    #[test]
+    fn stale_settings_reconcile_against_the_live_columns() {
+        let mut manager = ColumnManager::new(&columns());
+        manager.apply_settings(&ColumnSettings {
+            order: vec!["team".into(), "retired".into(), "name".into()],
+            hidden: vec!["retired".into(), "name".into()],
+        });
+        // Unknown ids vanish, missing ids append in declared order.
+        assert_eq!(manager.order(), vec!["team", "name", "age"]);
+        assert!(manager.is_hidden("name"));
+        assert!(!manager.is_hidden("age"));
+    }
+
+    #[test]
+    fn applied_settings_drive_rendering_and_export_order() {
+        let mut manager = ColumnManager::new(&columns());
+        manager.toggle("name");
+        manager.move_up("team");
+        manager.move_up("team");
+        let mut typed = columns();
+        manager.apply_to(&mut typed);
+        assert_eq!(typed[0].id, "team");
+        assert!(!typed.iter().find(|c| c.id == "name").unwrap().is_visible());
+    }
+
+    #[test]
+    fn panel_markup_exposes_delegation_hooks() {
+        let mut manager = ColumnManager::new(&columns());
+        manager.toggle("age");
+        let html = render_html(&manager);
+        assert!(html.contains("data-component=\"data-grid-column-panel\""));
+        assert!(html.contains(
+            "<input type=\"checkbox\" checked data-column-action=\"toggle\" data-column-id=\"name\"/>"
+        ));
+        assert!(html.contains(
+            "<input type=\"checkbox\" data-column-action=\"toggle\" data-column-id=\"age\"/>"
+        ));
+        assert!(html.contains("data-column-action=\"reset\""));
+    }
+}
@@ -0,0 +1,413 @@
+//! Server-side data source protocol for the data grid.
+//!
+//! Remote grids never hold the full row set in memory: the UI asks a backend
+//! for one page at a time together with the active sort and filter
+//! descriptors, and the backend reports the total row count so pagination
+//! controls can render.  [`GridDataSource`] captures exactly that contract.
+//!
+//! IO is deliberately kept out of the state machine.  [`GridFetchMachine`]
+//! hands callers an immutable [`PageRequest`] plus a generation number, the
+//! caller performs the fetch however it likes (HTTP, websocket, in-process)
+//! and feeds the outcome back through [`resolve`](GridFetchMachine::resolve)
+//! or [`reject`](GridFetchMachine::reject).  Stale generations are ignored so
+//! out-of-order responses can never clobber newer pages, while the exposed
+//! [`FetchStatus`] drives loading overlays and retry affordances without any
+//! async runtime dependency.
+//!
+//! [`InMemoryDataSource`] is the reference implementation used by tests and
+//! demos: it applies the descriptors against a local `Vec` through the typed
+//! [`ColumnDef`] set, mirroring what a well behaved backend would return.
+
+use std::fmt;
+
+use super::ColumnDef;
+
+/// Direction attached to a [`SortDescriptor`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SortDirection {
+    /// Smallest value first.
+    Ascending,
+    /// Largest value first.
+    Descending,
+}
+
+/// Requests rows ordered by a single column.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SortDescriptor {
+    /// Column identifier as declared through [`columns!`](crate::columns).
+    pub column: String,
+    /// Requested ordering.
+    pub direction: SortDirection,
+}
+
+/// Requests rows whose column value matches a query.
+///
+/// Matching semantics are owned by the data source; the reference
+/// implementation performs a case-insensitive substring match.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FilterDescriptor {
+    /// Column identifier as declared through [`columns!`](crate::columns).
+    pub column: String,
+    /// Raw query text entered by the user.
+    pub query: String,
+}
+
+/// One page worth of rows requested from a data source.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PageRequest {
+    /// Zero based index of the first row to return.
+    pub offset: usize,
+    /// Maximum number of rows to return.
+    pub limit: usize,
+    /// Sort descriptors applied in order of precedence.
+    pub sort: Vec<SortDescriptor>,
+    /// Filter descriptors combined with logical AND.
+    pub filters: Vec<FilterDescriptor>,
+}
+
+impl Default for PageRequest {
+    fn default() -> Self {
+        Self {
+            offset: 0,
+            limit: 50,
+            sort: Vec::new(),
+            filters: Vec::new(),
+        }
+    }
+}
+
+/// Rows returned by a data source together with the filtered total.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PageResponse<T> {
+    /// Rows for the requested window.
+    pub rows: Vec<T>,
+    /// Total row count after filtering, across all pages.
+    pub total: usize,
+}
+
+/// Failure reported by a data source, surfaced to retry affordances.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DataSourceError(pub String);
+
+impl fmt::Display for DataSourceError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "data source error: {}", self.0)
+    }
+}
+
+impl std::error::Error for DataSourceError {}
+
+/// Contract implemented by anything that can back a grid with pages of rows.
+pub trait GridDataSource<T> {
+    /// Fetches the rows described by `request` and the filtered total count.
+    fn fetch_page(&mut self, request: &PageRequest) -> Result<PageResponse<T>, DataSourceError>;
+}
+
+/// Reference [`GridDataSource`] applying descriptors against a local `Vec`.
+pub struct InMemoryDataSource<T> {
+    rows: Vec<T>,
+    columns: Vec<ColumnDef<T>>,
+}
+
+impl<T: Clone> InMemoryDataSource<T> {
+    /// Creates a source over the given rows and typed column set.
+    pub fn new(rows: Vec<T>, columns: Vec<ColumnDef<T>>) -> Self {
+        Self { rows, columns }
+    }
+
+    fn column(&self, id: &str) -> Option<&ColumnDef<T>> {
+        self.columns.iter().find(|column| column.id == id)
+    }
+}
+
+impl<T: Clone> GridDataSource<T> for InMemoryDataSource<T> {
+    fn fetch_page(&mut self, request: &PageRequest) -> Result<PageResponse<T>, DataSourceError> {
+        let mut matching: Vec<&T> = Vec::new();
+        'rows: for row in &self.rows {
+            for filter in &request.filters {
+                let column = self.column(&filter.column).ok_or_else(|| {
+                    DataSourceError(format!("unknown column `{}`", filter.column))
+                })?;
+                let value = column.value(row).to_lowercase();
+                if !value.contains(&filter.query.to_lowercase()) {
+                    continue 'rows;
+                }
+            }
+            matching.push(row);
+        }
+
+        // Apply descriptors in reverse so the first one wins precedence; the
+        // sort is stable which keeps later descriptors as tie breakers.
+        for descriptor in request.sort.iter().rev() {
+            let column = self.column(&descriptor.column).ok_or_else(|| {
+                DataSourceError(format!("unknown column `{}`", descriptor.column))
+            })?;
+            if !column.is_sortable() {
+                return Err(DataSourceError(format!(
+                    "column `{}` is not sortable",
+                    descriptor.column
+                )));
+            }
+            matching.sort_by(|a, b| {
+                let ordering = column.compare(a, b).expect("sortable column");
+                match descriptor.direction {
+                    SortDirection::Ascending => ordering,
+                    SortDirection::Descending => ordering.reverse(),
+                }
+            });
+        }
+
+        let total = matching.len();
+        let rows = matching
+            .into_iter()
+            .skip(request.offset)
+            .take(request.limit)
+            .cloned()
+            .collect();
+        Ok(PageResponse { rows, total })
+    }
+}
+
+/// Lifecycle of the most recent page fetch.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FetchStatus {
+    /// No fetch has been issued yet.
+    Idle,
+    /// A fetch is in flight; render the loading overlay.
+    Loading,
+    /// The last fetch succeeded and `rows`/`total` are current.
+    Loaded,
+    /// The last fetch failed; offer a retry affordance.
+    Failed,
+}
+
+/// Split-phase fetch machine connecting the grid to a [`GridDataSource`].
+#[derive(Debug)]
+pub struct GridFetchMachine<T> {
+    request: PageRequest,
+    status: FetchStatus,
+    rows: Vec<T>,
+    total: usize,
+    error: Option<DataSourceError>,
+    generation: u64,
+}
+
+impl<T> Default for GridFetchMachine<T> {
+    fn default() -> Self {
+        Self::new(PageRequest::default())
+    }
+}
+
+impl<T> GridFetchMachine<T> {
+    /// Creates the machine with an initial page request.
+    pub fn new(request: PageRequest) -> Self {
+        Self {
+            request,
+            status: FetchStatus::Idle,
+            rows: Vec::new(),
+            total: 0,
+            error: None,
+            generation: 0,
+        }
+    }
+
+    /// Current lifecycle phase, driving overlays and retry buttons.
+    #[inline]
+    pub fn status(&self) -> FetchStatus {
+        self.status
+    }
+
+    /// Rows from the most recent successful fetch.
+    #[inline]
+    pub fn rows(&self) -> &[T] {
+        &self.rows
+    }
+
+    /// Filtered total reported by the most recent successful fetch.
+    #[inline]
+    pub fn total(&self) -> usize {
+        self.total
+    }
+
+    /// Error from the most recent failed fetch, cleared on the next success.
+    #[inline]
+    pub fn error(&self) -> Option<&DataSourceError> {
+        self.error.as_ref()
+    }
+
+    /// Replaces the page request and begins a new fetch.
+    ///
+    /// Returns the generation plus a clone of the request for the caller to
+    /// execute; pass the generation back to [`resolve`](Self::resolve) or
+    /// [`reject`](Self::reject).
+    pub fn request_page(&mut self, request: PageRequest) -> (u64, PageRequest) {
+        self.request = request;
+        self.begin()
+    }
+
+    /// Re-issues the current request, typically from a retry button.
+    pub fn retry(&mut self) -> (u64, PageRequest) {
+        self.begin()
+    }
+
+    fn begin(&mut self) -> (u64, PageRequest) {
+        self.generation += 1;
+        self.status = FetchStatus::Loading;
+        (self.generation, self.request.clone())
+    }
+
+    /// Accepts a successful response for the given generation.
+    ///
+    /// Responses from superseded generations are ignored so slow requests can
+    /// never overwrite newer pages.
+    pub fn resolve(&mut self, generation: u64, response: PageResponse<T>) {
+        if generation != self.generation {
+            return;
+        }
+        self.rows = response.rows;
+        self.total = response.total;
+        self.error = None;
+        self.status = FetchStatus::Loaded;
+    }
+
+    /// Records a failed fetch for the given generation.
+    pub fn reject(&mut self, generation: u64, error: DataSourceError) {
+        if generation != self.generation {
+            return;
+        }
+        self.error = Some(error);
+        self.status = FetchStatus::Failed;
+    }
+
+    /// Convenience for synchronous sources: begins the fetch and immediately
+    /// feeds the outcome back into the machine.
+    pub fn fetch_from(&mut self, source: &mut impl GridDataSource<T>) {
+        let (generation, request) = self.begin();
+        match source.fetch_page(&request) {
+            Ok(response) => self.resolve(generation, response),
+            Err(error) => self.reject(generation, error),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Clone, Debug, PartialEq)]
+    struct City {
+        name: String,
+        population: u32,
+    }
+
+    fn cities() -> Vec<City> {
+        [
+            ("Lyon", 522_000),
+            ("Lisbon", 545_000),
+            ("Berlin", 3_700_000),
+            ("Lille", 236_000),
+        ]
+        .into_iter()
+        .map(|(name, population)| City {
+            name: name.into(),
+            population,
+        })
+        .collect()
+    }
+
+    fn source() -> InMemoryDataSource<City> {
+        let columns = crate::columns!(City => [
+            name {
+                header: "Name",
+                value: |row| row.name.clone(),
+                sort: |a, b| a.name.cmp(&b.name),
+            },
+            population {
+                header: "Population",
+                value: |row| row.population.to_string(),
+                sort: |a, b| a.population.cmp(&b.population),
+            },
+        ]);
+        InMemoryDataSource::new(cities(), columns)
+    }
+
+    #[test]
+    fn filters_sorts_and_pages_while_reporting_the_total() {
+        let mut source = source();
+        let response = source
+            .fetch_page(&PageRequest {
+                offset: 1,
+                limit: 1,
+                sort: vec![SortDescriptor {
+                    column: "population".into(),
+                    direction: SortDirection::Descending,
+                }],
+                filters: vec![FilterDescriptor {
+                    column: "name".into(),
+                    query: "li".into(),
+                }],
+            })
+            .unwrap();
+        // "li" matches Lisbon, Berlin and Lille; descending population puts
+        // Berlin first, so page two of size one is Lisbon.
+        assert_eq!(response.total, 3);
+        assert_eq!(response.rows.len(), 1);
+        assert_eq!(response.rows[0].name, "Lisbon");
+    }
+
+    #[test]
+    fn unknown_columns_surface_as_errors() {
+        let mut source = source();
+        let error = source
+            .fetch_page(&PageRequest {
+                filters: vec![FilterDescriptor {
+                    column: "mayor".into(),
+                    query: "a".into(),
+                }],
+                ..PageRequest::default()
+            })
+            .unwrap_err();
+        assert!(error.to_string().contains("unknown column `mayor`"));
+    }
+
+    #[test]
+    fn machine_tracks_loading_success_and_retry_after_failure() {
+        let mut machine = GridFetchMachine::new(PageRequest::default());
+        assert_eq!(machine.status(), FetchStatus::Idle);
+
+        let (generation, _request) = machine.retry();
+        assert_eq!(machine.status(), FetchStatus::Loading);
+        machine.reject(generation, DataSourceError("503".into()));
+        assert_eq!(machine.status(), FetchStatus::Failed);
+        assert!(machine.error().is_some());
+
+        let mut source = source();
+        machine.fetch_from(&mut source);
+        assert_eq!(machine.status(), FetchStatus::Loaded);
+        assert_eq!(machine.total(), 4);
+        assert!(machine.error().is_none());
+    }
+
+    #[test]
+    fn stale_generations_never_clobber_newer_pages() {
+        let mut machine = GridFetchMachine::<City>::new(PageRequest::default());
+        let (stale, _) = machine.retry();
+        let (fresh, _) = machine.retry();
+        machine.resolve(
+            fresh,
+            PageResponse {
+                rows: cities(),
+                total: 4,
+            },
+        );
+        machine.resolve(
+            stale,
+            PageResponse {
+                rows: Vec::new(),
+                total: 0,
+            },
+        );
+        assert_eq!(machine.status(), FetchStatus::Loaded);
+        assert_eq!(machine.total(), 4);
+    }
+}
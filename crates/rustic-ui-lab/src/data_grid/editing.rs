@@ -0,0 +1,322 @@
+//! Inline cell editing machine.
+//!
+//! Spreadsheet style grids let users double-click a cell, type a replacement
+//! value and either commit with `Enter` or abandon with `Escape`.
+//! [`EditingState`] owns that lifecycle: the active cell is backed by the
+//! shared [`TextFieldState`] machine (select driven cells follow the same
+//! commit/cancel contract through their own machines), validation failures
+//! pin an error message to the cell without losing the draft, and committed
+//! values accumulate in a per-row changeset so applications can batch-save
+//! everything the user touched in one request.
+//!
+//! The machine is renderer agnostic and identifies cells by `(row id,
+//! column id)` string pairs; callers hand in the current display value when
+//! an edit starts, typically via [`ColumnDef::value`](super::ColumnDef::value).
+
+use std::collections::HashMap;
+
+use rustic_ui_headless::text_field::TextFieldState;
+
+/// Validates a draft before it is committed.
+///
+/// Receives the column id and the draft value; returning `Err` keeps the
+/// editor open with the message surfaced on the cell.
+pub type CellValidator = fn(&str, &str) -> Result<(), String>;
+
+/// All committed edits for one row, ready for batch saving.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EditedRow {
+    /// Row identifier handed to [`EditingState::begin_edit`].
+    pub row_id: String,
+    /// `(column id, committed value)` pairs in commit order.
+    pub cells: Vec<(String, String)>,
+}
+
+#[derive(Debug)]
+struct ActiveEdit {
+    row_id: String,
+    column_id: String,
+    original: String,
+    field: TextFieldState,
+}
+
+/// Per-cell edit lifecycle and changeset accumulator.
+#[derive(Debug, Default)]
+pub struct EditingState {
+    validator: Option<CellValidator>,
+    active: Option<ActiveEdit>,
+    /// Committed values keyed by `(row id, column id)`.
+    changes: HashMap<(String, String), String>,
+    /// Validation messages keyed by `(row id, column id)`.
+    errors: HashMap<(String, String), String>,
+    /// Keys in first-commit order so changesets stay deterministic.
+    change_order: Vec<(String, String)>,
+}
+
+impl EditingState {
+    /// Creates a machine without validation.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Attaches a validator consulted on every commit.
+    pub fn with_validator(mut self, validator: CellValidator) -> Self {
+        self.validator = Some(validator);
+        self
+    }
+
+    /// Starts editing a cell.
+    ///
+    /// `current` is the unedited value from the column accessor; when the
+    /// cell already carries a pending change the editor seeds from that
+    /// change instead so users continue where they left off. Typing the
+    /// unedited value back drops the pending change entirely. Any other
+    /// active edit is cancelled first.
+    pub fn begin_edit(&mut self, row_id: &str, column_id: &str, current: impl Into<String>) {
+        let original = current.into();
+        let seed = self
+            .pending_value(row_id, column_id)
+            .map(str::to_string)
+            .unwrap_or_else(|| original.clone());
+        self.active = Some(ActiveEdit {
+            row_id: row_id.to_string(),
+            column_id: column_id.to_string(),
+            original,
+            field: TextFieldState::uncontrolled(seed, None),
+        });
+    }
+
+    /// `(row id, column id)` of the cell currently in edit mode.
+    pub fn active_cell(&self) -> Option<(&str, &str)> {
+        self.active
+            .as_ref()
+            .map(|edit| (edit.row_id.as_str(), edit.column_id.as_str()))
+    }
+
+    /// Draft value of the active editor, if any.
+    pub fn draft(&self) -> Option<&str> {
+        self.active.as_ref().map(|edit| edit.field.value())
+    }
+
+    /// Backing text field machine for the active editor, letting adapters
+    /// reuse its dirty/visited attributes on the rendered `<input>`.
+    pub fn active_field(&self) -> Option<&TextFieldState> {
+        self.active.as_ref().map(|edit| &edit.field)
+    }
+
+    /// Replaces the active editor's draft as the user types.
+    pub fn update_draft(&mut self, value: impl Into<String>) {
+        if let Some(edit) = self.active.as_mut() {
+            edit.field.change(value, |_| {});
+        }
+    }
+
+    /// Commits the active draft.
+    ///
+    /// Returns `true` when the edit mode closed: either the value passed
+    /// validation and entered the changeset, or it matched the original and
+    /// the pending change was dropped. A validation failure keeps the editor
+    /// open, records the message on the cell and returns `false`.
+    pub fn commit(&mut self) -> bool {
+        let Some(mut edit) = self.active.take() else {
+            return false;
+        };
+        let value = edit.field.value().to_string();
+        let key = (edit.row_id.clone(), edit.column_id.clone());
+        if let Some(validator) = self.validator {
+            if let Err(message) = validator(&edit.column_id, &value) {
+                edit.field.set_errors(vec![message.clone()]);
+                self.errors.insert(key, message);
+                self.active = Some(edit);
+                return false;
+            }
+        }
+        self.errors.remove(&key);
+        if value == edit.original && !self.changes.contains_key(&key) {
+            return true;
+        }
+        if value == edit.original {
+            self.changes.remove(&key);
+            self.change_order.retain(|existing| *existing != key);
+            return true;
+        }
+        if !self.changes.contains_key(&key) {
+            self.change_order.push(key.clone());
+        }
+        self.changes.insert(key, value);
+        true
+    }
+
+    /// Abandons the active draft, keeping any previously committed value.
+    pub fn cancel(&mut self) {
+        if let Some(edit) = self.active.take() {
+            self.errors.remove(&(edit.row_id, edit.column_id));
+        }
+    }
+
+    /// Applies the standard editing keyboard vocabulary.
+    ///
+    /// `key` uses DOM `KeyboardEvent::key` values: `Enter` commits and
+    /// `Escape` cancels. Returns `true` when the key was handled.
+    pub fn handle_key(&mut self, key: &str) -> bool {
+        match key {
+            "Enter" => {
+                self.commit();
+                true
+            }
+            "Escape" => {
+                self.cancel();
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Committed-but-unsaved value for a cell, shown instead of the accessor
+    /// output until the changeset is saved.
+    pub fn pending_value(&self, row_id: &str, column_id: &str) -> Option<&str> {
+        self.changes
+            .get(&(row_id.to_string(), column_id.to_string()))
+            .map(String::as_str)
+    }
+
+    /// Validation message pinned to a cell by the last failed commit.
+    pub fn error(&self, row_id: &str, column_id: &str) -> Option<&str> {
+        self.errors
+            .get(&(row_id.to_string(), column_id.to_string()))
+            .map(String::as_str)
+    }
+
+    /// Whether any committed edits await batch saving.
+    pub fn has_changes(&self) -> bool {
+        !self.changes.is_empty()
+    }
+
+    /// Snapshot of every committed edit grouped per row, in first-commit
+    /// order, for batch saving.
+    pub fn changeset(&self) -> Vec<EditedRow> {
+        let mut rows: Vec<EditedRow> = Vec::new();
+        for key in &self.change_order {
+            let (row_id, column_id) = key;
+            let value = self.changes[key].clone();
+            match rows.iter_mut().find(|row| row.row_id == *row_id) {
+                Some(row) => row.cells.push((column_id.clone(), value)),
+                None => rows.push(EditedRow {
+                    row_id: row_id.clone(),
+                    cells: vec![(column_id.clone(), value)],
+                }),
+            }
+        }
+        rows
+    }
+
+    /// Drops all committed edits, typically after a successful batch save.
+    pub fn clear_changes(&mut self) {
+        self.changes.clear();
+        self.change_order.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn numeric_amounts(column: &str, value: &str) -> Result<(), String> {
+        if column == "amount" && value.parse::<f64>().is_err() {
+            Err("enter a number".to_string())
+        } else {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn commit_records_the_change_and_cancel_discards_it() {
+        let mut state = EditingState::new();
+        state.begin_edit("r1", "name", "Alice");
+        state.update_draft("Alicia");
+        assert!(state.commit());
+        assert_eq!(state.pending_value("r1", "name"), Some("Alicia"));
+
+        state.begin_edit("r1", "name", "Alice");
+        state.update_draft("Bob");
+        state.cancel();
+        assert_eq!(state.pending_value("r1", "name"), Some("Alicia"));
+    }
+
+    #[test]
+    fn reverting_to_the_original_drops_the_pending_change() {
+        let mut state = EditingState::new();
+        state.begin_edit("r1", "name", "Alice");
+        state.update_draft("Alicia");
+        state.commit();
+        // Re-opening the edited cell seeds the draft from the pending value;
+        // confirming it keeps the change recorded.
+        state.begin_edit("r1", "name", "Alice");
+        assert_eq!(state.draft(), Some("Alicia"));
+        state.commit();
+        assert!(state.has_changes());
+
+        // Typing the unedited value back removes the change entirely.
+        state.begin_edit("r1", "name", "Alice");
+        state.update_draft("Alice");
+        assert!(state.commit());
+        assert!(!state.has_changes());
+    }
+
+    #[test]
+    fn validation_failures_keep_the_editor_open_with_a_cell_error() {
+        let mut state = EditingState::new().with_validator(numeric_amounts);
+        state.begin_edit("r1", "amount", "100");
+        state.update_draft("lots");
+        assert!(!state.commit());
+        assert_eq!(state.active_cell(), Some(("r1", "amount")));
+        assert_eq!(state.error("r1", "amount"), Some("enter a number"));
+        assert_eq!(state.active_field().unwrap().errors().len(), 1);
+
+        state.update_draft("250");
+        assert!(state.commit());
+        assert_eq!(state.error("r1", "amount"), None);
+        assert_eq!(state.pending_value("r1", "amount"), Some("250"));
+    }
+
+    #[test]
+    fn keyboard_vocabulary_commits_and_cancels() {
+        let mut state = EditingState::new();
+        state.begin_edit("r1", "name", "Alice");
+        state.update_draft("Alicia");
+        assert!(state.handle_key("Enter"));
+        assert_eq!(state.active_cell(), None);
+        assert_eq!(state.pending_value("r1", "name"), Some("Alicia"));
+
+        state.begin_edit("r2", "name", "Bob");
+        assert!(state.handle_key("Escape"));
+        assert_eq!(state.active_cell(), None);
+        assert!(!state.handle_key("F2"));
+    }
+
+    #[test]
+    fn changeset_groups_cells_per_row_for_batch_saving() {
+        let mut state = EditingState::new();
+        for (row, column, value) in [
+            ("r1", "name", "Alicia"),
+            ("r2", "amount", "50"),
+            ("r1", "amount", "75"),
+        ] {
+            state.begin_edit(row, column, "");
+            state.update_draft(value);
+            state.commit();
+        }
+        let changeset = state.changeset();
+        assert_eq!(changeset.len(), 2);
+        assert_eq!(changeset[0].row_id, "r1");
+        assert_eq!(
+            changeset[0].cells,
+            vec![
+                ("name".to_string(), "Alicia".to_string()),
+                ("amount".to_string(), "75".to_string()),
+            ]
+        );
+        state.clear_changes();
+        assert!(!state.has_changes());
+    }
+}
@@ -0,0 +1,358 @@
+//! Row grouping and per-group aggregation.
+//!
+//! Enterprise reports group rows by one or more columns (region, then
+//! quarter) and summarize each bucket with aggregates such as totals or
+//! averages.  [`GroupingState`] owns the group-by configuration plus the
+//! collapsed/expanded bookkeeping, and [`snapshot`](GroupingState::snapshot)
+//! flattens the grid into the exact sequence of group headers and leaf rows a
+//! renderer should paint — collapsed subtrees are omitted from the snapshot
+//! while their aggregates keep reflecting every underlying row, so summary
+//! numbers never change when a manager folds a section away.
+//!
+//! Group values come from the typed [`ColumnDef`] accessors, which keeps the
+//! bucketing consistent with what users see in the cells; aggregates parse
+//! the same accessor output as numbers and silently skip non-numeric cells.
+
+use std::collections::HashSet;
+
+use super::{ColumnDef, DataGrid};
+
+/// Aggregate function computed per group.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Aggregate {
+    /// Number of rows in the group.
+    Count,
+    /// Sum of the parsed numeric values in the group.
+    Sum,
+    /// Arithmetic mean of the parsed numeric values in the group.
+    Avg,
+}
+
+/// Requests an aggregate over a column's values.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AggregateDescriptor {
+    /// Column identifier as declared through [`columns!`](crate::columns).
+    pub column: String,
+    /// Function applied to the group's values.
+    pub function: Aggregate,
+}
+
+/// Computed aggregate carried on each group header.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AggregateResult {
+    /// Column the aggregate was computed over.
+    pub column: String,
+    /// Function that produced the value.
+    pub function: Aggregate,
+    /// Resulting value; counts are reported as whole numbers.
+    pub value: f64,
+}
+
+/// Header emitted at the start of every group in a snapshot.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GroupHeader {
+    /// Group values from the outermost level down to this group; also the
+    /// key passed to [`GroupingState::toggle_group`].
+    pub path: Vec<String>,
+    /// Nesting depth, zero based, for indentation.
+    pub depth: usize,
+    /// Display value of this group level.
+    pub label: String,
+    /// Number of leaf rows in the subtree.
+    pub count: usize,
+    /// Aggregates computed over every leaf row in the subtree.
+    pub aggregates: Vec<AggregateResult>,
+    /// Whether the subtree is currently collapsed.
+    pub collapsed: bool,
+}
+
+/// One entry in a grouped snapshot, painted top to bottom.
+#[derive(Debug)]
+pub enum SnapshotRow<'a, T> {
+    /// Start of a group; render the label, count and aggregates.
+    Group(GroupHeader),
+    /// Plain data row belonging to the most recent expanded groups.
+    Leaf(&'a T),
+}
+
+/// Group-by configuration and collapse state for a grid.
+#[derive(Debug, Clone, Default)]
+pub struct GroupingState {
+    group_by: Vec<String>,
+    aggregates: Vec<AggregateDescriptor>,
+    collapsed: HashSet<Vec<String>>,
+}
+
+impl GroupingState {
+    /// Creates an empty state: no grouping, no aggregates.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Replaces the group-by columns, outermost first.
+    ///
+    /// Collapse state is cleared because paths from the previous
+    /// configuration are meaningless under the new bucketing.
+    pub fn set_group_by(&mut self, columns: Vec<String>) {
+        self.group_by = columns;
+        self.collapsed.clear();
+    }
+
+    /// Currently grouped column ids, outermost first.
+    pub fn group_by(&self) -> &[String] {
+        &self.group_by
+    }
+
+    /// Replaces the aggregate descriptors evaluated per group.
+    pub fn set_aggregates(&mut self, aggregates: Vec<AggregateDescriptor>) {
+        self.aggregates = aggregates;
+    }
+
+    /// Collapses or expands the group identified by its value path.
+    pub fn toggle_group(&mut self, path: &[String]) {
+        if !self.collapsed.remove(path) {
+            self.collapsed.insert(path.to_vec());
+        }
+    }
+
+    /// Whether the group identified by `path` is collapsed.
+    pub fn is_collapsed(&self, path: &[String]) -> bool {
+        self.collapsed.contains(path)
+    }
+
+    /// Flattens the grid into group headers and visible leaf rows.
+    ///
+    /// Without group-by columns every row is emitted as a leaf.  Group
+    /// buckets preserve first-appearance order so the snapshot is stable
+    /// under whatever sort the grid already applied.
+    pub fn snapshot<'a, T>(
+        &self,
+        grid: &'a DataGrid<T>,
+        columns: &[ColumnDef<T>],
+    ) -> Vec<SnapshotRow<'a, T>> {
+        let mut out = Vec::new();
+        let rows: Vec<&T> = grid.rows.iter().collect();
+        self.emit(&rows, columns, 0, &mut Vec::new(), &mut out);
+        out
+    }
+
+    fn emit<'a, T>(
+        &self,
+        rows: &[&'a T],
+        columns: &[ColumnDef<T>],
+        depth: usize,
+        path: &mut Vec<String>,
+        out: &mut Vec<SnapshotRow<'a, T>>,
+    ) {
+        let Some(group_id) = self.group_by.get(depth) else {
+            out.extend(rows.iter().map(|row| SnapshotRow::Leaf(*row)));
+            return;
+        };
+        let Some(column) = columns.iter().find(|column| column.id == *group_id) else {
+            // Unknown group column: fall back to ungrouped leaves rather
+            // than silently bucketing everything under one label.
+            out.extend(rows.iter().map(|row| SnapshotRow::Leaf(*row)));
+            return;
+        };
+
+        // Bucket rows by group value, preserving first-appearance order.
+        let mut buckets: Vec<(String, Vec<&'a T>)> = Vec::new();
+        for row in rows {
+            let value = column.value(row);
+            match buckets.iter_mut().find(|(label, _)| *label == value) {
+                Some((_, bucket)) => bucket.push(row),
+                None => buckets.push((value, vec![row])),
+            }
+        }
+
+        for (label, bucket) in buckets {
+            path.push(label.clone());
+            let collapsed = self.is_collapsed(path);
+            out.push(SnapshotRow::Group(GroupHeader {
+                path: path.clone(),
+                depth,
+                label,
+                count: bucket.len(),
+                aggregates: self.compute_aggregates(&bucket, columns),
+                collapsed,
+            }));
+            if !collapsed {
+                self.emit(&bucket, columns, depth + 1, path, out);
+            }
+            path.pop();
+        }
+    }
+
+    fn compute_aggregates<T>(&self, rows: &[&T], columns: &[ColumnDef<T>]) -> Vec<AggregateResult> {
+        self.aggregates
+            .iter()
+            .filter_map(|descriptor| {
+                let column = columns
+                    .iter()
+                    .find(|column| column.id == descriptor.column)?;
+                let values: Vec<f64> = rows
+                    .iter()
+                    .filter_map(|row| column.value(row).parse::<f64>().ok())
+                    .collect();
+                let value = match descriptor.function {
+                    Aggregate::Count => rows.len() as f64,
+                    Aggregate::Sum => values.iter().sum(),
+                    Aggregate::Avg => {
+                        if values.is_empty() {
+                            0.0
+                        } else {
+                            values.iter().sum::<f64>() / values.len() as f64
+                        }
+                    }
+                };
+                Some(AggregateResult {
+                    column: descriptor.column.clone(),
+                    function: descriptor.function,
+                    value,
+                })
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Clone)]
+    struct Sale {
+        region: String,
+        quarter: String,
+        amount: u32,
+    }
+
+    fn sales() -> DataGrid<Sale> {
+        let rows = [
+            ("EMEA", "Q1", 100),
+            ("EMEA", "Q2", 300),
+            ("APAC", "Q1", 50),
+            ("EMEA", "Q1", 200),
+        ]
+        .into_iter()
+        .map(|(region, quarter, amount)| Sale {
+            region: region.into(),
+            quarter: quarter.into(),
+            amount,
+        })
+        .collect();
+        DataGrid::new(rows)
+    }
+
+    fn columns() -> Vec<ColumnDef<Sale>> {
+        crate::columns!(Sale => [
+            region { header: "Region", value: |row| row.region.clone() },
+            quarter { header: "Quarter", value: |row| row.quarter.clone() },
+            amount { header: "Amount", value: |row| row.amount.to_string() },
+        ])
+    }
+
+    fn grouped_state() -> GroupingState {
+        let mut state = GroupingState::new();
+        state.set_group_by(vec!["region".into()]);
+        state.set_aggregates(vec![
+            AggregateDescriptor {
+                column: "amount".into(),
+                function: Aggregate::Sum,
+            },
+            AggregateDescriptor {
+                column: "amount".into(),
+                function: Aggregate::Avg,
+            },
+            AggregateDescriptor {
+                column: "region".into(),
+                function: Aggregate::Count,
+            },
+        ]);
+        state
+    }
+
+    #[test]
+    fn groups_preserve_row_order_and_compute_aggregates() {
+        let grid = sales();
+        let state = grouped_state();
+        let snapshot = state.snapshot(&grid, &columns());
+
+        let SnapshotRow::Group(emea) = &snapshot[0] else {
+            panic!("expected a group header first");
+        };
+        assert_eq!(emea.label, "EMEA");
+        assert_eq!(emea.count, 3);
+        assert_eq!(emea.aggregates[0].value, 600.0); // sum
+        assert_eq!(emea.aggregates[1].value, 200.0); // avg
+        assert_eq!(emea.aggregates[2].value, 3.0); // count
+                                                   // Three EMEA leaves follow, then the APAC header and its leaf.
+        assert!(matches!(snapshot[1], SnapshotRow::Leaf(_)));
+        assert_eq!(snapshot.len(), 6);
+        let SnapshotRow::Group(apac) = &snapshot[4] else {
+            panic!("expected the APAC header");
+        };
+        assert_eq!(apac.label, "APAC");
+        assert_eq!(apac.aggregates[0].value, 50.0);
+    }
+
+    #[test]
+    fn collapsing_hides_leaves_but_keeps_aggregates() {
+        let grid = sales();
+        let mut state = grouped_state();
+        state.toggle_group(&["EMEA".to_string()]);
+        let snapshot = state.snapshot(&grid, &columns());
+
+        // EMEA header (collapsed), APAC header, APAC leaf.
+        assert_eq!(snapshot.len(), 3);
+        let SnapshotRow::Group(emea) = &snapshot[0] else {
+            panic!("expected the EMEA header");
+        };
+        assert!(emea.collapsed);
+        assert_eq!(emea.aggregates[0].value, 600.0);
+
+        state.toggle_group(&["EMEA".to_string()]);
+        assert_eq!(state.snapshot(&grid, &columns()).len(), 6);
+    }
+
+    #[test]
+    fn nested_group_by_produces_indented_headers() {
+        let grid = sales();
+        let mut state = grouped_state();
+        state.set_group_by(vec!["region".into(), "quarter".into()]);
+        let snapshot = state.snapshot(&grid, &columns());
+
+        let headers: Vec<(&str, usize, usize)> = snapshot
+            .iter()
+            .filter_map(|row| match row {
+                SnapshotRow::Group(header) => {
+                    Some((header.label.as_str(), header.depth, header.count))
+                }
+                SnapshotRow::Leaf(_) => None,
+            })
+            .collect();
+        assert_eq!(
+            headers,
+            vec![
+                ("EMEA", 0, 3),
+                ("Q1", 1, 2),
+                ("Q2", 1, 1),
+                ("APAC", 0, 1),
+                ("Q1", 1, 1),
+            ]
+        );
+        // Collapsing an inner group only hides its own leaves.
+        state.toggle_group(&["EMEA".to_string(), "Q1".to_string()]);
+        assert_eq!(state.snapshot(&grid, &columns()).len(), 7);
+    }
+
+    #[test]
+    fn without_group_by_every_row_is_a_leaf() {
+        let grid = sales();
+        let state = GroupingState::new();
+        let snapshot = state.snapshot(&grid, &columns());
+        assert_eq!(snapshot.len(), 4);
+        assert!(snapshot
+            .iter()
+            .all(|row| matches!(row, SnapshotRow::Leaf(_))));
+    }
+}
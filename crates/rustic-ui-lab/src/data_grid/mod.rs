@@ -0,0 +1,296 @@
+//! Minimal in-memory data grid.
+//!
+//! The goal of this experimental API is to provide a small, easily testable
+//! abstraction for manipulating tabular data. Rendering and virtualization are
+//! intentionally left to higher level crates so that this module can be reused
+//! across different UI frameworks or even server-side processing tools.
+//!
+//! Column metadata is described through [`ColumnDef`] and the companion
+//! [`columns!`](crate::columns) macro, which declares typed headers, accessors,
+//! formatters and sort comparators at compile time instead of the
+//! stringly-typed configuration maps common in JavaScript grids.
+//!
+//! The component is feature gated behind `data-grid` to avoid pulling it into
+//! applications that don't need it.
+
+use std::cmp::Ordering;
+
+pub mod column_manager;
+pub mod data_source;
+pub mod editing;
+pub mod export;
+pub mod grouping;
+pub mod tree;
+
+/// Generic grid storing rows of data.
+#[derive(Debug, Clone)]
+pub struct DataGrid<T> {
+    /// Rows backing the grid. In a real widget this would likely be a more
+    /// complex structure supporting pagination or virtualization.
+    pub rows: Vec<T>,
+}
+
+impl<T> DataGrid<T> {
+    /// Creates a new grid from a set of rows.
+    pub fn new(rows: Vec<T>) -> Self {
+        Self { rows }
+    }
+
+    /// Sorts the rows in place using the provided comparator.
+    pub fn sort_by<F>(&mut self, compare: F)
+    where
+        F: FnMut(&T, &T) -> std::cmp::Ordering,
+    {
+        self.rows.sort_by(compare);
+    }
+
+    /// Sorts the rows using a column's comparator.
+    ///
+    /// Returns `false` without touching the rows when the column did not
+    /// declare a `sort` comparator, so callers can ignore clicks on
+    /// unsortable headers.
+    pub fn sort_by_column(&mut self, column: &ColumnDef<T>) -> bool {
+        match column.comparator {
+            Some(compare) => {
+                self.rows.sort_by(compare);
+                true
+            }
+            None => false,
+        }
+    }
+}
+
+/// Typed description of a single grid column.
+///
+/// Instances are normally produced by the [`columns!`](crate::columns) macro
+/// rather than constructed by hand. The accessor extracts the raw cell value
+/// (used for export and as the display fallback) while the optional formatter
+/// decorates that value for presentation only.
+pub struct ColumnDef<T> {
+    /// Stable identifier derived from the column name in the macro.
+    pub id: &'static str,
+    /// Text rendered in the header cell.
+    pub header: &'static str,
+    accessor: fn(&T) -> String,
+    formatter: Option<fn(&str) -> String>,
+    comparator: Option<fn(&T, &T) -> Ordering>,
+    visible: bool,
+}
+
+impl<T> ColumnDef<T> {
+    /// Creates a column with the mandatory metadata.
+    pub fn new(id: &'static str, header: &'static str, accessor: fn(&T) -> String) -> Self {
+        Self {
+            id,
+            header,
+            accessor,
+            formatter: None,
+            comparator: None,
+            visible: true,
+        }
+    }
+
+    /// Attaches a display formatter applied on top of the raw value.
+    pub fn with_formatter(mut self, formatter: fn(&str) -> String) -> Self {
+        self.formatter = Some(formatter);
+        self
+    }
+
+    /// Attaches the comparator used by [`DataGrid::sort_by_column`].
+    pub fn with_comparator(mut self, comparator: fn(&T, &T) -> Ordering) -> Self {
+        self.comparator = Some(comparator);
+        self
+    }
+
+    /// Raw cell value for the given row.
+    pub fn value(&self, row: &T) -> String {
+        (self.accessor)(row)
+    }
+
+    /// Display value: the raw value run through the formatter when present.
+    pub fn display(&self, row: &T) -> String {
+        let raw = self.value(row);
+        match self.formatter {
+            Some(format) => format(&raw),
+            None => raw,
+        }
+    }
+
+    /// Whether the column declared a sort comparator.
+    pub fn is_sortable(&self) -> bool {
+        self.comparator.is_some()
+    }
+
+    /// Compares two rows with the declared comparator, if any.
+    pub fn compare(&self, a: &T, b: &T) -> Option<Ordering> {
+        self.comparator.map(|compare| compare(a, b))
+    }
+
+    /// Toggles whether the column participates in rendering and export.
+    ///
+    /// Columns start visible; user driven column management flips this flag
+    /// at runtime rather than rebuilding the column set.
+    pub fn set_visible(&mut self, visible: bool) {
+        self.visible = visible;
+    }
+
+    /// Whether the column is currently shown.
+    pub fn is_visible(&self) -> bool {
+        self.visible
+    }
+}
+
+impl<T> std::fmt::Debug for ColumnDef<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ColumnDef")
+            .field("id", &self.id)
+            .field("header", &self.header)
+            .field("sortable", &self.is_sortable())
+            .finish()
+    }
+}
+
+/// Declares the typed column set for a row type.
+///
+/// Each entry names the column, then lists `header` and `value` followed by
+/// the optional `format` and `sort` keys (in that order). All callbacks are
+/// plain function pointers so the declaration carries no hidden allocations
+/// and stays `const`-friendly for future compile time validation.
+///
+/// ```
+/// use rustic_ui_lab::columns;
+///
+/// struct Person {
+///     name: String,
+///     age: u32,
+/// }
+///
+/// let columns = columns!(Person => [
+///     name {
+///         header: "Name",
+///         value: |row| row.name.clone(),
+///         sort: |a, b| a.name.cmp(&b.name),
+///     },
+///     age {
+///         header: "Age",
+///         value: |row| row.age.to_string(),
+///         format: |raw| format!("{raw} yrs"),
+///         sort: |a, b| a.age.cmp(&b.age),
+///     },
+/// ]);
+/// assert_eq!(columns[1].id, "age");
+/// ```
+#[macro_export]
+macro_rules! columns {
+    ($row:ty => [ $( $id:ident {
+        header: $header:expr,
+        value: $value:expr
+        $(, format: $format:expr)?
+        $(, sort: $sort:expr)?
+        $(,)?
+    } ),* $(,)? ]) => {
+        vec![
+            $(
+                {
+                    #[allow(unused_mut)]
+                    let mut column = $crate::data_grid::ColumnDef::<$row>::new(
+                        stringify!($id),
+                        $header,
+                        $value,
+                    );
+                    $( column = column.with_formatter($format); )?
+                    $( column = column.with_comparator($sort); )?
+                    column
+                }
+            ),*
+        ]
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Person {
+        name: String,
+        age: u32,
+    }
+
+    fn people() -> Vec<Person> {
+        vec![
+            Person {
+                name: "Carol".into(),
+                age: 41,
+            },
+            Person {
+                name: "Alice".into(),
+                age: 34,
+            },
+            Person {
+                name: "Bob".into(),
+                age: 27,
+            },
+        ]
+    }
+
+    fn person_columns() -> Vec<ColumnDef<Person>> {
+        columns!(Person => [
+            name {
+                header: "Name",
+                value: |row| row.name.clone(),
+                sort: |a, b| a.name.cmp(&b.name),
+            },
+            age {
+                header: "Age",
+                value: |row| row.age.to_string(),
+                format: |raw| format!("{raw} yrs"),
+                sort: |a, b| a.age.cmp(&b.age),
+            },
+            notes {
+                header: "Notes",
+                value: |_| String::new(),
+            },
+        ])
+    }
+
+    #[test]
+    fn sort_by_orders_rows() {
+        let mut grid = DataGrid::new(vec![3, 1, 2]);
+        grid.sort_by(|a, b| a.cmp(b));
+        assert_eq!(grid.rows, vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn macro_declares_typed_columns() {
+        let columns = person_columns();
+        assert_eq!(columns.len(), 3);
+        assert_eq!(columns[0].id, "name");
+        assert_eq!(columns[0].header, "Name");
+        assert!(columns[0].is_sortable());
+        assert!(!columns[2].is_sortable());
+    }
+
+    #[test]
+    fn formatter_decorates_the_display_value_only() {
+        let columns = person_columns();
+        let row = &people()[0];
+        assert_eq!(columns[1].value(row), "41");
+        assert_eq!(columns[1].display(row), "41 yrs");
+        // Columns without a formatter fall back to the raw value.
+        assert_eq!(columns[0].display(row), "Carol");
+    }
+
+    #[test]
+    fn sort_by_column_honours_the_declared_comparator() {
+        let columns = person_columns();
+        let mut grid = DataGrid::new(people());
+        assert!(grid.sort_by_column(&columns[1]));
+        let ages: Vec<u32> = grid.rows.iter().map(|p| p.age).collect();
+        assert_eq!(ages, vec![27, 34, 41]);
+
+        // Unsortable columns leave the rows untouched.
+        assert!(!grid.sort_by_column(&columns[2]));
+        let ages: Vec<u32> = grid.rows.iter().map(|p| p.age).collect();
+        assert_eq!(ages, vec![27, 34, 41]);
+    }
+}
@@ -0,0 +1,329 @@
+//! Hierarchical (parent/child) row support.
+//!
+//! Org charts and file browsers render rows as a lazily loaded tree:
+//! expanding a parent reveals indented children which may themselves expand.
+//! [`TreeGridState`] owns the expansion bookkeeping and the cache of loaded
+//! children, fetching each parent's children at most once through a
+//! [`TreeDataSource`] so deep hierarchies never require loading the full
+//! data set up front.
+//!
+//! Row identity and expandability are supplied as plain accessor functions at
+//! construction time, matching the function-pointer style of the
+//! [`columns!`](crate::columns) macro.  [`snapshot`](TreeGridState::snapshot)
+//! flattens the visible tree into depth-annotated rows for renderers, and
+//! [`handle_key`](TreeGridState::handle_key) implements the standard
+//! ArrowRight/ArrowLeft/Enter expand-collapse vocabulary so keyboard support
+//! stays consistent with the stable tree components.
+
+use std::collections::{HashMap, HashSet};
+
+use super::data_source::DataSourceError;
+
+/// Supplies children for tree grids, one parent at a time.
+///
+/// `parent` is `None` for the root level.  Implementations back onto remote
+/// APIs in production; [`InMemoryTreeSource`] serves tests and demos.
+pub trait TreeDataSource<T> {
+    /// Fetches the direct children of `parent`.
+    fn fetch_children(&mut self, parent: Option<&str>) -> Result<Vec<T>, DataSourceError>;
+}
+
+/// Reference [`TreeDataSource`] serving children from a flat parent/child
+/// list.
+pub struct InMemoryTreeSource<T> {
+    rows: Vec<(Option<String>, T)>,
+}
+
+impl<T: Clone> InMemoryTreeSource<T> {
+    /// Creates a source from `(parent_id, row)` pairs; `None` marks roots.
+    pub fn new(rows: Vec<(Option<String>, T)>) -> Self {
+        Self { rows }
+    }
+}
+
+impl<T: Clone> TreeDataSource<T> for InMemoryTreeSource<T> {
+    fn fetch_children(&mut self, parent: Option<&str>) -> Result<Vec<T>, DataSourceError> {
+        Ok(self
+            .rows
+            .iter()
+            .filter(|(candidate, _)| candidate.as_deref() == parent)
+            .map(|(_, row)| row.clone())
+            .collect())
+    }
+}
+
+/// One visible row in a flattened tree snapshot.
+#[derive(Debug)]
+pub struct TreeRow<'a, T> {
+    /// The underlying data row.
+    pub row: &'a T,
+    /// Nesting depth, zero based, for indentation.
+    pub depth: usize,
+    /// Whether the row advertises children and renders a disclosure arrow.
+    pub expandable: bool,
+    /// Whether the row's children are currently visible.
+    pub expanded: bool,
+}
+
+/// Expansion state machine for hierarchical grids.
+pub struct TreeGridState<T> {
+    id_of: fn(&T) -> String,
+    has_children: fn(&T) -> bool,
+    roots: Vec<T>,
+    children: HashMap<String, Vec<T>>,
+    expanded: HashSet<String>,
+}
+
+impl<T> TreeGridState<T> {
+    /// Creates the machine with row identity and expandability accessors.
+    pub fn new(id_of: fn(&T) -> String, has_children: fn(&T) -> bool) -> Self {
+        Self {
+            id_of,
+            has_children,
+            roots: Vec::new(),
+            children: HashMap::new(),
+            expanded: HashSet::new(),
+        }
+    }
+
+    /// Loads (or reloads) the root level from the data source.
+    ///
+    /// Cached children and expansion state are cleared because row ids from
+    /// a previous data set may no longer exist.
+    pub fn load_roots(
+        &mut self,
+        source: &mut impl TreeDataSource<T>,
+    ) -> Result<(), DataSourceError> {
+        self.roots = source.fetch_children(None)?;
+        self.children.clear();
+        self.expanded.clear();
+        Ok(())
+    }
+
+    /// Whether the given row's children are currently visible.
+    pub fn is_expanded(&self, id: &str) -> bool {
+        self.expanded.contains(id)
+    }
+
+    /// Expands a row, lazily fetching its children on first use.
+    ///
+    /// Returns `true` when the state changed. Rows without children and
+    /// already expanded rows are no-ops.
+    pub fn expand(
+        &mut self,
+        id: &str,
+        source: &mut impl TreeDataSource<T>,
+    ) -> Result<bool, DataSourceError> {
+        if self.expanded.contains(id) {
+            return Ok(false);
+        }
+        let Some(row) = self.find_row(id) else {
+            return Ok(false);
+        };
+        if !(self.has_children)(row) {
+            return Ok(false);
+        }
+        if !self.children.contains_key(id) {
+            let children = source.fetch_children(Some(id))?;
+            self.children.insert(id.to_string(), children);
+        }
+        self.expanded.insert(id.to_string());
+        Ok(true)
+    }
+
+    /// Collapses a row, keeping its fetched children cached for the next
+    /// expansion. Returns `true` when the state changed.
+    pub fn collapse(&mut self, id: &str) -> bool {
+        self.expanded.remove(id)
+    }
+
+    /// Flips a row between expanded and collapsed.
+    pub fn toggle(
+        &mut self,
+        id: &str,
+        source: &mut impl TreeDataSource<T>,
+    ) -> Result<bool, DataSourceError> {
+        if self.is_expanded(id) {
+            Ok(self.collapse(id))
+        } else {
+            self.expand(id, source)
+        }
+    }
+
+    /// Applies the standard tree keyboard vocabulary to a focused row.
+    ///
+    /// `key` uses DOM `KeyboardEvent::key` values: `ArrowRight` expands,
+    /// `ArrowLeft` collapses and `Enter` or space toggles. Returns `true`
+    /// when the key was handled and the state changed.
+    pub fn handle_key(
+        &mut self,
+        id: &str,
+        key: &str,
+        source: &mut impl TreeDataSource<T>,
+    ) -> Result<bool, DataSourceError> {
+        match key {
+            "ArrowRight" => self.expand(id, source),
+            "ArrowLeft" => Ok(self.collapse(id)),
+            "Enter" | " " => self.toggle(id, source),
+            _ => Ok(false),
+        }
+    }
+
+    /// Flattens the visible tree into depth-annotated rows, top to bottom.
+    pub fn snapshot(&self) -> Vec<TreeRow<'_, T>> {
+        let mut out = Vec::new();
+        self.emit(&self.roots, 0, &mut out);
+        out
+    }
+
+    fn emit<'a>(&'a self, rows: &'a [T], depth: usize, out: &mut Vec<TreeRow<'a, T>>) {
+        for row in rows {
+            let id = (self.id_of)(row);
+            let expanded = self.is_expanded(&id);
+            out.push(TreeRow {
+                row,
+                depth,
+                expandable: (self.has_children)(row),
+                expanded,
+            });
+            if expanded {
+                if let Some(children) = self.children.get(&id) {
+                    self.emit(children, depth + 1, out);
+                }
+            }
+        }
+    }
+
+    fn find_row(&self, id: &str) -> Option<&T> {
+        self.roots
+            .iter()
+            .chain(self.children.values().flatten())
+            .find(|row| (self.id_of)(row) == id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Clone)]
+    struct Entry {
+        id: String,
+        directory: bool,
+    }
+
+    fn entry(id: &str, directory: bool) -> Entry {
+        Entry {
+            id: id.into(),
+            directory,
+        }
+    }
+
+    /// Wraps the in-memory source to count how often children are fetched.
+    struct CountingSource {
+        inner: InMemoryTreeSource<Entry>,
+        fetches: usize,
+    }
+
+    impl TreeDataSource<Entry> for CountingSource {
+        fn fetch_children(&mut self, parent: Option<&str>) -> Result<Vec<Entry>, DataSourceError> {
+            self.fetches += 1;
+            self.inner.fetch_children(parent)
+        }
+    }
+
+    fn file_system() -> CountingSource {
+        CountingSource {
+            inner: InMemoryTreeSource::new(vec![
+                (None, entry("src", true)),
+                (None, entry("README.md", false)),
+                (Some("src".into()), entry("lib.rs", false)),
+                (Some("src".into()), entry("data_grid", true)),
+                (Some("data_grid".into()), entry("mod.rs", false)),
+            ]),
+            fetches: 0,
+        }
+    }
+
+    fn state() -> TreeGridState<Entry> {
+        TreeGridState::new(|row| row.id.clone(), |row| row.directory)
+    }
+
+    #[test]
+    fn expansion_reveals_indented_children() {
+        let mut source = file_system();
+        let mut state = state();
+        state.load_roots(&mut source).unwrap();
+        assert_eq!(state.snapshot().len(), 2);
+
+        assert!(state.expand("src", &mut source).unwrap());
+        let rows: Vec<(String, usize)> = state
+            .snapshot()
+            .iter()
+            .map(|row| (row.row.id.clone(), row.depth))
+            .collect();
+        assert_eq!(
+            rows,
+            vec![
+                ("src".to_string(), 0),
+                ("lib.rs".to_string(), 1),
+                ("data_grid".to_string(), 1),
+                ("README.md".to_string(), 0),
+            ]
+        );
+    }
+
+    #[test]
+    fn children_are_fetched_lazily_and_cached() {
+        let mut source = file_system();
+        let mut state = state();
+        state.load_roots(&mut source).unwrap();
+        assert_eq!(source.fetches, 1);
+
+        // Leaves never hit the source.
+        assert!(!state.expand("README.md", &mut source).unwrap());
+        assert_eq!(source.fetches, 1);
+
+        state.expand("src", &mut source).unwrap();
+        assert_eq!(source.fetches, 2);
+        state.collapse("src");
+        state.expand("src", &mut source).unwrap();
+        assert_eq!(source.fetches, 2, "collapse keeps the cache warm");
+    }
+
+    #[test]
+    fn keyboard_vocabulary_expands_and_collapses() {
+        let mut source = file_system();
+        let mut state = state();
+        state.load_roots(&mut source).unwrap();
+
+        assert!(state.handle_key("src", "ArrowRight", &mut source).unwrap());
+        assert!(state.is_expanded("src"));
+        assert!(state.handle_key("src", "ArrowLeft", &mut source).unwrap());
+        assert!(!state.is_expanded("src"));
+        assert!(state.handle_key("src", "Enter", &mut source).unwrap());
+        assert!(state.is_expanded("src"));
+        assert!(!state.handle_key("src", "Escape", &mut source).unwrap());
+    }
+
+    #[test]
+    fn nested_directories_expand_independently() {
+        let mut source = file_system();
+        let mut state = state();
+        state.load_roots(&mut source).unwrap();
+        state.expand("src", &mut source).unwrap();
+        state.expand("data_grid", &mut source).unwrap();
+
+        let snapshot = state.snapshot();
+        let mod_rs = snapshot
+            .iter()
+            .find(|row| row.row.id == "mod.rs")
+            .expect("nested child visible");
+        assert_eq!(mod_rs.depth, 2);
+
+        // Collapsing the outer directory hides the inner one entirely.
+        state.collapse("src");
+        assert_eq!(state.snapshot().len(), 2);
+        assert!(state.is_expanded("data_grid"), "inner state is retained");
+    }
+}
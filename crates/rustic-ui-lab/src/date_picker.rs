@@ -49,3 +49,169 @@ impl<A: DateAdapter> DatePicker<A> {
         self.selected = self.adapter.add_days(&self.selected, delta);
     }
 }
+
+/// Preset ranges commonly exposed as one-click shortcuts next to a range
+/// calendar.  Keeping the set small mirrors the incremental philosophy of the
+/// lab crate; additional presets can be layered on by applications via
+/// [`DateRangePicker::select`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RangePreset {
+    /// The seven days ending today (inclusive).
+    LastSevenDays,
+    /// The first through the last day of the current month.
+    ThisMonth,
+}
+
+/// Range selection state machine layered on top of the same [`DateAdapter`]
+/// abstraction as [`DatePicker`].  The picker tracks an in-progress selection
+/// (start chosen, end pending), a hover preview of the span in between, the
+/// anchor month for a dual-month calendar layout and optional night count
+/// constraints so booking style UIs can reject too-short or too-long stays
+/// before any network round trip.
+pub struct DateRangePicker<A: DateAdapter> {
+    /// Adapter powering all date math and formatting.
+    pub adapter: A,
+    /// First day of the committed or in-progress range.
+    pub start: Option<A::Date>,
+    /// Last day of the committed range.  `None` while a selection is in
+    /// progress or nothing has been picked yet.
+    pub end: Option<A::Date>,
+    /// Day currently under the pointer, used to preview the prospective span
+    /// while the end date has not been committed.
+    pub hovered: Option<A::Date>,
+    /// First day of the leading visible month.  The trailing month of the
+    /// dual layout is always the following calendar month.
+    pub visible_month: A::Date,
+    /// Minimum number of nights (days between start and end) a range must
+    /// span to be accepted.
+    pub min_nights: Option<u32>,
+    /// Maximum number of nights a range may span.
+    pub max_nights: Option<u32>,
+}
+
+impl<A: DateAdapter> DateRangePicker<A> {
+    /// Creates an empty range picker anchored on the month containing
+    /// today's date.
+    pub fn new(adapter: A) -> Self {
+        let today = adapter.today();
+        let visible_month = adapter.start_of_month(&today);
+        Self {
+            adapter,
+            start: None,
+            end: None,
+            hovered: None,
+            visible_month,
+            min_nights: None,
+            max_nights: None,
+        }
+    }
+
+    /// Selects `date`, either starting a new range or attempting to close the
+    /// in-progress one.  Clicking a day before the pending start restarts the
+    /// selection from that day, matching the behavior users expect from
+    /// booking calendars.  Returns `false` when the resulting span violates
+    /// the night constraints, in which case the pending start is kept so the
+    /// user can try a different end date.
+    pub fn select(&mut self, date: A::Date) -> bool {
+        match (&self.start, &self.end) {
+            // Fresh selection or a completed range being replaced.
+            (None, _) | (Some(_), Some(_)) => {
+                self.start = Some(date);
+                self.end = None;
+                true
+            }
+            (Some(start), None) => {
+                let nights = self.adapter.days_between(start, &date);
+                if nights < 0 {
+                    self.start = Some(date);
+                    return true;
+                }
+                if !self.nights_allowed(nights as u32) {
+                    return false;
+                }
+                self.end = Some(date);
+                self.hovered = None;
+                true
+            }
+        }
+    }
+
+    /// Records the day under the pointer so [`Self::preview_span`] can
+    /// highlight the prospective range.
+    pub fn hover(&mut self, date: A::Date) {
+        self.hovered = Some(date);
+    }
+
+    /// Clears the hover preview, e.g. when the pointer leaves the calendar.
+    pub fn clear_hover(&mut self) {
+        self.hovered = None;
+    }
+
+    /// Returns the inclusive span that should be highlighted: the committed
+    /// range when complete, otherwise the pending start through the hovered
+    /// day (normalized so the earlier date comes first).
+    pub fn preview_span(&self) -> Option<(A::Date, A::Date)> {
+        if let (Some(start), Some(end)) = (&self.start, &self.end) {
+            return Some((start.clone(), end.clone()));
+        }
+        let start = self.start.as_ref()?;
+        let hovered = self.hovered.as_ref()?;
+        if self.adapter.days_between(start, hovered) >= 0 {
+            Some((start.clone(), hovered.clone()))
+        } else {
+            Some((hovered.clone(), start.clone()))
+        }
+    }
+
+    /// First days of the two months shown by the dual calendar layout.
+    pub fn visible_months(&self) -> (A::Date, A::Date) {
+        (
+            self.visible_month.clone(),
+            self.adapter.add_months(&self.visible_month, 1),
+        )
+    }
+
+    /// Advances both visible months forward by one.
+    pub fn next_month(&mut self) {
+        self.visible_month = self.adapter.add_months(&self.visible_month, 1);
+    }
+
+    /// Moves both visible months back by one.
+    pub fn previous_month(&mut self) {
+        self.visible_month = self.adapter.add_months(&self.visible_month, -1);
+    }
+
+    /// Applies a preset range, bypassing night constraints because presets
+    /// are curated by the application and therefore trusted.
+    pub fn apply_preset(&mut self, preset: RangePreset) {
+        let today = self.adapter.today();
+        let (start, end) = match preset {
+            RangePreset::LastSevenDays => (self.adapter.add_days(&today, -6), today),
+            RangePreset::ThisMonth => {
+                let start = self.adapter.start_of_month(&today);
+                let next = self.adapter.add_months(&start, 1);
+                let end = self.adapter.add_days(&next, -1);
+                (start, end)
+            }
+        };
+        self.visible_month = self.adapter.start_of_month(&start);
+        self.start = Some(start);
+        self.end = Some(end);
+        self.hovered = None;
+    }
+
+    /// Checks a night count against the configured min/max constraints.
+    fn nights_allowed(&self, nights: u32) -> bool {
+        if let Some(min) = self.min_nights {
+            if nights < min {
+                return false;
+            }
+        }
+        if let Some(max) = self.max_nights {
+            if nights > max {
+                return false;
+            }
+        }
+        true
+    }
+}
@@ -9,9 +9,9 @@
 //! downstream applications can swap implementations without touching
 //! widget logic.  This is intended to scale to enterprise grade usage
 //! where different teams may standardize on different date/time crates. Each
-//! widget lives behind a feature flag (`autocomplete`, `date-picker`,
-//! `data-grid`, `tree-view`, `timeline`, `time-picker`, `masonry`,
-//! `localization`) to minimize compile times and manual toggling.
+//! widget lives behind a feature flag (`autocomplete`, `charts`,
+//! `date-picker`, `data-grid`, `tree-view`, `timeline`, `time-picker`,
+//! `masonry`, `localization`) to minimize compile times and manual toggling.
 
 pub mod adapters;
 
@@ -24,6 +24,9 @@ pub mod autocomplete;
 #[cfg(feature = "date-picker")]
 pub mod date_picker;
 
+#[cfg(feature = "charts")]
+pub mod charts;
+
 #[cfg(feature = "data-grid")]
 pub mod data_grid;
 
@@ -0,0 +1,420 @@
+//! Locale-aware number and currency formatting.
+//!
+//! **Unstable:** The formatting bridge follows the same pre-production
+//! policy as the rest of the `localization` feature.
+//!
+//! The module mirrors the [`DateAdapter`](crate::adapters::DateAdapter)
+//! pattern for numeric values: widgets talk to the [`NumberAdapter`] trait so
+//! applications can plug in anything from the built-in [`DecimalFormat`] to
+//! an icu4x backed implementation without touching widget code.  Adapters
+//! are registered per locale alongside the locale packs, and the
+//! [`LocalizationProvider`] resolves them so data grid cells, pagination
+//! labels and number inputs all format through one configuration point.
+//!
+//! Formatting is only half the bridge: financial dashboards also need to
+//! read locale formatted input back out of number fields.  The adapter
+//! therefore exposes [`NumberAdapter::parse_number`] and
+//! [`NumberAdapter::parse_currency`], which tolerate grouping separators,
+//! whitespace and surrounding decoration (currency symbols, percent signs,
+//! unit suffixes) so applications stop hand-rolling per-field parsing.
+//! The [`currency_cell`] and [`percent_cell`] helpers package the round
+//! trip as plain `fn(&str) -> String` formatters that drop straight into
+//! the data grid's `ColumnDef::with_formatter` (the link stays textual
+//! because the `data-grid` feature is independent of `localization`).
+
+use std::collections::HashMap;
+use std::sync::RwLock;
+
+use once_cell::sync::Lazy;
+
+use super::LocalizationProvider;
+
+/// Abstraction over locale specific number rendering.
+///
+/// Implementations must be thread safe because the registry is shared
+/// process-wide, matching the locale pack registry.
+pub trait NumberAdapter: Send + Sync {
+    /// Formats an integer with locale grouping (e.g. `1,234,567`).
+    fn format_integer(&self, value: i64) -> String;
+
+    /// Formats a decimal with a fixed number of fraction digits.
+    fn format_decimal(&self, value: f64, fraction_digits: u8) -> String;
+
+    /// Formats a currency amount expressed in minor units (cents).
+    fn format_currency(&self, minor_units: i64) -> String;
+
+    /// Number of fraction digits carried by the currency's minor units;
+    /// the conversion factor between major amounts and minor units.
+    fn currency_fraction_digits(&self) -> u8 {
+        2
+    }
+
+    /// Formats a ratio (`0.25`) as a percentage (`25%`).
+    fn format_percent(&self, ratio: f64, fraction_digits: u8) -> String {
+        format!("{}%", self.format_decimal(ratio * 100.0, fraction_digits))
+    }
+
+    /// Formats a measurement with a trailing unit suffix (`12.5 km`).
+    fn format_unit(&self, value: f64, fraction_digits: u8, unit: &str) -> String {
+        format!("{} {unit}", self.format_decimal(value, fraction_digits))
+    }
+
+    /// Parses locale formatted input back into a number.
+    ///
+    /// Implementations must tolerate grouping separators, whitespace and
+    /// surrounding decoration such as currency symbols, percent signs or
+    /// unit suffixes; `None` signals input with no recognizable number.
+    fn parse_number(&self, input: &str) -> Option<f64>;
+
+    /// Parses a currency string back into minor units, rounding at the
+    /// currency's fraction digit boundary.
+    fn parse_currency(&self, input: &str) -> Option<i64>;
+}
+
+/// Built-in separator based formatter covering the vast majority of
+/// locales.  Serves as both the default adapter and a reference
+/// implementation for custom backends.
+#[derive(Clone, Debug, PartialEq)]
+pub struct DecimalFormat {
+    /// Separator between the integer and fractional part.
+    pub decimal_separator: char,
+    /// Separator between three-digit groups; `None` disables grouping.
+    pub group_separator: Option<char>,
+    /// Currency symbol rendered by [`NumberAdapter::format_currency`].
+    pub currency_symbol: String,
+    /// Whether the symbol precedes the amount (`$1.50`) or follows it
+    /// (`1,50 €`).
+    pub symbol_first: bool,
+    /// Fraction digits carried by the currency's minor units.
+    pub currency_fraction_digits: u8,
+}
+
+impl Default for DecimalFormat {
+    fn default() -> Self {
+        Self {
+            decimal_separator: '.',
+            group_separator: Some(','),
+            currency_symbol: "$".to_string(),
+            symbol_first: true,
+            currency_fraction_digits: 2,
+        }
+    }
+}
+
+impl DecimalFormat {
+    fn group_digits(&self, digits: &str) -> String {
+        let Some(separator) = self.group_separator else {
+            return digits.to_string();
+        };
+        let mut grouped = String::with_capacity(digits.len() + digits.len() / 3);
+        for (position, digit) in digits.chars().enumerate() {
+            let remaining = digits.len() - position;
+            if position > 0 && remaining.is_multiple_of(3) {
+                grouped.push(separator);
+            }
+            grouped.push(digit);
+        }
+        grouped
+    }
+}
+
+impl NumberAdapter for DecimalFormat {
+    fn format_integer(&self, value: i64) -> String {
+        let sign = if value < 0 { "-" } else { "" };
+        let digits = value.unsigned_abs().to_string();
+        format!("{sign}{}", self.group_digits(&digits))
+    }
+
+    fn format_decimal(&self, value: f64, fraction_digits: u8) -> String {
+        let rendered = format!("{value:.*}", usize::from(fraction_digits));
+        let (integer, fraction) = rendered.split_once('.').unwrap_or((rendered.as_str(), ""));
+        let (sign, digits) = integer
+            .strip_prefix('-')
+            .map_or(("", integer), |rest| ("-", rest));
+        let mut output = format!("{sign}{}", self.group_digits(digits));
+        if !fraction.is_empty() {
+            output.push(self.decimal_separator);
+            output.push_str(fraction);
+        }
+        output
+    }
+
+    fn format_currency(&self, minor_units: i64) -> String {
+        let scale = 10i64.pow(u32::from(self.currency_fraction_digits));
+        let amount = self.format_decimal(
+            minor_units as f64 / scale as f64,
+            self.currency_fraction_digits,
+        );
+        if self.symbol_first {
+            format!("{}{amount}", self.currency_symbol)
+        } else {
+            format!("{amount} {}", self.currency_symbol)
+        }
+    }
+
+    fn parse_number(&self, input: &str) -> Option<f64> {
+        let mut normalized = String::with_capacity(input.len());
+        let mut negative = false;
+        for ch in input.chars() {
+            if ch.is_ascii_digit() {
+                normalized.push(ch);
+            } else if ch == self.decimal_separator {
+                normalized.push('.');
+            } else if ch == '-' && normalized.is_empty() {
+                negative = true;
+            }
+            // Everything else — grouping separators, regular and no-break
+            // spaces, currency symbols, percent signs, unit suffixes — is
+            // decoration and skipped outright.
+        }
+        if !normalized.chars().any(|ch| ch.is_ascii_digit()) {
+            return None;
+        }
+        // Multiple decimal separators survive normalization ("1.2.3") and
+        // fail the final parse, which is exactly the rejection we want.
+        let value: f64 = normalized.parse().ok()?;
+        Some(if negative { -value } else { value })
+    }
+
+    fn currency_fraction_digits(&self) -> u8 {
+        self.currency_fraction_digits
+    }
+
+    fn parse_currency(&self, input: &str) -> Option<i64> {
+        let scale = 10i64.pow(u32::from(self.currency_fraction_digits));
+        let value = self.parse_number(input)?;
+        Some((value * scale as f64).round() as i64)
+    }
+}
+
+/// Registry of number adapters keyed by locale code, mirroring the locale
+/// pack registry in [`super`].
+static NUMBER_ADAPTERS: Lazy<RwLock<HashMap<String, Box<dyn NumberAdapter>>>> =
+    Lazy::new(|| RwLock::new(HashMap::new()));
+
+/// Registers (or replaces) the number adapter for a locale.
+pub fn register_number_adapter<A: NumberAdapter + 'static>(code: &str, adapter: A) {
+    NUMBER_ADAPTERS
+        .write()
+        .expect("number adapter registry poisoned")
+        .insert(code.to_string(), Box::new(adapter));
+}
+
+fn with_adapter<R>(code: &str, apply: impl FnOnce(&dyn NumberAdapter) -> R) -> R {
+    let registry = NUMBER_ADAPTERS
+        .read()
+        .expect("number adapter registry poisoned");
+    match registry.get(code) {
+        Some(adapter) => apply(adapter.as_ref()),
+        // Locales without a registered adapter fall back to the en-US style
+        // defaults so widgets always render something sensible.
+        None => apply(&DecimalFormat::default()),
+    }
+}
+
+impl LocalizationProvider {
+    /// Formats an integer using the locale's number adapter.
+    pub fn format_integer(&self, value: i64) -> String {
+        with_adapter(self.locale(), |adapter| adapter.format_integer(value))
+    }
+
+    /// Formats a decimal with fixed fraction digits using the locale's
+    /// number adapter.
+    pub fn format_decimal(&self, value: f64, fraction_digits: u8) -> String {
+        with_adapter(self.locale(), |adapter| {
+            adapter.format_decimal(value, fraction_digits)
+        })
+    }
+
+    /// Formats a currency amount (minor units) using the locale's number
+    /// adapter.
+    pub fn format_currency(&self, minor_units: i64) -> String {
+        with_adapter(self.locale(), |adapter| {
+            adapter.format_currency(minor_units)
+        })
+    }
+
+    /// Formats a ratio as a percentage using the locale's number adapter.
+    pub fn format_percent(&self, ratio: f64, fraction_digits: u8) -> String {
+        with_adapter(self.locale(), |adapter| {
+            adapter.format_percent(ratio, fraction_digits)
+        })
+    }
+
+    /// Formats a measurement with its unit suffix using the locale's
+    /// number adapter.
+    pub fn format_unit(&self, value: f64, fraction_digits: u8, unit: &str) -> String {
+        with_adapter(self.locale(), |adapter| {
+            adapter.format_unit(value, fraction_digits, unit)
+        })
+    }
+
+    /// Parses locale formatted input (number field contents, pasted
+    /// values) back into a number, tolerating grouping separators and
+    /// surrounding decoration.
+    pub fn parse_number(&self, input: &str) -> Option<f64> {
+        with_adapter(self.locale(), |adapter| adapter.parse_number(input))
+    }
+
+    /// Parses a currency string back into minor units using the locale's
+    /// number adapter.
+    pub fn parse_currency(&self, input: &str) -> Option<i64> {
+        with_adapter(self.locale(), |adapter| adapter.parse_currency(input))
+    }
+}
+
+/// Resolves the adapter for the active locale, falling back to the en-US
+/// style defaults when no locale has been activated yet.
+fn with_active_adapter<R>(apply: impl FnOnce(&dyn NumberAdapter) -> R) -> R {
+    match super::active_provider() {
+        Some(provider) => with_adapter(provider.locale(), apply),
+        None => apply(&DecimalFormat::default()),
+    }
+}
+
+/// Ready-made data grid cell formatter rendering a raw decimal cell value
+/// (`"1234.5"`, machine notation with `.`) as a locale currency.
+///
+/// Being a plain `fn(&str) -> String` it slots directly into the data
+/// grid's `ColumnDef::with_formatter` or the `define_columns!` macro's
+/// `format` key.  Raw values are parsed
+/// as canonical machine decimals — the locale-tolerant
+/// [`NumberAdapter::parse_number`] is reserved for user input — and
+/// values without a recognizable number (placeholder dashes, empty
+/// cells) pass through untouched so sparse data sets stay readable.
+pub fn currency_cell(raw: &str) -> String {
+    let Ok(value) = raw.trim().parse::<f64>() else {
+        return raw.to_string();
+    };
+    with_active_adapter(|adapter| {
+        let scale = 10i64.pow(u32::from(adapter.currency_fraction_digits()));
+        adapter.format_currency((value * scale as f64).round() as i64)
+    })
+}
+
+/// Ready-made data grid cell formatter rendering a raw ratio (`"0.25"`,
+/// machine notation) as a whole percentage (`25%`).  Mirrors
+/// [`currency_cell`]: unparsable values pass through untouched.
+pub fn percent_cell(raw: &str) -> String {
+    let Ok(ratio) = raw.trim().parse::<f64>() else {
+        return raw.to_string();
+    };
+    with_active_adapter(|adapter| adapter.format_percent(ratio, 0))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::localization::{init_default_locales, register_locale, set_active_locale};
+
+    #[test]
+    fn integers_group_per_locale() {
+        let format = DecimalFormat::default();
+        assert_eq!(format.format_integer(1_234_567), "1,234,567");
+        assert_eq!(format.format_integer(-42), "-42");
+    }
+
+    #[test]
+    fn decimals_respect_separators() {
+        let format = DecimalFormat {
+            decimal_separator: ',',
+            group_separator: Some('.'),
+            ..DecimalFormat::default()
+        };
+        assert_eq!(format.format_decimal(1234.5, 2), "1.234,50");
+        assert_eq!(format.format_decimal(-0.125, 3), "-0,125");
+    }
+
+    #[test]
+    fn currency_symbol_position_is_configurable() {
+        let usd = DecimalFormat::default();
+        assert_eq!(usd.format_currency(123_450), "$1,234.50");
+
+        let eur = DecimalFormat {
+            decimal_separator: ',',
+            group_separator: Some('.'),
+            currency_symbol: "€".to_string(),
+            symbol_first: false,
+            currency_fraction_digits: 2,
+        };
+        assert_eq!(eur.format_currency(123_450), "1.234,50 €");
+    }
+
+    #[test]
+    fn provider_falls_back_to_defaults_without_a_registered_adapter() {
+        init_default_locales();
+        let provider = LocalizationProvider::new("en-US").expect("en-US registered");
+        assert_eq!(provider.format_integer(9_000), "9,000");
+    }
+
+    /// Throwaway pack so the test owns a locale code no other test mutates:
+    /// the adapter registry is process-wide shared state.
+    struct SwissTest;
+
+    impl crate::localization::LocalePack for SwissTest {
+        fn code(&self) -> &'static str {
+            "de-CH"
+        }
+
+        fn format_date(&self, iso: &str) -> String {
+            iso.to_string()
+        }
+    }
+
+    #[test]
+    fn registered_adapters_flow_through_the_active_provider() {
+        register_locale(SwissTest);
+        register_number_adapter(
+            "de-CH",
+            DecimalFormat {
+                group_separator: Some('\''),
+                ..DecimalFormat::default()
+            },
+        );
+        assert!(set_active_locale("de-CH"));
+        let provider = crate::localization::active_provider().expect("active locale set");
+        assert_eq!(provider.format_integer(9_000), "9'000");
+        // The ready-made cell formatters resolve through the same active
+        // provider, so a data grid column picks up the locale for free.
+        assert_eq!(currency_cell("1234.5"), "$1'234.50");
+        assert_eq!(percent_cell("0.25"), "25%");
+        assert_eq!(percent_cell("—"), "—");
+    }
+
+    #[test]
+    fn percent_and_unit_share_the_locale_separators() {
+        let eur = DecimalFormat {
+            decimal_separator: ',',
+            group_separator: Some('.'),
+            ..DecimalFormat::default()
+        };
+        assert_eq!(eur.format_percent(0.125, 1), "12,5%");
+        assert_eq!(eur.format_unit(1234.5, 1, "km"), "1.234,5 km");
+    }
+
+    #[test]
+    fn parsing_tolerates_grouping_and_decoration() {
+        let usd = DecimalFormat::default();
+        assert_eq!(usd.parse_number("$1,234.50"), Some(1234.5));
+        assert_eq!(usd.parse_number(" -12.5 % "), Some(-12.5));
+        assert_eq!(usd.parse_number("—"), None);
+        assert_eq!(usd.parse_number("1.2.3"), None);
+
+        let eur = DecimalFormat {
+            decimal_separator: ',',
+            group_separator: Some('.'),
+            currency_symbol: "€".to_string(),
+            symbol_first: false,
+            currency_fraction_digits: 2,
+        };
+        assert_eq!(eur.parse_number("1.234,5 km"), Some(1234.5));
+        assert_eq!(eur.parse_currency("1.234,50 €"), Some(123_450));
+    }
+
+    #[test]
+    fn currency_round_trips_through_parse() {
+        let usd = DecimalFormat::default();
+        let rendered = usd.format_currency(987_654_321);
+        assert_eq!(usd.parse_currency(&rendered), Some(987_654_321));
+    }
+}
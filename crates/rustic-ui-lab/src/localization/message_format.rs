@@ -0,0 +1,439 @@
+//! Subset of ICU MessageFormat for localized component strings.
+//!
+//! **Unstable:** The grammar currently covers interpolation, `plural` and
+//! `select` — the constructs components actually need today (e.g. the
+//! pagination string `"{from}–{to} of {total}"`).  Nested arguments inside
+//! branches are supported; more exotic ICU features (ordinals, skeletons)
+//! can be layered on once real translations demand them.
+//!
+//! Catalogs are validated eagerly: every message is parsed the moment a
+//! [`MessageBundle`] is constructed so malformed translations fail the build
+//! of the catalog rather than panicking at render time.  Combined with a
+//! `build.rs` or test that loads all shipped catalogs this gives effectively
+//! compile-time validation without a proc macro.
+
+use std::collections::HashMap;
+
+/// Errors surfaced while parsing a message pattern or catalog.  The variants
+/// carry enough context for CI logs to pinpoint the offending translation.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MessageFormatError {
+    /// An opening `{` without a matching `}` (or vice versa).
+    UnbalancedBraces { message: String },
+    /// A `plural`/`select` argument without the mandatory `other` branch.
+    MissingOtherBranch { argument: String },
+    /// An argument used a type keyword the parser does not understand.
+    UnknownArgumentType { argument: String, kind: String },
+    /// The catalog source could not be deserialized at all.
+    InvalidCatalog { detail: String },
+}
+
+impl core::fmt::Display for MessageFormatError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::UnbalancedBraces { message } => {
+                write!(f, "unbalanced braces in message `{message}`")
+            }
+            Self::MissingOtherBranch { argument } => {
+                write!(f, "argument `{argument}` is missing the `other` branch")
+            }
+            Self::UnknownArgumentType { argument, kind } => {
+                write!(f, "argument `{argument}` has unknown type `{kind}`")
+            }
+            Self::InvalidCatalog { detail } => write!(f, "invalid message catalog: {detail}"),
+        }
+    }
+}
+
+impl std::error::Error for MessageFormatError {}
+
+/// Runtime value bound to a message argument.
+#[derive(Debug, Clone, PartialEq)]
+pub enum MessageArg {
+    /// Numeric argument, used by `plural` and rendered via `#`.
+    Number(i64),
+    /// Free-form text argument, used by interpolation and `select`.
+    Text(String),
+}
+
+impl MessageArg {
+    fn render(&self) -> String {
+        match self {
+            Self::Number(n) => n.to_string(),
+            Self::Text(t) => t.clone(),
+        }
+    }
+}
+
+/// Convenience alias for the argument map passed to formatting calls.
+pub type MessageArgs = HashMap<&'static str, MessageArg>;
+
+/// Branch selector of a `plural` argument.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum PluralKey {
+    /// Exact match written as `=N` in the pattern.
+    Exact(i64),
+    /// CLDR style category keyword (`zero`, `one`, `other`, ...).
+    Category(String),
+}
+
+/// A parsed segment of a message pattern.
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    /// Literal text copied verbatim into the output.
+    Literal(String),
+    /// `{name}` — straight interpolation of an argument.
+    Placeholder(String),
+    /// `#` inside a plural branch, replaced with the plural number.
+    PluralNumber,
+    /// `{name, plural, ...}` with its parsed branches.
+    Plural {
+        name: String,
+        branches: Vec<(PluralKey, Vec<Token>)>,
+    },
+    /// `{name, select, ...}` with its parsed branches.
+    Select {
+        name: String,
+        branches: Vec<(String, Vec<Token>)>,
+    },
+}
+
+/// A single parsed, validated message ready for formatting.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MessagePattern {
+    tokens: Vec<Token>,
+}
+
+impl MessagePattern {
+    /// Parses an ICU MessageFormat string, validating the full structure up
+    /// front so formatting can never fail.
+    pub fn parse(source: &str) -> Result<Self, MessageFormatError> {
+        let tokens = parse_tokens(source, source, false)?;
+        Ok(Self { tokens })
+    }
+
+    /// Renders the message with the supplied arguments.  Missing arguments
+    /// render as an empty string rather than panicking; translations are
+    /// user facing and should degrade gracefully.
+    pub fn format(&self, args: &MessageArgs) -> String {
+        render(&self.tokens, args, None)
+    }
+}
+
+/// Splits a pattern into tokens.  `full` is the original message used for
+/// error reporting and `in_plural` toggles the `#` shorthand.
+fn parse_tokens(
+    source: &str,
+    full: &str,
+    in_plural: bool,
+) -> Result<Vec<Token>, MessageFormatError> {
+    let mut tokens = Vec::new();
+    let mut literal = String::new();
+    let mut chars = source.char_indices();
+    while let Some((idx, ch)) = chars.next() {
+        match ch {
+            '{' => {
+                if !literal.is_empty() {
+                    tokens.push(Token::Literal(std::mem::take(&mut literal)));
+                }
+                let end = matching_brace(source, idx).ok_or_else(|| {
+                    MessageFormatError::UnbalancedBraces {
+                        message: full.to_string(),
+                    }
+                })?;
+                let inner = &source[idx + 1..end];
+                tokens.push(parse_argument(inner, full)?);
+                // Skip past the argument body including the closing brace.
+                for (i, _) in chars.by_ref() {
+                    if i == end {
+                        break;
+                    }
+                }
+            }
+            '}' => {
+                return Err(MessageFormatError::UnbalancedBraces {
+                    message: full.to_string(),
+                })
+            }
+            '#' if in_plural => {
+                if !literal.is_empty() {
+                    tokens.push(Token::Literal(std::mem::take(&mut literal)));
+                }
+                tokens.push(Token::PluralNumber);
+            }
+            _ => literal.push(ch),
+        }
+    }
+    if !literal.is_empty() {
+        tokens.push(Token::Literal(literal));
+    }
+    Ok(tokens)
+}
+
+/// Returns the byte index of the `}` matching the `{` at `open`.
+fn matching_brace(source: &str, open: usize) -> Option<usize> {
+    let mut depth = 0usize;
+    for (idx, ch) in source[open..].char_indices() {
+        match ch {
+            '{' => depth += 1,
+            '}' => {
+                depth -= 1;
+                if depth == 0 {
+                    return Some(open + idx);
+                }
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
+/// Parses the inside of a `{...}` argument.
+fn parse_argument(inner: &str, full: &str) -> Result<Token, MessageFormatError> {
+    let mut parts = inner.splitn(3, ',');
+    let name = parts.next().unwrap_or("").trim().to_string();
+    let Some(kind) = parts.next() else {
+        return Ok(Token::Placeholder(name));
+    };
+    let body = parts.next().unwrap_or("");
+    match kind.trim() {
+        "plural" => {
+            let raw = parse_branches(body, full)?;
+            let mut branches = Vec::with_capacity(raw.len());
+            let mut has_other = false;
+            for (key, tokens) in raw {
+                let key = if let Some(exact) = key.strip_prefix('=') {
+                    PluralKey::Exact(exact.parse().map_err(|_| {
+                        MessageFormatError::UnknownArgumentType {
+                            argument: name.clone(),
+                            kind: key.clone(),
+                        }
+                    })?)
+                } else {
+                    has_other |= key == "other";
+                    PluralKey::Category(key)
+                };
+                branches.push((key, tokens));
+            }
+            if !has_other {
+                return Err(MessageFormatError::MissingOtherBranch { argument: name });
+            }
+            Ok(Token::Plural { name, branches })
+        }
+        "select" => {
+            let branches = parse_branches(body, full)?;
+            if !branches.iter().any(|(key, _)| key == "other") {
+                return Err(MessageFormatError::MissingOtherBranch { argument: name });
+            }
+            Ok(Token::Select { name, branches })
+        }
+        other => Err(MessageFormatError::UnknownArgumentType {
+            argument: name,
+            kind: other.to_string(),
+        }),
+    }
+}
+
+/// Parses a sequence of `key {tokens}` branches.  Branch bodies are parsed
+/// recursively so nested arguments and the `#` shorthand work.
+fn parse_branches(body: &str, full: &str) -> Result<Vec<(String, Vec<Token>)>, MessageFormatError> {
+    let mut branches = Vec::new();
+    let mut rest = body.trim_start();
+    while !rest.is_empty() {
+        let open = rest
+            .find('{')
+            .ok_or_else(|| MessageFormatError::UnbalancedBraces {
+                message: full.to_string(),
+            })?;
+        let key = rest[..open].trim().to_string();
+        let close =
+            matching_brace(rest, open).ok_or_else(|| MessageFormatError::UnbalancedBraces {
+                message: full.to_string(),
+            })?;
+        let tokens = parse_tokens(&rest[open + 1..close], full, true)?;
+        branches.push((key, tokens));
+        rest = rest[close + 1..].trim_start();
+    }
+    Ok(branches)
+}
+
+/// Renders tokens, resolving `#` against `plural_number` when inside a
+/// plural branch.
+fn render(tokens: &[Token], args: &MessageArgs, plural_number: Option<i64>) -> String {
+    let mut out = String::new();
+    for token in tokens {
+        match token {
+            Token::Literal(text) => out.push_str(text),
+            Token::Placeholder(name) => {
+                if let Some(arg) = args.get(name.as_str()) {
+                    out.push_str(&arg.render());
+                }
+            }
+            Token::PluralNumber => {
+                if let Some(n) = plural_number {
+                    out.push_str(&n.to_string());
+                }
+            }
+            Token::Plural { name, branches } => {
+                let n = match args.get(name.as_str()) {
+                    Some(MessageArg::Number(n)) => *n,
+                    _ => 0,
+                };
+                let branch = branches
+                    .iter()
+                    .find(|(key, _)| *key == PluralKey::Exact(n))
+                    .or_else(|| {
+                        // Minimal CLDR mapping: English style `one`/`other`.
+                        let category = if n == 1 { "one" } else { "other" };
+                        branches
+                            .iter()
+                            .find(|(key, _)| *key == PluralKey::Category(category.to_string()))
+                    })
+                    .or_else(|| {
+                        branches
+                            .iter()
+                            .find(|(key, _)| *key == PluralKey::Category("other".to_string()))
+                    });
+                if let Some((_, tokens)) = branch {
+                    out.push_str(&render(tokens, args, Some(n)));
+                }
+            }
+            Token::Select { name, branches } => {
+                let value = args
+                    .get(name.as_str())
+                    .map(|arg| arg.render())
+                    .unwrap_or_default();
+                let branch = branches
+                    .iter()
+                    .find(|(key, _)| *key == value)
+                    .or_else(|| branches.iter().find(|(key, _)| key == "other"));
+                if let Some((_, tokens)) = branch {
+                    out.push_str(&render(tokens, args, plural_number));
+                }
+            }
+        }
+    }
+    out
+}
+
+/// A locale's worth of parsed messages.  Construction validates every entry,
+/// making bundles safe to cache globally and share across threads.
+#[derive(Debug, Clone)]
+pub struct MessageBundle {
+    locale: String,
+    patterns: HashMap<String, MessagePattern>,
+}
+
+impl MessageBundle {
+    /// Builds a bundle from already-parsed key/source pairs.
+    pub fn from_pairs<I>(locale: &str, pairs: I) -> Result<Self, MessageFormatError>
+    where
+        I: IntoIterator<Item = (String, String)>,
+    {
+        let mut patterns = HashMap::new();
+        for (key, source) in pairs {
+            patterns.insert(key, MessagePattern::parse(&source)?);
+        }
+        Ok(Self {
+            locale: locale.to_string(),
+            patterns,
+        })
+    }
+
+    /// Loads a catalog from a flat JSON object (`{"key": "message"}`), the
+    /// format most translation management systems export.
+    pub fn from_json(locale: &str, json: &str) -> Result<Self, MessageFormatError> {
+        let map: HashMap<String, String> =
+            serde_json::from_str(json).map_err(|err| MessageFormatError::InvalidCatalog {
+                detail: err.to_string(),
+            })?;
+        Self::from_pairs(locale, map)
+    }
+
+    /// Loads a catalog from Fluent-style `key = message` lines.  Blank lines
+    /// and `#` comments are ignored; everything after the first `=` is the
+    /// message source.
+    pub fn from_ftl(locale: &str, ftl: &str) -> Result<Self, MessageFormatError> {
+        let mut pairs = Vec::new();
+        for line in ftl.lines() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let (key, value) =
+                line.split_once('=')
+                    .ok_or_else(|| MessageFormatError::InvalidCatalog {
+                        detail: format!("line `{line}` is not `key = message`"),
+                    })?;
+            pairs.push((key.trim().to_string(), value.trim().to_string()));
+        }
+        Self::from_pairs(locale, pairs)
+    }
+
+    /// BCP-47 locale code this bundle was registered under.
+    pub fn locale(&self) -> &str {
+        &self.locale
+    }
+
+    /// Looks up a message by key.
+    pub fn get(&self, key: &str) -> Option<&MessagePattern> {
+        self.patterns.get(key)
+    }
+}
+
+/// Resolves messages across bundles using a locale fallback chain: the
+/// requested locale, then each ancestor obtained by stripping `-subtags`
+/// (`en-US-posix` → `en-US` → `en`), then the configured default locale.
+#[derive(Debug, Default)]
+pub struct MessageResolver {
+    bundles: HashMap<String, MessageBundle>,
+    default_locale: Option<String>,
+}
+
+impl MessageResolver {
+    /// Creates an empty resolver.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers a bundle, replacing any previous bundle for its locale.
+    pub fn register(&mut self, bundle: MessageBundle) {
+        self.bundles.insert(bundle.locale().to_string(), bundle);
+    }
+
+    /// Sets the locale consulted when the fallback chain is exhausted.
+    pub fn set_default_locale(&mut self, locale: &str) {
+        self.default_locale = Some(locale.to_string());
+    }
+
+    /// The fallback chain for `locale`, most specific first.
+    pub fn fallback_chain(&self, locale: &str) -> Vec<String> {
+        let mut chain = Vec::new();
+        let mut current = locale;
+        loop {
+            chain.push(current.to_string());
+            match current.rsplit_once('-') {
+                Some((parent, _)) => current = parent,
+                None => break,
+            }
+        }
+        if let Some(default) = &self.default_locale {
+            if !chain.iter().any(|c| c == default) {
+                chain.push(default.clone());
+            }
+        }
+        chain
+    }
+
+    /// Formats `key` for `locale`, walking the fallback chain until a bundle
+    /// provides the message.  Returns `None` when no bundle in the chain has
+    /// the key so callers can surface missing-translation diagnostics.
+    pub fn format(&self, locale: &str, key: &str, args: &MessageArgs) -> Option<String> {
+        for candidate in self.fallback_chain(locale) {
+            if let Some(pattern) = self.bundles.get(&candidate).and_then(|b| b.get(key)) {
+                return Some(pattern.format(args));
+            }
+        }
+        None
+    }
+}
@@ -67,6 +67,11 @@ impl LocalizationProvider {
         }
     }
 
+    /// Returns the provider's BCP-47 locale code.
+    pub fn locale(&self) -> &str {
+        &self.locale
+    }
+
     /// Formats a date using the locale pack and adapter.
     pub fn format_date<A: DateAdapter>(&self, date: &A::Date, adapter: &A) -> String {
         let iso = adapter.format(date);
@@ -79,9 +84,50 @@ impl LocalizationProvider {
     }
 }
 
+/// Application-wide active locale, configured once by the ThemeProvider
+/// integration (or directly by the host) so widgets deep in the tree can
+/// resolve a provider without threading locale codes through every call.
+static ACTIVE_LOCALE: Lazy<RwLock<Option<String>>> = Lazy::new(|| RwLock::new(None));
+
+/// Sets the active locale for the process.  Returns `false` (leaving the
+/// previous value untouched) when the locale was never registered so a typo
+/// cannot silently disable localization.
+pub fn set_active_locale(locale: &str) -> bool {
+    if !LOCALES
+        .read()
+        .expect("locale registry poisoned")
+        .contains_key(locale)
+    {
+        return false;
+    }
+    *ACTIVE_LOCALE.write().expect("active locale poisoned") = Some(locale.to_string());
+    true
+}
+
+/// Returns a provider for the active locale, if one was configured.
+/// Date/time pickers, data grid cells and pagination labels call this to
+/// pick up the single ThemeProvider-level configuration.
+pub fn active_provider() -> Option<LocalizationProvider> {
+    ACTIVE_LOCALE
+        .read()
+        .expect("active locale poisoned")
+        .as_deref()
+        .and_then(LocalizationProvider::new)
+}
+
 pub mod en_us;
 pub use en_us::EnUs;
 
+pub mod formatting;
+pub use formatting::{
+    currency_cell, percent_cell, register_number_adapter, DecimalFormat, NumberAdapter,
+};
+
+pub mod message_format;
+pub use message_format::{
+    MessageArg, MessageArgs, MessageBundle, MessageFormatError, MessagePattern, MessageResolver,
+};
+
 /// Initializes the registry with the default English locale.  Tests call
 /// this to ensure a baseline environment but production applications may
 /// choose to register their own locales instead.
@@ -42,3 +42,111 @@ impl<T: Clone> Masonry<T> {
         cols
     }
 }
+
+/// Declared size of a masonry item before the browser has measured it.  All
+/// math is integer based so the server and the hydrating client compute bit
+/// identical layouts.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ItemSizing {
+    /// Item height in pixels, known up front (e.g. fixed-height cards).
+    Height(u32),
+    /// Intrinsic aspect ratio, resolved against the column width (e.g. images
+    /// whose dimensions are stored alongside their URLs).
+    AspectRatio { width: u32, height: u32 },
+}
+
+/// Emitted when browser measurements shift items to different columns than
+/// the server predicted.  Applications typically respond by animating the
+/// moved items into place rather than re-rendering from scratch.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ReflowEvent {
+    /// Number of items whose column assignment changed.
+    pub moved_items: usize,
+}
+
+/// Masonry variant designed for server side rendering.  Instead of measuring
+/// DOM nodes it assigns each item to the currently shortest column using the
+/// heights declared via [`ItemSizing`].  Because the inputs are declarative
+/// the server and the client derive the exact same layout, eliminating the
+/// hydration flicker of measure-in-browser masonry.  Once real measurements
+/// arrive, [`record_measurement`](SsrMasonry::record_measurement) reports
+/// whether the layout actually changed so callers can reflow lazily.
+#[derive(Debug)]
+pub struct SsrMasonry<T: Clone> {
+    columns: usize,
+    /// Column width used to resolve aspect ratios into pixel heights.
+    column_width: u32,
+    items: Vec<(T, ItemSizing)>,
+}
+
+impl<T: Clone> SsrMasonry<T> {
+    /// Creates an SSR layout with `columns` columns of `column_width` pixels.
+    pub fn new(columns: usize, column_width: u32) -> Self {
+        Self {
+            columns: columns.max(1),
+            column_width: column_width.max(1),
+            items: Vec::new(),
+        }
+    }
+
+    /// Adds an item along with its declared sizing.
+    pub fn push(&mut self, item: T, sizing: ItemSizing) {
+        self.items.push((item, sizing));
+    }
+
+    /// Resolves a declared sizing into a pixel height.
+    fn resolve(&self, sizing: ItemSizing) -> u32 {
+        match sizing {
+            ItemSizing::Height(h) => h,
+            // Integer division keeps the result identical on every platform.
+            ItemSizing::AspectRatio { width, height } => {
+                (self.column_width * height) / width.max(1)
+            }
+        }
+    }
+
+    /// Column index assigned to each item, in insertion order.  Items go to
+    /// the shortest column, ties broken by the lowest index, which makes the
+    /// assignment fully deterministic.
+    fn assignments(&self) -> Vec<usize> {
+        let mut heights = vec![0u64; self.columns];
+        let mut out = Vec::with_capacity(self.items.len());
+        for (_, sizing) in &self.items {
+            let col = heights
+                .iter()
+                .enumerate()
+                .min_by_key(|(idx, h)| (**h, *idx))
+                .map(|(idx, _)| idx)
+                .unwrap_or(0);
+            heights[col] += self.resolve(*sizing) as u64;
+            out.push(col);
+        }
+        out
+    }
+
+    /// Computes the columnar layout from the declared sizes.  Rendering this
+    /// on the server and again during hydration yields identical markup.
+    pub fn layout(&self) -> Vec<Vec<T>> {
+        let mut cols: Vec<Vec<T>> = vec![Vec::new(); self.columns];
+        for ((item, _), col) in self.items.iter().zip(self.assignments()) {
+            cols[col].push(item.clone());
+        }
+        cols
+    }
+
+    /// Replaces the declared height of the item at `index` with the height
+    /// actually measured in the browser.  Returns a [`ReflowEvent`] when the
+    /// correction moves any item to a different column; `None` means the
+    /// server prediction still holds and no DOM work is required.
+    pub fn record_measurement(
+        &mut self,
+        index: usize,
+        measured_height: u32,
+    ) -> Option<ReflowEvent> {
+        let before = self.assignments();
+        self.items.get_mut(index)?.1 = ItemSizing::Height(measured_height);
+        let after = self.assignments();
+        let moved_items = before.iter().zip(&after).filter(|(a, b)| a != b).count();
+        (moved_items > 0).then_some(ReflowEvent { moved_items })
+    }
+}
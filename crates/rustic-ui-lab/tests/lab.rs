@@ -1,11 +1,12 @@
 use rustic_ui_lab::adapters::{AdapterChrono, AdapterTime, DateAdapter, TimeAdapter};
 use rustic_ui_lab::autocomplete::Autocomplete;
 use rustic_ui_lab::data_grid::DataGrid;
-use rustic_ui_lab::date_picker::{DatePicker, Key};
+use rustic_ui_lab::date_picker::{DatePicker, DateRangePicker, Key, RangePreset};
 use rustic_ui_lab::localization::{
-    init_default_locales, register_locale, LocalePack, LocalizationProvider,
+    init_default_locales, register_locale, LocalePack, LocalizationProvider, MessageArg,
+    MessageArgs, MessageBundle, MessageFormatError, MessageResolver,
 };
-use rustic_ui_lab::masonry::Masonry;
+use rustic_ui_lab::masonry::{ItemSizing, Masonry, SsrMasonry};
 use rustic_ui_lab::time_picker::TimePicker;
 use rustic_ui_lab::timeline::{Timeline, TimelineEvent};
 use rustic_ui_lab::tree_view::TreeNode;
@@ -38,6 +39,87 @@ fn localization_supports_custom_locale() {
     );
 }
 
+#[test]
+fn message_format_renders_pagination_and_plurals() {
+    let bundle = MessageBundle::from_json(
+        "en",
+        r#"{
+            "pagination": "{from}–{to} of {total}",
+            "items": "{count, plural, =0 {no items} one {# item} other {# items}}",
+            "owner": "{gender, select, female {her files} male {his files} other {their files}}"
+        }"#,
+    )
+    .expect("catalog parses");
+
+    let mut args = MessageArgs::new();
+    args.insert("from", MessageArg::Number(1));
+    args.insert("to", MessageArg::Number(10));
+    args.insert("total", MessageArg::Number(230));
+    assert_eq!(
+        bundle.get("pagination").unwrap().format(&args),
+        "1\u{2013}10 of 230"
+    );
+
+    let mut args = MessageArgs::new();
+    args.insert("count", MessageArg::Number(0));
+    assert_eq!(bundle.get("items").unwrap().format(&args), "no items");
+    args.insert("count", MessageArg::Number(1));
+    assert_eq!(bundle.get("items").unwrap().format(&args), "1 item");
+    args.insert("count", MessageArg::Number(5));
+    assert_eq!(bundle.get("items").unwrap().format(&args), "5 items");
+
+    let mut args = MessageArgs::new();
+    args.insert("gender", MessageArg::Text("female".into()));
+    assert_eq!(bundle.get("owner").unwrap().format(&args), "her files");
+}
+
+#[test]
+fn message_resolver_walks_locale_fallback_chain() {
+    let mut resolver = MessageResolver::new();
+    resolver.set_default_locale("en");
+    resolver.register(MessageBundle::from_ftl("en", "greeting = Hello {name}").unwrap());
+    resolver.register(MessageBundle::from_ftl("de", "greeting = Hallo {name}").unwrap());
+
+    assert_eq!(
+        resolver.fallback_chain("de-AT-vienna"),
+        vec!["de-AT-vienna", "de-AT", "de", "en"]
+    );
+
+    let mut args = MessageArgs::new();
+    args.insert("name", MessageArg::Text("Ada".into()));
+    // de-AT has no bundle so the parent `de` bundle answers.
+    assert_eq!(
+        resolver.format("de-AT", "greeting", &args).as_deref(),
+        Some("Hallo Ada")
+    );
+    // Unknown language falls through to the default locale.
+    assert_eq!(
+        resolver.format("fr", "greeting", &args).as_deref(),
+        Some("Hello Ada")
+    );
+    assert_eq!(resolver.format("de", "missing", &args), None);
+}
+
+#[test]
+fn message_catalog_validation_rejects_malformed_patterns() {
+    let err = MessageBundle::from_json("en", r#"{"bad": "{count, plural, one {# item}}"}"#)
+        .expect_err("missing other branch");
+    assert_eq!(
+        err,
+        MessageFormatError::MissingOtherBranch {
+            argument: "count".into()
+        }
+    );
+    assert!(matches!(
+        MessageBundle::from_json("en", r#"{"bad": "unclosed {brace"}"#),
+        Err(MessageFormatError::UnbalancedBraces { .. })
+    ));
+    assert!(matches!(
+        MessageBundle::from_ftl("en", "not a message line"),
+        Err(MessageFormatError::InvalidCatalog { .. })
+    ));
+}
+
 #[test]
 fn keyboard_navigation_moves_selection() {
     init_default_locales();
@@ -49,6 +131,62 @@ fn keyboard_navigation_moves_selection() {
     assert_eq!(picker.selected, expected);
 }
 
+#[test]
+fn range_picker_previews_hovered_span() {
+    let adapter = AdapterChrono;
+    let mut picker = DateRangePicker::new(adapter);
+    let start = picker.adapter.today();
+    assert!(picker.select(start));
+    picker.hover(picker.adapter.add_days(&start, 3));
+    let (lo, hi) = picker.preview_span().expect("span while hovering");
+    assert_eq!(lo, start);
+    assert_eq!(picker.adapter.days_between(&lo, &hi), 3);
+    // Hovering before the pending start normalizes the span ordering.
+    picker.hover(picker.adapter.add_days(&start, -2));
+    let (lo, hi) = picker.preview_span().expect("reversed hover span");
+    assert_eq!(hi, start);
+    assert_eq!(picker.adapter.days_between(&lo, &hi), 2);
+}
+
+#[test]
+fn range_picker_enforces_night_constraints() {
+    let adapter = AdapterChrono;
+    let mut picker = DateRangePicker::new(adapter);
+    picker.min_nights = Some(2);
+    picker.max_nights = Some(5);
+    let start = picker.adapter.today();
+    assert!(picker.select(start));
+    // One night is below the minimum; the pending start survives the reject.
+    assert!(!picker.select(picker.adapter.add_days(&start, 1)));
+    assert_eq!(picker.start, Some(start));
+    assert_eq!(picker.end, None);
+    // Seven nights exceeds the maximum.
+    assert!(!picker.select(picker.adapter.add_days(&start, 7)));
+    // Three nights is within bounds and commits the range.
+    assert!(picker.select(picker.adapter.add_days(&start, 3)));
+    assert!(picker.end.is_some());
+}
+
+#[test]
+fn range_picker_presets_and_dual_month_layout() {
+    let adapter = AdapterChrono;
+    let mut picker = DateRangePicker::new(adapter);
+    picker.apply_preset(RangePreset::LastSevenDays);
+    let (start, end) = picker.preview_span().expect("preset committed a range");
+    assert_eq!(picker.adapter.days_between(&start, &end), 6);
+
+    picker.apply_preset(RangePreset::ThisMonth);
+    let start = picker.start.expect("month preset start");
+    assert_eq!(picker.adapter.start_of_month(&start), start);
+
+    let (lead, trail) = picker.visible_months();
+    assert_eq!(picker.adapter.add_months(&lead, 1), trail);
+    picker.next_month();
+    assert_eq!(picker.visible_months().0, trail);
+    picker.previous_month();
+    assert_eq!(picker.visible_months().0, lead);
+}
+
 #[test]
 fn time_picker_increments_selection() {
     init_default_locales();
@@ -71,6 +209,48 @@ fn masonry_distributes_items_round_robin() {
     assert_eq!(layout[1], vec![2]);
 }
 
+#[test]
+fn ssr_masonry_layout_is_deterministic() {
+    let build = || {
+        let mut m = SsrMasonry::new(2, 100);
+        m.push("tall", ItemSizing::Height(300));
+        m.push(
+            "square",
+            ItemSizing::AspectRatio {
+                width: 1,
+                height: 1,
+            },
+        );
+        m.push(
+            "wide",
+            ItemSizing::AspectRatio {
+                width: 2,
+                height: 1,
+            },
+        );
+        m
+    };
+    // Server render and hydration pass must agree exactly.
+    assert_eq!(build().layout(), build().layout());
+    let layout = build().layout();
+    // "tall" fills column 0 so the shorter column 1 receives the next items.
+    assert_eq!(layout[0], vec!["tall"]);
+    assert_eq!(layout[1], vec!["square", "wide"]);
+}
+
+#[test]
+fn ssr_masonry_reports_reflow_on_measurement_drift() {
+    let mut m = SsrMasonry::new(2, 100);
+    m.push("a", ItemSizing::Height(300));
+    m.push("b", ItemSizing::Height(100));
+    m.push("c", ItemSizing::Height(100));
+    // Measurement matches the declaration: nothing moves.
+    assert_eq!(m.record_measurement(0, 300), None);
+    // "a" is actually short, so later items land in different columns.
+    let event = m.record_measurement(0, 50).expect("layout changed");
+    assert!(event.moved_items > 0);
+}
+
 #[test]
 fn locale_pack_serializes_to_json() {
     let en = rustic_ui_lab::localization::EnUs::default();
@@ -0,0 +1,445 @@
+//! Alert family: severity banners, titles, action slots and stacked groups.
+//!
+//! Alerts surface validation summaries and operational notices inline with
+//! the content they describe.  The module renders the whole family from one
+//! props tree: an optional bold title line, the message body, and an action
+//! slot whose buttons expose `data-alert-action` hooks for adapters to wire.
+//! Severity picks both the ARIA role (interruptions announce assertively via
+//! `role="alert"`, confirmations politely via `role="status"`) and the
+//! palette tone, so light/dark switches restyle banners with zero component
+//! logic.
+//!
+//! [`AlertGroupProps`] stacks related alerts — the classic "3 of your 12
+//! fields failed validation" form summary — and collapses everything past
+//! the configured threshold behind a disclosure button so long summaries
+//! don't shove the form off screen.
+
+use rustic_ui_styled_engine::{css_with_theme, Style};
+
+/// Visual and semantic tone of an alert.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum AlertSeverity {
+    /// Neutral informational notice.
+    #[default]
+    Info,
+    /// Positive confirmation.
+    Success,
+    /// Something needs attention but nothing failed yet.
+    Warning,
+    /// An operation failed or input is invalid.
+    Error,
+}
+
+impl AlertSeverity {
+    /// Stable string for `data-*` attributes and automation hooks.
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Info => "info",
+            Self::Success => "success",
+            Self::Warning => "warning",
+            Self::Error => "error",
+        }
+    }
+
+    /// ARIA role: failures interrupt, the rest announce politely.
+    const fn role(self) -> &'static str {
+        match self {
+            Self::Error | Self::Warning => "alert",
+            Self::Info | Self::Success => "status",
+        }
+    }
+}
+
+/// A button rendered in the alert's action slot.
+#[derive(Clone, Debug, PartialEq)]
+pub struct AlertAction {
+    /// Visible button label.
+    pub label: String,
+    /// Machine readable identifier stamped as `data-alert-action` so
+    /// adapters can route clicks without parsing labels.
+    pub action: String,
+}
+
+/// Shared properties accepted by all adapter implementations.
+#[derive(Clone, Debug, PartialEq, Default)]
+pub struct AlertProps {
+    /// Severity driving role, tone and iconography.
+    pub severity: AlertSeverity,
+    /// Optional bold title line rendered above the message.
+    pub title: Option<String>,
+    /// Message body.
+    pub message: String,
+    /// Buttons rendered in the trailing action slot.
+    pub actions: Vec<AlertAction>,
+    /// Optional automation identifier for analytics and end-to-end tests.
+    pub automation_id: Option<String>,
+}
+
+impl AlertProps {
+    /// Convenience constructor for the common severity + message pair.
+    pub fn new(severity: AlertSeverity, message: impl Into<String>) -> Self {
+        Self {
+            severity,
+            message: message.into(),
+            ..Self::default()
+        }
+    }
+
+    /// Adds the bold title line above the message.
+    pub fn with_title(mut self, title: impl Into<String>) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+
+    /// Appends a button to the action slot.
+    pub fn with_action(mut self, label: impl Into<String>, action: impl Into<String>) -> Self {
+        self.actions.push(AlertAction {
+            label: label.into(),
+            action: action.into(),
+        });
+        self
+    }
+
+    /// Sets the automation identifier stamped on the rendered hooks.
+    pub fn with_automation_id(mut self, id: impl Into<String>) -> Self {
+        self.automation_id = Some(id.into());
+        self
+    }
+}
+
+/// Shared rendering routine used by all adapters.
+fn render_html(props: &AlertProps) -> String {
+    let attrs = crate::style_helpers::themed_attributes_html(
+        themed_alert_style(),
+        vec![
+            ("role".to_string(), props.severity.role().to_string()),
+            (
+                "data-severity".to_string(),
+                props.severity.as_str().to_string(),
+            ),
+            (
+                "data-component".to_string(),
+                crate::style_helpers::automation_id(
+                    "alert",
+                    None,
+                    crate::style_helpers::NO_SEGMENTS,
+                ),
+            ),
+            (
+                crate::style_helpers::automation_data_attr("alert", ["root"]),
+                crate::style_helpers::automation_id(
+                    "alert",
+                    props.automation_id.as_deref(),
+                    ["root"],
+                ),
+            ),
+        ],
+    );
+    let title = props
+        .title
+        .as_ref()
+        .map(|title| {
+            format!(
+                "<strong data-alert-title=\"\">{}</strong>",
+                crate::render::escape_text(title)
+            )
+        })
+        .unwrap_or_default();
+    let actions = if props.actions.is_empty() {
+        String::new()
+    } else {
+        let buttons: String = props
+            .actions
+            .iter()
+            .map(|action| {
+                format!(
+                    "<button type=\"button\" data-alert-action=\"{action}\">{label}</button>",
+                    action = crate::render::escape_text(&action.action),
+                    label = crate::render::escape_text(&action.label),
+                )
+            })
+            .collect();
+        format!("<div data-alert-actions=\"\">{buttons}</div>")
+    };
+    format!(
+        "<div {attrs}><div data-alert-content=\"\">{title}<p>{message}</p></div>{actions}</div>",
+        message = crate::render::escape_text(&props.message),
+    )
+}
+
+/// Shared properties for a stacked group of related alerts.
+#[derive(Clone, Debug, PartialEq)]
+pub struct AlertGroupProps {
+    /// Alerts in display order, most important first.
+    pub alerts: Vec<AlertProps>,
+    /// Number of alerts shown before the rest collapse behind a disclosure
+    /// button.  `None` disables collapsing entirely.
+    pub collapse_after: Option<usize>,
+    /// Optional automation identifier for analytics and end-to-end tests.
+    pub automation_id: Option<String>,
+}
+
+impl AlertGroupProps {
+    /// Group the provided alerts with the default collapse threshold of 3.
+    pub fn new(alerts: Vec<AlertProps>) -> Self {
+        Self {
+            alerts,
+            collapse_after: Some(3),
+            automation_id: None,
+        }
+    }
+
+    /// Override how many alerts stay visible before collapsing.
+    pub fn collapse_after(mut self, count: usize) -> Self {
+        self.collapse_after = Some(count);
+        self
+    }
+
+    /// Render every alert unconditionally.
+    pub fn without_collapse(mut self) -> Self {
+        self.collapse_after = None;
+        self
+    }
+
+    /// Sets the automation identifier stamped on the rendered hooks.
+    pub fn with_automation_id(mut self, id: impl Into<String>) -> Self {
+        self.automation_id = Some(id.into());
+        self
+    }
+}
+
+/// Shared group rendering routine used by all adapters.
+///
+/// Collapsed alerts stay in the server rendered markup inside a hidden
+/// overflow container; adapters reveal it (and hide the disclosure button)
+/// when `data-alert-group-action="expand"` fires, so expansion never waits
+/// on a re-render.
+fn render_group_html(props: &AlertGroupProps) -> String {
+    let attrs = crate::style_helpers::themed_attributes_html(
+        themed_alert_group_style(),
+        vec![
+            ("role".to_string(), String::from("group")),
+            (
+                "data-component".to_string(),
+                crate::style_helpers::automation_id(
+                    "alert-group",
+                    None,
+                    crate::style_helpers::NO_SEGMENTS,
+                ),
+            ),
+            (
+                crate::style_helpers::automation_data_attr("alert-group", ["root"]),
+                crate::style_helpers::automation_id(
+                    "alert-group",
+                    props.automation_id.as_deref(),
+                    ["root"],
+                ),
+            ),
+        ],
+    );
+    let visible_count = props
+        .collapse_after
+        .filter(|count| *count < props.alerts.len())
+        .unwrap_or(props.alerts.len());
+    let visible: String = props.alerts[..visible_count]
+        .iter()
+        .map(render_html)
+        .collect();
+    let overflow = if visible_count < props.alerts.len() {
+        let hidden: String = props.alerts[visible_count..]
+            .iter()
+            .map(render_html)
+            .collect();
+        let remaining = props.alerts.len() - visible_count;
+        format!(
+            "<button type=\"button\" data-alert-group-action=\"expand\">\
+             Show {remaining} more</button>\
+             <div data-alert-group-overflow=\"\" hidden=\"\">{hidden}</div>"
+        )
+    } else {
+        String::new()
+    };
+    format!("<div {attrs}>{visible}{overflow}</div>")
+}
+
+/// Alert surface styling tinted through `data-severity` selectors.
+fn themed_alert_style() -> Style {
+    css_with_theme!(
+        r#"
+        display: flex;
+        align-items: flex-start;
+        justify-content: space-between;
+        gap: ${gap};
+        padding: ${padding};
+        border-radius: 4px;
+        border-left: 4px solid ${info};
+        background: ${surface};
+        color: ${text};
+        font-family: ${font_family};
+        font-size: 0.875rem;
+
+        & [data-alert-title] {
+            display: block;
+            margin-bottom: ${title_gap};
+        }
+
+        & p {
+            margin: 0;
+        }
+
+        &[data-severity='success'] {
+            border-left-color: ${success};
+        }
+
+        &[data-severity='warning'] {
+            border-left-color: ${warning};
+        }
+
+        &[data-severity='error'] {
+            border-left-color: ${danger};
+        }
+    "#,
+        gap = format!("{}px", theme.spacing(2)),
+        padding = format!("{}px", theme.spacing(2)),
+        info = theme.palette.active().info.clone(),
+        surface = theme.palette.active().background_paper.clone(),
+        text = theme.palette.active().text_primary.clone(),
+        font_family = theme.typography.font_family.clone(),
+        title_gap = format!("{}px", theme.spacing(1)),
+        success = theme.palette.active().success.clone(),
+        warning = theme.palette.active().warning.clone(),
+        danger = theme.palette.active().danger.clone(),
+    )
+}
+
+/// Vertical stack layout for grouped alerts.
+fn themed_alert_group_style() -> Style {
+    css_with_theme!(
+        r#"
+        display: flex;
+        flex-direction: column;
+        gap: ${gap};
+    "#,
+        gap = format!("{}px", theme.spacing(1)),
+    )
+}
+
+// ---------------------------------------------------------------------------
+// Adapter implementations
+// ---------------------------------------------------------------------------
+
+pub mod yew {
+    use super::*;
+
+    /// Render a single alert into a plain HTML string for SSR/hydration.
+    pub fn render(props: &AlertProps) -> String {
+        super::render_html(props)
+    }
+
+    /// Render a stacked alert group into a plain HTML string.
+    pub fn render_group(props: &AlertGroupProps) -> String {
+        super::render_group_html(props)
+    }
+}
+
+pub mod leptos {
+    use super::*;
+
+    /// Render a single alert into a plain HTML string for SSR/hydration.
+    pub fn render(props: &AlertProps) -> String {
+        super::render_html(props)
+    }
+
+    /// Render a stacked alert group into a plain HTML string.
+    pub fn render_group(props: &AlertGroupProps) -> String {
+        super::render_group_html(props)
+    }
+}
+
+pub mod dioxus {
+    use super::*;
+
+    /// Render a single alert into a plain HTML string for SSR/hydration.
+    pub fn render(props: &AlertProps) -> String {
+        super::render_html(props)
+    }
+
+    /// Render a stacked alert group into a plain HTML string.
+    pub fn render_group(props: &AlertGroupProps) -> String {
+        super::render_group_html(props)
+    }
+}
+
+pub mod sycamore {
+    use super::*;
+
+    /// Render a single alert into a plain HTML string for SSR/hydration.
+    pub fn render(props: &AlertProps) -> String {
+        super::render_html(props)
+    }
+
+    /// Render a stacked alert group into a plain HTML string.
+    pub fn render_group(props: &AlertGroupProps) -> String {
+        super::render_group_html(props)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn severity_drives_role_and_tone_attributes() {
+        let error = render_html(&AlertProps::new(AlertSeverity::Error, "Field required"));
+        assert!(error.contains("role=\"alert\""));
+        assert!(error.contains("data-severity=\"error\""));
+
+        let success = render_html(&AlertProps::new(AlertSeverity::Success, "Saved"));
+        assert!(success.contains("role=\"status\""));
+    }
+
+    #[test]
+    fn title_and_actions_render_in_their_slots() {
+        let props = AlertProps::new(AlertSeverity::Warning, "3 fields need attention")
+            .with_title("Validation <summary>")
+            .with_action("Review", "review")
+            .with_action("Dismiss", "dismiss")
+            .with_automation_id("form-summary");
+        let html = render_html(&props);
+        assert!(html.contains("<strong data-alert-title=\"\">Validation &lt;summary&gt;</strong>"));
+        assert!(html.contains("data-alert-action=\"review\">Review</button>"));
+        assert!(html.contains("data-alert-action=\"dismiss\">Dismiss</button>"));
+        assert!(html.contains("data-rustic-alert-root=\"rustic-alert-form-summary-root\""));
+
+        let bare = render_html(&AlertProps::new(AlertSeverity::Info, "FYI"));
+        assert!(!bare.contains("data-alert-actions"));
+        assert!(!bare.contains("data-alert-title"));
+    }
+
+    #[test]
+    fn group_collapses_past_the_threshold() {
+        let alerts: Vec<AlertProps> = (0..5)
+            .map(|index| AlertProps::new(AlertSeverity::Error, format!("Field {index} invalid")))
+            .collect();
+        let html = render_group_html(&AlertGroupProps::new(alerts).collapse_after(2));
+        assert!(html.contains("Field 0 invalid"));
+        assert!(html.contains("Field 1 invalid"));
+        assert!(html.contains("Show 3 more"));
+        // The overflow stays in the markup, hidden until expanded.
+        assert!(html.contains("data-alert-group-overflow=\"\" hidden=\"\""));
+        assert!(html.contains("Field 4 invalid"));
+    }
+
+    #[test]
+    fn small_groups_never_render_the_disclosure() {
+        let alerts = vec![
+            AlertProps::new(AlertSeverity::Error, "One"),
+            AlertProps::new(AlertSeverity::Error, "Two"),
+        ];
+        let html = render_group_html(&AlertGroupProps::new(alerts.clone()));
+        assert!(!html.contains("data-alert-group-action"));
+
+        let expanded = render_group_html(&AlertGroupProps::new(alerts).without_collapse());
+        assert!(!expanded.contains("hidden"));
+    }
+}
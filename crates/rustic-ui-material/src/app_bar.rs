@@ -36,8 +36,8 @@ pub use crate::macros::{Color as AppBarColor, Size as AppBarSize, Variant as App
 ))]
 fn resolve_style(theme: &Theme, color: AppBarColor, size: AppBarSize) -> (String, &'static str) {
     let bg = match color {
-        AppBarColor::Primary => theme.palette.primary.clone(),
-        AppBarColor::Secondary => theme.palette.secondary.clone(),
+        AppBarColor::Primary => theme.palette.active().primary.clone(),
+        AppBarColor::Secondary => theme.palette.active().secondary.clone(),
     };
     let height = match size {
         AppBarSize::Small => "48px",
@@ -181,6 +181,493 @@ pub mod dioxus {
     }
 }
 
+/// Structured toolbar rendered inside the app bar (or any banner surface).
+///
+/// Consumers previously hand-built flex layouts to position actions inside
+/// the bar; this module formalizes the pattern with three slots — `start`
+/// (navigation/branding), `center` (search, tabs) and `end` (actions) — plus
+/// a dense variant for data heavy screens.  Action children integrate with
+/// the headless [`ToolbarState`](rustic_ui_headless::toolbar::ToolbarState)
+/// machine so low priority items relocate into an overflow menu when the bar
+/// shrinks instead of wrapping or clipping.  Like the other shared renderers
+/// in this crate the markup assembly lives in one routine and the framework
+/// adapters simply forward props/state.
+///
+/// Responsive overflow needs live widths: the container and every visible
+/// child carry `data-toolbar-measure` attributes, so adapters attach one
+/// `ResizeObserver` per hook and feed readings into
+/// [`ToolbarState::set_available_width`](rustic_ui_headless::toolbar::ToolbarState::set_available_width)
+/// and
+/// [`ToolbarState::set_item_width`](rustic_ui_headless::toolbar::ToolbarState::set_item_width)
+/// before re-rendering.  SSR starts from the design-time width estimates so
+/// the first measurement pass reconciles without layout jumps.
+pub mod toolbar {
+    use rustic_ui_headless::toolbar::{ToolbarItem, ToolbarOrientation, ToolbarState};
+    use rustic_ui_styled_engine::{css_with_theme, Style};
+
+    /// The slot a toolbar child is assigned to.
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    pub enum ToolbarSlot {
+        /// Leading cluster: navigation toggles, branding, titles.
+        Start,
+        /// Flexible middle cluster: search fields, tab strips.
+        Center,
+        /// Trailing cluster: actions, avatars, overflow candidates.
+        End,
+    }
+
+    impl ToolbarSlot {
+        /// Stable string stamped into `data-toolbar-slot` hooks.
+        pub const fn as_str(self) -> &'static str {
+            match self {
+                Self::Start => "start",
+                Self::Center => "center",
+                Self::End => "end",
+            }
+        }
+
+        /// Group index fed into the headless machine so separators and
+        /// eviction reasoning can distinguish the clusters.
+        const fn group(self) -> usize {
+            match self {
+                Self::Start => 0,
+                Self::Center => 1,
+                Self::End => 2,
+            }
+        }
+    }
+
+    /// One pre-rendered child placed into a toolbar slot.
+    #[derive(Clone, Debug)]
+    pub struct ToolbarChild {
+        /// Stable identifier shared with the overflow machine and automation.
+        pub id: String,
+        /// Pre-rendered HTML fragment for the control.
+        pub html: String,
+        /// Slot the child is assigned to.
+        pub slot: ToolbarSlot,
+        /// Overflow priority forwarded to the headless machine; higher values
+        /// stay visible longer.
+        pub priority: u8,
+        /// Design-time width estimate (pixels) used for the SSR layout pass
+        /// until the client reports real measurements.
+        pub width_estimate: f64,
+    }
+
+    impl ToolbarChild {
+        /// Convenience constructor with a conservative 48px width estimate.
+        pub fn new(id: impl Into<String>, html: impl Into<String>, slot: ToolbarSlot) -> Self {
+            Self {
+                id: id.into(),
+                html: html.into(),
+                slot,
+                priority: 0,
+                width_estimate: 48.0,
+            }
+        }
+
+        /// Override the overflow priority.
+        pub fn with_priority(mut self, priority: u8) -> Self {
+            self.priority = priority;
+            self
+        }
+
+        /// Override the width estimate used before client measurements land.
+        pub fn with_width_estimate(mut self, width: f64) -> Self {
+            self.width_estimate = width;
+            self
+        }
+    }
+
+    /// Shared toolbar properties consumed by every adapter.
+    #[derive(Clone, Debug, Default)]
+    pub struct ToolbarProps {
+        /// Children across all slots; display order within a slot follows
+        /// insertion order.
+        pub children: Vec<ToolbarChild>,
+        /// Dense toolbars drop to a 48px row for data heavy screens.
+        pub dense: bool,
+        /// Accessible label of the overflow trigger; defaults to
+        /// "More actions" so the icon-only button always names itself.
+        pub overflow_label: Option<String>,
+        /// Optional automation identifier stamped into `data-*` hooks.
+        pub automation_id: Option<String>,
+    }
+
+    impl ToolbarProps {
+        /// Construct an empty toolbar.
+        pub fn new() -> Self {
+            Self::default()
+        }
+
+        /// Append a child to the toolbar.
+        pub fn with_child(mut self, child: ToolbarChild) -> Self {
+            self.children.push(child);
+            self
+        }
+
+        /// Toggle the dense row height.
+        pub fn with_dense(mut self, dense: bool) -> Self {
+            self.dense = dense;
+            self
+        }
+
+        /// Override the accessible label of the overflow trigger, e.g. for
+        /// localisation.
+        pub fn with_overflow_label(mut self, label: impl Into<String>) -> Self {
+            self.overflow_label = Some(label.into());
+            self
+        }
+
+        /// Override the automation identifier.
+        pub fn with_automation_id(mut self, id: impl Into<String>) -> Self {
+            self.automation_id = Some(id.into());
+            self
+        }
+
+        /// Children of one slot paired with their linear machine indices.
+        fn slot_children(&self, slot: ToolbarSlot) -> Vec<(usize, &ToolbarChild)> {
+            self.children
+                .iter()
+                .enumerate()
+                .filter(|(_, child)| child.slot == slot)
+                .collect()
+        }
+    }
+
+    /// Build the headless overflow machine matching the toolbar children.
+    ///
+    /// Adapters own the returned state: they feed container measurements into
+    /// [`ToolbarState::set_available_width`] and forward keyboard events, then
+    /// re-render through [`yew::render`] (or their framework's twin) so the
+    /// markup tracks the machine's layout verdict.
+    pub fn toolbar_state(props: &ToolbarProps) -> ToolbarState {
+        let items = props
+            .children
+            .iter()
+            .map(|child| {
+                ToolbarItem::new(child.id.clone(), child.width_estimate)
+                    .with_group(child.slot.group())
+                    .with_priority(child.priority)
+            })
+            .collect();
+        ToolbarState::new(items, ToolbarOrientation::Horizontal).with_trigger_width(48.0)
+    }
+
+    /// Shared rendering routine invoked by every framework adapter.
+    fn render_html(props: &ToolbarProps, state: &ToolbarState) -> String {
+        let layout = state.layout();
+        let container_attrs = crate::style_helpers::themed_attributes_html(
+            themed_toolbar_style(props.dense),
+            container_attributes(props, state),
+        );
+
+        let mut slots_html = String::new();
+        for slot in [ToolbarSlot::Start, ToolbarSlot::Center, ToolbarSlot::End] {
+            let mut slot_html = String::new();
+            for (index, child) in props.slot_children(slot) {
+                if !layout.visible.contains(&index) {
+                    continue;
+                }
+                let mut item_attrs: Vec<(String, String)> = state
+                    .item_attributes(index)
+                    .into_iter()
+                    .map(|(key, value)| (key.to_string(), value))
+                    .collect();
+                item_attrs.push(("data-toolbar-slot".into(), slot.as_str().into()));
+                // Measurement hook: adapters attach a `ResizeObserver` per
+                // child and feed readings into `ToolbarState::set_item_width`.
+                item_attrs.push(("data-toolbar-measure".into(), child.id.clone()));
+                let attrs = rustic_ui_utils::attributes_to_html(&item_attrs);
+                slot_html.push_str(&format!("<span {attrs}>{}</span>", child.html));
+            }
+            slots_html.push_str(&format!(
+                "<div data-toolbar-slot-group=\"{}\">{slot_html}</div>",
+                slot.as_str()
+            ));
+        }
+
+        let overflow_html = render_overflow(props, state, &layout.overflow);
+        format!("<div {container_attrs}>{slots_html}{overflow_html}</div>")
+    }
+
+    /// Render the overflow trigger and menu when any child overflowed.
+    ///
+    /// The trigger is a Material "more" icon button: icon-only, labelled via
+    /// `aria-label` and linked to its menu through `aria-controls` so screen
+    /// readers announce both the purpose and the relationship.  The
+    /// `aria-haspopup`/`aria-expanded` pair comes from the machine's
+    /// attribute builder, keeping the disclosure contract identical across
+    /// adapters.
+    fn render_overflow(props: &ToolbarProps, state: &ToolbarState, overflow: &[usize]) -> String {
+        if overflow.is_empty() {
+            return String::new();
+        }
+        let menu_id = crate::style_helpers::automation_id(
+            "toolbar",
+            props.automation_id.as_deref(),
+            ["overflow-menu"],
+        );
+        let label = props.overflow_label.as_deref().unwrap_or("More actions");
+        let trigger_attrs: Vec<(String, String)> = state
+            .overflow_trigger_attributes()
+            .into_iter()
+            .map(|(key, value)| (key.to_string(), value))
+            .chain([
+                ("type".to_string(), "button".to_string()),
+                ("aria-label".to_string(), crate::render::escape_text(label)),
+                ("aria-controls".to_string(), menu_id.clone()),
+                ("data-toolbar-overflow".to_string(), "trigger".to_string()),
+            ])
+            .collect();
+        let trigger = format!(
+            "<button {}><span aria-hidden=\"true\">\u{22ee}</span></button>",
+            rustic_ui_utils::attributes_to_html(&trigger_attrs)
+        );
+
+        let items: String = overflow
+            .iter()
+            .filter_map(|index| props.children.get(*index))
+            .map(|child| {
+                format!(
+                    "<li role=\"menuitem\" data-toolbar-item=\"{}\">{}</li>",
+                    child.id, child.html
+                )
+            })
+            .collect();
+        let hidden = !state.is_overflow_open();
+        let menu = format!(
+            "<ul role=\"menu\" aria-hidden=\"{hidden}\" id=\"{menu_id}\" data-toolbar-overflow=\"menu\">{items}</ul>"
+        );
+        format!("{trigger}{menu}")
+    }
+
+    /// Attributes applied to the toolbar container.
+    fn container_attributes(props: &ToolbarProps, state: &ToolbarState) -> Vec<(String, String)> {
+        let mut attrs: Vec<(String, String)> = state
+            .container_attributes()
+            .into_iter()
+            .map(|(key, value)| (key.to_string(), value))
+            .collect();
+        attrs.push((
+            "data-component".into(),
+            crate::style_helpers::automation_id("toolbar", None, crate::style_helpers::NO_SEGMENTS),
+        ));
+        attrs.push((
+            crate::style_helpers::automation_data_attr("toolbar", ["root"]),
+            crate::style_helpers::automation_id(
+                "toolbar",
+                props.automation_id.as_deref(),
+                ["root"],
+            ),
+        ));
+        attrs.push(("data-dense".into(), props.dense.to_string()));
+        // Measurement hook: adapters observe the container and feed readings
+        // into `ToolbarState::set_available_width` before re-rendering.
+        attrs.push(("data-toolbar-measure".into(), "container".into()));
+        attrs
+    }
+
+    /// Flex layout for the three slot toolbar; the center group absorbs the
+    /// remaining space so start/end stay pinned to their edges.
+    fn themed_toolbar_style(dense: bool) -> Style {
+        let min_height = if dense { "48px" } else { "64px" };
+        css_with_theme!(
+            r#"
+            display: flex;
+            align-items: center;
+            width: 100%;
+            gap: ${gap};
+            min-height: ${min_height};
+            padding: 0 ${padding_x};
+
+            & > [data-toolbar-slot-group] {
+                display: flex;
+                align-items: center;
+                gap: ${gap};
+            }
+
+            & > [data-toolbar-slot-group='center'] {
+                flex: 1;
+                justify-content: center;
+            }
+
+            & > [data-toolbar-slot-group='end'] {
+                justify-content: flex-end;
+            }
+
+            & > [data-toolbar-overflow='trigger'] {
+                display: inline-flex;
+                align-items: center;
+                justify-content: center;
+                width: 40px;
+                height: 40px;
+                border: none;
+                border-radius: 50%;
+                background: transparent;
+                color: inherit;
+                font: inherit;
+                cursor: pointer;
+            }
+
+            & > [data-toolbar-overflow='trigger']:focus-visible {
+                outline: ${focus_outline};
+                outline-offset: ${focus_outline_offset};
+            }
+        "#,
+            focus_outline = crate::style_helpers::focus_outline(&theme),
+            focus_outline_offset = crate::style_helpers::focus_outline_offset(&theme),
+            gap = format!("{}px", theme.spacing(1)),
+            min_height = min_height.to_string(),
+            padding_x = format!("{}px", theme.spacing(2)),
+        )
+    }
+
+    /// Adapter targeting the [`yew`] framework.
+    pub mod yew {
+        use super::*;
+
+        /// Render the toolbar into a HTML string using the shared renderer.
+        pub fn render(props: &ToolbarProps, state: &ToolbarState) -> String {
+            super::render_html(props, state)
+        }
+    }
+
+    /// Adapter targeting the [`leptos`] framework.
+    pub mod leptos {
+        use super::*;
+
+        /// Render the toolbar into a HTML string using the shared renderer.
+        pub fn render(props: &ToolbarProps, state: &ToolbarState) -> String {
+            super::render_html(props, state)
+        }
+    }
+
+    /// Adapter targeting the [`dioxus`] framework.
+    pub mod dioxus {
+        use super::*;
+
+        /// Render the toolbar into a HTML string using the shared renderer.
+        pub fn render(props: &ToolbarProps, state: &ToolbarState) -> String {
+            super::render_html(props, state)
+        }
+    }
+
+    /// Adapter targeting the [`sycamore`] framework.
+    pub mod sycamore {
+        use super::*;
+
+        /// Render the toolbar into a HTML string using the shared renderer.
+        pub fn render(props: &ToolbarProps, state: &ToolbarState) -> String {
+            super::render_html(props, state)
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        fn props() -> ToolbarProps {
+            ToolbarProps::new()
+                .with_child(
+                    ToolbarChild::new("menu", "<button>Menu</button>", ToolbarSlot::Start)
+                        .with_priority(3),
+                )
+                .with_child(
+                    ToolbarChild::new("search", "<input>", ToolbarSlot::Center)
+                        .with_priority(2)
+                        .with_width_estimate(200.0),
+                )
+                .with_child(ToolbarChild::new(
+                    "share",
+                    "<button>Share</button>",
+                    ToolbarSlot::End,
+                ))
+                .with_child(ToolbarChild::new(
+                    "export",
+                    "<button>Export</button>",
+                    ToolbarSlot::End,
+                ))
+        }
+
+        #[test]
+        fn renders_all_slots_with_roving_tabindex() {
+            let props = props();
+            let state = toolbar_state(&props);
+            let html = super::render_html(&props, &state);
+            assert!(html.contains("role=\"toolbar\""));
+            assert!(html.contains("data-toolbar-slot-group=\"start\""));
+            assert!(html.contains("data-toolbar-slot-group=\"center\""));
+            assert!(html.contains("data-toolbar-slot-group=\"end\""));
+            assert!(html.contains("tabindex=\"0\""));
+            // Nothing overflowed, so no trigger is rendered.
+            assert!(!html.contains("data-toolbar-overflow"));
+        }
+
+        #[test]
+        fn dense_variant_is_stamped_for_styling_hooks() {
+            let props = props().with_dense(true);
+            let state = toolbar_state(&props);
+            let html = super::render_html(&props, &state);
+            assert!(html.contains("data-dense=\"true\""));
+        }
+
+        #[test]
+        fn low_priority_actions_relocate_into_the_overflow_menu() {
+            let props = props();
+            let mut state = toolbar_state(&props);
+            // 344px of estimates; a 300px container (minus the 48px trigger)
+            // forces the zero priority end actions out while the higher
+            // priority menu and search stay inline.
+            state.set_available_width(300.0);
+            let html = super::render_html(&props, &state);
+            assert!(html.contains("aria-haspopup=\"menu\""));
+            assert!(html.contains("data-toolbar-item=\"share\""));
+            assert!(html.contains("data-toolbar-item=\"export\""));
+            assert!(!html.contains("data-toolbar-slot=\"end\""));
+        }
+
+        #[test]
+        fn overflow_trigger_is_a_labelled_icon_button_linked_to_its_menu() {
+            let props = props().with_automation_id("appbar");
+            let mut state = toolbar_state(&props);
+            state.set_available_width(300.0);
+            let html = super::render_html(&props, &state);
+            assert!(html.contains("aria-label=\"More actions\""));
+            assert!(html.contains("aria-controls=\"rustic-toolbar-appbar-overflow-menu\""));
+            assert!(html.contains("id=\"rustic-toolbar-appbar-overflow-menu\""));
+            assert!(html.contains("<span aria-hidden=\"true\">\u{22ee}</span>"));
+
+            let localised = props.clone().with_overflow_label("Weitere Aktionen");
+            let html = super::render_html(&localised, &state);
+            assert!(html.contains("aria-label=\"Weitere Aktionen\""));
+        }
+
+        #[test]
+        fn measurement_hooks_cover_the_container_and_visible_children() {
+            let props = props();
+            let mut state = toolbar_state(&props);
+            state.set_available_width(300.0);
+            let html = super::render_html(&props, &state);
+            assert!(html.contains("data-toolbar-measure=\"container\""));
+            assert!(html.contains("data-toolbar-measure=\"menu\""));
+            // Overflowed children are in the menu and not observed.
+            assert!(!html.contains("data-toolbar-measure=\"export\""));
+        }
+
+        #[test]
+        fn opening_the_overflow_menu_unhides_it() {
+            let props = props();
+            let mut state = toolbar_state(&props);
+            state.set_available_width(300.0);
+            state.toggle_overflow();
+            let html = super::render_html(&props, &state);
+            assert!(html.contains("aria-expanded=\"true\""));
+            assert!(html.contains("role=\"menu\" aria-hidden=\"false\""));
+        }
+    }
+}
+
 /// Adapter targeting the [`sycamore`] framework.
 ///
 /// Produces an accessible `<header>` with classes derived from the active
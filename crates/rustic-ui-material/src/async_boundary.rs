@@ -0,0 +1,246 @@
+//! Suspense-style boundary swapping skeletons for resolved children.
+//!
+//! Built on the [`Resource`](rustic_ui_utils::Resource) lifecycle: while a
+//! load is in flight and no data has ever resolved, the boundary renders
+//! pulse-animated skeleton lines sized from theme tokens; once the resource
+//! holds data the pre-rendered children take over.  Stale-while-revalidate
+//! falls out naturally — a refresh keeps the children visible (flagged via
+//! `aria-busy`) instead of flashing back to placeholders.
+//!
+//! Servers render deterministically: SSR pipelines never resolve the
+//! resource, so the emitted markup is always the skeleton in its initial
+//! phase and hydration replays the same bytes before the client load kicks
+//! off.
+
+use rustic_ui_styled_engine::{css_with_theme, Style};
+use rustic_ui_utils::Resource;
+
+/// Shared boundary properties consumed by every adapter.
+#[derive(Clone, Debug)]
+pub struct AsyncBoundaryProps {
+    /// Pre-rendered HTML shown once the resource holds data.
+    pub children_html: String,
+    /// Number of skeleton lines approximating the eventual content height,
+    /// keeping layout shift minimal when the swap happens.
+    pub skeleton_lines: usize,
+    /// Optional automation identifier stamped into `data-*` hooks.
+    pub automation_id: Option<String>,
+}
+
+impl AsyncBoundaryProps {
+    /// Wrap the provided children with the default three-line skeleton.
+    pub fn new(children_html: impl Into<String>) -> Self {
+        Self {
+            children_html: children_html.into(),
+            skeleton_lines: 3,
+            automation_id: None,
+        }
+    }
+
+    /// Override how many skeleton lines the fallback renders.
+    pub fn with_skeleton_lines(mut self, lines: usize) -> Self {
+        self.skeleton_lines = lines.max(1);
+        self
+    }
+
+    /// Override the automation identifier.
+    pub fn with_automation_id(mut self, id: impl Into<String>) -> Self {
+        self.automation_id = Some(id.into());
+        self
+    }
+}
+
+/// Shared rendering routine invoked by every framework adapter.
+///
+/// The wrapper element persists across the swap — only its contents, the
+/// `data-async-phase` hook and `aria-busy` change — giving QA pipelines and
+/// styling a stable target through the placeholder/content transition.
+fn render_html<T, E>(props: &AsyncBoundaryProps, resource: &Resource<T, E>) -> String {
+    let mut attrs = vec![
+        (
+            "data-component".to_string(),
+            crate::style_helpers::automation_id(
+                "async-boundary",
+                None,
+                crate::style_helpers::NO_SEGMENTS,
+            ),
+        ),
+        (
+            crate::style_helpers::automation_data_attr("async-boundary", ["root"]),
+            crate::style_helpers::automation_id(
+                "async-boundary",
+                props.automation_id.as_deref(),
+                ["root"],
+            ),
+        ),
+        (
+            "data-async-phase".to_string(),
+            resource.phase().as_str().to_string(),
+        ),
+    ];
+    attrs.extend(resource.loading_attributes());
+    let wrapper_attrs = crate::style_helpers::themed_attributes_html(themed_wrapper_style(), attrs);
+    let contents = if resource.data().is_some() {
+        props.children_html.clone()
+    } else {
+        render_skeleton(props)
+    };
+    format!("<div {wrapper_attrs}>{contents}</div>")
+}
+
+/// Pulse-animated placeholder lines shown before the first resolution.
+fn render_skeleton(props: &AsyncBoundaryProps) -> String {
+    let line_attrs = crate::style_helpers::themed_attributes_html(
+        themed_skeleton_style(),
+        [(
+            "data-async-boundary-slot".to_string(),
+            "skeleton-line".to_string(),
+        )],
+    );
+    std::iter::repeat_with(|| format!("<span {line_attrs}></span>"))
+        .take(props.skeleton_lines)
+        .collect()
+}
+
+/// Neutral wrapper style: the boundary stacks whatever it holds without
+/// imposing layout beyond a vertical flow for the skeleton lines.
+fn themed_wrapper_style() -> Style {
+    css_with_theme!(
+        r#"
+        display: flex;
+        flex-direction: column;
+        gap: ${gap};
+    "#,
+        gap = format!("{}px", theme.spacing(1))
+    )
+}
+
+/// One skeleton line, sized and animated from theme tokens so placeholders
+/// match the typography rhythm of the content they stand in for.
+fn themed_skeleton_style() -> Style {
+    css_with_theme!(
+        r#"
+        display: block;
+        height: ${height};
+        border-radius: ${radius};
+        background: ${color};
+        animation: rustic-skeleton-pulse 1.5s ease-in-out infinite;
+
+        @keyframes rustic-skeleton-pulse {
+            0% { opacity: 1; }
+            50% { opacity: 0.4; }
+            100% { opacity: 1; }
+        }
+    "#,
+        height = format!("{}px", theme.spacing(2)),
+        radius = format!("{}px", theme.spacing(0)),
+        color = theme.palette.active().text_secondary.clone()
+    )
+}
+
+// ---------------------------------------------------------------------------
+// Adapter implementations
+// ---------------------------------------------------------------------------
+
+/// Adapter targeting the [`yew`] framework.
+pub mod yew {
+    use super::*;
+
+    /// Render the boundary into a HTML string using the shared renderer.
+    pub fn render<T, E>(props: &AsyncBoundaryProps, resource: &Resource<T, E>) -> String {
+        super::render_html(props, resource)
+    }
+}
+
+/// Adapter targeting the [`leptos`] framework.
+pub mod leptos {
+    use super::*;
+
+    /// Render the boundary into a HTML string using the shared renderer.
+    pub fn render<T, E>(props: &AsyncBoundaryProps, resource: &Resource<T, E>) -> String {
+        super::render_html(props, resource)
+    }
+}
+
+/// Adapter targeting the [`dioxus`] framework.
+pub mod dioxus {
+    use super::*;
+
+    /// Render the boundary into a HTML string using the shared renderer.
+    pub fn render<T, E>(props: &AsyncBoundaryProps, resource: &Resource<T, E>) -> String {
+        super::render_html(props, resource)
+    }
+}
+
+/// Adapter targeting the [`sycamore`] framework.
+pub mod sycamore {
+    use super::*;
+
+    /// Render the boundary into a HTML string using the shared renderer.
+    pub fn render<T, E>(props: &AsyncBoundaryProps, resource: &Resource<T, E>) -> String {
+        super::render_html(props, resource)
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_props() -> AsyncBoundaryProps {
+        AsyncBoundaryProps::new("<ul><li>Widget</li></ul>").with_automation_id("dashboard")
+    }
+
+    #[test]
+    fn loading_without_data_renders_the_skeleton() {
+        let mut resource: Resource<Vec<u32>, String> = Resource::new();
+        resource.start();
+        let html = render_html(&sample_props(), &resource);
+        assert!(html.contains("data-async-phase=\"loading\""));
+        assert!(html.contains("aria-busy=\"true\""));
+        assert_eq!(
+            html.matches("data-async-boundary-slot=\"skeleton-line\"")
+                .count(),
+            3
+        );
+        assert!(!html.contains("Widget"));
+    }
+
+    #[test]
+    fn resolved_data_swaps_in_the_children() {
+        let mut resource: Resource<Vec<u32>, String> = Resource::new();
+        resource.succeed(vec![1]);
+        let html = render_html(&sample_props(), &resource);
+        assert!(html.contains("data-async-phase=\"ready\""));
+        assert!(html.contains("aria-busy=\"false\""));
+        assert!(html.contains("Widget"));
+        assert!(!html.contains("skeleton-line"));
+    }
+
+    #[test]
+    fn revalidation_keeps_children_visible_while_busy() {
+        let mut resource: Resource<Vec<u32>, String> = Resource::new();
+        resource.succeed(vec![1]);
+        resource.start();
+        let html = render_html(&sample_props(), &resource);
+        assert!(html.contains("aria-busy=\"true\""));
+        assert!(html.contains("Widget"));
+        assert!(!html.contains("skeleton-line"));
+    }
+
+    #[test]
+    fn server_renders_are_deterministic_for_an_unresolved_resource() {
+        let resource: Resource<Vec<u32>, String> = Resource::new();
+        let props = sample_props().with_skeleton_lines(2);
+        let first = render_html(&props, &resource);
+        let second = render_html(&props, &resource);
+        assert_eq!(first, second);
+        assert!(first.contains("data-async-phase=\"idle\""));
+        assert!(first
+            .contains("data-rustic-async-boundary-root=\"rustic-async-boundary-dashboard-root\""));
+        assert_eq!(first.matches("skeleton-line").count(), 2);
+    }
+}
@@ -0,0 +1,382 @@
+//! File cards and attachment lists for upload surfaces.
+//!
+//! Upload flows need a consistent way to show what the user attached: a type
+//! icon, the file name, a human readable size, live upload progress and a
+//! remove affordance.  [`file_card_html`] renders one attachment and
+//! [`AttachmentListProps`] stacks the cards into an accessible list, so forms
+//! and comment boxes share the same markup instead of each hand-rolling it.
+//!
+//! Progress arrives as an [`UploadStatus`] snapshot per attachment — the
+//! contract a dropzone state machine reports while bytes move — and the card
+//! re-renders whatever the snapshot says, keeping SSR and client renders
+//! aligned.  Icons resolve by MIME type to icon names from the Material set;
+//! the card stamps the name on a `data-rustic-icon` span so applications
+//! inject the SVG through the `rustic-ui-icons` macros without this crate
+//! depending on the icon bundles.
+
+use rustic_ui_styled_engine::{css_with_theme, Style};
+
+/// Per-attachment upload lifecycle snapshot rendered by the file card.
+#[derive(Clone, Debug, PartialEq, Eq, Default)]
+pub enum UploadStatus {
+    /// Queued but not yet transferring.
+    #[default]
+    Pending,
+    /// Transfer underway; the payload is the percentage complete (0-100).
+    Uploading(u8),
+    /// Transfer finished successfully.
+    Complete,
+    /// Transfer failed; the payload is the message shown on the card.
+    Failed(String),
+}
+
+impl UploadStatus {
+    /// Stable string for the `data-upload-status` attribute.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Pending => "pending",
+            Self::Uploading(_) => "uploading",
+            Self::Complete => "complete",
+            Self::Failed(_) => "failed",
+        }
+    }
+}
+
+/// Metadata describing one attached file.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Attachment {
+    /// File name shown on the card.
+    pub name: String,
+    /// MIME type driving the icon choice, e.g. `application/pdf`.
+    pub mime: String,
+    /// Size in bytes, formatted for display via [`format_file_size`].
+    pub size_bytes: u64,
+    /// Upload lifecycle snapshot.
+    pub status: UploadStatus,
+    /// Optional automation identifier for analytics and end-to-end tests.
+    pub automation_id: Option<String>,
+}
+
+impl Attachment {
+    /// Creates a pending attachment from its metadata.
+    pub fn new(name: impl Into<String>, mime: impl Into<String>, size_bytes: u64) -> Self {
+        Self {
+            name: name.into(),
+            mime: mime.into(),
+            size_bytes,
+            status: UploadStatus::Pending,
+            automation_id: None,
+        }
+    }
+
+    /// Sets the upload lifecycle snapshot.
+    pub fn with_status(mut self, status: UploadStatus) -> Self {
+        self.status = status;
+        self
+    }
+
+    /// Sets the automation identifier stamped on the rendered hooks.
+    pub fn with_automation_id(mut self, id: impl Into<String>) -> Self {
+        self.automation_id = Some(id.into());
+        self
+    }
+}
+
+/// Shared properties accepted by all attachment list adapters.
+#[derive(Clone, Debug, PartialEq, Eq, Default)]
+pub struct AttachmentListProps {
+    /// Attachments rendered in order.
+    pub attachments: Vec<Attachment>,
+    /// Optional automation identifier for analytics and end-to-end tests.
+    pub automation_id: Option<String>,
+}
+
+impl AttachmentListProps {
+    /// Creates a list over the provided attachments.
+    pub fn new(attachments: Vec<Attachment>) -> Self {
+        Self {
+            attachments,
+            automation_id: None,
+        }
+    }
+
+    /// Sets the automation identifier stamped on the rendered hooks.
+    pub fn with_automation_id(mut self, id: impl Into<String>) -> Self {
+        self.automation_id = Some(id.into());
+        self
+    }
+}
+
+/// Material icon name for a MIME type.
+///
+/// The mapping is intentionally coarse — audits only expect users to tell
+/// documents, media and archives apart at a glance.  Unknown types fall back
+/// to the generic file icon.
+#[must_use]
+pub fn icon_name_for_mime(mime: &str) -> &'static str {
+    let (kind, subtype) = mime.split_once('/').unwrap_or((mime, ""));
+    match kind {
+        "image" => "image_24px",
+        "audio" => "audiotrack_24px",
+        "video" => "videocam_24px",
+        "text" => "description_24px",
+        "application" => match subtype {
+            "pdf" => "picture_as_pdf_24px",
+            "zip" | "gzip" | "x-tar" | "x-7z-compressed" => "folder_zip_24px",
+            "json" | "xml" => "description_24px",
+            _ => "insert_drive_file_24px",
+        },
+        _ => "insert_drive_file_24px",
+    }
+}
+
+/// Formats a byte count for display (`845 B`, `1.2 MB`, `34 GB`).
+///
+/// Values below ten carry one decimal so small files stay distinguishable
+/// without cluttering larger ones.
+#[must_use]
+pub fn format_file_size(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["KB", "MB", "GB", "TB"];
+    if bytes < 1024 {
+        return format!("{bytes} B");
+    }
+    let mut value = bytes as f64;
+    let mut unit = "B";
+    for next in UNITS {
+        if value < 1024.0 {
+            break;
+        }
+        value /= 1024.0;
+        unit = next;
+    }
+    if value < 10.0 {
+        format!("{value:.1} {unit}")
+    } else {
+        format!("{} {unit}", value.round() as u64)
+    }
+}
+
+/// Render a single attachment card.
+///
+/// The remove button carries `data-attachment-action="remove"` plus the
+/// attachment automation id so adapters can delegate clicks without per-card
+/// closures.
+#[must_use]
+pub fn file_card_html(attachment: &Attachment) -> String {
+    let attrs = crate::style_helpers::themed_attributes_html(
+        themed_file_card_style(),
+        vec![
+            ("role".to_string(), String::from("listitem")),
+            (
+                "data-upload-status".to_string(),
+                attachment.status.as_str().to_string(),
+            ),
+            (
+                crate::style_helpers::automation_data_attr("file-card", ["root"]),
+                crate::style_helpers::automation_id(
+                    "file-card",
+                    attachment.automation_id.as_deref(),
+                    ["root"],
+                ),
+            ),
+        ],
+    );
+    let icon = icon_name_for_mime(&attachment.mime);
+    let name = crate::render::escape_text(&attachment.name);
+    let size = format_file_size(attachment.size_bytes);
+    let status_html = match &attachment.status {
+        UploadStatus::Uploading(percent) => {
+            let percent = (*percent).min(100);
+            format!(
+                "<div role=\"progressbar\" aria-valuemin=\"0\" aria-valuemax=\"100\" \
+                 aria-valuenow=\"{percent}\" data-upload-progress=\"{percent}\"></div>"
+            )
+        }
+        UploadStatus::Failed(message) => format!(
+            "<p role=\"alert\">{}</p>",
+            crate::render::escape_text(message)
+        ),
+        UploadStatus::Pending | UploadStatus::Complete => String::new(),
+    };
+    format!(
+        "<li {attrs}>\
+         <span data-rustic-icon=\"{icon}\" aria-hidden=\"true\"></span>\
+         <span>{name}</span><span>{size}</span>{status_html}\
+         <button type=\"button\" data-attachment-action=\"remove\" aria-label=\"Remove {name}\">✕</button>\
+         </li>"
+    )
+}
+
+/// Shared rendering routine used by all adapters.
+fn render_html(props: &AttachmentListProps) -> String {
+    let attrs = crate::style_helpers::themed_attributes_html(
+        themed_attachment_list_style(),
+        vec![
+            ("role".to_string(), String::from("list")),
+            (
+                "data-component".to_string(),
+                crate::style_helpers::automation_id(
+                    "attachment-list",
+                    None,
+                    crate::style_helpers::NO_SEGMENTS,
+                ),
+            ),
+            (
+                crate::style_helpers::automation_data_attr("attachment-list", ["root"]),
+                crate::style_helpers::automation_id(
+                    "attachment-list",
+                    props.automation_id.as_deref(),
+                    ["root"],
+                ),
+            ),
+        ],
+    );
+    let items: String = props.attachments.iter().map(file_card_html).collect();
+    format!("<ul {attrs}>{items}</ul>")
+}
+
+/// Vertical stack styling for the list container.
+fn themed_attachment_list_style() -> Style {
+    css_with_theme!(
+        r#"
+        list-style: none;
+        margin: 0;
+        padding: 0;
+        display: flex;
+        flex-direction: column;
+        gap: ${gap};
+    "#,
+        gap = format!("{}px", theme.spacing(1)),
+    )
+}
+
+/// Card row styling: icon, name, size, progress/error and the remove button.
+fn themed_file_card_style() -> Style {
+    css_with_theme!(
+        r#"
+        display: flex;
+        align-items: center;
+        gap: ${gap};
+        padding: ${padding};
+        border: 1px solid ${border};
+        border-radius: 4px;
+        background: ${background};
+        color: ${color};
+        font-family: ${font_family};
+        font-size: 0.875rem;
+
+        &[data-upload-status='failed'] {
+            border-color: ${error_color};
+        }
+    "#,
+        gap = format!("{}px", theme.spacing(1)),
+        padding = format!("{}px {}px", theme.spacing(1), theme.spacing(2)),
+        border = theme.palette.active().neutral.clone(),
+        background = theme.palette.active().background_paper.clone(),
+        color = theme.palette.active().text_primary.clone(),
+        font_family = theme.typography.font_family.clone(),
+        error_color = theme.palette.active().danger.clone(),
+    )
+}
+
+// ---------------------------------------------------------------------------
+// Adapter implementations
+// ---------------------------------------------------------------------------
+
+pub mod yew {
+    use super::*;
+
+    /// Render the attachment list into a plain HTML string for SSR/hydration.
+    pub fn render(props: &AttachmentListProps) -> String {
+        super::render_html(props)
+    }
+}
+
+pub mod leptos {
+    use super::*;
+
+    /// Render the attachment list into a plain HTML string for SSR/hydration.
+    pub fn render(props: &AttachmentListProps) -> String {
+        super::render_html(props)
+    }
+}
+
+pub mod dioxus {
+    use super::*;
+
+    /// Render the attachment list into a plain HTML string for SSR/hydration.
+    pub fn render(props: &AttachmentListProps) -> String {
+        super::render_html(props)
+    }
+}
+
+pub mod sycamore {
+    use super::*;
+
+    /// Render the attachment list into a plain HTML string for SSR/hydration.
+    pub fn render(props: &AttachmentListProps) -> String {
+        super::render_html(props)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn mime_types_resolve_to_material_icon_names() {
+        assert_eq!(icon_name_for_mime("image/png"), "image_24px");
+        assert_eq!(icon_name_for_mime("application/pdf"), "picture_as_pdf_24px");
+        assert_eq!(icon_name_for_mime("application/zip"), "folder_zip_24px");
+        assert_eq!(
+            icon_name_for_mime("application/octet-stream"),
+            "insert_drive_file_24px"
+        );
+        assert_eq!(icon_name_for_mime("nonsense"), "insert_drive_file_24px");
+    }
+
+    #[test]
+    fn file_sizes_format_human_readably() {
+        assert_eq!(format_file_size(845), "845 B");
+        assert_eq!(format_file_size(1_229), "1.2 KB");
+        assert_eq!(format_file_size(5 * 1024 * 1024), "5.0 MB");
+        assert_eq!(format_file_size(34 * 1024 * 1024 * 1024), "34 GB");
+    }
+
+    #[test]
+    fn uploading_cards_expose_an_accessible_progressbar() {
+        let attachment = Attachment::new("report.pdf", "application/pdf", 2_048)
+            .with_status(UploadStatus::Uploading(40));
+        let html = file_card_html(&attachment);
+        assert!(html.contains("data-upload-status=\"uploading\""));
+        assert!(html.contains("aria-valuenow=\"40\""));
+        assert!(html.contains("data-rustic-icon=\"picture_as_pdf_24px\""));
+        assert!(html.contains("2.0 KB"));
+    }
+
+    #[test]
+    fn failed_cards_alert_and_keep_the_remove_action() {
+        let attachment = Attachment::new("a<b>.png", "image/png", 10)
+            .with_status(UploadStatus::Failed("Virus scan rejected".into()));
+        let html = file_card_html(&attachment);
+        assert!(html.contains("data-upload-status=\"failed\""));
+        assert!(html.contains("<p role=\"alert\">Virus scan rejected</p>"));
+        assert!(html.contains("data-attachment-action=\"remove\""));
+        assert!(html.contains("aria-label=\"Remove a&lt;b&gt;.png\""));
+    }
+
+    #[test]
+    fn lists_stack_cards_with_automation_hooks() {
+        let props = AttachmentListProps::new(vec![
+            Attachment::new("one.txt", "text/plain", 100).with_automation_id("first"),
+            Attachment::new("two.txt", "text/plain", 200),
+        ])
+        .with_automation_id("composer");
+        let html = render_html(&props);
+        assert!(html.contains("role=\"list\""));
+        assert!(html
+            .contains("data-rustic-attachment-list-root=\"rustic-attachment-list-composer-root\""));
+        assert!(html.contains("data-rustic-file-card-root=\"rustic-file-card-first-root\""));
+        assert_eq!(html.matches("<li ").count(), 2);
+    }
+}
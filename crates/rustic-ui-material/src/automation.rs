@@ -0,0 +1,329 @@
+//! Public builder for the deterministic automation selectors stamped onto
+//! Material markup.
+//!
+//! The sanitisation and formatting rules originally lived inside
+//! [`style_helpers`](crate::style_helpers) as private utilities shared by the
+//! select/menu renderers. Promoting them here makes the contract available to
+//! downstream crates (QA harnesses, analytics pipelines, custom adapters) and
+//! gives component authors a single [`AutomationIdBuilder`] instead of ad-hoc
+//! string formatting.
+//!
+//! # Automation contract
+//!
+//! * Every selector begins with [`COMPONENT_PREFIX`] so suites can glob on
+//!   `rustic-*` without tracking historical aliases.
+//! * Component adapters supply the logical `component` name (e.g. `"select"`,
+//!   `"table"`), the optional user-provided identifier and any descriptive
+//!   `segments` (such as `"trigger"` or `"row-3"`).
+//! * Under the default [`SanitisationPolicy::KebabCase`] every input collapses
+//!   to `kebab-case` so the same selector is produced regardless of whether the
+//!   caller wrote `snake_case`, spaces or uppercase values.
+//!
+//! The resulting ids are safe as DOM `id`s and as values for automation
+//! focused `data-*` attributes, guaranteeing SSR snapshots, client renders and
+//! integration tests agree on the same selectors.
+
+/// Global prefix applied to every automation selector emitted by Material components.
+pub const COMPONENT_PREFIX: &str = "rustic";
+
+/// Empty segment list for [`automation_id`]/[`automation_data_attr`] call
+/// sites that only need the component/user portion of the identifier.  The
+/// explicit type pins the generic parameters so callers avoid repeating
+/// turbofish annotations.
+pub const NO_SEGMENTS: [&str; 0] = [];
+
+/// Controls how raw identifier fragments are normalised before being joined.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum SanitisationPolicy {
+    /// Collapse every fragment to `kebab-case` (the workspace default).
+    #[default]
+    KebabCase,
+    /// Pass fragments through untouched. Reserved for callers that already
+    /// enforce their own naming scheme and need byte-for-byte stability.
+    Verbatim,
+}
+
+impl SanitisationPolicy {
+    fn apply(self, value: &str) -> String {
+        match self {
+            Self::KebabCase => sanitise(value),
+            Self::Verbatim => value.to_string(),
+        }
+    }
+}
+
+/// Fluent builder assembling automation ids and `data-*` attribute names.
+///
+/// ```
+/// use rustic_ui_material::automation::AutomationIdBuilder;
+///
+/// let builder = AutomationIdBuilder::new("select")
+///     .with_user_id("Team Menu")
+///     .with_segment("trigger");
+/// assert_eq!(builder.id(), "rustic-select-team-menu-trigger");
+/// assert_eq!(builder.data_attr(), "data-rustic-select-trigger");
+/// ```
+#[derive(Clone, Debug)]
+pub struct AutomationIdBuilder {
+    prefix: String,
+    component: String,
+    user_id: Option<String>,
+    segments: Vec<String>,
+    policy: SanitisationPolicy,
+}
+
+impl AutomationIdBuilder {
+    /// Start a builder for the given logical component name.
+    pub fn new(component: impl Into<String>) -> Self {
+        Self {
+            prefix: COMPONENT_PREFIX.to_string(),
+            component: component.into(),
+            user_id: None,
+            segments: Vec::new(),
+            policy: SanitisationPolicy::default(),
+        }
+    }
+
+    /// Override the workspace prefix. Reach for this only when embedding the
+    /// components inside a host application that mandates its own namespace.
+    pub fn with_prefix(mut self, prefix: impl Into<String>) -> Self {
+        self.prefix = prefix.into();
+        self
+    }
+
+    /// Attach the caller supplied identifier distinguishing this instance.
+    pub fn with_user_id(mut self, id: impl Into<String>) -> Self {
+        self.user_id = Some(id.into());
+        self
+    }
+
+    /// Attach an optional identifier, mirroring the `Option<&str>` shape most
+    /// prop structs store.
+    pub fn maybe_user_id(mut self, id: Option<&str>) -> Self {
+        self.user_id = id.map(str::to_string);
+        self
+    }
+
+    /// Append one descriptive segment (e.g. `"trigger"`, `"row-3"`).
+    pub fn with_segment(mut self, segment: impl Into<String>) -> Self {
+        self.segments.push(segment.into());
+        self
+    }
+
+    /// Append every segment from the iterator in order.
+    pub fn with_segments<I, S>(mut self, segments: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        self.segments
+            .extend(segments.into_iter().map(|s| s.as_ref().to_string()));
+        self
+    }
+
+    /// Select the sanitisation policy applied to every fragment.
+    pub fn with_policy(mut self, policy: SanitisationPolicy) -> Self {
+        self.policy = policy;
+        self
+    }
+
+    /// Compose the final automation id including the user identifier.
+    #[must_use]
+    pub fn id(&self) -> String {
+        self.compose(true)
+    }
+
+    /// Compose the attribute name for automation focused `data-*` hooks.
+    ///
+    /// The user identifier never participates because QA tooling expects the
+    /// attribute key to remain stable across component instances; only the
+    /// logical component name and descriptive segments contribute.
+    #[must_use]
+    pub fn data_attr(&self) -> String {
+        format!("data-{}", self.compose(false))
+    }
+
+    fn compose(&self, include_user_id: bool) -> String {
+        let mut parts = Vec::new();
+        parts.push(self.policy.apply(&self.component));
+
+        if include_user_id {
+            if let Some(id) = &self.user_id {
+                let fragment = self.policy.apply(id);
+                if !fragment.is_empty() {
+                    parts.push(fragment);
+                }
+            }
+        }
+
+        for segment in &self.segments {
+            let fragment = self.policy.apply(segment);
+            if !fragment.is_empty() {
+                parts.push(fragment);
+            }
+        }
+
+        format!("{}-{}", self.prefix, parts.join("-"))
+    }
+}
+
+/// Compose a deterministic automation DOM id that adheres to the workspace contract.
+///
+/// Thin wrapper over [`AutomationIdBuilder`] kept for the overwhelmingly common
+/// "component + optional user id + segments" shape used by every renderer.
+#[must_use]
+pub fn automation_id<I, S>(component: &str, user_id: Option<&str>, segments: I) -> String
+where
+    I: IntoIterator<Item = S>,
+    S: AsRef<str>,
+{
+    AutomationIdBuilder::new(component)
+        .maybe_user_id(user_id)
+        .with_segments(segments)
+        .id()
+}
+
+/// Compose the attribute name for automation-focused `data-*` hooks.
+///
+/// Unlike [`automation_id`], the attribute name never incorporates a user
+/// identifier; see [`AutomationIdBuilder::data_attr`] for the rationale.
+#[must_use]
+pub fn automation_data_attr<I, S>(component: &str, segments: I) -> String
+where
+    I: IntoIterator<Item = S>,
+    S: AsRef<str>,
+{
+    AutomationIdBuilder::new(component)
+        .with_segments(segments)
+        .data_attr()
+}
+
+/// Compose an automation id that falls back to the hydration allocator when
+/// the caller did not configure an explicit identifier.
+///
+/// Explicit automation ids are already deterministic, but anonymous component
+/// instances historically collapsed onto one shared selector, colliding as
+/// soon as a page rendered two of them. Allocating from
+/// [`rustic_ui_styled_engine::next_hydration_id`] keeps anonymous ids unique
+/// while remaining identical between the server render and client hydration
+/// (both passes reset the scope before rendering, see the styled engine's
+/// hydration module for the contract).
+#[must_use]
+pub fn hydration_scoped_id<I, S>(component: &str, user_id: Option<&str>, segments: I) -> String
+where
+    I: IntoIterator<Item = S>,
+    S: AsRef<str>,
+{
+    match user_id {
+        Some(_) => automation_id(component, user_id, segments),
+        None => {
+            let base = rustic_ui_styled_engine::next_hydration_id(component);
+            let mut id = base;
+            for segment in segments {
+                let sanitised = sanitise(segment.as_ref());
+                if !sanitised.is_empty() {
+                    id.push('-');
+                    id.push_str(&sanitised);
+                }
+            }
+            id
+        }
+    }
+}
+
+/// Normalise an arbitrary fragment to the `kebab-case` selector alphabet.
+///
+/// Invalid characters collapse to single hyphens which keeps CSS selectors and
+/// testing tooling happy; an all-invalid input degrades to `"component"` so the
+/// composed id never contains an empty part.
+#[must_use]
+pub fn sanitise(value: &str) -> String {
+    let mut output = String::new();
+    let mut prev_dash = false;
+
+    for ch in value.chars() {
+        let mapped = match ch {
+            'A'..='Z' => Some(ch.to_ascii_lowercase()),
+            'a'..='z' | '0'..='9' => Some(ch),
+            '-' | '_' | ' ' | ':' | '.' | '/' => None,
+            _ => None,
+        };
+
+        if let Some(valid) = mapped {
+            output.push(valid);
+            prev_dash = false;
+        } else if !prev_dash {
+            output.push('-');
+            prev_dash = true;
+        }
+    }
+
+    let trimmed = output.trim_matches('-').to_string();
+
+    if trimmed.is_empty() {
+        String::from("component")
+    } else {
+        trimmed
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn automation_id_sanitises_segments() {
+        let id = automation_id("Select", Some("Team Menu"), ["Trigger", "Primary"]);
+        assert_eq!(id, "rustic-select-team-menu-trigger-primary");
+    }
+
+    #[test]
+    fn automation_data_attr_excludes_user_segment() {
+        let attr = automation_data_attr("tooltip", ["surface"]);
+        assert_eq!(attr, "data-rustic-tooltip-surface");
+    }
+
+    #[test]
+    fn builder_matches_the_free_functions() {
+        let builder = AutomationIdBuilder::new("menu")
+            .maybe_user_id(Some("Primary Nav"))
+            .with_segment("item")
+            .with_segment("3");
+        assert_eq!(
+            builder.id(),
+            automation_id("menu", Some("Primary Nav"), ["item", "3"])
+        );
+        assert_eq!(
+            builder.data_attr(),
+            automation_data_attr("menu", ["item", "3"])
+        );
+    }
+
+    #[test]
+    fn custom_prefix_replaces_the_workspace_default() {
+        let id = AutomationIdBuilder::new("chip")
+            .with_prefix("acme")
+            .with_segment("label")
+            .id();
+        assert_eq!(id, "acme-chip-label");
+    }
+
+    #[test]
+    fn verbatim_policy_preserves_fragments() {
+        let id = AutomationIdBuilder::new("Grid")
+            .with_policy(SanitisationPolicy::Verbatim)
+            .with_segment("Row_7")
+            .id();
+        assert_eq!(id, "rustic-Grid-Row_7");
+    }
+
+    #[test]
+    fn all_invalid_user_id_degrades_to_placeholder() {
+        let id = AutomationIdBuilder::new("select")
+            .with_user_id("***")
+            .with_segment("trigger")
+            .id();
+        // `***` sanitises to the `component` placeholder rather than vanishing.
+        assert_eq!(id, "rustic-select-component-trigger");
+    }
+}
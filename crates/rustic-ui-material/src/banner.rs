@@ -0,0 +1,314 @@
+//! Material banner: persistent, non-modal prominent message.
+//!
+//! Banners sit at the top of the content region and stay there until the
+//! user acts, which separates them from both [`crate::snackbar`] (transient,
+//! auto-dismissing) and [`crate::alert`] (inline, tied to the content it
+//! annotates).  Per the Material spec a banner carries one message, an
+//! optional leading icon and **at most two** text actions — the last action
+//! doubles as the dismiss affordance, so the builder simply refuses a third.
+//! The tiny [`BannerState`] machine owns the dismissed flag; adapters route
+//! `data-banner-action` clicks into [`BannerState::dismiss`] and re-render,
+//! while [`BannerState::restore`] lets session flows resurface the banner.
+
+use rustic_ui_styled_engine::{css_with_theme, Style};
+
+/// Dismiss machine backing a banner instance.
+///
+/// Banners start visible and, unlike snackbars, never time out — only an
+/// explicit action hides them.
+#[derive(Debug, Clone, Default)]
+pub struct BannerState {
+    dismissed: bool,
+}
+
+impl BannerState {
+    /// Creates a visible banner.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Whether the banner is currently shown.
+    #[inline]
+    pub fn is_visible(&self) -> bool {
+        !self.dismissed
+    }
+
+    /// Hides the banner in response to an action click.
+    pub fn dismiss(&mut self) {
+        self.dismissed = true;
+    }
+
+    /// Resurfaces a previously dismissed banner (e.g. when the condition it
+    /// reports reoccurs later in the session).
+    pub fn restore(&mut self) {
+        self.dismissed = false;
+    }
+
+    /// Attributes for the banner container element.
+    pub fn attributes(&self) -> Vec<(&'static str, String)> {
+        let mut attrs = vec![("data-banner-visible", self.is_visible().to_string())];
+        if self.dismissed {
+            attrs.push(("hidden", String::new()));
+        }
+        attrs
+    }
+}
+
+/// A text button rendered in the banner's trailing action row.
+#[derive(Clone, Debug, PartialEq)]
+pub struct BannerAction {
+    /// Visible button label.
+    pub label: String,
+    /// Machine readable identifier stamped as `data-banner-action` so
+    /// adapters can route clicks without parsing labels.
+    pub action: String,
+}
+
+/// Shared properties accepted by all adapter implementations.
+#[derive(Clone, Debug, PartialEq, Default)]
+pub struct BannerProps {
+    /// Message body; banners carry a single line of copy per the spec.
+    pub message: String,
+    /// Optional leading `<svg>` glyph, rendered decoratively.
+    pub icon: Option<String>,
+    /// Up to two trailing text actions; additional entries are ignored.
+    pub actions: Vec<BannerAction>,
+    /// Optional automation identifier for analytics and end-to-end tests.
+    pub automation_id: Option<String>,
+}
+
+impl BannerProps {
+    /// Convenience constructor used by examples and tests.
+    pub fn new(message: impl Into<String>) -> Self {
+        Self {
+            message: message.into(),
+            ..Self::default()
+        }
+    }
+
+    /// Adds the decorative leading icon.
+    pub fn with_icon(mut self, svg: impl Into<String>) -> Self {
+        self.icon = Some(svg.into());
+        self
+    }
+
+    /// Appends a trailing action.  The Material spec caps banners at two
+    /// actions, so anything past the second is dropped rather than rendered.
+    pub fn with_action(mut self, label: impl Into<String>, action: impl Into<String>) -> Self {
+        if self.actions.len() < 2 {
+            self.actions.push(BannerAction {
+                label: label.into(),
+                action: action.into(),
+            });
+        }
+        self
+    }
+
+    /// Sets the automation identifier stamped on the rendered hooks.
+    pub fn with_automation_id(mut self, id: impl Into<String>) -> Self {
+        self.automation_id = Some(id.into());
+        self
+    }
+}
+
+/// Shared rendering routine used by all adapters.
+fn render_html(props: &BannerProps, state: &BannerState) -> String {
+    let mut attrs: Vec<(String, String)> = vec![
+        // Banners are persistent rather than interruptive, so they announce
+        // politely instead of claiming `role="alert"`.
+        ("role".to_string(), "status".to_string()),
+        (
+            "data-component".to_string(),
+            crate::style_helpers::automation_id("banner", None, crate::style_helpers::NO_SEGMENTS),
+        ),
+        (
+            crate::style_helpers::automation_data_attr("banner", ["root"]),
+            crate::style_helpers::automation_id("banner", props.automation_id.as_deref(), ["root"]),
+        ),
+    ];
+    attrs.extend(
+        state
+            .attributes()
+            .into_iter()
+            .map(|(key, value)| (key.to_string(), value)),
+    );
+    let attrs = crate::style_helpers::themed_attributes_html(themed_banner_style(), attrs);
+
+    let icon = props
+        .icon
+        .as_ref()
+        .map(|svg| format!("<span data-banner-icon=\"\" aria-hidden=\"true\">{svg}</span>"))
+        .unwrap_or_default();
+    let actions = if props.actions.is_empty() {
+        String::new()
+    } else {
+        let buttons: String = props
+            .actions
+            .iter()
+            .map(|action| {
+                format!(
+                    "<button type=\"button\" data-banner-action=\"{action}\">{label}</button>",
+                    action = crate::render::escape_text(&action.action),
+                    label = crate::render::escape_text(&action.label),
+                )
+            })
+            .collect();
+        format!("<div data-banner-actions=\"\">{buttons}</div>")
+    };
+    format!(
+        "<div {attrs}><div data-banner-content=\"\">{icon}<p>{message}</p></div>{actions}</div>",
+        message = crate::render::escape_text(&props.message),
+    )
+}
+
+/// Banner surface styling: full-width bar with a bottom divider and the
+/// action row pushed to the trailing edge per the Material layout.
+fn themed_banner_style() -> Style {
+    css_with_theme!(
+        r#"
+        display: flex;
+        flex-wrap: wrap;
+        align-items: center;
+        justify-content: space-between;
+        gap: ${gap};
+        padding: ${padding};
+        border-bottom: 1px solid ${divider};
+        background: ${surface};
+        color: ${text};
+        font-family: ${font_family};
+        font-size: 0.875rem;
+
+        & [data-banner-content] {
+            display: flex;
+            align-items: center;
+            gap: ${gap};
+        }
+
+        & [data-banner-icon] {
+            display: inline-flex;
+            color: ${accent};
+        }
+
+        & p {
+            margin: 0;
+        }
+
+        & [data-banner-actions] {
+            display: flex;
+            gap: ${gap};
+            margin-left: auto;
+        }
+
+        & [data-banner-actions] button {
+            border: none;
+            background: transparent;
+            color: ${accent};
+            font: inherit;
+            font-weight: 500;
+            text-transform: uppercase;
+            cursor: pointer;
+            padding: ${action_padding};
+        }
+    "#,
+        gap = format!("{}px", theme.spacing(2)),
+        padding = format!("{}px {}px", theme.spacing(1), theme.spacing(2)),
+        divider = theme.palette.active().text_secondary.clone(),
+        surface = theme.palette.active().background_paper.clone(),
+        text = theme.palette.active().text_primary.clone(),
+        font_family = theme.typography.font_family.clone(),
+        accent = theme.palette.active().primary.clone(),
+        action_padding = format!("{}px", theme.spacing(1)),
+    )
+}
+
+// ---------------------------------------------------------------------------
+// Adapter implementations
+// ---------------------------------------------------------------------------
+
+pub mod yew {
+    use super::*;
+
+    /// Render the banner into a plain HTML string for SSR/hydration.
+    pub fn render(props: &BannerProps, state: &BannerState) -> String {
+        super::render_html(props, state)
+    }
+}
+
+pub mod leptos {
+    use super::*;
+
+    /// Render the banner into a plain HTML string for SSR/hydration.
+    pub fn render(props: &BannerProps, state: &BannerState) -> String {
+        super::render_html(props, state)
+    }
+}
+
+pub mod dioxus {
+    use super::*;
+
+    /// Render the banner into a plain HTML string for SSR/hydration.
+    pub fn render(props: &BannerProps, state: &BannerState) -> String {
+        super::render_html(props, state)
+    }
+}
+
+pub mod sycamore {
+    use super::*;
+
+    /// Render the banner into a plain HTML string for SSR/hydration.
+    pub fn render(props: &BannerProps, state: &BannerState) -> String {
+        super::render_html(props, state)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn visible_banner_renders_message_icon_and_actions() {
+        let props = BannerProps::new("Your trial ends in 3 days")
+            .with_icon("<svg viewBox=\"0 0 24 24\"></svg>")
+            .with_action("Upgrade", "upgrade")
+            .with_action("Dismiss", "dismiss")
+            .with_automation_id("trial");
+        let html = render_html(&props, &BannerState::new());
+        assert!(html.contains("role=\"status\""));
+        assert!(html.contains("data-banner-visible=\"true\""));
+        assert!(html.contains("data-banner-icon=\"\" aria-hidden=\"true\""));
+        assert!(html.contains("data-banner-action=\"upgrade\">Upgrade</button>"));
+        assert!(html.contains("data-banner-action=\"dismiss\">Dismiss</button>"));
+        assert!(html.contains("data-rustic-banner-root=\"rustic-banner-trial-root\""));
+    }
+
+    #[test]
+    fn builder_caps_actions_at_two_per_the_spec() {
+        let props = BannerProps::new("msg")
+            .with_action("One", "one")
+            .with_action("Two", "two")
+            .with_action("Three", "three");
+        assert_eq!(props.actions.len(), 2);
+        assert!(!render_html(&props, &BannerState::new()).contains("three"));
+    }
+
+    #[test]
+    fn dismissing_hides_the_banner_until_restored() {
+        let mut state = BannerState::new();
+        state.dismiss();
+        let html = render_html(&BannerProps::new("msg"), &state);
+        assert!(html.contains("data-banner-visible=\"false\""));
+        assert!(html.contains("hidden=\"\""));
+
+        state.restore();
+        let html = render_html(&BannerProps::new("msg"), &state);
+        assert!(html.contains("data-banner-visible=\"true\""));
+        assert!(!html.contains("hidden=\"\""));
+    }
+
+    #[test]
+    fn bare_banner_skips_empty_icon_and_action_slots() {
+        let html = render_html(&BannerProps::new("msg"), &BannerState::new());
+        assert!(!html.contains("data-banner-icon"));
+        assert!(!html.contains("data-banner-actions"));
+    }
+}
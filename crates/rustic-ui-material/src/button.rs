@@ -46,16 +46,23 @@ impl ButtonProps {
 /// `<button>` element and the ARIA attributes emitted from [`ButtonState`] are
 /// merged into the final tag to enhance accessibility for assistive
 /// technologies.
-fn render_html(props: &ButtonProps, state: &ButtonState) -> String {
+pub(crate) fn render_html(props: &ButtonProps, state: &ButtonState) -> String {
     // Build an attribute string that includes the themed class and the latest
     // ARIA metadata from the state machine. The shared helper keeps adapters
     // extremely small while guaranteeing they all emit the same markup for SSR
-    // and hydration scenarios.
-    let attr_string = crate::style_helpers::themed_attributes_html(
+    // and hydration scenarios. Routing through the component-aware variant
+    // also honours any `theme.components` override registered under the
+    // `button` slot so design systems can restyle every button globally.
+    let attr_string = crate::style_helpers::component_themed_attributes_html(
+        "button",
         themed_button_style(),
         state.aria_attributes(),
     );
 
+    // Surface the render as a typed telemetry event so analytics pipelines
+    // observe component activity without scraping data attributes.
+    rustic_ui_utils::telemetry::emit("button", None, "render");
+
     // Final HTML representation. Individual adapters simply forward to this
     // function keeping rendering logic DRY and easy to evolve.
     format!("<button {}>{}</button>", attr_string, props.label)
@@ -73,7 +80,7 @@ fn render_html(props: &ButtonProps, state: &ButtonState) -> String {
 ///   sprinkling literal values around the codebase.
 /// * Padding, radius and focus outlines from the shared spacing and Joy token
 ///   helpers which keeps spatial relationships consistent across components.
-fn themed_button_style() -> Style {
+pub(crate) fn themed_button_style() -> Style {
     css_with_theme!(
         r#"
         background: ${background};
@@ -92,21 +99,21 @@ fn themed_button_style() -> Style {
         }
 
         &:focus-visible {
-            outline: ${focus_outline_width} solid ${focus_outline_color};
-            outline-offset: 2px;
+            outline: ${focus_outline};
+            outline-offset: ${focus_outline_offset};
         }
     "#,
-        background = theme.palette.primary.clone(),
-        hover_background = theme.palette.secondary.clone(),
-        text = theme.palette.background_paper.clone(),
+        background = theme.palette.active().primary.clone(),
+        hover_background = theme.palette.active().secondary.clone(),
+        text = theme.palette.active().background_paper.clone(),
         padding_y = format!("{}px", theme.spacing(1)),
         padding_x = format!("{}px", theme.spacing(2)),
         radius = format!("{}px", theme.joy.radius),
         font_family = theme.typography.font_family.clone(),
         font_weight = theme.typography.font_weight_medium.to_string(),
         letter_spacing = format!("{:.3}rem", theme.typography.button_letter_spacing),
-        focus_outline_width = format!("{}px", theme.joy.focus.thickness),
-        focus_outline_color = theme.palette.text_primary.clone()
+        focus_outline = crate::style_helpers::focus_outline(&theme),
+        focus_outline_offset = crate::style_helpers::focus_outline_offset(&theme)
     )
 }
 
@@ -156,6 +163,23 @@ pub mod yew {
     pub fn render(props: &ButtonProps, state: &ButtonState) -> String {
         super::render_html(props, state)
     }
+
+    /// Render the button with a native [`Callback`](yew::Callback) wired to
+    /// click events.
+    ///
+    /// The markup stays identical to [`render`]; the callback receives the raw
+    /// [`MouseEvent`](yew::events::MouseEvent) so callers can dispatch into
+    /// their [`ButtonState`] (for example via
+    /// [`ButtonState::press`](rustic_ui_headless::button::ButtonState::press))
+    /// and re-render, instead of re-querying the DOM.
+    #[cfg(feature = "yew")]
+    pub fn render_with_on_click(
+        props: &ButtonProps,
+        state: &ButtonState,
+        on_click: ::yew::Callback<::yew::events::MouseEvent>,
+    ) -> ::yew::Html {
+        crate::render::yew::delegated_click(super::render_html(props, state), on_click)
+    }
 }
 
 /// Adapter targeting the [`leptos`] framework.
@@ -167,6 +191,18 @@ pub mod leptos {
     pub fn render(props: &ButtonProps, state: &ButtonState) -> String {
         super::render_html(props, state)
     }
+
+    /// Render the button with a click handler, mirroring
+    /// [`yew::render_with_on_click`](super::yew::render_with_on_click) for
+    /// Leptos consumers.
+    #[cfg(feature = "leptos")]
+    pub fn render_with_on_click(
+        props: &ButtonProps,
+        state: &ButtonState,
+        on_click: impl Fn(::leptos::ev::MouseEvent) + 'static,
+    ) -> ::leptos::View {
+        crate::render::leptos::delegated_click(super::render_html(props, state), on_click)
+    }
 }
 
 // ---------------------------------------------------------------------------
@@ -47,7 +47,7 @@ fn resolve_style() -> Style {
         border: 1px solid ${border};
         padding: ${pad};
         "#,
-        border = theme.palette.primary.clone(),
+        border = theme.palette.active().primary.clone(),
         pad = format!("{}px", theme.spacing(2))
     )
 }
@@ -8,6 +8,7 @@
 use rustic_ui_headless::checkbox::CheckboxState;
 use rustic_ui_styled_engine::{css_with_theme, Style};
 
+use crate::macros::Size;
 use crate::selection_control;
 
 /// Props shared across all framework adapters.
@@ -15,6 +16,10 @@ use crate::selection_control;
 pub struct CheckboxProps {
     /// Visible label rendered alongside the checkbox indicator.
     pub label: String,
+    /// Density preset scaling the indicator and paddings; see
+    /// [`style_helpers::control_spacing_scale`](crate::style_helpers) for the
+    /// mapping to the theme density tokens.
+    pub size: Size,
 }
 
 impl CheckboxProps {
@@ -22,24 +27,33 @@ impl CheckboxProps {
     pub fn new(label: impl Into<String>) -> Self {
         Self {
             label: label.into(),
+            size: Size::Medium,
         }
     }
+
+    /// Override the density preset, compacting or enlarging the control.
+    pub fn with_size(mut self, size: Size) -> Self {
+        self.size = size;
+        self
+    }
 }
 
 fn render_html(props: &CheckboxProps, state: &CheckboxState) -> String {
-    let attrs = state
+    let mut attrs: Vec<(String, String)> = state
         .aria_attributes()
         .into_iter()
         .map(|(k, v)| (k.to_string(), v))
         .collect();
-    selection_control::render_toggle(&props.label, themed_checkbox_style(), attrs)
+    attrs.push(("data-size".into(), props.size.as_str().into()));
+    selection_control::render_toggle(&props.label, themed_checkbox_style(&props.size), attrs)
 }
 
 /// Generates the themed style for the checkbox container. The macro pulls
 /// palette colors, typography metrics and spacing tokens from the active
 /// [`Theme`](rustic_ui_styled_engine::Theme) so enterprise teams can rely on global
-/// design governance rather than tweaking individual components.
-fn themed_checkbox_style() -> Style {
+/// design governance rather than tweaking individual components. The `size`
+/// preset scales the indicator and gaps through the shared density helpers.
+fn themed_checkbox_style(size: &Size) -> Style {
     css_with_theme!(
         r#"
         display: inline-flex;
@@ -71,8 +85,8 @@ fn themed_checkbox_style() -> Style {
         }
 
         &[data-focus-visible='true'] {
-            outline: ${focus_outline_width} solid ${focus_outline_color};
-            outline-offset: 2px;
+            outline: ${focus_outline};
+            outline-offset: ${focus_outline_offset};
         }
 
         &[aria-disabled='true'] {
@@ -80,20 +94,20 @@ fn themed_checkbox_style() -> Style {
             opacity: 0.38;
         }
     "#,
-        gap = format!("{}px", theme.spacing(1)),
+        gap = crate::style_helpers::control_spacing_px(&theme, size, 1),
         padding_y = format!("{}px", theme.spacing(0)),
         padding_x = format!("{}px", theme.spacing(0)),
         radius = format!("{}px", theme.joy.radius),
-        text_color = theme.palette.text_primary.clone(),
+        text_color = theme.palette.active().text_primary.clone(),
         font_family = theme.typography.font_family.clone(),
-        font_size = format!("{:.3}rem", theme.typography.body1),
-        box_size = format!("{}px", theme.spacing(2)),
+        font_size = crate::style_helpers::control_font_size(size),
+        box_size = crate::style_helpers::control_spacing_px(&theme, size, 2),
         box_radius = format!("{}px", theme.joy.radius),
-        border_color = theme.palette.text_secondary.clone(),
-        box_background = theme.palette.background_paper.clone(),
-        checked_background = theme.palette.primary.clone(),
-        focus_outline_width = format!("{}px", theme.joy.focus.thickness),
-        focus_outline_color = theme.palette.primary.clone()
+        border_color = theme.palette.active().text_secondary.clone(),
+        box_background = theme.palette.active().background_paper.clone(),
+        checked_background = theme.palette.active().primary.clone(),
+        focus_outline = crate::style_helpers::focus_outline(&theme),
+        focus_outline_offset = crate::style_helpers::focus_outline_offset(&theme)
     )
 }
 
@@ -159,5 +173,15 @@ mod tests {
         let html = render_html(&props, &state);
         assert!(html.contains(">Accept<"));
         assert!(html.contains("aria-checked"));
+        // The default density stamps its stable identifier for automation.
+        assert!(html.contains("data-size=\"medium\""));
+    }
+
+    #[test]
+    fn render_html_reflects_size_preset() {
+        let props = CheckboxProps::new("Dense").with_size(Size::Small);
+        let state = CheckboxState::uncontrolled(false, false);
+        let html = render_html(&props, &state);
+        assert!(html.contains("data-size=\"small\""));
     }
 }
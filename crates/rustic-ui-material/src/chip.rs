@@ -24,7 +24,7 @@
 //! use rustic_ui_styled_engine::{StyleRegistry, Theme};
 //!
 //! let mut theme = Theme::default();
-//! theme.palette.secondary = "#D81B60".into();
+//! theme.palette.light.secondary = "#D81B60".into();
 //! let registry = StyleRegistry::new(theme.clone());
 //!
 //! let mut state = ChipState::new(ChipConfig::enterprise_defaults());
@@ -36,7 +36,7 @@
 //!     .with_delete_label("remove escalation");
 //!
 //! let html = chip_yew::render(&props, &state);
-//! assert!(html.contains("data-component=\"rustic_ui_chip\""));
+//! assert!(html.contains("data-component=\"rustic-chip\""));
 //! assert!(html.contains("data-rustic-chip-id=\"rustic-chip-feedback-chip\""));
 //!
 //! // Style collection mirrors the tooltip story so SSR snapshots remain themed.
@@ -51,6 +51,8 @@
 use rustic_ui_headless::chip::{ChipAttributes, ChipDeleteAttributes, ChipState};
 use rustic_ui_styled_engine::{css_with_theme, Style};
 
+use crate::macros::Size;
+
 /// Shared properties consumed by every chip adapter.
 #[derive(Clone, Debug)]
 pub struct ChipProps {
@@ -64,6 +66,10 @@ pub struct ChipProps {
     pub delete_icon: String,
     /// Mirrors [`ChipConfig::dismissible`] so render output matches behaviour.
     pub dismissible: bool,
+    /// Density preset scaling paddings and typography; see
+    /// [`style_helpers::control_spacing_scale`](crate::style_helpers) for the
+    /// mapping to the theme density tokens.
+    pub size: Size,
 }
 
 impl ChipProps {
@@ -75,6 +81,7 @@ impl ChipProps {
             delete_label: Some("Remove".into()),
             delete_icon: "✕".into(),
             dismissible: true,
+            size: Size::Medium,
         }
     }
 
@@ -101,16 +108,22 @@ impl ChipProps {
         self.dismissible = dismissible;
         self
     }
+
+    /// Override the density preset, compacting or enlarging the chip.
+    pub fn with_size(mut self, size: Size) -> Self {
+        self.size = size;
+        self
+    }
 }
 
 /// Shared rendering routine used by SSR and hydration aware adapters.
-fn render_html(props: &ChipProps, state: &ChipState) -> String {
+pub(crate) fn render_html(props: &ChipProps, state: &ChipState) -> String {
     let base_id = automation_base(props);
     let label_id = label_id(props);
     let delete_id = delete_id(props);
 
     let root_attrs = crate::style_helpers::themed_attributes_html(
-        themed_root_style(),
+        themed_root_style(&props.size),
         root_attributes(props, state, &base_id, &label_id, &delete_id),
     );
     let label_html = crate::render_helpers::render_element_html(
@@ -142,7 +155,11 @@ fn render_html(props: &ChipProps, state: &ChipState) -> String {
 
 /// Resolve the automation identifier base.
 fn automation_base(props: &ChipProps) -> String {
-    crate::style_helpers::automation_id("chip", props.automation_id.as_deref(), [])
+    crate::style_helpers::automation_id(
+        "chip",
+        props.automation_id.as_deref(),
+        crate::style_helpers::NO_SEGMENTS,
+    )
 }
 
 /// DOM id for the label span.
@@ -198,7 +215,7 @@ fn root_attributes(
     ));
     attrs.push((
         "data-component".into(),
-        crate::style_helpers::automation_id("chip", None, []),
+        crate::style_helpers::automation_id("chip", None, crate::style_helpers::NO_SEGMENTS),
     ));
     attrs.push(("data-visible".into(), state.is_visible().to_string()));
     attrs.push((
@@ -213,6 +230,7 @@ fn root_attributes(
         attrs.push((data_key.into(), data_value));
     }
     attrs.push(("data-dismissible".into(), props.dismissible.to_string()));
+    attrs.push(("data-size".into(), props.size.as_str().into()));
     attrs.push(("data-label-id".into(), label_id.to_string()));
     attrs.push(("data-delete-id".into(), delete_id.to_string()));
     attrs.push((
@@ -262,8 +280,9 @@ fn delete_attributes(
     attrs
 }
 
-/// Root container styling.
-fn themed_root_style() -> Style {
+/// Root container styling. The `size` preset scales paddings and typography
+/// through the shared density helpers.
+pub(crate) fn themed_root_style(size: &Size) -> Style {
     css_with_theme!(
         r#"
         display: inline-flex;
@@ -291,30 +310,33 @@ fn themed_root_style() -> Style {
         }
 
         &:focus-visible {
-            outline: ${focus_width} solid ${focus_color};
-            outline-offset: 2px;
+            outline: ${focus_outline};
+            outline-offset: ${focus_outline_offset};
         }
     "#,
-        gap = format!("{}px", theme.spacing(1)),
-        padding_y = format!("{}px", theme.spacing(1) / 2),
-        padding_x = format!("{}px", theme.spacing(1)),
+        gap = crate::style_helpers::control_spacing_px(&theme, size, 1),
+        padding_y = format!(
+            "{:.1}px",
+            f32::from(theme.spacing(1) / 2) * crate::style_helpers::control_spacing_scale(size)
+        ),
+        padding_x = crate::style_helpers::control_spacing_px(&theme, size, 1),
         radius = format!("{}px", theme.joy.radius),
-        background = theme.palette.background_paper.clone(),
-        text_color = theme.palette.text_primary.clone(),
+        background = theme.palette.active().background_paper.clone(),
+        text_color = theme.palette.active().text_primary.clone(),
         border_color = format!(
             "color-mix(in srgb, {} 28%, transparent)",
-            theme.palette.text_secondary.clone()
+            theme.palette.active().text_secondary.clone()
         ),
         font_family = theme.typography.font_family.clone(),
         font_size = format!("{:.3}rem", theme.typography.body2),
         line_height = format!("{:.2}", theme.typography.line_height),
-        focus_width = format!("{:.1}px", (theme.joy.focus.thickness as f32).max(1.0)),
-        focus_color = theme.palette.primary.clone(),
+        focus_outline = crate::style_helpers::focus_outline(&theme),
+        focus_outline_offset = crate::style_helpers::focus_outline_offset(&theme),
     )
 }
 
 /// Styling for the label span.
-fn themed_label_style() -> Style {
+pub(crate) fn themed_label_style() -> Style {
     css_with_theme!(
         r#"
         font-weight: ${font_weight};
@@ -326,7 +348,7 @@ fn themed_label_style() -> Style {
 }
 
 /// Styling for the delete button.
-fn themed_delete_style() -> Style {
+pub(crate) fn themed_delete_style() -> Style {
     css_with_theme!(
         r#"
         display: inline-flex;
@@ -353,17 +375,17 @@ fn themed_delete_style() -> Style {
         }
 
         &:focus-visible {
-            outline: ${focus_width} solid ${focus_color};
-            outline-offset: 2px;
+            outline: ${focus_outline};
+            outline-offset: ${focus_outline_offset};
         }
     "#,
         size = format!("{}px", theme.spacing(3)),
         radius = format!("{}px", theme.joy.radius / 2),
-        icon_color = theme.palette.text_secondary.clone(),
+        icon_color = theme.palette.active().text_secondary.clone(),
         font_family = theme.typography.font_family.clone(),
         font_size = format!("{:.3}rem", theme.typography.caption),
-        focus_width = format!("{:.1}px", (theme.joy.focus.thickness as f32).max(1.0)),
-        focus_color = theme.palette.primary.clone(),
+        focus_outline = crate::style_helpers::focus_outline(&theme),
+        focus_outline_offset = crate::style_helpers::focus_outline_offset(&theme),
     )
 }
 
@@ -397,6 +419,36 @@ pub mod yew {
     pub fn render(props: &ChipProps, state: &ChipState) -> String {
         super::render_html(props, state)
     }
+
+    /// Render the chip with a native callback fired when the delete
+    /// affordance is clicked.
+    ///
+    /// Clicks anywhere inside the chip are delegated from a wrapper node; the
+    /// adapter resolves the `data-chip-slot="delete"` hook emitted by the
+    /// shared renderer, so the callback only fires for the dismiss button.
+    /// Callers typically dispatch into
+    /// [`ChipState::request_delete`](rustic_ui_headless::chip::ChipState::request_delete)
+    /// and re-render with the updated state.
+    #[cfg(feature = "yew")]
+    pub fn render_with_on_delete(
+        props: &ChipProps,
+        state: &ChipState,
+        on_delete: ::yew::Callback<()>,
+    ) -> ::yew::Html {
+        use ::wasm_bindgen::JsCast;
+
+        let on_click = ::yew::Callback::from(move |event: ::yew::events::MouseEvent| {
+            let target = event
+                .target()
+                .and_then(|target| target.dyn_into::<::web_sys::Element>().ok());
+            if let Some(element) = target {
+                if let Ok(Some(_)) = element.closest("[data-chip-slot=\"delete\"]") {
+                    on_delete.emit(());
+                }
+            }
+        });
+        crate::render::yew::delegated_click(super::render_html(props, state), on_click)
+    }
 }
 
 /// Adapter targeting the [`leptos`] framework.
@@ -407,6 +459,30 @@ pub mod leptos {
     pub fn render(props: &ChipProps, state: &ChipState) -> String {
         super::render_html(props, state)
     }
+
+    /// Render the chip with a delete handler, mirroring
+    /// [`yew::render_with_on_delete`](super::yew::render_with_on_delete) for
+    /// Leptos consumers.
+    #[cfg(feature = "leptos")]
+    pub fn render_with_on_delete(
+        props: &ChipProps,
+        state: &ChipState,
+        on_delete: impl Fn() + 'static,
+    ) -> ::leptos::View {
+        use ::leptos::wasm_bindgen::JsCast;
+
+        let on_click = move |event: ::leptos::ev::MouseEvent| {
+            let target = event
+                .target()
+                .and_then(|target| target.dyn_into::<::leptos::web_sys::Element>().ok());
+            if let Some(element) = target {
+                if let Ok(Some(_)) = element.closest("[data-chip-slot=\"delete\"]") {
+                    on_delete();
+                }
+            }
+        };
+        crate::render::leptos::delegated_click(super::render_html(props, state), on_click)
+    }
 }
 
 /// Adapter targeting the [`dioxus`] framework.
@@ -444,9 +520,18 @@ mod tests {
         let state = ChipState::new(ChipConfig::default());
         let html = super::render_html(&props, &state);
 
-        assert!(html.contains("data-component=\"rustic_ui_chip\""));
+        assert!(html.contains("data-component=\"rustic-chip\""));
         assert!(html.contains("data-chip-slot=\"delete\""));
         assert!(html.contains("aria-hidden"));
+        assert!(html.contains("data-size=\"medium\""));
+    }
+
+    #[test]
+    fn render_html_reflects_size_preset() {
+        let props = ChipProps::new("Dense").with_size(Size::Small);
+        let state = ChipState::new(ChipConfig::default());
+        let html = super::render_html(&props, &state);
+        assert!(html.contains("data-size=\"small\""));
     }
 
     #[test]
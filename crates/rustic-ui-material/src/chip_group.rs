@@ -0,0 +1,471 @@
+//! Chip group: filter chips with wrap or single-line overflow layout.
+//!
+//! Dashboards filter by tags, statuses and owners through rows of chips;
+//! this module lays those rows out and delegates every selection decision to
+//! the headless [`ToggleButtonGroupState`], so single-select (exclusive) and
+//! multi-select filter groups share one code path with Joy's toggle
+//! buttons.  The wrapping [`ChipGroupState`] adds roving-tabindex keyboard
+//! navigation and, in single-line mode, an overflow popover: chips past the
+//! visible budget collapse behind a "+4 more" trigger whose menu stays in
+//! the SSR markup and unhides when [`ChipGroupState::toggle_overflow`]
+//! fires — the same pattern as the toolbar overflow menu.
+
+use rustic_ui_headless::toggle_button_group::{
+    ToggleButtonGroupChange, ToggleButtonGroupConfig, ToggleButtonGroupState,
+};
+use rustic_ui_styled_engine::{css_with_theme, Style};
+
+/// Selection semantics of the group.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ChipGroupSelection {
+    /// Any number of chips may be active (multi-select filters).
+    #[default]
+    Multiple,
+    /// At most one chip is active at a time (mutually exclusive filters).
+    Single,
+}
+
+/// Layout of the chip row.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ChipGroupLayout {
+    /// Chips wrap onto additional lines as needed.
+    #[default]
+    Wrap,
+    /// Chips stay on one line; the rest collapse behind a "+N more" trigger.
+    SingleLine,
+}
+
+impl ChipGroupLayout {
+    /// Stable identifier stamped into `data-chip-group-layout` hooks.
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Wrap => "wrap",
+            Self::SingleLine => "single-line",
+        }
+    }
+}
+
+/// State machine for a chip group: headless toggle selection plus roving
+/// focus and the single-line overflow flag.
+#[derive(Debug, Clone)]
+pub struct ChipGroupState {
+    toggles: ToggleButtonGroupState,
+    focused: usize,
+    overflow_open: bool,
+}
+
+impl ChipGroupState {
+    /// Creates the machine for `chip_count` chips with the given selection
+    /// semantics.
+    pub fn new(chip_count: usize, selection: ChipGroupSelection) -> Self {
+        Self {
+            toggles: ToggleButtonGroupState::new(ToggleButtonGroupConfig {
+                button_count: chip_count,
+                exclusive: selection == ChipGroupSelection::Single,
+                initial_pressed: Vec::new(),
+            }),
+            focused: 0,
+            overflow_open: false,
+        }
+    }
+
+    /// Underlying toggle machine, for adapters syncing server state.
+    pub fn toggles_mut(&mut self) -> &mut ToggleButtonGroupState {
+        &mut self.toggles
+    }
+
+    /// Whether the chip at `index` is currently active.
+    pub fn is_selected(&self, index: usize) -> bool {
+        self.toggles.is_pressed(index)
+    }
+
+    /// Indices of the active chips, ascending.
+    pub fn selected_indices(&self) -> Vec<usize> {
+        self.toggles.pressed_indices()
+    }
+
+    /// Toggles one chip, moving focus to it as pointer interaction does.
+    pub fn toggle(&mut self, index: usize) -> ToggleButtonGroupChange {
+        if index < self.toggles.button_count() {
+            self.focused = index;
+        }
+        self.toggles.toggle(index)
+    }
+
+    /// Index of the chip holding the roving tabindex.
+    pub fn focused(&self) -> usize {
+        self.focused
+    }
+
+    /// Moves focus to the next chip, wrapping at the end (ArrowRight).
+    pub fn focus_next(&mut self) {
+        let count = self.toggles.button_count();
+        if count > 0 {
+            self.focused = (self.focused + 1) % count;
+        }
+    }
+
+    /// Moves focus to the previous chip, wrapping at the start (ArrowLeft).
+    pub fn focus_prev(&mut self) {
+        let count = self.toggles.button_count();
+        if count > 0 {
+            self.focused = (self.focused + count - 1) % count;
+        }
+    }
+
+    /// Whether the single-line overflow menu is open.
+    pub fn overflow_open(&self) -> bool {
+        self.overflow_open
+    }
+
+    /// Opens or closes the overflow menu, returning the new state.
+    pub fn toggle_overflow(&mut self) -> bool {
+        self.overflow_open = !self.overflow_open;
+        self.overflow_open
+    }
+
+    /// Closes the overflow menu (Escape, outside click).
+    pub fn close_overflow(&mut self) {
+        self.overflow_open = false;
+    }
+
+    /// Attributes for one chip element: the toggle machine's ARIA verdicts
+    /// plus the roving tabindex.
+    pub fn chip_attributes(&self, index: usize) -> Vec<(&'static str, String)> {
+        let mut attrs = self.toggles.button_attributes(index);
+        attrs.push((
+            "tabindex",
+            if index == self.focused { "0" } else { "-1" }.to_string(),
+        ));
+        attrs
+    }
+}
+
+/// One chip within the group.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ChipGroupItem {
+    /// Visible chip label.
+    pub label: String,
+}
+
+impl ChipGroupItem {
+    /// Convenience constructor used by examples and tests.
+    pub fn new(label: impl Into<String>) -> Self {
+        Self {
+            label: label.into(),
+        }
+    }
+}
+
+/// Shared properties accepted by all adapter implementations.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ChipGroupProps {
+    /// Chips in display order, index-aligned with the machine.
+    pub chips: Vec<ChipGroupItem>,
+    /// Row layout; overflow counting only applies to single-line groups.
+    pub layout: ChipGroupLayout,
+    /// Chips shown inline before the rest collapse into the overflow
+    /// popover.  Ignored in wrap layout.
+    pub max_visible: usize,
+    /// Optional automation identifier for analytics and end-to-end tests.
+    pub automation_id: Option<String>,
+}
+
+impl ChipGroupProps {
+    /// Convenience constructor used by examples and tests.
+    pub fn new(chips: Vec<ChipGroupItem>) -> Self {
+        Self {
+            chips,
+            layout: ChipGroupLayout::default(),
+            max_visible: 4,
+            automation_id: None,
+        }
+    }
+
+    /// Selects the row layout.
+    pub fn with_layout(mut self, layout: ChipGroupLayout) -> Self {
+        self.layout = layout;
+        self
+    }
+
+    /// Overrides how many chips stay inline in single-line layout.
+    pub fn with_max_visible(mut self, count: usize) -> Self {
+        self.max_visible = count;
+        self
+    }
+
+    /// Sets the automation identifier stamped on the rendered hooks.
+    pub fn with_automation_id(mut self, id: impl Into<String>) -> Self {
+        self.automation_id = Some(id.into());
+        self
+    }
+}
+
+/// Renders one chip button at its machine index.
+fn render_chip(props_chip: &ChipGroupItem, state: &ChipGroupState, index: usize) -> String {
+    let mut attrs: Vec<(String, String)> = vec![
+        ("type".to_string(), "button".to_string()),
+        ("data-chip-index".to_string(), index.to_string()),
+    ];
+    attrs.extend(
+        state
+            .chip_attributes(index)
+            .into_iter()
+            .map(|(key, value)| (key.to_string(), value)),
+    );
+    format!(
+        "<button {}>{}</button>",
+        rustic_ui_utils::attributes_to_html(&attrs),
+        crate::render::escape_text(&props_chip.label),
+    )
+}
+
+/// Shared rendering routine used by all adapters.
+fn render_html(props: &ChipGroupProps, state: &ChipGroupState) -> String {
+    let attrs = crate::style_helpers::themed_attributes_html(
+        themed_chip_group_style(),
+        vec![
+            ("role".to_string(), "group".to_string()),
+            (
+                "data-chip-group-layout".to_string(),
+                props.layout.as_str().to_string(),
+            ),
+            (
+                crate::style_helpers::automation_data_attr("chip-group", ["root"]),
+                crate::style_helpers::automation_id(
+                    "chip-group",
+                    props.automation_id.as_deref(),
+                    ["root"],
+                ),
+            ),
+        ],
+    );
+
+    let visible_count = match props.layout {
+        ChipGroupLayout::Wrap => props.chips.len(),
+        ChipGroupLayout::SingleLine if props.max_visible < props.chips.len() => props.max_visible,
+        ChipGroupLayout::SingleLine => props.chips.len(),
+    };
+    let visible: String = props.chips[..visible_count]
+        .iter()
+        .enumerate()
+        .map(|(index, chip)| render_chip(chip, state, index))
+        .collect();
+
+    let overflow = if visible_count < props.chips.len() {
+        let menu_id = crate::style_helpers::automation_id(
+            "chip-group",
+            props.automation_id.as_deref(),
+            ["overflow-menu"],
+        );
+        let hidden_count = props.chips.len() - visible_count;
+        let items: String = props.chips[visible_count..]
+            .iter()
+            .enumerate()
+            .map(|(offset, chip)| {
+                format!(
+                    "<li role=\"none\">{}</li>",
+                    render_chip(chip, state, visible_count + offset)
+                )
+            })
+            .collect();
+        format!(
+            "<button type=\"button\" data-chip-group-overflow=\"trigger\" \
+             aria-haspopup=\"menu\" aria-expanded=\"{expanded}\" aria-controls=\"{menu_id}\">\
+             +{hidden_count} more</button>\
+             <ul role=\"menu\" aria-hidden=\"{hidden}\" id=\"{menu_id}\" \
+             data-chip-group-overflow=\"menu\">{items}</ul>",
+            expanded = state.overflow_open(),
+            hidden = !state.overflow_open(),
+        )
+    } else {
+        String::new()
+    };
+
+    format!("<div {attrs}>{visible}{overflow}</div>")
+}
+
+/// Chip row styling: pill-shaped toggles with the pressed state tinted from
+/// the theme's primary channel; single-line rows hide their own scrollbar
+/// because overflow lives in the popover instead.
+fn themed_chip_group_style() -> Style {
+    css_with_theme!(
+        r#"
+        display: flex;
+        flex-wrap: wrap;
+        align-items: center;
+        gap: ${gap};
+        font-family: ${font_family};
+
+        &[data-chip-group-layout='single-line'] {
+            flex-wrap: nowrap;
+            overflow: hidden;
+        }
+
+        & button[data-chip-index],
+        & [data-chip-group-overflow='trigger'] {
+            border: 1px solid ${outline};
+            border-radius: 16px;
+            padding: ${chip_padding};
+            background: ${surface};
+            color: ${text};
+            font: inherit;
+            font-size: 0.8125rem;
+            cursor: pointer;
+            white-space: nowrap;
+        }
+
+        & button[aria-pressed='true'] {
+            background: ${accent};
+            border-color: ${accent};
+            color: ${on_accent};
+        }
+
+        & [data-chip-group-overflow='menu'] {
+            position: absolute;
+            list-style: none;
+            margin: 0;
+            padding: ${menu_padding};
+            background: ${surface};
+            border: 1px solid ${outline};
+            border-radius: 4px;
+            display: flex;
+            flex-direction: column;
+            gap: ${gap};
+        }
+
+        & [data-chip-group-overflow='menu'][aria-hidden='true'] {
+            display: none;
+        }
+    "#,
+        gap = format!("{}px", theme.spacing(1)),
+        font_family = theme.typography.font_family.clone(),
+        outline = theme.palette.active().text_secondary.clone(),
+        chip_padding = format!("{}px {}px", theme.spacing(1) / 2, theme.spacing(2)),
+        surface = theme.palette.active().background_paper.clone(),
+        text = theme.palette.active().text_primary.clone(),
+        accent = theme.palette.active().primary.clone(),
+        on_accent = theme.palette.active().background_paper.clone(),
+        menu_padding = format!("{}px", theme.spacing(1)),
+    )
+}
+
+// ---------------------------------------------------------------------------
+// Adapter implementations
+// ---------------------------------------------------------------------------
+
+pub mod yew {
+    use super::*;
+
+    /// Render the chip group into a plain HTML string for SSR/hydration.
+    pub fn render(props: &ChipGroupProps, state: &ChipGroupState) -> String {
+        super::render_html(props, state)
+    }
+}
+
+pub mod leptos {
+    use super::*;
+
+    /// Render the chip group into a plain HTML string for SSR/hydration.
+    pub fn render(props: &ChipGroupProps, state: &ChipGroupState) -> String {
+        super::render_html(props, state)
+    }
+}
+
+pub mod dioxus {
+    use super::*;
+
+    /// Render the chip group into a plain HTML string for SSR/hydration.
+    pub fn render(props: &ChipGroupProps, state: &ChipGroupState) -> String {
+        super::render_html(props, state)
+    }
+}
+
+pub mod sycamore {
+    use super::*;
+
+    /// Render the chip group into a plain HTML string for SSR/hydration.
+    pub fn render(props: &ChipGroupProps, state: &ChipGroupState) -> String {
+        super::render_html(props, state)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn chips(count: usize) -> Vec<ChipGroupItem> {
+        (0..count)
+            .map(|index| ChipGroupItem::new(format!("Tag {index}")))
+            .collect()
+    }
+
+    #[test]
+    fn wrap_layout_renders_every_chip_inline() {
+        let props = ChipGroupProps::new(chips(6)).with_automation_id("filters");
+        let state = ChipGroupState::new(6, ChipGroupSelection::Multiple);
+        let html = render_html(&props, &state);
+        assert!(html.contains("data-chip-group-layout=\"wrap\""));
+        assert!(html.contains("Tag 5"));
+        assert!(!html.contains("data-chip-group-overflow"));
+        assert!(html.contains("data-rustic-chip-group-root=\"rustic-chip-group-filters-root\""));
+    }
+
+    #[test]
+    fn single_line_layout_counts_the_overflow() {
+        let props = ChipGroupProps::new(chips(7))
+            .with_layout(ChipGroupLayout::SingleLine)
+            .with_max_visible(3);
+        let mut state = ChipGroupState::new(7, ChipGroupSelection::Multiple);
+        let html = render_html(&props, &state);
+        assert!(html.contains("+4 more"));
+        assert!(html.contains("aria-expanded=\"false\""));
+        assert!(html.contains("role=\"menu\" aria-hidden=\"true\""));
+        // Hidden chips stay in the markup so opening never waits on a render.
+        assert!(html.contains("Tag 6"));
+
+        state.toggle_overflow();
+        let html = render_html(&props, &state);
+        assert!(html.contains("aria-expanded=\"true\""));
+        assert!(html.contains("role=\"menu\" aria-hidden=\"false\""));
+    }
+
+    #[test]
+    fn single_selection_groups_stay_exclusive() {
+        let mut state = ChipGroupState::new(3, ChipGroupSelection::Single);
+        state.toggle(0);
+        let change = state.toggle(2);
+        assert_eq!(change.pressed, vec![2]);
+        assert!(!state.is_selected(0));
+
+        let mut multi = ChipGroupState::new(3, ChipGroupSelection::Multiple);
+        multi.toggle(0);
+        multi.toggle(2);
+        assert_eq!(multi.selected_indices(), vec![0, 2]);
+    }
+
+    #[test]
+    fn selection_state_surfaces_through_aria_pressed() {
+        let props = ChipGroupProps::new(chips(3));
+        let mut state = ChipGroupState::new(3, ChipGroupSelection::Multiple);
+        state.toggle(1);
+        let html = render_html(&props, &state);
+        assert!(html.contains("data-chip-index=\"1\" role=\"button\" aria-pressed=\"true\""));
+    }
+
+    #[test]
+    fn roving_tabindex_wraps_in_both_directions() {
+        let mut state = ChipGroupState::new(3, ChipGroupSelection::Multiple);
+        assert_eq!(state.focused(), 0);
+        state.focus_prev();
+        assert_eq!(state.focused(), 2);
+        state.focus_next();
+        assert_eq!(state.focused(), 0);
+        assert!(state
+            .chip_attributes(0)
+            .contains(&("tabindex", "0".to_string())));
+        assert!(state
+            .chip_attributes(1)
+            .contains(&("tabindex", "-1".to_string())));
+    }
+}
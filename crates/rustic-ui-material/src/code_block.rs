@@ -0,0 +1,407 @@
+//! Material themed code block with clipboard support.
+//!
+//! Documentation sites and developer tools need the same three affordances on
+//! every snippet: stable class hooks a syntax highlighter can target, optional
+//! line numbers, and a copy-to-clipboard button.  The shared [`render_html`]
+//! routine emits all of them as deterministic SSR markup so Yew, Leptos,
+//! Dioxus and Sycamore adapters stay byte-for-byte identical.
+//!
+//! Highlighting itself stays out of scope: the `<code>` element carries the
+//! conventional `language-*` class plus a `data-language` attribute so
+//! Prism/highlight.js style tooling (or a build-time highlighter) can attach
+//! token colors.  Colors and spacing resolve through `css_with_theme!` from
+//! the active palette, so the block follows light/dark scheme switches without
+//! any component-level branching.
+//!
+//! Copying routes through [`rustic_ui_utils::clipboard`], keeping the
+//! component free of platform APIs: applications install a provider once and
+//! [`copy_code`] works identically in web, desktop and test environments.
+
+use rustic_ui_styled_engine::{css_with_theme, Style};
+use rustic_ui_utils::clipboard::{self, ClipboardError};
+
+/// Shared properties accepted by all adapter implementations.
+#[derive(Clone, Debug, PartialEq)]
+pub struct CodeBlockProps {
+    /// Raw source text rendered inside the block. Escaped during rendering.
+    pub code: String,
+    /// Language tag forwarded to the `language-*` class hook, e.g. `"rust"`.
+    pub language: Option<String>,
+    /// Whether per-line numbers render in the gutter.
+    pub show_line_numbers: bool,
+    /// Label on the copy button.
+    pub copy_label: String,
+    /// Optional automation identifier for analytics and end-to-end tests.
+    pub automation_id: Option<String>,
+}
+
+impl CodeBlockProps {
+    /// Convenience constructor used by examples and tests.
+    pub fn new(code: impl Into<String>) -> Self {
+        Self {
+            code: code.into(),
+            language: None,
+            show_line_numbers: false,
+            copy_label: String::from("Copy"),
+            automation_id: None,
+        }
+    }
+
+    /// Sets the language tag for syntax highlighting hooks.
+    pub fn with_language(mut self, language: impl Into<String>) -> Self {
+        self.language = Some(language.into());
+        self
+    }
+
+    /// Toggles the line number gutter.
+    pub fn with_line_numbers(mut self, enabled: bool) -> Self {
+        self.show_line_numbers = enabled;
+        self
+    }
+
+    /// Overrides the copy button label, e.g. for localisation.
+    pub fn with_copy_label(mut self, label: impl Into<String>) -> Self {
+        self.copy_label = label.into();
+        self
+    }
+
+    /// Sets the automation identifier stamped on the rendered hooks.
+    pub fn with_automation_id(mut self, id: impl Into<String>) -> Self {
+        self.automation_id = Some(id.into());
+        self
+    }
+}
+
+/// Shared rendering routine used by all adapters.
+fn render_html(props: &CodeBlockProps) -> String {
+    let language = props.language.as_deref().unwrap_or("plain");
+    let root_attrs = crate::style_helpers::themed_attributes_html(
+        themed_code_block_style(),
+        vec![
+            (
+                "data-component".to_string(),
+                crate::style_helpers::automation_id(
+                    "code-block",
+                    None,
+                    crate::style_helpers::NO_SEGMENTS,
+                ),
+            ),
+            (
+                crate::style_helpers::automation_data_attr("code-block", ["root"]),
+                crate::style_helpers::automation_id(
+                    "code-block",
+                    props.automation_id.as_deref(),
+                    ["root"],
+                ),
+            ),
+            ("data-language".to_string(), language.to_string()),
+            (
+                "data-line-numbers".to_string(),
+                props.show_line_numbers.to_string(),
+            ),
+        ],
+    );
+
+    let copy_attrs = crate::style_helpers::themed_attributes_html(
+        themed_copy_button_style(),
+        vec![
+            ("type".to_string(), String::from("button")),
+            (
+                "aria-label".to_string(),
+                String::from("Copy code to clipboard"),
+            ),
+            ("data-code-block-action".to_string(), String::from("copy")),
+        ],
+    );
+
+    rustic_ui_utils::telemetry::emit("code_block", props.automation_id.as_deref(), "render");
+
+    format!(
+        "<div {root_attrs}><button {copy_attrs}>{label}</button><pre><code class=\"language-{language}\">{body}</code></pre></div>",
+        label = props.copy_label,
+        body = code_markup(props),
+    )
+}
+
+/// Escape the source and, when the gutter is enabled, wrap each line in a span
+/// carrying its one-based number.  The number renders from CSS via
+/// `attr(data-code-line)` so it never pollutes clipboard selections.
+fn code_markup(props: &CodeBlockProps) -> String {
+    if !props.show_line_numbers {
+        return crate::render::escape_text(&props.code);
+    }
+    let mut body = String::new();
+    for (index, line) in props.code.lines().enumerate() {
+        body.push_str(&format!(
+            "<span data-code-line=\"{number}\">{content}\n</span>",
+            number = index + 1,
+            content = crate::render::escape_text(line),
+        ));
+    }
+    body
+}
+
+/// Copy the block's source through the installed clipboard provider.
+///
+/// Adapters call this from the copy button's click handler.  The outcome is
+/// surfaced as a telemetry action (`copy` or `copy-error`) so QA pipelines can
+/// confirm the affordance works on each platform without scraping the DOM.
+pub fn copy_code(props: &CodeBlockProps) -> Result<(), ClipboardError> {
+    let result = clipboard::copy_text(&props.code);
+    let action = match &result {
+        Ok(()) => "copy",
+        Err(_) => "copy-error",
+    };
+    rustic_ui_utils::telemetry::emit("code_block", props.automation_id.as_deref(), action);
+    result
+}
+
+/// Root styling: a themed panel with the copy button pinned to the corner.
+///
+/// Every color derives from the active palette scheme, so the block tracks
+/// light/dark (and high contrast) switches through the same tokens as the
+/// rest of the component set.
+fn themed_code_block_style() -> Style {
+    css_with_theme!(
+        r#"
+        position: relative;
+        background: ${background};
+        color: ${text};
+        border: 1px solid ${border_color};
+        border-radius: ${radius};
+        overflow: hidden;
+
+        & pre {
+            margin: 0;
+            padding: ${padding};
+            overflow-x: auto;
+        }
+
+        & code {
+            font-family: ${mono_family};
+            font-size: 0.875rem;
+            line-height: ${line_height};
+        }
+
+        &[data-line-numbers='true'] code span[data-code-line] {
+            display: block;
+        }
+
+        &[data-line-numbers='true'] code span[data-code-line]::before {
+            content: attr(data-code-line);
+            display: inline-block;
+            width: ${gutter_width};
+            margin-right: ${gutter_gap};
+            text-align: right;
+            color: ${gutter_color};
+            user-select: none;
+        }
+    "#,
+        background = format!(
+            "color-mix(in srgb, {} 4%, {})",
+            theme.palette.active().text_primary.clone(),
+            theme.palette.active().background_paper.clone()
+        ),
+        text = theme.palette.active().text_primary.clone(),
+        border_color = format!(
+            "color-mix(in srgb, {} 18%, transparent)",
+            theme.palette.active().text_secondary.clone()
+        ),
+        radius = format!("{}px", theme.joy.radius),
+        padding = format!("{}px", theme.spacing(2)),
+        mono_family = String::from(
+            "ui-monospace, SFMono-Regular, Menlo, Consolas, 'Liberation Mono', monospace"
+        ),
+        line_height = format!("{:.3}", theme.typography.line_height),
+        gutter_width = format!("{}px", theme.spacing(3)),
+        gutter_gap = format!("{}px", theme.spacing(2)),
+        gutter_color = theme.palette.active().text_secondary.clone(),
+    )
+}
+
+/// Discrete copy button floating above the scrollable code surface.
+fn themed_copy_button_style() -> Style {
+    css_with_theme!(
+        r#"
+        position: absolute;
+        top: ${offset};
+        right: ${offset};
+        padding: ${padding_y} ${padding_x};
+        background: ${background};
+        color: ${text};
+        border: 1px solid ${border_color};
+        border-radius: ${radius};
+        font-family: ${font_family};
+        font-size: 0.75rem;
+        cursor: pointer;
+        transition: background-color 160ms ease;
+
+        &:hover {
+            background: ${hover_background};
+        }
+
+        &:focus-visible {
+            outline: ${focus_outline};
+            outline-offset: ${focus_outline_offset};
+        }
+    "#,
+        offset = format!("{}px", theme.spacing(1)),
+        padding_y = format!("{}px", theme.spacing(0)),
+        padding_x = format!("{}px", theme.spacing(1)),
+        background = theme.palette.active().background_paper.clone(),
+        text = theme.palette.active().text_secondary.clone(),
+        border_color = format!(
+            "color-mix(in srgb, {} 24%, transparent)",
+            theme.palette.active().text_secondary.clone()
+        ),
+        radius = format!("{}px", theme.joy.radius),
+        font_family = theme.typography.font_family.clone(),
+        hover_background = format!(
+            "color-mix(in srgb, {} 8%, {})",
+            theme.palette.active().text_primary.clone(),
+            theme.palette.active().background_paper.clone()
+        ),
+        focus_outline = crate::style_helpers::focus_outline(&theme),
+        focus_outline_offset = crate::style_helpers::focus_outline_offset(&theme)
+    )
+}
+
+// ---------------------------------------------------------------------------
+// Adapter implementations
+// ---------------------------------------------------------------------------
+
+pub mod yew {
+    use super::*;
+
+    /// Render the code block into a plain HTML string for SSR/hydration.
+    pub fn render(props: &CodeBlockProps) -> String {
+        super::render_html(props)
+    }
+
+    /// Render the code block with the copy button wired to a callback.
+    ///
+    /// The callback typically forwards to [`copy_code`](super::copy_code) and
+    /// then flips local UI state (e.g. a "Copied!" toast).
+    #[cfg(feature = "yew")]
+    pub fn render_with_on_copy(
+        props: &CodeBlockProps,
+        on_copy: ::yew::Callback<()>,
+    ) -> ::yew::Html {
+        use ::wasm_bindgen::JsCast;
+
+        let on_click = ::yew::Callback::from(move |event: ::yew::events::MouseEvent| {
+            let target = event
+                .target()
+                .and_then(|target| target.dyn_into::<::web_sys::Element>().ok());
+            if let Some(element) = target {
+                if let Ok(Some(_)) = element.closest("[data-code-block-action=\"copy\"]") {
+                    on_copy.emit(());
+                }
+            }
+        });
+        crate::render::yew::delegated_click(super::render_html(props), on_click)
+    }
+}
+
+pub mod leptos {
+    use super::*;
+
+    /// Render the code block into a plain HTML string for SSR/hydration.
+    pub fn render(props: &CodeBlockProps) -> String {
+        super::render_html(props)
+    }
+
+    /// Render the code block with a copy handler, mirroring
+    /// [`yew::render_with_on_copy`](super::yew::render_with_on_copy) for
+    /// Leptos consumers.
+    #[cfg(feature = "leptos")]
+    pub fn render_with_on_copy(
+        props: &CodeBlockProps,
+        on_copy: impl Fn() + 'static,
+    ) -> ::leptos::View {
+        use ::leptos::wasm_bindgen::JsCast;
+
+        let on_click = move |event: ::leptos::ev::MouseEvent| {
+            let target = event
+                .target()
+                .and_then(|target| target.dyn_into::<::leptos::web_sys::Element>().ok());
+            if let Some(element) = target {
+                if let Ok(Some(_)) = element.closest("[data-code-block-action=\"copy\"]") {
+                    on_copy();
+                }
+            }
+        };
+        crate::render::leptos::delegated_click(super::render_html(props), on_click)
+    }
+}
+
+pub mod dioxus {
+    use super::*;
+
+    /// Render the code block into a plain HTML string for SSR/hydration.
+    pub fn render(props: &CodeBlockProps) -> String {
+        super::render_html(props)
+    }
+}
+
+pub mod sycamore {
+    use super::*;
+
+    /// Render the code block into a plain HTML string for SSR/hydration.
+    pub fn render(props: &CodeBlockProps) -> String {
+        super::render_html(props)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rustic_ui_utils::clipboard::MemoryClipboard;
+    use std::sync::Arc;
+
+    #[test]
+    fn render_html_emits_language_and_automation_hooks() {
+        let props = CodeBlockProps::new("let x = 1;")
+            .with_language("rust")
+            .with_automation_id("docs-snippet");
+        let html = render_html(&props);
+        assert!(html.contains("class=\"language-rust\""));
+        assert!(html.contains("data-language=\"rust\""));
+        assert!(
+            html.contains("data-rustic-code-block-root=\"rustic-code-block-docs-snippet-root\"")
+        );
+        assert!(html.contains("data-code-block-action=\"copy\""));
+        assert!(html.contains(">Copy</button>"));
+    }
+
+    #[test]
+    fn line_numbers_wrap_each_line_in_a_numbered_span() {
+        let props = CodeBlockProps::new("first\nsecond").with_line_numbers(true);
+        let html = render_html(&props);
+        assert!(html.contains("data-line-numbers=\"true\""));
+        assert!(html.contains("<span data-code-line=\"1\">first"));
+        assert!(html.contains("<span data-code-line=\"2\">second"));
+
+        let plain = render_html(&CodeBlockProps::new("first\nsecond"));
+        assert!(!plain.contains("data-code-line"));
+    }
+
+    #[test]
+    fn source_is_escaped_before_interpolation() {
+        let props = CodeBlockProps::new("if a < b && c > d {}");
+        let html = render_html(&props);
+        assert!(html.contains("if a &lt; b &amp;&amp; c &gt; d {}"));
+        assert!(!html.contains("if a < b"));
+    }
+
+    #[test]
+    fn copy_code_routes_through_the_clipboard_provider() {
+        let provider = Arc::new(MemoryClipboard::default());
+        clipboard::install_provider(provider.clone());
+        let props = CodeBlockProps::new("cargo test").with_automation_id("copy-me");
+        copy_code(&props).expect("provider accepts the write");
+        clipboard::reset_provider();
+        assert!(copy_code(&props).is_err());
+        assert_eq!(provider.copies(), vec!["cargo test".to_string()]);
+    }
+}
@@ -17,7 +17,7 @@
 //! ## Style composition
 //! * [`css_with_theme!`](rustic_ui_styled_engine::css_with_theme) powers every
 //!   adapter. The macro exposes a `theme` binding so border colours pull from
-//!   `theme.palette.secondary` while padding respects `theme.spacing(3)`.
+//!   `theme.palette.active().secondary` while padding respects `theme.spacing(3)`.
 //!   Wrapping the declaration inside
 //!   [`style_helpers::themed_class`](crate::style_helpers::themed_class) produces
 //!   a deterministic class name that can be safely reused across renders without
@@ -73,7 +73,7 @@ fn resolve_style() -> Style {
         "#,
         // Pull colors and spacing from the theme so consumers only tweak
         // global tokens instead of individual components.
-        border = theme.palette.secondary.clone(),
+        border = theme.palette.active().secondary.clone(),
         pad = format!("{}px", theme.spacing(3))
     )
 }
@@ -170,9 +170,14 @@ pub fn dialog_surface_attributes(
     }
     let (trap_key, trap_value) = attrs.data_focus_trap();
     pairs.push((trap_key.into(), trap_value.into()));
-    if let Some((key, value)) = attrs.data_analytics_id() {
+    let analytics_id = attrs.data_analytics_id();
+    if let Some((key, value)) = analytics_id {
         pairs.push((key.into(), value.into()));
     }
+    // Every adapter funnels through this helper, making it the natural spot
+    // to emit a typed telemetry event; the analytics identifier mirrors the
+    // `data-analytics-id` attribute so pipelines no longer scrape the DOM.
+    rustic_ui_utils::telemetry::emit("dialog", analytics_id.map(|(_, value)| value), "render");
     pairs
 }
 
@@ -9,7 +9,8 @@
 //! Yew/Leptos/Dioxus/Sycamore without forcing each team to juggle bespoke CSS.
 
 use rustic_ui_headless::drawer::{
-    DrawerAnchor, DrawerBackdropAttributes, DrawerState, DrawerSurfaceAttributes, DrawerVariant,
+    DrawerAnchor, DrawerBackdropAttributes, DrawerState, DrawerSurfaceAttributes, DrawerSwipeState,
+    DrawerVariant,
 };
 use rustic_ui_styled_engine::{css_with_theme, Style};
 use rustic_ui_system::{
@@ -180,6 +181,41 @@ pub fn drawer_surface_attributes(
     pairs
 }
 
+/// Attributes translating an in-flight swipe gesture onto the surface.
+///
+/// Adapters feed touch events into a
+/// [`DrawerSwipeState`](rustic_ui_headless::drawer::DrawerSwipeState) and
+/// append these pairs to [`drawer_surface_attributes`] on every move so the
+/// surface follows the finger: the inline transform overrides the anchor's
+/// resting translation and the transition is suspended while dragging so the
+/// surface tracks 1:1 instead of easing behind the touch.  Once
+/// [`touch_end`](rustic_ui_headless::drawer::DrawerSwipeState::touch_end)
+/// settles the gesture the vector comes back empty and the stylesheet's
+/// animated `data-open` transforms take over again.
+#[must_use]
+pub fn drawer_swipe_attributes(
+    anchor: DrawerAnchor,
+    swipe: &DrawerSwipeState,
+) -> Vec<(String, String)> {
+    if !swipe.is_dragging() {
+        return Vec::new();
+    }
+    let offset = swipe.surface_offset();
+    let transform = match anchor {
+        DrawerAnchor::Start => format!("translateX(-{offset:.1}px)"),
+        DrawerAnchor::End => format!("translateX({offset:.1}px)"),
+        DrawerAnchor::Top => format!("translateY(-{offset:.1}px)"),
+        DrawerAnchor::Bottom => format!("translateY({offset:.1}px)"),
+    };
+    vec![
+        ("data-swiping".into(), "true".into()),
+        (
+            "style".into(),
+            format!("transform:{transform};transition:none;opacity:1;visibility:visible;"),
+        ),
+    ]
+}
+
 /// Render the drawer surface to HTML using the shared renderer.
 #[must_use]
 pub fn render_drawer_surface_html(
@@ -247,7 +283,7 @@ fn drawer_surface_style() -> Style {
         width: min(100%, ${width_mobile});
         max-width: 100%;
         box-shadow: 0 24px 54px -36px color-mix(in srgb, ${shadow_base} 65%, transparent);
-        transition: transform 200ms ease, opacity 200ms ease, visibility 200ms ease;
+        transition: ${transitions};
         &[data-anchor="start"] {
             left: 0;
             transform: translateX(-100%);
@@ -291,8 +327,8 @@ fn drawer_surface_style() -> Style {
             width: ${width_desktop};
         }
     "#,
-        background = theme.palette.background_paper.clone(),
-        text = theme.palette.text_primary.clone(),
+        background = theme.palette.active().background_paper.clone(),
+        text = theme.palette.active().text_primary.clone(),
         gap = format!("{}px", theme.spacing(2)),
         padding_mobile = format!("{}px", theme.spacing(2)),
         padding_tablet = format!("{}px", theme.spacing(3)),
@@ -300,7 +336,10 @@ fn drawer_surface_style() -> Style {
         width_mobile = format!("{}px", theme.spacing(40)),
         width_tablet = format!("{}px", theme.spacing(48)),
         width_desktop = format!("{}px", theme.spacing(56)),
-        shadow_base = theme.palette.neutral.clone(),
+        shadow_base = theme.palette.active().neutral.clone(),
+        transitions = theme
+            .motion
+            .transition(&["transform", "opacity", "visibility"]),
         sm = theme.breakpoints.sm,
         lg = theme.breakpoints.lg,
     )
@@ -313,7 +352,7 @@ fn drawer_backdrop_style() -> Style {
         position: fixed;
         inset: 0;
         background: color-mix(in srgb, ${scrim} 65%, transparent);
-        transition: opacity 200ms ease;
+        transition: ${transitions};
         opacity: 0;
         &[data-open="true"] {
             opacity: 1;
@@ -322,7 +361,8 @@ fn drawer_backdrop_style() -> Style {
             pointer-events: none;
         }
     "#,
-        scrim = theme.palette.text_primary.clone(),
+        scrim = theme.palette.active().text_primary.clone(),
+        transitions = theme.motion.transition(&["opacity"]),
     )
 }
 
@@ -530,6 +570,36 @@ mod tests {
         assert!(html.contains("<nav>Links</nav>"));
     }
 
+    #[test]
+    fn swipe_attributes_follow_the_finger_while_dragging() {
+        use rustic_ui_headless::drawer::{DrawerSwipeConfig, DrawerSwipeState};
+
+        let mut swipe = DrawerSwipeState::new(DrawerSwipeConfig::enterprise_defaults(300.0));
+        assert!(drawer_swipe_attributes(DrawerAnchor::Start, &swipe).is_empty());
+
+        swipe.touch_start(10.0, 0.0, false);
+        swipe.touch_move(100.0, 400.0);
+        let attrs = drawer_swipe_attributes(DrawerAnchor::Start, &swipe);
+        assert!(attrs
+            .iter()
+            .any(|(k, v)| k == "data-swiping" && v == "true"));
+        assert!(attrs
+            .iter()
+            .any(|(k, v)| k == "style" && v.contains("transform:translateX(-210.0px)")));
+        assert!(attrs
+            .iter()
+            .any(|(k, v)| k == "style" && v.contains("transition:none")));
+
+        // End anchors translate in the opposite direction.
+        let end = drawer_swipe_attributes(DrawerAnchor::End, &swipe);
+        assert!(end
+            .iter()
+            .any(|(k, v)| k == "style" && v.contains("translateX(210.0px)")));
+
+        swipe.touch_end();
+        assert!(drawer_swipe_attributes(DrawerAnchor::Start, &swipe).is_empty());
+    }
+
     #[test]
     fn backdrop_renders_only_for_modal_variants() {
         let modal = sample_state(true, DrawerVariant::Modal);
@@ -0,0 +1,423 @@
+//! Catch child render failures and degrade to a themed fallback.
+//!
+//! Enterprise dashboards compose dozens of widgets; a single panicking chart
+//! should not blank the whole page.  This module formalizes the recovery
+//! path: the boundary runs a child render closure, records any panic (or
+//! application-level error) in [`ErrorBoundaryState`], and swaps in a themed
+//! fallback card with a retry affordance.  Every capture is surfaced as a
+//! typed telemetry event so operations teams see degraded widgets without
+//! scraping logs.
+//!
+//! Panic capture uses [`std::panic::catch_unwind`], which is effective on
+//! native targets (SSR pipelines, tests).  On `wasm32` panics abort rather
+//! than unwind, so browser adapters should route fallible work through
+//! [`render_result`] and reserve the catching variant for server rendering.
+
+use std::panic::{catch_unwind, AssertUnwindSafe};
+
+use rustic_ui_styled_engine::{css_with_theme, Style};
+
+/// Shared boundary properties consumed by every adapter.
+#[derive(Clone, Debug)]
+pub struct ErrorBoundaryProps {
+    /// Human friendly message shown inside the fallback card.  Error details
+    /// stay in [`ErrorBoundaryState`] and telemetry; the markup never leaks
+    /// panic payloads to end users.
+    pub fallback_message: String,
+    /// Label rendered on the retry button.
+    pub retry_label: String,
+    /// Optional automation identifier stamped into `data-*` hooks.
+    pub automation_id: Option<String>,
+}
+
+impl Default for ErrorBoundaryProps {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ErrorBoundaryProps {
+    /// Boundary with the stock copy used across the workspace.
+    pub fn new() -> Self {
+        Self {
+            fallback_message: "Something went wrong.".into(),
+            retry_label: "Retry".into(),
+            automation_id: None,
+        }
+    }
+
+    /// Override the message shown in the fallback card.
+    pub fn with_fallback_message(mut self, message: impl Into<String>) -> Self {
+        self.fallback_message = message.into();
+        self
+    }
+
+    /// Override the retry button label.
+    pub fn with_retry_label(mut self, label: impl Into<String>) -> Self {
+        self.retry_label = label.into();
+        self
+    }
+
+    /// Override the automation identifier.
+    pub fn with_automation_id(mut self, id: impl Into<String>) -> Self {
+        self.automation_id = Some(id.into());
+        self
+    }
+}
+
+/// Failure tracker owned by the framework adapter.
+///
+/// Frameworks keep this alongside their other component state; the retry
+/// handler calls [`ErrorBoundaryState::retry`] and re-renders, giving the
+/// children another chance while counting attempts for observability.
+#[derive(Clone, Debug, Default)]
+pub struct ErrorBoundaryState {
+    error: Option<String>,
+    retries: u32,
+}
+
+impl ErrorBoundaryState {
+    /// Healthy boundary with no recorded failure.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns whether the fallback should render instead of the children.
+    pub fn has_error(&self) -> bool {
+        self.error.is_some()
+    }
+
+    /// Detail of the most recent failure, if any.
+    pub fn error(&self) -> Option<&str> {
+        self.error.as_deref()
+    }
+
+    /// Record a failure detail, switching subsequent renders to the fallback.
+    pub fn capture(&mut self, detail: impl Into<String>) {
+        self.error = Some(detail.into());
+    }
+
+    /// Clear the failure so the next render re-attempts the children.
+    pub fn retry(&mut self) {
+        self.error = None;
+        self.retries += 1;
+    }
+
+    /// Number of retries requested so far.
+    pub fn retries(&self) -> u32 {
+        self.retries
+    }
+}
+
+/// Run the child renderer inside the boundary, catching panics.
+///
+/// Healthy children render straight through.  A panic is captured into the
+/// state, reported via telemetry and replaced by [`render_fallback`] markup.
+/// When the state already holds an error the children are skipped entirely —
+/// the adapter decides when to re-attempt by calling
+/// [`ErrorBoundaryState::retry`].
+pub fn render_caught(
+    props: &ErrorBoundaryProps,
+    state: &mut ErrorBoundaryState,
+    children: impl FnOnce() -> String,
+) -> String {
+    if state.has_error() {
+        return render_fallback(props, state);
+    }
+    match catch_unwind(AssertUnwindSafe(children)) {
+        Ok(html) => html,
+        Err(payload) => {
+            let detail = payload
+                .downcast_ref::<&str>()
+                .map(|message| (*message).to_string())
+                .or_else(|| payload.downcast_ref::<String>().cloned())
+                .unwrap_or_else(|| "child render panicked".to_string());
+            state.capture(detail);
+            rustic_ui_utils::telemetry::emit(
+                "error_boundary",
+                props.automation_id.as_deref(),
+                "catch",
+            );
+            render_fallback(props, state)
+        }
+    }
+}
+
+/// Run a fallible child renderer inside the boundary.
+///
+/// The unwind-free companion to [`render_caught`] for targets where panics
+/// abort (`wasm32`) or where the failure is already expressed as a
+/// [`Result`] — data fetch errors, schema mismatches and the like.
+pub fn render_result<E: std::fmt::Display>(
+    props: &ErrorBoundaryProps,
+    state: &mut ErrorBoundaryState,
+    result: Result<String, E>,
+) -> String {
+    if state.has_error() {
+        return render_fallback(props, state);
+    }
+    match result {
+        Ok(html) => html,
+        Err(error) => {
+            state.capture(error.to_string());
+            rustic_ui_utils::telemetry::emit(
+                "error_boundary",
+                props.automation_id.as_deref(),
+                "catch",
+            );
+            render_fallback(props, state)
+        }
+    }
+}
+
+/// Themed fallback card rendered while the boundary holds an error.
+///
+/// The retry button carries a `data-error-boundary-action="retry"` hook so
+/// the framework adapters (and QA suites) can target it without caring about
+/// the surrounding markup.
+fn render_fallback(props: &ErrorBoundaryProps, state: &ErrorBoundaryState) -> String {
+    let root_attrs = crate::style_helpers::themed_attributes_html(
+        themed_fallback_style(),
+        [
+            (
+                "data-component".to_string(),
+                crate::style_helpers::automation_id(
+                    "error-boundary",
+                    None,
+                    crate::style_helpers::NO_SEGMENTS,
+                ),
+            ),
+            (
+                crate::style_helpers::automation_data_attr("error-boundary", ["root"]),
+                crate::style_helpers::automation_id(
+                    "error-boundary",
+                    props.automation_id.as_deref(),
+                    ["root"],
+                ),
+            ),
+            ("role".to_string(), "alert".to_string()),
+            ("data-retries".to_string(), state.retries().to_string()),
+        ],
+    );
+    rustic_ui_utils::telemetry::emit("error_boundary", props.automation_id.as_deref(), "fallback");
+    format!(
+        "<div {root_attrs}><p>{message}</p>\
+         <button type=\"button\" data-error-boundary-action=\"retry\">{retry}</button></div>",
+        message = props.fallback_message,
+        retry = props.retry_label,
+    )
+}
+
+/// Fallback card styling built from the active theme tokens so the degraded
+/// state still matches the surrounding dashboard.
+fn themed_fallback_style() -> Style {
+    css_with_theme!(
+        r#"
+        display: flex;
+        flex-direction: column;
+        align-items: flex-start;
+        gap: ${gap};
+        padding: ${padding};
+        border: 1px solid ${border_color};
+        border-radius: ${radius};
+        background: ${background};
+        color: ${text_color};
+        font-family: ${font_family};
+    "#,
+        gap = format!("{}px", theme.spacing(1)),
+        padding = format!("{}px", theme.spacing(2)),
+        border_color = theme.palette.active().text_secondary.clone(),
+        radius = format!("{}px", theme.spacing(1)),
+        background = theme.palette.active().background_paper.clone(),
+        text_color = theme.palette.active().text_primary.clone(),
+        font_family = theme.typography.font_family.clone()
+    )
+}
+
+// ---------------------------------------------------------------------------
+// Adapter implementations
+// ---------------------------------------------------------------------------
+
+/// Adapter targeting the [`yew`] framework.
+pub mod yew {
+    use super::*;
+
+    /// Render the boundary into a HTML string using the shared renderer.
+    pub fn render(
+        props: &ErrorBoundaryProps,
+        state: &mut ErrorBoundaryState,
+        children: impl FnOnce() -> String,
+    ) -> String {
+        super::render_caught(props, state, children)
+    }
+
+    /// Render the boundary with a native callback fired when the retry
+    /// button is clicked.
+    ///
+    /// Clicks are delegated from a wrapper node; the adapter resolves the
+    /// `data-error-boundary-action="retry"` hook emitted by the fallback, so
+    /// the callback only fires for the retry affordance.  Callers typically
+    /// dispatch into [`ErrorBoundaryState::retry`] and re-render.
+    #[cfg(feature = "yew")]
+    pub fn render_with_on_retry(
+        props: &ErrorBoundaryProps,
+        state: &mut ErrorBoundaryState,
+        children: impl FnOnce() -> String,
+        on_retry: ::yew::Callback<()>,
+    ) -> ::yew::Html {
+        use ::wasm_bindgen::JsCast;
+
+        let on_click = ::yew::Callback::from(move |event: ::yew::events::MouseEvent| {
+            let target = event
+                .target()
+                .and_then(|target| target.dyn_into::<::web_sys::Element>().ok());
+            if let Some(element) = target {
+                if let Ok(Some(_)) = element.closest("[data-error-boundary-action=\"retry\"]") {
+                    on_retry.emit(());
+                }
+            }
+        });
+        crate::render::yew::delegated_click(super::render_caught(props, state, children), on_click)
+    }
+}
+
+/// Adapter targeting the [`leptos`] framework.
+pub mod leptos {
+    use super::*;
+
+    /// Render the boundary into a HTML string using the shared renderer.
+    pub fn render(
+        props: &ErrorBoundaryProps,
+        state: &mut ErrorBoundaryState,
+        children: impl FnOnce() -> String,
+    ) -> String {
+        super::render_caught(props, state, children)
+    }
+
+    /// Render the boundary with a retry handler, mirroring
+    /// [`yew::render_with_on_retry`](super::yew::render_with_on_retry) for
+    /// Leptos consumers.
+    #[cfg(feature = "leptos")]
+    pub fn render_with_on_retry(
+        props: &ErrorBoundaryProps,
+        state: &mut ErrorBoundaryState,
+        children: impl FnOnce() -> String,
+        on_retry: impl Fn() + 'static,
+    ) -> ::leptos::View {
+        use ::leptos::wasm_bindgen::JsCast;
+
+        let on_click = move |event: ::leptos::ev::MouseEvent| {
+            let target = event
+                .target()
+                .and_then(|target| target.dyn_into::<::leptos::web_sys::Element>().ok());
+            if let Some(element) = target {
+                if let Ok(Some(_)) = element.closest("[data-error-boundary-action=\"retry\"]") {
+                    on_retry();
+                }
+            }
+        };
+        crate::render::leptos::delegated_click(
+            super::render_caught(props, state, children),
+            on_click,
+        )
+    }
+}
+
+/// Adapter targeting the [`dioxus`] framework.
+pub mod dioxus {
+    use super::*;
+
+    /// Render the boundary into a HTML string using the shared renderer.
+    pub fn render(
+        props: &ErrorBoundaryProps,
+        state: &mut ErrorBoundaryState,
+        children: impl FnOnce() -> String,
+    ) -> String {
+        super::render_caught(props, state, children)
+    }
+}
+
+/// Adapter targeting the [`sycamore`] framework.
+pub mod sycamore {
+    use super::*;
+
+    /// Render the boundary into a HTML string using the shared renderer.
+    pub fn render(
+        props: &ErrorBoundaryProps,
+        state: &mut ErrorBoundaryState,
+        children: impl FnOnce() -> String,
+    ) -> String {
+        super::render_caught(props, state, children)
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn healthy_children_render_straight_through() {
+        let props = ErrorBoundaryProps::new();
+        let mut state = ErrorBoundaryState::new();
+        let html = render_caught(&props, &mut state, || "<p>dashboard</p>".to_string());
+        assert_eq!(html, "<p>dashboard</p>");
+        assert!(!state.has_error());
+    }
+
+    #[test]
+    fn panics_are_captured_into_the_fallback() {
+        let props = ErrorBoundaryProps::new().with_automation_id("revenue-chart");
+        let mut state = ErrorBoundaryState::new();
+        let html = render_caught(&props, &mut state, || panic!("chart data missing"));
+        assert!(html.contains("role=\"alert\""));
+        assert!(html.contains("Something went wrong."));
+        assert!(html.contains("data-error-boundary-action=\"retry\""));
+        // Panic payloads never leak into user facing markup.
+        assert!(!html.contains("chart data missing"));
+        assert_eq!(state.error(), Some("chart data missing"));
+    }
+
+    #[test]
+    fn retry_clears_the_error_and_counts_attempts() {
+        let props = ErrorBoundaryProps::new();
+        let mut state = ErrorBoundaryState::new();
+        render_caught(&props, &mut state, || panic!("boom"));
+        state.retry();
+        assert!(!state.has_error());
+        assert_eq!(state.retries(), 1);
+        let html = render_caught(&props, &mut state, || "<p>recovered</p>".to_string());
+        assert_eq!(html, "<p>recovered</p>");
+    }
+
+    #[test]
+    fn result_errors_take_the_same_path_without_unwinding() {
+        let props = ErrorBoundaryProps::new().with_retry_label("Try again");
+        let mut state = ErrorBoundaryState::new();
+        let html = render_result(&props, &mut state, Err::<String, _>("upstream 503"));
+        assert!(html.contains("Try again"));
+        assert_eq!(state.error(), Some("upstream 503"));
+    }
+
+    #[test]
+    fn captures_emit_a_typed_telemetry_event() {
+        let sink = std::sync::Arc::new(rustic_ui_utils::telemetry::MemorySink::default());
+        rustic_ui_utils::telemetry::install_sink(sink.clone());
+
+        let props = ErrorBoundaryProps::new().with_automation_id("kpi-grid");
+        let mut state = ErrorBoundaryState::new();
+        render_caught(&props, &mut state, || panic!("boom"));
+        rustic_ui_utils::telemetry::reset_sink();
+
+        // Other components rendering in parallel tests may share the global
+        // sink, so filter down to this instance's automation identifier.
+        assert!(sink.events().iter().any(|event| {
+            event.component == "error_boundary"
+                && event.action == "catch"
+                && event.automation_id.as_deref() == Some("kpi-grid")
+        }));
+    }
+}
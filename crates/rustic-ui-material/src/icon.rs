@@ -0,0 +1,304 @@
+//! Density-aware icon and icon button renderers.
+//!
+//! Icon glyphs come from `rustic_ui_icons` (or any raw `<svg>` markup); this
+//! module only owns the box around them.  Sizing flows through the theme's
+//! [`IconSize`] scale, which inherits from the typography line heights, so
+//! an icon next to `body1` copy occupies exactly that line box and density
+//! overrides restyle icons and text together.  [`IconButtonProps`] wraps the
+//! same glyph in an accessible 48px-minimum touch target with the shared
+//! focus ring tokens.
+
+use rustic_ui_styled_engine::{css_with_theme, IconSize, Style};
+
+/// Shared properties accepted by all icon adapter implementations.
+#[derive(Clone, Debug, PartialEq)]
+pub struct IconProps {
+    /// Raw `<svg>` markup, typically produced by a `rustic_ui_icons` macro.
+    pub svg: String,
+    /// Token resolved against the theme's typography-derived scale.
+    pub size: IconSize,
+    /// Accessible name; omit for purely decorative icons, which are then
+    /// hidden from assistive technology.
+    pub label: Option<String>,
+    /// Optional automation identifier for analytics and end-to-end tests.
+    pub automation_id: Option<String>,
+}
+
+impl IconProps {
+    /// Convenience constructor used by examples and tests.
+    pub fn new(svg: impl Into<String>) -> Self {
+        Self {
+            svg: svg.into(),
+            size: IconSize::default(),
+            label: None,
+            automation_id: None,
+        }
+    }
+
+    /// Selects a size token from the theme's icon scale.
+    pub fn with_size(mut self, size: IconSize) -> Self {
+        self.size = size;
+        self
+    }
+
+    /// Names the icon for assistive technology.
+    pub fn with_label(mut self, label: impl Into<String>) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+
+    /// Sets the automation identifier stamped on the rendered hooks.
+    pub fn with_automation_id(mut self, id: impl Into<String>) -> Self {
+        self.automation_id = Some(id.into());
+        self
+    }
+}
+
+/// Shared properties for the icon button variant.
+#[derive(Clone, Debug, PartialEq)]
+pub struct IconButtonProps {
+    /// The glyph rendered inside the button.
+    pub icon: IconProps,
+    /// Accessible name of the action; required because the button has no
+    /// visible text.
+    pub label: String,
+    /// Renders the button inert while keeping it in the layout.
+    pub disabled: bool,
+}
+
+impl IconButtonProps {
+    /// Convenience constructor used by examples and tests.
+    pub fn new(icon: IconProps, label: impl Into<String>) -> Self {
+        Self {
+            icon,
+            label: label.into(),
+            disabled: false,
+        }
+    }
+
+    /// Disables the button.
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+}
+
+fn size_token(size: IconSize) -> &'static str {
+    match size {
+        IconSize::Small => "small",
+        IconSize::Medium => "medium",
+        IconSize::Large => "large",
+    }
+}
+
+/// Shared rendering routine used by all icon adapters.
+fn render_icon_html(props: &IconProps) -> String {
+    let accessibility = match &props.label {
+        Some(label) => format!(
+            "role=\"img\" aria-label=\"{}\"",
+            crate::render::escape_text(label)
+        ),
+        None => "aria-hidden=\"true\"".to_string(),
+    };
+    let attrs = crate::style_helpers::themed_attributes_html(
+        themed_icon_style(props.size),
+        vec![
+            (
+                "data-icon-size".to_string(),
+                size_token(props.size).to_string(),
+            ),
+            (
+                crate::style_helpers::automation_data_attr("icon", ["root"]),
+                crate::style_helpers::automation_id(
+                    "icon",
+                    props.automation_id.as_deref(),
+                    ["root"],
+                ),
+            ),
+        ],
+    );
+    format!(
+        "<span {attrs} {accessibility}>{svg}</span>",
+        svg = props.svg
+    )
+}
+
+/// Shared rendering routine used by all icon button adapters.
+fn render_icon_button_html(props: &IconButtonProps) -> String {
+    let attrs = crate::style_helpers::themed_attributes_html(
+        themed_icon_button_style(),
+        vec![(
+            "aria-label".to_string(),
+            crate::render::escape_text(&props.label),
+        )],
+    );
+    let disabled = if props.disabled { " disabled" } else { "" };
+    format!(
+        "<button type=\"button\" {attrs}{disabled}>{icon}</button>",
+        icon = render_icon_html(&props.icon),
+    )
+}
+
+/// Square glyph box sized from the theme's typography-derived icon scale.
+fn themed_icon_style(size: IconSize) -> Style {
+    css_with_theme!(
+        r#"
+        display: inline-flex;
+        align-items: center;
+        justify-content: center;
+        width: ${box_size};
+        height: ${box_size};
+        flex-shrink: 0;
+
+        & svg {
+            width: 100%;
+            height: 100%;
+            fill: currentColor;
+        }
+    "#,
+        box_size = format!("{}px", theme.icon_size_px(size)),
+    )
+}
+
+/// Circular touch target wrapping the glyph in the shared focus tokens.
+fn themed_icon_button_style() -> Style {
+    css_with_theme!(
+        r#"
+        display: inline-flex;
+        align-items: center;
+        justify-content: center;
+        min-width: 48px;
+        min-height: 48px;
+        padding: ${padding};
+        border: none;
+        border-radius: 50%;
+        background: transparent;
+        color: ${text};
+        cursor: pointer;
+
+        &:hover {
+            background: ${hover};
+        }
+
+        &:focus-visible {
+            outline: ${focus_outline};
+            outline-offset: ${focus_outline_offset};
+        }
+
+        &:disabled {
+            color: ${disabled};
+            cursor: default;
+            background: transparent;
+        }
+    "#,
+        padding = format!("{}px", theme.spacing(1)),
+        text = theme.palette.active().text_primary.clone(),
+        hover = theme.palette.active().neutral.clone(),
+        focus_outline = crate::style_helpers::focus_outline(&theme),
+        focus_outline_offset = crate::style_helpers::focus_outline_offset(&theme),
+        disabled = theme.palette.active().text_secondary.clone(),
+    )
+}
+
+// ---------------------------------------------------------------------------
+// Adapter implementations
+// ---------------------------------------------------------------------------
+
+pub mod yew {
+    use super::*;
+
+    /// Render the icon into a plain HTML string for SSR/hydration.
+    pub fn render(props: &IconProps) -> String {
+        super::render_icon_html(props)
+    }
+
+    /// Render the icon button into a plain HTML string for SSR/hydration.
+    pub fn render_button(props: &IconButtonProps) -> String {
+        super::render_icon_button_html(props)
+    }
+}
+
+pub mod leptos {
+    use super::*;
+
+    /// Render the icon into a plain HTML string for SSR/hydration.
+    pub fn render(props: &IconProps) -> String {
+        super::render_icon_html(props)
+    }
+
+    /// Render the icon button into a plain HTML string for SSR/hydration.
+    pub fn render_button(props: &IconButtonProps) -> String {
+        super::render_icon_button_html(props)
+    }
+}
+
+pub mod dioxus {
+    use super::*;
+
+    /// Render the icon into a plain HTML string for SSR/hydration.
+    pub fn render(props: &IconProps) -> String {
+        super::render_icon_html(props)
+    }
+
+    /// Render the icon button into a plain HTML string for SSR/hydration.
+    pub fn render_button(props: &IconButtonProps) -> String {
+        super::render_icon_button_html(props)
+    }
+}
+
+pub mod sycamore {
+    use super::*;
+
+    /// Render the icon into a plain HTML string for SSR/hydration.
+    pub fn render(props: &IconProps) -> String {
+        super::render_icon_html(props)
+    }
+
+    /// Render the icon button into a plain HTML string for SSR/hydration.
+    pub fn render_button(props: &IconButtonProps) -> String {
+        super::render_icon_button_html(props)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const GLYPH: &str = "<svg viewBox=\"0 0 24 24\"><path d=\"M0 0h24v24H0z\"/></svg>";
+
+    #[test]
+    fn decorative_icons_hide_from_assistive_technology() {
+        let html = render_icon_html(&IconProps::new(GLYPH));
+        assert!(html.contains("aria-hidden=\"true\""));
+        assert!(html.contains("data-icon-size=\"medium\""));
+        assert!(html.contains(GLYPH));
+    }
+
+    #[test]
+    fn labelled_icons_expose_an_image_role() {
+        let html = render_icon_html(
+            &IconProps::new(GLYPH)
+                .with_size(IconSize::Large)
+                .with_label("Warning")
+                .with_automation_id("alerts"),
+        );
+        assert!(html.contains("role=\"img\" aria-label=\"Warning\""));
+        assert!(html.contains("data-icon-size=\"large\""));
+        assert!(html.contains("data-rustic-icon-root=\"rustic-icon-alerts-root\""));
+    }
+
+    #[test]
+    fn icon_buttons_wrap_the_glyph_in_a_labelled_target() {
+        let props = IconButtonProps::new(
+            IconProps::new(GLYPH).with_size(IconSize::Small),
+            "Close dialog",
+        );
+        let html = render_icon_button_html(&props);
+        assert!(html.contains("aria-label=\"Close dialog\""));
+        assert!(html.contains("data-icon-size=\"small\""));
+        assert!(!html.contains("disabled>"));
+
+        let disabled = render_icon_button_html(&props.clone().disabled(true));
+        assert!(disabled.contains(" disabled>"));
+    }
+}
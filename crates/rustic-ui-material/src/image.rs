@@ -0,0 +1,283 @@
+//! Layout-stable image blueprint with lazy loading and error fallback.
+//!
+//! Media heavy pages suffer two recurring regressions: cumulative layout
+//! shift when images pop in without reserved space, and broken-image glyphs
+//! when a CDN entry goes missing.  This blueprint addresses both at render
+//! time: the wrapper reserves the intrinsic aspect ratio before any bytes
+//! arrive, the `<img>` ships native `loading="lazy"`/`decoding="async"`
+//! attributes so the browser schedules the fetch, and an optional blur-up
+//! placeholder paints the reserved box until the real pixels decode.
+//!
+//! Failed loads are handled declaratively: adapters flip the wrapper's
+//! `data-image-state` attribute to `error` from the `<img>` error event and
+//! the stylesheet swaps the broken image for the fallback slot.  The fallback
+//! stays in the server rendered markup (hidden by default) so hydration never
+//! has to invent nodes.
+
+use rustic_ui_styled_engine::{css_with_theme, Style};
+
+/// Shared properties accepted by all adapter implementations.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ImageProps {
+    /// Source URL of the full resolution asset.
+    pub src: String,
+    /// Alternative text announced by assistive technology and reused as the
+    /// fallback slot's accessible name.
+    pub alt: String,
+    /// Intrinsic width in pixels, paired with [`height`](Self::height) to
+    /// reserve the aspect ratio before the asset loads.
+    pub width: Option<u32>,
+    /// Intrinsic height in pixels.
+    pub height: Option<u32>,
+    /// Optional low resolution placeholder (typically an inline data URI)
+    /// painted behind the image for a blur-up effect.
+    pub placeholder: Option<String>,
+    /// Optional text rendered in the fallback slot when the load fails.
+    /// Defaults to the alt text when omitted.
+    pub fallback: Option<String>,
+    /// Opt out of lazy loading for above-the-fold hero imagery.
+    pub eager: bool,
+    /// Optional automation identifier for analytics and end-to-end tests.
+    pub automation_id: Option<String>,
+}
+
+impl ImageProps {
+    /// Convenience constructor covering the mandatory accessibility pair.
+    pub fn new(src: impl Into<String>, alt: impl Into<String>) -> Self {
+        Self {
+            src: src.into(),
+            alt: alt.into(),
+            width: None,
+            height: None,
+            placeholder: None,
+            fallback: None,
+            eager: false,
+            automation_id: None,
+        }
+    }
+
+    /// Declares the intrinsic dimensions so the layout reserves the aspect
+    /// ratio before the first byte arrives.
+    pub fn with_dimensions(mut self, width: u32, height: u32) -> Self {
+        self.width = Some(width);
+        self.height = Some(height);
+        self
+    }
+
+    /// Paints a low resolution placeholder behind the image while it loads.
+    pub fn with_placeholder(mut self, placeholder: impl Into<String>) -> Self {
+        self.placeholder = Some(placeholder.into());
+        self
+    }
+
+    /// Overrides the text shown in the error fallback slot.
+    pub fn with_fallback(mut self, fallback: impl Into<String>) -> Self {
+        self.fallback = Some(fallback.into());
+        self
+    }
+
+    /// Loads the image eagerly, e.g. for above-the-fold hero imagery.
+    pub fn eager(mut self) -> Self {
+        self.eager = true;
+        self
+    }
+
+    /// Sets the automation identifier stamped on the rendered hooks.
+    pub fn with_automation_id(mut self, id: impl Into<String>) -> Self {
+        self.automation_id = Some(id.into());
+        self
+    }
+}
+
+/// Shared rendering routine used by all adapters.
+fn render_html(props: &ImageProps) -> String {
+    let mut wrapper_attrs = vec![
+        ("data-image-state".to_string(), String::from("loading")),
+        (
+            "data-component".to_string(),
+            crate::style_helpers::automation_id("image", None, crate::style_helpers::NO_SEGMENTS),
+        ),
+        (
+            crate::style_helpers::automation_data_attr("image", ["root"]),
+            crate::style_helpers::automation_id("image", props.automation_id.as_deref(), ["root"]),
+        ),
+    ];
+    if let Some(style) = inline_reservation_style(props) {
+        wrapper_attrs.push(("style".to_string(), style));
+    }
+    let attrs = crate::style_helpers::themed_attributes_html(themed_image_style(), wrapper_attrs);
+
+    let alt = crate::render::escape_text(&props.alt);
+    let dimensions = props
+        .width
+        .zip(props.height)
+        .map(|(width, height)| format!(" width=\"{width}\" height=\"{height}\""))
+        .unwrap_or_default();
+    let loading = if props.eager { "eager" } else { "lazy" };
+    let fallback = crate::render::escape_text(props.fallback.as_deref().unwrap_or(&props.alt));
+    format!(
+        "<figure {attrs}>\
+         <img src=\"{src}\" alt=\"{alt}\" loading=\"{loading}\" decoding=\"async\"{dimensions} />\
+         <span role=\"img\" aria-label=\"{alt}\" data-rustic-image-fallback=\"\" hidden=\"\">{fallback}</span>\
+         </figure>",
+        src = crate::render::escape_text(&props.src),
+    )
+}
+
+/// Per-instance inline declarations reserving layout before the load.
+///
+/// Aspect ratio and placeholder URLs vary per image, so they travel as an
+/// inline `style` attribute instead of baking one stylesheet per instance.
+fn inline_reservation_style(props: &ImageProps) -> Option<String> {
+    let mut declarations = Vec::new();
+    if let Some((width, height)) = props.width.zip(props.height) {
+        declarations.push(format!("aspect-ratio:{width} / {height};"));
+    }
+    if let Some(placeholder) = &props.placeholder {
+        declarations.push(format!(
+            "background-image:url({});",
+            crate::render::escape_text(placeholder)
+        ));
+    }
+    (!declarations.is_empty()).then(|| declarations.concat())
+}
+
+/// Static styling shared by every image instance.
+///
+/// The blur-up background sits behind the real image; once adapters flip
+/// `data-image-state` to `error` the broken `<img>` hides and the fallback
+/// slot takes over the reserved box.
+fn themed_image_style() -> Style {
+    css_with_theme!(
+        r#"
+        display: block;
+        margin: 0;
+        overflow: hidden;
+        border-radius: 4px;
+        background-color: ${surface};
+        background-size: cover;
+        background-position: center;
+
+        & img {
+            display: block;
+            width: 100%;
+            height: 100%;
+            object-fit: cover;
+        }
+
+        &[data-image-state='error'] img {
+            display: none;
+        }
+
+        &[data-image-state='error'] [data-rustic-image-fallback] {
+            display: flex;
+            align-items: center;
+            justify-content: center;
+            width: 100%;
+            height: 100%;
+            padding: ${padding};
+            font-family: ${font_family};
+            font-size: 0.875rem;
+            color: ${text_color};
+        }
+    "#,
+        surface = theme.palette.active().background_paper.clone(),
+        padding = format!("{}px", theme.spacing(2)),
+        font_family = theme.typography.font_family.clone(),
+        text_color = theme.palette.active().text_secondary.clone(),
+    )
+}
+
+// ---------------------------------------------------------------------------
+// Adapter implementations
+// ---------------------------------------------------------------------------
+
+pub mod yew {
+    use super::*;
+
+    /// Render the image into a plain HTML string for SSR/hydration.
+    pub fn render(props: &ImageProps) -> String {
+        super::render_html(props)
+    }
+}
+
+pub mod leptos {
+    use super::*;
+
+    /// Render the image into a plain HTML string for SSR/hydration.
+    pub fn render(props: &ImageProps) -> String {
+        super::render_html(props)
+    }
+}
+
+pub mod dioxus {
+    use super::*;
+
+    /// Render the image into a plain HTML string for SSR/hydration.
+    pub fn render(props: &ImageProps) -> String {
+        super::render_html(props)
+    }
+}
+
+pub mod sycamore {
+    use super::*;
+
+    /// Render the image into a plain HTML string for SSR/hydration.
+    pub fn render(props: &ImageProps) -> String {
+        super::render_html(props)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lazy_loading_and_automation_hooks_are_stamped() {
+        let props = ImageProps::new("/cdn/hero.avif", "Team offsite").with_automation_id("hero");
+        let html = render_html(&props);
+        assert!(html.contains("loading=\"lazy\""));
+        assert!(html.contains("decoding=\"async\""));
+        assert!(html.contains("data-image-state=\"loading\""));
+        assert!(html.contains("data-rustic-image-root=\"rustic-image-hero-root\""));
+    }
+
+    #[test]
+    fn dimensions_reserve_the_aspect_ratio() {
+        let props = ImageProps::new("/cdn/chart.png", "Revenue chart").with_dimensions(1600, 900);
+        let html = render_html(&props);
+        assert!(html.contains("aspect-ratio:1600 / 900;"));
+        assert!(html.contains("width=\"1600\" height=\"900\""));
+
+        let bare = render_html(&ImageProps::new("/cdn/chart.png", "Revenue chart"));
+        assert!(!bare.contains("aspect-ratio"));
+        assert!(!bare.contains("style="));
+    }
+
+    #[test]
+    fn placeholder_paints_behind_the_image() {
+        let props = ImageProps::new("/cdn/photo.jpg", "Photo")
+            .with_placeholder("data:image/jpeg;base64,AAAA");
+        let html = render_html(&props);
+        assert!(html.contains("background-image:url(data:image/jpeg;base64,AAAA);"));
+    }
+
+    #[test]
+    fn fallback_slot_defaults_to_the_alt_text() {
+        let props = ImageProps::new("/cdn/missing.png", "Quarterly <report>");
+        let html = render_html(&props);
+        assert!(html.contains(
+            "data-rustic-image-fallback=\"\" hidden=\"\">Quarterly &lt;report&gt;</span>"
+        ));
+
+        let custom = ImageProps::new("/cdn/missing.png", "Quarterly report")
+            .with_fallback("Chart unavailable");
+        assert!(render_html(&custom).contains(">Chart unavailable</span>"));
+    }
+
+    #[test]
+    fn eager_opt_out_disables_lazy_loading() {
+        let props = ImageProps::new("/cdn/hero.avif", "Hero").eager();
+        assert!(render_html(&props).contains("loading=\"eager\""));
+    }
+}
@@ -22,21 +22,46 @@
 //! }
 //! ```
 
+pub mod alert;
 pub mod app_bar;
+pub mod async_boundary;
+pub mod attachment_list;
+pub mod automation;
+pub mod banner;
 pub mod button;
 pub mod card;
 pub mod checkbox;
 pub mod chip;
+pub mod chip_group;
+pub mod code_block;
 pub mod dialog;
+pub mod diff_viewer;
 pub mod drawer;
+pub mod error_boundary;
+pub mod icon;
+pub mod image;
+pub mod link;
 pub mod list;
+pub mod loading_overlay;
 pub mod macros;
 pub mod menu;
+pub mod mobile_stepper;
+pub mod modal;
+pub mod no_ssr;
+pub mod popover;
 pub mod radio;
+pub mod rating_summary;
+pub mod render;
 mod render_helpers;
+pub mod ripple;
+pub mod routing;
 pub mod select;
 mod selection_control;
+pub mod session_dialog;
+pub mod skip_nav;
 pub mod snackbar;
+pub mod status_badge;
+pub mod stories;
 mod style_helpers;
 pub mod switch;
 pub mod tab;
@@ -45,7 +70,13 @@ pub mod table;
 pub mod tabs;
 pub mod text_field;
 pub mod tooltip;
+pub mod tour;
+pub mod transfer_list;
+pub mod undo;
+#[cfg(feature = "web-components")]
+pub mod web_components;
 
+pub use no_ssr as client_only;
 pub use rustic_ui_styled_engine::Theme;
 
 /// Confirms that the crate links to `rustic_ui_styled_engine` and compiles.
@@ -0,0 +1,189 @@
+//! Material flavored link rendering router-aware anchors.
+//!
+//! The component builds on the [`routing`](crate::routing) contract: every
+//! rendered anchor keeps a real `href` for SSR crawlers and no-JavaScript
+//! fallbacks while carrying the `data-rustic-router` attributes the client
+//! bootstrap intercepts for history based navigation.  Styling flows through
+//! [`css_with_theme!`](rustic_ui_styled_engine::css_with_theme) exactly like the
+//! other Material components so palette changes restyle links globally.
+//!
+//! Like [`button`](crate::button), the module is framework agnostic and
+//! exposes thin per-framework adapters delegating to one shared renderer.
+
+use crate::routing::{link_attributes, RouterTarget};
+use rustic_ui_styled_engine::{css_with_theme, Style};
+
+/// Underline treatment mirroring the upstream Material `Link` API.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum LinkUnderline {
+    /// Underline only while hovered.
+    #[default]
+    Hover,
+    /// Always underlined.
+    Always,
+    /// Never underlined.
+    None,
+}
+
+impl LinkUnderline {
+    /// CSS `text-decoration` value for the resting state.
+    fn resting(self) -> &'static str {
+        match self {
+            Self::Always => "underline",
+            Self::Hover | Self::None => "none",
+        }
+    }
+
+    /// CSS `text-decoration` value while hovered.
+    fn hovered(self) -> &'static str {
+        match self {
+            Self::Always | Self::Hover => "underline",
+            Self::None => "none",
+        }
+    }
+}
+
+/// Shared properties accepted by all adapter implementations.
+#[derive(Clone, Debug)]
+pub struct LinkProps {
+    /// Text rendered inside the anchor.
+    pub label: String,
+    /// Navigation target resolved through the router contract.
+    pub target: RouterTarget,
+    /// Underline treatment.
+    pub underline: LinkUnderline,
+}
+
+impl LinkProps {
+    /// Convenience constructor for internal routes.
+    pub fn new(label: impl Into<String>, target: RouterTarget) -> Self {
+        Self {
+            label: label.into(),
+            target,
+            underline: LinkUnderline::default(),
+        }
+    }
+
+    /// Override the underline treatment.
+    pub fn with_underline(mut self, underline: LinkUnderline) -> Self {
+        self.underline = underline;
+        self
+    }
+}
+
+/// Shared rendering routine used by all adapters.
+fn render_html(props: &LinkProps) -> String {
+    let attr_string = crate::style_helpers::themed_attributes_html(
+        themed_link_style(props.underline),
+        link_attributes(&props.target),
+    );
+    format!("<a {}>{}</a>", attr_string, props.label)
+}
+
+/// Builds the [`Style`] powering the Material flavored link.
+fn themed_link_style(underline: LinkUnderline) -> Style {
+    css_with_theme!(
+        r#"
+        color: ${color};
+        text-decoration: ${resting};
+        font-family: ${font_family};
+        cursor: pointer;
+
+        &:hover {
+            text-decoration: ${hovered};
+        }
+
+        &:focus-visible {
+            outline: ${focus_outline};
+            outline-offset: ${focus_outline_offset};
+        }
+    "#,
+        color = theme.palette.active().primary.clone(),
+        resting = underline.resting().to_string(),
+        hovered = underline.hovered().to_string(),
+        font_family = theme.typography.font_family.clone(),
+        focus_outline = crate::style_helpers::focus_outline(&theme),
+        focus_outline_offset = crate::style_helpers::focus_outline_offset(&theme)
+    )
+}
+
+// ---------------------------------------------------------------------------
+// Adapter implementations
+// ---------------------------------------------------------------------------
+
+/// Adapter targeting server rendered React experiences.
+pub mod react {
+    use super::*;
+
+    /// Render the link into an HTML string identical to the other adapters.
+    pub fn render(props: &LinkProps) -> String {
+        super::render_html(props)
+    }
+}
+
+/// Adapter targeting the [`yew`] framework.
+pub mod yew {
+    use super::*;
+
+    /// Render the link into a plain HTML string using a theme aware style.
+    pub fn render(props: &LinkProps) -> String {
+        super::render_html(props)
+    }
+}
+
+/// Adapter targeting the [`leptos`] framework.
+pub mod leptos {
+    use super::*;
+
+    /// Render the link into a plain HTML string using a theme aware style.
+    pub fn render(props: &LinkProps) -> String {
+        super::render_html(props)
+    }
+}
+
+/// Adapter targeting the [`dioxus`] framework.
+pub mod dioxus {
+    use super::*;
+
+    /// Render the link into a plain HTML string using a theme aware style.
+    pub fn render(props: &LinkProps) -> String {
+        super::render_html(props)
+    }
+}
+
+/// Adapter targeting the [`sycamore`] framework.
+pub mod sycamore {
+    use super::*;
+
+    /// Render the link into a plain HTML string using a theme aware style.
+    pub fn render(props: &LinkProps) -> String {
+        super::render_html(props)
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn render_html_includes_router_contract_and_label() {
+        let props = LinkProps::new("Dashboard", RouterTarget::route("/dashboard"));
+        let html = render_html(&props);
+        assert!(html.contains("class=\""));
+        assert!(html.contains("href=\"/dashboard\""));
+        assert!(html.contains("data-rustic-router=\"route\""));
+        assert!(html.contains(">Dashboard</a>"));
+    }
+
+    #[test]
+    fn external_links_skip_interception() {
+        let props = LinkProps::new("Docs", RouterTarget::external("https://example.com/docs"));
+        let html = render_html(&props);
+        assert!(html.contains("data-rustic-router=\"external\""));
+        assert!(html.contains("rel=\"noopener\""));
+    }
+}
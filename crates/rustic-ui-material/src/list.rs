@@ -28,6 +28,9 @@ use rustic_ui_styled_engine::{css_with_theme, Style};
 pub enum ListDensity {
     /// Default spacing: 16px vertical padding per item.
     Comfortable,
+    /// Intermediate spacing: 12px vertical padding per item, matching the
+    /// Material data table default.
+    Standard,
     /// Compact spacing: 8px vertical padding per item.
     Compact,
 }
@@ -43,15 +46,20 @@ impl ListDensity {
     pub(crate) fn data_value(self) -> &'static str {
         match self {
             Self::Comfortable => "comfortable",
+            Self::Standard => "standard",
             Self::Compact => "compact",
         }
     }
 
+    /// Vertical padding in pixels resolved from the theme spacing scale.  The
+    /// standard preset sits halfway between the comfortable and compact steps
+    /// so dense enterprise tables keep legible row heights.
     #[inline]
-    pub(crate) fn vertical_padding(self) -> u16 {
+    pub(crate) fn padding_y_px(self, theme: &rustic_ui_styled_engine::Theme) -> u16 {
         match self {
-            Self::Comfortable => 2,
-            Self::Compact => 1,
+            Self::Comfortable => theme.spacing(2),
+            Self::Standard => theme.spacing(3) / 2,
+            Self::Compact => theme.spacing(1),
         }
     }
 
@@ -59,6 +67,7 @@ impl ListDensity {
     pub(crate) fn row_gap(self) -> u16 {
         match self {
             Self::Comfortable => 1,
+            Self::Standard => 1,
             Self::Compact => 0,
         }
     }
@@ -107,6 +116,9 @@ pub struct ListItem {
     pub secondary: Option<String>,
     /// Optional metadata column rendered on the trailing edge.
     pub meta: Option<String>,
+    /// Optional pre-rendered HTML for the trailing secondary action slot
+    /// (icon buttons, switches, checkboxes).
+    pub secondary_action: Option<String>,
     /// Stable automation identifier appended to `data-rustic-list-item`.
     pub automation_id: Option<String>,
     /// Whether the row should be marked as disabled.
@@ -120,6 +132,7 @@ impl ListItem {
             primary: primary.into(),
             secondary: None,
             meta: None,
+            secondary_action: None,
             automation_id: None,
             disabled: false,
         }
@@ -137,6 +150,12 @@ impl ListItem {
         self
     }
 
+    /// Sets the trailing secondary action markup.
+    pub fn with_secondary_action(mut self, html: impl Into<String>) -> Self {
+        self.secondary_action = Some(html.into());
+        self
+    }
+
     /// Overrides the automation identifier suffix for the row.
     pub fn with_automation_id(mut self, id: impl Into<String>) -> Self {
         self.automation_id = Some(id.into());
@@ -150,6 +169,36 @@ impl ListItem {
     }
 }
 
+/// Group of rows rendered below a sticky subheader.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ListSection {
+    /// Subheader label pinned above the section while it scrolls.
+    pub subheader: String,
+    /// Rows rendered inside the section.
+    pub items: Vec<ListItem>,
+    /// Whether the section can be collapsed.  Collapsible sections start
+    /// collapsed; hosts call [`ListState::sync_expanded`] or
+    /// [`ListState::toggle_expansion`] to open them.
+    pub collapsible: bool,
+}
+
+impl ListSection {
+    /// Creates a non-collapsible section.
+    pub fn new(subheader: impl Into<String>, items: Vec<ListItem>) -> Self {
+        Self {
+            subheader: subheader.into(),
+            items,
+            collapsible: false,
+        }
+    }
+
+    /// Marks the section as collapsible.
+    pub fn collapsible(mut self) -> Self {
+        self.collapsible = true;
+        self
+    }
+}
+
 /// Shared configuration consumed by every framework adapter.
 #[derive(Clone, Debug, PartialEq)]
 pub struct ListProps {
@@ -213,6 +262,88 @@ impl ListProps {
     }
 }
 
+/// Shared configuration for grouped lists rendered with sticky subheaders.
+///
+/// Item indices flatten across sections in document order and feed the same
+/// [`ListState`] selection machinery as flat lists, so collapsing a section
+/// never shifts the indices of the rows beneath it.  Section expansion is
+/// driven by the machine's expansion state keyed by section position.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SectionedListProps {
+    /// Sections rendered inside the list container.
+    pub sections: Vec<ListSection>,
+    /// Visual density applied to the list.
+    pub density: ListDensity,
+    /// Typography variant for the primary text slot.
+    pub primary_typography: ListTypography,
+    /// Typography variant for the optional secondary slot.
+    pub secondary_typography: ListTypography,
+    /// Selection mode forwarded to the headless state machine.
+    pub selection_mode: SelectionMode,
+    /// Optional automation identifier used as a prefix for generated hooks.
+    pub automation_id: Option<String>,
+}
+
+impl SectionedListProps {
+    /// Creates a new [`SectionedListProps`] instance with defaults matching
+    /// [`ListProps::new`].
+    pub fn new(sections: Vec<ListSection>) -> Self {
+        Self {
+            sections,
+            density: ListDensity::default(),
+            primary_typography: ListTypography::default(),
+            secondary_typography: ListTypography::Body2,
+            selection_mode: SelectionMode::None,
+            automation_id: None,
+        }
+    }
+
+    /// Overrides the density preset.
+    pub fn with_density(mut self, density: ListDensity) -> Self {
+        self.density = density;
+        self
+    }
+
+    /// Configures the selection mode.
+    pub fn with_selection_mode(mut self, mode: SelectionMode) -> Self {
+        self.selection_mode = mode;
+        self
+    }
+
+    /// Specifies the automation identifier used to stamp deterministic
+    /// `data-*` hooks.
+    pub fn with_automation_id(mut self, id: impl Into<String>) -> Self {
+        self.automation_id = Some(id.into());
+        self
+    }
+
+    /// Total number of rows across all sections; pass this to the
+    /// [`ListState`] constructors so selection indices line up.
+    pub fn item_count(&self) -> usize {
+        self.sections
+            .iter()
+            .map(|section| section.items.len())
+            .sum()
+    }
+
+    /// Flat view of the props so the per-item helpers (ids, automation hooks,
+    /// ARIA) behave identically for grouped and ungrouped lists.
+    fn flat_props(&self) -> ListProps {
+        ListProps {
+            items: self
+                .sections
+                .iter()
+                .flat_map(|section| section.items.iter().cloned())
+                .collect(),
+            density: self.density,
+            primary_typography: self.primary_typography,
+            secondary_typography: self.secondary_typography,
+            selection_mode: self.selection_mode,
+            automation_id: self.automation_id.clone(),
+        }
+    }
+}
+
 /// Render the list into a SSR friendly HTML string.
 fn render_html(props: &ListProps, state: &ListState) -> String {
     let root_attrs = crate::style_helpers::themed_attributes_html(
@@ -232,8 +363,94 @@ fn render_html(props: &ListProps, state: &ListState) -> String {
     format!("<ul {root_attrs}>{items_html}</ul>")
 }
 
+/// Render a grouped list with sticky subheaders into a SSR friendly HTML
+/// string.
+///
+/// Collapsible sections emit their subheader as a `<button>` carrying
+/// `aria-expanded`/`aria-controls` plus a rotating expand icon; the nested
+/// `<ul role="group">` stays in the DOM when collapsed (hidden via
+/// `data-expanded`) so SSR and hydration agree on the markup.
+fn render_sectioned_html(props: &SectionedListProps, state: &ListState) -> String {
+    let flat = props.flat_props();
+    let mut root_attrs = root_attributes(&flat, state);
+    root_attrs.push(("data-sectioned".into(), "true".into()));
+    let root_attrs = crate::style_helpers::themed_attributes_html(list_style(&flat), root_attrs);
+
+    let mut sections_html = String::new();
+    let mut flat_index = 0usize;
+    for (section_index, section) in props.sections.iter().enumerate() {
+        let expanded = !section.collapsible || state.is_expanded(section_index);
+        let group_id = crate::style_helpers::automation_id(
+            "list",
+            props.automation_id.as_deref(),
+            [format!("section-{section_index}")],
+        );
+
+        let subheader_html = if section.collapsible {
+            let attrs = crate::style_helpers::themed_attributes_html(
+                subheader_style(),
+                vec![
+                    ("type".to_string(), "button".to_string()),
+                    ("aria-expanded".to_string(), expanded.to_string()),
+                    ("aria-controls".to_string(), group_id.clone()),
+                    ("data-section-index".to_string(), section_index.to_string()),
+                    (
+                        crate::style_helpers::automation_data_attr("list", ["subheader"]),
+                        group_id.clone(),
+                    ),
+                ],
+            );
+            format!(
+                "<button {attrs}><span class=\"rustic_ui_list_expand_icon\" aria-hidden=\"true\"></span>{}</button>",
+                section.subheader
+            )
+        } else {
+            let attrs = crate::style_helpers::themed_attributes_html(
+                subheader_style(),
+                vec![
+                    ("role".to_string(), "presentation".to_string()),
+                    ("data-section-index".to_string(), section_index.to_string()),
+                    (
+                        crate::style_helpers::automation_data_attr("list", ["subheader"]),
+                        group_id.clone(),
+                    ),
+                ],
+            );
+            format!("<div {attrs}>{}</div>", section.subheader)
+        };
+
+        let mut items_html = String::new();
+        for item in &section.items {
+            let item_attrs = crate::style_helpers::themed_attributes_html(
+                list_item_style(),
+                item_attributes(&flat, state, item, flat_index),
+            );
+            items_html.push_str(&format!("<li {item_attrs}>{}</li>", item_markup(item)));
+            flat_index += 1;
+        }
+        let group_attrs = crate::style_helpers::themed_attributes_html(
+            section_group_style(),
+            vec![
+                ("id".to_string(), group_id),
+                ("role".to_string(), "group".to_string()),
+                ("data-expanded".to_string(), expanded.to_string()),
+            ],
+        );
+
+        sections_html.push_str(&format!(
+            "<li class=\"rustic_ui_list_section\" data-section-index=\"{section_index}\">{subheader_html}<ul {group_attrs}>{items_html}</ul></li>"
+        ));
+    }
+
+    format!("<ul {root_attrs}>{sections_html}</ul>")
+}
+
 fn automation_base(props: &ListProps) -> String {
-    crate::style_helpers::automation_id("list", props.automation_id.as_deref(), [])
+    crate::style_helpers::automation_id(
+        "list",
+        props.automation_id.as_deref(),
+        crate::style_helpers::NO_SEGMENTS,
+    )
 }
 
 fn item_automation_id(props: &ListProps, item: &ListItem, index: usize) -> String {
@@ -260,7 +477,7 @@ fn root_attributes(props: &ListProps, state: &ListState) -> Vec<(String, String)
     let mut attrs = vec![
         (
             "data-component".into(),
-            crate::style_helpers::automation_id("list", None, []),
+            crate::style_helpers::automation_id("list", None, crate::style_helpers::NO_SEGMENTS),
         ),
         ("data-density".into(), props.density.data_value().into()),
         (
@@ -368,6 +585,11 @@ fn item_markup(item: &ListItem) -> String {
             "<span class=\"rustic_ui_list_meta\">{meta}</span>"
         ));
     }
+    if let Some(action) = &item.secondary_action {
+        html.push_str(&format!(
+            "<span class=\"rustic_ui_list_secondary_action\">{action}</span>"
+        ));
+    }
     html
 }
 
@@ -394,13 +616,13 @@ fn list_style(props: &ListProps) -> Style {
         --rustic_ui_list_secondary_font_weight: ${secondary_weight};
     "#,
         gap = format!("{}px", theme.spacing(density.row_gap())),
-        background = theme.palette.background_paper.clone(),
+        background = theme.palette.active().background_paper.clone(),
         radius = format!("{}px", theme.joy.radius),
         border_color = format!(
             "color-mix(in srgb, {} 18%, transparent)",
-            theme.palette.text_secondary.clone()
+            theme.palette.active().text_secondary.clone()
         ),
-        padding_y = format!("{}px", theme.spacing(density.vertical_padding())),
+        padding_y = format!("{}px", density.padding_y_px(&theme)),
         padding_x = format!("{}px", theme.spacing(2)),
         item_gap = format!("{}px", theme.spacing(1)),
         primary_size = format!("{:.3}rem", props.primary_typography.font_size(&theme)),
@@ -414,7 +636,7 @@ fn list_item_style() -> Style {
     css_with_theme!(
         r#"
         display: grid;
-        grid-template-columns: 1fr auto;
+        grid-template-columns: 1fr auto auto;
         align-items: center;
         column-gap: var(--rustic_ui_list_gap);
         padding: var(--rustic_ui_list_padding_y) var(--rustic_ui_list_padding_x);
@@ -469,26 +691,96 @@ fn list_item_style() -> Style {
             font-size: var(--rustic_ui_list_secondary_font_size);
             color: ${meta_color};
         }
+
+        .rustic_ui_list_secondary_action {
+            justify-self: end;
+            display: inline-flex;
+            align-items: center;
+        }
     "#,
-        text_color = theme.palette.text_primary.clone(),
+        text_color = theme.palette.active().text_primary.clone(),
         font_family = theme.typography.font_family.clone(),
         divider = format!(
             "color-mix(in srgb, {} 14%, transparent)",
-            theme.palette.text_secondary.clone()
+            theme.palette.active().text_secondary.clone()
         ),
         selected_bg = format!(
             "color-mix(in srgb, {} 12%, transparent)",
-            theme.palette.primary.clone()
+            theme.palette.active().primary.clone()
         ),
-        selected_color = theme.palette.primary.clone(),
-        focus_color = theme.palette.primary.clone(),
+        selected_color = theme.palette.active().primary.clone(),
+        focus_color = theme.palette.active().primary.clone(),
         focus_width = format!("{}px", theme.joy.focus.thickness.max(1)),
         focus_offset = format!("{:.1}px", (theme.joy.focus.thickness as f32) / 2.0),
-        disabled_color = theme.palette.text_secondary.clone(),
+        disabled_color = theme.palette.active().text_secondary.clone(),
         content_gap = format!("{}px", theme.spacing(0)),
         line_height = format!("{:.2}", theme.typography.line_height),
-        secondary_color = theme.palette.text_secondary.clone(),
-        meta_color = theme.palette.text_secondary.clone(),
+        secondary_color = theme.palette.active().text_secondary.clone(),
+        meta_color = theme.palette.active().text_secondary.clone(),
+    )
+}
+
+/// Sticky subheader styling shared by the static and collapsible variants.
+/// The button variant keeps the same metrics so toggling collapsibility never
+/// shifts the layout.
+fn subheader_style() -> Style {
+    css_with_theme!(
+        r#"
+        position: sticky;
+        top: 0;
+        z-index: 1;
+        display: flex;
+        align-items: center;
+        gap: ${gap};
+        width: 100%;
+        box-sizing: border-box;
+        margin: 0;
+        border: none;
+        text-align: start;
+        padding: var(--rustic_ui_list_padding_y) var(--rustic_ui_list_padding_x);
+        background: ${background};
+        color: ${color};
+        font-family: ${font_family};
+        font-size: ${font_size};
+        font-weight: ${font_weight};
+        cursor: default;
+
+        &[aria-expanded] {
+            cursor: pointer;
+        }
+
+        .rustic_ui_list_expand_icon::before {
+            content: '\25B8';
+            display: inline-block;
+            transition: transform 120ms ease;
+        }
+
+        &[aria-expanded='true'] .rustic_ui_list_expand_icon::before {
+            transform: rotate(90deg);
+        }
+    "#,
+        gap = format!("{}px", theme.spacing(1)),
+        background = theme.palette.active().background_paper.clone(),
+        color = theme.palette.active().text_secondary.clone(),
+        font_family = theme.typography.font_family.clone(),
+        font_size = format!("{:.3}rem", theme.typography.subtitle2),
+        font_weight = theme.typography.font_weight_medium.to_string(),
+    )
+}
+
+/// Nested group styling: collapsed sections stay in the DOM but are hidden so
+/// SSR markup and hydrated markup remain byte-identical.
+fn section_group_style() -> Style {
+    css_with_theme!(
+        r#"
+        list-style: none;
+        margin: 0;
+        padding: 0;
+
+        &[data-expanded='false'] {
+            display: none;
+        }
+    "#,
     )
 }
 
@@ -499,6 +791,11 @@ pub mod yew {
     pub fn render(props: &ListProps, state: &ListState) -> String {
         super::render_html(props, state)
     }
+
+    /// Render a grouped list with sticky subheaders.
+    pub fn render_sectioned(props: &SectionedListProps, state: &ListState) -> String {
+        super::render_sectioned_html(props, state)
+    }
 }
 
 pub mod leptos {
@@ -508,6 +805,11 @@ pub mod leptos {
     pub fn render(props: &ListProps, state: &ListState) -> String {
         super::render_html(props, state)
     }
+
+    /// Render a grouped list with sticky subheaders.
+    pub fn render_sectioned(props: &SectionedListProps, state: &ListState) -> String {
+        super::render_sectioned_html(props, state)
+    }
 }
 
 pub mod dioxus {
@@ -517,6 +819,11 @@ pub mod dioxus {
     pub fn render(props: &ListProps, state: &ListState) -> String {
         super::render_html(props, state)
     }
+
+    /// Render a grouped list with sticky subheaders.
+    pub fn render_sectioned(props: &SectionedListProps, state: &ListState) -> String {
+        super::render_sectioned_html(props, state)
+    }
 }
 
 pub mod sycamore {
@@ -526,6 +833,11 @@ pub mod sycamore {
     pub fn render(props: &ListProps, state: &ListState) -> String {
         super::render_html(props, state)
     }
+
+    /// Render a grouped list with sticky subheaders.
+    pub fn render_sectioned(props: &SectionedListProps, state: &ListState) -> String {
+        super::render_sectioned_html(props, state)
+    }
 }
 
 #[cfg(test)]
@@ -552,7 +864,7 @@ mod tests {
         assert!(attrs.iter().any(|(k, v)| k == "role" && v == "listbox"));
         assert!(attrs
             .iter()
-            .any(|(k, v)| k == "aria-activedescendant" && v.starts_with("sample-list")));
+            .any(|(k, v)| k == "aria-activedescendant" && v.contains("sample-list")));
     }
 
     #[test]
@@ -579,4 +891,59 @@ mod tests {
         assert!(html.contains("data-rustic-list-item"));
         assert!(html.contains("<ul"));
     }
+
+    fn sectioned_props() -> SectionedListProps {
+        SectionedListProps::new(vec![
+            ListSection::new(
+                "Pinned",
+                vec![ListItem::new("Inbox")
+                    .with_secondary_action("<button aria-label=\"Mute\">M</button>")],
+            ),
+            ListSection::new(
+                "Folders",
+                vec![ListItem::new("Archive"), ListItem::new("Trash")],
+            )
+            .collapsible(),
+        ])
+        .with_selection_mode(SelectionMode::Single)
+        .with_automation_id("mail")
+    }
+
+    #[test]
+    fn sectioned_render_collapses_collapsible_sections_by_default() {
+        let props = sectioned_props();
+        let state = build_state(props.item_count());
+        let html = super::render_sectioned_html(&props, &state);
+        assert!(html.contains("data-sectioned=\"true\""));
+        // The static section is always expanded; the collapsible one starts
+        // closed but its rows stay in the DOM for hydration parity.
+        assert!(html.contains("data-expanded=\"true\""));
+        assert!(html.contains("aria-expanded=\"false\""));
+        assert!(html.contains("Trash"));
+    }
+
+    #[test]
+    fn sectioned_render_reflects_expansion_state() {
+        let props = sectioned_props();
+        let mut state = build_state(props.item_count());
+        state.toggle_expansion(1, |_| {});
+        let html = super::render_sectioned_html(&props, &state);
+        assert!(html.contains("aria-expanded=\"true\""));
+        assert!(html.contains("aria-controls=\"rustic-list-mail-section-1\""));
+        assert!(!html.contains("data-expanded=\"false\""));
+    }
+
+    #[test]
+    fn sectioned_render_flattens_item_indices_across_sections() {
+        let props = sectioned_props();
+        let mut state = build_state(props.item_count());
+        state.toggle(2, |_| {});
+        let html = super::render_sectioned_html(&props, &state);
+        // "Trash" is the third row overall even though it is the second row of
+        // its section.
+        assert!(html.contains("id=\"rustic-list-mail-option-2\""));
+        let selected = html.matches("data-selected=\"true\"").count();
+        assert_eq!(selected, 1);
+        assert!(html.contains("class=\"rustic_ui_list_secondary_action\""));
+    }
 }
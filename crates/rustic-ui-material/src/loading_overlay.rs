@@ -0,0 +1,466 @@
+//! Material loading overlay combining a backdrop, circular progress and
+//! optional status text.
+//!
+//! The component exists because naive spinner wiring produces two classes of
+//! jank: spinners that flash for sub-100ms loads, and spinners that pop out
+//! the instant data lands even when they only just appeared.  The
+//! [`LoadingOverlayState`] guard built on the headless
+//! [`timing`](rustic_ui_headless::timing) primitives solves both — the
+//! overlay waits for a show delay before appearing and, once visible, lingers
+//! until a minimum display time has elapsed.  Backing the guard with the
+//! [`Clock`] abstraction keeps every transition deterministic under test via
+//! [`MockClock`](rustic_ui_headless::timing::MockClock).
+//!
+//! Rendering follows the crate wide convention: a single [`render_html`]
+//! routine assembles themed markup (backdrop, spinner, status line) and the
+//! framework adapters simply forward props/state, guaranteeing SSR parity and
+//! consistent automation hooks across Yew, Leptos, Dioxus and Sycamore.
+
+use std::time::Duration;
+
+use rustic_ui_headless::timing::{Clock, SystemClock};
+use rustic_ui_styled_engine::{css_with_theme, Style};
+
+/// Lifecycle phases of the overlay guard.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum OverlayPhase {
+    /// No load in flight and nothing on screen.
+    Idle,
+    /// A load started but the show delay has not elapsed yet.
+    Pending,
+    /// The overlay is visible and the load is still in flight.
+    Visible,
+    /// The load finished but the minimum display time keeps the overlay up.
+    Lingering,
+}
+
+/// Visibility guard pacing when the overlay appears and disappears.
+///
+/// * `show_delay` — loads faster than this never show the overlay at all.
+/// * `min_visible` — once shown, the overlay stays at least this long even if
+///   the load completes earlier.
+///
+/// Adapters call [`LoadingOverlayState::poll`] from their scheduling hook
+/// (e.g. `requestAnimationFrame` or a timer) to commit pending transitions.
+#[derive(Debug, Clone)]
+pub struct LoadingOverlayState<C: Clock = SystemClock> {
+    clock: C,
+    show_delay: Duration,
+    min_visible: Duration,
+    phase: OverlayPhase,
+    /// Instant the current load started (drives the show delay).
+    started_at: Option<C::Instant>,
+    /// Instant the overlay actually appeared (drives the minimum display).
+    shown_at: Option<C::Instant>,
+}
+
+impl LoadingOverlayState<SystemClock> {
+    /// Construct a guard backed by the wall clock with the enterprise
+    /// defaults: a 120ms show delay and a 480ms minimum display time.
+    pub fn new() -> Self {
+        Self::with_clock(
+            SystemClock,
+            Duration::from_millis(120),
+            Duration::from_millis(480),
+        )
+    }
+}
+
+impl Default for LoadingOverlayState<SystemClock> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<C: Clock> LoadingOverlayState<C> {
+    /// Construct a guard with an explicit clock and thresholds.  Tests inject
+    /// [`MockClock`](rustic_ui_headless::timing::MockClock) here to validate
+    /// transitions without sleeping.
+    pub fn with_clock(clock: C, show_delay: Duration, min_visible: Duration) -> Self {
+        Self {
+            clock,
+            show_delay,
+            min_visible,
+            phase: OverlayPhase::Idle,
+            started_at: None,
+            shown_at: None,
+        }
+    }
+
+    /// Signal that an async load began.  Restarting while already tracking a
+    /// load resets the delay window so overlapping refreshes behave like one
+    /// longer load instead of flickering.
+    pub fn start(&mut self) {
+        self.started_at = Some(self.clock.now());
+        if self.phase == OverlayPhase::Idle {
+            self.phase = OverlayPhase::Pending;
+        } else if self.phase == OverlayPhase::Lingering {
+            self.phase = OverlayPhase::Visible;
+        }
+    }
+
+    /// Signal that the load completed.  Returns the duration the overlay must
+    /// keep lingering (zero when it can hide immediately), which adapters use
+    /// to schedule the next [`poll`](Self::poll).
+    pub fn finish(&mut self) -> Duration {
+        match self.phase {
+            OverlayPhase::Pending => {
+                // Never shown: drop straight back to idle — this is the
+                // sub-100ms path the component exists to de-flicker.
+                self.phase = OverlayPhase::Idle;
+                self.started_at = None;
+                Duration::ZERO
+            }
+            OverlayPhase::Visible => {
+                let shown = self
+                    .shown_at
+                    .map(|shown_at| self.clock.duration_between(shown_at, self.clock.now()))
+                    .unwrap_or(Duration::ZERO);
+                if shown >= self.min_visible {
+                    self.phase = OverlayPhase::Idle;
+                    self.reset_instants();
+                    Duration::ZERO
+                } else {
+                    self.phase = OverlayPhase::Lingering;
+                    self.min_visible - shown
+                }
+            }
+            _ => Duration::ZERO,
+        }
+    }
+
+    /// Commit any time-based transition (pending → visible, lingering →
+    /// hidden) and return whether the overlay should currently render.
+    pub fn poll(&mut self) -> bool {
+        let now = self.clock.now();
+        match self.phase {
+            OverlayPhase::Pending => {
+                let waited = self
+                    .started_at
+                    .map(|started_at| self.clock.duration_between(started_at, now))
+                    .unwrap_or(Duration::ZERO);
+                if waited >= self.show_delay {
+                    self.phase = OverlayPhase::Visible;
+                    self.shown_at = Some(now);
+                }
+            }
+            OverlayPhase::Lingering => {
+                let shown = self
+                    .shown_at
+                    .map(|shown_at| self.clock.duration_between(shown_at, now))
+                    .unwrap_or(Duration::ZERO);
+                if shown >= self.min_visible {
+                    self.phase = OverlayPhase::Idle;
+                    self.reset_instants();
+                }
+            }
+            _ => {}
+        }
+        self.visible()
+    }
+
+    /// Returns whether the overlay should currently be rendered.
+    pub fn visible(&self) -> bool {
+        matches!(self.phase, OverlayPhase::Visible | OverlayPhase::Lingering)
+    }
+
+    /// Returns whether a load is still in flight (drives `aria-busy`).
+    pub fn busy(&self) -> bool {
+        matches!(self.phase, OverlayPhase::Pending | OverlayPhase::Visible)
+    }
+
+    fn reset_instants(&mut self) {
+        self.started_at = None;
+        self.shown_at = None;
+    }
+}
+
+/// Shared overlay properties consumed by every adapter.
+#[derive(Clone, Debug, Default)]
+pub struct LoadingOverlayProps {
+    /// Optional status text announced under the spinner (e.g. "Loading
+    /// report…").  Omitting it renders the spinner alone.
+    pub status: Option<String>,
+    /// Optional automation identifier stamped into `data-*` hooks.
+    pub automation_id: Option<String>,
+}
+
+impl LoadingOverlayProps {
+    /// Convenience constructor for an overlay without status text.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Attach a status line rendered beneath the progress indicator.
+    pub fn with_status(mut self, status: impl Into<String>) -> Self {
+        self.status = Some(status.into());
+        self
+    }
+
+    /// Override the automation identifier.
+    pub fn with_automation_id(mut self, id: impl Into<String>) -> Self {
+        self.automation_id = Some(id.into());
+        self
+    }
+}
+
+/// Shared rendering routine invoked by every framework adapter.
+///
+/// The overlay stays in the DOM across visibility flips — only `aria-hidden`
+/// and the `data-visible` hook change — so CSS transitions driven by the
+/// theme's motion tokens can fade it rather than popping.
+fn render_html<C: Clock>(props: &LoadingOverlayProps, state: &LoadingOverlayState<C>) -> String {
+    let visible = state.visible();
+    let backdrop_attrs = crate::style_helpers::themed_attributes_html(
+        themed_backdrop_style(),
+        backdrop_attributes(props, state),
+    );
+    let spinner_attrs = crate::style_helpers::themed_attributes_html(
+        themed_spinner_style(),
+        [
+            ("role".to_string(), "progressbar".to_string()),
+            ("aria-hidden".to_string(), (!visible).to_string()),
+        ],
+    );
+    let status_html = props
+        .status
+        .as_deref()
+        .map(|status| {
+            let status_attrs = crate::style_helpers::themed_attributes_html(
+                themed_status_style(),
+                [
+                    ("role".to_string(), "status".to_string()),
+                    ("aria-live".to_string(), "polite".to_string()),
+                ],
+            );
+            format!("<p {status_attrs}>{status}</p>")
+        })
+        .unwrap_or_default();
+
+    format!("<div {backdrop_attrs}><span {spinner_attrs}></span>{status_html}</div>")
+}
+
+/// Attributes applied to the backdrop container.
+fn backdrop_attributes<C: Clock>(
+    props: &LoadingOverlayProps,
+    state: &LoadingOverlayState<C>,
+) -> Vec<(String, String)> {
+    let visible = state.visible();
+    vec![
+        (
+            "data-component".into(),
+            crate::style_helpers::automation_id(
+                "loading-overlay",
+                None,
+                crate::style_helpers::NO_SEGMENTS,
+            ),
+        ),
+        (
+            crate::style_helpers::automation_data_attr("loading-overlay", ["root"]),
+            crate::style_helpers::automation_id(
+                "loading-overlay",
+                props.automation_id.as_deref(),
+                ["root"],
+            ),
+        ),
+        ("data-visible".into(), visible.to_string()),
+        ("aria-hidden".into(), (!visible).to_string()),
+        ("aria-busy".into(), state.busy().to_string()),
+    ]
+}
+
+/// Backdrop covering the async section, fading via the theme motion tokens.
+fn themed_backdrop_style() -> Style {
+    css_with_theme!(
+        r#"
+        position: absolute;
+        inset: 0;
+        display: flex;
+        flex-direction: column;
+        align-items: center;
+        justify-content: center;
+        gap: ${gap};
+        background: ${background};
+        z-index: 10;
+        transition: ${transitions};
+
+        &[aria-hidden='true'] {
+            opacity: 0;
+            pointer-events: none;
+        }
+
+        &[aria-hidden='false'] {
+            opacity: 1;
+        }
+    "#,
+        gap = format!("{}px", theme.spacing(2)),
+        background = format!(
+            "color-mix(in srgb, {} 72%, transparent)",
+            theme.palette.active().background_default.clone()
+        ),
+        transitions = theme.motion.transition(&["opacity", "visibility"]),
+    )
+}
+
+/// Indeterminate circular progress rendered as a spinning ring.
+fn themed_spinner_style() -> Style {
+    css_with_theme!(
+        r#"
+        width: ${size};
+        height: ${size};
+        border-radius: 50%;
+        border: ${thickness} solid ${track_color};
+        border-top-color: ${indicator_color};
+        animation: rustic-loading-overlay-spin ${duration} linear infinite;
+
+        @keyframes rustic-loading-overlay-spin {
+            to {
+                transform: rotate(360deg);
+            }
+        }
+    "#,
+        size = format!("{}px", theme.spacing(5)),
+        thickness = format!("{}px", theme.spacing(1) / 2),
+        track_color = format!(
+            "color-mix(in srgb, {} 24%, transparent)",
+            theme.palette.active().primary.clone()
+        ),
+        indicator_color = theme.palette.active().primary.clone(),
+        duration = format!("{}ms", theme.motion.effective_duration_ms(900).max(1)),
+    )
+}
+
+/// Typography for the optional status line.
+fn themed_status_style() -> Style {
+    css_with_theme!(
+        r#"
+        margin: 0;
+        font-family: ${font_family};
+        font-size: ${font_size};
+        color: ${text_color};
+    "#,
+        font_family = theme.typography.font_family.clone(),
+        font_size = format!("{:.3}rem", theme.typography.body2),
+        text_color = theme.palette.active().text_secondary.clone(),
+    )
+}
+
+// ---------------------------------------------------------------------------
+// Adapter implementations
+// ---------------------------------------------------------------------------
+
+/// Adapter targeting the [`yew`] framework.
+pub mod yew {
+    use super::*;
+
+    /// Render the overlay into a HTML string using the shared renderer.
+    pub fn render<C: Clock>(props: &LoadingOverlayProps, state: &LoadingOverlayState<C>) -> String {
+        super::render_html(props, state)
+    }
+}
+
+/// Adapter targeting the [`leptos`] framework.
+pub mod leptos {
+    use super::*;
+
+    /// Render the overlay into a HTML string using the shared renderer.
+    pub fn render<C: Clock>(props: &LoadingOverlayProps, state: &LoadingOverlayState<C>) -> String {
+        super::render_html(props, state)
+    }
+}
+
+/// Adapter targeting the [`dioxus`] framework.
+pub mod dioxus {
+    use super::*;
+
+    /// Render the overlay into a HTML string using the shared renderer.
+    pub fn render<C: Clock>(props: &LoadingOverlayProps, state: &LoadingOverlayState<C>) -> String {
+        super::render_html(props, state)
+    }
+}
+
+/// Adapter targeting the [`sycamore`] framework.
+pub mod sycamore {
+    use super::*;
+
+    /// Render the overlay into a HTML string using the shared renderer.
+    pub fn render<C: Clock>(props: &LoadingOverlayProps, state: &LoadingOverlayState<C>) -> String {
+        super::render_html(props, state)
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rustic_ui_headless::timing::MockClock;
+
+    fn guarded(clock: &MockClock) -> LoadingOverlayState<MockClock> {
+        LoadingOverlayState::with_clock(
+            clock.clone(),
+            Duration::from_millis(100),
+            Duration::from_millis(400),
+        )
+    }
+
+    #[test]
+    fn fast_loads_never_show_the_overlay() {
+        let clock = MockClock::new();
+        let mut state = guarded(&clock);
+        state.start();
+        clock.advance(Duration::from_millis(50));
+        assert!(!state.poll());
+        assert_eq!(state.finish(), Duration::ZERO);
+        assert!(!state.visible());
+    }
+
+    #[test]
+    fn slow_loads_show_after_the_delay_and_linger() {
+        let clock = MockClock::new();
+        let mut state = guarded(&clock);
+        state.start();
+        clock.advance(Duration::from_millis(150));
+        assert!(state.poll());
+        // Finishing 100ms after showing leaves 300ms of minimum display.
+        clock.advance(Duration::from_millis(100));
+        assert_eq!(state.finish(), Duration::from_millis(300));
+        assert!(state.visible());
+        clock.advance(Duration::from_millis(300));
+        assert!(!state.poll());
+    }
+
+    #[test]
+    fn restarting_during_linger_keeps_the_overlay_up() {
+        let clock = MockClock::new();
+        let mut state = guarded(&clock);
+        state.start();
+        clock.advance(Duration::from_millis(150));
+        state.poll();
+        state.finish();
+        state.start();
+        assert!(state.visible());
+        assert!(state.busy());
+    }
+
+    #[test]
+    fn markup_reflects_visibility_and_status() {
+        let clock = MockClock::new();
+        let mut state = guarded(&clock);
+        let props = LoadingOverlayProps::new()
+            .with_status("Loading report…")
+            .with_automation_id("report");
+        let hidden = super::render_html(&props, &state);
+        assert!(hidden.contains("aria-hidden=\"true\""));
+        state.start();
+        clock.advance(Duration::from_millis(150));
+        state.poll();
+        let shown = super::render_html(&props, &state);
+        assert!(shown.contains("data-component=\"rustic-loading-overlay\""));
+        assert!(shown.contains("aria-busy=\"true\""));
+        assert!(shown.contains("role=\"progressbar\""));
+        assert!(shown.contains("Loading report…"));
+    }
+}
@@ -1,32 +1,47 @@
 //! Helper macros for defining Material UI component props and enums.
+//!
+//! Earlier revisions emitted one struct per framework which meant every new
+//! adapter required another hand-written copy of the field list (and enabling
+//! two framework features at once produced duplicate definitions).  The macros
+//! now mirror the approach used by `rustic_ui_joy`: a single framework-neutral
+//! struct with `#[cfg_attr]` hooks layering in the `yew::Properties`,
+//! `dioxus::Props` and `sycamore::Props` derives whenever the matching Cargo
+//! feature is enabled.
 
-/// Generates a `Properties` struct for the target framework with a
-/// `Default` implementation. Each field automatically receives the
-/// appropriate attribute so callers may omit them when instantiating the
-/// component.
+/// Marker trait implemented for every generated props struct whenever the
+/// `leptos` feature is active.
+///
+/// Leptos components receive props through function parameters rather than a
+/// derive macro, so the trait simply certifies that a struct satisfies the
+/// `Clone + Default` contract Leptos wrappers rely on without imposing extra
+/// bounds on individual fields.
+#[cfg(feature = "leptos")]
+pub trait LeptosPropsAdapter: Clone + Default {}
+
+/// Generates a framework-neutral props struct with a `Default` implementation.
+///
+/// The struct always derives `Clone`, `Default` and `PartialEq`; framework
+/// specific derives and field attributes (for example Yew's
+/// `#[prop_or_default]`) are attached via `#[cfg_attr]` so they only apply when
+/// the corresponding feature is compiled in.  Callers therefore maintain a
+/// single field list shared by every adapter.
 #[macro_export]
 macro_rules! material_props {
     ($name:ident { $( $(#[$meta:meta])* $field:ident : $ty:ty ),* $(,)? }) => {
-        #[cfg(feature = "yew")]
-        #[derive(yew::Properties, Clone, PartialEq, Default)]
-        pub struct $name {
-            $( $(#[$meta])* #[prop_or_default] pub $field: $ty, )*
-        }
-
-        #[cfg(feature = "leptos")]
-        #[derive(leptos::Props, Clone, PartialEq)]
+        #[derive(Clone, Default, PartialEq)]
+        #[cfg_attr(feature = "yew", derive(yew::Properties))]
+        #[cfg_attr(feature = "dioxus", derive(dioxus::Props))]
+        #[cfg_attr(feature = "sycamore", derive(sycamore::Props))]
         pub struct $name {
-            $( $(#[$meta])* #[prop(optional, into)] pub $field: $ty, )*
+            $(
+                $(#[$meta])*
+                #[cfg_attr(feature = "yew", prop_or_default)]
+                pub $field: $ty,
+            )*
         }
 
         #[cfg(feature = "leptos")]
-        impl Default for $name {
-            fn default() -> Self {
-                Self {
-                    $( $field: Default::default(), )*
-                }
-            }
-        }
+        impl $crate::macros::LeptosPropsAdapter for $name {}
     };
 }
 
@@ -42,7 +57,7 @@ macro_rules! material_enum {
             $(,)?
         }
     ) => {
-        #[derive(Clone, PartialEq)]
+        #[derive(Clone, Debug, PartialEq)]
         pub enum $name {
             $(#[$meta_first])* $first,
             $( $(#[$meta])* $rest, )*
@@ -80,6 +95,18 @@ material_enum!(Size {
     Large
 });
 
+impl Size {
+    /// Lowercase identifier mirrored into `data-size` automation hooks so QA
+    /// suites and density-aware CSS target the same stable vocabulary.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Small => "small",
+            Self::Medium => "medium",
+            Self::Large => "large",
+        }
+    }
+}
+
 /// Convenience macro generating a `Props` struct that already includes the
 /// ubiquitous `color`, `variant` and `size` fields.  This drastically reduces
 /// boilerplate when adding new components by centralizing prop definitions in
@@ -90,7 +117,8 @@ material_enum!(Size {
 /// ```
 ///
 /// The above expands to a struct `AppBarProps` with fields `title`, `color`,
-/// `variant` and `size` – each one optional thanks to `#[prop_or_default]`.
+/// `variant` and `size` – each one optional for Yew consumers thanks to the
+/// conditionally applied `#[prop_or_default]` attribute.
 #[macro_export]
 macro_rules! material_component_props {
     ($name:ident { $( $(#[$meta:meta])* $field:ident : $ty:ty ),* $(,)? }) => {
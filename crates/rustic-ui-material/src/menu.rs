@@ -22,6 +22,10 @@ pub struct MenuItem {
     pub label: String,
     /// Stable identifier wired into `data-command` for automation scripts.
     pub command: String,
+    /// Optional navigation target. When set the item body renders as a
+    /// router-aware anchor following the [`crate::routing`] contract instead
+    /// of plain text, so selecting the item navigates without a full reload.
+    pub route: Option<crate::routing::RouterTarget>,
 }
 
 impl MenuItem {
@@ -30,8 +34,15 @@ impl MenuItem {
         Self {
             label: label.into(),
             command: command.into(),
+            route: None,
         }
     }
+
+    /// Attach a router navigation target to the item.
+    pub fn with_route(mut self, route: crate::routing::RouterTarget) -> Self {
+        self.route = Some(route);
+        self
+    }
 }
 
 /// Props shared across framework adapters.
@@ -108,7 +119,13 @@ fn render_html(props: &MenuProps, menu_state: &MenuState, popover_state: &Popove
             themed_item_style(),
             item_attributes(props, menu_state, index),
         );
-        items_html.push_str(&format!("<li {item_attrs}>{}</li>", item.label));
+        // Router-aware items wrap their label in an interceptable anchor so
+        // activating them performs a client side navigation.
+        let body = match &item.route {
+            Some(route) => crate::routing::wrap_in_link(route, &item.label),
+            None => item.label.clone(),
+        };
+        items_html.push_str(&format!("<li {item_attrs}>{body}</li>"));
     }
 
     let anchor_attrs = anchor_attributes(&anchor_meta, &portal);
@@ -124,7 +141,11 @@ fn render_html(props: &MenuProps, menu_state: &MenuState, popover_state: &Popove
 }
 
 fn automation_base(props: &MenuProps) -> String {
-    crate::style_helpers::automation_id("menu", props.automation_id.as_deref(), [])
+    crate::style_helpers::automation_id(
+        "menu",
+        props.automation_id.as_deref(),
+        crate::style_helpers::NO_SEGMENTS,
+    )
 }
 
 fn surface_id(props: &MenuProps) -> String {
@@ -158,7 +179,7 @@ fn root_attributes(
     ));
     attrs.push((
         "data-component".into(),
-        crate::style_helpers::automation_id("menu", None, []),
+        crate::style_helpers::automation_id("menu", None, crate::style_helpers::NO_SEGMENTS),
     ));
     let (open_key, open_value) = surface_meta.data_open();
     attrs.push((open_key.into(), open_value.into()));
@@ -284,7 +305,9 @@ fn item_attributes(props: &MenuProps, state: &MenuState, index: usize) -> Vec<(S
 }
 
 fn popover_mount(props: &MenuProps) -> PortalMount {
-    PortalMount::popover(crate::style_helpers::automation_id(
+    // Anonymous menus draw their portal id from the hydration allocator so
+    // multiple instances on one page stay unique and SSR/CSR stay aligned.
+    PortalMount::popover(crate::style_helpers::hydration_scoped_id(
         "menu",
         props.automation_id.as_deref(),
         ["popover"],
@@ -374,17 +397,17 @@ fn themed_trigger_style() -> Style {
         radius = format!("{}px", theme.joy.radius),
         border_color = format!(
             "color-mix(in srgb, {} 40%, transparent)",
-            theme.palette.text_secondary.clone()
+            theme.palette.active().text_secondary.clone()
         ),
-        background = theme.palette.background_paper.clone(),
-        text_color = theme.palette.text_primary.clone(),
+        background = theme.palette.active().background_paper.clone(),
+        text_color = theme.palette.active().text_primary.clone(),
         font_family = theme.typography.font_family.clone(),
         font_size = format!("{:.3}rem", theme.typography.button),
-        focus_color = theme.palette.secondary.clone(),
+        focus_color = theme.palette.active().secondary.clone(),
         focus_width = format!("{:.1}px", (theme.joy.focus.thickness as f32).max(1.0) / 2.0),
         focus_color_transparent = format!(
             "color-mix(in srgb, {} 24%, transparent)",
-            theme.palette.secondary.clone()
+            theme.palette.active().secondary.clone()
         )
     )
 }
@@ -416,12 +439,12 @@ fn themed_surface_style() -> Style {
         radius = format!("{}px", theme.joy.radius),
         border_color = format!(
             "color-mix(in srgb, {} 40%, transparent)",
-            theme.palette.text_secondary.clone()
+            theme.palette.active().text_secondary.clone()
         ),
-        background = theme.palette.background_paper.clone(),
+        background = theme.palette.active().background_paper.clone(),
         shadow = format!(
             "0 12px 24px color-mix(in srgb, {} 18%, transparent)",
-            theme.palette.text_primary.clone()
+            theme.palette.active().text_primary.clone()
         )
     )
 }
@@ -446,11 +469,11 @@ fn themed_item_style() -> Style {
         radius = format!("{:.1}px", (theme.joy.radius as f32) / 2.0),
         font_family = theme.typography.font_family.clone(),
         font_size = format!("{:.3}rem", theme.typography.body2),
-        text_color = theme.palette.text_primary.clone(),
+        text_color = theme.palette.active().text_primary.clone(),
         hover_background = format!(
             "color-mix(in srgb, {} 12%, {})",
-            theme.palette.secondary.clone(),
-            theme.palette.background_paper.clone()
+            theme.palette.active().secondary.clone(),
+            theme.palette.active().background_paper.clone()
         )
     )
 }
@@ -0,0 +1,326 @@
+//! Compact wizard footer driven by the headless
+//! [`StepperState`](rustic_ui_headless::stepper::StepperState).
+//!
+//! Mobile wizard flows trade the full horizontal stepper for a slim footer:
+//! back and next controls flanking a progress indicator.  The machine keeps
+//! owning step arithmetic; this renderer only chooses how progress is drawn
+//! — dots for short flows, a progress bar for longer ones, or plain "3 of
+//! 5" text — and wires the controls to `data-stepper-action` hooks so
+//! adapters delegate clicks straight into
+//! [`StepperState::advance`](rustic_ui_headless::stepper::StepperState::advance)
+//! and `previous`.
+
+use rustic_ui_headless::stepper::StepperState;
+use rustic_ui_styled_engine::{css_with_theme, Style};
+
+/// How the position within the wizard is visualized between the controls.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum MobileStepperVariant {
+    /// One dot per step with the active one highlighted; best under ~6
+    /// steps.
+    #[default]
+    Dots,
+    /// Determinate progress bar scaled to the active step.
+    Progress,
+    /// Plain "3 of 5" text for flows where dots would overflow.
+    Text,
+}
+
+impl MobileStepperVariant {
+    fn as_str(self) -> &'static str {
+        match self {
+            MobileStepperVariant::Dots => "dots",
+            MobileStepperVariant::Progress => "progress",
+            MobileStepperVariant::Text => "text",
+        }
+    }
+}
+
+/// Shared properties accepted by all adapter implementations.
+#[derive(Clone, Debug, PartialEq)]
+pub struct MobileStepperProps {
+    /// Progress visualization between the two controls.
+    pub variant: MobileStepperVariant,
+    /// Label of the backward control slot.
+    pub back_label: String,
+    /// Label of the forward control slot.
+    pub next_label: String,
+    /// Optional automation identifier for analytics and end-to-end tests.
+    pub automation_id: Option<String>,
+}
+
+impl Default for MobileStepperProps {
+    fn default() -> Self {
+        Self {
+            variant: MobileStepperVariant::default(),
+            back_label: "Back".to_string(),
+            next_label: "Next".to_string(),
+            automation_id: None,
+        }
+    }
+}
+
+impl MobileStepperProps {
+    /// Convenience constructor used by examples and tests.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Selects the progress visualization.
+    pub fn with_variant(mut self, variant: MobileStepperVariant) -> Self {
+        self.variant = variant;
+        self
+    }
+
+    /// Overrides the backward control label, e.g. "Previous".
+    pub fn with_back_label(mut self, label: impl Into<String>) -> Self {
+        self.back_label = label.into();
+        self
+    }
+
+    /// Overrides the forward control label, e.g. "Continue" or "Place
+    /// order" on the final step.
+    pub fn with_next_label(mut self, label: impl Into<String>) -> Self {
+        self.next_label = label.into();
+        self
+    }
+
+    /// Sets the automation identifier stamped on the rendered hooks.
+    pub fn with_automation_id(mut self, id: impl Into<String>) -> Self {
+        self.automation_id = Some(id.into());
+        self
+    }
+}
+
+/// Shared rendering routine used by all adapters.
+fn render_html(props: &MobileStepperProps, state: &StepperState) -> String {
+    let active = state.active().unwrap_or(0);
+    let count = state.step_count();
+    let attrs = crate::style_helpers::themed_attributes_html(
+        themed_mobile_stepper_style(),
+        vec![
+            ("role".to_string(), String::from("group")),
+            (
+                "aria-label".to_string(),
+                format!("Step {} of {}", active + 1, count),
+            ),
+            (
+                "data-variant".to_string(),
+                props.variant.as_str().to_string(),
+            ),
+            (
+                crate::style_helpers::automation_data_attr("mobile-stepper", ["root"]),
+                crate::style_helpers::automation_id(
+                    "mobile-stepper",
+                    props.automation_id.as_deref(),
+                    ["root"],
+                ),
+            ),
+        ],
+    );
+    let indicator = match props.variant {
+        MobileStepperVariant::Dots => {
+            let dots: String = (0..count)
+                .map(|index| {
+                    format!(
+                        "<span data-stepper-dot=\"\" data-active=\"{}\"></span>",
+                        index == active
+                    )
+                })
+                .collect();
+            format!("<div data-stepper-dots=\"\" aria-hidden=\"true\">{dots}</div>")
+        }
+        MobileStepperVariant::Progress => {
+            // Progress reflects steps entered, so the bar starts empty and
+            // fills completely on the final step.
+            let percent = if count > 1 {
+                (active as f64 / (count - 1) as f64 * 100.0).round() as u8
+            } else {
+                100
+            };
+            format!(
+                "<div role=\"progressbar\" aria-valuemin=\"0\" aria-valuemax=\"100\" \
+                 aria-valuenow=\"{percent}\"><span style=\"width:{percent}%;\"></span></div>"
+            )
+        }
+        MobileStepperVariant::Text => format!(
+            "<span data-stepper-position=\"\">{} of {}</span>",
+            active + 1,
+            count
+        ),
+    };
+    let back_disabled = if active == 0 { " disabled" } else { "" };
+    let next_disabled = if active + 1 >= count { " disabled" } else { "" };
+    format!(
+        "<footer {attrs}>\
+         <button type=\"button\" data-stepper-action=\"back\"{back_disabled}>{back}</button>\
+         {indicator}\
+         <button type=\"button\" data-stepper-action=\"next\"{next_disabled}>{next}</button>\
+         </footer>",
+        back = crate::render::escape_text(&props.back_label),
+        next = crate::render::escape_text(&props.next_label),
+    )
+}
+
+/// Footer bar styling pulled from the active theme tokens.
+fn themed_mobile_stepper_style() -> Style {
+    css_with_theme!(
+        r#"
+        display: flex;
+        align-items: center;
+        justify-content: space-between;
+        gap: ${gap};
+        padding: ${padding};
+        background: ${surface};
+        font-family: ${font_family};
+        color: ${text};
+
+        & [data-stepper-dots] {
+            display: flex;
+            gap: ${dot_gap};
+        }
+
+        & [data-stepper-dot] {
+            width: ${dot_size};
+            height: ${dot_size};
+            border-radius: 50%;
+            background: ${track};
+        }
+
+        & [data-stepper-dot][data-active='true'] {
+            background: ${accent};
+        }
+
+        & [role='progressbar'] {
+            flex: 1;
+            height: ${bar_height};
+            border-radius: 9999px;
+            background: ${track};
+            overflow: hidden;
+        }
+
+        & [role='progressbar'] > span {
+            display: block;
+            height: 100%;
+            background: ${accent};
+        }
+
+        & [data-stepper-position] {
+            font-size: 0.75rem;
+            color: ${secondary};
+        }
+    "#,
+        gap = format!("{}px", theme.spacing(2)),
+        padding = format!("{}px", theme.spacing(1)),
+        surface = theme.palette.active().background_paper.clone(),
+        font_family = theme.typography.font_family.clone(),
+        text = theme.palette.active().text_primary.clone(),
+        dot_gap = format!("{}px", theme.spacing(1)),
+        dot_size = format!("{}px", theme.spacing(1)),
+        track = theme.palette.active().neutral.clone(),
+        accent = theme.palette.active().primary.clone(),
+        bar_height = format!("{}px", theme.spacing(1) / 2),
+        secondary = theme.palette.active().text_secondary.clone(),
+    )
+}
+
+// ---------------------------------------------------------------------------
+// Adapter implementations
+// ---------------------------------------------------------------------------
+
+pub mod yew {
+    use super::*;
+
+    /// Render the mobile stepper into a plain HTML string for SSR/hydration.
+    pub fn render(props: &MobileStepperProps, state: &StepperState) -> String {
+        super::render_html(props, state)
+    }
+}
+
+pub mod leptos {
+    use super::*;
+
+    /// Render the mobile stepper into a plain HTML string for SSR/hydration.
+    pub fn render(props: &MobileStepperProps, state: &StepperState) -> String {
+        super::render_html(props, state)
+    }
+}
+
+pub mod dioxus {
+    use super::*;
+
+    /// Render the mobile stepper into a plain HTML string for SSR/hydration.
+    pub fn render(props: &MobileStepperProps, state: &StepperState) -> String {
+        super::render_html(props, state)
+    }
+}
+
+pub mod sycamore {
+    use super::*;
+
+    /// Render the mobile stepper into a plain HTML string for SSR/hydration.
+    pub fn render(props: &MobileStepperProps, state: &StepperState) -> String {
+        super::render_html(props, state)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rustic_ui_headless::stepper::StepperConfig;
+
+    fn stepper_at(step_count: usize, active: usize) -> StepperState {
+        // Non-linear so the tests can jump straight to the step under
+        // inspection without completing the preceding ones.
+        StepperState::new(StepperConfig {
+            step_count,
+            linear: false,
+            initial_active: Some(active),
+        })
+    }
+
+    #[test]
+    fn dots_highlight_the_active_step() {
+        let html = render_html(
+            &MobileStepperProps::new().with_automation_id("checkout"),
+            &stepper_at(3, 1),
+        );
+        assert!(html.contains("data-variant=\"dots\""));
+        assert_eq!(html.matches("data-stepper-dot=\"\"").count(), 3);
+        assert_eq!(html.matches("data-active=\"true\"").count(), 1);
+        assert!(html.contains("aria-label=\"Step 2 of 3\""));
+        assert!(html
+            .contains("data-rustic-mobile-stepper-root=\"rustic-mobile-stepper-checkout-root\""));
+    }
+
+    #[test]
+    fn progress_variant_scales_to_the_active_step() {
+        let props = MobileStepperProps::new().with_variant(MobileStepperVariant::Progress);
+        let html = render_html(&props, &stepper_at(5, 2));
+        assert!(html.contains("aria-valuenow=\"50\""));
+        assert!(html.contains("width:50%;"));
+    }
+
+    #[test]
+    fn text_variant_renders_the_position() {
+        let props = MobileStepperProps::new()
+            .with_variant(MobileStepperVariant::Text)
+            .with_back_label("Previous")
+            .with_next_label("Continue");
+        let html = render_html(&props, &stepper_at(8, 4));
+        assert!(html.contains("data-stepper-position=\"\">5 of 8<"));
+        assert!(html.contains(">Previous</button>"));
+        assert!(html.contains(">Continue</button>"));
+    }
+
+    #[test]
+    fn controls_disable_at_the_sequence_edges() {
+        let first = render_html(&MobileStepperProps::new(), &stepper_at(3, 0));
+        assert!(first.contains("data-stepper-action=\"back\" disabled>"));
+        assert!(first.contains("data-stepper-action=\"next\">"));
+
+        let last = render_html(&MobileStepperProps::new(), &stepper_at(3, 2));
+        assert!(last.contains("data-stepper-action=\"back\">"));
+        assert!(last.contains("data-stepper-action=\"next\" disabled>"));
+    }
+}
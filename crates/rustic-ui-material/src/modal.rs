@@ -0,0 +1,265 @@
+//! Low level modal infrastructure shared by custom blocking surfaces.
+//!
+//! [`dialog`](crate::dialog) bundles `role="dialog"`, `aria-modal` and the
+//! labelling contract together with its backdrop and focus management.  That
+//! is exactly right for dialogs but too opinionated for lightboxes, full
+//! screen takeovers and other bespoke surfaces that still need the
+//! surrounding machinery: a portal container rendered outside the host
+//! markup, a themed backdrop, body scroll locking and focus trap hooks.
+//!
+//! This module exposes that machinery on its own.  The rendered container
+//! carries only `data-*` hooks — no dialog role and no `aria-modal` — so
+//! callers remain free to place whatever semantics their surface actually
+//! warrants inside the children.  Lifecycle, transition phases and focus trap
+//! bookkeeping are delegated to the same
+//! [`DialogState`](rustic_ui_headless::dialog::DialogState) machine dialog
+//! uses, keeping open/close analytics identical across both primitives.
+
+use rustic_ui_headless::dialog::DialogState;
+use rustic_ui_styled_engine::{css_with_theme, Style};
+use rustic_ui_system::portal::PortalMount;
+
+/// Shared modal properties consumed by every adapter.
+#[derive(Clone, Debug, Default)]
+pub struct ModalProps {
+    /// Pre-rendered HTML for the surface hosted inside the modal container.
+    pub children_html: String,
+    /// Optional automation identifier stamped into `data-*` hooks.
+    pub automation_id: Option<String>,
+}
+
+impl ModalProps {
+    /// Wrap the provided surface markup.
+    pub fn new(children_html: impl Into<String>) -> Self {
+        Self {
+            children_html: children_html.into(),
+            automation_id: None,
+        }
+    }
+
+    /// Override the automation identifier.
+    pub fn with_automation_id(mut self, id: impl Into<String>) -> Self {
+        self.automation_id = Some(id.into());
+        self
+    }
+}
+
+/// Portal descriptor anchoring the modal container outside the host markup.
+fn modal_mount(props: &ModalProps) -> PortalMount {
+    PortalMount::modal(crate::style_helpers::hydration_scoped_id(
+        "modal",
+        props.automation_id.as_deref(),
+        ["portal"],
+    ))
+}
+
+/// Attributes adapters should spread onto the document `<body>` (or scroll
+/// container) while the modal is open.
+///
+/// Styling is intentionally left to a single global rule applications inject
+/// once — see [`scroll_lock_css`] — so the lock can be asserted in SSR tests
+/// without touching a real DOM.
+pub fn scroll_lock_attributes(state: &DialogState) -> Vec<(String, String)> {
+    vec![(
+        "data-scroll-lock".into(),
+        if state.is_open() {
+            "active"
+        } else {
+            "inactive"
+        }
+        .to_string(),
+    )]
+}
+
+/// Global stylesheet rule complementing [`scroll_lock_attributes`].
+pub fn scroll_lock_css() -> &'static str {
+    "[data-scroll-lock='active'] { overflow: hidden; }"
+}
+
+/// Shared rendering routine invoked by every framework adapter.
+///
+/// Emits the portal-wrapped backdrop and content container.  The container is
+/// a plain `<div>` with transition and focus trap hooks; callers layer their
+/// own semantics (or none at all) via `children_html`.
+fn render_html(props: &ModalProps, state: &DialogState) -> String {
+    let portal = modal_mount(props);
+    let backdrop_meta = state.backdrop_attributes();
+    let surface_meta = state.surface_attributes();
+
+    let mut backdrop_attrs: Vec<(String, String)> = vec![(
+        "data-visible".to_string(),
+        backdrop_meta.is_visible().to_string(),
+    )];
+    let (key, value) = backdrop_meta.data_state();
+    backdrop_attrs.push((key.to_string(), value.to_string()));
+    backdrop_attrs.push((
+        crate::style_helpers::automation_data_attr("modal", ["backdrop"]),
+        crate::style_helpers::automation_id("modal", props.automation_id.as_deref(), ["backdrop"]),
+    ));
+    backdrop_attrs.push(("aria-hidden".to_string(), "true".to_string()));
+    let backdrop_attrs =
+        crate::style_helpers::themed_attributes_html(themed_backdrop_style(), backdrop_attrs);
+
+    // Deliberately no `role` and no `aria-modal`: those belong to the surface
+    // hosted inside the container, not to the infrastructure.
+    let mut container_attrs: Vec<(String, String)> = vec![(
+        "data-component".to_string(),
+        crate::style_helpers::automation_id("modal", None, crate::style_helpers::NO_SEGMENTS),
+    )];
+    container_attrs.push((
+        crate::style_helpers::automation_data_attr("modal", ["root"]),
+        crate::style_helpers::automation_id("modal", props.automation_id.as_deref(), ["root"]),
+    ));
+    let (key, value) = surface_meta.data_state();
+    container_attrs.push((key.to_string(), value.to_string()));
+    if let Some((key, value)) = surface_meta.data_transition() {
+        container_attrs.push((key.to_string(), value.to_string()));
+    }
+    let (key, value) = surface_meta.data_focus_trap();
+    container_attrs.push((key.to_string(), value.to_string()));
+    container_attrs.push(("tabindex".to_string(), "-1".to_string()));
+    let container_attrs =
+        crate::style_helpers::themed_attributes_html(themed_container_style(), container_attrs);
+
+    let inner = format!(
+        "<div {backdrop_attrs}></div><div {container_attrs}>{}</div>",
+        props.children_html
+    );
+    format!("{}{}", portal.anchor_html(), portal.wrap(inner).into_html())
+}
+
+/// Backdrop styling: a fixed scrim fading with the theme's motion tokens.
+fn themed_backdrop_style() -> Style {
+    css_with_theme!(
+        r#"
+        position: fixed;
+        inset: 0;
+        background: rgba(0, 0, 0, 0.5);
+        transition: ${transition};
+        &[data-visible='false'] {
+            opacity: 0;
+            pointer-events: none;
+        }
+    "#,
+        transition = theme.motion.transition(&["opacity"]),
+    )
+}
+
+/// Container styling: centers the hosted surface above the backdrop without
+/// imposing surface chrome (padding, elevation) that custom surfaces own.
+fn themed_container_style() -> Style {
+    css_with_theme!(
+        r#"
+        position: fixed;
+        inset: 0;
+        display: flex;
+        align-items: center;
+        justify-content: center;
+        outline: none;
+        &[data-state='closed'] {
+            display: none;
+        }
+    "#,
+    )
+}
+
+// ---------------------------------------------------------------------------
+// Adapter implementations
+// ---------------------------------------------------------------------------
+
+/// Adapter targeting the [`yew`] framework.
+pub mod yew {
+    use super::*;
+
+    /// Render the modal into a HTML string using the shared renderer.
+    pub fn render(props: &ModalProps, state: &DialogState) -> String {
+        super::render_html(props, state)
+    }
+}
+
+/// Adapter targeting the [`leptos`] framework.
+pub mod leptos {
+    use super::*;
+
+    /// Render the modal into a HTML string using the shared renderer.
+    pub fn render(props: &ModalProps, state: &DialogState) -> String {
+        super::render_html(props, state)
+    }
+}
+
+/// Adapter targeting the [`dioxus`] framework.
+pub mod dioxus {
+    use super::*;
+
+    /// Render the modal into a HTML string using the shared renderer.
+    pub fn render(props: &ModalProps, state: &DialogState) -> String {
+        super::render_html(props, state)
+    }
+}
+
+/// Adapter targeting the [`sycamore`] framework.
+pub mod sycamore {
+    use super::*;
+
+    /// Render the modal into a HTML string using the shared renderer.
+    pub fn render(props: &ModalProps, state: &DialogState) -> String {
+        super::render_html(props, state)
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn open_state() -> DialogState {
+        let mut state = DialogState::uncontrolled(false);
+        state.open(|_| {});
+        state
+    }
+
+    #[test]
+    fn render_omits_dialog_semantics() {
+        let props = ModalProps::new("<figure>lightbox</figure>").with_automation_id("gallery");
+        let html = super::render_html(&props, &open_state());
+        assert!(!html.contains("role=\"dialog\""));
+        assert!(!html.contains("aria-modal"));
+        assert!(html.contains("<figure>lightbox</figure>"));
+    }
+
+    #[test]
+    fn render_exposes_focus_trap_and_state_hooks() {
+        let props = ModalProps::new("<div></div>").with_automation_id("gallery");
+        let html = super::render_html(&props, &open_state());
+        assert!(html.contains("data-focus-trap=\"active\""));
+        assert!(html.contains("data-state=\"open\""));
+        assert!(html.contains("data-rustic-modal-root=\"rustic-modal-gallery-root\""));
+        assert!(html.contains("data-rustic-modal-backdrop"));
+    }
+
+    #[test]
+    fn portal_markup_uses_the_modal_layer() {
+        let props = ModalProps::new("<div></div>");
+        let html = super::render_html(&props, &DialogState::uncontrolled(false));
+        assert!(html.contains("data-portal-layer=\"modal\""));
+        assert!(html.contains("data-portal-root"));
+    }
+
+    #[test]
+    fn scroll_lock_follows_the_open_state() {
+        let mut state = DialogState::uncontrolled(false);
+        let attrs = scroll_lock_attributes(&state);
+        assert!(attrs
+            .iter()
+            .any(|(k, v)| k == "data-scroll-lock" && v == "inactive"));
+        state.open(|_| {});
+        let attrs = scroll_lock_attributes(&state);
+        assert!(attrs
+            .iter()
+            .any(|(k, v)| k == "data-scroll-lock" && v == "active"));
+        assert!(scroll_lock_css().contains("overflow: hidden"));
+    }
+}
@@ -0,0 +1,212 @@
+//! Defer browser-only children until after hydration.
+//!
+//! Widgets that touch browser-only APIs — canvas charts, `ResizeObserver`
+//! driven measurements, clipboard integrations — cannot render meaningfully
+//! on the server.  This module formalizes the escape hatch: the server emits
+//! a stable placeholder (optionally showing a fallback such as a skeleton),
+//! and the client swaps the real children in after its first render.  Because
+//! both phases flow through the same [`render_html`] routine the container
+//! markup and automation hooks are byte-identical across SSR and hydration,
+//! so the swap never trips hydration mismatch detection.
+//!
+//! The module is also exported as `client_only` from the crate root since
+//! both names are common in the wider ecosystem.
+
+use rustic_ui_styled_engine::{css_with_theme, Style};
+
+/// Mount tracker owned by the framework adapter.
+///
+/// Frameworks flip the flag from their "after first client render" hook
+/// (`use_effect` with empty deps in Yew, `create_effect` in Leptos, etc.);
+/// server renderers simply never call [`NoSsrState::mark_mounted`].
+#[derive(Debug, Clone, Copy, Default)]
+pub struct NoSsrState {
+    mounted: bool,
+}
+
+impl NoSsrState {
+    /// State as seen by the server and the very first client render.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record that hydration completed and browser APIs are available.
+    pub fn mark_mounted(&mut self) {
+        self.mounted = true;
+    }
+
+    /// Returns whether the real children should render.
+    pub fn is_mounted(&self) -> bool {
+        self.mounted
+    }
+}
+
+/// Shared wrapper properties consumed by every adapter.
+#[derive(Clone, Debug, Default)]
+pub struct NoSsrProps {
+    /// Pre-rendered HTML for the browser-only children.
+    pub children_html: String,
+    /// Optional fallback shown while the children are deferred (skeletons,
+    /// fixed-size placeholders preventing layout shift).
+    pub fallback_html: Option<String>,
+    /// Optional automation identifier stamped into `data-*` hooks.
+    pub automation_id: Option<String>,
+}
+
+impl NoSsrProps {
+    /// Wrap the provided children.
+    pub fn new(children_html: impl Into<String>) -> Self {
+        Self {
+            children_html: children_html.into(),
+            fallback_html: None,
+            automation_id: None,
+        }
+    }
+
+    /// Provide fallback markup rendered until the client mounts.
+    pub fn with_fallback(mut self, fallback_html: impl Into<String>) -> Self {
+        self.fallback_html = Some(fallback_html.into());
+        self
+    }
+
+    /// Override the automation identifier.
+    pub fn with_automation_id(mut self, id: impl Into<String>) -> Self {
+        self.automation_id = Some(id.into());
+        self
+    }
+}
+
+/// Shared rendering routine invoked by every framework adapter.
+///
+/// The wrapper element persists across the swap — only its contents and the
+/// `data-no-ssr-phase` hook change — giving QA pipelines and styling a stable
+/// target through the placeholder/content transition.
+fn render_html(props: &NoSsrProps, state: &NoSsrState) -> String {
+    let phase = if state.is_mounted() {
+        "mounted"
+    } else {
+        "deferred"
+    };
+    let attrs = crate::style_helpers::themed_attributes_html(
+        themed_wrapper_style(),
+        [
+            (
+                "data-component".to_string(),
+                crate::style_helpers::automation_id(
+                    "no-ssr",
+                    None,
+                    crate::style_helpers::NO_SEGMENTS,
+                ),
+            ),
+            (
+                crate::style_helpers::automation_data_attr("no-ssr", ["root"]),
+                crate::style_helpers::automation_id(
+                    "no-ssr",
+                    props.automation_id.as_deref(),
+                    ["root"],
+                ),
+            ),
+            ("data-no-ssr-phase".to_string(), phase.to_string()),
+        ],
+    );
+    let contents = if state.is_mounted() {
+        props.children_html.as_str()
+    } else {
+        props.fallback_html.as_deref().unwrap_or("")
+    };
+    format!("<span {attrs}>{contents}</span>")
+}
+
+/// Neutral wrapper style: display contents so the wrapper never perturbs the
+/// surrounding layout while still being targetable via its data hooks.
+fn themed_wrapper_style() -> Style {
+    css_with_theme!(
+        r#"
+        display: contents;
+    "#,
+    )
+}
+
+// ---------------------------------------------------------------------------
+// Adapter implementations
+// ---------------------------------------------------------------------------
+
+/// Adapter targeting the [`yew`] framework.
+pub mod yew {
+    use super::*;
+
+    /// Render the wrapper into a HTML string using the shared renderer.
+    pub fn render(props: &NoSsrProps, state: &NoSsrState) -> String {
+        super::render_html(props, state)
+    }
+}
+
+/// Adapter targeting the [`leptos`] framework.
+pub mod leptos {
+    use super::*;
+
+    /// Render the wrapper into a HTML string using the shared renderer.
+    pub fn render(props: &NoSsrProps, state: &NoSsrState) -> String {
+        super::render_html(props, state)
+    }
+}
+
+/// Adapter targeting the [`dioxus`] framework.
+pub mod dioxus {
+    use super::*;
+
+    /// Render the wrapper into a HTML string using the shared renderer.
+    pub fn render(props: &NoSsrProps, state: &NoSsrState) -> String {
+        super::render_html(props, state)
+    }
+}
+
+/// Adapter targeting the [`sycamore`] framework.
+pub mod sycamore {
+    use super::*;
+
+    /// Render the wrapper into a HTML string using the shared renderer.
+    pub fn render(props: &NoSsrProps, state: &NoSsrState) -> String {
+        super::render_html(props, state)
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn server_renders_the_fallback_not_the_children() {
+        let props = NoSsrProps::new("<canvas id=\"chart\"></canvas>")
+            .with_fallback("<div class=\"skeleton\"></div>");
+        let state = NoSsrState::new();
+        let html = super::render_html(&props, &state);
+        assert!(html.contains("data-no-ssr-phase=\"deferred\""));
+        assert!(html.contains("skeleton"));
+        assert!(!html.contains("canvas"));
+    }
+
+    #[test]
+    fn children_appear_after_mounting() {
+        let props = NoSsrProps::new("<canvas id=\"chart\"></canvas>");
+        let mut state = NoSsrState::new();
+        state.mark_mounted();
+        let html = super::render_html(&props, &state);
+        assert!(html.contains("data-no-ssr-phase=\"mounted\""));
+        assert!(html.contains("canvas"));
+    }
+
+    #[test]
+    fn missing_fallback_renders_an_empty_wrapper() {
+        let props = NoSsrProps::new("<video></video>").with_automation_id("player");
+        let state = NoSsrState::new();
+        let html = super::render_html(&props, &state);
+        assert!(html.contains("data-component=\"rustic-no-ssr\""));
+        assert!(html.contains("data-rustic-no-ssr-root=\"rustic-no-ssr-player-root\""));
+        assert!(html.ends_with("></span>"));
+    }
+}
@@ -0,0 +1,529 @@
+//! Material themed popover renderer for the headless
+//! [`PopoverState`](rustic_ui_headless::popover::PopoverState).
+//!
+//! The headless machine has long powered [`menu`](crate::menu) and
+//! [`select`](crate::select) internally, but feature teams wanting a generic
+//! floating surface had to hand-assemble anchor spans, portal containers and
+//! placement metadata.  This module closes that gap with the crate's standard
+//! shape: shared [`render_html`] assembly, theme-aware styling, deterministic
+//! automation hooks and thin per-framework adapters.  The renderer emits the
+//! anchor binding, the collision-resolved placement attributes, an optional
+//! arrow and a portal-wrapped surface so SSR snapshots and hydrated trees stay
+//! in lockstep.
+
+use rustic_ui_headless::popover::{
+    AnchorGeometry, CollisionOutcome, PopoverPlacement, PopoverState,
+};
+use rustic_ui_styled_engine::{css_with_theme, Style};
+use rustic_ui_system::portal::PortalMount;
+use rustic_ui_utils::attributes_to_html;
+
+/// Shared popover properties consumed by every adapter.
+#[derive(Clone, Debug)]
+pub struct PopoverProps {
+    /// Content rendered inside the trigger button.
+    pub trigger_label: String,
+    /// Pre-rendered HTML fragment displayed inside the floating surface.
+    pub surface_html: String,
+    /// Render a placement-aware arrow pointing at the anchor.
+    pub arrow: bool,
+    /// Optional automation identifier stamped into `data-*` hooks.
+    pub automation_id: Option<String>,
+}
+
+impl PopoverProps {
+    /// Convenience constructor used by documentation examples and tests.
+    pub fn new(trigger_label: impl Into<String>, surface_html: impl Into<String>) -> Self {
+        Self {
+            trigger_label: trigger_label.into(),
+            surface_html: surface_html.into(),
+            arrow: false,
+            automation_id: None,
+        }
+    }
+
+    /// Toggle the placement-aware arrow.
+    pub fn with_arrow(mut self, arrow: bool) -> Self {
+        self.arrow = arrow;
+        self
+    }
+
+    /// Override the automation identifier.
+    pub fn with_automation_id(mut self, id: impl Into<String>) -> Self {
+        self.automation_id = Some(id.into());
+        self
+    }
+}
+
+/// Viewport-aware flip resolver suitable for
+/// [`PopoverState::resolve_with`].
+///
+/// The resolver flips vertical placements when the surface would cross the
+/// viewport edge on the preferred side and there is more room on the opposite
+/// one; horizontal placements mirror the logic.  Centered placements are kept
+/// as-is.  Adapters that need fancier behaviour (shift, size clamping) can
+/// supply their own closure — this helper only standardizes the common case
+/// so feature teams stop re-deriving it.
+pub fn flip_resolver(
+    surface_size: (f64, f64),
+    viewport: (f64, f64),
+) -> impl FnOnce(AnchorGeometry, PopoverPlacement) -> PopoverPlacement {
+    move |anchor, preferred| {
+        let (surface_width, surface_height) = surface_size;
+        let (viewport_width, viewport_height) = viewport;
+        match preferred {
+            PopoverPlacement::Bottom
+                if anchor.y + anchor.height + surface_height > viewport_height
+                    && anchor.y >= surface_height =>
+            {
+                PopoverPlacement::Top
+            }
+            PopoverPlacement::Top
+                if anchor.y < surface_height
+                    && anchor.y + anchor.height + surface_height <= viewport_height =>
+            {
+                PopoverPlacement::Bottom
+            }
+            PopoverPlacement::End
+                if anchor.x + anchor.width + surface_width > viewport_width
+                    && anchor.x >= surface_width =>
+            {
+                PopoverPlacement::Start
+            }
+            PopoverPlacement::Start
+                if anchor.x < surface_width
+                    && anchor.x + anchor.width + surface_width <= viewport_width =>
+            {
+                PopoverPlacement::End
+            }
+            other => other,
+        }
+    }
+}
+
+/// Shared rendering routine invoked by every framework adapter.
+fn render_html(props: &PopoverProps, state: &PopoverState) -> String {
+    let portal = popover_mount(props);
+    let outcome = state.last_outcome();
+    let anchor_meta = state.anchor_attributes();
+    let surface_meta = surface_metadata(props, state);
+
+    let root_attrs = crate::style_helpers::themed_attributes_html(
+        themed_root_style(),
+        root_attributes(props, &surface_meta, outcome, &portal),
+    );
+    let trigger_attrs = crate::style_helpers::themed_attributes_html(
+        themed_trigger_style(),
+        trigger_attributes(props, state, &surface_meta, &portal),
+    );
+    let surface_attrs = crate::style_helpers::themed_attributes_html(
+        themed_surface_style(),
+        surface_attributes(props, state, &surface_meta, outcome, &portal),
+    );
+
+    let arrow_html = if props.arrow {
+        let arrow_attrs = crate::style_helpers::themed_attributes_html(
+            themed_arrow_style(),
+            [
+                (
+                    "data-popover-arrow".to_string(),
+                    state.resolved_placement().as_str().to_string(),
+                ),
+                ("aria-hidden".to_string(), "true".to_string()),
+            ],
+        );
+        format!("<span {arrow_attrs}></span>")
+    } else {
+        String::new()
+    };
+
+    let mut anchor_attrs = portal.anchor_attributes();
+    let (placement_key, placement_value) = anchor_meta.data_placement();
+    anchor_attrs.push((placement_key.into(), placement_value.into()));
+    if let Some((key, value)) = anchor_meta.id() {
+        anchor_attrs.push((key.into(), value.into()));
+    }
+    let anchor_html = format!("<span {}></span>", attributes_to_html(&anchor_attrs));
+
+    let portal_markup = portal.wrap(format!(
+        "<div {surface_attrs}>{arrow_html}{}</div>",
+        props.surface_html
+    ));
+
+    format!(
+        "<div {root_attrs}><button {trigger_attrs}>{}</button>{anchor_html}</div>{}",
+        props.trigger_label,
+        portal_markup.into_html()
+    )
+}
+
+fn surface_id(props: &PopoverProps) -> String {
+    crate::style_helpers::automation_id("popover", props.automation_id.as_deref(), ["surface"])
+}
+
+fn popover_mount(props: &PopoverProps) -> PortalMount {
+    // Anonymous popovers draw their portal id from the hydration allocator so
+    // multiple instances on one page stay unique and SSR/CSR stay aligned.
+    PortalMount::popover(crate::style_helpers::hydration_scoped_id(
+        "popover",
+        props.automation_id.as_deref(),
+        ["portal"],
+    ))
+}
+
+fn surface_metadata<'a>(
+    props: &'a PopoverProps,
+    state: &'a PopoverState,
+) -> rustic_ui_headless::popover::PopoverSurfaceAttributes<'a> {
+    let surface_meta = state.surface_attributes();
+    if let Some(id) = props.automation_id.as_deref() {
+        surface_meta.analytics_id(id)
+    } else {
+        surface_meta
+    }
+}
+
+fn collision_outcome(outcome: CollisionOutcome) -> &'static str {
+    match outcome {
+        CollisionOutcome::Preferred => "preferred",
+        CollisionOutcome::Repositioned => "repositioned",
+    }
+}
+
+fn root_attributes(
+    props: &PopoverProps,
+    surface_meta: &rustic_ui_headless::popover::PopoverSurfaceAttributes<'_>,
+    outcome: CollisionOutcome,
+    portal: &PortalMount,
+) -> Vec<(String, String)> {
+    let mut attrs = Vec::new();
+    attrs.push((
+        "data-component".into(),
+        crate::style_helpers::automation_id("popover", None, crate::style_helpers::NO_SEGMENTS),
+    ));
+    attrs.push((
+        crate::style_helpers::automation_data_attr("popover", ["root"]),
+        crate::style_helpers::automation_id("popover", props.automation_id.as_deref(), ["root"]),
+    ));
+    let (open_key, open_value) = surface_meta.data_open();
+    attrs.push((open_key.into(), open_value.into()));
+    let (preferred_key, preferred_value) = surface_meta.data_preferred();
+    attrs.push((preferred_key.into(), preferred_value.into()));
+    let (resolved_key, resolved_value) = surface_meta.data_resolved();
+    attrs.push((resolved_key.into(), resolved_value.into()));
+    attrs.push((
+        "data-placement-outcome".into(),
+        collision_outcome(outcome).into(),
+    ));
+    attrs.push((
+        "data-portal-layer".into(),
+        portal.layer().as_str().to_string(),
+    ));
+    attrs
+}
+
+fn trigger_attributes(
+    props: &PopoverProps,
+    state: &PopoverState,
+    surface_meta: &rustic_ui_headless::popover::PopoverSurfaceAttributes<'_>,
+    portal: &PortalMount,
+) -> Vec<(String, String)> {
+    let mut attrs: Vec<(String, String)> = vec![
+        ("type".into(), "button".into()),
+        ("aria-haspopup".into(), "dialog".into()),
+        ("aria-expanded".into(), state.is_open().to_string()),
+        ("aria-controls".into(), surface_id(props)),
+    ];
+    let (open_key, open_value) = surface_meta.data_open();
+    attrs.push((open_key.into(), open_value.into()));
+    attrs.push(("data-portal-anchor".into(), portal.anchor_id()));
+    attrs.push(("data-portal-root".into(), portal.container_id()));
+    attrs.push((
+        crate::style_helpers::automation_data_attr("popover", ["trigger"]),
+        crate::style_helpers::automation_id("popover", props.automation_id.as_deref(), ["trigger"]),
+    ));
+    attrs
+}
+
+fn surface_attributes(
+    props: &PopoverProps,
+    state: &PopoverState,
+    surface_meta: &rustic_ui_headless::popover::PopoverSurfaceAttributes<'_>,
+    outcome: CollisionOutcome,
+    portal: &PortalMount,
+) -> Vec<(String, String)> {
+    let mut attrs = Vec::new();
+    attrs.push(("id".into(), surface_id(props)));
+    attrs.push(("aria-hidden".into(), (!state.is_open()).to_string()));
+    let (open_key, open_value) = surface_meta.data_open();
+    attrs.push((open_key.into(), open_value.into()));
+    let (preferred_key, preferred_value) = surface_meta.data_preferred();
+    attrs.push((preferred_key.into(), preferred_value.into()));
+    let (resolved_key, resolved_value) = surface_meta.data_resolved();
+    attrs.push((resolved_key.into(), resolved_value.into()));
+    if let Some((analytics_key, analytics_value)) = surface_meta.data_analytics_id() {
+        attrs.push((analytics_key.into(), analytics_value.into()));
+    }
+    attrs.push((
+        "data-placement-outcome".into(),
+        collision_outcome(outcome).into(),
+    ));
+    attrs.push(("data-portal-anchor".into(), portal.anchor_id()));
+    attrs.push(("data-portal-root".into(), portal.container_id()));
+    attrs.push((
+        crate::style_helpers::automation_data_attr("popover", ["surface"]),
+        crate::style_helpers::automation_id("popover", props.automation_id.as_deref(), ["surface"]),
+    ));
+    attrs
+}
+
+fn themed_root_style() -> Style {
+    css_with_theme!(
+        r#"
+        display: inline-flex;
+        position: relative;
+        gap: ${gap};
+    "#,
+        gap = format!("{}px", theme.spacing(0)),
+    )
+}
+
+fn themed_trigger_style() -> Style {
+    css_with_theme!(
+        r#"
+        display: inline-flex;
+        align-items: center;
+        padding: ${padding_y} ${padding_x};
+        border-radius: ${radius};
+        border: 1px solid ${border_color};
+        background: ${background};
+        color: ${text_color};
+        font-family: ${font_family};
+        font-size: ${font_size};
+        cursor: pointer;
+        transition: border-color 160ms ease, box-shadow 160ms ease;
+
+        &[data-open='true'] {
+            border-color: ${focus_color};
+        }
+    "#,
+        padding_y = format!("{}px", theme.spacing(1)),
+        padding_x = format!("{}px", theme.spacing(2)),
+        radius = format!("{}px", theme.joy.radius),
+        border_color = format!(
+            "color-mix(in srgb, {} 40%, transparent)",
+            theme.palette.active().text_secondary.clone()
+        ),
+        background = theme.palette.active().background_paper.clone(),
+        text_color = theme.palette.active().text_primary.clone(),
+        font_family = theme.typography.font_family.clone(),
+        font_size = format!("{:.3}rem", theme.typography.button),
+        focus_color = theme.palette.active().primary.clone(),
+    )
+}
+
+fn themed_surface_style() -> Style {
+    css_with_theme!(
+        r#"
+        position: absolute;
+        min-width: ${min_width};
+        padding: ${padding};
+        border-radius: ${radius};
+        border: 1px solid ${border_color};
+        background: ${background};
+        box-shadow: ${shadow};
+        font-family: ${font_family};
+        font-size: ${font_size};
+        color: ${text_color};
+        z-index: 12;
+
+        &[aria-hidden='true'] {
+            display: none;
+        }
+
+        &[data-resolved-placement='top'] {
+            bottom: calc(100% + ${offset});
+        }
+
+        &[data-resolved-placement='bottom'] {
+            top: calc(100% + ${offset});
+        }
+
+        &[data-resolved-placement='start'] {
+            right: calc(100% + ${offset});
+        }
+
+        &[data-resolved-placement='end'] {
+            left: calc(100% + ${offset});
+        }
+    "#,
+        min_width = format!("{}px", theme.spacing(20)),
+        padding = format!("{}px", theme.spacing(2)),
+        radius = format!("{}px", theme.joy.radius),
+        border_color = format!(
+            "color-mix(in srgb, {} 32%, transparent)",
+            theme.palette.active().text_secondary.clone()
+        ),
+        background = theme.palette.active().background_paper.clone(),
+        shadow = "0px 12px 32px rgba(15, 23, 42, 0.22)".to_string(),
+        font_family = theme.typography.font_family.clone(),
+        font_size = format!("{:.3}rem", theme.typography.body2),
+        text_color = theme.palette.active().text_primary.clone(),
+        offset = format!("{}px", theme.spacing(1)),
+    )
+}
+
+/// Rotated square whose borders match the surface so the arrow blends in;
+/// attribute selectors park it on the edge facing the anchor.
+fn themed_arrow_style() -> Style {
+    css_with_theme!(
+        r#"
+        position: absolute;
+        width: ${size};
+        height: ${size};
+        background: ${background};
+        border: 1px solid ${border_color};
+        transform: rotate(45deg);
+
+        &[data-popover-arrow='bottom'] {
+            top: calc(${size} / -2);
+            border-bottom: none;
+            border-right: none;
+        }
+
+        &[data-popover-arrow='top'] {
+            bottom: calc(${size} / -2);
+            border-top: none;
+            border-left: none;
+        }
+
+        &[data-popover-arrow='end'] {
+            left: calc(${size} / -2);
+            border-top: none;
+            border-right: none;
+        }
+
+        &[data-popover-arrow='start'] {
+            right: calc(${size} / -2);
+            border-bottom: none;
+            border-left: none;
+        }
+    "#,
+        size = format!("{}px", theme.spacing(1)),
+        background = theme.palette.active().background_paper.clone(),
+        border_color = format!(
+            "color-mix(in srgb, {} 32%, transparent)",
+            theme.palette.active().text_secondary.clone()
+        ),
+    )
+}
+
+// ---------------------------------------------------------------------------
+// Adapter implementations
+// ---------------------------------------------------------------------------
+
+/// Adapter targeting the [`yew`] framework.
+pub mod yew {
+    use super::*;
+
+    /// Render the popover into a HTML string using the shared renderer.
+    pub fn render(props: &PopoverProps, state: &PopoverState) -> String {
+        super::render_html(props, state)
+    }
+}
+
+/// Adapter targeting the [`leptos`] framework.
+pub mod leptos {
+    use super::*;
+
+    /// Render the popover into a HTML string using the shared renderer.
+    pub fn render(props: &PopoverProps, state: &PopoverState) -> String {
+        super::render_html(props, state)
+    }
+}
+
+/// Adapter targeting the [`dioxus`] framework.
+pub mod dioxus {
+    use super::*;
+
+    /// Render the popover into a HTML string using the shared renderer.
+    pub fn render(props: &PopoverProps, state: &PopoverState) -> String {
+        super::render_html(props, state)
+    }
+}
+
+/// Adapter targeting the [`sycamore`] framework.
+pub mod sycamore {
+    use super::*;
+
+    /// Render the popover into a HTML string using the shared renderer.
+    pub fn render(props: &PopoverProps, state: &PopoverState) -> String {
+        super::render_html(props, state)
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn props() -> PopoverProps {
+        PopoverProps::new("Details", "<p>Body</p>").with_automation_id("account")
+    }
+
+    #[test]
+    fn render_html_wires_anchor_portal_and_placement_metadata() {
+        let state = PopoverState::uncontrolled(false, PopoverPlacement::Bottom);
+        let html = super::render_html(&props(), &state);
+        assert!(html.contains("data-component=\"rustic-popover\""));
+        assert!(html.contains("aria-haspopup=\"dialog\""));
+        assert!(html.contains("data-portal-anchor"));
+        assert!(html.contains("data-preferred-placement=\"bottom\""));
+        assert!(html.contains("aria-hidden=\"true\""));
+    }
+
+    #[test]
+    fn opening_reveals_the_surface() {
+        let mut state = PopoverState::uncontrolled(false, PopoverPlacement::Bottom);
+        state.open(|_| {});
+        let html = super::render_html(&props(), &state);
+        assert!(html.contains("aria-expanded=\"true\""));
+        assert!(html.contains("data-open=\"true\""));
+    }
+
+    #[test]
+    fn arrow_tracks_the_resolved_placement() {
+        let mut state = PopoverState::uncontrolled(true, PopoverPlacement::Bottom);
+        state.set_anchor_metadata(
+            Some("anchor"),
+            Some(AnchorGeometry {
+                x: 10.0,
+                y: 700.0,
+                width: 80.0,
+                height: 32.0,
+            }),
+        );
+        state.resolve_with(flip_resolver((200.0, 160.0), (1024.0, 768.0)));
+        let html = super::render_html(&props().with_arrow(true), &state);
+        assert!(html.contains("data-popover-arrow=\"top\""));
+        assert!(html.contains("data-placement-outcome=\"repositioned\""));
+    }
+
+    #[test]
+    fn flip_resolver_keeps_placements_that_fit() {
+        let resolver = flip_resolver((200.0, 160.0), (1024.0, 768.0));
+        let anchor = AnchorGeometry {
+            x: 10.0,
+            y: 100.0,
+            width: 80.0,
+            height: 32.0,
+        };
+        assert_eq!(
+            resolver(anchor, PopoverPlacement::Bottom),
+            PopoverPlacement::Bottom
+        );
+    }
+}
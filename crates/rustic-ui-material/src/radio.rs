@@ -6,6 +6,7 @@
 use rustic_ui_headless::radio::{RadioGroupState, RadioOrientation};
 use rustic_ui_styled_engine::{css_with_theme, Style};
 
+use crate::macros::Size;
 use crate::selection_control;
 
 #[derive(Clone, Debug)]
@@ -13,19 +14,28 @@ pub struct RadioGroupProps {
     /// Optional custom labels for each option. When omitted the state's option
     /// names are reused.
     pub option_labels: Vec<String>,
+    /// Density preset scaling the dots and gaps; see
+    /// [`style_helpers::control_spacing_scale`](crate::style_helpers) for the
+    /// mapping to the theme density tokens.
+    pub size: Size,
 }
 
 impl RadioGroupProps {
     pub fn new(option_labels: impl Into<Vec<String>>) -> Self {
         Self {
             option_labels: option_labels.into(),
+            size: Size::Medium,
         }
     }
 
     pub fn from_state(state: &RadioGroupState) -> Self {
-        Self {
-            option_labels: state.options().to_vec(),
-        }
+        Self::new(state.options().to_vec())
+    }
+
+    /// Override the density preset, compacting or enlarging the group.
+    pub fn with_size(mut self, size: Size) -> Self {
+        self.size = size;
+        self
     }
 }
 
@@ -40,6 +50,7 @@ fn render_html(props: &RadioGroupProps, state: &RadioGroupState) -> String {
         RadioOrientation::Vertical => "vertical",
     };
     group_attrs.push(("data-orientation".into(), orientation_value.into()));
+    group_attrs.push(("data-size".into(), props.size.as_str().into()));
 
     let labels = if props.option_labels.is_empty() {
         state.options().to_vec()
@@ -60,16 +71,16 @@ fn render_html(props: &RadioGroupProps, state: &RadioGroupState) -> String {
     }
 
     selection_control::render_radio_group(
-        themed_radio_group_style(),
+        themed_radio_group_style(&props.size),
         group_attrs,
-        || themed_radio_option_style(),
+        || themed_radio_option_style(&props.size),
         &options,
     )
 }
 
 /// Generates layout styling for the radio group container, including
 /// orientation-aware flex direction toggles.
-fn themed_radio_group_style() -> Style {
+fn themed_radio_group_style(size: &Size) -> Style {
     css_with_theme!(
         r#"
         display: inline-flex;
@@ -84,13 +95,14 @@ fn themed_radio_group_style() -> Style {
             opacity: 0.38;
         }
     "#,
-        gap = format!("{}px", theme.spacing(1)),
+        gap = crate::style_helpers::control_spacing_px(&theme, size, 1),
     )
 }
 
 /// Visual styling for individual radio options including the faux dot used to
-/// communicate selection.
-fn themed_radio_option_style() -> Style {
+/// communicate selection. The `size` preset flows through the shared density
+/// helpers so the dot and label track the group's compactness.
+fn themed_radio_option_style(size: &Size) -> Style {
     css_with_theme!(
         r#"
         display: inline-flex;
@@ -120,26 +132,26 @@ fn themed_radio_option_style() -> Style {
         }
 
         &[data-focus-visible='true'] {
-            outline: ${focus_outline_width} solid ${focus_outline_color};
-            outline-offset: 2px;
+            outline: ${focus_outline};
+            outline-offset: ${focus_outline_offset};
         }
 
         &[aria-disabled='true'] {
             cursor: not-allowed;
         }
     "#,
-        gap = format!("{}px", theme.spacing(1)),
+        gap = crate::style_helpers::control_spacing_px(&theme, size, 1),
         font_family = theme.typography.font_family.clone(),
-        font_size = format!("{:.3}rem", theme.typography.body1),
-        text_color = theme.palette.text_primary.clone(),
+        font_size = crate::style_helpers::control_font_size(size),
+        text_color = theme.palette.active().text_primary.clone(),
         padding_y = format!("{}px", theme.spacing(0)),
         padding_x = format!("{}px", theme.spacing(0)),
         radius = format!("{}px", theme.joy.radius),
-        dot_size = format!("{}px", theme.spacing(1)),
-        border_color = theme.palette.text_secondary.clone(),
-        checked_background = theme.palette.primary.clone(),
-        focus_outline_width = format!("{}px", theme.joy.focus.thickness),
-        focus_outline_color = theme.palette.primary.clone()
+        dot_size = crate::style_helpers::control_spacing_px(&theme, size, 1),
+        border_color = theme.palette.active().text_secondary.clone(),
+        checked_background = theme.palette.active().primary.clone(),
+        focus_outline = crate::style_helpers::focus_outline(&theme),
+        focus_outline_offset = crate::style_helpers::focus_outline_offset(&theme)
     )
 }
 
@@ -202,6 +214,20 @@ mod tests {
         assert!(html.contains(">A<"));
         assert!(html.contains(">B<"));
         assert!(html.contains("radiogroup"));
+        assert!(html.contains("data-size=\"medium\""));
+    }
+
+    #[test]
+    fn render_html_reflects_size_preset() {
+        let props = RadioGroupProps::new(vec!["A".to_string()]).with_size(Size::Small);
+        let state = RadioGroupState::uncontrolled(
+            vec!["A".into()],
+            false,
+            RadioOrientation::Vertical,
+            None,
+        );
+        let html = render_html(&props, &state);
+        assert!(html.contains("data-size=\"small\""));
     }
 
     #[test]
@@ -0,0 +1,222 @@
+//! Review aggregate blueprint driven by the headless [`RatingSummary`].
+//!
+//! Feedback screens lead with the average, the review count and a stacked
+//! distribution bar per star level.  All arithmetic lives in
+//! [`RatingSummary`](rustic_ui_headless::rating_summary::RatingSummary); this
+//! component only lays the numbers out, exposing each bar as a labelled
+//! `role="progressbar"` so screen readers announce "5 stars, 60 percent"
+//! without parsing visual widths.
+
+use rustic_ui_headless::rating_summary::RatingSummary;
+use rustic_ui_styled_engine::{css_with_theme, Style};
+
+/// Shared properties accepted by all adapter implementations.
+#[derive(Clone, Debug, PartialEq, Default)]
+pub struct RatingSummaryProps {
+    /// Optional heading rendered above the aggregate, e.g. "Customer reviews".
+    pub label: Option<String>,
+    /// Optional automation identifier for analytics and end-to-end tests.
+    pub automation_id: Option<String>,
+}
+
+impl RatingSummaryProps {
+    /// Convenience constructor used by examples and tests.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a heading above the aggregate figures.
+    pub fn with_label(mut self, label: impl Into<String>) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+
+    /// Sets the automation identifier stamped on the rendered hooks.
+    pub fn with_automation_id(mut self, id: impl Into<String>) -> Self {
+        self.automation_id = Some(id.into());
+        self
+    }
+}
+
+/// Shared rendering routine used by all adapters.
+fn render_html(props: &RatingSummaryProps, summary: &RatingSummary) -> String {
+    let attrs = crate::style_helpers::themed_attributes_html(
+        themed_rating_summary_style(),
+        vec![
+            (
+                "data-component".to_string(),
+                crate::style_helpers::automation_id(
+                    "rating-summary",
+                    None,
+                    crate::style_helpers::NO_SEGMENTS,
+                ),
+            ),
+            (
+                crate::style_helpers::automation_data_attr("rating-summary", ["root"]),
+                crate::style_helpers::automation_id(
+                    "rating-summary",
+                    props.automation_id.as_deref(),
+                    ["root"],
+                ),
+            ),
+        ],
+    );
+    let label = props
+        .label
+        .as_ref()
+        .map(|label| format!("<h2>{}</h2>", crate::render::escape_text(label)))
+        .unwrap_or_default();
+    let average = summary.format_average();
+    let total = summary.total();
+    let bars: String = summary
+        .bars()
+        .iter()
+        .map(|bar| {
+            format!(
+                "<li data-stars=\"{stars}\"><span>{stars}\u{2605}</span>\
+                 <div role=\"progressbar\" aria-label=\"{stars} stars\" \
+                 aria-valuemin=\"0\" aria-valuemax=\"100\" aria-valuenow=\"{percent}\">\
+                 <span style=\"width:{percent}%;\"></span></div>\
+                 <span>{percent}%</span></li>",
+                stars = bar.stars,
+                percent = bar.percent,
+            )
+        })
+        .collect();
+    format!(
+        "<section {attrs}>{label}\
+         <p data-rating-average=\"{average}\"><strong>{average}</strong> out of 5</p>\
+         <p data-rating-total=\"{total}\">{total} reviews</p>\
+         <ul role=\"list\">{bars}</ul></section>"
+    )
+}
+
+/// Stacked bar styling pulled from the active theme tokens.
+fn themed_rating_summary_style() -> Style {
+    css_with_theme!(
+        r#"
+        display: flex;
+        flex-direction: column;
+        gap: ${gap};
+        font-family: ${font_family};
+        color: ${text};
+
+        & h2 {
+            margin: 0;
+            font-size: 1rem;
+        }
+
+        & p {
+            margin: 0;
+        }
+
+        & ul {
+            display: flex;
+            flex-direction: column;
+            gap: ${bar_gap};
+            margin: 0;
+            padding: 0;
+            list-style: none;
+        }
+
+        & li {
+            display: flex;
+            align-items: center;
+            gap: ${bar_gap};
+            font-size: 0.75rem;
+        }
+
+        & [role='progressbar'] {
+            flex: 1;
+            height: ${bar_height};
+            border-radius: 9999px;
+            background: ${track};
+            overflow: hidden;
+        }
+
+        & [role='progressbar'] > span {
+            display: block;
+            height: 100%;
+            background: ${fill};
+        }
+    "#,
+        gap = format!("{}px", theme.spacing(1)),
+        font_family = theme.typography.font_family.clone(),
+        text = theme.palette.active().text_primary.clone(),
+        bar_gap = format!("{}px", theme.spacing(1)),
+        bar_height = format!("{}px", theme.spacing(1)),
+        track = theme.palette.active().neutral.clone(),
+        fill = theme.palette.active().warning.clone(),
+    )
+}
+
+// ---------------------------------------------------------------------------
+// Adapter implementations
+// ---------------------------------------------------------------------------
+
+pub mod yew {
+    use super::*;
+
+    /// Render the rating summary into a plain HTML string for SSR/hydration.
+    pub fn render(props: &RatingSummaryProps, summary: &RatingSummary) -> String {
+        super::render_html(props, summary)
+    }
+}
+
+pub mod leptos {
+    use super::*;
+
+    /// Render the rating summary into a plain HTML string for SSR/hydration.
+    pub fn render(props: &RatingSummaryProps, summary: &RatingSummary) -> String {
+        super::render_html(props, summary)
+    }
+}
+
+pub mod dioxus {
+    use super::*;
+
+    /// Render the rating summary into a plain HTML string for SSR/hydration.
+    pub fn render(props: &RatingSummaryProps, summary: &RatingSummary) -> String {
+        super::render_html(props, summary)
+    }
+}
+
+pub mod sycamore {
+    use super::*;
+
+    /// Render the rating summary into a plain HTML string for SSR/hydration.
+    pub fn render(props: &RatingSummaryProps, summary: &RatingSummary) -> String {
+        super::render_html(props, summary)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn summary_renders_average_total_and_bars() {
+        let summary = RatingSummary::from_counts(&[5, 0, 15, 20, 60]);
+        let props = RatingSummaryProps::new()
+            .with_label("Customer reviews")
+            .with_automation_id("product-7");
+        let html = render_html(&props, &summary);
+        assert!(html.contains("<h2>Customer reviews</h2>"));
+        assert!(html.contains("<strong>4.3</strong> out of 5"));
+        assert!(html.contains("data-rating-total=\"100\">100 reviews"));
+        assert!(html.contains("data-stars=\"5\""));
+        assert!(html.contains("aria-valuenow=\"60\""));
+        assert!(html.contains("width:60%;"));
+        assert!(html
+            .contains("data-rustic-rating-summary-root=\"rustic-rating-summary-product-7-root\""));
+    }
+
+    #[test]
+    fn empty_summaries_render_without_division_noise() {
+        let summary = RatingSummary::from_counts(&[0, 0, 0, 0, 0]);
+        let html = render_html(&RatingSummaryProps::new(), &summary);
+        assert!(html.contains("<strong>0.0</strong> out of 5"));
+        assert!(html.contains("0 reviews"));
+        assert!(!html.contains("<h2>"));
+    }
+}
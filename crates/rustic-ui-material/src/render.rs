@@ -0,0 +1,340 @@
+//! Framework-neutral render tree produced by Material renderers.
+//!
+//! Historically every adapter serialized markup through `format!` which made
+//! attribute escaping a per-call-site concern and left client frameworks no
+//! room to attach event listeners. [`RenderNode`] captures the element
+//! structure — tag, attributes and children — once, and per-framework emitters
+//! lower the tree into the representation each runtime expects:
+//!
+//! * [`RenderNode::into_html`] serializes to an escaped HTML string for SSR
+//!   pipelines and golden tests.
+//! * [`yew::render`](self::yew::render) builds real virtual-DOM nodes so Yew
+//!   components can patch listeners onto the emitted tags.
+//! * [`leptos::render`](self::leptos::render) assembles Leptos views through
+//!   the dynamic element API.
+//! * [`dioxus::render`](self::dioxus::render) and
+//!   [`sycamore::render`](self::sycamore::render) currently lower to the
+//!   escaped HTML string consumed via `dangerous_inner_html`, keeping the
+//!   contract identical while native emitters are built out.
+//!
+//! Renderers that still expose `String` APIs can migrate incrementally: build
+//! the tree, then call `into_html()` where the old concatenation lived.
+
+/// One node in the framework-neutral render tree.
+#[derive(Clone, Debug, PartialEq)]
+pub enum RenderNode {
+    /// An element with a tag, attributes and ordered children.
+    Element(ElementNode),
+    /// Text content; escaped during HTML serialization.
+    Text(String),
+    /// Pre-rendered HTML injected verbatim. Reserved for trusted fragments
+    /// (icon sprites, renderer output from sibling crates) — never user input.
+    Raw(String),
+}
+
+/// Tag, attribute list and children backing [`RenderNode::Element`].
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ElementNode {
+    /// Lowercase tag name (`"div"`, `"button"`, …).
+    pub tag: String,
+    /// Attribute pairs emitted in insertion order so SSR output stays
+    /// deterministic across runs.
+    pub attributes: Vec<(String, String)>,
+    /// Child nodes rendered in order.
+    pub children: Vec<RenderNode>,
+}
+
+impl ElementNode {
+    /// Start an element with the given tag.
+    pub fn new(tag: impl Into<String>) -> Self {
+        Self {
+            tag: tag.into(),
+            attributes: Vec::new(),
+            children: Vec::new(),
+        }
+    }
+
+    /// Append one attribute pair.
+    pub fn attr(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.attributes.push((key.into(), value.into()));
+        self
+    }
+
+    /// Append every attribute pair from the iterator, making it ergonomic to
+    /// feed builders from `rustic_ui_headless` or
+    /// [`style_helpers::themed_attributes`](crate::style_helpers) directly.
+    pub fn attrs<I, K, V>(mut self, iter: I) -> Self
+    where
+        I: IntoIterator<Item = (K, V)>,
+        K: Into<String>,
+        V: Into<String>,
+    {
+        self.attributes
+            .extend(iter.into_iter().map(|(k, v)| (k.into(), v.into())));
+        self
+    }
+
+    /// Append a child node.
+    pub fn child(mut self, child: RenderNode) -> Self {
+        self.children.push(child);
+        self
+    }
+
+    /// Append an escaped text child.
+    pub fn text(self, text: impl Into<String>) -> Self {
+        self.child(RenderNode::Text(text.into()))
+    }
+
+    /// Finish the builder, producing a [`RenderNode`].
+    #[must_use]
+    pub fn build(self) -> RenderNode {
+        RenderNode::Element(self)
+    }
+}
+
+impl RenderNode {
+    /// Convenience constructor mirroring [`ElementNode::new`].
+    pub fn element(tag: impl Into<String>) -> ElementNode {
+        ElementNode::new(tag)
+    }
+
+    /// Serialize the tree into an escaped HTML string.
+    ///
+    /// Attribute values and text content are escaped centrally, eliminating
+    /// the class of bugs where an individual renderer forgot to handle quotes
+    /// or angle brackets inside user supplied labels.
+    #[must_use]
+    pub fn into_html(self) -> String {
+        let mut out = String::new();
+        self.write_html(&mut out);
+        out
+    }
+
+    fn write_html(&self, out: &mut String) {
+        match self {
+            Self::Text(text) => out.push_str(&escape_text(text)),
+            Self::Raw(html) => out.push_str(html),
+            Self::Element(el) => {
+                out.push('<');
+                out.push_str(&el.tag);
+                for (key, value) in &el.attributes {
+                    out.push(' ');
+                    out.push_str(key);
+                    out.push_str("=\"");
+                    out.push_str(&escape_attribute(value));
+                    out.push('"');
+                }
+                out.push('>');
+                for child in &el.children {
+                    child.write_html(out);
+                }
+                out.push_str("</");
+                out.push_str(&el.tag);
+                out.push('>');
+            }
+        }
+    }
+}
+
+/// Escape text content for safe interpolation between tags.
+pub(crate) fn escape_text(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
+/// Escape an attribute value for safe interpolation inside double quotes.
+fn escape_attribute(value: &str) -> String {
+    escape_text(value).replace('"', "&quot;")
+}
+
+/// Emitter lowering the tree into Yew virtual-DOM nodes.
+#[cfg(feature = "yew")]
+pub mod yew {
+    use super::RenderNode;
+    use yew::virtual_dom::{ApplyAttributeAs, AttrValue, VNode, VTag, VText};
+    use yew::Html;
+
+    /// Convert the tree into [`Html`] built from real virtual-DOM tags so
+    /// callers can subsequently attach listeners or keys to the nodes.
+    #[must_use]
+    pub fn render(node: RenderNode) -> Html {
+        match node {
+            RenderNode::Text(text) => VNode::from(VText::new(text)),
+            RenderNode::Raw(html) => Html::from_html_unchecked(AttrValue::from(html)),
+            RenderNode::Element(el) => {
+                let mut tag = VTag::new(el.tag);
+                // `VTag::add_attribute` requires `'static` keys, so dynamic
+                // attribute names (automation hooks, `aria-*` metadata) go
+                // through the index map directly.
+                let attributes = tag.attributes.get_mut_index_map();
+                for (key, value) in el.attributes {
+                    attributes.insert(
+                        AttrValue::from(key),
+                        (AttrValue::from(value), ApplyAttributeAs::Attribute),
+                    );
+                }
+                for child in el.children {
+                    tag.add_child(render(child));
+                }
+                VNode::VTag(Box::new(tag))
+            }
+        }
+    }
+
+    /// Wrap pre-rendered markup in a container that owns the provided click
+    /// listener.
+    ///
+    /// The SSR string stays the canonical markup source while the wrapper
+    /// gives client code a real virtual-DOM node to hang the listener on;
+    /// `display:contents` keeps the extra element out of layout. Interactive
+    /// adapters use this to delegate clicks bubbling from elements inside the
+    /// injected fragment (dismiss buttons, options, …) without re-querying
+    /// the DOM.
+    #[must_use]
+    pub fn delegated_click(
+        markup: String,
+        on_click: ::yew::Callback<::yew::events::MouseEvent>,
+    ) -> Html {
+        use std::rc::Rc;
+        use yew::html::onclick;
+        use yew::virtual_dom::Listener;
+
+        let mut tag = VTag::new("span");
+        tag.attributes.get_mut_index_map().insert(
+            AttrValue::Static("style"),
+            (
+                AttrValue::Static("display:contents;"),
+                ApplyAttributeAs::Attribute,
+            ),
+        );
+        let listener: Rc<dyn Listener> = Rc::new(onclick::Wrapper::new(on_click));
+        tag.set_listeners(Box::new([Some(listener)]));
+        tag.add_child(Html::from_html_unchecked(AttrValue::from(markup)));
+        VNode::VTag(Box::new(tag))
+    }
+}
+
+/// Emitter lowering the tree into Leptos views.
+#[cfg(feature = "leptos")]
+pub mod leptos {
+    use super::RenderNode;
+    use leptos::html::{custom, Custom};
+    use leptos::{IntoView, View};
+
+    /// Convert the tree into a [`View`] using Leptos' dynamic element API.
+    #[must_use]
+    pub fn render(node: RenderNode) -> View {
+        match node {
+            RenderNode::Text(text) => text.into_view(),
+            RenderNode::Raw(html) => leptos::html::div().inner_html(html).into_view(),
+            RenderNode::Element(el) => {
+                let mut element = custom(Custom::new(el.tag));
+                for (key, value) in el.attributes {
+                    element = element.attr(key, value);
+                }
+                for child in el.children {
+                    element = element.child(render(child));
+                }
+                element.into_view()
+            }
+        }
+    }
+
+    /// Wrap pre-rendered markup in a container that owns the provided click
+    /// listener, mirroring [`yew::delegated_click`](super::yew::delegated_click).
+    #[must_use]
+    pub fn delegated_click(
+        markup: String,
+        on_click: impl Fn(::leptos::ev::MouseEvent) + 'static,
+    ) -> View {
+        leptos::html::span()
+            .attr("style", "display:contents;")
+            .on(::leptos::ev::click, on_click)
+            .inner_html(markup)
+            .into_view()
+    }
+}
+
+/// Emitter for Dioxus consumers.
+#[cfg(feature = "dioxus")]
+pub mod dioxus {
+    use super::RenderNode;
+
+    /// Serialize the tree for injection through `dangerous_inner_html`.
+    ///
+    /// A native `VirtualDom` emitter is planned; in the meantime the escaped
+    /// string keeps Dioxus output byte-identical with the other frameworks.
+    #[must_use]
+    pub fn render(node: RenderNode) -> String {
+        node.into_html()
+    }
+}
+
+/// Emitter for Sycamore consumers.
+#[cfg(feature = "sycamore")]
+pub mod sycamore {
+    use super::RenderNode;
+
+    /// Serialize the tree for injection through `dangerously_set_inner_html`.
+    ///
+    /// A native view emitter is planned; in the meantime the escaped string
+    /// keeps Sycamore output byte-identical with the other frameworks.
+    #[must_use]
+    pub fn render(node: RenderNode) -> String {
+        node.into_html()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serializes_nested_elements_in_order() {
+        let node = RenderNode::element("div")
+            .attr("class", "card")
+            .child(RenderNode::element("span").text("Hello").build())
+            .build();
+        assert_eq!(
+            node.into_html(),
+            "<div class=\"card\"><span>Hello</span></div>"
+        );
+    }
+
+    #[test]
+    fn escapes_text_content() {
+        let node = RenderNode::element("span").text("a < b & c > d").build();
+        assert_eq!(node.into_html(), "<span>a &lt; b &amp; c &gt; d</span>");
+    }
+
+    #[test]
+    fn escapes_attribute_values() {
+        let node = RenderNode::element("button")
+            .attr("aria-label", "Say \"hi\" & wave")
+            .build();
+        assert_eq!(
+            node.into_html(),
+            "<button aria-label=\"Say &quot;hi&quot; &amp; wave\"></button>"
+        );
+    }
+
+    #[test]
+    fn raw_fragments_pass_through_verbatim() {
+        let node = RenderNode::element("div")
+            .child(RenderNode::Raw("<svg data-icon=\"check\"></svg>".into()))
+            .build();
+        assert!(node.into_html().contains("<svg data-icon=\"check\"></svg>"));
+    }
+
+    #[test]
+    fn attrs_extends_from_attribute_builders() {
+        let pairs = vec![("role", "status"), ("data-open", "true")];
+        let node = RenderNode::element("div").attrs(pairs).build();
+        assert_eq!(
+            node.into_html(),
+            "<div role=\"status\" data-open=\"true\"></div>"
+        );
+    }
+}
@@ -0,0 +1,258 @@
+//! Opt-in Material ripple overlay rendered from the headless press feedback
+//! machine.
+//!
+//! The lifecycle — press origin, covering radius and expand/fade phases — is
+//! owned by [`PressFeedbackState`](rustic_ui_headless::press_feedback::PressFeedbackState)
+//! so it stays deterministic and framework agnostic.  This module only
+//! translates the active ripples into markup: an absolutely positioned overlay
+//! span containing one span per ripple, each carrying its geometry through the
+//! `--rustic-ripple-x`/`--rustic-ripple-y`/`--rustic-ripple-size` CSS
+//! variables and its phase through `data-ripple-phase` so the scoped
+//! animations pick up where the machine left off.
+//!
+//! The feature is opt-in: components render exactly as before until an adapter
+//! nests [`overlay_html`] (or uses [`attach`] on pre-rendered markup) inside an
+//! interactive surface such as a button, list item or tab.  The host element
+//! only needs `position: relative` for the overlay to clip correctly.
+
+use rustic_ui_headless::press_feedback::{PressFeedbackConfig, PressFeedbackState, Ripple};
+use rustic_ui_headless::timing::Clock;
+use rustic_ui_styled_engine::{css_with_theme, Style};
+
+/// Render the ripple overlay for the supplied machine.
+///
+/// The overlay is emitted even when no ripple is active so hydration does not
+/// reshuffle the DOM when the first press lands.  Adapters re-render after
+/// calling [`press`](rustic_ui_headless::press_feedback::PressFeedbackState::press),
+/// [`release`](rustic_ui_headless::press_feedback::PressFeedbackState::release)
+/// and [`poll`](rustic_ui_headless::press_feedback::PressFeedbackState::poll)
+/// so the spans below always mirror the machine.
+#[must_use]
+pub fn overlay_html<C: Clock>(state: &PressFeedbackState<C>) -> String {
+    let mut children = String::new();
+    for ripple in state.ripples() {
+        children.push_str(&ripple_html(state.config(), ripple));
+    }
+    let attrs = crate::style_helpers::themed_attributes_html(
+        themed_overlay_style(),
+        [("data-component".to_string(), "ripple-overlay".to_string())],
+    );
+    format!("<span {attrs}>{children}</span>")
+}
+
+/// Insert the overlay just before the closing tag of `markup`'s root element.
+///
+/// This lets adapters retrofit press feedback onto renderers that do not take
+/// children, e.g. [`button::render_html`](crate::button) output:
+///
+/// ```ignore
+/// let html = ripple::attach(button::yew::render(&props, &state), &feedback);
+/// ```
+///
+/// Markup without a closing tag simply gets the overlay appended.
+#[must_use]
+pub fn attach<C: Clock>(markup: String, state: &PressFeedbackState<C>) -> String {
+    let overlay = overlay_html(state);
+    match markup.rfind("</") {
+        Some(index) => {
+            let mut out = String::with_capacity(markup.len() + overlay.len());
+            out.push_str(&markup[..index]);
+            out.push_str(&overlay);
+            out.push_str(&markup[index..]);
+            out
+        }
+        None => {
+            let mut out = markup;
+            out.push_str(&overlay);
+            out
+        }
+    }
+}
+
+/// Render a single ripple span with its geometry exposed as CSS variables.
+fn ripple_html(config: &PressFeedbackConfig, ripple: &Ripple) -> String {
+    let (origin_x, origin_y) = ripple.origin();
+    let css_variables = format!(
+        "--rustic-ripple-x: {origin_x}px; --rustic-ripple-y: {origin_y}px; --rustic-ripple-size: {diameter}px;",
+        diameter = ripple.radius() * 2.0,
+    );
+    let attrs = crate::style_helpers::themed_attributes_html(
+        themed_ripple_style(config),
+        [
+            ("style".to_string(), css_variables),
+            (
+                "data-ripple-phase".to_string(),
+                ripple.phase().as_str().to_string(),
+            ),
+        ],
+    );
+    format!("<span {attrs}></span>")
+}
+
+/// Overlay container clipping the ripples to the host's rounded corners.
+fn themed_overlay_style() -> Style {
+    css_with_theme!(
+        r#"
+        position: absolute;
+        inset: 0;
+        overflow: hidden;
+        border-radius: inherit;
+        pointer-events: none;
+    "#,
+    )
+}
+
+/// Per-ripple styling driving the expand and fade animations.
+///
+/// The durations interpolate from the machine configuration so the CSS always
+/// matches the moment [`PressFeedbackState::poll`] removes the span, and the
+/// ripple tints with `currentColor` so it inherits whatever text color the
+/// host surface resolved from the palette.  Under reduced motion
+/// (`theme.motion.reduce`) both durations collapse to zero so the ripple
+/// appears and disappears instantly without animating.
+fn themed_ripple_style(config: &PressFeedbackConfig) -> Style {
+    css_with_theme!(
+        r#"
+        position: absolute;
+        left: var(--rustic-ripple-x);
+        top: var(--rustic-ripple-y);
+        width: var(--rustic-ripple-size);
+        height: var(--rustic-ripple-size);
+        border-radius: 50%;
+        background: currentColor;
+        opacity: 0.24;
+        transform: translate(-50%, -50%) scale(1);
+        animation: rustic-ripple-expand ${expand_duration} ease-out;
+
+        &[data-ripple-phase='fading'] {
+            animation: rustic-ripple-fade ${fade_duration} ease-in forwards;
+        }
+
+        @keyframes rustic-ripple-expand {
+            from {
+                transform: translate(-50%, -50%) scale(0);
+            }
+            to {
+                transform: translate(-50%, -50%) scale(1);
+            }
+        }
+
+        @keyframes rustic-ripple-fade {
+            from {
+                opacity: 0.24;
+            }
+            to {
+                opacity: 0;
+            }
+        }
+    "#,
+        expand_duration = format!(
+            "{}ms",
+            theme
+                .motion
+                .effective_duration_ms(config.expand_duration.as_millis() as u16)
+        ),
+        fade_duration = format!(
+            "{}ms",
+            theme
+                .motion
+                .effective_duration_ms(config.fade_duration.as_millis() as u16)
+        )
+    )
+}
+
+// ---------------------------------------------------------------------------
+// Adapter modules
+// ---------------------------------------------------------------------------
+//
+// The overlay is already framework neutral HTML, so the adapters simply
+// forward to the shared renderer.  Event wiring (pointerdown → `press`,
+// pointerup/pointerleave → `release`, animation frames → `poll`) stays in
+// application code because each framework schedules frames differently.
+
+pub mod yew {
+    use super::*;
+
+    pub fn overlay<C: Clock>(state: &PressFeedbackState<C>) -> String {
+        super::overlay_html(state)
+    }
+}
+
+pub mod leptos {
+    use super::*;
+
+    pub fn overlay<C: Clock>(state: &PressFeedbackState<C>) -> String {
+        super::overlay_html(state)
+    }
+}
+
+pub mod dioxus {
+    use super::*;
+
+    pub fn overlay<C: Clock>(state: &PressFeedbackState<C>) -> String {
+        super::overlay_html(state)
+    }
+}
+
+pub mod sycamore {
+    use super::*;
+
+    pub fn overlay<C: Clock>(state: &PressFeedbackState<C>) -> String {
+        super::overlay_html(state)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rustic_ui_headless::timing::MockClock;
+    use std::time::Duration;
+
+    fn feedback() -> (MockClock, PressFeedbackState<MockClock>) {
+        let clock = MockClock::new();
+        let state = PressFeedbackState::with_clock(
+            clock.clone(),
+            PressFeedbackConfig::enterprise_defaults(),
+        );
+        (clock, state)
+    }
+
+    #[test]
+    fn idle_machine_renders_an_empty_overlay() {
+        let (_clock, state) = feedback();
+        let html = overlay_html(&state);
+        assert!(html.contains("data-component=\"ripple-overlay\""));
+        assert!(!html.contains("data-ripple-phase"));
+    }
+
+    #[test]
+    fn ripple_spans_expose_geometry_through_css_variables() {
+        let (_clock, mut state) = feedback();
+        state.press(10.0, 10.0, 100.0, 40.0);
+        let html = overlay_html(&state);
+        assert!(html.contains("--rustic-ripple-x: 10px"));
+        assert!(html.contains("--rustic-ripple-y: 10px"));
+        let diameter = (90.0f64 * 90.0 + 30.0 * 30.0).sqrt() * 2.0;
+        assert!(html.contains(&format!("--rustic-ripple-size: {diameter}px")));
+        assert!(html.contains("data-ripple-phase=\"expanding\""));
+    }
+
+    #[test]
+    fn released_ripples_render_the_fading_phase() {
+        let (clock, mut state) = feedback();
+        let id = state.press(5.0, 5.0, 10.0, 10.0);
+        state.release(id);
+        clock.advance(Duration::from_millis(450));
+        assert!(state.poll().is_empty());
+        let html = overlay_html(&state);
+        assert!(html.contains("data-ripple-phase=\"fading\""));
+    }
+
+    #[test]
+    fn attach_nests_the_overlay_inside_the_root_element() {
+        let (_clock, state) = feedback();
+        let html = attach("<button class=\"a\">Save</button>".to_string(), &state);
+        assert!(html.contains("ripple-overlay"));
+        assert!(html.ends_with("</span></button>"));
+    }
+}
@@ -0,0 +1,377 @@
+//! Router integration layer shared by the navigation components.
+//!
+//! Navigation components historically rendered plain `<a href>` strings,
+//! which forces a full document reload under every framework router.  This
+//! module centralizes the attribute contract that turns those anchors into
+//! router-aware navigations: every link carries `data-rustic-router`
+//! metadata describing the target route, and the per-framework bootstrap
+//! (yew-router, leptos_router, dioxus-router — wired in the feature gated
+//! adapter modules below) intercepts clicks on matching anchors and pushes
+//! the route through the client side history API instead.
+//!
+//! Centralizing the contract means Tabs, Menu items, Breadcrumbs, List items
+//! and the [`Link`](crate::link) component all emit identical markup, and a
+//! single interception hook per framework upgrades every one of them at
+//! once.
+
+use rustic_ui_utils::attributes_to_html;
+
+/// Declarative description of a client side navigation target.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RouterTarget {
+    /// Application route path (e.g. `/projects/42`).
+    pub path: String,
+    /// Replace the current history entry instead of pushing a new one.
+    pub replace: bool,
+    /// Escape hatch for genuinely external URLs which must bypass the
+    /// client router and perform a normal browser navigation.
+    pub external: bool,
+}
+
+impl RouterTarget {
+    /// Internal route handled by the client side router.
+    pub fn route(path: impl Into<String>) -> Self {
+        Self {
+            path: path.into(),
+            replace: false,
+            external: false,
+        }
+    }
+
+    /// External URL that should bypass the router entirely.
+    pub fn external(url: impl Into<String>) -> Self {
+        Self {
+            path: url.into(),
+            replace: false,
+            external: true,
+        }
+    }
+
+    /// Switch the navigation to replace the current history entry.
+    pub fn with_replace(mut self) -> Self {
+        self.replace = true;
+        self
+    }
+}
+
+/// Attribute set shared by every router-aware anchor.
+///
+/// The `href` keeps anchors functional without JavaScript (and for SSR
+/// crawlers); the `data-rustic-router` attributes carry the interception
+/// contract for the client bootstrap.
+#[must_use]
+pub fn link_attributes(target: &RouterTarget) -> Vec<(String, String)> {
+    let mut attrs = vec![("href".to_string(), target.path.clone())];
+    if target.external {
+        // External links opt out explicitly so the interception hook can
+        // skip them without URL heuristics.
+        attrs.push(("data-rustic-router".into(), "external".into()));
+        attrs.push(("rel".into(), "noopener".into()));
+    } else {
+        attrs.push(("data-rustic-router".into(), "route".into()));
+        attrs.push((
+            "data-rustic-router-replace".into(),
+            target.replace.to_string(),
+        ));
+    }
+    attrs
+}
+
+/// Wrap already-rendered item markup (a tab label, menu item body,
+/// breadcrumb segment or list item) in a router-aware anchor.
+///
+/// The navigation components delegate here so their inner markup stays
+/// untouched while gaining client side navigation semantics.
+#[must_use]
+pub fn wrap_in_link(target: &RouterTarget, inner_html: &str) -> String {
+    format!(
+        "<a {}>{}</a>",
+        attributes_to_html(&link_attributes(target)),
+        inner_html
+    )
+}
+
+/// CSS selector the client bootstrap uses to find router-aware anchors.
+pub const ROUTER_LINK_SELECTOR: &str = "a[data-rustic-router=\"route\"]";
+
+/// Render an accessible breadcrumb trail where every ancestor segment is a
+/// router-aware link and the current page renders as plain text with
+/// `aria-current="page"`.
+///
+/// Markup follows the WAI-ARIA breadcrumb pattern (`nav` + ordered list) so
+/// assistive technologies announce the trail correctly across frameworks.
+#[must_use]
+pub fn render_breadcrumbs(segments: &[(String, RouterTarget)], current: &str) -> String {
+    let mut items = String::new();
+    for (label, target) in segments {
+        items.push_str(&format!("<li>{}</li>", wrap_in_link(target, label)));
+    }
+    items.push_str(&format!("<li aria-current=\"page\">{current}</li>"));
+    format!("<nav aria-label=\"Breadcrumb\"><ol>{items}</ol></nav>")
+}
+
+/// Declarative metadata for one route in the application's nested route
+/// tree, mirroring the route definitions frameworks already maintain.
+///
+/// App shells register this tree once and derive breadcrumb trails from it
+/// with [`breadcrumbs_for`], instead of hand-maintaining breadcrumb state in
+/// every page component.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RouteDefinition {
+    /// Path segment owned by this route (e.g. `projects`). Segments starting
+    /// with `:` are dynamic and match any concrete value.
+    pub segment: String,
+    /// Human readable label shown in the breadcrumb trail. Dynamic segments
+    /// fall back to the concrete path value when the label is empty.
+    pub label: String,
+    /// Optional icon name rendered ahead of the label.
+    pub icon: Option<String>,
+    /// Nested child routes.
+    pub children: Vec<RouteDefinition>,
+}
+
+impl RouteDefinition {
+    /// Creates a route for a path segment with its breadcrumb label.
+    pub fn new(segment: impl Into<String>, label: impl Into<String>) -> Self {
+        Self {
+            segment: segment.into(),
+            label: label.into(),
+            icon: None,
+            children: Vec::new(),
+        }
+    }
+
+    /// Attaches an icon name to the breadcrumb entry.
+    pub fn with_icon(mut self, icon: impl Into<String>) -> Self {
+        self.icon = Some(icon.into());
+        self
+    }
+
+    /// Nests a child route underneath this one.
+    pub fn child(mut self, child: RouteDefinition) -> Self {
+        self.children.push(child);
+        self
+    }
+
+    fn matches(&self, segment: &str) -> bool {
+        self.segment.starts_with(':') || self.segment == segment
+    }
+
+    fn breadcrumb(&self, segment: &str, href: String) -> BreadcrumbDescriptor {
+        let label = if self.segment.starts_with(':') && self.label.is_empty() {
+            segment.to_string()
+        } else {
+            self.label.clone()
+        };
+        BreadcrumbDescriptor {
+            label,
+            href,
+            icon: self.icon.clone(),
+        }
+    }
+}
+
+/// One entry of a derived breadcrumb trail.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BreadcrumbDescriptor {
+    /// Text shown for the segment.
+    pub label: String,
+    /// Accumulated path up to and including this segment.
+    pub href: String,
+    /// Icon name rendered ahead of the label, when the route declared one.
+    pub icon: Option<String>,
+}
+
+/// Derives the breadcrumb trail for `path` by walking the nested route
+/// definitions, accumulating hrefs segment by segment.
+///
+/// Returns `None` when the path does not resolve through the tree so app
+/// shells can fall back to hiding the trail rather than rendering a partial
+/// one. Dynamic `:param` segments match any value and label themselves with
+/// the concrete path segment unless the definition supplied a label.
+#[must_use]
+pub fn breadcrumbs_for(
+    routes: &[RouteDefinition],
+    path: &str,
+) -> Option<Vec<BreadcrumbDescriptor>> {
+    let segments: Vec<&str> = path.split('/').filter(|s| !s.is_empty()).collect();
+    let mut trail = Vec::with_capacity(segments.len());
+    let mut candidates = routes;
+    let mut href = String::new();
+    for segment in segments {
+        let route = candidates.iter().find(|route| route.matches(segment))?;
+        href.push('/');
+        href.push_str(segment);
+        trail.push(route.breadcrumb(segment, href.clone()));
+        candidates = &route.children;
+    }
+    if trail.is_empty() {
+        return None;
+    }
+    Some(trail)
+}
+
+/// Derives the trail for `path` and feeds it straight into
+/// [`render_breadcrumbs`]: ancestors become router-aware links (icons
+/// rendered as `data-rustic-icon` spans ahead of the label) and the final
+/// segment becomes the `aria-current` page.
+#[must_use]
+pub fn render_breadcrumbs_for(routes: &[RouteDefinition], path: &str) -> Option<String> {
+    let trail = breadcrumbs_for(routes, path)?;
+    let (current, ancestors) = trail.split_last().expect("trail is never empty");
+    let segments: Vec<(String, RouterTarget)> = ancestors
+        .iter()
+        .map(|crumb| (breadcrumb_label(crumb), RouterTarget::route(&crumb.href)))
+        .collect();
+    Some(render_breadcrumbs(&segments, &breadcrumb_label(current)))
+}
+
+fn breadcrumb_label(crumb: &BreadcrumbDescriptor) -> String {
+    match &crumb.icon {
+        Some(icon) => format!(
+            "<span data-rustic-icon=\"{icon}\" aria-hidden=\"true\"></span>{}",
+            crumb.label
+        ),
+        None => crumb.label.clone(),
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Framework bootstrap adapters
+// ---------------------------------------------------------------------------
+
+/// Interception glue for `yew-router` applications.
+///
+/// Client bundles query [`ROUTER_LINK_SELECTOR`], suppress the default click
+/// and feed [`RouterTarget`] values (reconstructed via [`target_from_attrs`])
+/// into `yew_router::prelude::Navigator::{push,replace}`. Kept behind the
+/// `yew` feature so server-only consumers never pull the router machinery.
+#[cfg(feature = "yew")]
+pub mod yew {
+    pub use super::{link_attributes, target_from_attrs, ROUTER_LINK_SELECTOR};
+}
+
+/// Interception glue for `leptos_router` applications; mirrors the
+/// [`yew`](self::yew) module with `leptos_router::use_navigate`.
+#[cfg(feature = "leptos")]
+pub mod leptos {
+    pub use super::{link_attributes, target_from_attrs, ROUTER_LINK_SELECTOR};
+}
+
+/// Interception glue for `dioxus-router` applications; mirrors the
+/// [`yew`](self::yew) module with `dioxus_router::prelude::navigator`.
+#[cfg(feature = "dioxus")]
+pub mod dioxus {
+    pub use super::{link_attributes, target_from_attrs, ROUTER_LINK_SELECTOR};
+}
+
+/// Reconstruct a [`RouterTarget`] from the attributes stamped by
+/// [`link_attributes`], used by the client bootstraps when a matching anchor
+/// is clicked.
+#[must_use]
+pub fn target_from_attrs(href: &str, router: Option<&str>, replace: Option<&str>) -> RouterTarget {
+    RouterTarget {
+        path: href.to_string(),
+        replace: replace == Some("true"),
+        external: router == Some("external"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn internal_links_carry_the_interception_contract() {
+        let html = wrap_in_link(&RouterTarget::route("/reports"), "Reports");
+        assert!(html.contains("href=\"/reports\""));
+        assert!(html.contains("data-rustic-router=\"route\""));
+        assert!(html.contains("data-rustic-router-replace=\"false\""));
+        assert!(html.contains(">Reports</a>"));
+    }
+
+    #[test]
+    fn replace_navigation_is_flagged() {
+        let attrs = link_attributes(&RouterTarget::route("/login").with_replace());
+        assert!(attrs
+            .iter()
+            .any(|(key, value)| key == "data-rustic-router-replace" && value == "true"));
+    }
+
+    #[test]
+    fn external_links_opt_out_of_interception() {
+        let attrs = link_attributes(&RouterTarget::external("https://example.com"));
+        assert!(attrs
+            .iter()
+            .any(|(key, value)| key == "data-rustic-router" && value == "external"));
+        assert!(attrs.iter().any(|(key, _)| key == "rel"));
+    }
+
+    #[test]
+    fn breadcrumbs_follow_the_aria_pattern() {
+        let segments = vec![
+            ("Home".to_string(), RouterTarget::route("/")),
+            ("Projects".to_string(), RouterTarget::route("/projects")),
+        ];
+        let html = render_breadcrumbs(&segments, "Apollo");
+        assert!(html.contains("<nav aria-label=\"Breadcrumb\">"));
+        assert!(html.contains("href=\"/projects\""));
+        assert!(html.contains("<li aria-current=\"page\">Apollo</li>"));
+    }
+
+    fn route_tree() -> Vec<RouteDefinition> {
+        vec![
+            RouteDefinition::new("projects", "Projects")
+                .with_icon("folder")
+                .child(
+                    RouteDefinition::new(":id", "")
+                        .child(RouteDefinition::new("settings", "Settings")),
+                ),
+            RouteDefinition::new("reports", "Reports"),
+        ]
+    }
+
+    #[test]
+    fn breadcrumbs_accumulate_hrefs_through_nested_routes() {
+        let trail = breadcrumbs_for(&route_tree(), "/projects/42/settings").unwrap();
+        let hrefs: Vec<&str> = trail.iter().map(|crumb| crumb.href.as_str()).collect();
+        assert_eq!(
+            hrefs,
+            vec!["/projects", "/projects/42", "/projects/42/settings"]
+        );
+        // Dynamic segments label themselves with the concrete value.
+        assert_eq!(trail[1].label, "42");
+        assert_eq!(trail[0].icon.as_deref(), Some("folder"));
+    }
+
+    #[test]
+    fn unresolved_paths_produce_no_trail() {
+        assert!(breadcrumbs_for(&route_tree(), "/missing").is_none());
+        assert!(breadcrumbs_for(&route_tree(), "/projects/42/unknown").is_none());
+        assert!(breadcrumbs_for(&route_tree(), "/").is_none());
+    }
+
+    #[test]
+    fn derived_trails_feed_the_breadcrumb_renderer() {
+        let html = render_breadcrumbs_for(&route_tree(), "/projects/42/settings").unwrap();
+        assert!(html.contains("<nav aria-label=\"Breadcrumb\">"));
+        assert!(html.contains("href=\"/projects/42\""));
+        assert!(html.contains("data-rustic-icon=\"folder\""));
+        assert!(html.contains("<li aria-current=\"page\">Settings</li>"));
+    }
+
+    #[test]
+    fn targets_round_trip_through_the_attribute_contract() {
+        let target = RouterTarget::route("/projects/7").with_replace();
+        let attrs = link_attributes(&target);
+        let router = attrs
+            .iter()
+            .find(|(key, _)| key == "data-rustic-router")
+            .map(|(_, value)| value.as_str());
+        let replace = attrs
+            .iter()
+            .find(|(key, _)| key == "data-rustic-router-replace")
+            .map(|(_, value)| value.as_str());
+        assert_eq!(target_from_attrs(&target.path, router, replace), target);
+    }
+}
@@ -20,6 +20,8 @@ use rustic_ui_headless::select::SelectState;
 use rustic_ui_styled_engine::{css_with_theme, Style};
 use rustic_ui_system::portal::PortalMount;
 
+use crate::macros::Size;
+
 /// Discrete option rendered inside the Material select popover.
 #[derive(Clone, Debug)]
 pub struct SelectOption {
@@ -49,6 +51,10 @@ pub struct SelectProps {
     /// Optional automation identifier used to stamp deterministic `data-*`
     /// attributes for end-to-end tests.
     pub automation_id: Option<String>,
+    /// Density preset scaling the trigger and option paddings; see
+    /// [`style_helpers::control_spacing_scale`](crate::style_helpers) for the
+    /// mapping to the theme density tokens.
+    pub size: Size,
 }
 
 impl SelectProps {
@@ -58,6 +64,7 @@ impl SelectProps {
             label: label.into(),
             options,
             automation_id: None,
+            size: Size::Medium,
         }
     }
 
@@ -67,6 +74,12 @@ impl SelectProps {
         self.automation_id = Some(id.into());
         self
     }
+
+    /// Override the density preset, compacting or enlarging the control.
+    pub fn with_size(mut self, size: Size) -> Self {
+        self.size = size;
+        self
+    }
 }
 
 /// Shared rendering routine used by SSR adapters.
@@ -81,7 +94,7 @@ fn render_html(props: &SelectProps, state: &SelectState) -> String {
         root_attributes(props, state, &portal),
     );
     let trigger_attrs = crate::style_helpers::themed_attributes_html(
-        themed_trigger_style(),
+        themed_trigger_style(&props.size),
         trigger_attributes(props, state, &portal),
     );
     let list_attrs = crate::style_helpers::themed_attributes_html(
@@ -95,7 +108,7 @@ fn render_html(props: &SelectProps, state: &SelectState) -> String {
     let mut options_html = String::new();
     for (index, option) in props.options.iter().enumerate() {
         let option_attrs = crate::style_helpers::themed_attributes_html(
-            themed_option_style(),
+            themed_option_style(&props.size),
             option_attributes(props, state, index),
         );
         options_html.push_str(&format!("<li {option_attrs}>{}</li>", option.label));
@@ -104,6 +117,11 @@ fn render_html(props: &SelectProps, state: &SelectState) -> String {
     let anchor_html = portal.anchor_html();
     let popover_markup = portal.wrap(format!("<ul {list_attrs}>{options_html}</ul>"));
 
+    // Surface the render as a typed telemetry event carrying the same
+    // automation identifier stamped on the markup, so analytics pipelines can
+    // correlate events with DOM selectors without scraping attributes.
+    rustic_ui_utils::telemetry::emit("select", Some(&automation_base(props)), "render");
+
     format!(
         "<div {root_attrs}><button {trigger_attrs}>{}</button>{}</div>{}",
         props.label,
@@ -114,7 +132,11 @@ fn render_html(props: &SelectProps, state: &SelectState) -> String {
 
 /// Resolve the automation identifier used for data hooks and DOM ids.
 fn automation_base(props: &SelectProps) -> String {
-    crate::style_helpers::automation_id("select", props.automation_id.as_deref(), [])
+    crate::style_helpers::automation_id(
+        "select",
+        props.automation_id.as_deref(),
+        crate::style_helpers::NO_SEGMENTS,
+    )
 }
 
 /// Compute the DOM id for the option list.
@@ -152,13 +174,14 @@ fn root_attributes(
     ));
     attrs.push((
         "data-component".into(),
-        crate::style_helpers::automation_id("select", None, []),
+        crate::style_helpers::automation_id("select", None, crate::style_helpers::NO_SEGMENTS),
     ));
     attrs.push(("data-open".into(), state.is_open().to_string()));
     attrs.push((
         "data-portal-layer".into(),
         portal.layer().as_str().to_string(),
     ));
+    attrs.push(("data-size".into(), props.size.as_str().into()));
     attrs
 }
 
@@ -243,7 +266,9 @@ fn option_attributes(
 }
 
 fn popover_mount(props: &SelectProps) -> PortalMount {
-    PortalMount::popover(crate::style_helpers::automation_id(
+    // Anonymous selects draw their portal id from the hydration allocator so
+    // multiple instances on one page stay unique and SSR/CSR stay aligned.
+    PortalMount::popover(crate::style_helpers::hydration_scoped_id(
         "select",
         props.automation_id.as_deref(),
         ["popover"],
@@ -270,8 +295,9 @@ fn themed_root_style() -> Style {
 /// Palette tokens flow directly from the theme ensuring brand overrides cascade
 /// automatically.  Inline notes document how the generated CSS leans on design
 /// tokens instead of hard coded values which keeps enterprise rollouts
-/// repeatable.
-fn themed_trigger_style() -> Style {
+/// repeatable.  The `size` preset scales paddings through the shared density
+/// helpers.
+fn themed_trigger_style(size: &Size) -> Style {
     css_with_theme!(
         r#"
         display: inline-flex;
@@ -294,22 +320,22 @@ fn themed_trigger_style() -> Style {
         }
     "#,
         min_width = format!("{}px", theme.spacing(18)),
-        padding_y = format!("{}px", theme.spacing(1)),
-        padding_x = format!("{}px", theme.spacing(2)),
+        padding_y = crate::style_helpers::control_spacing_px(&theme, size, 1),
+        padding_x = crate::style_helpers::control_spacing_px(&theme, size, 2),
         radius = format!("{}px", theme.joy.radius),
         border_color = format!(
             "color-mix(in srgb, {} 40%, transparent)",
-            theme.palette.text_secondary.clone()
+            theme.palette.active().text_secondary.clone()
         ),
-        background = theme.palette.background_paper.clone(),
-        text_color = theme.palette.text_primary.clone(),
+        background = theme.palette.active().background_paper.clone(),
+        text_color = theme.palette.active().text_primary.clone(),
         font_family = theme.typography.font_family.clone(),
         font_size = format!("{:.3}rem", theme.typography.body1),
-        focus_outline_color = theme.palette.primary.clone(),
+        focus_outline_color = theme.palette.active().primary.clone(),
         focus_outline_width = format!("{:.1}px", (theme.joy.focus.thickness as f32).max(1.0) / 2.0),
         focus_outline_color_transparent = format!(
             "color-mix(in srgb, {} 24%, transparent)",
-            theme.palette.primary.clone()
+            theme.palette.active().primary.clone()
         )
     )
 }
@@ -347,12 +373,12 @@ fn themed_list_style() -> Style {
         radius = format!("{}px", theme.joy.radius),
         border_color = format!(
             "color-mix(in srgb, {} 40%, transparent)",
-            theme.palette.text_secondary.clone()
+            theme.palette.active().text_secondary.clone()
         ),
-        background = theme.palette.background_paper.clone(),
+        background = theme.palette.active().background_paper.clone(),
         shadow = format!(
             "0 12px 24px color-mix(in srgb, {} 16%, transparent)",
-            theme.palette.text_primary.clone()
+            theme.palette.active().text_primary.clone()
         )
     )
 }
@@ -361,8 +387,9 @@ fn themed_list_style() -> Style {
 ///
 /// The macro leans on palette surface/hover tokens, while `data-highlighted`
 /// drives hover/keyboard focus affordances so automation hooks can assert the
-/// same state used for styling.
-fn themed_option_style() -> Style {
+/// same state used for styling.  Option paddings follow the trigger's density
+/// preset so dense menus stay visually aligned.
+fn themed_option_style(size: &Size) -> Style {
     css_with_theme!(
         r#"
         padding: ${padding_y} ${padding_x};
@@ -381,16 +408,16 @@ fn themed_option_style() -> Style {
             font-weight: ${font_weight};
         }
     "#,
-        padding_y = format!("{}px", theme.spacing(1)),
-        padding_x = format!("{}px", theme.spacing(2)),
+        padding_y = crate::style_helpers::control_spacing_px(&theme, size, 1),
+        padding_x = crate::style_helpers::control_spacing_px(&theme, size, 2),
         radius = format!("{:.1}px", (theme.joy.radius as f32) / 2.0),
         font_family = theme.typography.font_family.clone(),
         font_size = format!("{:.3}rem", theme.typography.body2),
-        text_color = theme.palette.text_primary.clone(),
+        text_color = theme.palette.active().text_primary.clone(),
         hover_background = format!(
             "color-mix(in srgb, {} 12%, {})",
-            theme.palette.primary.clone(),
-            theme.palette.background_paper.clone()
+            theme.palette.active().primary.clone(),
+            theme.palette.active().background_paper.clone()
         ),
         font_weight = theme.typography.font_weight_medium.to_string()
     )
@@ -404,6 +431,40 @@ pub mod yew {
     pub fn render(props: &SelectProps, state: &SelectState) -> String {
         super::render_html(props, state)
     }
+
+    /// Render the select with a native callback reporting the clicked option
+    /// index.
+    ///
+    /// Clicks are delegated from a wrapper node and resolved against the
+    /// `data-index` attribute every option carries, so the callback receives
+    /// the option's position ready to feed into
+    /// [`SelectState::select`](rustic_ui_headless::select::SelectState::select)
+    /// before re-rendering. Clicks on the trigger or outside the listbox never
+    /// fire the callback.
+    #[cfg(feature = "yew")]
+    pub fn render_with_on_select(
+        props: &SelectProps,
+        state: &SelectState,
+        on_select: ::yew::Callback<usize>,
+    ) -> ::yew::Html {
+        use ::wasm_bindgen::JsCast;
+
+        let on_click = ::yew::Callback::from(move |event: ::yew::events::MouseEvent| {
+            let option = event
+                .target()
+                .and_then(|target| target.dyn_into::<::web_sys::Element>().ok())
+                .and_then(|element| element.closest("[data-index]").ok().flatten());
+            if let Some(element) = option {
+                if let Some(index) = element
+                    .get_attribute("data-index")
+                    .and_then(|value| value.parse::<usize>().ok())
+                {
+                    on_select.emit(index);
+                }
+            }
+        });
+        crate::render::yew::delegated_click(super::render_html(props, state), on_click)
+    }
 }
 
 /// Adapter targeting the [`leptos`] framework.
@@ -414,6 +475,34 @@ pub mod leptos {
     pub fn render(props: &SelectProps, state: &SelectState) -> String {
         super::render_html(props, state)
     }
+
+    /// Render the select with an option handler, mirroring
+    /// [`yew::render_with_on_select`](super::yew::render_with_on_select) for
+    /// Leptos consumers.
+    #[cfg(feature = "leptos")]
+    pub fn render_with_on_select(
+        props: &SelectProps,
+        state: &SelectState,
+        on_select: impl Fn(usize) + 'static,
+    ) -> ::leptos::View {
+        use ::leptos::wasm_bindgen::JsCast;
+
+        let on_click = move |event: ::leptos::ev::MouseEvent| {
+            let option = event
+                .target()
+                .and_then(|target| target.dyn_into::<::leptos::web_sys::Element>().ok())
+                .and_then(|element| element.closest("[data-index]").ok().flatten());
+            if let Some(element) = option {
+                if let Some(index) = element
+                    .get_attribute("data-index")
+                    .and_then(|value| value.parse::<usize>().ok())
+                {
+                    on_select(index);
+                }
+            }
+        };
+        crate::render::leptos::delegated_click(super::render_html(props, state), on_click)
+    }
 }
 
 /// Adapter targeting the [`dioxus`] framework.
@@ -501,6 +590,34 @@ mod tests {
         assert!(html.contains("data-value=\"1\""));
         assert!(html.contains("data-portal-root"));
         assert!(html.contains("data-portal-anchor"));
+        assert!(html.contains("data-size=\"medium\""));
+    }
+
+    #[test]
+    fn render_html_reflects_size_preset() {
+        let props = sample_props().with_size(Size::Small);
+        let state = build_state(props.options.len());
+        let html = render_html(&props, &state);
+        assert!(html.contains("data-size=\"small\""));
+    }
+
+    #[test]
+    fn render_html_emits_a_typed_telemetry_event() {
+        let sink = std::sync::Arc::new(rustic_ui_utils::telemetry::MemorySink::default());
+        rustic_ui_utils::telemetry::install_sink(sink.clone());
+
+        let props = sample_props();
+        let state = build_state(props.options.len());
+        render_html(&props, &state);
+        rustic_ui_utils::telemetry::reset_sink();
+
+        // Other components rendering in parallel tests may share the global
+        // sink, so filter down to this instance's automation identifier.
+        assert!(sink.events().iter().any(|event| {
+            event.component == "select"
+                && event.action == "render"
+                && event.automation_id.as_deref() == Some("rustic-select-sample")
+        }));
     }
 
     #[test]
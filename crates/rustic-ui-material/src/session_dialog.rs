@@ -0,0 +1,239 @@
+//! Idle-logout dialog blueprint driven by the headless session timeout
+//! machine.
+//!
+//! Regulated applications show the same surface everywhere: nothing while the
+//! session is active, a warning dialog with a live countdown plus
+//! "stay signed in" and "sign out" actions once inactivity trips the
+//! threshold, and an expiry notice pointing back at re-authentication after
+//! the countdown runs out.  The blueprint renders whatever
+//! [`SessionTimeoutState::phase`] currently reports, so adapters simply
+//! re-render after [`poll`](rustic_ui_headless::session_timeout::SessionTimeoutState::poll)
+//! ticks and wire the buttons to
+//! [`extend`](rustic_ui_headless::session_timeout::SessionTimeoutState::extend)
+//! / [`expire_now`](rustic_ui_headless::session_timeout::SessionTimeoutState::expire_now)
+//! via the `data-session-action` hooks.
+
+use rustic_ui_headless::session_timeout::{SessionPhase, SessionTimeoutState};
+use rustic_ui_headless::timing::Clock;
+use rustic_ui_styled_engine::{css_with_theme, Style};
+
+/// Shared properties accepted by all adapter implementations.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SessionDialogProps {
+    /// Heading of the warning dialog.
+    pub title: String,
+    /// Supporting copy explaining the idle logout.
+    pub message: String,
+    /// Label of the button extending the session.
+    pub continue_label: String,
+    /// Label of the button signing out immediately.
+    pub logout_label: String,
+    /// Copy shown once the session expired.
+    pub expired_message: String,
+    /// Optional automation identifier for analytics and end-to-end tests.
+    pub automation_id: Option<String>,
+}
+
+impl Default for SessionDialogProps {
+    fn default() -> Self {
+        Self {
+            title: "Session expiring".into(),
+            message: "You have been inactive for a while. For your security you \
+                      will be signed out automatically."
+                .into(),
+            continue_label: "Stay signed in".into(),
+            logout_label: "Sign out".into(),
+            expired_message: "Your session has expired. Please sign in again.".into(),
+            automation_id: None,
+        }
+    }
+}
+
+impl SessionDialogProps {
+    /// Convenience constructor using the enterprise default copy.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the automation identifier stamped on the rendered hooks.
+    pub fn with_automation_id(mut self, id: impl Into<String>) -> Self {
+        self.automation_id = Some(id.into());
+        self
+    }
+}
+
+/// Shared rendering routine used by all adapters.
+///
+/// Returns an empty string while the session is active so the dialog never
+/// occupies the DOM outside the warning and expired phases.
+fn render_html<C: Clock>(props: &SessionDialogProps, state: &SessionTimeoutState<C>) -> String {
+    let phase = state.phase();
+    if phase == SessionPhase::Active {
+        return String::new();
+    }
+    let attrs = crate::style_helpers::themed_attributes_html(
+        themed_session_dialog_style(),
+        vec![
+            ("role".to_string(), String::from("alertdialog")),
+            ("aria-modal".to_string(), String::from("true")),
+            ("aria-live".to_string(), String::from("assertive")),
+            ("data-session-phase".to_string(), phase.as_str().to_string()),
+            (
+                "data-component".to_string(),
+                crate::style_helpers::automation_id(
+                    "session-dialog",
+                    None,
+                    crate::style_helpers::NO_SEGMENTS,
+                ),
+            ),
+            (
+                crate::style_helpers::automation_data_attr("session-dialog", ["root"]),
+                crate::style_helpers::automation_id(
+                    "session-dialog",
+                    props.automation_id.as_deref(),
+                    ["root"],
+                ),
+            ),
+        ],
+    );
+    let body = match phase {
+        SessionPhase::Warning => warning_body(props, state),
+        SessionPhase::Expired => format!(
+            "<p>{}</p>",
+            crate::render::escape_text(&props.expired_message)
+        ),
+        SessionPhase::Active => unreachable!("active sessions render nothing"),
+    };
+    format!("<div {attrs}>{body}</div>")
+}
+
+/// Warning phase body: heading, copy, live countdown and the action pair.
+fn warning_body<C: Clock>(props: &SessionDialogProps, state: &SessionTimeoutState<C>) -> String {
+    let seconds = state
+        .warning_remaining()
+        .map(|remaining| remaining.as_secs())
+        .unwrap_or(0);
+    format!(
+        "<h2>{title}</h2><p>{message}</p>\
+         <p data-session-countdown=\"{seconds}\">{seconds}s</p>\
+         <button type=\"button\" data-session-action=\"extend\">{continue_label}</button>\
+         <button type=\"button\" data-session-action=\"logout\">{logout_label}</button>",
+        title = crate::render::escape_text(&props.title),
+        message = crate::render::escape_text(&props.message),
+        continue_label = crate::render::escape_text(&props.continue_label),
+        logout_label = crate::render::escape_text(&props.logout_label),
+    )
+}
+
+/// Centered surface styling pulled from the active theme tokens.
+fn themed_session_dialog_style() -> Style {
+    css_with_theme!(
+        r#"
+        position: fixed;
+        top: 50%;
+        left: 50%;
+        transform: translate(-50%, -50%);
+        z-index: 1400;
+        min-width: 320px;
+        padding: ${padding};
+        border-radius: 8px;
+        background: ${background};
+        color: ${color};
+        font-family: ${font_family};
+        box-shadow: 0 8px 32px rgba(0, 0, 0, 0.32);
+    "#,
+        padding = format!("{}px", theme.spacing(3)),
+        background = theme.palette.active().background_paper.clone(),
+        color = theme.palette.active().text_primary.clone(),
+        font_family = theme.typography.font_family.clone(),
+    )
+}
+
+// ---------------------------------------------------------------------------
+// Adapter implementations
+// ---------------------------------------------------------------------------
+
+pub mod yew {
+    use super::*;
+
+    /// Render the session dialog into a plain HTML string for SSR/hydration.
+    pub fn render<C: Clock>(props: &SessionDialogProps, state: &SessionTimeoutState<C>) -> String {
+        super::render_html(props, state)
+    }
+}
+
+pub mod leptos {
+    use super::*;
+
+    /// Render the session dialog into a plain HTML string for SSR/hydration.
+    pub fn render<C: Clock>(props: &SessionDialogProps, state: &SessionTimeoutState<C>) -> String {
+        super::render_html(props, state)
+    }
+}
+
+pub mod dioxus {
+    use super::*;
+
+    /// Render the session dialog into a plain HTML string for SSR/hydration.
+    pub fn render<C: Clock>(props: &SessionDialogProps, state: &SessionTimeoutState<C>) -> String {
+        super::render_html(props, state)
+    }
+}
+
+pub mod sycamore {
+    use super::*;
+
+    /// Render the session dialog into a plain HTML string for SSR/hydration.
+    pub fn render<C: Clock>(props: &SessionDialogProps, state: &SessionTimeoutState<C>) -> String {
+        super::render_html(props, state)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rustic_ui_headless::session_timeout::SessionTimeoutConfig;
+    use rustic_ui_headless::timing::MockClock;
+    use std::time::Duration;
+
+    fn session(clock: &MockClock) -> SessionTimeoutState<MockClock> {
+        SessionTimeoutState::with_clock(clock.clone(), SessionTimeoutConfig::enterprise_defaults())
+    }
+
+    #[test]
+    fn active_sessions_render_nothing() {
+        let clock = MockClock::new();
+        let state = session(&clock);
+        assert_eq!(render_html(&SessionDialogProps::new(), &state), "");
+    }
+
+    #[test]
+    fn warning_dialog_counts_down_with_both_actions() {
+        let clock = MockClock::new();
+        let mut state = session(&clock);
+        clock.advance(Duration::from_secs(780));
+        state.poll();
+        clock.advance(Duration::from_secs(30));
+
+        let props = SessionDialogProps::new().with_automation_id("idle-guard");
+        let html = render_html(&props, &state);
+        assert!(html.contains("role=\"alertdialog\""));
+        assert!(html.contains("data-session-phase=\"warning\""));
+        assert!(html.contains("data-session-countdown=\"90\""));
+        assert!(html.contains("data-session-action=\"extend\""));
+        assert!(html.contains("data-session-action=\"logout\""));
+        assert!(html
+            .contains("data-rustic-session-dialog-root=\"rustic-session-dialog-idle-guard-root\""));
+    }
+
+    #[test]
+    fn expired_sessions_show_the_reauthentication_notice() {
+        let clock = MockClock::new();
+        let mut state = session(&clock);
+        state.expire_now();
+        let html = render_html(&SessionDialogProps::new(), &state);
+        assert!(html.contains("data-session-phase=\"expired\""));
+        assert!(html.contains("Please sign in again."));
+        assert!(!html.contains("data-session-countdown"));
+    }
+}
@@ -27,8 +27,8 @@ fn resolve_style(
     variant: SnackbarVariant,
 ) -> (String, &'static str, String) {
     let bg = match color {
-        SnackbarColor::Primary => theme.palette.primary.clone(),
-        SnackbarColor::Secondary => theme.palette.secondary.clone(),
+        SnackbarColor::Primary => theme.palette.active().primary.clone(),
+        SnackbarColor::Secondary => theme.palette.active().secondary.clone(),
     };
     let padding = match size {
         SnackbarSize::Small => "4px 8px",
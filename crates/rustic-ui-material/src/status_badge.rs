@@ -0,0 +1,210 @@
+//! Presence indicator badge driven by the headless [`PresenceState`].
+//!
+//! Avatars and roster rows decorate themselves with a colored dot conveying
+//! availability.  Instead of hand-written static markup, the badge renders
+//! whatever [`PresenceState::status`](rustic_ui_headless::presence::PresenceState::status)
+//! currently reports, so staleness decay and busy overlays flow into the DOM
+//! automatically whenever adapters re-render after a
+//! [`PresenceChange`](rustic_ui_headless::presence::PresenceChange).
+//!
+//! The dot colors map onto the active palette (success for online, warning
+//! for away, danger for busy, the muted secondary text color for offline) so
+//! light/dark scheme switches restyle the badge with zero component logic.
+
+use rustic_ui_headless::presence::PresenceState;
+use rustic_ui_headless::timing::Clock;
+use rustic_ui_styled_engine::{css_with_theme, Style};
+
+/// Shared properties accepted by all adapter implementations.
+#[derive(Clone, Debug, PartialEq, Default)]
+pub struct StatusBadgeProps {
+    /// Optional visible label next to the dot, e.g. the user's name or the
+    /// status word itself.  When omitted only the dot renders and the status
+    /// is still announced through `aria-label`.
+    pub label: Option<String>,
+    /// Optional automation identifier for analytics and end-to-end tests.
+    pub automation_id: Option<String>,
+}
+
+impl StatusBadgeProps {
+    /// Convenience constructor used by examples and tests.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a visible label after the indicator dot.
+    pub fn with_label(mut self, label: impl Into<String>) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+
+    /// Sets the automation identifier stamped on the rendered hooks.
+    pub fn with_automation_id(mut self, id: impl Into<String>) -> Self {
+        self.automation_id = Some(id.into());
+        self
+    }
+}
+
+/// Shared rendering routine used by all adapters.
+fn render_html<C: Clock>(props: &StatusBadgeProps, state: &PresenceState<C>) -> String {
+    let status = state.status();
+    let attrs = crate::style_helpers::themed_attributes_html(
+        themed_status_badge_style(),
+        vec![
+            ("role".to_string(), String::from("status")),
+            ("aria-label".to_string(), status.as_str().to_string()),
+            ("data-status".to_string(), status.as_str().to_string()),
+            (
+                "data-component".to_string(),
+                crate::style_helpers::automation_id(
+                    "status-badge",
+                    None,
+                    crate::style_helpers::NO_SEGMENTS,
+                ),
+            ),
+            (
+                crate::style_helpers::automation_data_attr("status-badge", ["root"]),
+                crate::style_helpers::automation_id(
+                    "status-badge",
+                    props.automation_id.as_deref(),
+                    ["root"],
+                ),
+            ),
+        ],
+    );
+    let label_html = props
+        .label
+        .as_ref()
+        .map(|label| format!("<span>{}</span>", crate::render::escape_text(label)))
+        .unwrap_or_default();
+    format!("<span {attrs}>{label_html}</span>")
+}
+
+/// Builds the badge styling from the active theme tokens.
+///
+/// The indicator dot is a `::before` pseudo element recolored through
+/// `data-status` attribute selectors, keeping the markup identical across all
+/// four presence states so hydration never reshuffles the DOM.
+fn themed_status_badge_style() -> Style {
+    css_with_theme!(
+        r#"
+        display: inline-flex;
+        align-items: center;
+        gap: ${gap};
+        font-family: ${font_family};
+        font-size: 0.75rem;
+        color: ${text_color};
+
+        &::before {
+            content: "";
+            width: ${dot_size};
+            height: ${dot_size};
+            border-radius: 9999px;
+            background: ${offline_color};
+            box-shadow: 0 0 0 2px ${ring_color};
+        }
+
+        &[data-status='online']::before {
+            background: ${online_color};
+        }
+
+        &[data-status='away']::before {
+            background: ${away_color};
+        }
+
+        &[data-status='busy']::before {
+            background: ${busy_color};
+        }
+    "#,
+        gap = format!("{}px", theme.spacing(1)),
+        font_family = theme.typography.font_family.clone(),
+        text_color = theme.palette.active().text_secondary.clone(),
+        dot_size = format!("{}px", theme.spacing(1)),
+        ring_color = theme.palette.active().background_paper.clone(),
+        online_color = theme.palette.active().success.clone(),
+        away_color = theme.palette.active().warning.clone(),
+        busy_color = theme.palette.active().danger.clone(),
+        offline_color = theme.palette.active().text_secondary.clone(),
+    )
+}
+
+// ---------------------------------------------------------------------------
+// Adapter implementations
+// ---------------------------------------------------------------------------
+
+pub mod yew {
+    use super::*;
+
+    /// Render the status badge into a plain HTML string for SSR/hydration.
+    pub fn render<C: Clock>(props: &StatusBadgeProps, state: &PresenceState<C>) -> String {
+        super::render_html(props, state)
+    }
+}
+
+pub mod leptos {
+    use super::*;
+
+    /// Render the status badge into a plain HTML string for SSR/hydration.
+    pub fn render<C: Clock>(props: &StatusBadgeProps, state: &PresenceState<C>) -> String {
+        super::render_html(props, state)
+    }
+}
+
+pub mod dioxus {
+    use super::*;
+
+    /// Render the status badge into a plain HTML string for SSR/hydration.
+    pub fn render<C: Clock>(props: &StatusBadgeProps, state: &PresenceState<C>) -> String {
+        super::render_html(props, state)
+    }
+}
+
+pub mod sycamore {
+    use super::*;
+
+    /// Render the status badge into a plain HTML string for SSR/hydration.
+    pub fn render<C: Clock>(props: &StatusBadgeProps, state: &PresenceState<C>) -> String {
+        super::render_html(props, state)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rustic_ui_headless::presence::{PresenceConfig, PresenceStatus};
+    use rustic_ui_headless::timing::MockClock;
+
+    fn presence(clock: &MockClock) -> PresenceState<MockClock> {
+        PresenceState::with_clock(clock.clone(), PresenceConfig::enterprise_defaults())
+    }
+
+    #[test]
+    fn render_html_reflects_the_presence_state() {
+        let clock = MockClock::new();
+        let mut state = presence(&clock);
+        let props = StatusBadgeProps::new().with_automation_id("avatar-status");
+        let html = render_html(&props, &state);
+        assert!(html.contains("data-status=\"offline\""));
+        assert!(html.contains("aria-label=\"offline\""));
+        assert!(html
+            .contains("data-rustic-status-badge-root=\"rustic-status-badge-avatar-status-root\""));
+
+        state.heartbeat();
+        assert!(render_html(&props, &state).contains("data-status=\"online\""));
+        state.set_busy(true);
+        assert_eq!(state.status(), PresenceStatus::Busy);
+        assert!(render_html(&props, &state).contains("data-status=\"busy\""));
+    }
+
+    #[test]
+    fn label_renders_escaped_next_to_the_dot() {
+        let clock = MockClock::new();
+        let state = presence(&clock);
+        let props = StatusBadgeProps::new().with_label("Ada <Lovelace>");
+        let html = render_html(&props, &state);
+        assert!(html.contains("<span>Ada &lt;Lovelace&gt;</span>"));
+
+        let bare = render_html(&StatusBadgeProps::new(), &state);
+        assert!(!bare.contains("<span>Ada"));
+    }
+}
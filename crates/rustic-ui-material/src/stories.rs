@@ -0,0 +1,188 @@
+//! Story registry powering the static component gallery.
+//!
+//! Each entry pairs a component with a named props permutation and a
+//! deterministic renderer, giving the `cargo xtask stories` command a single
+//! source to walk when it bakes the HTML explorer pages used for visual
+//! regression baselines.  Renderers must be pure — stories built on state
+//! machines pin a [`MockClock`](rustic_ui_headless::timing::MockClock) so the
+//! same markup falls out on every run, which is what makes the output
+//! diffable in CI.
+//!
+//! Component teams extend the gallery by appending to [`all`]; stories keep
+//! explicit automation identifiers so the generated pages double as stable
+//! anchors for screenshot tooling.
+
+use rustic_ui_headless::presence::{PresenceConfig, PresenceState};
+use rustic_ui_headless::session_timeout::{SessionTimeoutConfig, SessionTimeoutState};
+use rustic_ui_headless::timing::MockClock;
+use std::time::Duration;
+
+/// A named, deterministic props permutation for one component.
+pub struct Story {
+    /// Component slug the story belongs to, e.g. `status-badge`.
+    pub component: &'static str,
+    /// Human readable permutation name, e.g. `busy`.
+    pub name: &'static str,
+    /// Pure renderer producing the story markup.
+    pub render: fn() -> String,
+}
+
+/// Every registered story, grouped by component in registration order.
+pub fn all() -> Vec<Story> {
+    vec![
+        Story {
+            component: "status-badge",
+            name: "offline",
+            render: || {
+                let clock = MockClock::new();
+                let state = PresenceState::with_clock(clock, PresenceConfig::enterprise_defaults());
+                crate::status_badge::yew::render(
+                    &crate::status_badge::StatusBadgeProps::new()
+                        .with_label("Ada Lovelace")
+                        .with_automation_id("story"),
+                    &state,
+                )
+            },
+        },
+        Story {
+            component: "status-badge",
+            name: "online",
+            render: || {
+                let clock = MockClock::new();
+                let mut state =
+                    PresenceState::with_clock(clock, PresenceConfig::enterprise_defaults());
+                state.heartbeat();
+                crate::status_badge::yew::render(
+                    &crate::status_badge::StatusBadgeProps::new()
+                        .with_label("Ada Lovelace")
+                        .with_automation_id("story"),
+                    &state,
+                )
+            },
+        },
+        Story {
+            component: "image",
+            name: "lazy-with-placeholder",
+            render: || {
+                crate::image::yew::render(
+                    &crate::image::ImageProps::new("https://example.com/hero.avif", "Hero")
+                        .with_dimensions(1600, 900)
+                        .with_placeholder("data:image/gif;base64,R0lGODlhAQABAAAAACw=")
+                        .with_automation_id("story"),
+                )
+            },
+        },
+        Story {
+            component: "image",
+            name: "eager-fallback",
+            render: || {
+                crate::image::yew::render(
+                    &crate::image::ImageProps::new("https://example.com/missing.png", "Chart")
+                        .with_fallback("Chart unavailable")
+                        .eager()
+                        .with_automation_id("story"),
+                )
+            },
+        },
+        Story {
+            component: "attachment-list",
+            name: "upload-states",
+            render: || {
+                use crate::attachment_list::{Attachment, AttachmentListProps, UploadStatus};
+                crate::attachment_list::yew::render(
+                    &AttachmentListProps::new(vec![
+                        Attachment::new("quarterly-report.pdf", "application/pdf", 4_718_592)
+                            .with_status(UploadStatus::Complete),
+                        Attachment::new("team-photo.jpg", "image/jpeg", 1_258_291)
+                            .with_status(UploadStatus::Uploading(62)),
+                        Attachment::new("export.csv", "text/csv", 9_216)
+                            .with_status(UploadStatus::Failed("Network timeout".into())),
+                    ])
+                    .with_automation_id("story"),
+                )
+            },
+        },
+        Story {
+            component: "session-dialog",
+            name: "warning-countdown",
+            render: || {
+                let clock = MockClock::new();
+                let mut state = SessionTimeoutState::with_clock(
+                    clock.clone(),
+                    SessionTimeoutConfig::enterprise_defaults(),
+                );
+                clock.advance(Duration::from_secs(780));
+                state.poll();
+                crate::session_dialog::yew::render(
+                    &crate::session_dialog::SessionDialogProps::new().with_automation_id("story"),
+                    &state,
+                )
+            },
+        },
+        Story {
+            component: "skip-nav",
+            name: "default",
+            render: || {
+                crate::skip_nav::yew::render(
+                    &crate::skip_nav::SkipLinkProps::new("main-content", "Skip to main content")
+                        .with_automation_id("story"),
+                )
+            },
+        },
+    ]
+}
+
+/// Stories registered for a single component slug.
+pub fn for_component(component: &str) -> Vec<Story> {
+    all()
+        .into_iter()
+        .filter(|story| story.component == component)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn every_story_renders_deterministic_markup() {
+        for story in all() {
+            let first = (story.render)();
+            assert!(
+                !first.is_empty(),
+                "story {}/{} rendered nothing",
+                story.component,
+                story.name
+            );
+            assert_eq!(
+                first,
+                (story.render)(),
+                "story {}/{} is not deterministic",
+                story.component,
+                story.name
+            );
+        }
+    }
+
+    #[test]
+    fn component_and_name_pairs_are_unique() {
+        let stories = all();
+        for (index, story) in stories.iter().enumerate() {
+            assert!(
+                !stories[index + 1..]
+                    .iter()
+                    .any(|other| other.component == story.component && other.name == story.name),
+                "duplicate story {}/{}",
+                story.component,
+                story.name
+            );
+        }
+    }
+
+    #[test]
+    fn for_component_filters_the_registry() {
+        let badges = for_component("status-badge");
+        assert_eq!(badges.len(), 2);
+        assert!(for_component("does-not-exist").is_empty());
+    }
+}
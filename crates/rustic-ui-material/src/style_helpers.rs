@@ -5,17 +5,17 @@
 //! extraction avoids repetitive `.get_class_name().to_string()` calls while
 //! documenting the intended lifecycle of stylist [`Style`] handles.
 
-use rustic_ui_styled_engine::Style;
+use crate::macros::Size;
+use rustic_ui_styled_engine::{Style, Theme};
 use rustic_ui_utils::{attributes_to_html, collect_attributes};
 
-/// Global prefix applied to every automation selector emitted by Material components.
-///
-/// All DOM ids and `data-*` attributes intended for QA automation or accessibility
-/// smoke tests should begin with this prefix. Centralising the prefix (and the
-/// accompanying formatting helpers) keeps selectors stable across SSR and client
-/// renders while dramatically reducing the risk of typos when new components are
-/// introduced.
-pub(crate) const COMPONENT_PREFIX: &str = "rustic";
+// The automation selector machinery graduated into the public
+// [`automation`](crate::automation) module so downstream QA tooling can reuse
+// the exact contract. These re-exports keep the long-standing internal call
+// sites untouched while routing everything through the shared builder.
+pub(crate) use crate::automation::{
+    automation_data_attr, automation_id, hydration_scoped_id, NO_SEGMENTS,
+};
 
 /// Consumes a [`Style`] and returns the scoped class name produced by the
 /// styled engine.
@@ -63,94 +63,115 @@ where
     attributes_to_html(&attrs)
 }
 
-/// Compose a deterministic automation DOM id that adheres to the workspace contract.
-///
-/// # Automation contract
-///
-/// * Every automation selector must start with [`COMPONENT_PREFIX`] so QA suites can
-///   glob on `rustic-*` without worrying about historical aliases.
-/// * Component adapters pass the logical `component` name (e.g. `"select"`,
-///   `"table"`) along with the optional user provided identifier and any additional
-///   `segments` describing the element (such as `"trigger"`, `"row-3"`).
-/// * All inputs are sanitised to `kebab-case` so the same selector is produced
-///   regardless of whether the caller provided `snake_case`, spaces or uppercase
-///   values. Invalid characters collapse to hyphens which keeps CSS and testing
-///   tooling happy.
+/// Variant of [`themed_attributes_html`] for component root elements that
+/// honours the theme's [`components`](rustic_ui_styled_engine::ComponentOverrides)
+/// registry.
 ///
-/// The resulting id is safe to use both as a DOM `id` and as the value for
-/// automation-focused `data-*` attributes. By funnelling every component through
-/// this helper we minimise the amount of manual string formatting and guarantee
-/// that SSR snapshots, client renders, and integration tests share the same
-/// selectors.
+/// When the active theme declares a style override for `component` the
+/// snippet is compiled into its own scoped class and appended after the base
+/// class, so the override rules win the cascade without `!important`.  Slot
+/// names match the automation vocabulary (`"button"`, `"chip"`, ...), giving
+/// design systems one stable key for restyling and test selectors alike.
 #[must_use]
-pub(crate) fn automation_id<I, S>(component: &str, user_id: Option<&str>, segments: I) -> String
+pub(crate) fn component_themed_attributes_html<I, K, V>(
+    component: &str,
+    style: Style,
+    iter: I,
+) -> String
 where
-    I: IntoIterator<Item = S>,
-    S: AsRef<str>,
+    I: IntoIterator<Item = (K, V)>,
+    K: Into<String>,
+    V: Into<String>,
 {
-    let mut parts = Vec::new();
-    parts.push(sanitise(component));
-
-    if let Some(id) = user_id {
-        let sanitised = sanitise(id);
-        if !sanitised.is_empty() {
-            parts.push(sanitised);
-        }
-    }
+    let theme = rustic_ui_styled_engine::use_theme();
+    let attrs = component_themed_attributes(&theme, component, style, iter);
+    attributes_to_html(&attrs)
+}
 
-    for segment in segments {
-        let sanitised = sanitise(segment.as_ref());
-        if !sanitised.is_empty() {
-            parts.push(sanitised);
+/// Theme-explicit core of [`component_themed_attributes_html`], split out so
+/// tests can inject a theme carrying override entries without a provider.
+#[must_use]
+pub(crate) fn component_themed_attributes<I, K, V>(
+    theme: &Theme,
+    component: &str,
+    style: Style,
+    iter: I,
+) -> Vec<(String, String)>
+where
+    I: IntoIterator<Item = (K, V)>,
+    K: Into<String>,
+    V: Into<String>,
+{
+    let mut attrs = themed_attributes(style, iter);
+    if let Some(override_style) =
+        rustic_ui_styled_engine::component_style_override(theme, component)
+    {
+        let override_class = themed_class(override_style);
+        if let Some((_, class)) = attrs.iter_mut().find(|(key, _)| key == "class") {
+            class.push(' ');
+            class.push_str(&override_class);
         }
     }
-
-    format!("{COMPONENT_PREFIX}-{}", parts.join("-"))
+    attrs
 }
 
-/// Compose the attribute name for automation-focused `data-*` hooks.
+/// Scale factor applied to spacing-derived paddings, gaps and indicator
+/// dimensions for the shared `size` prop.
 ///
-/// Unlike [`automation_id`], the attribute name never incorporates the caller's
-/// `user_id` because QA tooling expects the attribute key to remain stable across
-/// component instances. Only the logical `component` name and descriptive
-/// `segments` participate in the key.
-#[must_use]
-pub(crate) fn automation_data_attr<I, S>(component: &str, segments: I) -> String
-where
-    I: IntoIterator<Item = S>,
-    S: AsRef<str>,
-{
-    format!("data-{}", automation_id(component, None, segments))
+/// The mapping layers on top of the theme density tokens rather than replacing
+/// them: every component still derives its base measurements from
+/// [`Theme::spacing`], so a globally tightened spacing unit (for example the
+/// `dense_enterprise` preset) compounds with the per-control size.
+///
+/// | [`Size`]  | spacing scale | font size |
+/// |-----------|---------------|-----------|
+/// | `Small`   | `0.75`        | `0.8rem`  |
+/// | `Medium`  | `1.0`         | `1rem`    |
+/// | `Large`   | `1.25`        | `1.2rem`  |
+pub(crate) fn control_spacing_scale(size: &Size) -> f32 {
+    match size {
+        Size::Small => 0.75,
+        Size::Medium => 1.0,
+        Size::Large => 1.25,
+    }
 }
 
-fn sanitise(value: &str) -> String {
-    let mut output = String::new();
-    let mut prev_dash = false;
-
-    for ch in value.chars() {
-        let mapped = match ch {
-            'A'..='Z' => Some(ch.to_ascii_lowercase()),
-            'a'..='z' | '0'..='9' => Some(ch),
-            '-' | '_' | ' ' | ':' | '.' | '/' => None,
-            _ => None,
-        };
-
-        if let Some(valid) = mapped {
-            output.push(valid);
-            prev_dash = false;
-        } else if !prev_dash {
-            output.push('-');
-            prev_dash = true;
-        }
+/// Font size aligned with the Material defaults for each control size. The
+/// values mirror the long-standing `text_field` ramp so mixed forms stay
+/// visually cohesive; see [`control_spacing_scale`] for the full mapping table.
+pub(crate) fn control_font_size(size: &Size) -> &'static str {
+    match size {
+        Size::Small => "0.8rem",
+        Size::Medium => "1rem",
+        Size::Large => "1.2rem",
     }
+}
 
-    let trimmed = output.trim_matches('-').to_string();
+/// Resolve a spacing token scaled by the control size into a `px` string ready
+/// for interpolation inside `css_with_theme!` blocks.
+pub(crate) fn control_spacing_px(theme: &Theme, size: &Size, factor: u16) -> String {
+    format!(
+        "{:.1}px",
+        f32::from(theme.spacing(factor)) * control_spacing_scale(size)
+    )
+}
 
-    if trimmed.is_empty() {
-        String::from("component")
-    } else {
-        trimmed
-    }
+/// `outline` value for `:focus-visible` rings resolved from the theme focus
+/// tokens (thickness, template and palette channel).
+///
+/// Components interpolate this together with [`focus_outline_offset`] instead
+/// of assembling outline CSS by hand, which keeps ring thickness, offset and
+/// colour consistent across every interactive Material surface.  See
+/// [`Theme::focus_ring_declarations`] for the token contract.
+pub(crate) fn focus_outline(theme: &Theme) -> String {
+    theme
+        .joy
+        .focus_outline_for_color(&theme.joy.focus_color_from_palette(theme.palette.active()))
+}
+
+/// `outline-offset` companion to [`focus_outline`].
+pub(crate) fn focus_outline_offset(theme: &Theme) -> String {
+    format!("{}px", theme.joy.focus.offset)
 }
 
 #[cfg(test)]
@@ -185,14 +206,39 @@ mod tests {
     }
 
     #[test]
-    fn automation_id_sanitises_segments() {
-        let id = automation_id("Select", Some("Team Menu"), ["Trigger", "Primary"]);
-        assert_eq!(id, "rustic-select-team-menu-trigger-primary");
+    fn component_overrides_append_a_second_scoped_class() {
+        let mut theme = Theme::default();
+        theme.components.set(
+            "button",
+            rustic_ui_styled_engine::ComponentOverride::new()
+                .with_style_override("text-transform: uppercase;"),
+        );
+
+        let style =
+            Style::new(css!("color: red;")).expect("css! macro should produce a valid style");
+        let attrs = component_themed_attributes(&theme, "button", style, [("role", "button")]);
+        assert_eq!(attrs[0].0, "class");
+        // Base class plus the compiled override class, in cascade order.
+        assert_eq!(attrs[0].1.split(' ').count(), 2);
+
+        // Slots without an override keep the single base class.
+        let style =
+            Style::new(css!("color: red;")).expect("css! macro should produce a valid style");
+        let attrs = component_themed_attributes(&theme, "chip", style, [("role", "button")]);
+        assert_eq!(attrs[0].1.split(' ').count(), 1);
     }
 
     #[test]
-    fn automation_data_attr_excludes_user_segment() {
-        let attr = automation_data_attr("tooltip", ["surface"]);
-        assert_eq!(attr, "data-rustic-tooltip-surface");
+    fn control_density_scales_spacing_tokens() {
+        let theme = Theme::default();
+        assert_eq!(
+            control_spacing_px(&theme, &Size::Medium, 2),
+            format!("{:.1}px", f32::from(theme.spacing(2)))
+        );
+        assert_eq!(
+            control_spacing_px(&theme, &Size::Small, 2),
+            format!("{:.1}px", f32::from(theme.spacing(2)) * 0.75)
+        );
+        assert_eq!(control_font_size(&Size::Large), "1.2rem");
     }
 }
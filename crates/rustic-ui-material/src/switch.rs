@@ -6,35 +6,49 @@
 use rustic_ui_headless::switch::SwitchState;
 use rustic_ui_styled_engine::{css_with_theme, Style};
 
+use crate::macros::Size;
 use crate::selection_control;
 
 #[derive(Clone, Debug)]
 pub struct SwitchProps {
     /// Human friendly label rendered adjacent to the switch track.
     pub label: String,
+    /// Density preset scaling the track and thumb; see
+    /// [`style_helpers::control_spacing_scale`](crate::style_helpers) for the
+    /// mapping to the theme density tokens.
+    pub size: Size,
 }
 
 impl SwitchProps {
     pub fn new(label: impl Into<String>) -> Self {
         Self {
             label: label.into(),
+            size: Size::Medium,
         }
     }
+
+    /// Override the density preset, compacting or enlarging the control.
+    pub fn with_size(mut self, size: Size) -> Self {
+        self.size = size;
+        self
+    }
 }
 
 fn render_html(props: &SwitchProps, state: &SwitchState) -> String {
-    let attrs = state
+    let mut attrs: Vec<(String, String)> = state
         .aria_attributes()
         .into_iter()
         .map(|(k, v)| (k.to_string(), v))
         .collect();
-    selection_control::render_toggle(&props.label, themed_switch_style(), attrs)
+    attrs.push(("data-size".into(), props.size.as_str().into()));
+    selection_control::render_toggle(&props.label, themed_switch_style(&props.size), attrs)
 }
 
 /// Builds the switch track and thumb styling from the active theme tokens. By
 /// leaning on `css_with_theme!` we avoid scattering literal values and keep the
-/// component responsive to palette or spacing overrides.
-fn themed_switch_style() -> Style {
+/// component responsive to palette or spacing overrides. The `size` preset
+/// scales the track and thumb through the shared density helpers.
+fn themed_switch_style(size: &Size) -> Style {
     css_with_theme!(
         r#"
         display: inline-flex;
@@ -79,8 +93,8 @@ fn themed_switch_style() -> Style {
         }
 
         &[data-focus-visible='true'] {
-            outline: ${focus_outline_width} solid ${focus_outline_color};
-            outline-offset: 2px;
+            outline: ${focus_outline};
+            outline-offset: ${focus_outline_offset};
         }
 
         &[aria-disabled='true'] {
@@ -88,22 +102,22 @@ fn themed_switch_style() -> Style {
             opacity: 0.38;
         }
     "#,
-        gap = format!("{}px", theme.spacing(1)),
+        gap = crate::style_helpers::control_spacing_px(&theme, size, 1),
         font_family = theme.typography.font_family.clone(),
-        text_color = theme.palette.text_primary.clone(),
+        text_color = theme.palette.active().text_primary.clone(),
         padding_y = format!("{}px", theme.spacing(0)),
         padding_x = format!("{}px", theme.spacing(0)),
-        track_width = format!("{}px", theme.spacing(4)),
-        track_height = format!("{}px", theme.spacing(1)),
+        track_width = crate::style_helpers::control_spacing_px(&theme, size, 4),
+        track_height = crate::style_helpers::control_spacing_px(&theme, size, 1),
         track_radius = format!("{}px", theme.spacing(1)),
-        track_off = theme.palette.text_secondary.clone(),
-        track_on = theme.palette.primary.clone(),
-        thumb_size = format!("{}px", theme.spacing(2)),
-        thumb_color = theme.palette.background_paper.clone(),
+        track_off = theme.palette.active().text_secondary.clone(),
+        track_on = theme.palette.active().primary.clone(),
+        thumb_size = crate::style_helpers::control_spacing_px(&theme, size, 2),
+        thumb_color = theme.palette.active().background_paper.clone(),
         thumb_offset = format!("{}px", theme.spacing(0)),
-        thumb_translate = format!("{}px", theme.spacing(2)),
-        focus_outline_width = format!("{}px", theme.joy.focus.thickness),
-        focus_outline_color = theme.palette.primary.clone()
+        thumb_translate = crate::style_helpers::control_spacing_px(&theme, size, 2),
+        focus_outline = crate::style_helpers::focus_outline(&theme),
+        focus_outline_offset = crate::style_helpers::focus_outline_offset(&theme)
     )
 }
 
@@ -167,5 +181,14 @@ mod tests {
         let html = render_html(&props, &state);
         assert!(html.contains(">Notifications<"));
         assert!(html.contains("data-on"));
+        assert!(html.contains("data-size=\"medium\""));
+    }
+
+    #[test]
+    fn render_html_reflects_size_preset() {
+        let props = SwitchProps::new("Compact").with_size(Size::Small);
+        let state = SwitchState::uncontrolled(false, false);
+        let html = render_html(&props, &state);
+        assert!(html.contains("data-size=\"small\""));
     }
 }
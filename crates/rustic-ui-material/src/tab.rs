@@ -97,8 +97,8 @@ fn tab_style(_orientation: TabsOrientation) -> Style {
             height: auto;
         }
         &[data-focused="true"] {
-            outline: ${focus_width} solid ${focus_color};
-            outline-offset: 2px;
+            outline: ${focus_outline};
+            outline-offset: ${focus_outline_offset};
         }
         &:hover {
             background: ${hover_background};
@@ -114,8 +114,8 @@ fn tab_style(_orientation: TabsOrientation) -> Style {
         padding_y_large = format!("{}px", theme.spacing(2)),
         padding_x_large = format!("{}px", theme.spacing(3)),
         min_width = format!("{}px", theme.spacing(10)),
-        color_inactive = theme.palette.text_secondary.clone(),
-        color_active = theme.palette.text_primary.clone(),
+        color_inactive = theme.palette.active().text_secondary.clone(),
+        color_active = theme.palette.active().text_primary.clone(),
         font_family = theme.typography.font_family.clone(),
         font_size_small = format!("{:.3}rem", theme.typography.button),
         font_size_large = format!("{:.3}rem", theme.typography.subtitle1),
@@ -124,13 +124,13 @@ fn tab_style(_orientation: TabsOrientation) -> Style {
         line_height = format!("{:.3}", theme.typography.line_height),
         radius = format!("{}px", theme.joy.radius),
         indicator_thickness = format!("{}px", theme.joy.focus.thickness.max(2)),
-        indicator_color = theme.palette.primary.clone(),
+        indicator_color = theme.palette.active().primary.clone(),
         indicator_radius = format!("{}px", theme.joy.focus.thickness.max(2)),
-        focus_width = format!("{}px", theme.joy.focus.thickness),
-        focus_color = theme.joy.focus_color_from_palette(theme.palette.active()),
+        focus_outline = crate::style_helpers::focus_outline(&theme),
+        focus_outline_offset = crate::style_helpers::focus_outline_offset(&theme),
         hover_background = format!(
             "color-mix(in srgb, {} 12%, transparent)",
-            theme.palette.primary.clone()
+            theme.palette.active().primary.clone()
         ),
         sm = theme.breakpoints.sm,
     )
@@ -78,13 +78,13 @@ fn tab_panel_style(_orientation: TabsOrientation) -> Style {
         padding = format!("{}px", theme.spacing(2)),
         padding_large = format!("{}px", theme.spacing(3)),
         padding_xl = format!("{}px", theme.spacing(4)),
-        background = theme.palette.background_paper.clone(),
-        text_color = theme.palette.text_primary.clone(),
+        background = theme.palette.active().background_paper.clone(),
+        text_color = theme.palette.active().text_primary.clone(),
         radius = format!("{}px", theme.joy.radius),
-        shadow_base = theme.palette.neutral.clone(),
+        shadow_base = theme.palette.active().neutral.clone(),
         divider = format!(
             "1px solid color-mix(in srgb, {} 18%, transparent)",
-            theme.palette.neutral.clone()
+            theme.palette.active().neutral.clone()
         ),
         sm = theme.breakpoints.sm,
         lg = theme.breakpoints.lg,
@@ -14,6 +14,25 @@
 use crate::list::{ListDensity, ListTypography};
 use rustic_ui_headless::list::{ListState, SelectionMode};
 use rustic_ui_styled_engine::{css_with_theme, Style};
+use rustic_ui_virtual::{VirtualWindow, Virtualizer};
+
+/// Width hint resolved into the `<colgroup>` emitted ahead of the header.
+///
+/// Declaring a hint on any column switches the table to `table-layout: fixed`
+/// so the browser honours the declared widths instead of reflowing around cell
+/// content.  That keeps wide enterprise tables stable across SSR output and
+/// post-hydration re-renders.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum TableColumnWidth {
+    /// Let the browser size the column from its content.
+    Auto,
+    /// Reserve a fixed pixel width.
+    Fixed(u16),
+    /// Share the remaining space proportionally with the other flex columns.
+    /// The weight is relative: `Flex(2.0)` claims twice the space of
+    /// `Flex(1.0)`.
+    Flex(f32),
+}
 
 /// Describes a column rendered in the table header.
 #[derive(Clone, Debug, PartialEq)]
@@ -23,6 +42,12 @@ pub struct TableColumn {
     /// Whether the column is numeric. Numeric columns are right aligned and use
     /// tabular numbers for consistent metrics.
     pub numeric: bool,
+    /// Width hint resolved into the `<colgroup>`.
+    pub width: TableColumnWidth,
+    /// Lower bound in pixels forwarded to the column's `<col>` element.
+    pub min_width: Option<u16>,
+    /// Upper bound in pixels forwarded to the column's `<col>` element.
+    pub max_width: Option<u16>,
     /// Stable automation identifier appended to `data-rustic-table-column`.
     pub automation_id: Option<String>,
 }
@@ -33,6 +58,9 @@ impl TableColumn {
         Self {
             header: header.into(),
             numeric: false,
+            width: TableColumnWidth::Auto,
+            min_width: None,
+            max_width: None,
             automation_id: None,
         }
     }
@@ -43,11 +71,34 @@ impl TableColumn {
         self
     }
 
+    /// Overrides the width hint.
+    pub fn with_width(mut self, width: TableColumnWidth) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the minimum pixel width.
+    pub fn with_min_width(mut self, px: u16) -> Self {
+        self.min_width = Some(px);
+        self
+    }
+
+    /// Sets the maximum pixel width.
+    pub fn with_max_width(mut self, px: u16) -> Self {
+        self.max_width = Some(px);
+        self
+    }
+
     /// Overrides the automation identifier suffix.
     pub fn with_automation_id(mut self, id: impl Into<String>) -> Self {
         self.automation_id = Some(id.into());
         self
     }
+
+    /// Whether the column declares any layout hint the `<colgroup>` must carry.
+    fn has_width_hint(&self) -> bool {
+        self.width != TableColumnWidth::Auto || self.min_width.is_some() || self.max_width.is_some()
+    }
 }
 
 /// Represents a single table row.
@@ -84,6 +135,37 @@ impl TableRow {
     }
 }
 
+/// Pinned summary row rendered inside the table's `<tfoot>`.
+///
+/// Cells line up with the declared columns and typically carry aggregate
+/// values (totals, averages, counts) computed by the caller — for example
+/// from the experimental data grid aggregation state — pre-formatted into
+/// display strings.  Footer cells stick to the bottom edge of the scroll
+/// container so the summary stays visible while the body scrolls.
+#[derive(Clone, Debug, PartialEq)]
+pub struct TableSummaryRow {
+    /// Individual summary cell values rendered in column order.
+    pub cells: Vec<String>,
+    /// Optional automation identifier appended to `data-rustic-table-summary`.
+    pub automation_id: Option<String>,
+}
+
+impl TableSummaryRow {
+    /// Convenience constructor mirroring [`TableRow::new`].
+    pub fn new(cells: Vec<String>) -> Self {
+        Self {
+            cells,
+            automation_id: None,
+        }
+    }
+
+    /// Overrides the automation identifier suffix.
+    pub fn with_automation_id(mut self, id: impl Into<String>) -> Self {
+        self.automation_id = Some(id.into());
+        self
+    }
+}
+
 /// Shared props consumed by the table renderer across frameworks.
 #[derive(Clone, Debug, PartialEq)]
 pub struct TableProps {
@@ -101,6 +183,8 @@ pub struct TableProps {
     pub striped: bool,
     /// Selection mode forwarded to the [`ListState`].
     pub selection_mode: SelectionMode,
+    /// Summary rows pinned to the bottom of the table in a `<tfoot>`.
+    pub summary_rows: Vec<TableSummaryRow>,
     /// Optional caption describing the table for assistive technology.
     pub caption: Option<String>,
     /// Optional automation identifier prefix.
@@ -118,6 +202,7 @@ impl TableProps {
             body_typography: ListTypography::Body2,
             striped: true,
             selection_mode: SelectionMode::None,
+            summary_rows: Vec::new(),
             caption: None,
             automation_id: None,
         }
@@ -153,6 +238,12 @@ impl TableProps {
         self
     }
 
+    /// Appends pinned summary rows rendered in the `<tfoot>`.
+    pub fn with_summary_rows(mut self, rows: Vec<TableSummaryRow>) -> Self {
+        self.summary_rows = rows;
+        self
+    }
+
     /// Adds an accessible caption.
     pub fn with_caption(mut self, caption: impl Into<String>) -> Self {
         self.caption = Some(caption.into());
@@ -219,15 +310,128 @@ fn render_html(props: &TableProps, state: &ListState) -> String {
     }
 
     format!(
-        "<table {root_attrs}>{caption}<thead><tr {header_row_attrs}>{headers}</tr></thead><tbody>{rows}</tbody></table>",
+        "<table {root_attrs}>{caption}{colgroup}<thead><tr {header_row_attrs}>{headers}</tr></thead><tbody>{rows}</tbody>{tfoot}</table>",
         caption = caption_html,
+        colgroup = colgroup_markup(props),
         headers = header_cells_html,
         rows = body_rows_html,
+        tfoot = tfoot_markup(props),
     )
 }
 
+/// Render the pinned summary rows into a `<tfoot>`.
+///
+/// Tables without summary rows skip the element entirely so existing markup
+/// stays byte-for-byte identical.  Each cell re-uses the column's numeric
+/// alignment and `headers` reference so screen readers associate the
+/// aggregate with its column header.
+fn tfoot_markup(props: &TableProps) -> String {
+    if props.summary_rows.is_empty() {
+        return String::new();
+    }
+    let mut rows_html = String::new();
+    for (index, summary) in props.summary_rows.iter().enumerate() {
+        let summary_value = summary
+            .automation_id
+            .clone()
+            .map(|id| {
+                crate::style_helpers::automation_id("table", props.automation_id.as_deref(), [id])
+            })
+            .unwrap_or_else(|| {
+                crate::style_helpers::automation_id(
+                    "table",
+                    props.automation_id.as_deref(),
+                    [format!("summary-{index}")],
+                )
+            });
+        let row_attrs = crate::style_helpers::themed_attributes_html(
+            table_footer_row_style(),
+            vec![
+                ("role".to_string(), String::from("row")),
+                (
+                    crate::style_helpers::automation_data_attr("table", ["summary"]),
+                    summary_value,
+                ),
+            ],
+        );
+        let mut cells_html = String::new();
+        for (col_index, column) in props.columns.iter().enumerate() {
+            let cell_value = summary.cells.get(col_index).cloned().unwrap_or_default();
+            let cell_attrs = crate::style_helpers::themed_attributes_html(
+                table_footer_cell_style(),
+                vec![
+                    ("role".to_string(), String::from("gridcell")),
+                    ("data-numeric".to_string(), column.numeric.to_string()),
+                    ("headers".to_string(), column_id(props, col_index)),
+                ],
+            );
+            cells_html.push_str(&format!("<td {cell_attrs}>{cell_value}</td>"));
+        }
+        rows_html.push_str(&format!("<tr {row_attrs}>{cells_html}</tr>"));
+    }
+    format!("<tfoot>{rows_html}</tfoot>")
+}
+
+/// Whether any column declares a width hint that must reach the `<colgroup>`.
+fn has_width_hints(props: &TableProps) -> bool {
+    props.columns.iter().any(TableColumn::has_width_hint)
+}
+
+/// Resolve the column width hints into a `<colgroup>`.
+///
+/// Fixed widths become pixel declarations, flex weights are normalised into
+/// percentages of the combined flex weight, and min/max bounds travel along on
+/// the same `<col>` element.  Tables without hints skip the `<colgroup>`
+/// entirely so existing markup stays byte-for-byte identical.
+fn colgroup_markup(props: &TableProps) -> String {
+    if !has_width_hints(props) {
+        return String::new();
+    }
+    let flex_total: f32 = props
+        .columns
+        .iter()
+        .map(|column| match column.width {
+            TableColumnWidth::Flex(weight) if weight > 0.0 => weight,
+            _ => 0.0,
+        })
+        .sum();
+    let mut cols = String::new();
+    for (index, column) in props.columns.iter().enumerate() {
+        let mut declarations = String::new();
+        match column.width {
+            TableColumnWidth::Auto => {}
+            TableColumnWidth::Fixed(px) => declarations.push_str(&format!("width:{px}px;")),
+            TableColumnWidth::Flex(weight) => {
+                if weight > 0.0 && flex_total > 0.0 {
+                    declarations.push_str(&format!("width:{:.2}%;", weight / flex_total * 100.0));
+                }
+            }
+        }
+        if let Some(px) = column.min_width {
+            declarations.push_str(&format!("min-width:{px}px;"));
+        }
+        if let Some(px) = column.max_width {
+            declarations.push_str(&format!("max-width:{px}px;"));
+        }
+        let style_attr = if declarations.is_empty() {
+            String::new()
+        } else {
+            format!(" style=\"{declarations}\"")
+        };
+        cols.push_str(&format!(
+            "<col {key}=\"{index}\"{style_attr}>",
+            key = crate::style_helpers::automation_data_attr("table", ["col"]),
+        ));
+    }
+    format!("<colgroup>{cols}</colgroup>")
+}
+
 fn automation_base(props: &TableProps) -> String {
-    crate::style_helpers::automation_id("table", props.automation_id.as_deref(), [])
+    crate::style_helpers::automation_id(
+        "table",
+        props.automation_id.as_deref(),
+        crate::style_helpers::NO_SEGMENTS,
+    )
 }
 
 fn column_id(props: &TableProps, index: usize) -> String {
@@ -251,7 +455,7 @@ fn cell_automation_id(props: &TableProps, row: usize, col: usize, column: &Table
         crate::style_helpers::automation_id(
             "table",
             props.automation_id.as_deref(),
-            [id.as_str(), format!("row-{row}")],
+            [id.clone(), format!("row-{row}")],
         )
     } else {
         crate::style_helpers::automation_id(
@@ -266,13 +470,21 @@ fn table_attributes(props: &TableProps, state: &ListState) -> Vec<(String, Strin
     let mut attrs = vec![
         (
             "data-component".to_string(),
-            crate::style_helpers::automation_id("table", None, []),
+            crate::style_helpers::automation_id("table", None, crate::style_helpers::NO_SEGMENTS),
         ),
         (
             "data-density".to_string(),
             props.density.data_value().to_string(),
         ),
         ("data-striped".to_string(), props.striped.to_string()),
+        (
+            "data-layout".to_string(),
+            if has_width_hints(props) {
+                String::from("fixed")
+            } else {
+                String::from("auto")
+            },
+        ),
         ("aria-rowcount".to_string(), props.rows.len().to_string()),
         ("aria-colcount".to_string(), props.columns.len().to_string()),
     ];
@@ -461,18 +673,22 @@ fn table_style(props: &TableProps) -> Style {
         --rustic_ui_table_body_font_size: ${body_size};
         --rustic_ui_table_body_font_weight: ${body_weight};
 
+        &[data-layout='fixed'] {
+            table-layout: fixed;
+        }
+
         &[data-striped='true'] tbody tr:nth-child(even) {
             background: ${striped_bg};
         }
     "#,
-        background = theme.palette.background_paper.clone(),
-        text_color = theme.palette.text_primary.clone(),
+        background = theme.palette.active().background_paper.clone(),
+        text_color = theme.palette.active().text_primary.clone(),
         radius = format!("{}px", theme.joy.radius),
         border_color = format!(
             "color-mix(in srgb, {} 18%, transparent)",
-            theme.palette.text_secondary.clone()
+            theme.palette.active().text_secondary.clone()
         ),
-        padding_y = format!("{}px", theme.spacing(density.vertical_padding())),
+        padding_y = format!("{}px", density.padding_y_px(&theme)),
         padding_x = format!("{}px", theme.spacing(2)),
         header_size = format!("{:.3}rem", props.header_typography.font_size(&theme)),
         header_weight = props.header_typography.font_weight(&theme).to_string(),
@@ -480,7 +696,7 @@ fn table_style(props: &TableProps) -> Style {
         body_weight = props.body_typography.font_weight(&theme).to_string(),
         striped_bg = format!(
             "color-mix(in srgb, {} 8%, transparent)",
-            theme.palette.text_secondary.clone()
+            theme.palette.active().text_secondary.clone()
         ),
     )
 }
@@ -492,7 +708,7 @@ fn table_header_row_style() -> Style {
     "#,
         header_bg = format!(
             "color-mix(in srgb, {} 6%, transparent)",
-            theme.palette.text_secondary.clone()
+            theme.palette.active().text_secondary.clone()
         ),
     )
 }
@@ -513,10 +729,10 @@ fn table_header_cell_style() -> Style {
             text-align: right;
         }
     "#,
-        header_color = theme.palette.text_secondary.clone(),
+        header_color = theme.palette.active().text_secondary.clone(),
         divider = format!(
             "color-mix(in srgb, {} 20%, transparent)",
-            theme.palette.text_secondary.clone()
+            theme.palette.active().text_secondary.clone()
         ),
     )
 }
@@ -542,15 +758,52 @@ fn table_row_style() -> Style {
     "#,
         selected_bg = format!(
             "color-mix(in srgb, {} 12%, transparent)",
-            theme.palette.primary.clone()
+            theme.palette.active().primary.clone()
         ),
-        selected_color = theme.palette.primary.clone(),
-        focus_color = theme.palette.primary.clone(),
+        selected_color = theme.palette.active().primary.clone(),
+        focus_color = theme.palette.active().primary.clone(),
         focus_width = format!("{}px", theme.joy.focus.thickness.max(1)),
         focus_offset = format!("{:.1}px", (theme.joy.focus.thickness as f32) / 2.0),
     )
 }
 
+fn table_footer_row_style() -> Style {
+    css_with_theme!(
+        r#"
+        background: ${footer_bg};
+    "#,
+        footer_bg = theme.palette.active().background_paper.clone(),
+    )
+}
+
+/// Summary cells stick to the bottom of the scroll container so aggregates
+/// stay visible while the body scrolls underneath them.
+fn table_footer_cell_style() -> Style {
+    css_with_theme!(
+        r#"
+        position: sticky;
+        bottom: 0;
+        padding: var(--rustic_ui_table_padding_y) var(--rustic_ui_table_padding_x);
+        font-size: var(--rustic_ui_table_body_font_size);
+        font-weight: var(--rustic_ui_table_header_font_weight);
+        color: ${footer_color};
+        background: ${footer_bg};
+        border-top: 2px solid ${divider};
+
+        &[data-numeric='true'] {
+            text-align: right;
+            font-variant-numeric: tabular-nums;
+        }
+    "#,
+        footer_color = theme.palette.active().text_primary.clone(),
+        footer_bg = theme.palette.active().background_paper.clone(),
+        divider = format!(
+            "color-mix(in srgb, {} 20%, transparent)",
+            theme.palette.active().text_secondary.clone()
+        ),
+    )
+}
+
 fn table_body_cell_style() -> Style {
     css_with_theme!(
         r#"
@@ -566,15 +819,301 @@ fn table_body_cell_style() -> Style {
             font-variant-numeric: tabular-nums;
         }
     "#,
-        body_color = theme.palette.text_primary.clone(),
+        body_color = theme.palette.active().text_primary.clone(),
         divider = format!(
             "color-mix(in srgb, {} 12%, transparent)",
-            theme.palette.text_secondary.clone()
+            theme.palette.active().text_secondary.clone()
         ),
         font_family = theme.typography.font_family.clone(),
     )
 }
 
+/// Scroll-driven loading state for log-viewer style tables.
+///
+/// Wraps a [`Virtualizer`] so only the rows intersecting the viewport are
+/// rendered, and fires a load-more callback once the window drifts within
+/// `threshold_rows` of the last loaded row.  Because new rows are appended
+/// below the current scroll offset the offset itself never moves, which
+/// preserves scroll anchoring without any compensation logic — the machine
+/// simply grows the virtual content underneath the user.
+#[derive(Clone, Debug, PartialEq)]
+pub struct InfiniteTableState {
+    virtualizer: Virtualizer,
+    threshold_rows: usize,
+    placeholder_rows: usize,
+    loading: bool,
+    end_reached: bool,
+}
+
+impl InfiniteTableState {
+    /// Create the state over `row_count` already-loaded rows with a uniform
+    /// row height in pixels.
+    pub fn new(row_count: usize, row_height: f64) -> Self {
+        Self {
+            virtualizer: Virtualizer::new(row_count, row_height),
+            threshold_rows: 10,
+            placeholder_rows: 3,
+            loading: false,
+            end_reached: false,
+        }
+    }
+
+    /// Builder-style viewport override mirroring [`Virtualizer::with_viewport`].
+    pub fn with_viewport(mut self, viewport: f64) -> Self {
+        self.virtualizer.set_viewport(viewport);
+        self
+    }
+
+    /// Overrides how many rows before the end trigger the next load.
+    pub fn with_threshold(mut self, rows: usize) -> Self {
+        self.threshold_rows = rows;
+        self
+    }
+
+    /// Overrides how many placeholder rows render while a load is in flight.
+    pub fn with_placeholder_rows(mut self, rows: usize) -> Self {
+        self.placeholder_rows = rows;
+        self
+    }
+
+    /// Update the viewport after a container resize.
+    pub fn set_viewport(&mut self, viewport: f64) {
+        self.virtualizer.set_viewport(viewport);
+    }
+
+    /// Number of rows currently loaded.
+    pub fn row_count(&self) -> usize {
+        self.virtualizer.item_count()
+    }
+
+    /// Whether a load-more request is currently in flight.
+    pub fn is_loading(&self) -> bool {
+        self.loading
+    }
+
+    /// Whether the data source reported that no further rows exist.
+    pub fn end_reached(&self) -> bool {
+        self.end_reached
+    }
+
+    /// Record a new scroll offset and request more rows when the window nears
+    /// the end of the loaded data.
+    ///
+    /// `request_more` receives the current row count (i.e. the offset the
+    /// data source should load from) and is invoked at most once per load:
+    /// further scrolling is ignored until [`InfiniteTableState::finish_load`]
+    /// runs.
+    pub fn on_scroll<F>(&mut self, offset: f64, mut request_more: F)
+    where
+        F: FnMut(usize),
+    {
+        self.virtualizer.set_scroll_offset(offset);
+        if self.loading || self.end_reached {
+            return;
+        }
+        let count = self.virtualizer.item_count();
+        let last_visible = self
+            .virtualizer
+            .window()
+            .items
+            .iter()
+            .filter(|item| !item.sticky)
+            .map(|item| item.index)
+            .max()
+            .unwrap_or(0);
+        if count == 0 || last_visible + self.threshold_rows + 1 >= count {
+            self.loading = true;
+            request_more(count);
+        }
+    }
+
+    /// Complete an in-flight load with the number of rows that were appended.
+    ///
+    /// Appending zero rows flags the end of the data source so the machine
+    /// stops issuing requests.  The scroll offset is left untouched: appended
+    /// rows only extend the content below it.
+    pub fn finish_load(&mut self, appended: usize) {
+        self.loading = false;
+        if appended == 0 {
+            self.end_reached = true;
+            return;
+        }
+        let count = self.virtualizer.item_count();
+        self.virtualizer.set_item_count(count + appended);
+    }
+
+    /// Snapshot of the rows to render for the current scroll position.
+    pub fn window(&self) -> VirtualWindow {
+        self.virtualizer.window()
+    }
+}
+
+/// Render the table in infinite-scroll mode.
+///
+/// Only the rows covered by the virtual window are materialised; spacer rows
+/// above and below keep the scrollbar honest so the browser's own scroll
+/// anchoring works unmodified.  While a load is in flight, pulsing
+/// placeholder rows render beneath the data to signal progress without
+/// shifting the loaded rows.
+fn render_virtualized_html(
+    props: &TableProps,
+    state: &ListState,
+    scroll: &InfiniteTableState,
+) -> String {
+    let window = scroll.window();
+    let column_count = props.columns.len().max(1);
+
+    let mut body_rows_html = String::new();
+    let rendered: Vec<_> = window
+        .items
+        .iter()
+        .filter(|item| item.index < props.rows.len())
+        .collect();
+    let top = rendered.first().map(|item| item.offset).unwrap_or(0.0);
+    let bottom = rendered
+        .last()
+        .map(|item| window.total_size - item.offset - item.size)
+        .unwrap_or(0.0);
+
+    body_rows_html.push_str(&spacer_row(column_count, "top", top));
+    for item in &rendered {
+        let row = &props.rows[item.index];
+        let row_attrs = crate::style_helpers::themed_attributes_html(
+            table_row_style(),
+            row_attributes(props, state, row, item.index),
+        );
+        body_rows_html.push_str(&format!(
+            "<tr {row_attrs}>{}</tr>",
+            row_markup(props, row, item.index)
+        ));
+    }
+    body_rows_html.push_str(&spacer_row(column_count, "bottom", bottom));
+
+    if scroll.is_loading() {
+        for _ in 0..scroll.placeholder_rows {
+            let attrs = crate::style_helpers::themed_attributes_html(
+                loading_row_style(),
+                vec![
+                    ("role".to_string(), "row".to_string()),
+                    ("aria-hidden".to_string(), "true".to_string()),
+                    ("data-loading-row".to_string(), "true".to_string()),
+                ],
+            );
+            body_rows_html.push_str(&format!(
+                "<tr {attrs}><td colspan=\"{column_count}\"><span class=\"rustic_ui_table_loading_bar\"></span></td></tr>"
+            ));
+        }
+    }
+
+    let root_attrs = crate::style_helpers::themed_attributes_html(
+        table_style(props),
+        table_attributes(props, state),
+    );
+    let caption_html = props
+        .caption
+        .as_ref()
+        .map(|caption| format!("<caption>{caption}</caption>"))
+        .unwrap_or_default();
+    let mut header_cells_html = String::new();
+    for (index, column) in props.columns.iter().enumerate() {
+        let cell_attrs = crate::style_helpers::themed_attributes_html(
+            table_header_cell_style(),
+            header_cell_attributes(props, column, index),
+        );
+        header_cells_html.push_str(&format!("<th {cell_attrs}>{}</th>", column.header));
+    }
+
+    let viewport_attrs = crate::style_helpers::themed_attributes_html(
+        table_viewport_style(),
+        vec![
+            (
+                crate::style_helpers::automation_data_attr("table", ["viewport"]),
+                crate::style_helpers::automation_id(
+                    "table",
+                    props.automation_id.as_deref(),
+                    ["viewport"],
+                ),
+            ),
+            ("data-loading".to_string(), scroll.is_loading().to_string()),
+            (
+                "data-end-reached".to_string(),
+                scroll.end_reached().to_string(),
+            ),
+            (
+                "data-total-size".to_string(),
+                format!("{:.0}", window.total_size),
+            ),
+        ],
+    );
+
+    format!(
+        "<div {viewport_attrs}><table {root_attrs}>{caption}{colgroup}<thead><tr>{headers}</tr></thead><tbody>{rows}</tbody>{tfoot}</table></div>",
+        caption = caption_html,
+        colgroup = colgroup_markup(props),
+        headers = header_cells_html,
+        rows = body_rows_html,
+        tfoot = tfoot_markup(props),
+    )
+}
+
+/// Invisible row reserving the extent of the off-screen rows above or below
+/// the virtual window.
+fn spacer_row(column_count: usize, edge: &str, height: f64) -> String {
+    format!(
+        "<tr aria-hidden=\"true\" data-virtual-spacer=\"{edge}\"><td colspan=\"{column_count}\" style=\"height:{height:.0}px;padding:0;border:0;\"></td></tr>"
+    )
+}
+
+/// Scroll container hosting the virtualized table.
+fn table_viewport_style() -> Style {
+    css_with_theme!(
+        r#"
+        position: relative;
+        overflow-y: auto;
+        height: 100%;
+    "#,
+    )
+}
+
+/// Pulsing placeholder rendered while the next page of rows loads.
+fn loading_row_style() -> Style {
+    css_with_theme!(
+        r#"
+        td {
+            padding: var(--rustic_ui_table_padding_y) var(--rustic_ui_table_padding_x);
+            border-bottom: 1px solid ${divider};
+        }
+
+        .rustic_ui_table_loading_bar {
+            display: block;
+            height: 0.75em;
+            border-radius: ${radius};
+            background: ${pulse_color};
+            animation: rustic-table-loading-pulse ${duration} ease-in-out infinite alternate;
+        }
+
+        @keyframes rustic-table-loading-pulse {
+            from {
+                opacity: 0.35;
+            }
+            to {
+                opacity: 0.9;
+            }
+        }
+    "#,
+        divider = format!(
+            "color-mix(in srgb, {} 12%, transparent)",
+            theme.palette.active().text_secondary.clone()
+        ),
+        radius = format!("{}px", theme.joy.radius),
+        pulse_color = format!(
+            "color-mix(in srgb, {} 16%, transparent)",
+            theme.palette.active().text_secondary.clone()
+        ),
+        duration = format!("{}ms", theme.motion.effective_duration_ms(700).max(1)),
+    )
+}
+
 pub mod yew {
     use super::*;
 
@@ -582,6 +1121,15 @@ pub mod yew {
     pub fn render(props: &TableProps, state: &ListState) -> String {
         super::render_html(props, state)
     }
+
+    /// Render the table in infinite-scroll mode.
+    pub fn render_virtualized(
+        props: &TableProps,
+        state: &ListState,
+        scroll: &InfiniteTableState,
+    ) -> String {
+        super::render_virtualized_html(props, state, scroll)
+    }
 }
 
 pub mod leptos {
@@ -591,6 +1139,15 @@ pub mod leptos {
     pub fn render(props: &TableProps, state: &ListState) -> String {
         super::render_html(props, state)
     }
+
+    /// Render the table in infinite-scroll mode.
+    pub fn render_virtualized(
+        props: &TableProps,
+        state: &ListState,
+        scroll: &InfiniteTableState,
+    ) -> String {
+        super::render_virtualized_html(props, state, scroll)
+    }
 }
 
 pub mod dioxus {
@@ -600,6 +1157,15 @@ pub mod dioxus {
     pub fn render(props: &TableProps, state: &ListState) -> String {
         super::render_html(props, state)
     }
+
+    /// Render the table in infinite-scroll mode.
+    pub fn render_virtualized(
+        props: &TableProps,
+        state: &ListState,
+        scroll: &InfiniteTableState,
+    ) -> String {
+        super::render_virtualized_html(props, state, scroll)
+    }
 }
 
 pub mod sycamore {
@@ -609,6 +1175,15 @@ pub mod sycamore {
     pub fn render(props: &TableProps, state: &ListState) -> String {
         super::render_html(props, state)
     }
+
+    /// Render the table in infinite-scroll mode.
+    pub fn render_virtualized(
+        props: &TableProps,
+        state: &ListState,
+        scroll: &InfiniteTableState,
+    ) -> String {
+        super::render_virtualized_html(props, state, scroll)
+    }
 }
 
 #[cfg(test)]
@@ -642,7 +1217,7 @@ mod tests {
         assert!(attrs.iter().any(|(k, v)| k == "role" && v == "grid"));
         assert!(attrs
             .iter()
-            .any(|(k, v)| k == "aria-activedescendant" && v.starts_with("sample-table")));
+            .any(|(k, v)| k == "aria-activedescendant" && v.contains("sample-table")));
     }
 
     #[test]
@@ -662,4 +1237,143 @@ mod tests {
         assert!(html.contains("<table"));
         assert!(html.contains("rustic-table"));
     }
+
+    #[test]
+    fn standard_density_flows_into_the_data_attribute() {
+        let props = sample_props().with_density(ListDensity::Standard);
+        let state = build_state(props.rows.len());
+        let html = super::render_html(&props, &state);
+        assert!(html.contains("data-density=\"standard\""));
+    }
+
+    #[test]
+    fn width_hints_resolve_into_a_fixed_layout_colgroup() {
+        let props = TableProps::new(
+            vec![
+                TableColumn::new("Id")
+                    .with_width(TableColumnWidth::Fixed(120))
+                    .with_min_width(80),
+                TableColumn::new("Summary").with_width(TableColumnWidth::Flex(2.0)),
+                TableColumn::new("Owner")
+                    .with_width(TableColumnWidth::Flex(1.0))
+                    .with_max_width(400),
+            ],
+            vec![TableRow::new(vec!["1".into(), "a".into(), "b".into()])],
+        );
+        let state = ListState::uncontrolled(props.rows.len(), &[], SelectionMode::None);
+        let html = super::render_html(&props, &state);
+        assert!(html.contains("data-layout=\"fixed\""));
+        assert!(html.contains("<colgroup>"));
+        assert!(html.contains("width:120px;min-width:80px;"));
+        assert!(html.contains("width:66.67%;"));
+        assert!(html.contains("width:33.33%;max-width:400px;"));
+    }
+
+    #[test]
+    fn tables_without_width_hints_skip_the_colgroup() {
+        let props = sample_props();
+        let state = build_state(props.rows.len());
+        let html = super::render_html(&props, &state);
+        assert!(!html.contains("<colgroup>"));
+        assert!(html.contains("data-layout=\"auto\""));
+    }
+
+    #[test]
+    fn summary_rows_render_in_a_tfoot_with_column_alignment() {
+        let props = sample_props().with_summary_rows(vec![TableSummaryRow::new(vec![
+            "Total".into(),
+            "20".into(),
+        ])
+        .with_automation_id("totals")]);
+        let state = build_state(props.rows.len());
+        let html = super::render_html(&props, &state);
+        assert!(html.contains("<tfoot>"));
+        assert!(html.contains("data-rustic-table-summary=\"rustic-table-sample-table-totals\""));
+        // The numeric column's alignment flag carries onto the summary cell.
+        let tfoot = html.split("<tfoot>").nth(1).unwrap();
+        assert!(tfoot.contains("data-numeric=\"true\""));
+        assert!(tfoot.contains(">20</td>"));
+    }
+
+    #[test]
+    fn tables_without_summary_rows_skip_the_tfoot() {
+        let props = sample_props();
+        let state = build_state(props.rows.len());
+        assert!(!super::render_html(&props, &state).contains("<tfoot>"));
+    }
+
+    fn log_props(rows: usize) -> TableProps {
+        TableProps::new(
+            vec![TableColumn::new("Timestamp"), TableColumn::new("Message")],
+            (0..rows)
+                .map(|i| TableRow::new(vec![format!("t{i}"), format!("line {i}")]))
+                .collect(),
+        )
+        .with_automation_id("logs")
+    }
+
+    #[test]
+    fn infinite_scroll_requests_more_rows_once_near_the_end() {
+        let mut scroll = InfiniteTableState::new(100, 20.0)
+            .with_viewport(200.0)
+            .with_threshold(10);
+        let mut requests = Vec::new();
+        scroll.on_scroll(0.0, |from| requests.push(from));
+        assert!(requests.is_empty());
+        // 100 rows * 20px - 200px viewport puts the window at the last rows.
+        scroll.on_scroll(1_800.0, |from| requests.push(from));
+        assert_eq!(requests, vec![100]);
+        assert!(scroll.is_loading());
+        // No duplicate requests while the first one is in flight.
+        scroll.on_scroll(1_800.0, |from| requests.push(from));
+        assert_eq!(requests.len(), 1);
+        scroll.finish_load(50);
+        assert_eq!(scroll.row_count(), 150);
+        assert!(!scroll.is_loading());
+    }
+
+    #[test]
+    fn empty_load_marks_the_end_of_the_data_source() {
+        let mut scroll = InfiniteTableState::new(10, 20.0).with_viewport(400.0);
+        let mut requests = 0;
+        scroll.on_scroll(0.0, |_| requests += 1);
+        assert_eq!(requests, 1);
+        scroll.finish_load(0);
+        assert!(scroll.end_reached());
+        scroll.on_scroll(0.0, |_| requests += 1);
+        assert_eq!(requests, 1);
+    }
+
+    #[test]
+    fn virtualized_render_windows_rows_between_spacers() {
+        let props = log_props(100);
+        let state = ListState::uncontrolled(props.rows.len(), &[], SelectionMode::None);
+        let mut scroll = InfiniteTableState::new(100, 20.0)
+            .with_viewport(200.0)
+            .with_threshold(0);
+        scroll.on_scroll(1_000.0, |_| {});
+        let html = super::render_virtualized_html(&props, &state, &scroll);
+        assert!(html.contains("data-virtual-spacer=\"top\""));
+        assert!(html.contains("data-virtual-spacer=\"bottom\""));
+        assert!(html.contains("line 50"));
+        assert!(!html.contains("line 5<"));
+        assert!(!html.contains("line 95"));
+        assert!(html.contains("data-rustic-table-viewport=\"rustic-table-logs-viewport\""));
+        assert!(html.contains("data-total-size=\"2000\""));
+    }
+
+    #[test]
+    fn loading_placeholders_render_while_a_request_is_in_flight() {
+        let props = log_props(10);
+        let state = ListState::uncontrolled(props.rows.len(), &[], SelectionMode::None);
+        let mut scroll = InfiniteTableState::new(10, 20.0)
+            .with_viewport(400.0)
+            .with_placeholder_rows(2);
+        scroll.on_scroll(0.0, |_| {});
+        assert!(scroll.is_loading());
+        let html = super::render_virtualized_html(&props, &state, &scroll);
+        assert_eq!(html.matches("data-loading-row=\"true\"").count(), 2);
+        assert!(html.contains("data-loading=\"true\""));
+        assert!(html.contains("rustic_ui_table_loading_bar"));
+    }
 }
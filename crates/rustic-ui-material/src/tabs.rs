@@ -211,10 +211,10 @@ fn tab_list_style(_orientation: TabsOrientation) -> Style {
         gap_large = format!("{}px", theme.spacing(2)),
         padding_small = format!("{}px", theme.spacing(1)),
         padding_large = format!("{}px", theme.spacing(2)),
-        background = theme.palette.background_paper.clone(),
+        background = theme.palette.active().background_paper.clone(),
         border_color = format!(
             "color-mix(in srgb, {} 24%, transparent)",
-            theme.palette.neutral.clone()
+            theme.palette.active().neutral.clone()
         ),
         radius = format!("{}px", theme.joy.radius),
         vertical_min_width = format!("{}px", theme.spacing(22)),
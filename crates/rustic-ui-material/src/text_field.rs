@@ -77,14 +77,12 @@ fn compute_parts(
     variant: TextFieldVariant,
 ) -> (String, &'static str, String) {
     let color = match color {
-        TextFieldColor::Primary => theme.palette.primary.clone(),
-        TextFieldColor::Secondary => theme.palette.secondary.clone(),
-    };
-    let font_size = match size {
-        TextFieldSize::Small => "0.8rem",
-        TextFieldSize::Medium => "1rem",
-        TextFieldSize::Large => "1.2rem",
+        TextFieldColor::Primary => theme.palette.active().primary.clone(),
+        TextFieldColor::Secondary => theme.palette.active().secondary.clone(),
     };
+    // The ramp is centralised in `style_helpers` so every sized control
+    // (text fields, selects, toggles, chips) compacts consistently.
+    let font_size = crate::style_helpers::control_font_size(&size);
     let border = match variant {
         TextFieldVariant::Outlined => format!("1px solid {}", color.clone()),
         TextFieldVariant::Contained => format!("1px solid {}", color.clone()),
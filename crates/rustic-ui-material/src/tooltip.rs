@@ -34,7 +34,7 @@
 //! use rustic_ui_styled_engine::{StyleRegistry, Theme};
 //!
 //! let mut theme = Theme::default();
-//! theme.palette.primary = "#0057B7".into();
+//! theme.palette.light.primary = "#0057B7".into();
 //! let registry = StyleRegistry::new(theme.clone());
 //!
 //! let mut state = TooltipState::new(TooltipConfig::enterprise_defaults());
@@ -160,7 +160,11 @@ fn render_html(props: &TooltipProps, state: &TooltipState) -> String {
 
 /// Resolve the base automation identifier used to derive ids and data hooks.
 fn automation_base(props: &TooltipProps) -> String {
-    crate::style_helpers::automation_id("tooltip", props.automation_id.as_deref(), [])
+    crate::style_helpers::automation_id(
+        "tooltip",
+        props.automation_id.as_deref(),
+        crate::style_helpers::NO_SEGMENTS,
+    )
 }
 
 /// Compute the DOM id for the trigger element.
@@ -203,7 +207,7 @@ fn root_attributes(
     ));
     attrs.push((
         "data-component".into(),
-        crate::style_helpers::automation_id("tooltip", None, []),
+        crate::style_helpers::automation_id("tooltip", None, crate::style_helpers::NO_SEGMENTS),
     ));
     attrs.push(("data-visible".into(), state.visible().to_string()));
     attrs.push((
@@ -350,8 +354,8 @@ fn themed_trigger_style() -> Style {
         }
 
         &:focus-visible {
-            outline: ${focus_outline_width} solid ${focus_outline_color};
-            outline-offset: 2px;
+            outline: ${focus_outline};
+            outline-offset: ${focus_outline_offset};
         }
     "#,
         gap = format!("{}px", theme.spacing(1) / 2),
@@ -360,19 +364,20 @@ fn themed_trigger_style() -> Style {
         radius = format!("{}px", theme.joy.radius),
         border_color = format!(
             "color-mix(in srgb, {} 32%, transparent)",
-            theme.palette.text_secondary.clone()
+            theme.palette.active().text_secondary.clone()
         ),
-        background = theme.palette.background_paper.clone(),
-        text_color = theme.palette.text_primary.clone(),
+        background = theme.palette.active().background_paper.clone(),
+        text_color = theme.palette.active().text_primary.clone(),
         font_family = theme.typography.font_family.clone(),
         font_size = format!("{:.3}rem", theme.typography.body2),
         line_height = format!("{:.2}", theme.typography.line_height),
-        hover_color = theme.palette.primary.clone(),
+        hover_color = theme.palette.active().primary.clone(),
         focus_outline_width = format!("{:.1}px", (theme.joy.focus.thickness as f32).max(1.0)),
-        focus_outline_color = theme.palette.primary.clone(),
+        focus_outline = crate::style_helpers::focus_outline(&theme),
+        focus_outline_offset = crate::style_helpers::focus_outline_offset(&theme),
         focus_outline_color_transparent = format!(
             "color-mix(in srgb, {} 28%, transparent)",
-            theme.palette.primary.clone()
+            theme.palette.active().primary.clone()
         )
     )
 }
@@ -413,9 +418,9 @@ fn themed_surface_style() -> Style {
         radius = format!("{}px", theme.joy.radius),
         background = format!(
             "color-mix(in srgb, {} 92%, transparent)",
-            theme.palette.neutral.clone()
+            theme.palette.active().neutral.clone()
         ),
-        text_color = theme.palette.background_paper.clone(),
+        text_color = theme.palette.active().background_paper.clone(),
         shadow = "0px 8px 24px rgba(15, 23, 42, 0.25)".to_string(),
         font_family = theme.typography.font_family.clone(),
         font_size = format!("{:.3}rem", theme.typography.caption),
@@ -482,7 +487,7 @@ mod tests {
         let state = TooltipState::new(TooltipConfig::default());
         let html = super::render_html(&props, &state);
 
-        assert!(html.contains("data-component=\"rustic_ui_tooltip\""));
+        assert!(html.contains("data-component=\"rustic-tooltip\""));
         assert!(html.contains("aria-describedby"));
         assert!(html.contains("role=\"tooltip\""));
         assert!(html.contains("data-portal-layer=\"popover\""));
@@ -495,9 +500,9 @@ mod tests {
         let attrs = super::trigger_attributes(
             &props,
             &state,
-            &tooltip_portal("rustic_ui_tooltip"),
-            &trigger_id("rustic_ui_tooltip"),
-            &surface_id("rustic_ui_tooltip"),
+            &tooltip_portal(&props),
+            &trigger_id(&props),
+            &surface_id(&props),
         );
 
         assert!(attrs.iter().any(|(k, _)| k == "aria-expanded"));
@@ -0,0 +1,342 @@
+//! Spotlight renderer for the headless onboarding
+//! [`TourState`](rustic_ui_headless::tour::TourState).
+//!
+//! The machine owns step sequencing and anchor geometry; this module draws
+//! the spotlight — a fixed overlay whose cutout tracks the active step's
+//! anchor via an oversized box shadow — and the anchored card with the
+//! step copy, progress footer and Back/Next/Skip intents exposed as
+//! `data-tour-action` hooks.  Completion events are persisted through
+//! [`rustic_ui_utils::storage`] so a finished or skipped tour never
+//! replays on the next visit; hosts call [`is_complete`] before starting
+//! the machine and [`persist_completion`] when it emits
+//! [`TourCompletion`].
+
+use rustic_ui_headless::tour::{TourCompletion, TourState};
+use rustic_ui_styled_engine::{css_with_theme, Style};
+use rustic_ui_utils::storage;
+
+/// Copy rendered inside the card for one tour step, parallel to the
+/// machine's step list.
+#[derive(Clone, Debug, PartialEq)]
+pub struct TourStepContent {
+    /// Short heading, e.g. "Saved filters".
+    pub title: String,
+    /// Body copy explaining the highlighted surface.
+    pub body: String,
+}
+
+impl TourStepContent {
+    /// Convenience constructor used by examples and tests.
+    pub fn new(title: impl Into<String>, body: impl Into<String>) -> Self {
+        Self {
+            title: title.into(),
+            body: body.into(),
+        }
+    }
+}
+
+/// Shared properties accepted by all adapter implementations.
+#[derive(Clone, Debug, PartialEq)]
+pub struct TourProps {
+    /// Stable identifier of the tour, also used as the persistence key.
+    pub tour_id: String,
+    /// Card copy per step, in machine step order.
+    pub steps: Vec<TourStepContent>,
+    /// Optional automation identifier for analytics and end-to-end tests.
+    pub automation_id: Option<String>,
+}
+
+impl TourProps {
+    /// Convenience constructor used by examples and tests.
+    pub fn new(tour_id: impl Into<String>, steps: Vec<TourStepContent>) -> Self {
+        Self {
+            tour_id: tour_id.into(),
+            steps,
+            automation_id: None,
+        }
+    }
+
+    /// Sets the automation identifier stamped on the rendered hooks.
+    pub fn with_automation_id(mut self, id: impl Into<String>) -> Self {
+        self.automation_id = Some(id.into());
+        self
+    }
+}
+
+/// Storage key recording a tour's terminal status.
+fn completion_key(tour_id: &str) -> String {
+    format!("rustic.tour.{tour_id}")
+}
+
+/// Returns whether the tour already completed on this device.  Platforms
+/// without a storage provider report `false` so the tour simply runs.
+pub fn is_complete(tour_id: &str) -> bool {
+    matches!(storage::get_item(&completion_key(tour_id)), Ok(Some(_)))
+}
+
+/// Persists a completion event emitted by the machine.  Storage failures
+/// are swallowed: replaying a tour is preferable to surfacing an error in
+/// an onboarding flow.
+pub fn persist_completion(tour_id: &str, completion: TourCompletion) {
+    let status = match completion {
+        TourCompletion::Finished => "finished",
+        TourCompletion::Skipped { .. } => "skipped",
+    };
+    let _ = storage::set_item(&completion_key(tour_id), status);
+}
+
+/// Clears the persisted completion so the tour replays, e.g. from a "show
+/// me around again" help menu entry.
+pub fn reset_completion(tour_id: &str) {
+    let _ = storage::remove_item(&completion_key(tour_id));
+}
+
+/// Shared rendering routine used by all adapters.  Returns an empty
+/// string while the tour is not active so the overlay drops out of the
+/// DOM entirely.
+fn render_html(props: &TourProps, state: &TourState) -> String {
+    let Some(index) = state.active_index() else {
+        return String::new();
+    };
+    let Some(content) = props.steps.get(index) else {
+        return String::new();
+    };
+    let step = &state.steps()[index];
+    let attrs = crate::style_helpers::themed_attributes_html(
+        themed_tour_style(),
+        vec![
+            ("role".to_string(), String::from("dialog")),
+            ("aria-modal".to_string(), String::from("false")),
+            (
+                "aria-label".to_string(),
+                crate::render::escape_text(&content.title),
+            ),
+            (
+                "data-tour-step".to_string(),
+                crate::render::escape_text(step.id()),
+            ),
+            (
+                crate::style_helpers::automation_data_attr("tour", ["root"]),
+                crate::style_helpers::automation_id(
+                    "tour",
+                    props.automation_id.as_deref(),
+                    ["root"],
+                ),
+            ),
+        ],
+    );
+    // The spotlight cutout sits exactly over the anchor; the oversized box
+    // shadow dims everything else.  Unmeasured anchors collapse the cutout
+    // so only the card shows.
+    let spotlight = step
+        .anchor()
+        .map(|anchor| {
+            format!(
+                "<div data-tour-spotlight=\"\" style=\"left:{x:.1}px;top:{y:.1}px;\
+                 width:{width:.1}px;height:{height:.1}px;\"></div>",
+                x = anchor.x,
+                y = anchor.y,
+                width = anchor.width,
+                height = anchor.height,
+            )
+        })
+        .unwrap_or_else(|| "<div data-tour-spotlight=\"\" hidden></div>".to_string());
+    let back_disabled = if index == 0 { " disabled" } else { "" };
+    let next_label = if index + 1 == state.steps().len() {
+        "Finish"
+    } else {
+        "Next"
+    };
+    format!(
+        "<div {attrs}>{spotlight}\
+         <section data-tour-card=\"\">\
+         <h2>{title}</h2>\
+         <p>{body}</p>\
+         <footer>\
+         <span data-tour-progress=\"\" aria-live=\"polite\">{progress}</span>\
+         <button type=\"button\" data-tour-action=\"skip\">Skip</button>\
+         <button type=\"button\" data-tour-action=\"back\"{back_disabled}>Back</button>\
+         <button type=\"button\" data-tour-action=\"next\">{next_label}</button>\
+         </footer>\
+         </section></div>",
+        title = crate::render::escape_text(&content.title),
+        body = crate::render::escape_text(&content.body),
+        progress = state.progress(),
+    )
+}
+
+/// Overlay, spotlight and card styling pulled from the active theme.
+fn themed_tour_style() -> Style {
+    css_with_theme!(
+        r#"
+        position: fixed;
+        inset: 0;
+        z-index: 1400;
+        font-family: ${font_family};
+        color: ${text};
+
+        & [data-tour-spotlight] {
+            position: absolute;
+            border-radius: ${radius};
+            box-shadow: 0 0 0 9999px rgba(0, 0, 0, 0.5);
+            pointer-events: none;
+        }
+
+        & [data-tour-card] {
+            position: absolute;
+            max-width: 320px;
+            padding: ${padding};
+            border-radius: ${radius};
+            background: ${surface};
+            box-shadow: 0 8px 24px rgba(0, 0, 0, 0.2);
+        }
+
+        & h2 {
+            margin: 0 0 ${gap} 0;
+            font-size: 1rem;
+        }
+
+        & p {
+            margin: 0 0 ${gap} 0;
+            font-size: 0.875rem;
+        }
+
+        & footer {
+            display: flex;
+            align-items: center;
+            gap: ${gap};
+        }
+
+        & [data-tour-progress] {
+            flex: 1;
+            font-size: 0.75rem;
+            color: ${secondary};
+        }
+    "#,
+        font_family = theme.typography.font_family.clone(),
+        text = theme.palette.active().text_primary.clone(),
+        radius = format!("{}px", theme.joy.radius),
+        padding = format!("{}px", theme.spacing(2)),
+        surface = theme.palette.active().background_paper.clone(),
+        gap = format!("{}px", theme.spacing(1)),
+        secondary = theme.palette.active().text_secondary.clone(),
+    )
+}
+
+// ---------------------------------------------------------------------------
+// Adapter implementations
+// ---------------------------------------------------------------------------
+
+pub mod yew {
+    use super::*;
+
+    /// Render the tour spotlight into a plain HTML string for SSR/hydration.
+    pub fn render(props: &TourProps, state: &TourState) -> String {
+        super::render_html(props, state)
+    }
+}
+
+pub mod leptos {
+    use super::*;
+
+    /// Render the tour spotlight into a plain HTML string for SSR/hydration.
+    pub fn render(props: &TourProps, state: &TourState) -> String {
+        super::render_html(props, state)
+    }
+}
+
+pub mod dioxus {
+    use super::*;
+
+    /// Render the tour spotlight into a plain HTML string for SSR/hydration.
+    pub fn render(props: &TourProps, state: &TourState) -> String {
+        super::render_html(props, state)
+    }
+}
+
+pub mod sycamore {
+    use super::*;
+
+    /// Render the tour spotlight into a plain HTML string for SSR/hydration.
+    pub fn render(props: &TourProps, state: &TourState) -> String {
+        super::render_html(props, state)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rustic_ui_headless::popover::AnchorGeometry;
+    use rustic_ui_headless::tour::TourStep;
+
+    fn sample() -> (TourProps, TourState) {
+        let props = TourProps::new(
+            "workspace",
+            vec![
+                TourStepContent::new("Search", "Find anything across the workspace."),
+                TourStepContent::new("Export", "Download the current view."),
+            ],
+        )
+        .with_automation_id("onboarding");
+        let state = TourState::new(vec![TourStep::new("search"), TourStep::new("export")]);
+        (props, state)
+    }
+
+    #[test]
+    fn inactive_tours_render_nothing() {
+        let (props, state) = sample();
+        assert_eq!(render_html(&props, &state), "");
+    }
+
+    #[test]
+    fn spotlight_tracks_the_active_anchor() {
+        let (props, mut state) = sample();
+        state.start();
+        state.set_anchor(
+            "search",
+            AnchorGeometry {
+                x: 12.0,
+                y: 34.0,
+                width: 200.0,
+                height: 40.0,
+            },
+        );
+        let html = render_html(&props, &state);
+        assert!(html.contains("data-tour-step=\"search\""));
+        assert!(html.contains("left:12.0px;top:34.0px;width:200.0px;height:40.0px;"));
+        assert!(html.contains("<h2>Search</h2>"));
+        assert!(html.contains(">1 of 2<"));
+        assert!(html.contains("data-tour-action=\"back\" disabled>"));
+        assert!(html.contains("data-tour-action=\"next\">Next<"));
+        assert!(html.contains("data-rustic-tour-root=\"rustic-tour-onboarding-root\""));
+    }
+
+    #[test]
+    fn final_step_offers_finish() {
+        let (props, mut state) = sample();
+        state.start();
+        state.advance();
+        let html = render_html(&props, &state);
+        assert!(html.contains("data-tour-action=\"next\">Finish<"));
+        // Anchors were never measured, so the spotlight collapses.
+        assert!(html.contains("data-tour-spotlight=\"\" hidden"));
+    }
+
+    #[test]
+    fn completion_round_trips_through_storage() {
+        use rustic_ui_utils::storage::MemoryStorage;
+        use std::sync::Arc;
+
+        let provider = Arc::new(MemoryStorage::default());
+        storage::install_provider(provider.clone());
+        assert!(!is_complete("workspace-tour-test"));
+        persist_completion("workspace-tour-test", TourCompletion::Finished);
+        assert!(is_complete("workspace-tour-test"));
+        assert_eq!(
+            provider.entries().get("rustic.tour.workspace-tour-test"),
+            Some(&"finished".to_string())
+        );
+        reset_completion("workspace-tour-test");
+        assert!(!is_complete("workspace-tour-test"));
+    }
+}
@@ -0,0 +1,383 @@
+//! Paired-card transfer list driven by the headless
+//! [`ListboxState`](rustic_ui_headless::listbox::ListboxState).
+//!
+//! Permission-assignment screens show two listbox panes side by side with
+//! move buttons in between.  All selection arithmetic — shift ranges,
+//! tri-state select-all, announcement strings — lives in the headless
+//! machine; this renderer lays out the cards, stamps the machine's intents
+//! onto `data-transfer-action` hooks so adapters can delegate clicks
+//! without per-button closures, and derives the checked counts shown in
+//! each card header.  Every item carries a deterministic automation id so
+//! end-to-end suites can target "third item in the source pane" without
+//! brittle selectors.
+
+use rustic_ui_headless::listbox::ListboxState;
+use rustic_ui_styled_engine::{css_with_theme, Style};
+
+/// Shared properties accepted by all adapter implementations.
+#[derive(Clone, Debug, PartialEq)]
+pub struct TransferListProps {
+    /// Heading of the source pane, e.g. "Available permissions".
+    pub source_title: String,
+    /// Heading of the target pane, e.g. "Granted permissions".
+    pub target_title: String,
+    /// Optional automation identifier for analytics and end-to-end tests.
+    pub automation_id: Option<String>,
+}
+
+impl TransferListProps {
+    /// Convenience constructor used by examples and tests.
+    pub fn new(source_title: impl Into<String>, target_title: impl Into<String>) -> Self {
+        Self {
+            source_title: source_title.into(),
+            target_title: target_title.into(),
+            automation_id: None,
+        }
+    }
+
+    /// Sets the automation identifier stamped on the rendered hooks.
+    pub fn with_automation_id(mut self, id: impl Into<String>) -> Self {
+        self.automation_id = Some(id.into());
+        self
+    }
+}
+
+/// One pane of the transfer list: the rendered labels plus the listbox
+/// machine owning their selection.
+pub struct TransferPane<'a> {
+    items: &'a [String],
+    state: &'a ListboxState,
+}
+
+impl<'a> TransferPane<'a> {
+    /// Bundles a pane's labels with its selection machine.  The slice
+    /// length is expected to match [`ListboxState::item_count`].
+    pub fn new(items: &'a [String], state: &'a ListboxState) -> Self {
+        Self { items, state }
+    }
+}
+
+/// Shared rendering routine used by all adapters.
+fn render_html(props: &TransferListProps, source: &TransferPane, target: &TransferPane) -> String {
+    let attrs = crate::style_helpers::themed_attributes_html(
+        themed_transfer_list_style(),
+        vec![
+            (
+                "data-component".to_string(),
+                crate::style_helpers::automation_id(
+                    "transfer-list",
+                    None,
+                    crate::style_helpers::NO_SEGMENTS,
+                ),
+            ),
+            (
+                crate::style_helpers::automation_data_attr("transfer-list", ["root"]),
+                crate::style_helpers::automation_id(
+                    "transfer-list",
+                    props.automation_id.as_deref(),
+                    ["root"],
+                ),
+            ),
+        ],
+    );
+    let source_pane = pane_html(props, "source", &props.source_title, source);
+    let target_pane = pane_html(props, "target", &props.target_title, target);
+    let controls = controls_html(props, source, target);
+    format!("<div {attrs}>{source_pane}{controls}{target_pane}</div>")
+}
+
+/// Renders one card: header with tri-state checkbox and checked count,
+/// then the multiselect listbox itself.
+fn pane_html(props: &TransferListProps, side: &str, title: &str, pane: &TransferPane) -> String {
+    let title = crate::render::escape_text(title);
+    let selected = pane.state.selection().len();
+    let total = pane.state.item_count();
+    let checked = pane.state.select_all_state().aria_checked();
+    let items: String = pane
+        .items
+        .iter()
+        .enumerate()
+        .map(|(index, label)| {
+            let automation = crate::style_helpers::automation_id(
+                "transfer-list",
+                props.automation_id.as_deref(),
+                [side, index.to_string().as_str()],
+            );
+            format!(
+                "<li role=\"option\" aria-selected=\"{selected}\" data-index=\"{index}\" \
+                 {attr}=\"{automation}\">{label}</li>",
+                selected = pane.state.is_selected(index),
+                attr = crate::style_helpers::automation_data_attr("transfer-list", ["item"]),
+                label = crate::render::escape_text(label),
+            )
+        })
+        .collect();
+    format!(
+        "<section data-transfer-pane=\"{side}\">\
+         <header>\
+         <span role=\"checkbox\" tabindex=\"0\" aria-checked=\"{checked}\" \
+         aria-label=\"Select all {title}\" data-transfer-action=\"toggle-select-all\" \
+         data-transfer-pane=\"{side}\"></span>\
+         <h3>{title}</h3>\
+         <span data-transfer-count=\"{selected}\">{selected}/{total} selected</span>\
+         </header>\
+         <ul role=\"listbox\" aria-multiselectable=\"true\" aria-label=\"{title}\">{items}</ul>\
+         </section>"
+    )
+}
+
+/// Renders the move button column.  Buttons map one-to-one onto machine
+/// intents and disable themselves when the intent would be a no-op.
+fn controls_html(
+    props: &TransferListProps,
+    source: &TransferPane,
+    target: &TransferPane,
+) -> String {
+    let buttons = [
+        (
+            "move-all-to-target",
+            "\u{226b}",
+            format!("Move all to {}", props.target_title),
+            source.state.item_count() == 0,
+        ),
+        (
+            "move-selected-to-target",
+            "\u{3e}",
+            format!("Move selected to {}", props.target_title),
+            source.state.selection().is_empty(),
+        ),
+        (
+            "move-selected-to-source",
+            "\u{3c}",
+            format!("Move selected to {}", props.source_title),
+            target.state.selection().is_empty(),
+        ),
+        (
+            "move-all-to-source",
+            "\u{226a}",
+            format!("Move all to {}", props.source_title),
+            target.state.item_count() == 0,
+        ),
+    ];
+    let rendered: String = buttons
+        .into_iter()
+        .map(|(action, glyph, label, disabled)| {
+            let disabled = if disabled { " disabled" } else { "" };
+            format!(
+                "<button type=\"button\" data-transfer-action=\"{action}\" \
+                 aria-label=\"{label}\"{disabled}>{glyph}</button>",
+                label = crate::render::escape_text(&label),
+            )
+        })
+        .collect();
+    format!("<div data-transfer-controls=\"\">{rendered}</div>")
+}
+
+/// Paired-card layout styling pulled from the active theme tokens.
+fn themed_transfer_list_style() -> Style {
+    css_with_theme!(
+        r#"
+        display: flex;
+        align-items: stretch;
+        gap: ${gap};
+        font-family: ${font_family};
+        color: ${text};
+
+        & section {
+            flex: 1;
+            display: flex;
+            flex-direction: column;
+            border: 1px solid ${border};
+            border-radius: 4px;
+            background: ${surface};
+        }
+
+        & header {
+            display: flex;
+            align-items: center;
+            gap: ${header_gap};
+            padding: ${header_padding};
+            border-bottom: 1px solid ${border};
+        }
+
+        & h3 {
+            margin: 0;
+            flex: 1;
+            font-size: 1rem;
+        }
+
+        & [data-transfer-count] {
+            font-size: 0.75rem;
+            color: ${secondary};
+        }
+
+        & ul {
+            margin: 0;
+            padding: 0;
+            list-style: none;
+            overflow-y: auto;
+        }
+
+        & li {
+            padding: ${item_padding};
+            cursor: pointer;
+        }
+
+        & li[aria-selected='true'] {
+            background: ${selected_bg};
+        }
+
+        & [data-transfer-controls] {
+            display: flex;
+            flex-direction: column;
+            justify-content: center;
+            gap: ${header_gap};
+        }
+    "#,
+        gap = format!("{}px", theme.spacing(2)),
+        font_family = theme.typography.font_family.clone(),
+        text = theme.palette.active().text_primary.clone(),
+        border = theme.palette.active().neutral.clone(),
+        surface = theme.palette.active().background_paper.clone(),
+        secondary = theme.palette.active().text_secondary.clone(),
+        header_gap = format!("{}px", theme.spacing(1)),
+        header_padding = format!("{}px", theme.spacing(1)),
+        item_padding = format!("{}px {}px", theme.spacing(1), theme.spacing(2)),
+        selected_bg = theme.palette.active().neutral.clone(),
+    )
+}
+
+// ---------------------------------------------------------------------------
+// Adapter implementations
+// ---------------------------------------------------------------------------
+
+pub mod yew {
+    use super::*;
+
+    /// Render the transfer list into a plain HTML string for SSR/hydration.
+    pub fn render(
+        props: &TransferListProps,
+        source: &TransferPane,
+        target: &TransferPane,
+    ) -> String {
+        super::render_html(props, source, target)
+    }
+}
+
+pub mod leptos {
+    use super::*;
+
+    /// Render the transfer list into a plain HTML string for SSR/hydration.
+    pub fn render(
+        props: &TransferListProps,
+        source: &TransferPane,
+        target: &TransferPane,
+    ) -> String {
+        super::render_html(props, source, target)
+    }
+}
+
+pub mod dioxus {
+    use super::*;
+
+    /// Render the transfer list into a plain HTML string for SSR/hydration.
+    pub fn render(
+        props: &TransferListProps,
+        source: &TransferPane,
+        target: &TransferPane,
+    ) -> String {
+        super::render_html(props, source, target)
+    }
+}
+
+pub mod sycamore {
+    use super::*;
+
+    /// Render the transfer list into a plain HTML string for SSR/hydration.
+    pub fn render(
+        props: &TransferListProps,
+        source: &TransferPane,
+        target: &TransferPane,
+    ) -> String {
+        super::render_html(props, source, target)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_items(labels: &[&str]) -> Vec<String> {
+        labels.iter().map(|label| label.to_string()).collect()
+    }
+
+    #[test]
+    fn panes_render_counts_and_tri_state_headers() {
+        let source_items = sample_items(&["Read", "Write", "Admin"]);
+        let mut source_state = ListboxState::new(3);
+        source_state.select_only(0, |_| {});
+        source_state.select_range(1, |_| {});
+        let target_items = sample_items(&["Audit"]);
+        let target_state = ListboxState::new(1);
+
+        let props = TransferListProps::new("Available", "Granted").with_automation_id("perms");
+        let html = render_html(
+            &props,
+            &TransferPane::new(&source_items, &source_state),
+            &TransferPane::new(&target_items, &target_state),
+        );
+        assert!(html.contains("data-transfer-count=\"2\">2/3 selected"));
+        assert!(html.contains("data-transfer-count=\"0\">0/1 selected"));
+        assert!(html.contains("aria-checked=\"mixed\""));
+        assert!(html.contains("aria-checked=\"false\""));
+        assert!(html.contains("data-rustic-transfer-list-root=\"rustic-transfer-list-perms-root\""));
+    }
+
+    #[test]
+    fn items_expose_selection_and_automation_hooks() {
+        let items = sample_items(&["Read", "Write"]);
+        let mut state = ListboxState::new(2);
+        state.toggle(1, |_| {});
+        let props = TransferListProps::new("Available", "Granted").with_automation_id("perms");
+        let html = render_html(
+            &props,
+            &TransferPane::new(&items, &state),
+            &TransferPane::new(&[], &ListboxState::new(0)),
+        );
+        assert!(
+            html.contains("data-rustic-transfer-list-item=\"rustic-transfer-list-perms-source-1\"")
+        );
+        assert!(html.contains("aria-selected=\"true\" data-index=\"1\""));
+        assert!(html.contains("aria-selected=\"false\" data-index=\"0\""));
+    }
+
+    #[test]
+    fn move_buttons_disable_no_op_intents() {
+        let items = sample_items(&["Read"]);
+        let state = ListboxState::new(1);
+        let props = TransferListProps::new("Available", "Granted");
+        let html = render_html(
+            &props,
+            &TransferPane::new(&items, &state),
+            &TransferPane::new(&[], &ListboxState::new(0)),
+        );
+        // Nothing is selected and the target pane is empty, so only
+        // "move all to target" stays actionable.
+        assert!(html.contains(
+            "data-transfer-action=\"move-all-to-target\" \
+                               aria-label=\"Move all to Granted\">"
+        ));
+        assert!(html.contains(
+            "data-transfer-action=\"move-selected-to-target\" \
+                               aria-label=\"Move selected to Granted\" disabled>"
+        ));
+        assert!(html.contains(
+            "data-transfer-action=\"move-selected-to-source\" \
+                               aria-label=\"Move selected to Available\" disabled>"
+        ));
+        assert!(html.contains(
+            "data-transfer-action=\"move-all-to-source\" \
+                               aria-label=\"Move all to Available\" disabled>"
+        ));
+    }
+}
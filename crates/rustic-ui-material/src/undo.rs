@@ -0,0 +1,505 @@
+//! Undoable action pattern layered on the headless snackbar queue.
+//!
+//! Destructive operations (delete, archive, bulk edits) should not execute the
+//! moment the user clicks: Material guidance is to show a snackbar with an
+//! Undo button and only commit once the auto-hide window elapses without an
+//! undo.  [`UndoSnackbarState`] packages that choreography on top of
+//! [`SnackbarState`](rustic_ui_headless::snackbar::SnackbarState): dispatching an
+//! action enqueues the snackbar and parks the commit callback, undoing drops
+//! the callback, and the timeout (or an explicit dismissal) finally runs it.
+//!
+//! Every transition — `dispatch`, `show`, `undo`, `commit` — is emitted
+//! through [`rustic_ui_utils::telemetry`] so QA suites can assert the full
+//! lifecycle instead of guessing from rendered markup.
+
+use rustic_ui_headless::snackbar::{SnackbarChange, SnackbarConfig, SnackbarState};
+use rustic_ui_headless::timing::{Clock, SystemClock};
+use rustic_ui_styled_engine::{css_with_theme, Style};
+use std::collections::{HashMap, VecDeque};
+
+/// Describes one undoable operation presented to the user.
+#[derive(Clone, Debug, PartialEq)]
+pub struct UndoableAction {
+    /// Message shown in the snackbar, e.g. `"Conversation archived"`.
+    pub message: String,
+    /// Label on the undo button.
+    pub undo_label: String,
+    /// Optional automation identifier for analytics and end-to-end tests.
+    pub automation_id: Option<String>,
+}
+
+impl UndoableAction {
+    /// Convenience constructor used by examples and tests.
+    pub fn new(message: impl Into<String>) -> Self {
+        Self {
+            message: message.into(),
+            undo_label: String::from("Undo"),
+            automation_id: None,
+        }
+    }
+
+    /// Overrides the undo button label, e.g. for localisation.
+    pub fn with_undo_label(mut self, label: impl Into<String>) -> Self {
+        self.undo_label = label.into();
+        self
+    }
+
+    /// Sets the automation identifier stamped on markup and telemetry.
+    pub fn with_automation_id(mut self, id: impl Into<String>) -> Self {
+        self.automation_id = Some(id.into());
+        self
+    }
+}
+
+/// Queue entry pairing the visible action with the token that owns its
+/// parked commit callback.
+#[derive(Clone, Debug)]
+struct UndoEntry {
+    token: u64,
+    action: UndoableAction,
+}
+
+/// Commit callback parked until its undo window resolves.
+type ParkedCommit = (UndoableAction, Box<dyn FnOnce()>);
+
+/// Snackbar queue whose dismissals commit parked callbacks.
+///
+/// The struct deliberately skips `Clone`/`Debug`: the parked callbacks are
+/// boxed `FnOnce` closures which must run exactly once.
+pub struct UndoSnackbarState<C: Clock = SystemClock> {
+    snackbar: SnackbarState<UndoEntry, C>,
+    commits: HashMap<u64, ParkedCommit>,
+    pending: VecDeque<u64>,
+    current_token: Option<u64>,
+    next_token: u64,
+    max_queue: usize,
+}
+
+impl UndoSnackbarState<SystemClock> {
+    /// Construct the helper bound to the system clock.
+    pub fn new(config: SnackbarConfig) -> Self {
+        Self::with_clock(SystemClock, config)
+    }
+}
+
+impl<C: Clock> UndoSnackbarState<C> {
+    /// Construct the helper bound to an arbitrary clock (mock clocks for
+    /// tests).
+    pub fn with_clock(clock: C, config: SnackbarConfig) -> Self {
+        let max_queue = config.max_queue;
+        Self {
+            snackbar: SnackbarState::with_clock(clock, config),
+            commits: HashMap::new(),
+            pending: VecDeque::new(),
+            current_token: None,
+            next_token: 0,
+            max_queue,
+        }
+    }
+
+    /// Action currently presented to the user (if any).
+    pub fn current(&self) -> Option<&UndoableAction> {
+        self.snackbar
+            .current()
+            .map(|message| &message.payload.action)
+    }
+
+    /// How many actions are waiting behind the visible one.
+    #[inline]
+    pub fn queue_len(&self) -> usize {
+        self.snackbar.queue_len()
+    }
+
+    /// Returns whether no action is showing or queued.
+    #[inline]
+    pub fn is_idle(&self) -> bool {
+        self.snackbar.is_idle()
+    }
+
+    /// Fraction (0.0–1.0) of the undo window already elapsed; see
+    /// [`SnackbarState::auto_hide_progress`].
+    pub fn auto_hide_progress(&self) -> Option<f64> {
+        self.snackbar.auto_hide_progress()
+    }
+
+    /// Dispatch an undoable action.
+    ///
+    /// The snackbar (with its Undo affordance) shows immediately or queues
+    /// behind the current one; `commit` is parked until the action's auto-hide
+    /// window elapses or it is dismissed without an undo.  If the queue
+    /// overflows, the oldest waiting action commits immediately — the user
+    /// never saw its snackbar, so deferring further would only delay the
+    /// operation they asked for.
+    pub fn dispatch(&mut self, action: UndoableAction, commit: impl FnOnce() + 'static) {
+        let token = self.next_token;
+        self.next_token = self.next_token.wrapping_add(1);
+        emit(&action, "dispatch");
+
+        // Mirror the queue-overflow eviction performed by the headless
+        // machine so the evicted entry's callback still runs exactly once.
+        if self.snackbar.current().is_some() && self.pending.len() >= self.max_queue {
+            if let Some(evicted) = self.pending.pop_front() {
+                self.commit_token(evicted);
+            }
+        }
+
+        self.commits
+            .insert(token, (action.clone(), Box::new(commit)));
+        let change = self.snackbar.enqueue(UndoEntry {
+            token,
+            action: action.clone(),
+        });
+        if change.shown.is_none() {
+            self.pending.push_back(token);
+        }
+        self.apply(change);
+    }
+
+    /// Undo the currently visible action, dropping its parked commit.
+    pub fn undo_current(&mut self) {
+        let Some(token) = self.current_token else {
+            return;
+        };
+        if let Some(message) = self.snackbar.current() {
+            emit(&message.payload.action, "undo");
+        }
+        self.commits.remove(&token);
+        let change = self.snackbar.dismiss_current();
+        self.apply(change);
+    }
+
+    /// Dismiss the current snackbar, committing its action immediately.
+    ///
+    /// Closing the snackbar is a decision not to undo, so the callback runs
+    /// right away instead of waiting out the remaining window.
+    pub fn dismiss_current(&mut self) {
+        let change = self.snackbar.dismiss_current();
+        self.apply(change);
+    }
+
+    /// Pause the undo window, e.g. while the pointer hovers the snackbar.
+    pub fn pause(&mut self) {
+        self.snackbar.pause();
+    }
+
+    /// Resume a paused undo window.
+    pub fn resume(&mut self) {
+        self.snackbar.resume();
+    }
+
+    /// Advance timers: expired windows commit their action and reveal the
+    /// next queued snackbar.  Call from an animation-frame or interval loop.
+    pub fn tick(&mut self) {
+        let change = self.snackbar.tick();
+        self.apply(change);
+    }
+
+    fn apply(&mut self, change: SnackbarChange<UndoEntry>) {
+        if let Some(dismissed) = change.dismissed {
+            if self.current_token == Some(dismissed.payload.token) {
+                self.current_token = None;
+            }
+            self.commit_token(dismissed.payload.token);
+        }
+        if let Some(shown) = change.shown {
+            self.pending.retain(|token| *token != shown.payload.token);
+            self.current_token = Some(shown.payload.token);
+            emit(&shown.payload.action, "show");
+        }
+    }
+
+    fn commit_token(&mut self, token: u64) {
+        if let Some((action, commit)) = self.commits.remove(&token) {
+            emit(&action, "commit");
+            commit();
+        }
+    }
+}
+
+fn emit(action: &UndoableAction, lifecycle: &str) {
+    rustic_ui_utils::telemetry::emit("undo_snackbar", action.automation_id.as_deref(), lifecycle);
+}
+
+/// Shared rendering routine used by all adapters.
+///
+/// Emits an empty string while idle so adapters can interpolate the result
+/// unconditionally.  The undo button carries `data-undo-action="undo"` for
+/// event delegation, mirroring the retry hook on
+/// [`error_boundary`](crate::error_boundary).
+fn render_html<C: Clock>(state: &UndoSnackbarState<C>) -> String {
+    let Some(action) = state.current() else {
+        return String::new();
+    };
+    let root_attrs = crate::style_helpers::themed_attributes_html(
+        themed_undo_snackbar_style(),
+        vec![
+            ("role".to_string(), String::from("status")),
+            (
+                "data-component".to_string(),
+                crate::style_helpers::automation_id(
+                    "undo-snackbar",
+                    None,
+                    crate::style_helpers::NO_SEGMENTS,
+                ),
+            ),
+            (
+                crate::style_helpers::automation_data_attr("undo-snackbar", ["root"]),
+                crate::style_helpers::automation_id(
+                    "undo-snackbar",
+                    action.automation_id.as_deref(),
+                    ["root"],
+                ),
+            ),
+            (
+                "data-queue-length".to_string(),
+                state.queue_len().to_string(),
+            ),
+        ],
+    );
+    let button_attrs = crate::style_helpers::themed_attributes_html(
+        themed_undo_button_style(),
+        vec![
+            ("type".to_string(), String::from("button")),
+            ("data-undo-action".to_string(), String::from("undo")),
+        ],
+    );
+    format!(
+        "<div {root_attrs}><span>{message}</span><button {button_attrs}>{label}</button></div>",
+        message = action.message,
+        label = action.undo_label,
+    )
+}
+
+/// Snackbar surface styling derived from the active palette.
+fn themed_undo_snackbar_style() -> Style {
+    css_with_theme!(
+        r#"
+        display: inline-flex;
+        align-items: center;
+        gap: ${gap};
+        padding: ${padding_y} ${padding_x};
+        background: ${background};
+        color: ${text};
+        border-radius: ${radius};
+        font-family: ${font_family};
+        box-shadow: 0 3px 10px rgba(0, 0, 0, 0.2);
+    "#,
+        gap = format!("{}px", theme.spacing(2)),
+        padding_y = format!("{}px", theme.spacing(1)),
+        padding_x = format!("{}px", theme.spacing(2)),
+        background = theme.palette.active().text_primary.clone(),
+        text = theme.palette.active().background_paper.clone(),
+        radius = format!("{}px", theme.joy.radius),
+        font_family = theme.typography.font_family.clone(),
+    )
+}
+
+/// Prominent undo affordance contrasting with the inverted surface.
+fn themed_undo_button_style() -> Style {
+    css_with_theme!(
+        r#"
+        background: transparent;
+        border: none;
+        color: ${accent};
+        font-family: ${font_family};
+        font-weight: ${font_weight};
+        text-transform: uppercase;
+        letter-spacing: ${letter_spacing};
+        cursor: pointer;
+
+        &:focus-visible {
+            outline: ${focus_outline};
+            outline-offset: ${focus_outline_offset};
+        }
+    "#,
+        accent = theme.palette.active().secondary.clone(),
+        font_family = theme.typography.font_family.clone(),
+        font_weight = theme.typography.font_weight_medium.to_string(),
+        letter_spacing = format!("{:.3}rem", theme.typography.button_letter_spacing),
+        focus_outline = crate::style_helpers::focus_outline(&theme),
+        focus_outline_offset = crate::style_helpers::focus_outline_offset(&theme)
+    )
+}
+
+// ---------------------------------------------------------------------------
+// Adapter implementations
+// ---------------------------------------------------------------------------
+
+pub mod yew {
+    use super::*;
+
+    /// Render the undo snackbar into a plain HTML string for SSR/hydration.
+    pub fn render<C: Clock>(state: &UndoSnackbarState<C>) -> String {
+        super::render_html(state)
+    }
+
+    /// Render with the undo button wired to a callback; the callback should
+    /// invoke [`UndoSnackbarState::undo_current`] and re-render.
+    #[cfg(feature = "yew")]
+    pub fn render_with_on_undo<C: Clock>(
+        state: &UndoSnackbarState<C>,
+        on_undo: ::yew::Callback<()>,
+    ) -> ::yew::Html {
+        use ::wasm_bindgen::JsCast;
+
+        let on_click = ::yew::Callback::from(move |event: ::yew::events::MouseEvent| {
+            let target = event
+                .target()
+                .and_then(|target| target.dyn_into::<::web_sys::Element>().ok());
+            if let Some(element) = target {
+                if let Ok(Some(_)) = element.closest("[data-undo-action=\"undo\"]") {
+                    on_undo.emit(());
+                }
+            }
+        });
+        crate::render::yew::delegated_click(super::render_html(state), on_click)
+    }
+}
+
+pub mod leptos {
+    use super::*;
+
+    /// Render the undo snackbar into a plain HTML string for SSR/hydration.
+    pub fn render<C: Clock>(state: &UndoSnackbarState<C>) -> String {
+        super::render_html(state)
+    }
+
+    /// Render with an undo handler, mirroring
+    /// [`yew::render_with_on_undo`](super::yew::render_with_on_undo) for
+    /// Leptos consumers.
+    #[cfg(feature = "leptos")]
+    pub fn render_with_on_undo<C: Clock>(
+        state: &UndoSnackbarState<C>,
+        on_undo: impl Fn() + 'static,
+    ) -> ::leptos::View {
+        use ::leptos::wasm_bindgen::JsCast;
+
+        let on_click = move |event: ::leptos::ev::MouseEvent| {
+            let target = event
+                .target()
+                .and_then(|target| target.dyn_into::<::leptos::web_sys::Element>().ok());
+            if let Some(element) = target {
+                if let Ok(Some(_)) = element.closest("[data-undo-action=\"undo\"]") {
+                    on_undo();
+                }
+            }
+        };
+        crate::render::leptos::delegated_click(super::render_html(state), on_click)
+    }
+}
+
+pub mod dioxus {
+    use super::*;
+
+    /// Render the undo snackbar into a plain HTML string for SSR/hydration.
+    pub fn render<C: Clock>(state: &UndoSnackbarState<C>) -> String {
+        super::render_html(state)
+    }
+}
+
+pub mod sycamore {
+    use super::*;
+
+    /// Render the undo snackbar into a plain HTML string for SSR/hydration.
+    pub fn render<C: Clock>(state: &UndoSnackbarState<C>) -> String {
+        super::render_html(state)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rustic_ui_headless::timing::MockClock;
+    use std::cell::Cell;
+    use std::rc::Rc;
+    use std::time::Duration;
+
+    fn config(auto_hide_ms: u64, max_queue: usize) -> SnackbarConfig {
+        SnackbarConfig {
+            auto_hide: Duration::from_millis(auto_hide_ms),
+            max_queue,
+        }
+    }
+
+    fn flag() -> (Rc<Cell<bool>>, impl FnOnce()) {
+        let committed = Rc::new(Cell::new(false));
+        let handle = committed.clone();
+        (committed, move || handle.set(true))
+    }
+
+    #[test]
+    fn commit_waits_for_the_undo_window_to_elapse() {
+        let clock = MockClock::new();
+        let mut state = UndoSnackbarState::with_clock(clock.clone(), config(100, 3));
+        let (committed, commit) = flag();
+        state.dispatch(UndoableAction::new("Message deleted"), commit);
+        assert_eq!(state.current().unwrap().message, "Message deleted");
+
+        clock.advance(Duration::from_millis(99));
+        state.tick();
+        assert!(!committed.get());
+
+        clock.advance(Duration::from_millis(1));
+        state.tick();
+        assert!(committed.get());
+        assert!(state.is_idle());
+    }
+
+    #[test]
+    fn undo_drops_the_parked_commit() {
+        let clock = MockClock::new();
+        let mut state = UndoSnackbarState::with_clock(clock.clone(), config(100, 3));
+        let (committed, commit) = flag();
+        state.dispatch(UndoableAction::new("Row archived"), commit);
+        state.undo_current();
+        clock.advance(Duration::from_millis(500));
+        state.tick();
+        assert!(!committed.get());
+        assert!(state.is_idle());
+    }
+
+    #[test]
+    fn manual_dismissal_commits_immediately() {
+        let clock = MockClock::new();
+        let mut state = UndoSnackbarState::with_clock(clock.clone(), config(1_000, 3));
+        let (committed, commit) = flag();
+        state.dispatch(UndoableAction::new("Filter removed"), commit);
+        state.dismiss_current();
+        assert!(committed.get());
+    }
+
+    #[test]
+    fn queue_overflow_commits_the_evicted_action() {
+        let clock = MockClock::new();
+        let mut state = UndoSnackbarState::with_clock(clock.clone(), config(1_000, 1));
+        let (first, commit_first) = flag();
+        let (second, commit_second) = flag();
+        let (third, commit_third) = flag();
+        state.dispatch(UndoableAction::new("one"), commit_first);
+        state.dispatch(UndoableAction::new("two"), commit_second);
+        // Queue capacity is one, so dispatching a third evicts "two" which
+        // must commit right away rather than silently vanish.
+        state.dispatch(UndoableAction::new("three"), commit_third);
+        assert!(!first.get());
+        assert!(second.get());
+        assert!(!third.get());
+    }
+
+    #[test]
+    fn render_html_exposes_message_and_undo_hook() {
+        let clock = MockClock::new();
+        let mut state = UndoSnackbarState::with_clock(clock.clone(), config(1_000, 3));
+        assert_eq!(super::render_html(&state), "");
+        state.dispatch(
+            UndoableAction::new("Draft discarded").with_automation_id("draft-undo"),
+            || {},
+        );
+        let html = super::render_html(&state);
+        assert!(html.contains("role=\"status\""));
+        assert!(html.contains(">Draft discarded<"));
+        assert!(html.contains("data-undo-action=\"undo\""));
+        assert!(html.contains(">Undo</button>"));
+        assert!(html
+            .contains("data-rustic-undo-snackbar-root=\"rustic-undo-snackbar-draft-undo-root\""));
+    }
+}
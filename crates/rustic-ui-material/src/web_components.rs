@@ -0,0 +1,229 @@
+//! Custom element wrappers exposing Material components to non-Rust hosts.
+//!
+//! The `web-components` feature lets plain HTML applications (or frameworks
+//! outside the supported adapters) embed RusticUI widgets as custom
+//! elements.  The Rust side stays declarative and fully testable on native
+//! targets:
+//!
+//! * [`definitions`] enumerates the wrapped components, their tag names and
+//!   the attributes that map onto props.
+//! * [`render_shadow_root`] maps host element attributes onto the
+//!   component's props/state and returns a declarative shadow DOM template —
+//!   the component markup plus a `<style>` tag carrying the themed CSS — so
+//!   styles are scoped to the shadow root without touching the host page.
+//!
+//! On `wasm32` the [`registration`] module exports both entry points through
+//! `wasm-bindgen`; a ~20 line JS shim (shown there) performs the actual
+//! `customElements.define` call, forwarding `attributeChangedCallback` into
+//! [`render_shadow_root`].  Keeping the class definition in JS avoids a
+//! fragile `js_sys` reimplementation of class semantics while every piece of
+//! rendering logic remains in Rust.
+
+use rustic_ui_headless::button::ButtonState;
+use rustic_ui_headless::chip::{ChipConfig, ChipState};
+
+use crate::button::ButtonProps;
+use crate::chip::ChipProps;
+
+/// Metadata describing one exported custom element.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct CustomElementDefinition {
+    /// Tag name registered with `customElements.define`.
+    pub tag_name: &'static str,
+    /// Attributes whose changes re-render the shadow root
+    /// (`observedAttributes` in the custom element class).
+    pub observed_attributes: &'static [&'static str],
+}
+
+/// Every component currently exported as a custom element.
+#[must_use]
+pub fn definitions() -> Vec<CustomElementDefinition> {
+    vec![
+        CustomElementDefinition {
+            tag_name: "rustic-button",
+            observed_attributes: &["label", "disabled"],
+        },
+        CustomElementDefinition {
+            tag_name: "rustic-chip",
+            observed_attributes: &["label", "deletable", "automation-id"],
+        },
+    ]
+}
+
+fn attr<'a>(attributes: &'a [(String, String)], name: &str) -> Option<&'a str> {
+    attributes
+        .iter()
+        .find(|(key, _)| key == name)
+        .map(|(_, value)| value.as_str())
+}
+
+/// Boolean custom element attributes follow HTML semantics: present (with
+/// any value except `"false"`) means true.
+fn bool_attr(attributes: &[(String, String)], name: &str) -> bool {
+    attr(attributes, name).is_some_and(|value| value != "false")
+}
+
+/// Render the shadow root contents for a custom element.
+///
+/// Returns `None` for unknown tag names so the JS shim can fall through
+/// gracefully.  The output is a declarative shadow DOM template, which also
+/// makes the wrappers SSR friendly: servers can emit the same string and
+/// browsers hydrate it without JavaScript.
+#[must_use]
+pub fn render_shadow_root(tag_name: &str, attributes: &[(String, String)]) -> Option<String> {
+    let (css, html) = match tag_name {
+        "rustic-button" => {
+            let props = ButtonProps::new(attr(attributes, "label").unwrap_or("Button"));
+            let state = ButtonState::new(bool_attr(attributes, "disabled"), None);
+            (
+                crate::button::themed_button_style()
+                    .get_style_str()
+                    .to_string(),
+                crate::button::render_html(&props, &state),
+            )
+        }
+        "rustic-chip" => {
+            let mut props = ChipProps::new(attr(attributes, "label").unwrap_or("Chip"))
+                .with_dismissible(bool_attr(attributes, "deletable"));
+            if let Some(id) = attr(attributes, "automation-id") {
+                props = props.with_automation_id(id);
+            }
+            let state = ChipState::new(ChipConfig::default());
+            let css = [
+                crate::chip::themed_root_style(&props.size)
+                    .get_style_str()
+                    .to_string(),
+                crate::chip::themed_label_style()
+                    .get_style_str()
+                    .to_string(),
+                crate::chip::themed_delete_style()
+                    .get_style_str()
+                    .to_string(),
+            ]
+            .join("\n");
+            (css, crate::chip::render_html(&props, &state))
+        }
+        _ => return None,
+    };
+    Some(format!(
+        "<template shadowrootmode=\"open\"><style>{css}</style>{html}</template>"
+    ))
+}
+
+/// `wasm-bindgen` exports consumed by the JS registration shim.
+///
+/// The shim registers each definition and delegates every render to Rust:
+///
+/// ```text
+/// import init, { rusticDefinitions, rusticRenderShadowRoot } from "./pkg/app.js";
+/// await init();
+/// for (const tag of rusticDefinitions()) {
+///     customElements.define(tag, class extends HTMLElement {
+///         static observedAttributes = rusticObservedAttributes(tag);
+///         connectedCallback() { this.#render(); }
+///         attributeChangedCallback() { this.#render(); }
+///         #render() {
+///             const names = this.getAttributeNames();
+///             const values = names.map((name) => this.getAttribute(name));
+///             const markup = rusticRenderShadowRoot(tag, names, values);
+///             if (markup !== undefined) { this.innerHTML = markup; }
+///         }
+///     });
+/// }
+/// ```
+#[cfg(target_arch = "wasm32")]
+pub mod registration {
+    use wasm_bindgen::prelude::*;
+
+    /// Tag names of every exported custom element.
+    #[wasm_bindgen(js_name = rusticDefinitions)]
+    pub fn definitions_js() -> Vec<String> {
+        super::definitions()
+            .into_iter()
+            .map(|definition| definition.tag_name.to_string())
+            .collect()
+    }
+
+    /// `observedAttributes` for one exported custom element.
+    #[wasm_bindgen(js_name = rusticObservedAttributes)]
+    pub fn observed_attributes_js(tag_name: &str) -> Vec<String> {
+        super::definitions()
+            .into_iter()
+            .find(|definition| definition.tag_name == tag_name)
+            .map(|definition| {
+                definition
+                    .observed_attributes
+                    .iter()
+                    .map(|attribute| attribute.to_string())
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    /// Render the shadow root for a host element's current attributes.
+    /// `names`/`values` are the parallel arrays produced by
+    /// `getAttributeNames()` in the shim above.
+    #[wasm_bindgen(js_name = rusticRenderShadowRoot)]
+    pub fn render_shadow_root_js(
+        tag_name: &str,
+        names: Vec<String>,
+        values: Vec<String>,
+    ) -> Option<String> {
+        let attributes: Vec<(String, String)> = names.into_iter().zip(values).collect();
+        super::render_shadow_root(tag_name, &attributes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn attrs(pairs: &[(&str, &str)]) -> Vec<(String, String)> {
+        pairs
+            .iter()
+            .map(|(key, value)| (key.to_string(), value.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn definitions_cover_the_exported_tags() {
+        let tags: Vec<_> = definitions()
+            .iter()
+            .map(|definition| definition.tag_name)
+            .collect();
+        assert_eq!(tags, ["rustic-button", "rustic-chip"]);
+    }
+
+    #[test]
+    fn button_attributes_map_onto_props_and_state() {
+        let markup = render_shadow_root(
+            "rustic-button",
+            &attrs(&[("label", "Deploy"), ("disabled", "")]),
+        )
+        .expect("known tag renders");
+        assert!(markup.starts_with("<template shadowrootmode=\"open\"><style>"));
+        assert!(markup.contains("Deploy"));
+        assert!(markup.contains("role=\"button\""));
+    }
+
+    #[test]
+    fn chip_shadow_root_scopes_styles_and_automation_ids() {
+        let markup = render_shadow_root(
+            "rustic-chip",
+            &attrs(&[
+                ("label", "Beta"),
+                ("deletable", ""),
+                ("automation-id", "release-flag"),
+            ]),
+        )
+        .expect("known tag renders");
+        assert!(markup.contains("<style>"));
+        assert!(markup.contains("Beta"));
+        assert!(markup.contains("release-flag"));
+    }
+
+    #[test]
+    fn unknown_tags_return_none() {
+        assert_eq!(render_shadow_root("rustic-unknown", &[]), None);
+    }
+}
@@ -0,0 +1,117 @@
+//! FLIP (First, Last, Invert, Play) layout transition helpers.
+//!
+//! Reordered lists, expanding cards and shared-element navigations all
+//! animate a layout change by measuring the element before (`first`) and
+//! after (`last`) the DOM update, applying the inverse delta as a transform
+//! so the element visually stays put, then transitioning that transform back
+//! to identity.  This module supplies the measurement math and the CSS
+//! strings; the framework hook only needs to toggle a class on the next
+//! frame.
+//!
+//! Under reduced motion [`play_transition`] returns `none`, so the inverted
+//! transform is never applied for more than a single frame and the element
+//! simply appears in its final position.
+
+use rustic_ui_system::Theme;
+
+/// Measured element bounds in CSS pixels.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Rect {
+    pub x: f64,
+    pub y: f64,
+    pub width: f64,
+    pub height: f64,
+}
+
+impl Rect {
+    /// Convenience constructor mirroring `DOMRect`.
+    pub fn new(x: f64, y: f64, width: f64, height: f64) -> Self {
+        Self {
+            x,
+            y,
+            width,
+            height,
+        }
+    }
+}
+
+/// Inverse transform that makes an element at its `last` bounds render where
+/// its `first` bounds were.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct FlipTransform {
+    pub translate_x: f64,
+    pub translate_y: f64,
+    pub scale_x: f64,
+    pub scale_y: f64,
+}
+
+impl FlipTransform {
+    /// Whether the layout change was a no-op (no animation required).
+    pub fn is_identity(&self) -> bool {
+        self.translate_x == 0.0
+            && self.translate_y == 0.0
+            && self.scale_x == 1.0
+            && self.scale_y == 1.0
+    }
+
+    /// CSS `transform` value applying the inversion.
+    pub fn to_css(&self) -> String {
+        format!(
+            "translate({}px, {}px) scale({}, {})",
+            self.translate_x, self.translate_y, self.scale_x, self.scale_y
+        )
+    }
+}
+
+/// Compute the Invert step from the First and Last measurements.
+///
+/// Collapsed elements (zero extent) fall back to a scale of one so the
+/// transform never divides by zero.
+#[must_use]
+pub fn invert(first: &Rect, last: &Rect) -> FlipTransform {
+    let scale = |before: f64, after: f64| if after > 0.0 { before / after } else { 1.0 };
+    FlipTransform {
+        translate_x: first.x - last.x,
+        translate_y: first.y - last.y,
+        scale_x: scale(first.width, last.width),
+        scale_y: scale(first.height, last.height),
+    }
+}
+
+/// CSS `transition` value for the Play step, derived from the theme's
+/// standard motion tokens (`none` under reduced motion).
+#[must_use]
+pub fn play_transition(theme: &Theme) -> String {
+    theme.motion.transition(&["transform"])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn invert_produces_the_inverse_delta() {
+        let first = Rect::new(0.0, 0.0, 100.0, 50.0);
+        let last = Rect::new(40.0, 10.0, 200.0, 50.0);
+        let transform = invert(&first, &last);
+        assert_eq!(transform.translate_x, -40.0);
+        assert_eq!(transform.translate_y, -10.0);
+        assert_eq!(transform.scale_x, 0.5);
+        assert_eq!(transform.scale_y, 1.0);
+        assert_eq!(transform.to_css(), "translate(-40px, -10px) scale(0.5, 1)");
+    }
+
+    #[test]
+    fn unchanged_layout_is_identity() {
+        let rect = Rect::new(5.0, 5.0, 10.0, 10.0);
+        assert!(invert(&rect, &rect).is_identity());
+    }
+
+    #[test]
+    fn play_transition_honors_reduced_motion() {
+        let mut theme = Theme::default();
+        assert_eq!(play_transition(&theme), "transform 200ms ease");
+        theme.motion.reduce = true;
+        assert_eq!(play_transition(&theme), "none");
+    }
+}
@@ -0,0 +1,34 @@
+#![forbid(unsafe_code)]
+//! Animation primitives honoring the theme's motion tokens.
+//!
+//! The crate provides the interpolation math behind the transition
+//! components and the drawer/dialog animations without touching the DOM, so
+//! every framework adapter (and headless test) drives identical motion:
+//!
+//! * [`spring`] - damped spring simulation for physical transitions.
+//! * [`tween`] - fixed duration interpolation with the classic easing
+//!   curves, durations sourced from
+//!   [`MotionScheme`](rustic_ui_system::MotionScheme).
+//! * [`flip`] - FLIP layout transition measurement and CSS helpers.
+//!
+//! Reduced motion is a first class concern: every themed constructor reads
+//! `theme.motion.reduce` and degrades to an instant change, so call sites
+//! never special-case the preference.
+//!
+//! # Examples
+//! ```
+//! use rustic_ui_motion::{Easing, Tween};
+//! use rustic_ui_system::Theme;
+//!
+//! let theme = Theme::default();
+//! let fade = Tween::standard(&theme, 0.0, 1.0).with_easing(Easing::EaseOut);
+//! assert!(fade.sample(50.0) > 0.0);
+//! ```
+
+pub mod flip;
+pub mod spring;
+pub mod tween;
+
+pub use flip::{invert, play_transition, FlipTransform, Rect};
+pub use spring::{Spring, SpringAnimation};
+pub use tween::{Easing, Tween};
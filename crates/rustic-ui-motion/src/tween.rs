@@ -0,0 +1,181 @@
+//! Duration based interpolation with the classic easing curves.
+//!
+//! Tweens cover everything that wants a predictable end time — progress
+//! indicators, fades, coordinated entrances.  Durations come from the
+//! theme's [`MotionScheme`](rustic_ui_system::MotionScheme) so a single
+//! override retunes the whole application, and reduced motion collapses the
+//! duration to zero which makes [`Tween::sample`] return the final value
+//! immediately.
+
+use rustic_ui_system::Theme;
+
+/// Easing curve applied to normalized progress.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Easing {
+    /// Constant velocity.
+    Linear,
+    /// Cubic acceleration from rest.
+    EaseIn,
+    /// Cubic deceleration into rest.
+    EaseOut,
+    /// Accelerate then decelerate; the Material standard curve and therefore
+    /// the default.
+    #[default]
+    EaseInOut,
+}
+
+impl Easing {
+    /// Map linear progress in `0.0..=1.0` through the curve.
+    pub fn apply(self, t: f64) -> f64 {
+        let t = t.clamp(0.0, 1.0);
+        match self {
+            Self::Linear => t,
+            Self::EaseIn => t * t * t,
+            Self::EaseOut => {
+                let inverted = 1.0 - t;
+                1.0 - inverted * inverted * inverted
+            }
+            Self::EaseInOut => {
+                if t < 0.5 {
+                    4.0 * t * t * t
+                } else {
+                    let inverted = -2.0 * t + 2.0;
+                    1.0 - inverted * inverted * inverted / 2.0
+                }
+            }
+        }
+    }
+}
+
+/// Fixed-duration interpolation between two scalar values.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Tween {
+    from: f64,
+    to: f64,
+    duration_ms: u16,
+    easing: Easing,
+}
+
+impl Tween {
+    /// Tween with an explicit duration.
+    pub fn new(from: f64, to: f64, duration_ms: u16, easing: Easing) -> Self {
+        Self {
+            from,
+            to,
+            duration_ms,
+            easing,
+        }
+    }
+
+    /// Tween using the theme's standard duration, honoring reduced motion.
+    pub fn standard(theme: &Theme, from: f64, to: f64) -> Self {
+        Self::new(
+            from,
+            to,
+            theme
+                .motion
+                .effective_duration_ms(theme.motion.duration_standard_ms),
+            Easing::default(),
+        )
+    }
+
+    /// Tween using the theme's short duration, honoring reduced motion.
+    pub fn short(theme: &Theme, from: f64, to: f64) -> Self {
+        Self::new(
+            from,
+            to,
+            theme
+                .motion
+                .effective_duration_ms(theme.motion.duration_short_ms),
+            Easing::default(),
+        )
+    }
+
+    /// Tween using the theme's long duration, honoring reduced motion.
+    pub fn long(theme: &Theme, from: f64, to: f64) -> Self {
+        Self::new(
+            from,
+            to,
+            theme
+                .motion
+                .effective_duration_ms(theme.motion.duration_long_ms),
+            Easing::default(),
+        )
+    }
+
+    /// Swap the easing curve.
+    pub fn with_easing(mut self, easing: Easing) -> Self {
+        self.easing = easing;
+        self
+    }
+
+    /// Value at `elapsed_ms` since the tween started.  Zero-duration tweens
+    /// (reduced motion) return the final value for any elapsed time.
+    pub fn sample(&self, elapsed_ms: f64) -> f64 {
+        if self.duration_ms == 0 {
+            return self.to;
+        }
+        let progress = self.easing.apply(elapsed_ms / f64::from(self.duration_ms));
+        self.from + (self.to - self.from) * progress
+    }
+
+    /// Whether the tween has run its full duration.
+    pub fn is_complete(&self, elapsed_ms: f64) -> bool {
+        elapsed_ms >= f64::from(self.duration_ms)
+    }
+
+    /// Configured duration in milliseconds.
+    pub fn duration_ms(&self) -> u16 {
+        self.duration_ms
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn easing_curves_hit_their_endpoints() {
+        for easing in [
+            Easing::Linear,
+            Easing::EaseIn,
+            Easing::EaseOut,
+            Easing::EaseInOut,
+        ] {
+            assert_eq!(easing.apply(0.0), 0.0);
+            assert_eq!(easing.apply(1.0), 1.0);
+        }
+    }
+
+    #[test]
+    fn samples_interpolate_and_clamp() {
+        let tween = Tween::new(10.0, 20.0, 100, Easing::Linear);
+        assert_eq!(tween.sample(0.0), 10.0);
+        assert_eq!(tween.sample(50.0), 15.0);
+        assert_eq!(tween.sample(500.0), 20.0);
+        assert!(tween.is_complete(100.0));
+    }
+
+    #[test]
+    fn themed_constructors_read_the_motion_tokens() {
+        let theme = Theme::default();
+        assert_eq!(
+            Tween::standard(&theme, 0.0, 1.0).duration_ms(),
+            theme.motion.duration_standard_ms
+        );
+        assert_eq!(
+            Tween::short(&theme, 0.0, 1.0).duration_ms(),
+            theme.motion.duration_short_ms
+        );
+    }
+
+    #[test]
+    fn reduced_motion_skips_straight_to_the_end() {
+        let mut theme = Theme::default();
+        theme.motion.reduce = true;
+        let tween = Tween::long(&theme, 0.0, 1.0);
+        assert_eq!(tween.duration_ms(), 0);
+        assert_eq!(tween.sample(0.0), 1.0);
+        assert!(tween.is_complete(0.0));
+    }
+}
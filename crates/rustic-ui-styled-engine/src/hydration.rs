@@ -0,0 +1,249 @@
+//! Deterministic hydration identifier allocation and mismatch debugging.
+//!
+//! Server rendered markup and the client hydration pass must agree on every
+//! generated element id, otherwise ARIA relationships dangle and frameworks
+//! discard the server DOM. Components with an explicit automation id are
+//! already stable, but anonymous instances need generated ids — and those
+//! must come out identical on both sides.
+//!
+//! The allocator here hands out ids from a render-scoped counter keyed by
+//! component family. Both environments render components in the same order,
+//! so as long as each render pass starts from a fresh scope (the SSR helpers
+//! in [`crate::ssr`] reset it automatically; client bootstraps call
+//! [`reset_scope`] before hydrating) the `n`-th anonymous select on the
+//! server receives the same id as the `n`-th anonymous select on the client.
+//!
+//! [`diff_markup`] backs a debug mode for the remaining failure cases: it
+//! compares the server and client HTML strings structurally and reports the
+//! first divergences in tag order, ids and classes instead of leaving teams
+//! to eyeball two DOM dumps.
+
+use std::cell::RefCell;
+use std::collections::HashMap;
+
+thread_local! {
+    /// Per-thread allocator; renders are single threaded in both the SSR
+    /// handlers and the WebAssembly client.
+    static SCOPE: RefCell<HydrationIdAllocator> = RefCell::new(HydrationIdAllocator::new("app"));
+}
+
+/// Render-scoped counter handing out deterministic element ids.
+#[derive(Clone, Debug)]
+pub struct HydrationIdAllocator {
+    scope: String,
+    counters: HashMap<String, usize>,
+}
+
+impl HydrationIdAllocator {
+    /// Create an allocator for the given scope label. The label prefixes all
+    /// generated ids so concurrent scopes (e.g. per-request SSR) can never
+    /// collide in tests that compare markup across requests.
+    pub fn new(scope: impl Into<String>) -> Self {
+        Self {
+            scope: scope.into(),
+            counters: HashMap::new(),
+        }
+    }
+
+    /// Allocate the next id for a component family, e.g.
+    /// `rustic-app-select-0`, `rustic-app-select-1`, ...
+    pub fn next_id(&mut self, component: &str) -> String {
+        let counter = self.counters.entry(component.to_string()).or_insert(0);
+        let id = format!("rustic-{}-{component}-{counter}", self.scope);
+        *counter += 1;
+        id
+    }
+}
+
+/// Allocate the next hydration id for `component` from the active scope.
+///
+/// Renderers call this for every element that needs a generated id; the
+/// sequence restarts whenever a new scope is installed so server and client
+/// passes stay aligned.
+pub fn next_hydration_id(component: &str) -> String {
+    SCOPE.with(|scope| scope.borrow_mut().next_id(component))
+}
+
+/// Reset the active scope, restarting every counter.
+///
+/// The SSR helpers call this at the start of each render; client bundles
+/// must call it once before their hydration pass so both sides allocate from
+/// identical starting points.
+pub fn reset_scope(scope: &str) {
+    SCOPE.with(|current| *current.borrow_mut() = HydrationIdAllocator::new(scope));
+}
+
+/// One divergence between the server markup and the client render.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct HydrationMismatch {
+    /// Zero based index of the element in document order.
+    pub position: usize,
+    /// Human readable description of the divergence.
+    pub detail: String,
+}
+
+impl std::fmt::Display for HydrationMismatch {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "element #{}: {}", self.position, self.detail)
+    }
+}
+
+/// Structurally compare server and client markup, reporting tag, id and
+/// class divergences in document order.
+///
+/// This intentionally ignores text content and unrelated attributes: the
+/// goal is to debug hydration id drift, not to be a generic DOM differ.
+pub fn diff_markup(server: &str, client: &str) -> Vec<HydrationMismatch> {
+    let server_elements = scan_elements(server);
+    let client_elements = scan_elements(client);
+    let mut mismatches = Vec::new();
+
+    let common = server_elements.len().min(client_elements.len());
+    for position in 0..common {
+        let lhs = &server_elements[position];
+        let rhs = &client_elements[position];
+        if lhs.tag != rhs.tag {
+            mismatches.push(HydrationMismatch {
+                position,
+                detail: format!("tag <{}> on server but <{}> on client", lhs.tag, rhs.tag),
+            });
+            continue;
+        }
+        for attribute in ["id", "class"] {
+            let left = lhs.attributes.get(attribute);
+            let right = rhs.attributes.get(attribute);
+            if left != right {
+                mismatches.push(HydrationMismatch {
+                    position,
+                    detail: format!(
+                        "<{}> `{attribute}` is {:?} on server but {:?} on client",
+                        lhs.tag, left, right
+                    ),
+                });
+            }
+        }
+    }
+    if server_elements.len() != client_elements.len() {
+        mismatches.push(HydrationMismatch {
+            position: common,
+            detail: format!(
+                "server rendered {} elements but the client rendered {}",
+                server_elements.len(),
+                client_elements.len()
+            ),
+        });
+    }
+    mismatches
+}
+
+/// Minimal element snapshot used by [`diff_markup`].
+struct ScannedElement {
+    tag: String,
+    attributes: HashMap<String, String>,
+}
+
+/// Scan opening tags out of well-formed, machine generated markup. Closing
+/// tags, comments and text nodes are skipped.
+fn scan_elements(html: &str) -> Vec<ScannedElement> {
+    let mut elements = Vec::new();
+    let mut cursor = 0;
+    while let Some(open) = html[cursor..].find('<') {
+        let start = cursor + open + 1;
+        if start >= html.len() {
+            break;
+        }
+        if html.as_bytes()[start] == b'/' || html.as_bytes()[start] == b'!' {
+            cursor = start;
+            continue;
+        }
+        let Some(close) = html[start..].find('>') else {
+            break;
+        };
+        let raw = html[start..start + close].trim_end_matches('/');
+        let tag_end = raw.find(char::is_whitespace).unwrap_or(raw.len());
+        let tag = raw[..tag_end].to_ascii_lowercase();
+        if !tag.is_empty()
+            && tag
+                .chars()
+                .next()
+                .is_some_and(|ch| ch.is_ascii_alphabetic())
+        {
+            elements.push(ScannedElement {
+                tag,
+                attributes: scan_attributes(&raw[tag_end..]),
+            });
+        }
+        cursor = start + close + 1;
+    }
+    elements
+}
+
+/// Extract double-quoted `key="value"` pairs from a tag interior.
+fn scan_attributes(raw: &str) -> HashMap<String, String> {
+    let mut attributes = HashMap::new();
+    let mut rest = raw;
+    while let Some(eq) = rest.find("=\"") {
+        let name = rest[..eq]
+            .rsplit(char::is_whitespace)
+            .next()
+            .unwrap_or("")
+            .to_string();
+        let value_start = eq + 2;
+        let Some(end) = rest[value_start..].find('"') else {
+            break;
+        };
+        if !name.is_empty() {
+            attributes.insert(name, rest[value_start..value_start + end].to_string());
+        }
+        rest = &rest[value_start + end + 1..];
+    }
+    attributes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn allocator_is_deterministic_per_scope() {
+        let mut server = HydrationIdAllocator::new("req");
+        let mut client = HydrationIdAllocator::new("req");
+        for _ in 0..3 {
+            assert_eq!(server.next_id("select"), client.next_id("select"));
+        }
+        assert_eq!(server.next_id("dialog"), "rustic-req-dialog-0");
+    }
+
+    #[test]
+    fn reset_scope_restarts_the_global_sequence() {
+        reset_scope("app");
+        let first = next_hydration_id("menu");
+        reset_scope("app");
+        assert_eq!(next_hydration_id("menu"), first);
+    }
+
+    #[test]
+    fn diff_reports_id_divergence() {
+        let server = r#"<div id="rustic-app-select-0"></div>"#;
+        let client = r#"<div id="rustic-app-select-1"></div>"#;
+        let mismatches = diff_markup(server, client);
+        assert_eq!(mismatches.len(), 1);
+        assert!(mismatches[0].detail.contains("`id`"));
+    }
+
+    #[test]
+    fn diff_reports_structural_drift() {
+        let server = "<div><span></span></div>";
+        let client = "<div></div>";
+        let mismatches = diff_markup(server, client);
+        assert!(mismatches
+            .iter()
+            .any(|mismatch| mismatch.detail.contains("2 elements")));
+    }
+
+    #[test]
+    fn matching_markup_produces_no_mismatches() {
+        let html = r#"<ul class="a" id="x"><li class="b">1</li></ul>"#;
+        assert!(diff_markup(html, html).is_empty());
+    }
+}
@@ -17,7 +17,9 @@
 //! assert!(!style.get_class_name().is_empty());
 //! ```
 
-pub use rustic_ui_system::theme::{Breakpoints, Palette, Theme, TypographyScheme};
+pub use rustic_ui_system::theme::{
+    Breakpoints, ComponentOverride, ComponentOverrides, IconSize, Palette, Theme, TypographyScheme,
+};
 pub use rustic_ui_system::theme_provider::use_theme;
 #[cfg(all(not(feature = "yew"), feature = "leptos"))]
 pub use rustic_ui_system::theme_provider::ThemeProviderLeptos as ThemeProvider;
@@ -29,6 +31,9 @@ pub use rustic_ui_styled_engine_macros::{css_with_theme, styled_component, Theme
 // when used internally.
 extern crate self as rustic_ui_styled_engine;
 
+mod hydration;
+pub use hydration::*;
+
 mod ssr;
 pub use ssr::*;
 
@@ -37,6 +42,18 @@ pub use context::*;
 
 pub use stylist::{css, global_style, Style, StyleSource};
 
+/// Compiles the [`ComponentOverrides`] CSS declared for a component slot into
+/// its own scoped [`Style`].
+///
+/// Renderers append the resulting class after their themed base class so the
+/// override rules win the cascade without `!important`.  Returns `None` when
+/// the theme declares nothing for the slot or the snippet fails to parse —
+/// a malformed override must not take the component down with it.
+pub fn component_style_override(theme: &Theme, component: &str) -> Option<Style> {
+    let css = theme.component_style_override(component)?;
+    Style::new(css.to_string()).ok()
+}
+
 #[cfg(feature = "yew")]
 mod yew_integration {
     use super::*;
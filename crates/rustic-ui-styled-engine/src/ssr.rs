@@ -27,6 +27,11 @@ pub fn render_with_style<F>(render: F) -> SsrOutput
 where
     F: FnOnce(StyleManager) -> String,
 {
+    // Restart the hydration id scope so generated element ids allocate from
+    // zero for this render, matching the client pass which performs the same
+    // reset before hydrating. See [`crate::hydration`] for the full contract.
+    crate::hydration::reset_scope("app");
+
     // Create a writer/reader pair. The writer is passed to the manager so it can
     // record CSS rules; the reader is used afterwards to turn the rules into
     // style tags.
@@ -0,0 +1,242 @@
+//! Theme-aware renderer for transactional email HTML.
+//!
+//! Email clients reject almost everything the component crates rely on:
+//! stylesheets are stripped, flexbox and CSS custom properties are ignored
+//! and layout only survives when expressed as nested tables with inline
+//! styles.  This module renders a deliberately constrained subset of the
+//! design system — typography, buttons, dividers and data tables — straight
+//! from the shared [`Theme`] so password resets and invoices carry the same
+//! brand tokens as the product itself.  Every helper returns plain HTML
+//! strings; compose them inside [`document`] which supplies the client-safe
+//! shell (600px centered table, `role="presentation"` wrappers, background
+//! colors as both `bgcolor` attributes and inline styles).
+//!
+//! Sizes resolve to whole pixels because `rem` support is inconsistent
+//! across clients, and colors are emitted verbatim from the palette so the
+//! corporate hex values land untouched in the final markup.
+
+use crate::theme::Theme;
+use crate::typography::TypographyVariant;
+
+/// Escape text content for safe interpolation between tags.
+fn escape_text(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
+/// Resolve a typography slot from rems into the whole pixel value email
+/// clients understand.
+fn rem_to_px(theme: &Theme, rem: f32) -> u32 {
+    (rem * theme.typography.html_font_size).round() as u32
+}
+
+/// Render a heading or body paragraph with fully inlined brand typography.
+pub fn typography(theme: &Theme, variant: TypographyVariant, text: &str) -> String {
+    let (tag, size, weight) = match variant {
+        TypographyVariant::H1 => (
+            "h1",
+            rem_to_px(theme, theme.typography.h1),
+            theme.typography.font_weight_bold,
+        ),
+        TypographyVariant::H2 => (
+            "h2",
+            rem_to_px(theme, theme.typography.h2),
+            theme.typography.font_weight_medium,
+        ),
+        TypographyVariant::Body1 => (
+            "p",
+            rem_to_px(theme, theme.typography.body1),
+            theme.typography.font_weight_regular,
+        ),
+    };
+    format!(
+        "<{tag} style=\"margin:0 0 {margin}px 0;font-family:{font};font-size:{size}px;\
+         font-weight:{weight};line-height:{line_height};color:{color};\">{text}</{tag}>",
+        margin = theme.spacing(2),
+        font = theme.typography.font_family,
+        line_height = theme.typography.line_height,
+        color = theme.palette.active().text_primary,
+        text = escape_text(text),
+    )
+}
+
+/// Render a "bulletproof" call-to-action button.
+///
+/// The link nests inside its own presentation table with the primary color
+/// applied as both `bgcolor` and inline background so Outlook and webmail
+/// clients agree on the fill.
+pub fn button(theme: &Theme, label: &str, href: &str) -> String {
+    let primary = &theme.palette.active().primary;
+    format!(
+        "<table role=\"presentation\" border=\"0\" cellpadding=\"0\" cellspacing=\"0\">\
+         <tr><td bgcolor=\"{primary}\" style=\"border-radius:4px;background-color:{primary};\">\
+         <a href=\"{href}\" style=\"display:inline-block;padding:{pad_y}px {pad_x}px;\
+         font-family:{font};font-size:{size}px;font-weight:{weight};\
+         color:{contrast};text-decoration:none;border-radius:4px;\">{label}</a>\
+         </td></tr></table>",
+        pad_y = theme.spacing(1) + theme.spacing(1) / 2,
+        pad_x = theme.spacing(3),
+        font = theme.typography.font_family,
+        size = rem_to_px(theme, theme.typography.button),
+        weight = theme.typography.font_weight_medium,
+        contrast = theme.palette.active().background_paper,
+        href = escape_text(href),
+        label = escape_text(label),
+    )
+}
+
+/// Render a horizontal divider as a border-top cell.
+///
+/// `<hr>` renders wildly differently across clients, so the conventional
+/// single-cell table with a top border stands in for it.
+pub fn divider(theme: &Theme) -> String {
+    format!(
+        "<table role=\"presentation\" border=\"0\" cellpadding=\"0\" cellspacing=\"0\" \
+         width=\"100%\"><tr><td style=\"border-top:1px solid {color};font-size:0;\
+         line-height:0;padding:{pad}px 0 0 0;\">&nbsp;</td></tr></table>",
+        color = theme.palette.active().neutral,
+        pad = theme.spacing(2),
+    )
+}
+
+/// Render a data table (e.g. an invoice line-up) with inline-styled cells.
+///
+/// Headers pick up the secondary text color and a divider border; body rows
+/// alternate nothing — zebra striping is left to callers via the cell
+/// content because background overrides are unreliable in dark-mode clients.
+pub fn table(theme: &Theme, headers: &[&str], rows: &[Vec<String>]) -> String {
+    let font = &theme.typography.font_family;
+    let cell_padding = theme.spacing(1);
+    let header_cells: String = headers
+        .iter()
+        .map(|header| {
+            format!(
+                "<th align=\"left\" style=\"padding:{cell_padding}px;font-family:{font};\
+                 font-size:{size}px;font-weight:{weight};color:{color};\
+                 border-bottom:2px solid {border};\">{text}</th>",
+                size = rem_to_px(theme, theme.typography.body2),
+                weight = theme.typography.font_weight_medium,
+                color = theme.palette.active().text_secondary,
+                border = theme.palette.active().neutral,
+                text = escape_text(header),
+            )
+        })
+        .collect();
+    let body_rows: String = rows
+        .iter()
+        .map(|row| {
+            let cells: String = row
+                .iter()
+                .map(|cell| {
+                    format!(
+                        "<td style=\"padding:{cell_padding}px;font-family:{font};\
+                         font-size:{size}px;color:{color};\
+                         border-bottom:1px solid {border};\">{text}</td>",
+                        size = rem_to_px(theme, theme.typography.body2),
+                        color = theme.palette.active().text_primary,
+                        border = theme.palette.active().neutral,
+                        text = escape_text(cell),
+                    )
+                })
+                .collect();
+            format!("<tr>{cells}</tr>")
+        })
+        .collect();
+    format!(
+        "<table border=\"0\" cellpadding=\"0\" cellspacing=\"0\" width=\"100%\">\
+         <tr>{header_cells}</tr>{body_rows}</table>"
+    )
+}
+
+/// Wrap composed fragments in a complete email-client-safe document.
+///
+/// Produces the conventional shell: full-width background table carrying the
+/// theme's default background, a centered 600px content card on the paper
+/// color, and the preamble clients expect (doctype, UTF-8 charset, viewport
+/// meta).  The `title` feeds the `<title>` element shown by some clients in
+/// notification previews.
+pub fn document(theme: &Theme, title: &str, body: &str) -> String {
+    format!(
+        "<!DOCTYPE html><html><head><meta charset=\"utf-8\">\
+         <meta name=\"viewport\" content=\"width=device-width, initial-scale=1.0\">\
+         <title>{title}</title></head>\
+         <body style=\"margin:0;padding:0;background-color:{background};\">\
+         <table role=\"presentation\" border=\"0\" cellpadding=\"0\" cellspacing=\"0\" \
+         width=\"100%\" bgcolor=\"{background}\" style=\"background-color:{background};\">\
+         <tr><td align=\"center\" style=\"padding:{outer_pad}px 0;\">\
+         <table role=\"presentation\" border=\"0\" cellpadding=\"0\" cellspacing=\"0\" \
+         width=\"600\" bgcolor=\"{paper}\" style=\"background-color:{paper};\
+         border-radius:8px;\"><tr><td style=\"padding:{inner_pad}px;\">{body}\
+         </td></tr></table></td></tr></table></body></html>",
+        title = escape_text(title),
+        background = theme.palette.active().background_default,
+        paper = theme.palette.active().background_paper,
+        outer_pad = theme.spacing(4),
+        inner_pad = theme.spacing(4),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn typography_inlines_theme_tokens() {
+        let theme = Theme::default();
+        let heading = typography(&theme, TypographyVariant::H1, "Welcome <back>");
+        assert!(heading.starts_with("<h1 style=\""));
+        assert!(heading.contains(&format!("font-family:{}", theme.typography.font_family)));
+        assert!(heading.contains("Welcome &lt;back&gt;"));
+        // Rem based slots resolve to whole pixels for client compatibility.
+        assert!(!heading.contains("rem"));
+
+        let body = typography(&theme, TypographyVariant::Body1, "Copy");
+        assert!(body.starts_with("<p "));
+    }
+
+    #[test]
+    fn button_doubles_the_fill_for_outlook() {
+        let theme = Theme::default();
+        let html = button(
+            &theme,
+            "Reset password",
+            "https://example.com/reset?a=1&b=2",
+        );
+        let primary = &theme.palette.active().primary;
+        assert!(html.contains(&format!("bgcolor=\"{primary}\"")));
+        assert!(html.contains(&format!("background-color:{primary};")));
+        assert!(html.contains("href=\"https://example.com/reset?a=1&amp;b=2\""));
+        assert!(html.contains("role=\"presentation\""));
+    }
+
+    #[test]
+    fn table_styles_every_cell_inline() {
+        let theme = Theme::default();
+        let html = table(
+            &theme,
+            &["Item", "Amount"],
+            &[vec!["Seat license".into(), "$49.00".into()]],
+        );
+        assert_eq!(html.matches("<th ").count(), 2);
+        assert!(html.contains("border-bottom:2px solid"));
+        assert!(html.contains("<td style=\""));
+        assert!(html.contains("Seat license"));
+    }
+
+    #[test]
+    fn document_wraps_fragments_in_a_centered_card() {
+        let theme = Theme::default();
+        let html = document(&theme, "Invoice #42", &divider(&theme));
+        assert!(html.starts_with("<!DOCTYPE html>"));
+        assert!(html.contains("<title>Invoice #42</title>"));
+        assert!(html.contains("width=\"600\""));
+        assert!(html.contains(&format!(
+            "bgcolor=\"{}\"",
+            theme.palette.active().background_default
+        )));
+        // The divider fragment made it into the content cell.
+        assert!(html.contains("border-top:1px solid"));
+    }
+}
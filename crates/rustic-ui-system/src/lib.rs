@@ -11,6 +11,7 @@
 pub mod macros;
 pub mod portal;
 pub mod responsive;
+pub mod scheme_scheduler;
 mod scoped_class;
 pub mod style;
 pub mod theme;
@@ -21,10 +22,13 @@ pub mod themed_element;
 
 pub mod r#box;
 pub mod container;
+pub mod email;
 pub mod grid;
 pub mod stack;
 pub mod theme_provider;
-#[cfg(any(feature = "yew", feature = "leptos"))]
+// The variant enum carries no framework dependencies (the adapters inside
+// are gated individually) and is shared with the email renderer, so the
+// module itself stays unconditional.
 pub mod typography;
 
 #[doc(hidden)]
@@ -37,13 +41,17 @@ pub use portal::{PortalFragment, PortalLayer, PortalMount};
 #[cfg(any(feature = "yew", feature = "leptos"))]
 pub use r#box::Box;
 pub use responsive::{grid_span_to_percent, Responsive};
+pub use scheme_scheduler::{SchemeSchedule, SchemeScheduler};
 #[cfg(any(feature = "yew", feature = "leptos"))]
 pub use stack::{Stack, StackDirection};
 #[allow(unused_imports)]
 pub use style::*;
 #[doc(hidden)]
 pub use stylist::{css, Style};
-pub use theme::{Breakpoints, Palette, Theme};
+pub use theme::{
+    Breakpoints, ChartsScheme, ComponentOverride, ComponentOverrides, IconSize, MotionScheme,
+    Palette, Theme, ThemeBuilder,
+};
 extern crate self as rustic_ui_styled_engine;
 #[cfg(all(not(feature = "yew"), feature = "leptos"))]
 pub use theme_provider::ThemeProviderLeptos as ThemeProvider;
@@ -51,7 +59,8 @@ pub use theme_provider::ThemeProviderLeptos as ThemeProvider;
 pub use theme_provider::ThemeProviderYew as ThemeProvider;
 pub use themed_element::{ThemedProps, Variant};
 #[cfg(any(feature = "yew", feature = "leptos"))]
-pub use typography::{Typography, TypographyVariant};
+pub use typography::Typography;
+pub use typography::TypographyVariant;
 
 #[cfg(any(feature = "yew", feature = "leptos"))]
 pub(crate) use scoped_class::ScopedClass;
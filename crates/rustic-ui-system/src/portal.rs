@@ -20,6 +20,9 @@ use rustic_ui_utils::attributes_to_html;
 pub enum PortalLayer {
     /// Floating surfaces such as menus, selects and tooltips.
     Popover,
+    /// Blocking surfaces such as modals, lightboxes and full screen takeovers
+    /// that render a backdrop and trap focus while open.
+    Modal,
 }
 
 impl PortalLayer {
@@ -27,6 +30,7 @@ impl PortalLayer {
     pub fn as_str(self) -> &'static str {
         match self {
             PortalLayer::Popover => "popover",
+            PortalLayer::Modal => "modal",
         }
     }
 }
@@ -77,6 +81,11 @@ impl PortalMount {
         Self::new(base_id, PortalLayer::Popover)
     }
 
+    /// Convenience constructor for modal style surfaces.
+    pub fn modal(base_id: impl Into<String>) -> Self {
+        Self::new(base_id, PortalLayer::Modal)
+    }
+
     /// Identifier applied to the hidden anchor element colocated with the
     /// trigger.
     pub fn anchor_id(&self) -> String {
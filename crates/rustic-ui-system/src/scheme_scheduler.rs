@@ -0,0 +1,212 @@
+//! Time based color scheme scheduling for unattended deployments.
+//!
+//! Kiosks and NOC dashboards run around the clock without a user toggling
+//! dark mode, so operators instead declare *when* each scheme applies: a
+//! fixed wall-clock window ("dark from 19:00 to 07:00") or the site's
+//! sunrise/sunset times.  [`SchemeScheduler`] evaluates that schedule,
+//! honours a persisted manual override (an operator pinning dark mode from a
+//! maintenance console must survive the nightly reboot) and reports when the
+//! next transition is due so hosts can arm a single timer instead of
+//! polling.
+//!
+//! The scheduler is deliberately clock-free: callers pass the local time as
+//! minutes since midnight, which keeps every decision deterministic in tests
+//! and leaves timezone handling to the host.  Overrides persist through
+//! [`rustic_ui_utils::storage`], the same pluggable provider used by the
+//! component layer, and degrade to in-memory state when no provider is
+//! installed.
+
+use crate::theme::{ColorScheme, Theme};
+use crate::theme_provider::theme_with_color_scheme;
+
+/// Minutes in one day; schedule arithmetic wraps at this boundary.
+const MINUTES_PER_DAY: u16 = 24 * 60;
+
+/// Declarative description of when dark mode applies.
+///
+/// Both constructors normalise their inputs modulo 24h, so "dark from 19:00
+/// to 07:00" and sunrise/sunset pairs that span midnight behave identically.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SchemeSchedule {
+    /// Minutes since local midnight when dark mode engages.
+    dark_start: u16,
+    /// Minutes since local midnight when light mode resumes.
+    dark_end: u16,
+}
+
+impl SchemeSchedule {
+    /// Fixed wall-clock window: dark mode between `dark_start` and
+    /// `dark_end` (both minutes since local midnight).
+    pub fn clock(dark_start: u16, dark_end: u16) -> Self {
+        Self {
+            dark_start: dark_start % MINUTES_PER_DAY,
+            dark_end: dark_end % MINUTES_PER_DAY,
+        }
+    }
+
+    /// Sunrise/sunset configuration: dark mode from sunset until sunrise.
+    /// Deployments feed in the site's published times (minutes since local
+    /// midnight) and refresh them as often as their accuracy demands.
+    pub fn sun(sunset: u16, sunrise: u16) -> Self {
+        Self::clock(sunset, sunrise)
+    }
+
+    /// Scheme dictated by the schedule at the given local time.
+    pub fn scheme_at(&self, minutes: u16) -> ColorScheme {
+        let minutes = minutes % MINUTES_PER_DAY;
+        let dark = if self.dark_start <= self.dark_end {
+            minutes >= self.dark_start && minutes < self.dark_end
+        } else {
+            // Window spans midnight (the common sunset -> sunrise case).
+            minutes >= self.dark_start || minutes < self.dark_end
+        };
+        if dark {
+            ColorScheme::Dark
+        } else {
+            ColorScheme::Light
+        }
+    }
+
+    /// Minutes until the schedule next flips relative to the given local
+    /// time.  Hosts arm one timer with this value instead of polling.
+    pub fn minutes_until_transition(&self, minutes: u16) -> u16 {
+        let minutes = minutes % MINUTES_PER_DAY;
+        let until =
+            |boundary: u16| (boundary + MINUTES_PER_DAY - minutes - 1) % MINUTES_PER_DAY + 1;
+        until(self.dark_start).min(until(self.dark_end))
+    }
+}
+
+/// Evaluates a [`SchemeSchedule`] while honouring a persisted operator
+/// override.
+///
+/// The override survives restarts through the storage provider installed via
+/// [`rustic_ui_utils::storage::install_provider`]; persistence failures are
+/// swallowed so a missing provider merely downgrades the pin to the current
+/// process lifetime.
+#[derive(Clone, Debug)]
+pub struct SchemeScheduler {
+    schedule: SchemeSchedule,
+    storage_key: String,
+    override_scheme: Option<ColorScheme>,
+}
+
+impl SchemeScheduler {
+    /// Creates a scheduler for the given deployment, reloading any override
+    /// persisted under the same `scheduler_id` by a previous run.
+    pub fn new(scheduler_id: &str, schedule: SchemeSchedule) -> Self {
+        let storage_key = format!("rustic.scheme.{scheduler_id}");
+        let override_scheme = rustic_ui_utils::storage::get_item(&storage_key)
+            .ok()
+            .flatten()
+            .and_then(|value| parse_scheme(&value));
+        Self {
+            schedule,
+            storage_key,
+            override_scheme,
+        }
+    }
+
+    /// Scheme in effect at the given local time: a pinned override wins,
+    /// otherwise the schedule decides.
+    pub fn scheme_at(&self, minutes: u16) -> ColorScheme {
+        self.override_scheme
+            .unwrap_or_else(|| self.schedule.scheme_at(minutes))
+    }
+
+    /// Pins the scheme regardless of the schedule and persists the choice.
+    /// High contrast is a valid pin so accessibility kiosks stay readable
+    /// through scheduled transitions.
+    pub fn set_override(&mut self, scheme: ColorScheme) {
+        self.override_scheme = Some(scheme);
+        let _ = rustic_ui_utils::storage::set_item(&self.storage_key, scheme.as_str());
+    }
+
+    /// Clears the pinned override and returns control to the schedule.
+    pub fn clear_override(&mut self) {
+        self.override_scheme = None;
+        let _ = rustic_ui_utils::storage::remove_item(&self.storage_key);
+    }
+
+    /// Currently pinned override, if any.
+    pub fn override_scheme(&self) -> Option<ColorScheme> {
+        self.override_scheme
+    }
+
+    /// Minutes until the active scheme next changes, or `None` while an
+    /// override pins it.  Hosts re-query after every transition.
+    pub fn minutes_until_transition(&self, minutes: u16) -> Option<u16> {
+        if self.override_scheme.is_some() {
+            return None;
+        }
+        Some(self.schedule.minutes_until_transition(minutes))
+    }
+
+    /// Returns the theme with the scheme for the given local time encoded,
+    /// ready to hand to a theme provider.
+    pub fn apply_to(&self, theme: Theme, minutes: u16) -> Theme {
+        theme_with_color_scheme(theme, self.scheme_at(minutes))
+    }
+}
+
+/// Inverse of [`ColorScheme::as_str`] used when reloading persisted pins.
+fn parse_scheme(value: &str) -> Option<ColorScheme> {
+    match value {
+        "light" => Some(ColorScheme::Light),
+        "dark" => Some(ColorScheme::Dark),
+        "high-contrast" => Some(ColorScheme::HighContrast),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SUNSET: u16 = 19 * 60;
+    const SUNRISE: u16 = 7 * 60;
+
+    #[test]
+    fn sun_schedule_darkens_across_midnight() {
+        let schedule = SchemeSchedule::sun(SUNSET, SUNRISE);
+        assert_eq!(schedule.scheme_at(12 * 60), ColorScheme::Light);
+        assert_eq!(schedule.scheme_at(SUNSET), ColorScheme::Dark);
+        assert_eq!(schedule.scheme_at(23 * 60), ColorScheme::Dark);
+        assert_eq!(schedule.scheme_at(3 * 60), ColorScheme::Dark);
+        assert_eq!(schedule.scheme_at(SUNRISE), ColorScheme::Light);
+    }
+
+    #[test]
+    fn transitions_report_the_next_boundary() {
+        let schedule = SchemeSchedule::sun(SUNSET, SUNRISE);
+        // Noon: the next flip is sunset, seven hours away.
+        assert_eq!(schedule.minutes_until_transition(12 * 60), 7 * 60);
+        // One minute before sunset.
+        assert_eq!(schedule.minutes_until_transition(SUNSET - 1), 1);
+        // Midnight: sunrise is next.
+        assert_eq!(schedule.minutes_until_transition(0), SUNRISE);
+    }
+
+    #[test]
+    fn overrides_pin_the_scheme_and_pause_scheduling() {
+        let mut scheduler =
+            SchemeScheduler::new("test-noc", SchemeSchedule::clock(SUNSET, SUNRISE));
+        assert_eq!(scheduler.scheme_at(12 * 60), ColorScheme::Light);
+        assert_eq!(scheduler.minutes_until_transition(12 * 60), Some(7 * 60));
+
+        scheduler.set_override(ColorScheme::HighContrast);
+        assert_eq!(scheduler.scheme_at(12 * 60), ColorScheme::HighContrast);
+        assert_eq!(scheduler.minutes_until_transition(12 * 60), None);
+
+        scheduler.clear_override();
+        assert_eq!(scheduler.override_scheme(), None);
+        assert_eq!(scheduler.scheme_at(12 * 60), ColorScheme::Light);
+    }
+
+    #[test]
+    fn applied_theme_encodes_the_scheduled_scheme() {
+        let scheduler = SchemeScheduler::new("test-kiosk", SchemeSchedule::sun(SUNSET, SUNRISE));
+        let theme = scheduler.apply_to(Theme::default(), 22 * 60);
+        assert_eq!(theme.palette.initial_color_scheme, ColorScheme::Dark);
+    }
+}
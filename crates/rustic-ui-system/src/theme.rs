@@ -1,4 +1,7 @@
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+pub mod presets;
 
 /// Enumerates the supported Material color schemes.
 ///
@@ -12,6 +15,11 @@ pub enum ColorScheme {
     Light,
     /// Darker backgrounds paired with lighter foreground content.
     Dark,
+    /// Maximum-contrast tokens for low vision users.  Mirrors the intent of
+    /// Windows High Contrast / `forced-colors` mode while remaining usable on
+    /// platforms without OS support by flipping the scheme explicitly.
+    #[serde(rename = "high-contrast")]
+    HighContrast,
 }
 
 impl Default for ColorScheme {
@@ -27,14 +35,19 @@ impl ColorScheme {
         match self {
             Self::Light => "light",
             Self::Dark => "dark",
+            Self::HighContrast => "high-contrast",
         }
     }
 
     /// Convenience helper used by toggling hooks.
+    ///
+    /// High contrast is an accessibility preference rather than one side of
+    /// the light/dark pair, so toggling leaves it untouched.
     pub fn toggled(self) -> Self {
         match self {
             Self::Light => Self::Dark,
             Self::Dark => Self::Light,
+            Self::HighContrast => Self::HighContrast,
         }
     }
 }
@@ -58,6 +71,23 @@ pub struct Theme {
     pub palette: Palette,
     /// Material typography ramp expressed in rems and point sizes.
     pub typography: TypographyScheme,
+    /// Animation durations, easing and the reduced-motion preference.
+    /// Defaulted during deserialization so themes serialized before the
+    /// tokens existed keep loading unchanged.
+    #[serde(default)]
+    pub motion: MotionScheme,
+    /// Visualization tokens (categorical series palette, grid lines, tooltip
+    /// surface) consumed by the lab chart widgets.  Defaulted during
+    /// deserialization so themes serialized before the tokens existed keep
+    /// loading unchanged.
+    #[serde(default)]
+    pub charts: ChartsScheme,
+    /// Per-component default props and style overrides, keyed by component
+    /// slot name.  Mirrors `theme.components` from the JS implementation and
+    /// is likewise defaulted during deserialization so older serialized
+    /// themes keep loading unchanged.
+    #[serde(default)]
+    pub components: ComponentOverrides,
     /// Joy specific design tokens such as corner radius and focus outlines.
     pub joy: JoyTheme,
 }
@@ -69,6 +99,9 @@ impl Default for Theme {
             breakpoints: Breakpoints::default(),
             palette: Palette::default(),
             typography: TypographyScheme::default(),
+            motion: MotionScheme::default(),
+            charts: ChartsScheme::default(),
+            components: ComponentOverrides::default(),
             joy: JoyTheme::default(),
         }
     }
@@ -100,6 +133,153 @@ impl Theme {
     {
         self.joy.merge_overrides(overrides);
     }
+
+    /// Returns a [`ThemeBuilder`] seeded with the canonical defaults.
+    pub fn builder() -> ThemeBuilder {
+        ThemeBuilder::default()
+    }
+
+    /// Resolves an [`IconSize`] token to pixels.
+    ///
+    /// The icon box equals the line box of the companion typography slot,
+    /// so icons consume density changes through the typography tokens
+    /// rather than a parallel scale. With the default ramp this yields
+    /// 18px / 24px / 36px for small / medium / large.
+    pub fn icon_size_px(&self, size: IconSize) -> f32 {
+        let slot_rem = match size {
+            IconSize::Small => self.typography.caption,
+            IconSize::Medium => self.typography.body1,
+            IconSize::Large => self.typography.h5,
+        };
+        slot_rem * self.typography.line_height * self.typography.html_font_size
+    }
+
+    /// Extra CSS declared for the given component slot via
+    /// [`ComponentOverrides`], if any.  Renderers append the compiled result
+    /// after their base style block so override rules win the cascade.
+    pub fn component_style_override(&self, component: &str) -> Option<&str> {
+        self.components.get(component)?.style_override.as_deref()
+    }
+
+    /// Default value declared for a prop of the given component slot via
+    /// [`ComponentOverrides`], if any.  Renderers consult this when the
+    /// caller leaves the prop unset, mirroring `defaultProps` resolution in
+    /// the JS implementation.
+    pub fn component_default_prop(&self, component: &str, prop: &str) -> Option<&str> {
+        self.components
+            .get(component)?
+            .default_props
+            .get(prop)
+            .map(String::as_str)
+    }
+
+    /// Resolved `:focus-visible` ring declarations (`outline` plus
+    /// `outline-offset`) driven by the focus tokens and the active palette.
+    ///
+    /// Every interactive renderer interpolates this single string instead of
+    /// assembling its own outline CSS, so a change to the focus thickness,
+    /// offset or palette channel restyles focus rings everywhere at once.
+    pub fn focus_ring_declarations(&self) -> String {
+        let channel = self.joy.focus.palette_reference.clone();
+        self.focus_ring_for_channel(&channel)
+    }
+
+    /// Variant of [`Theme::focus_ring_declarations`] resolving the ring colour
+    /// from a specific palette channel (`"primary"`, `"danger"`, ...), for
+    /// components whose focus affordance follows their tonal variant.
+    pub fn focus_ring_for_channel(&self, channel: &str) -> String {
+        let palette = self.palette.active();
+        let color = match channel {
+            "neutral" => palette.neutral.clone(),
+            "danger" => palette.danger.clone(),
+            "success" => palette.success.clone(),
+            "warning" => palette.warning.clone(),
+            "info" => palette.info.clone(),
+            "secondary" => palette.secondary.clone(),
+            "text" => palette.text_primary.clone(),
+            _ => palette.primary.clone(),
+        };
+        format!(
+            "outline: {}; outline-offset: {}px;",
+            self.joy.focus_outline_for_color(&color),
+            self.joy.focus.offset
+        )
+    }
+}
+
+/// Builder style helper for assembling a full [`Theme`].
+///
+/// Unlike [`JoyThemeBuilder`], which accumulates sparse overrides, this
+/// builder owns a complete theme seeded from the defaults: every field always
+/// holds a valid value, so presets and automation pipelines can layer
+/// adjustments in any order and call [`ThemeBuilder::build`] at any point.
+/// Nested sections accept closures rather than one setter per token — the
+/// palette and typography ramps are wide enough that mirroring each field
+/// here would only add churn whenever a token is introduced.
+#[derive(Clone, Debug, Default)]
+pub struct ThemeBuilder {
+    theme: Theme,
+}
+
+impl ThemeBuilder {
+    /// Override the base spacing unit (in pixels).
+    pub fn spacing(mut self, unit: u16) -> Self {
+        self.theme.spacing = unit;
+        self
+    }
+
+    /// Replace the responsive breakpoint grid.
+    pub fn breakpoints(mut self, breakpoints: Breakpoints) -> Self {
+        self.theme.breakpoints = breakpoints;
+        self
+    }
+
+    /// Declare which color scheme providers should activate initially.
+    pub fn initial_color_scheme(mut self, scheme: ColorScheme) -> Self {
+        self.theme.palette.initial_color_scheme = scheme;
+        self
+    }
+
+    /// Adjust the palette tokens for one specific color scheme.
+    pub fn palette_scheme<F>(mut self, scheme: ColorScheme, adjust: F) -> Self
+    where
+        F: FnOnce(&mut PaletteScheme),
+    {
+        adjust(self.theme.palette.scheme_mut(scheme));
+        self
+    }
+
+    /// Adjust the typography ramp.
+    pub fn typography<F>(mut self, adjust: F) -> Self
+    where
+        F: FnOnce(&mut TypographyScheme),
+    {
+        adjust(&mut self.theme.typography);
+        self
+    }
+
+    /// Adjust the motion tokens.
+    pub fn motion<F>(mut self, adjust: F) -> Self
+    where
+        F: FnOnce(&mut MotionScheme),
+    {
+        adjust(&mut self.theme.motion);
+        self
+    }
+
+    /// Layer Joy overrides on top of the accumulated theme.
+    pub fn joy_overrides<O>(mut self, overrides: O) -> Self
+    where
+        O: Into<JoyThemeOverrides>,
+    {
+        self.theme.apply_joy_overrides(overrides);
+        self
+    }
+
+    /// Finalises the builder into a fully resolved [`Theme`].
+    pub fn build(self) -> Theme {
+        self.theme
+    }
 }
 
 /// Breakpoint definitions in ascending order. Consumers can extend this
@@ -186,9 +366,9 @@ impl Default for PaletteScheme {
 
 /// Material color palette definitions for each supported color scheme.
 ///
-/// The struct stores separate [`PaletteScheme`] instances for light and dark
-/// operation so enterprise operators can vend both sets of tokens from a
-/// single configuration file.  Framework adapters are expected to honour the
+/// The struct stores separate [`PaletteScheme`] instances for light, dark and
+/// high contrast operation so enterprise operators can vend every set of
+/// tokens from a single configuration file.  Framework adapters are expected to honour the
 /// `initial_color_scheme` flag when emitting global styles or instantiating
 /// providers and expose hooks/state that allow flipping the active scheme at
 /// runtime without rebuilding the entire theme object.  This mirrors the
@@ -200,6 +380,11 @@ pub struct Palette {
     pub light: PaletteScheme,
     /// Dark mode tokens aligned to Material Design guidance.
     pub dark: PaletteScheme,
+    /// Maximum-contrast tokens modelled on Windows High Contrast palettes.
+    /// Defaulted during deserialization so themes serialized before the
+    /// scheme existed keep loading unchanged.
+    #[serde(default = "default_high_contrast_palette")]
+    pub high_contrast: PaletteScheme,
     /// Scheme that should be considered active when building CSS resets.
     #[serde(default)]
     pub initial_color_scheme: ColorScheme,
@@ -208,19 +393,13 @@ pub struct Palette {
 impl Palette {
     /// Returns the [`PaletteScheme`] declared as the initial/active scheme.
     pub fn active(&self) -> &PaletteScheme {
-        match self.initial_color_scheme {
-            ColorScheme::Light => &self.light,
-            ColorScheme::Dark => &self.dark,
-        }
+        self.scheme(self.initial_color_scheme)
     }
 
     /// Mutable variant of [`Palette::active`] used by helper utilities to
     /// update tokens in-place while maintaining the currently selected scheme.
     pub fn active_mut(&mut self) -> &mut PaletteScheme {
-        match self.initial_color_scheme {
-            ColorScheme::Light => &mut self.light,
-            ColorScheme::Dark => &mut self.dark,
-        }
+        self.scheme_mut(self.initial_color_scheme)
     }
 
     /// Returns a reference to a specific [`ColorScheme`] regardless of the
@@ -230,6 +409,7 @@ impl Palette {
         match scheme {
             ColorScheme::Light => &self.light,
             ColorScheme::Dark => &self.dark,
+            ColorScheme::HighContrast => &self.high_contrast,
         }
     }
 
@@ -238,6 +418,7 @@ impl Palette {
         match scheme {
             ColorScheme::Light => &mut self.light,
             ColorScheme::Dark => &mut self.dark,
+            ColorScheme::HighContrast => &mut self.high_contrast,
         }
     }
 }
@@ -258,11 +439,31 @@ fn default_dark_palette() -> PaletteScheme {
     }
 }
 
+/// High contrast tokens follow the Windows High Contrast Black convention:
+/// pure black surfaces, pure white text and saturated accent hues that stay
+/// legible at maximum contrast ratios.
+fn default_high_contrast_palette() -> PaletteScheme {
+    PaletteScheme {
+        primary: "#ffff00".to_string(),
+        secondary: "#00ffff".to_string(),
+        neutral: "#ffffff".to_string(),
+        danger: "#ff5555".to_string(),
+        success: "#55ff55".to_string(),
+        warning: "#ffb900".to_string(),
+        info: "#66ccff".to_string(),
+        background_default: "#000000".to_string(),
+        background_paper: "#000000".to_string(),
+        text_primary: "#ffffff".to_string(),
+        text_secondary: "#ffffff".to_string(),
+    }
+}
+
 impl Default for Palette {
     fn default() -> Self {
         Self {
             light: PaletteScheme::default(),
             dark: default_dark_palette(),
+            high_contrast: default_high_contrast_palette(),
             initial_color_scheme: ColorScheme::Light,
         }
     }
@@ -342,6 +543,210 @@ impl Default for TypographyScheme {
     }
 }
 
+/// Icon sizing tokens inheriting from the typography ramp.
+///
+/// Icons almost always sit next to text, so the scale resolves each token
+/// to the line box of a companion typography slot (`rem size × line height
+/// × html font size`) instead of carrying a second set of pixel knobs.
+/// Density overrides — a tighter ramp or a smaller `line_height` — shrink
+/// icons automatically, keeping them optically aligned with adjacent copy
+/// at any density setting.
+#[derive(Clone, Copy, Debug, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub enum IconSize {
+    /// Matches `caption` copy; dense toolbars and table rows.
+    Small,
+    /// Matches `body1` copy; the default for buttons and inputs.
+    #[default]
+    Medium,
+    /// Matches `h5` headings; hero affordances and empty states.
+    Large,
+}
+
+/// Motion design tokens controlling animation durations, easing and the
+/// reduced-motion escape hatch.
+///
+/// Components never hardcode transition timings; they read this scheme so a
+/// single override (or an OS level `prefers-reduced-motion` signal mapped
+/// onto [`MotionScheme::reduce`]) tunes or disables animation everywhere at
+/// once.  The `rustic-ui-motion` crate consumes the same tokens for its
+/// spring and tween interpolators.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+#[serde(default)]
+pub struct MotionScheme {
+    /// Duration for small transitions such as hover feedback (milliseconds).
+    pub duration_short_ms: u16,
+    /// Duration for standard enter/exit transitions like drawers and menus.
+    pub duration_standard_ms: u16,
+    /// Duration for large surface transitions such as full screen dialogs.
+    pub duration_long_ms: u16,
+    /// CSS easing keyword or `cubic-bezier(...)` expression shared by
+    /// transitions derived from the theme.
+    pub easing: String,
+    /// When `true` every theme-derived transition collapses to an instant
+    /// change. Providers set this from `prefers-reduced-motion` or an
+    /// explicit user preference.
+    pub reduce: bool,
+}
+
+impl Default for MotionScheme {
+    fn default() -> Self {
+        Self {
+            duration_short_ms: 120,
+            duration_standard_ms: 200,
+            duration_long_ms: 320,
+            easing: "ease".to_string(),
+            reduce: false,
+        }
+    }
+}
+
+impl MotionScheme {
+    /// Duration honoring the reduced-motion preference.
+    pub fn effective_duration_ms(&self, duration_ms: u16) -> u16 {
+        if self.reduce {
+            0
+        } else {
+            duration_ms
+        }
+    }
+
+    /// Builds a CSS `transition` value covering the given properties at the
+    /// supplied duration, or `none` under reduced motion.
+    pub fn transition_for(&self, properties: &[&str], duration_ms: u16) -> String {
+        if self.reduce || properties.is_empty() {
+            return "none".to_string();
+        }
+        properties
+            .iter()
+            .map(|property| format!("{property} {duration_ms}ms {}", self.easing))
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+
+    /// Standard-duration transition helper used by most component surfaces.
+    pub fn transition(&self, properties: &[&str]) -> String {
+        self.transition_for(properties, self.duration_standard_ms)
+    }
+}
+
+/// Visualization design tokens shared by every chart widget.
+///
+/// Brand teams override the categorical ramp once and every series — bars,
+/// lines, pie slices, legend swatches — follows.  The default ramp is the
+/// Okabe-Ito palette, chosen for its colour-vision-deficiency safety, so
+/// dashboards stay accessible even before a brand palette is configured.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+#[serde(default)]
+pub struct ChartsScheme {
+    /// Categorical series colours, cycled via [`ChartsScheme::series_color`]
+    /// when a chart draws more series than the ramp holds.
+    pub categorical: Vec<String>,
+    /// Stroke colour for grid lines and axis rules; translucent so it reads
+    /// on both light and dark plot backgrounds.
+    pub grid_line: String,
+    /// Background of chart tooltips and crosshair readouts.
+    pub tooltip_surface: String,
+    /// Text colour paired with [`ChartsScheme::tooltip_surface`].
+    pub tooltip_text: String,
+}
+
+impl Default for ChartsScheme {
+    fn default() -> Self {
+        Self {
+            categorical: [
+                "#0072b2", "#e69f00", "#009e73", "#cc79a7", "#56b4e9", "#d55e00", "#f0e442",
+                "#999999",
+            ]
+            .into_iter()
+            .map(str::to_string)
+            .collect(),
+            grid_line: "rgba(128, 128, 128, 0.28)".to_string(),
+            tooltip_surface: "rgba(33, 33, 33, 0.92)".to_string(),
+            tooltip_text: "#ffffff".to_string(),
+        }
+    }
+}
+
+impl ChartsScheme {
+    /// Colour for the n-th series, cycling through the categorical ramp.
+    /// Falls back to the grid colour if a theme declares an empty ramp so
+    /// charts never panic on malformed overrides.
+    pub fn series_color(&self, index: usize) -> &str {
+        if self.categorical.is_empty() {
+            return &self.grid_line;
+        }
+        &self.categorical[index % self.categorical.len()]
+    }
+}
+
+/// Per-component customisation registry, the strongly typed counterpart of
+/// `theme.components` in the JS implementation.
+///
+/// Entries are keyed by the component slot name used throughout the Material
+/// crate's automation hooks (`"button"`, `"chip"`, `"mobile-stepper"`, ...).
+/// Each entry can declare default prop values and a style override that the
+/// renderer appends after its themed base class, letting a design system
+/// restyle every instance of a component without wrapping it.
+#[derive(Clone, Debug, Default, Serialize, Deserialize, PartialEq)]
+pub struct ComponentOverrides {
+    /// Override entries keyed by component slot name.
+    #[serde(flatten)]
+    entries: BTreeMap<String, ComponentOverride>,
+}
+
+impl ComponentOverrides {
+    /// Registers (or replaces) the override entry for a component slot.
+    pub fn set(&mut self, component: impl Into<String>, component_override: ComponentOverride) {
+        self.entries.insert(component.into(), component_override);
+    }
+
+    /// Returns the override entry declared for a component slot, if any.
+    pub fn get(&self, component: &str) -> Option<&ComponentOverride> {
+        self.entries.get(component)
+    }
+
+    /// `true` when no component declares an override, letting renderers skip
+    /// the lookup on the common unstyled path.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+
+/// Customisation declared for a single component slot inside
+/// [`ComponentOverrides`].
+#[derive(Clone, Debug, Default, Serialize, Deserialize, PartialEq)]
+#[serde(default)]
+pub struct ComponentOverride {
+    /// CSS appended after the component's themed base style.  The snippet is
+    /// compiled into its own scoped class by the styled engine, so plain
+    /// declarations target the root element and `&` nesting works exactly as
+    /// it does inside `css_with_theme!` blocks.
+    pub style_override: Option<String>,
+    /// Default prop values applied when the caller leaves the prop unset,
+    /// keyed by prop name with stringly typed values (enums use their
+    /// serialized form, e.g. `"dots"`).
+    pub default_props: BTreeMap<String, String>,
+}
+
+impl ComponentOverride {
+    /// Creates an empty override; combine with the builder helpers below.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Declares the CSS appended after the component's base style.
+    pub fn with_style_override(mut self, css: impl Into<String>) -> Self {
+        self.style_override = Some(css.into());
+        self
+    }
+
+    /// Declares a default value for a prop of the component.
+    pub fn with_default_prop(mut self, prop: impl Into<String>, value: impl Into<String>) -> Self {
+        self.default_props.insert(prop.into(), value.into());
+        self
+    }
+}
+
 /// Joy specific design tokens that do not exist in the core Material theme.
 ///
 /// The metadata drives Joy component styling across frameworks, enables
@@ -417,6 +822,7 @@ impl JoyTheme {
         vec![
             "radius – shared corner rounding applied to Joy surfaces.",
             "focus.thickness – pixel width of the focus outline + shadow.",
+            "focus.offset – pixel gap between component edge and focus ring.",
             "focus.palette_reference – palette key resolved for focus rings.",
             "focus.outline_template – string template used for outline CSS.",
             "shadow.focus_ring_template – format string for Joy focus shadows.",
@@ -435,6 +841,7 @@ impl JoyTheme {
             "radius": default.radius,
             "focus": {
                 "thickness": default.focus.thickness,
+                "offset": default.focus.offset,
                 "palette_reference": default.focus.palette_reference,
                 "outline_template": default.focus.outline_template,
             },
@@ -467,6 +874,8 @@ impl Default for JoyTheme {
 pub struct JoyFocus {
     /// Thickness in pixels of the default focus ring used for accessibility.
     pub thickness: u8,
+    /// Gap in pixels between the component edge and the focus ring.
+    pub offset: u8,
     /// Palette key resolved to produce the focus colour. Defaults to the Joy
     /// primary colour so focus affordances align with brand accents.
     pub palette_reference: String,
@@ -488,6 +897,7 @@ impl Default for JoyFocus {
     fn default() -> Self {
         Self {
             thickness: 2,
+            offset: 2,
             palette_reference: "primary".to_string(),
             outline_template: "{thickness}px solid {color}".to_string(),
         }
@@ -606,6 +1016,15 @@ impl JoyThemeBuilder {
         self
     }
 
+    /// Override the focus ring offset (in pixels).
+    pub fn focus_offset(mut self, offset: u8) -> Self {
+        self.overrides
+            .focus
+            .get_or_insert_with(Default::default)
+            .offset = Some(offset);
+        self
+    }
+
     /// Update the palette slot powering focus indicators.
     pub fn focus_palette_reference<S>(mut self, slot: S) -> Self
     where
@@ -690,6 +1109,7 @@ impl JoyThemeBuilder {
 #[serde(default)]
 pub struct JoyFocusOverrides {
     pub thickness: Option<u8>,
+    pub offset: Option<u8>,
     pub palette_reference: Option<String>,
     pub outline_template: Option<String>,
 }
@@ -699,6 +1119,9 @@ impl JoyFocusOverrides {
         if let Some(thickness) = self.thickness {
             focus.thickness = thickness;
         }
+        if let Some(offset) = self.offset {
+            focus.offset = offset;
+        }
         if let Some(reference) = self.palette_reference {
             focus.palette_reference = reference;
         }
@@ -774,6 +1197,82 @@ mod tests {
         assert_eq!(theme, de);
     }
 
+    #[test]
+    fn icon_sizes_inherit_from_the_typography_ramp() {
+        let theme = Theme::default();
+        assert_eq!(theme.icon_size_px(IconSize::Small), 18.0);
+        assert_eq!(theme.icon_size_px(IconSize::Medium), 24.0);
+        assert_eq!(theme.icon_size_px(IconSize::Large), 36.0);
+
+        // A denser ramp resizes every icon without touching a second scale.
+        let mut dense = Theme::default();
+        dense.typography.line_height = 1.25;
+        assert_eq!(dense.icon_size_px(IconSize::Medium), 20.0);
+    }
+
+    #[test]
+    fn chart_tokens_cycle_and_survive_legacy_payloads() {
+        let theme = Theme::default();
+        let ramp_len = theme.charts.categorical.len();
+        assert!(ramp_len >= 8, "default ramp covers typical dashboards");
+        assert_eq!(theme.charts.series_color(0), theme.charts.categorical[0]);
+        assert_eq!(
+            theme.charts.series_color(ramp_len + 2),
+            theme.charts.categorical[2]
+        );
+
+        // An emptied ramp degrades to the grid colour instead of panicking.
+        let mut charts = ChartsScheme::default();
+        charts.categorical.clear();
+        assert_eq!(charts.series_color(5), charts.grid_line);
+
+        // Themes serialized before the tokens existed keep deserializing.
+        let mut legacy = serde_json::to_value(Theme::default()).expect("to value");
+        legacy
+            .as_object_mut()
+            .expect("theme object")
+            .remove("charts");
+        let legacy: Theme = serde_json::from_value(legacy).expect("legacy deserialize");
+        assert_eq!(legacy.charts, ChartsScheme::default());
+    }
+
+    #[test]
+    fn component_overrides_resolve_and_survive_legacy_payloads() {
+        let mut theme = Theme::default();
+        assert!(theme.components.is_empty());
+
+        theme.components.set(
+            "button",
+            ComponentOverride::new()
+                .with_style_override("text-transform: uppercase;")
+                .with_default_prop("variant", "outlined"),
+        );
+        assert_eq!(
+            theme.component_style_override("button"),
+            Some("text-transform: uppercase;")
+        );
+        assert_eq!(
+            theme.component_default_prop("button", "variant"),
+            Some("outlined")
+        );
+        assert_eq!(theme.component_default_prop("button", "size"), None);
+        assert_eq!(theme.component_style_override("chip"), None);
+
+        // Entries survive the serde round trip used by theme templates.
+        let json = serde_json::to_string(&theme).expect("serialize");
+        let reloaded: Theme = serde_json::from_str(&json).expect("deserialize");
+        assert_eq!(reloaded, theme);
+
+        // Themes serialized before the registry existed keep deserializing.
+        let mut legacy = serde_json::to_value(Theme::default()).expect("to value");
+        legacy
+            .as_object_mut()
+            .expect("theme object")
+            .remove("components");
+        let legacy: Theme = serde_json::from_value(legacy).expect("legacy deserialize");
+        assert!(legacy.components.is_empty());
+    }
+
     #[test]
     fn palette_defaults_cover_light_and_dark_joy_colors() {
         let palette = Palette::default();
@@ -799,6 +1298,64 @@ mod tests {
         }
     }
 
+    #[test]
+    fn focus_ring_declarations_resolve_tokens_and_channels() {
+        let theme = Theme::default();
+        assert_eq!(
+            theme.focus_ring_declarations(),
+            "outline: 2px solid #1976d2; outline-offset: 2px;"
+        );
+        assert!(theme.focus_ring_for_channel("danger").contains("#d32f2f"));
+        assert!(theme
+            .focus_ring_for_channel("text")
+            .contains(&theme.palette.active().text_primary));
+
+        let custom = Theme::with_joy_overrides(
+            JoyTheme::builder()
+                .focus_thickness(3)
+                .focus_offset(1)
+                .build(),
+        );
+        assert_eq!(
+            custom.focus_ring_declarations(),
+            "outline: 3px solid #1976d2; outline-offset: 1px;"
+        );
+    }
+
+    #[test]
+    fn high_contrast_scheme_resolves_through_the_palette() {
+        let mut palette = Palette::default();
+        assert_eq!(
+            palette.scheme(ColorScheme::HighContrast).background_default,
+            "#000000"
+        );
+        assert_eq!(
+            palette.scheme(ColorScheme::HighContrast).text_primary,
+            "#ffffff"
+        );
+
+        palette.initial_color_scheme = ColorScheme::HighContrast;
+        assert_eq!(palette.active().primary, "#ffff00");
+        // Toggling is a light/dark affair; the accessibility preference wins.
+        assert_eq!(
+            ColorScheme::HighContrast.toggled(),
+            ColorScheme::HighContrast
+        );
+        assert_eq!(ColorScheme::HighContrast.as_str(), "high-contrast");
+    }
+
+    #[test]
+    fn high_contrast_deserializes_from_older_themes() {
+        // A palette serialized before the high contrast scheme existed must
+        // keep loading, with the new tokens falling back to the defaults.
+        let legacy = serde_json::json!({
+            "light": PaletteScheme::default(),
+            "dark": default_dark_palette(),
+        });
+        let palette: Palette = serde_json::from_value(legacy).expect("deserialize");
+        assert_eq!(palette.high_contrast, default_high_contrast_palette());
+    }
+
     #[test]
     fn joy_theme_builder_applies_overrides() {
         let overrides = JoyTheme::builder()
@@ -0,0 +1,161 @@
+//! Gallery of fully-resolved branded themes built through
+//! [`ThemeBuilder`](super::ThemeBuilder).
+//!
+//! Teams bootstrapping a product rarely want to mutate [`Theme::default`]
+//! field by field — they want a vetted starting point that already balances
+//! contrast, density and mood. Each preset here is a complete theme (not a
+//! sparse override set), so it serializes, diffs and round-trips exactly like
+//! a hand-authored configuration. The presets are selectable by their
+//! kebab-case name via [`preset`], which is the hook the `generate-theme`
+//! xtask uses to export them as JSON/TOML templates for non-Rust tooling.
+
+use super::{ColorScheme, Theme};
+
+/// Names accepted by [`preset`], in gallery order.
+pub const PRESET_NAMES: [&str; 4] = ["high-contrast", "dense-enterprise", "pastel", "dark-first"];
+
+/// Resolve a preset by its kebab-case name.
+///
+/// Returns `None` for unknown names so CLI callers can print
+/// [`PRESET_NAMES`] instead of guessing at typos.
+#[must_use]
+pub fn preset(name: &str) -> Option<Theme> {
+    match name {
+        "high-contrast" => Some(high_contrast()),
+        "dense-enterprise" => Some(dense_enterprise()),
+        "pastel" => Some(pastel()),
+        "dark-first" => Some(dark_first()),
+        _ => None,
+    }
+}
+
+/// WCAG-first theme: pure black-on-white (and white-on-black) surfaces with
+/// saturated accents that clear the AAA 7:1 contrast threshold, a thicker
+/// Joy focus indicator and reduced motion by default.
+#[must_use]
+pub fn high_contrast() -> Theme {
+    Theme::builder()
+        .palette_scheme(ColorScheme::Light, |palette| {
+            palette.primary = "#0000c7".into();
+            palette.secondary = "#8f0057".into();
+            palette.danger = "#b00020".into();
+            palette.background_default = "#ffffff".into();
+            palette.background_paper = "#ffffff".into();
+            palette.text_primary = "#000000".into();
+            palette.text_secondary = "#1f1f1f".into();
+        })
+        .palette_scheme(ColorScheme::Dark, |palette| {
+            palette.primary = "#ffd600".into();
+            palette.secondary = "#00e5ff".into();
+            palette.background_default = "#000000".into();
+            palette.background_paper = "#000000".into();
+            palette.text_primary = "#ffffff".into();
+            palette.text_secondary = "#e0e0e0".into();
+        })
+        .motion(|motion| motion.reduce = true)
+        .joy_overrides(super::JoyTheme::builder().focus_thickness(3).radius(0))
+        .build()
+}
+
+/// Data-heavy back office theme: a 4px spacing unit, a tighter typography
+/// ramp and squarer corners so grids and forms fit noticeably more rows per
+/// viewport without shrinking tap targets below usability floors.
+#[must_use]
+pub fn dense_enterprise() -> Theme {
+    Theme::builder()
+        .spacing(4)
+        .typography(|typography| {
+            typography.font_size = 13.0;
+            typography.body1 = 0.875;
+            typography.body2 = 0.8125;
+            typography.button = 0.8125;
+            typography.line_height = 1.4;
+        })
+        .motion(|motion| {
+            motion.duration_short_ms = 80;
+            motion.duration_standard_ms = 140;
+            motion.duration_long_ms = 220;
+        })
+        .joy_overrides(super::JoyTheme::builder().radius(4))
+        .build()
+}
+
+/// Soft marketing-friendly theme: muted pastel accents over warm off-white
+/// surfaces with generously rounded corners.
+#[must_use]
+pub fn pastel() -> Theme {
+    Theme::builder()
+        .palette_scheme(ColorScheme::Light, |palette| {
+            palette.primary = "#7c9ed9".into();
+            palette.secondary = "#e8a0bf".into();
+            palette.neutral = "#a3b2c2".into();
+            palette.success = "#8fc9a8".into();
+            palette.warning = "#f2c57c".into();
+            palette.info = "#9ad1d4".into();
+            palette.background_default = "#fdf8f2".into();
+            palette.background_paper = "#fffdf9".into();
+            palette.text_primary = "#3d4a57".into();
+            palette.text_secondary = "#6b7a89".into();
+        })
+        .joy_overrides(super::JoyTheme::builder().radius(12))
+        .build()
+}
+
+/// Dark-first theme for developer tooling and dashboards: identical tokens
+/// to the defaults but with the dark scheme declared active, so SSR emits
+/// dark CSS baselines without waiting for a client-side scheme flip.
+#[must_use]
+pub fn dark_first() -> Theme {
+    Theme::builder()
+        .initial_color_scheme(ColorScheme::Dark)
+        .build()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn every_advertised_name_resolves() {
+        for name in PRESET_NAMES {
+            assert!(preset(name).is_some(), "preset `{name}` should resolve");
+        }
+        assert!(preset("brutalist").is_none());
+    }
+
+    #[test]
+    fn high_contrast_flattens_surfaces_and_motion() {
+        let theme = high_contrast();
+        assert_eq!(theme.palette.light.text_primary, "#000000");
+        assert_eq!(theme.palette.dark.background_default, "#000000");
+        assert!(theme.motion.reduce);
+        assert_eq!(theme.joy.focus.thickness, 3);
+    }
+
+    #[test]
+    fn dense_enterprise_tightens_density_only() {
+        let theme = dense_enterprise();
+        assert_eq!(theme.spacing(2), 8);
+        assert_eq!(theme.typography.font_size, 13.0);
+        // Density presets must not drift the palette away from the brand.
+        assert_eq!(theme.palette, Theme::default().palette);
+    }
+
+    #[test]
+    fn dark_first_only_flips_the_active_scheme() {
+        let theme = dark_first();
+        assert_eq!(theme.palette.initial_color_scheme, ColorScheme::Dark);
+        assert_eq!(theme.palette.active(), &theme.palette.dark);
+        assert_eq!(theme.palette.dark, Theme::default().palette.dark);
+    }
+
+    #[test]
+    fn presets_round_trip_through_serde() {
+        for name in PRESET_NAMES {
+            let theme = preset(name).unwrap();
+            let json = serde_json::to_string(&theme).unwrap();
+            let restored: Theme = serde_json::from_str(&json).unwrap();
+            assert_eq!(restored, theme, "preset `{name}` should round-trip");
+        }
+    }
+}
@@ -21,6 +21,11 @@ pub fn material_theme_dark() -> Theme {
     material_theme_for_scheme(ColorScheme::Dark)
 }
 
+/// Returns the Material theme pre-configured for high contrast mode.
+pub fn material_theme_high_contrast() -> Theme {
+    material_theme_for_scheme(ColorScheme::HighContrast)
+}
+
 /// Builds the canonical Material theme but forces the initial color scheme to
 /// the supplied mode.  This helper is the recommended entrypoint for
 /// automated pipelines that need to render artifacts for each scheme without
@@ -92,12 +97,13 @@ pub fn material_css_baseline_from_theme(theme: &Theme) -> String {
     let active_palette = theme.palette.active();
     let light_palette = &theme.palette.light;
     let dark_palette = &theme.palette.dark;
+    let high_contrast_palette = &theme.palette.high_contrast;
     let joy_focus_color = theme.joy.focus_color_from_palette(active_palette);
     let joy_focus_outline = theme.joy.focus_outline_for_color(&joy_focus_color);
     let joy_focus_shadow = theme.joy.focus_shadow_for_color(&joy_focus_color);
 
     format!(
-        "/* Global baseline generated from the strongly typed Material theme.\n   Enterprise operators: adjust the `data-rustic_ui_color_scheme` attribute on the document element to flip between modes without rebuilding CSS. */\nhtml {{\n    box-sizing: border-box;\n    font-family: {};\n    font-size: {}px;\n    -webkit-font-smoothing: antialiased;\n    -moz-osx-font-smoothing: grayscale;\n    color-scheme: {};\n    background-color: {};\n    color: {};\n}}\n\n*, *::before, *::after {{\n    box-sizing: inherit;\n}}\n\n:root {{\n    color-scheme: {};\n    /* Joy automation hook: the custom properties below stay in sync with `cargo xtask generate-theme --joy`. */\n    --joy-radius: {}px;\n    --joy-focus-outline: {};\n    --joy-focus-shadow: {};\n}}\n\nbody {{\n    margin: 0;\n    min-height: 100vh;\n    font-family: {};\n    font-size: {}px;\n    line-height: {};\n    background-color: {};\n    color: {};\n}}\n\nstrong, b {{\n    font-weight: {};\n}}\n\ncode, pre {{\n    font-family: {};\n}}\n\n/* Data attribute selectors keep automated deployments deterministic by allowing infrastructure to force a mode before JS boots. */\n[data-rustic_ui_color_scheme='light'] html,\n[data-rustic_ui_color_scheme='light'] body {{\n    background-color: {};\n    color: {};\n}}\n\n[data-rustic_ui_color_scheme='light'] :root {{\n    color-scheme: light;\n}}\n\n[data-rustic_ui_color_scheme='dark'] html,\n[data-rustic_ui_color_scheme='dark'] body {{\n    background-color: {};\n    color: {};\n}}\n\n[data-rustic_ui_color_scheme='dark'] :root {{\n    color-scheme: dark;\n}}\n\n/* Respect end-user preference media queries so SSR output automatically matches OS settings even before hydration. */\n@media (prefers-color-scheme: dark) {{\n    :root {{\n        color-scheme: dark;\n    }}\n\n    html, body {{\n        background-color: {};\n        color: {};\n    }}\n}}\n\n@media (prefers-color-scheme: light) {{\n    :root {{\n        color-scheme: light;\n    }}\n\n    html, body {{\n        background-color: {};\n        color: {};\n    }}\n}}\n",
+        "/* Global baseline generated from the strongly typed Material theme.\n   Enterprise operators: adjust the `data-rustic_ui_color_scheme` attribute on the document element to flip between modes without rebuilding CSS. */\nhtml {{\n    box-sizing: border-box;\n    font-family: {};\n    font-size: {}px;\n    -webkit-font-smoothing: antialiased;\n    -moz-osx-font-smoothing: grayscale;\n    color-scheme: {};\n    background-color: {};\n    color: {};\n}}\n\n*, *::before, *::after {{\n    box-sizing: inherit;\n}}\n\n:root {{\n    color-scheme: {};\n    /* Joy automation hook: the custom properties below stay in sync with `cargo xtask generate-theme --joy`. */\n    --joy-radius: {}px;\n    --joy-focus-outline: {};\n    --joy-focus-shadow: {};\n}}\n\nbody {{\n    margin: 0;\n    min-height: 100vh;\n    font-family: {};\n    font-size: {}px;\n    line-height: {};\n    background-color: {};\n    color: {};\n}}\n\nstrong, b {{\n    font-weight: {};\n}}\n\ncode, pre {{\n    font-family: {};\n}}\n\n/* Data attribute selectors keep automated deployments deterministic by allowing infrastructure to force a mode before JS boots. */\n[data-rustic_ui_color_scheme='light'] html,\n[data-rustic_ui_color_scheme='light'] body {{\n    background-color: {};\n    color: {};\n}}\n\n[data-rustic_ui_color_scheme='light'] :root {{\n    color-scheme: light;\n}}\n\n[data-rustic_ui_color_scheme='dark'] html,\n[data-rustic_ui_color_scheme='dark'] body {{\n    background-color: {};\n    color: {};\n}}\n\n[data-rustic_ui_color_scheme='dark'] :root {{\n    color-scheme: dark;\n}}\n\n[data-rustic_ui_color_scheme='high-contrast'] html,\n[data-rustic_ui_color_scheme='high-contrast'] body {{\n    background-color: {};\n    color: {};\n}}\n\n[data-rustic_ui_color_scheme='high-contrast'] :root {{\n    color-scheme: dark;\n}}\n\n/* Respect end-user preference media queries so SSR output automatically matches OS settings even before hydration. */\n@media (prefers-color-scheme: dark) {{\n    :root {{\n        color-scheme: dark;\n    }}\n\n    html, body {{\n        background-color: {};\n        color: {};\n    }}\n}}\n\n@media (prefers-color-scheme: light) {{\n    :root {{\n        color-scheme: light;\n    }}\n\n    html, body {{\n        background-color: {};\n        color: {};\n    }}\n}}\n\n/* Forced colors (Windows High Contrast): defer to the user's system palette and rebuild borders and focus rings from system color keywords so every component keeps a perceivable boundary without per-app work. */\n@media (forced-colors: active) {{\n    html, body {{\n        background-color: Canvas;\n        color: CanvasText;\n    }}\n\n    a {{\n        color: LinkText;\n    }}\n\n    button, input, select, textarea, [role='button'] {{\n        border: 1px solid ButtonText;\n    }}\n\n    :focus-visible {{\n        outline: 3px solid Highlight;\n        outline-offset: 2px;\n    }}\n}}\n\n/* Reduced motion: collapse animations and transitions as soon as the OS preference is visible to CSS, before hydration flips `theme.motion.reduce`. The `data-rustic_ui_reduced_motion` attribute mirrors the MotionPreference override API so applications can force either direction. */\n@media (prefers-reduced-motion: reduce) {{\n    :root:not([data-rustic_ui_reduced_motion='no-preference']) *,\n    :root:not([data-rustic_ui_reduced_motion='no-preference']) *::before,\n    :root:not([data-rustic_ui_reduced_motion='no-preference']) *::after {{\n        animation-duration: 0.01ms !important;\n        animation-iteration-count: 1 !important;\n        transition-duration: 0.01ms !important;\n        scroll-behavior: auto !important;\n    }}\n}}\n\n[data-rustic_ui_reduced_motion='reduce'] *,\n[data-rustic_ui_reduced_motion='reduce'] *::before,\n[data-rustic_ui_reduced_motion='reduce'] *::after {{\n    animation-duration: 0.01ms !important;\n    animation-iteration-count: 1 !important;\n    transition-duration: 0.01ms !important;\n    scroll-behavior: auto !important;\n}}\n",
         theme.typography.font_family,
         html_font_size,
         active_scheme.as_str(),
@@ -118,6 +124,8 @@ pub fn material_css_baseline_from_theme(theme: &Theme) -> String {
         light_palette.text_primary,
         dark_palette.background_default,
         dark_palette.text_primary,
+        high_contrast_palette.background_default,
+        high_contrast_palette.text_primary,
         dark_palette.background_default,
         dark_palette.text_primary,
         light_palette.background_default,
@@ -125,6 +133,88 @@ pub fn material_css_baseline_from_theme(theme: &Theme) -> String {
     )
 }
 
+/// Application level override for the reduced motion behaviour.
+///
+/// Providers resolve this against the OS `prefers-reduced-motion` media query
+/// and flip [`MotionScheme::reduce`](crate::theme::MotionScheme) accordingly,
+/// so every theme-derived transition and the ripple system shorten or disable
+/// animation without per-component wiring.  The explicit variants let
+/// accessibility settings screens override the OS signal in either direction.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum MotionPreference {
+    /// Follow the OS level `prefers-reduced-motion` media query.
+    #[default]
+    System,
+    /// Force full animations even when the OS requests reduced motion.
+    Full,
+    /// Force reduced motion regardless of the OS signal.
+    Reduced,
+}
+
+impl MotionPreference {
+    /// Resolves the preference to the effective reduced-motion flag.
+    ///
+    /// [`MotionPreference::System`] consults `matchMedia` in the browser and
+    /// falls back to full motion on platforms without a detectable signal
+    /// (SSR, tests, native shells).
+    #[must_use]
+    pub fn reduced(self) -> bool {
+        match self {
+            Self::System => detect_user_prefers_reduced_motion().unwrap_or(false),
+            Self::Full => false,
+            Self::Reduced => true,
+        }
+    }
+
+    /// Value stamped on the `data-rustic_ui_reduced_motion` DOM attribute so
+    /// the generated CSS baseline can honour explicit overrides before any
+    /// component re-renders.
+    #[must_use]
+    pub fn as_attribute_value(self) -> &'static str {
+        match self {
+            Self::System => "system",
+            Self::Full => "no-preference",
+            Self::Reduced => "reduce",
+        }
+    }
+}
+
+/// Applies the resolved motion preference to a cloned [`Theme`] by flipping
+/// [`MotionScheme::reduce`](crate::theme::MotionScheme), mirroring
+/// [`theme_with_color_scheme`] for builder style flows.
+pub fn theme_with_motion_preference(mut theme: Theme, preference: MotionPreference) -> Theme {
+    theme.motion.reduce = preference.reduced();
+    theme
+}
+
+#[cfg(target_arch = "wasm32")]
+fn detect_user_prefers_reduced_motion() -> Option<bool> {
+    web_sys::window()
+        .and_then(|window| window.match_media("(prefers-reduced-motion: reduce)").ok())
+        .flatten()
+        .map(|media| media.matches())
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn detect_user_prefers_reduced_motion() -> Option<bool> {
+    None
+}
+
+#[cfg(target_arch = "wasm32")]
+fn push_motion_preference_to_dom(preference: MotionPreference) {
+    if let Some(document) = web_sys::window().and_then(|window| window.document()) {
+        if let Some(root) = document.document_element() {
+            let _ = root.set_attribute(
+                "data-rustic_ui_reduced_motion",
+                preference.as_attribute_value(),
+            );
+        }
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn push_motion_preference_to_dom(_preference: MotionPreference) {}
+
 #[cfg(target_arch = "wasm32")]
 fn detect_user_prefers_dark() -> Option<bool> {
     web_sys::window()
@@ -255,6 +345,64 @@ mod yew_impl {
         UseMaterialColorScheme { state }
     }
 
+    /// Rich handle returned by [`use_reduced_motion`] mirroring
+    /// [`UseMaterialColorScheme`] for the motion preference.
+    #[derive(Clone)]
+    pub struct UseReducedMotion {
+        state: UseStateHandle<MotionPreference>,
+    }
+
+    impl UseReducedMotion {
+        /// Returns the currently selected [`MotionPreference`].
+        pub fn preference(&self) -> MotionPreference {
+            *self.state
+        }
+
+        /// Resolved reduced-motion flag after consulting the OS signal.
+        pub fn reduced(&self) -> bool {
+            self.preference().reduced()
+        }
+
+        /// Overwrite the active preference, e.g. from a settings screen.
+        pub fn set(&self, preference: MotionPreference) {
+            self.state.set(preference);
+        }
+
+        /// Convenience helper returning a [`Callback`] for toggles.
+        pub fn setter(&self) -> Callback<MotionPreference> {
+            let state = self.state.clone();
+            Callback::from(move |preference| state.set(preference))
+        }
+
+        /// Applies the resolved flag to the provided [`Theme`], returning a
+        /// clone with [`MotionScheme::reduce`](crate::theme::MotionScheme)
+        /// encoded so transitions and ripples pick it up on the next render.
+        pub fn apply_to(&self, theme: Theme) -> Theme {
+            theme_with_motion_preference(theme, self.preference())
+        }
+    }
+
+    /// Tracks the reduced-motion preference, honouring the OS level
+    /// `prefers-reduced-motion` media query until the application overrides
+    /// it.  The hook mirrors [`use_material_color_scheme`]: the DOM attribute
+    /// stays in sync so the CSS baseline collapses animations immediately,
+    /// and [`UseReducedMotion::apply_to`] flows the flag into the theme for
+    /// components that derive transitions from motion tokens.
+    #[hook]
+    pub fn use_reduced_motion() -> UseReducedMotion {
+        let state = use_state(MotionPreference::default);
+
+        {
+            let state = state.clone();
+            use_effect_with(*state, move |preference: &MotionPreference| {
+                push_motion_preference_to_dom(*preference);
+                || ()
+            });
+        }
+
+        UseReducedMotion { state }
+    }
+
     /// Properties accepted by [`CssBaseline`].
     #[derive(Properties, PartialEq, Default)]
     pub struct CssBaselineProps {
@@ -296,17 +444,18 @@ mod yew_impl {
 
 #[cfg(feature = "yew")]
 pub use yew_impl::{
-    use_material_color_scheme, use_theme, CssBaseline, CssBaselineProps, GlobalStyles,
-    ThemeProvider, ThemeProviderProps, UseMaterialColorScheme,
+    use_material_color_scheme, use_reduced_motion, use_theme, CssBaseline, CssBaselineProps,
+    GlobalStyles, ThemeProvider, ThemeProviderProps, UseMaterialColorScheme, UseReducedMotion,
 };
 
 #[cfg(feature = "yew")]
 pub use yew_impl::{
-    use_material_color_scheme as use_material_color_scheme_yew, use_theme as use_theme_yew,
+    use_material_color_scheme as use_material_color_scheme_yew,
+    use_reduced_motion as use_reduced_motion_yew, use_theme as use_theme_yew,
     CssBaseline as CssBaselineYew, CssBaselineProps as CssBaselinePropsYew,
     GlobalStyles as GlobalStylesYew, ThemeProvider as ThemeProviderYew,
     ThemeProviderProps as ThemeProviderPropsYew,
-    UseMaterialColorScheme as UseMaterialColorSchemeYew,
+    UseMaterialColorScheme as UseMaterialColorSchemeYew, UseReducedMotion as UseReducedMotionYew,
 };
 
 #[cfg(feature = "leptos")]
@@ -380,6 +529,51 @@ mod leptos_impl {
         view! { <style>{css}</style> }
     }
 
+    /// Handle returned by [`use_reduced_motion`] for Leptos adapters.
+    #[derive(Clone, Copy)]
+    pub struct ReducedMotionHandle {
+        preference: RwSignal<MotionPreference>,
+    }
+
+    impl ReducedMotionHandle {
+        /// Currently selected preference.
+        pub fn preference(&self) -> MotionPreference {
+            self.preference.get()
+        }
+
+        /// Resolved reduced-motion flag after consulting the OS signal.
+        pub fn reduced(&self) -> bool {
+            self.preference().reduced()
+        }
+
+        /// Expose a read-only signal for UI bindings.
+        pub fn signal(&self) -> ReadSignal<MotionPreference> {
+            self.preference.read_only()
+        }
+
+        /// Imperatively update the preference.
+        pub fn set(&self, preference: MotionPreference) {
+            self.preference.set(preference);
+        }
+
+        /// Apply the resolved flag to a cloned [`Theme`].
+        pub fn apply_to(&self, theme: Theme) -> Theme {
+            theme_with_motion_preference(theme, self.preference())
+        }
+    }
+
+    /// Leptos hook mirroring the Yew `use_reduced_motion` implementation,
+    /// keeping the DOM attribute in sync for the generated CSS selectors.
+    pub fn use_reduced_motion() -> ReducedMotionHandle {
+        let preference = create_rw_signal(MotionPreference::default());
+
+        create_effect(move |_| {
+            push_motion_preference_to_dom(preference.get());
+        });
+
+        ReducedMotionHandle { preference }
+    }
+
     /// Leptos hook mirroring [`use_material_color_scheme`] for Yew.  Returns a
     /// handle that drives UI elements and keeps the DOM attribute in sync for
     /// the generated CSS selectors.
@@ -409,16 +603,18 @@ mod leptos_impl {
 }
 
 #[cfg(feature = "leptos")]
-pub use leptos_impl::MaterialColorSchemeHandle;
+pub use leptos_impl::{MaterialColorSchemeHandle, ReducedMotionHandle};
 
 #[cfg(all(feature = "leptos", not(feature = "yew")))]
 pub use leptos_impl::{
-    use_material_color_scheme, use_theme, CssBaseline, GlobalStyles, ThemeProvider,
+    use_material_color_scheme, use_reduced_motion, use_theme, CssBaseline, GlobalStyles,
+    ThemeProvider,
 };
 
 #[cfg(feature = "leptos")]
 pub use leptos_impl::{
-    use_material_color_scheme as use_material_color_scheme_leptos, use_theme as use_theme_leptos,
+    use_material_color_scheme as use_material_color_scheme_leptos,
+    use_reduced_motion as use_reduced_motion_leptos, use_theme as use_theme_leptos,
     CssBaseline as CssBaselineLeptos, GlobalStyles as GlobalStylesLeptos,
     ThemeProvider as ThemeProviderLeptos,
 };
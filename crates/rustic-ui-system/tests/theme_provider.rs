@@ -4,8 +4,9 @@ extern crate rustic_ui_system as rustic_ui_styled_engine;
 use rustic_ui_system::theme::{ColorScheme, Palette};
 use rustic_ui_system::theme_provider::{
     material_css_baseline, material_css_baseline_from_theme, material_theme, material_theme_dark,
-    material_theme_for_scheme, material_theme_light, material_theme_with_optional_overrides,
-    material_theme_with_overrides,
+    material_theme_for_scheme, material_theme_high_contrast, material_theme_light,
+    material_theme_with_optional_overrides, material_theme_with_overrides,
+    theme_with_motion_preference, MotionPreference,
 };
 
 #[derive(Clone)]
@@ -100,6 +101,17 @@ fn css_baseline_includes_both_schemes() {
     assert!(css.contains("[data-rustic_ui_color_scheme='dark']"));
 }
 
+#[test]
+fn css_baseline_handles_forced_colors_mode() {
+    let theme = material_theme();
+    let css = material_css_baseline_from_theme(&theme);
+    assert!(css.contains("@media (forced-colors: active)"));
+    assert!(css.contains("background-color: Canvas"));
+    assert!(css.contains("outline: 3px solid Highlight"));
+    assert!(css.contains("[data-rustic_ui_color_scheme='high-contrast']"));
+    assert!(css.contains(&theme.palette.high_contrast.background_default));
+}
+
 #[test]
 fn scheme_specific_helpers_adjust_initial_mode() {
     assert_eq!(
@@ -110,11 +122,43 @@ fn scheme_specific_helpers_adjust_initial_mode() {
         material_theme_dark().palette.initial_color_scheme,
         ColorScheme::Dark
     );
+    assert_eq!(
+        material_theme_high_contrast().palette.initial_color_scheme,
+        ColorScheme::HighContrast
+    );
 
     let forced = material_theme_for_scheme(ColorScheme::Dark);
     assert_eq!(forced.palette.initial_color_scheme, ColorScheme::Dark);
 }
 
+#[test]
+fn motion_preference_resolves_and_flips_the_theme_flag() {
+    assert!(MotionPreference::Reduced.reduced());
+    assert!(!MotionPreference::Full.reduced());
+    // Outside the browser there is no detectable signal, so System falls
+    // back to full motion.
+    assert!(!MotionPreference::System.reduced());
+
+    let reduced = theme_with_motion_preference(material_theme(), MotionPreference::Reduced);
+    assert!(reduced.motion.reduce);
+    assert_eq!(reduced.motion.effective_duration_ms(200), 0);
+    assert_eq!(reduced.motion.transition(&["opacity"]), "none");
+
+    let full = theme_with_motion_preference(reduced, MotionPreference::Full);
+    assert!(!full.motion.reduce);
+}
+
+#[test]
+fn css_baseline_honours_reduced_motion_preferences() {
+    let css = material_css_baseline();
+    assert!(css.contains("@media (prefers-reduced-motion: reduce)"));
+    assert!(css.contains("animation-duration: 0.01ms !important"));
+    // The attribute override both opts out of the OS signal and forces
+    // reduction when requested explicitly.
+    assert!(css.contains(":root:not([data-rustic_ui_reduced_motion='no-preference'])"));
+    assert!(css.contains("[data-rustic_ui_reduced_motion='reduce'] *"));
+}
+
 #[test]
 fn css_differs_between_light_and_dark_templates() {
     let light_theme = material_theme_light();
@@ -0,0 +1,138 @@
+//! Assertion helpers replacing ad-hoc `html.contains(...)` checks.
+//!
+//! Substring assertions silently pass when the value under test appears in
+//! the wrong attribute (or in visible text). These helpers parse the markup
+//! first so failures point at real structural problems and carry messages
+//! that describe what was expected.
+
+use crate::dom::{parse_elements, Element};
+
+/// Assert that some element exposes `expected` as an automation identifier.
+///
+/// The workspace stamps automation hooks as either DOM `id`s or `data-*`
+/// attributes (e.g. `data-rustic-select-id`), so the assertion accepts a
+/// match in any of those positions.
+///
+/// # Panics
+/// Panics with a descriptive message when no element carries the identifier.
+pub fn assert_has_automation_id(html: &str, expected: &str) {
+    let elements = parse_elements(html);
+    let found = elements.iter().any(|element| {
+        element
+            .attributes
+            .iter()
+            .any(|(key, value)| (key == "id" || key.starts_with("data-")) && value == expected)
+    });
+    assert!(
+        found,
+        "no element exposes automation id {expected:?} via `id` or a `data-*` attribute"
+    );
+}
+
+/// Assert that every `aria-labelledby`, `aria-describedby` and
+/// `aria-controls` reference resolves to an element `id` within the same
+/// markup, so assistive technology relationships cannot silently dangle.
+///
+/// All three attributes hold space separated id lists per the ARIA spec.
+///
+/// # Panics
+/// Panics naming the attribute and the dangling id when resolution fails.
+pub fn assert_aria_references_resolve(html: &str) {
+    let elements = parse_elements(html);
+    let ids: Vec<&str> = elements
+        .iter()
+        .filter_map(|element| element.attr("id"))
+        .collect();
+
+    for element in &elements {
+        for attribute in ["aria-labelledby", "aria-describedby", "aria-controls"] {
+            let Some(references) = element.attr(attribute) else {
+                continue;
+            };
+            for reference in references.split_whitespace() {
+                assert!(
+                    ids.contains(&reference),
+                    "<{}> references {reference:?} via `{attribute}` but no element in the \
+                     markup has that id",
+                    element.tag
+                );
+            }
+        }
+    }
+}
+
+/// Assert that `aria-activedescendant` references resolve the same way.
+///
+/// Kept separate from [`assert_aria_references_resolve`] because composite
+/// widgets legitimately omit the attribute while closed.
+///
+/// # Panics
+/// Panics naming the dangling id when resolution fails.
+pub fn assert_active_descendant_resolves(html: &str) {
+    let elements = parse_elements(html);
+    let ids: Vec<&str> = elements
+        .iter()
+        .filter_map(|element| element.attr("id"))
+        .collect();
+    for element in &elements {
+        if let Some(reference) = element.attr("aria-activedescendant") {
+            assert!(
+                ids.contains(&reference),
+                "<{}> points `aria-activedescendant` at {reference:?} but no element in the \
+                 markup has that id",
+                element.tag
+            );
+        }
+    }
+}
+
+/// Find the first element with the given attribute value, panicking with a
+/// descriptive message when absent. Handy for follow-up attribute checks.
+pub fn expect_element<'a>(elements: &'a [Element], key: &str, value: &str) -> &'a Element {
+    elements
+        .iter()
+        .find(|element| element.attr(key) == Some(value))
+        .unwrap_or_else(|| panic!("no element with {key}={value:?} found in markup"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn automation_id_accepts_id_and_data_attributes() {
+        assert_has_automation_id(
+            r#"<div id="rustic-select-sample"></div>"#,
+            "rustic-select-sample",
+        );
+        assert_has_automation_id(
+            r#"<div data-rustic-select-id="rustic-select-sample"></div>"#,
+            "rustic-select-sample",
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "no element exposes automation id")]
+    fn automation_id_rejects_substring_matches() {
+        // The id appears in visible text only; a `contains` check would pass.
+        assert_has_automation_id("<p>rustic-select-sample</p>", "rustic-select-sample");
+    }
+
+    #[test]
+    fn aria_references_resolve_across_the_markup() {
+        let html = r#"<button aria-controls="list"></button><ul id="list"></ul>"#;
+        assert_aria_references_resolve(html);
+    }
+
+    #[test]
+    #[should_panic(expected = "aria-labelledby")]
+    fn dangling_labelledby_panics() {
+        assert_aria_references_resolve(r#"<div aria-labelledby="missing"></div>"#);
+    }
+
+    #[test]
+    fn active_descendant_resolution() {
+        let html = r#"<ul aria-activedescendant="opt-1"><li id="opt-1"></li></ul>"#;
+        assert_active_descendant_resolves(html);
+    }
+}
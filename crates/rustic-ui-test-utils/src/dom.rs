@@ -0,0 +1,176 @@
+//! Minimal HTML scanning utilities.
+//!
+//! The component renderers emit well-formed, machine-generated markup, so a
+//! full spec-compliant parser would be overkill. This module walks the tag
+//! soup and materializes every opening tag as an [`Element`] with its
+//! attribute list, which is all the assertion helpers in this crate need.
+//! Text nodes, comments and closing tags are intentionally skipped.
+
+/// One opening tag extracted from rendered markup.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Element {
+    /// Lowercased tag name (e.g. `"button"`).
+    pub tag: String,
+    /// Attribute key/value pairs in document order. Boolean attributes carry
+    /// an empty value.
+    pub attributes: Vec<(String, String)>,
+}
+
+impl Element {
+    /// Look up an attribute value by name.
+    pub fn attr(&self, name: &str) -> Option<&str> {
+        self.attributes
+            .iter()
+            .find(|(key, _)| key == name)
+            .map(|(_, value)| value.as_str())
+    }
+
+    /// Whether the attribute is present at all (including empty values).
+    pub fn has_attr(&self, name: &str) -> bool {
+        self.attributes.iter().any(|(key, _)| key == name)
+    }
+}
+
+/// Parse every opening tag in `html` into an [`Element`] list.
+///
+/// The scanner tolerates unquoted, single-quoted and double-quoted attribute
+/// values as well as self-closing tags, matching everything the SSR
+/// renderers in this workspace produce.
+pub fn parse_elements(html: &str) -> Vec<Element> {
+    let mut elements = Vec::new();
+    let bytes = html.as_bytes();
+    let mut cursor = 0;
+
+    while let Some(open) = html[cursor..].find('<') {
+        let start = cursor + open + 1;
+        if start >= html.len() {
+            break;
+        }
+        // Skip closing tags, comments and doctype declarations.
+        if bytes[start] == b'/' || bytes[start] == b'!' {
+            cursor = start;
+            continue;
+        }
+        let Some(close) = html[start..].find('>') else {
+            break;
+        };
+        let raw = html[start..start + close].trim_end_matches('/');
+        if let Some(element) = parse_tag(raw) {
+            elements.push(element);
+        }
+        cursor = start + close + 1;
+    }
+    elements
+}
+
+/// Find every element carrying `key="value"`.
+pub fn find_by_attr<'a>(elements: &'a [Element], key: &str, value: &str) -> Vec<&'a Element> {
+    elements
+        .iter()
+        .filter(|element| element.attr(key) == Some(value))
+        .collect()
+}
+
+/// Parse the interior of one opening tag (`tag attr="value" ...`).
+fn parse_tag(raw: &str) -> Option<Element> {
+    let mut chars = raw.char_indices().peekable();
+    let tag_end = raw.find(|ch: char| ch.is_whitespace()).unwrap_or(raw.len());
+    let tag = raw[..tag_end].to_ascii_lowercase();
+    if tag.is_empty()
+        || !tag
+            .chars()
+            .next()
+            .is_some_and(|ch| ch.is_ascii_alphabetic())
+    {
+        return None;
+    }
+    // Position the iterator after the tag name before scanning attributes.
+    while chars.peek().is_some_and(|(index, _)| *index < tag_end) {
+        chars.next();
+    }
+
+    let mut attributes = Vec::new();
+    while let Some((start, ch)) = chars.next() {
+        if ch.is_whitespace() {
+            continue;
+        }
+        // Attribute name runs until `=`, whitespace or the end of the tag.
+        let mut name_end = raw.len();
+        for (index, ch) in raw[start..].char_indices() {
+            if ch == '=' || ch.is_whitespace() {
+                name_end = start + index;
+                break;
+            }
+        }
+        let name = raw[start..name_end].to_string();
+        while chars.peek().is_some_and(|(index, _)| *index < name_end) {
+            chars.next();
+        }
+
+        // Boolean attribute: no `=` follows the name.
+        if !raw[name_end..].starts_with('=') {
+            attributes.push((name, String::new()));
+            continue;
+        }
+        chars.next(); // consume `=`
+
+        let value_start = name_end + 1;
+        let (value, value_end) = match raw[value_start..].chars().next() {
+            Some(quote @ ('"' | '\'')) => {
+                let inner_start = value_start + 1;
+                let inner_end = raw[inner_start..]
+                    .find(quote)
+                    .map(|offset| inner_start + offset)
+                    .unwrap_or(raw.len());
+                (raw[inner_start..inner_end].to_string(), inner_end + 1)
+            }
+            _ => {
+                let end = raw[value_start..]
+                    .find(|ch: char| ch.is_whitespace())
+                    .map(|offset| value_start + offset)
+                    .unwrap_or(raw.len());
+                (raw[value_start..end].to_string(), end)
+            }
+        };
+        attributes.push((name, value));
+        while chars.peek().is_some_and(|(index, _)| *index < value_end) {
+            chars.next();
+        }
+    }
+
+    Some(Element { tag, attributes })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_tags_attributes_and_boolean_flags() {
+        let html = r#"<div class="a b" data-open="true"><input disabled value='x'/>text</div>"#;
+        let elements = parse_elements(html);
+        assert_eq!(elements.len(), 2);
+        assert_eq!(elements[0].tag, "div");
+        assert_eq!(elements[0].attr("class"), Some("a b"));
+        assert_eq!(elements[1].tag, "input");
+        assert!(elements[1].has_attr("disabled"));
+        assert_eq!(elements[1].attr("value"), Some("x"));
+    }
+
+    #[test]
+    fn skips_closing_tags_and_comments() {
+        let html = "<!-- note --><ul><li>1</li></ul>";
+        let tags: Vec<_> = parse_elements(html)
+            .into_iter()
+            .map(|element| element.tag)
+            .collect();
+        assert_eq!(tags, ["ul", "li"]);
+    }
+
+    #[test]
+    fn find_by_attr_matches_exact_values() {
+        let elements = parse_elements(r#"<a id="x"></a><b id="y"></b>"#);
+        assert_eq!(find_by_attr(&elements, "id", "y")[0].tag, "b");
+        assert!(find_by_attr(&elements, "id", "z").is_empty());
+    }
+}
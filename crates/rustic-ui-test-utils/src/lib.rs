@@ -0,0 +1,38 @@
+#![forbid(unsafe_code)]
+//! Markup assertion helpers for testing `rustic_ui_*` component output.
+//!
+//! The SSR renderers across this workspace produce HTML strings, and tests
+//! historically verified them with `html.contains(...)` — a pattern that
+//! happily matches values in the wrong attribute or in visible text. This
+//! crate centralizes structural helpers so every test suite asserts against
+//! parsed markup instead:
+//!
+//! # Modules
+//! * [`dom`] - lightweight HTML scanning into element/attribute lists.
+//! * [`assertions`] - automation id and ARIA relationship checks.
+//! * [`normalize`] - snapshot normalization hiding volatile class names.
+//!
+//! # Examples
+//! ```
+//! use rustic_ui_test_utils::{assert_has_automation_id, assert_aria_references_resolve};
+//!
+//! let html = r#"<button aria-controls="rustic-select-list" id="rustic-select-trigger">
+//!     Choose</button><ul id="rustic-select-list"></ul>"#;
+//! assert_has_automation_id(html, "rustic-select-trigger");
+//! assert_aria_references_resolve(html);
+//! ```
+//!
+//! The crate deliberately avoids heavyweight HTML parsing dependencies: the
+//! markup under test is machine generated and well formed, so a small
+//! scanner keeps compile times negligible for every downstream test suite.
+
+pub mod assertions;
+pub mod dom;
+pub mod normalize;
+
+pub use assertions::{
+    assert_active_descendant_resolves, assert_aria_references_resolve, assert_has_automation_id,
+    expect_element,
+};
+pub use dom::{find_by_attr, parse_elements, Element};
+pub use normalize::normalize_snapshot;
@@ -0,0 +1,120 @@
+//! Snapshot normalization for rendered markup.
+//!
+//! The styled engine generates hashed class names (`stylist-…`) that change
+//! whenever the CSS content does, which makes raw HTML snapshots churn on
+//! every styling tweak. Normalizing replaces those volatile tokens with
+//! stable placeholders and collapses insignificant whitespace so snapshot
+//! diffs only show meaningful structural changes.
+
+/// Replace volatile generated class names with stable placeholders and
+/// collapse whitespace runs between tags.
+///
+/// Each distinct generated class maps to a deterministic placeholder
+/// (`__class-0__`, `__class-1__`, …) in order of first appearance, so
+/// snapshots still distinguish elements styled differently from each other.
+pub fn normalize_snapshot(html: &str) -> String {
+    let mut seen: Vec<String> = Vec::new();
+    let mut out = String::with_capacity(html.len());
+
+    for token in split_preserving_delimiters(html) {
+        if is_generated_class(&token) {
+            let position = seen
+                .iter()
+                .position(|known| *known == token)
+                .unwrap_or_else(|| {
+                    seen.push(token.clone());
+                    seen.len() - 1
+                });
+            out.push_str(&format!("__class-{position}__"));
+        } else {
+            out.push_str(&token);
+        }
+    }
+
+    collapse_inter_tag_whitespace(&out)
+}
+
+/// Whether the token is a class name emitted by the styled engine.
+fn is_generated_class(token: &str) -> bool {
+    token
+        .strip_prefix("stylist-")
+        .is_some_and(|suffix| !suffix.is_empty() && suffix.chars().all(|ch| ch.is_alphanumeric()))
+}
+
+/// Split on the characters that can delimit a class token inside markup
+/// (whitespace and quotes), keeping the delimiters so the output
+/// reassembles byte-for-byte.
+fn split_preserving_delimiters(input: &str) -> Vec<String> {
+    let mut tokens = Vec::new();
+    let mut current = String::new();
+    for ch in input.chars() {
+        if ch.is_whitespace() || ch == '"' || ch == '\'' {
+            if !current.is_empty() {
+                tokens.push(std::mem::take(&mut current));
+            }
+            tokens.push(ch.to_string());
+        } else {
+            current.push(ch);
+        }
+    }
+    if !current.is_empty() {
+        tokens.push(current);
+    }
+    tokens
+}
+
+/// Collapse whitespace runs between a closing `>` and the next `<` so
+/// formatting-only changes do not dirty snapshots.
+fn collapse_inter_tag_whitespace(input: &str) -> String {
+    let mut out = String::with_capacity(input.len());
+    let mut chars = input.chars().peekable();
+    while let Some(ch) = chars.next() {
+        out.push(ch);
+        if ch == '>' {
+            let mut saw_whitespace = false;
+            while chars.peek().is_some_and(|next| next.is_whitespace()) {
+                chars.next();
+                saw_whitespace = true;
+            }
+            if saw_whitespace && chars.peek() != Some(&'<') {
+                out.push(' ');
+            }
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn generated_classes_become_stable_placeholders() {
+        let html = r#"<div class="stylist-a1B2c3"><span class="stylist-Zz9">x</span></div>"#;
+        let normalized = normalize_snapshot(html);
+        assert_eq!(
+            normalized,
+            r#"<div class="__class-0__"><span class="__class-1__">x</span></div>"#
+        );
+    }
+
+    #[test]
+    fn repeated_classes_share_a_placeholder() {
+        let html = r#"<i class="stylist-abc"></i><i class="stylist-abc"></i>"#;
+        let normalized = normalize_snapshot(html);
+        assert_eq!(normalized.matches("__class-0__").count(), 2);
+    }
+
+    #[test]
+    fn whitespace_between_tags_collapses() {
+        let html = "<ul>\n    <li>1</li>\n</ul>";
+        assert_eq!(normalize_snapshot(html), "<ul><li>1</li></ul>");
+    }
+
+    #[test]
+    fn ordinary_classes_are_untouched() {
+        let html = r#"<div class="toolbar stylist-x7 active">t</div>"#;
+        let normalized = normalize_snapshot(html);
+        assert!(normalized.contains("toolbar __class-0__ active"));
+    }
+}
@@ -0,0 +1,379 @@
+//! Static accessibility audit over rendered HTML strings.
+//!
+//! Component unit tests and the `cargo xtask accessibility-audit` pipeline
+//! need to gate common accessibility regressions without booting a browser.
+//! Because every adapter funnels through the shared `render_html` routines,
+//! auditing the SSR string catches the same markup each framework mounts.
+//!
+//! [`audit_html`] scans the markup with a dependency free tag scanner and
+//! returns machine-readable [`AuditFinding`]s (serializable, so CI can emit
+//! JSON artifacts).  The ruleset intentionally covers the high-signal,
+//! statically decidable checks:
+//!
+//! * form controls and images missing an accessible name,
+//! * `aria-*` id references that resolve to nothing in the same fragment,
+//! * `aria-hidden="true"` on focusable elements,
+//! * insufficient text contrast when both `color` and a background are
+//!   declared as inline hex styles.
+//!
+//! Anything requiring layout, computed styles or scripting stays in the
+//! Playwright sweeps.
+
+use serde::Serialize;
+
+/// Category of a detected violation.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum AuditRule {
+    /// A form control or image lacks an accessible name.
+    MissingLabel,
+    /// An `aria-*` attribute references an id that does not exist.
+    BrokenAriaReference,
+    /// A focusable element is hidden from assistive technology.
+    AriaHiddenFocusable,
+    /// Inline foreground/background colors fall below WCAG AA contrast.
+    LowContrast,
+}
+
+/// One violation detected in the audited markup.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct AuditFinding {
+    /// Which rule fired.
+    pub rule: AuditRule,
+    /// Human readable description including the offending ids/values.
+    pub message: String,
+    /// The opening tag that triggered the finding, for quick triage.
+    pub snippet: String,
+}
+
+/// Minimal representation of one scanned opening tag.
+struct ScannedTag {
+    name: String,
+    attributes: Vec<(String, String)>,
+    snippet: String,
+}
+
+impl ScannedTag {
+    fn attr(&self, name: &str) -> Option<&str> {
+        self.attributes
+            .iter()
+            .find(|(key, _)| key == name)
+            .map(|(_, value)| value.as_str())
+    }
+
+    fn has_attr(&self, name: &str) -> bool {
+        self.attributes.iter().any(|(key, _)| key == name)
+    }
+}
+
+/// Audit a rendered HTML fragment and return every detected violation.
+///
+/// An empty vector means the fragment passed; tests typically assert exactly
+/// that with the failure message carrying the serialized findings.
+#[must_use]
+pub fn audit_html(html: &str) -> Vec<AuditFinding> {
+    let tags = scan_tags(html);
+    let ids: Vec<&str> = tags.iter().filter_map(|tag| tag.attr("id")).collect();
+    let labelled_ids: Vec<&str> = tags
+        .iter()
+        .filter(|tag| tag.name == "label")
+        .filter_map(|tag| tag.attr("for"))
+        .collect();
+
+    let mut findings = Vec::new();
+    for tag in &tags {
+        check_missing_label(tag, &labelled_ids, &mut findings);
+        check_aria_references(tag, &ids, &mut findings);
+        check_aria_hidden_focusable(tag, &mut findings);
+        check_contrast(tag, &mut findings);
+    }
+    findings
+}
+
+fn check_missing_label(tag: &ScannedTag, labelled_ids: &[&str], findings: &mut Vec<AuditFinding>) {
+    let needs_name = match tag.name.as_str() {
+        "img" => {
+            if !tag.has_attr("alt") {
+                findings.push(AuditFinding {
+                    rule: AuditRule::MissingLabel,
+                    message: "<img> is missing an alt attribute".into(),
+                    snippet: tag.snippet.clone(),
+                });
+            }
+            return;
+        }
+        "input" => tag.attr("type") != Some("hidden"),
+        "select" | "textarea" => true,
+        _ => false,
+    };
+    if !needs_name {
+        return;
+    }
+    let labelled = tag.has_attr("aria-label")
+        || tag.has_attr("aria-labelledby")
+        || tag.has_attr("title")
+        || tag.attr("id").is_some_and(|id| labelled_ids.contains(&id));
+    if !labelled {
+        findings.push(AuditFinding {
+            rule: AuditRule::MissingLabel,
+            message: format!(
+                "<{}> has no accessible name (aria-label, aria-labelledby or a <label for>)",
+                tag.name
+            ),
+            snippet: tag.snippet.clone(),
+        });
+    }
+}
+
+/// ARIA attributes whose values are (space separated lists of) id references.
+const REFERENCE_ATTRIBUTES: [&str; 4] = [
+    "aria-labelledby",
+    "aria-describedby",
+    "aria-controls",
+    "aria-activedescendant",
+];
+
+fn check_aria_references(tag: &ScannedTag, ids: &[&str], findings: &mut Vec<AuditFinding>) {
+    for attribute in REFERENCE_ATTRIBUTES {
+        let Some(value) = tag.attr(attribute) else {
+            continue;
+        };
+        for reference in value.split_whitespace() {
+            if !ids.contains(&reference) {
+                findings.push(AuditFinding {
+                    rule: AuditRule::BrokenAriaReference,
+                    message: format!("{attribute} references missing id `{reference}`"),
+                    snippet: tag.snippet.clone(),
+                });
+            }
+        }
+    }
+}
+
+fn check_aria_hidden_focusable(tag: &ScannedTag, findings: &mut Vec<AuditFinding>) {
+    if tag.attr("aria-hidden") != Some("true") {
+        return;
+    }
+    let focusable = match tag.name.as_str() {
+        "input" | "select" | "textarea" | "button" => true,
+        "a" => tag.has_attr("href"),
+        _ => tag
+            .attr("tabindex")
+            .and_then(|value| value.parse::<i32>().ok())
+            .is_some_and(|index| index >= 0),
+    };
+    if focusable {
+        findings.push(AuditFinding {
+            rule: AuditRule::AriaHiddenFocusable,
+            message: format!("focusable <{}> is aria-hidden", tag.name),
+            snippet: tag.snippet.clone(),
+        });
+    }
+}
+
+/// WCAG AA minimum contrast ratio for normal text.
+const MIN_CONTRAST: f64 = 4.5;
+
+fn check_contrast(tag: &ScannedTag, findings: &mut Vec<AuditFinding>) {
+    let Some(style) = tag.attr("style") else {
+        return;
+    };
+    let mut foreground = None;
+    let mut background = None;
+    for declaration in style.split(';') {
+        let Some((property, value)) = declaration.split_once(':') else {
+            continue;
+        };
+        match property.trim() {
+            "color" => foreground = parse_hex_color(value.trim()),
+            "background" | "background-color" => background = parse_hex_color(value.trim()),
+            _ => {}
+        }
+    }
+    if let (Some(foreground), Some(background)) = (foreground, background) {
+        let ratio = contrast_ratio(foreground, background);
+        if ratio < MIN_CONTRAST {
+            findings.push(AuditFinding {
+                rule: AuditRule::LowContrast,
+                message: format!(
+                    "inline colors have a contrast ratio of {ratio:.2}:1 (minimum {MIN_CONTRAST}:1)"
+                ),
+                snippet: tag.snippet.clone(),
+            });
+        }
+    }
+}
+
+/// Parse `#rgb` / `#rrggbb` into linear-ready 0..=255 channels.  Non-hex
+/// colors (keywords, `var(...)`) are skipped: the audit only judges what it
+/// can evaluate statically.
+fn parse_hex_color(value: &str) -> Option<[u8; 3]> {
+    let digits = value.strip_prefix('#')?;
+    match digits.len() {
+        3 => {
+            let mut channels = [0u8; 3];
+            for (slot, digit) in channels.iter_mut().zip(digits.chars()) {
+                let nibble = digit.to_digit(16)? as u8;
+                *slot = nibble * 16 + nibble;
+            }
+            Some(channels)
+        }
+        6 => {
+            let mut channels = [0u8; 3];
+            for (slot, pair) in channels.iter_mut().zip([0, 2, 4]) {
+                *slot = u8::from_str_radix(&digits[pair..pair + 2], 16).ok()?;
+            }
+            Some(channels)
+        }
+        _ => None,
+    }
+}
+
+/// WCAG relative luminance of an sRGB color.
+fn relative_luminance(color: [u8; 3]) -> f64 {
+    let linear = color.map(|channel| {
+        let srgb = f64::from(channel) / 255.0;
+        if srgb <= 0.039_28 {
+            srgb / 12.92
+        } else {
+            ((srgb + 0.055) / 1.055).powf(2.4)
+        }
+    });
+    0.2126 * linear[0] + 0.7152 * linear[1] + 0.0722 * linear[2]
+}
+
+/// WCAG contrast ratio between two colors (always >= 1).
+fn contrast_ratio(a: [u8; 3], b: [u8; 3]) -> f64 {
+    let (lighter, darker) = {
+        let (la, lb) = (relative_luminance(a), relative_luminance(b));
+        if la > lb {
+            (la, lb)
+        } else {
+            (lb, la)
+        }
+    };
+    (lighter + 0.05) / (darker + 0.05)
+}
+
+/// Scan opening tags (name + attributes) out of an HTML fragment.
+///
+/// The scanner deliberately mirrors the rendered-markup subset the
+/// `render_html` routines emit: double-quoted attribute values, no CDATA and
+/// no script blocks.  Closing tags and comments are skipped.
+fn scan_tags(html: &str) -> Vec<ScannedTag> {
+    let mut tags = Vec::new();
+    let mut rest = html;
+    while let Some(open) = rest.find('<') {
+        rest = &rest[open..];
+        if rest.starts_with("</") || rest.starts_with("<!--") {
+            rest = &rest[1..];
+            continue;
+        }
+        let Some(close) = rest.find('>') else {
+            break;
+        };
+        let raw = &rest[1..close];
+        let snippet = format!("<{}>", raw.trim_end_matches('/').trim_end());
+        if let Some(tag) = parse_tag(raw.trim_end_matches('/'), snippet) {
+            tags.push(tag);
+        }
+        rest = &rest[close + 1..];
+    }
+    tags
+}
+
+fn parse_tag(raw: &str, snippet: String) -> Option<ScannedTag> {
+    let mut chars = raw.char_indices();
+    let name_end = chars
+        .find(|(_, character)| character.is_whitespace())
+        .map(|(index, _)| index)
+        .unwrap_or(raw.len());
+    let name = raw[..name_end].to_ascii_lowercase();
+    if name.is_empty() || !name.chars().all(|c| c.is_ascii_alphanumeric() || c == '-') {
+        return None;
+    }
+    let mut attributes = Vec::new();
+    let mut remainder = raw[name_end..].trim_start();
+    while !remainder.is_empty() {
+        let key_end = remainder
+            .find(|c: char| c == '=' || c.is_whitespace())
+            .unwrap_or(remainder.len());
+        let key = remainder[..key_end].to_ascii_lowercase();
+        remainder = remainder[key_end..].trim_start();
+        if let Some(stripped) = remainder.strip_prefix('=') {
+            let value_part = stripped.trim_start();
+            if let Some(quoted) = value_part.strip_prefix('"') {
+                let end = quoted.find('"').unwrap_or(quoted.len());
+                attributes.push((key, quoted[..end].to_string()));
+                remainder = quoted.get(end + 1..).unwrap_or("").trim_start();
+            } else {
+                let end = value_part
+                    .find(char::is_whitespace)
+                    .unwrap_or(value_part.len());
+                attributes.push((key, value_part[..end].to_string()));
+                remainder = value_part[end..].trim_start();
+            }
+        } else if !key.is_empty() {
+            // Boolean attribute such as `disabled`.
+            attributes.push((key, String::new()));
+        } else {
+            break;
+        }
+    }
+    Some(ScannedTag {
+        name,
+        attributes,
+        snippet,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn labelled_controls_pass() {
+        let html = r#"<label for="email">Email</label><input id="email" type="text">"#;
+        assert_eq!(audit_html(html), Vec::new());
+    }
+
+    #[test]
+    fn unlabelled_input_is_reported() {
+        let findings = audit_html(r#"<input type="text">"#);
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].rule, AuditRule::MissingLabel);
+    }
+
+    #[test]
+    fn broken_aria_reference_is_reported() {
+        let findings = audit_html(r#"<div role="dialog" aria-labelledby="missing-title"></div>"#);
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].rule, AuditRule::BrokenAriaReference);
+        assert!(findings[0].message.contains("missing-title"));
+    }
+
+    #[test]
+    fn hidden_focusable_elements_are_reported() {
+        let findings = audit_html(r#"<button aria-hidden="true" aria-label="Close">x</button>"#);
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].rule, AuditRule::AriaHiddenFocusable);
+    }
+
+    #[test]
+    fn low_contrast_inline_styles_are_reported() {
+        let findings =
+            audit_html(r#"<span style="color:#777777;background-color:#888888">hi</span>"#);
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].rule, AuditRule::LowContrast);
+
+        let passing = audit_html(r#"<span style="color:#000000;background:#ffffff">hi</span>"#);
+        assert_eq!(passing, Vec::new());
+    }
+
+    #[test]
+    fn findings_serialize_for_ci_artifacts() {
+        let findings = audit_html(r#"<input type="text">"#);
+        let json = serde_json::to_string(&findings).expect("findings serialize");
+        assert!(json.contains("\"missing-label\""));
+    }
+}
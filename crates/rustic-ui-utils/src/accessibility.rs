@@ -63,6 +63,61 @@ pub fn attributes_to_html(attrs: &[(String, String)]) -> String {
         .join(" ")
 }
 
+/// ARIA landmark roles that assistive technologies expose for quick
+/// navigation between page regions.
+///
+/// Components wrap their roots with [`landmark_attributes`] so audits find
+/// every app bar, drawer and content area labelled consistently instead of
+/// each component hand-writing `role` strings.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Landmark {
+    /// Site-wide header, typically the app bar.
+    Banner,
+    /// Primary or secondary navigation, typically a drawer or menu rail.
+    Navigation,
+    /// The main content area; one per page.
+    Main,
+    /// Supporting content such as side panels.
+    Complementary,
+    /// Site-wide footer.
+    ContentInfo,
+    /// Search form wrapper.
+    Search,
+    /// Generic labelled region for anything without a dedicated role.
+    Region,
+}
+
+impl Landmark {
+    /// The `role` attribute value for the landmark.
+    #[must_use]
+    pub fn role(self) -> &'static str {
+        match self {
+            Self::Banner => "banner",
+            Self::Navigation => "navigation",
+            Self::Main => "main",
+            Self::Complementary => "complementary",
+            Self::ContentInfo => "contentinfo",
+            Self::Search => "search",
+            Self::Region => "region",
+        }
+    }
+}
+
+/// Builds the attribute pairs for a landmark region.
+///
+/// The label becomes `aria-label`, which distinguishes multiple landmarks of
+/// the same role (two `navigation` regions, say) in the assistive technology
+/// rotor. [`Landmark::Region`] is only exposed when labelled, so callers
+/// should always pass a label for it.
+#[must_use]
+pub fn landmark_attributes(landmark: Landmark, label: Option<&str>) -> Vec<(String, String)> {
+    let mut attrs = vec![("role".to_string(), landmark.role().to_string())];
+    if let Some(label) = label {
+        attrs.push(("aria-label".to_string(), label.to_string()));
+    }
+    attrs
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -86,4 +141,15 @@ mod tests {
         assert_eq!(attrs.len(), 3);
         assert!(attrs.iter().any(|(k, _)| k == "aria-live"));
     }
+
+    #[test]
+    fn landmarks_expose_their_role_and_label() {
+        let attrs = landmark_attributes(Landmark::Navigation, Some("Primary"));
+        assert_eq!(attrs[0], ("role".into(), "navigation".into()));
+        assert_eq!(attrs[1], ("aria-label".into(), "Primary".into()));
+
+        let unlabelled = landmark_attributes(Landmark::Main, None);
+        assert_eq!(unlabelled, vec![("role".into(), "main".into())]);
+        assert_eq!(Landmark::ContentInfo.role(), "contentinfo");
+    }
 }
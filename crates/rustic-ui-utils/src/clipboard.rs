@@ -0,0 +1,138 @@
+//! Clipboard writes behind a pluggable provider.
+//!
+//! Components that offer copy-to-clipboard affordances (code blocks, share
+//! links, table cells) call [`copy_text`] instead of talking to a platform
+//! API directly. The provider is installed by the application, mirroring the
+//! [`telemetry`](crate::telemetry) sink pattern: web builds typically forward
+//! to `navigator.clipboard.writeText`, desktop shells to their windowing
+//! toolkit, and tests install [`MemoryClipboard`] to assert on the copied
+//! payloads.
+//!
+//! Without a provider [`copy_text`] fails with
+//! [`ClipboardError::Unavailable`], letting components surface a graceful
+//! fallback (e.g. "press Ctrl+C") instead of panicking on platforms where no
+//! clipboard exists.
+//!
+//! # Examples
+//! ```
+//! use rustic_ui_utils::clipboard::{self, MemoryClipboard};
+//! use std::sync::Arc;
+//!
+//! let provider = Arc::new(MemoryClipboard::default());
+//! clipboard::install_provider(provider.clone());
+//!
+//! clipboard::copy_text("cargo build").unwrap();
+//! assert_eq!(provider.copies(), vec!["cargo build".to_string()]);
+//!
+//! clipboard::reset_provider();
+//! ```
+
+use std::fmt;
+use std::sync::{Arc, Mutex, RwLock};
+
+/// Failure modes surfaced by [`copy_text`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ClipboardError {
+    /// No provider is installed on this platform.
+    Unavailable,
+    /// The installed provider rejected the write with a backend message.
+    Backend(String),
+}
+
+impl fmt::Display for ClipboardError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Unavailable => write!(f, "no clipboard provider installed"),
+            Self::Backend(message) => write!(f, "clipboard provider failed: {message}"),
+        }
+    }
+}
+
+impl std::error::Error for ClipboardError {}
+
+/// Destination for clipboard writes.
+///
+/// Implementations must be cheap and non-blocking; [`copy_text`] runs inline
+/// on interaction paths.
+pub trait ClipboardProvider: Send + Sync {
+    /// Place the text on the platform clipboard.
+    fn copy(&self, text: &str) -> Result<(), ClipboardError>;
+}
+
+/// Globally installed provider. `None` until an application opts in.
+static PROVIDER: RwLock<Option<Arc<dyn ClipboardProvider>>> = RwLock::new(None);
+
+/// Install the process-wide clipboard provider, replacing any previous one.
+pub fn install_provider(provider: Arc<dyn ClipboardProvider>) {
+    *PROVIDER.write().expect("clipboard provider lock poisoned") = Some(provider);
+}
+
+/// Remove the installed provider, returning [`copy_text`] to its unavailable
+/// state.
+pub fn reset_provider() {
+    *PROVIDER.write().expect("clipboard provider lock poisoned") = None;
+}
+
+/// Copy `text` through the installed provider.
+pub fn copy_text(text: &str) -> Result<(), ClipboardError> {
+    let guard = PROVIDER.read().expect("clipboard provider lock poisoned");
+    match guard.as_ref() {
+        Some(provider) => provider.copy(text),
+        None => Err(ClipboardError::Unavailable),
+    }
+}
+
+/// In-memory provider buffering copies for assertions in tests and demos.
+#[derive(Default)]
+pub struct MemoryClipboard {
+    copies: Mutex<Vec<String>>,
+}
+
+impl MemoryClipboard {
+    /// Snapshot of every copied payload in write order.
+    pub fn copies(&self) -> Vec<String> {
+        self.copies
+            .lock()
+            .expect("clipboard buffer lock poisoned")
+            .clone()
+    }
+
+    /// Discard all buffered payloads.
+    pub fn clear(&self) {
+        self.copies
+            .lock()
+            .expect("clipboard buffer lock poisoned")
+            .clear();
+    }
+}
+
+impl ClipboardProvider for MemoryClipboard {
+    fn copy(&self, text: &str) -> Result<(), ClipboardError> {
+        self.copies
+            .lock()
+            .expect("clipboard buffer lock poisoned")
+            .push(text.to_string());
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn copy_fails_gracefully_without_a_provider() {
+        reset_provider();
+        assert_eq!(copy_text("anything"), Err(ClipboardError::Unavailable));
+    }
+
+    #[test]
+    fn installed_provider_receives_writes_until_reset() {
+        let provider = Arc::new(MemoryClipboard::default());
+        install_provider(provider.clone());
+        copy_text("first").unwrap();
+        reset_provider();
+        assert_eq!(copy_text("second"), Err(ClipboardError::Unavailable));
+        assert_eq!(provider.copies(), vec!["first".to_string()]);
+    }
+}
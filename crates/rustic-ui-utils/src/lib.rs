@@ -12,11 +12,17 @@
 //!
 //! # Modules
 //! * [`accessibility`] - compose ARIA rich HTML attribute collections.
+//! * [`a11y_audit`] - statically audit rendered markup for common
+//!   accessibility violations.
 //! * [`debounce`] - delay execution until a burst of calls has
 //!   subsided.
 //! * [`throttle`] - ensure a function runs at most once per interval.
 //! * [`deep_merge`] - recursively merge JSON-like values.
 //! * [`compose_classes`] - build CSS class strings for component slots.
+//! * [`clipboard`] - copy text through a pluggable platform provider.
+//! * [`resource`] - async data lifecycle with stale-while-revalidate.
+//! * [`storage`] - persist key/value pairs through a pluggable provider.
+//! * [`telemetry`] - emit typed component events through a pluggable sink.
 //!
 //! # Examples
 //! ```
@@ -37,16 +43,28 @@
 //! Future utilities can extend this crate to keep application code DRY
 //! and encourage reuse across the ecosystem.
 
+pub mod a11y_audit;
 pub mod accessibility;
+pub mod clipboard;
 pub mod compose_classes;
 pub mod debounce;
 pub mod deep_merge;
+pub mod resource;
+pub mod storage;
+pub mod telemetry;
 pub mod throttle;
 
-pub use accessibility::{attributes_to_html, collect_attributes, extend_attributes};
+pub use a11y_audit::{audit_html, AuditFinding, AuditRule};
+pub use accessibility::{
+    attributes_to_html, collect_attributes, extend_attributes, landmark_attributes, Landmark,
+};
+pub use clipboard::{copy_text, ClipboardError, ClipboardProvider};
 pub use compose_classes::compose_classes;
 pub use debounce::debounce;
 pub use deep_merge::deep_merge;
+pub use resource::{Resource, ResourcePhase};
+pub use storage::{get_item, remove_item, set_item, StorageError, StorageProvider};
+pub use telemetry::{TelemetryEvent, TelemetrySink};
 pub use throttle::throttle;
 
 #[cfg(feature = "compat-mui")]
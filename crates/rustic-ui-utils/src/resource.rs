@@ -0,0 +1,209 @@
+//! Async resource state machine with stale-while-revalidate semantics.
+//!
+//! Components that show remote data (Select option lists, Autocomplete
+//! suggestions, DataGrid rows) all need the same lifecycle: nothing yet,
+//! loading, loaded, failed — and on refresh they should keep showing the
+//! previous data instead of flashing a spinner.  Examples historically
+//! re-implemented this per adapter; [`Resource`] centralizes it as a pure
+//! state machine the framework hooks drive from their effect/spawn
+//! primitives.
+//!
+//! The machine never performs IO itself: callers mark the transition points
+//! ([`Resource::start`], [`Resource::succeed`], [`Resource::fail`]) around
+//! whatever fetch mechanism the host uses, which keeps the type usable from
+//! WASM, SSR warmup code and plain unit tests alike.
+
+/// Lifecycle phase of a [`Resource`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ResourcePhase {
+    /// No load has been requested yet.
+    Idle,
+    /// A load is in flight (possibly with stale data still on display).
+    Loading,
+    /// The latest load finished successfully.
+    Ready,
+    /// The latest load failed (possibly with stale data still on display).
+    Error,
+}
+
+impl ResourcePhase {
+    /// Stable string for `data-*` automation attributes.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Idle => "idle",
+            Self::Loading => "loading",
+            Self::Ready => "ready",
+            Self::Error => "error",
+        }
+    }
+}
+
+/// Async data holder implementing stale-while-revalidate.
+///
+/// On revalidation the previous value stays readable through
+/// [`Resource::data`] until the new load resolves, so components keep
+/// rendering rows/options while [`Resource::is_loading`] drives a subtle
+/// busy indicator instead of a blank state.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Resource<T, E> {
+    phase: ResourcePhase,
+    value: Option<T>,
+    error: Option<E>,
+}
+
+impl<T, E> Default for Resource<T, E> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, E> Resource<T, E> {
+    /// Idle resource with no data.
+    pub fn new() -> Self {
+        Self {
+            phase: ResourcePhase::Idle,
+            value: None,
+            error: None,
+        }
+    }
+
+    /// Current lifecycle phase.
+    pub fn phase(&self) -> ResourcePhase {
+        self.phase
+    }
+
+    /// Latest known value — fresh after a successful load, stale during a
+    /// revalidation or after a failed refresh.
+    pub fn data(&self) -> Option<&T> {
+        self.value.as_ref()
+    }
+
+    /// Error from the latest failed load, if any.
+    pub fn error(&self) -> Option<&E> {
+        self.error.as_ref()
+    }
+
+    /// Whether a load is currently in flight.
+    pub fn is_loading(&self) -> bool {
+        self.phase == ResourcePhase::Loading
+    }
+
+    /// Whether the currently visible data predates the in-flight or failed
+    /// load (the "stale" in stale-while-revalidate).
+    pub fn is_stale(&self) -> bool {
+        self.value.is_some() && self.phase != ResourcePhase::Ready
+    }
+
+    /// Mark a load as started.  Existing data is retained for display.
+    pub fn start(&mut self) {
+        self.phase = ResourcePhase::Loading;
+        self.error = None;
+    }
+
+    /// Resolve the in-flight load with fresh data.
+    pub fn succeed(&mut self, value: T) {
+        self.phase = ResourcePhase::Ready;
+        self.value = Some(value);
+        self.error = None;
+    }
+
+    /// Resolve the in-flight load with an error, keeping stale data.
+    pub fn fail(&mut self, error: E) {
+        self.phase = ResourcePhase::Error;
+        self.error = Some(error);
+    }
+
+    /// Drop everything and return to idle (e.g. when the query that
+    /// produced the data no longer applies).
+    pub fn reset(&mut self) {
+        *self = Self::new();
+    }
+
+    /// Drive one full load from a future.  Framework hooks call this from
+    /// their spawn primitive; tests drive it with a manual executor.
+    pub async fn load<F>(&mut self, fetch: F)
+    where
+        F: std::future::Future<Output = Result<T, E>>,
+    {
+        self.start();
+        match fetch.await {
+            Ok(value) => self.succeed(value),
+            Err(error) => self.fail(error),
+        }
+    }
+
+    /// Attributes components spread onto their root element so automation
+    /// and assistive technology observe the load lifecycle:
+    /// `aria-busy` plus a `data-loading-state` hook.
+    pub fn loading_attributes(&self) -> Vec<(String, String)> {
+        vec![
+            ("aria-busy".to_string(), self.is_loading().to_string()),
+            (
+                "data-loading-state".to_string(),
+                self.phase.as_str().to_string(),
+            ),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::task::{Context, Poll, Waker};
+
+    fn block_on<F: std::future::Future>(future: F) -> F::Output {
+        let mut future = Box::pin(future);
+        let mut context = Context::from_waker(Waker::noop());
+        loop {
+            if let Poll::Ready(output) = future.as_mut().poll(&mut context) {
+                return output;
+            }
+        }
+    }
+
+    #[test]
+    fn lifecycle_transitions_through_the_phases() {
+        let mut resource: Resource<Vec<u32>, String> = Resource::new();
+        assert_eq!(resource.phase(), ResourcePhase::Idle);
+        resource.start();
+        assert!(resource.is_loading());
+        resource.succeed(vec![1, 2]);
+        assert_eq!(resource.phase(), ResourcePhase::Ready);
+        assert_eq!(resource.data(), Some(&vec![1, 2]));
+        assert!(!resource.is_stale());
+    }
+
+    #[test]
+    fn revalidation_keeps_stale_data_visible() {
+        let mut resource: Resource<Vec<u32>, String> = Resource::new();
+        resource.succeed(vec![1]);
+        resource.start();
+        assert!(resource.is_loading());
+        assert!(resource.is_stale());
+        assert_eq!(resource.data(), Some(&vec![1]));
+
+        resource.fail("offline".to_string());
+        assert_eq!(resource.phase(), ResourcePhase::Error);
+        assert_eq!(resource.data(), Some(&vec![1]));
+        assert_eq!(resource.error(), Some(&"offline".to_string()));
+    }
+
+    #[test]
+    fn load_drives_a_future_to_either_outcome() {
+        let mut resource: Resource<u32, String> = Resource::new();
+        block_on(resource.load(async { Ok(7) }));
+        assert_eq!(resource.data(), Some(&7));
+        block_on(resource.load(async { Err("boom".to_string()) }));
+        assert_eq!(resource.phase(), ResourcePhase::Error);
+        assert_eq!(resource.data(), Some(&7), "stale data survives failures");
+    }
+
+    #[test]
+    fn loading_attributes_expose_the_lifecycle() {
+        let mut resource: Resource<u32, String> = Resource::new();
+        resource.start();
+        let attrs = resource.loading_attributes();
+        assert!(attrs.contains(&("aria-busy".to_string(), "true".to_string())));
+        assert!(attrs.contains(&("data-loading-state".to_string(), "loading".to_string())));
+    }
+}
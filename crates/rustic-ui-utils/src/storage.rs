@@ -0,0 +1,182 @@
+//! Key/value persistence behind a pluggable provider.
+//!
+//! Components that persist user customizations (grid column layouts, panel
+//! sizes, dismissed onboarding hints) call [`get_item`]/[`set_item`] instead
+//! of talking to a platform API directly. The provider is installed by the
+//! application, mirroring the [`clipboard`](crate::clipboard) and
+//! [`telemetry`](crate::telemetry) patterns: web builds typically forward to
+//! `localStorage`, desktop shells to a settings file, and tests install
+//! [`MemoryStorage`] to assert on the persisted payloads.
+//!
+//! Without a provider every operation fails with
+//! [`StorageError::Unavailable`], letting callers fall back to in-memory
+//! defaults instead of panicking on platforms without persistent storage.
+//!
+//! # Examples
+//! ```
+//! use rustic_ui_utils::storage::{self, MemoryStorage};
+//! use std::sync::Arc;
+//!
+//! let provider = Arc::new(MemoryStorage::default());
+//! storage::install_provider(provider.clone());
+//!
+//! storage::set_item("grid.columns", "{\"hidden\":[]}").unwrap();
+//! assert_eq!(
+//!     storage::get_item("grid.columns").unwrap(),
+//!     Some("{\"hidden\":[]}".to_string())
+//! );
+//!
+//! storage::reset_provider();
+//! ```
+
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::{Arc, Mutex, RwLock};
+
+/// Failure modes surfaced by the storage helpers.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum StorageError {
+    /// No provider is installed on this platform.
+    Unavailable,
+    /// The installed provider rejected the operation with a backend message.
+    Backend(String),
+}
+
+impl fmt::Display for StorageError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Unavailable => write!(f, "no storage provider installed"),
+            Self::Backend(message) => write!(f, "storage provider failed: {message}"),
+        }
+    }
+}
+
+impl std::error::Error for StorageError {}
+
+/// Backing store for persisted key/value pairs.
+///
+/// Implementations must be cheap and non-blocking; the helpers run inline on
+/// interaction paths. Values are opaque strings — callers typically persist
+/// JSON via `serde`.
+pub trait StorageProvider: Send + Sync {
+    /// Read the value stored under `key`, if any.
+    fn get(&self, key: &str) -> Result<Option<String>, StorageError>;
+    /// Store `value` under `key`, replacing any previous value.
+    fn set(&self, key: &str, value: &str) -> Result<(), StorageError>;
+    /// Remove the value stored under `key`, if any.
+    fn remove(&self, key: &str) -> Result<(), StorageError>;
+}
+
+/// Globally installed provider. `None` until an application opts in.
+static PROVIDER: RwLock<Option<Arc<dyn StorageProvider>>> = RwLock::new(None);
+
+/// Install the process-wide storage provider, replacing any previous one.
+pub fn install_provider(provider: Arc<dyn StorageProvider>) {
+    *PROVIDER.write().expect("storage provider lock poisoned") = Some(provider);
+}
+
+/// Remove the installed provider, returning the helpers to their unavailable
+/// state.
+pub fn reset_provider() {
+    *PROVIDER.write().expect("storage provider lock poisoned") = None;
+}
+
+fn with_provider<R>(
+    operation: impl FnOnce(&dyn StorageProvider) -> Result<R, StorageError>,
+) -> Result<R, StorageError> {
+    let guard = PROVIDER.read().expect("storage provider lock poisoned");
+    match guard.as_ref() {
+        Some(provider) => operation(provider.as_ref()),
+        None => Err(StorageError::Unavailable),
+    }
+}
+
+/// Read the value stored under `key` through the installed provider.
+pub fn get_item(key: &str) -> Result<Option<String>, StorageError> {
+    with_provider(|provider| provider.get(key))
+}
+
+/// Store `value` under `key` through the installed provider.
+pub fn set_item(key: &str, value: &str) -> Result<(), StorageError> {
+    with_provider(|provider| provider.set(key, value))
+}
+
+/// Remove the value stored under `key` through the installed provider.
+pub fn remove_item(key: &str) -> Result<(), StorageError> {
+    with_provider(|provider| provider.remove(key))
+}
+
+/// In-memory provider for tests, demos and SSR processes.
+#[derive(Default)]
+pub struct MemoryStorage {
+    entries: Mutex<HashMap<String, String>>,
+}
+
+impl MemoryStorage {
+    /// Snapshot of every persisted entry.
+    pub fn entries(&self) -> HashMap<String, String> {
+        self.entries
+            .lock()
+            .expect("storage buffer lock poisoned")
+            .clone()
+    }
+
+    /// Discard all persisted entries.
+    pub fn clear(&self) {
+        self.entries
+            .lock()
+            .expect("storage buffer lock poisoned")
+            .clear();
+    }
+}
+
+impl StorageProvider for MemoryStorage {
+    fn get(&self, key: &str) -> Result<Option<String>, StorageError> {
+        Ok(self
+            .entries
+            .lock()
+            .expect("storage buffer lock poisoned")
+            .get(key)
+            .cloned())
+    }
+
+    fn set(&self, key: &str, value: &str) -> Result<(), StorageError> {
+        self.entries
+            .lock()
+            .expect("storage buffer lock poisoned")
+            .insert(key.to_string(), value.to_string());
+        Ok(())
+    }
+
+    fn remove(&self, key: &str) -> Result<(), StorageError> {
+        self.entries
+            .lock()
+            .expect("storage buffer lock poisoned")
+            .remove(key);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn helpers_fail_gracefully_without_a_provider() {
+        reset_provider();
+        assert_eq!(get_item("anything"), Err(StorageError::Unavailable));
+        assert_eq!(set_item("anything", "x"), Err(StorageError::Unavailable));
+    }
+
+    #[test]
+    fn installed_provider_round_trips_values_until_reset() {
+        let provider = Arc::new(MemoryStorage::default());
+        install_provider(provider.clone());
+        set_item("key", "value").unwrap();
+        assert_eq!(get_item("key").unwrap(), Some("value".to_string()));
+        remove_item("key").unwrap();
+        assert_eq!(get_item("key").unwrap(), None);
+        reset_provider();
+        assert_eq!(get_item("key"), Err(StorageError::Unavailable));
+    }
+}
@@ -0,0 +1,34 @@
+use criterion::{criterion_group, criterion_main, Criterion};
+use rustic_ui_virtual::ComboboxVirtualizer;
+
+/// Arrow-key navigation across a 10k option popup: every keystroke re-pins
+/// the highlight, applies the minimal scroll and recomputes the window.
+fn arrow_navigation(c: &mut Criterion) {
+    c.bench_function("combobox_arrow_navigation_10k", |b| {
+        let mut popup = ComboboxVirtualizer::new(10_000, 32.0).with_viewport(256.0);
+        let mut index = 0;
+        b.iter(|| {
+            index = (index + 1) % 10_000;
+            popup.follow_highlight(Some(index));
+            popup.window()
+        });
+    });
+}
+
+/// Typeahead worst case: the highlight jumps to a random far-away index
+/// instead of stepping to a neighbour.
+fn typeahead_jump(c: &mut Criterion) {
+    c.bench_function("combobox_typeahead_jump_10k", |b| {
+        let mut popup = ComboboxVirtualizer::new(10_000, 32.0).with_viewport(256.0);
+        let mut index = 0;
+        b.iter(|| {
+            // Stride co-prime with the option count to touch the whole list.
+            index = (index + 6_997) % 10_000;
+            popup.follow_highlight(Some(index));
+            popup.window()
+        });
+    });
+}
+
+criterion_group!(benches, arrow_navigation, typeahead_jump);
+criterion_main!(benches);
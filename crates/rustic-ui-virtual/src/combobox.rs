@@ -0,0 +1,162 @@
+//! Virtualization bridge for combobox option lists.
+//!
+//! Select and autocomplete machines (`rustic_ui_headless`) run their
+//! highlight and typeahead logic over the full option set, so a 10k-entry
+//! list stays correct even when only a handful of options are mounted.
+//! What breaks under naive windowing is the DOM side: the option named by
+//! `aria-activedescendant` must exist, and a typeahead jump must land the
+//! highlight inside the viewport.  [`ComboboxVirtualizer`] wraps the
+//! windowing math with exactly those guarantees:
+//!
+//! * the highlighted option is pinned into every window, so the active
+//!   descendant id always resolves even after the user wheel-scrolls away;
+//! * [`ComboboxVirtualizer::follow_highlight`] applies the minimal
+//!   [`ScrollAlignment::Auto`] scroll whenever arrow keys or typeahead move
+//!   the highlight, returning the offset to forward to the real container.
+//!
+//! Adapters feed every highlight change from the machine (arrow keys,
+//! typeahead, pointer hover) through
+//! [`follow_highlight`](ComboboxVirtualizer::follow_highlight) and render
+//! [`window`](ComboboxVirtualizer::window) — no other bookkeeping is
+//! required.  Keyboard navigation latency over large lists is tracked by
+//! `benches/combobox_bench.rs`.
+
+use crate::scroll::ScrollAlignment;
+use crate::window::{VirtualWindow, Virtualizer};
+
+/// Windowing state for one combobox popup.
+#[derive(Debug, Clone)]
+pub struct ComboboxVirtualizer {
+    virtualizer: Virtualizer,
+    highlighted: Option<usize>,
+}
+
+impl ComboboxVirtualizer {
+    /// Construct the bridge for `option_count` options of uniform height
+    /// (in logical pixels, matching the popup's option rows).
+    pub fn new(option_count: usize, option_height: f64) -> Self {
+        Self {
+            virtualizer: Virtualizer::new(option_count, option_height),
+            highlighted: None,
+        }
+    }
+
+    /// Builder style override for the popup height.
+    pub fn with_viewport(mut self, viewport: f64) -> Self {
+        self.virtualizer = self.virtualizer.with_viewport(viewport);
+        self
+    }
+
+    /// Builder style override for the overscan row count.
+    pub fn with_overscan(mut self, overscan: usize) -> Self {
+        self.virtualizer = self.virtualizer.with_overscan(overscan);
+        self
+    }
+
+    /// Number of options currently tracked.
+    pub fn option_count(&self) -> usize {
+        self.virtualizer.item_count()
+    }
+
+    /// Currently followed highlight, if any.
+    pub fn highlighted(&self) -> Option<usize> {
+        self.highlighted
+    }
+
+    /// Synchronizes the option count after filtering, releasing a pin that
+    /// fell out of range.  Callers also resync the machine
+    /// (`set_option_count`) so both sides agree on bounds.
+    pub fn set_option_count(&mut self, count: usize) {
+        if let Some(index) = self.highlighted {
+            if index >= count {
+                self.virtualizer.unstick(index);
+                self.highlighted = None;
+            }
+        }
+        self.virtualizer.set_item_count(count);
+    }
+
+    /// Records the container's scroll position from pointer scrolling.
+    /// The highlighted option stays pinned, so `aria-activedescendant`
+    /// keeps resolving while the user scrolls elsewhere.
+    pub fn set_scroll_offset(&mut self, offset: f64) {
+        self.virtualizer.set_scroll_offset(offset);
+    }
+
+    /// Follows a highlight change emitted by the select/autocomplete
+    /// machine: re-pins the new index and applies the minimal scroll that
+    /// brings it into view.  Returns the resulting offset so adapters can
+    /// forward it to the real scroll container.
+    pub fn follow_highlight(&mut self, highlighted: Option<usize>) -> f64 {
+        if let Some(previous) = self.highlighted {
+            self.virtualizer.unstick(previous);
+        }
+        self.highlighted = highlighted;
+        match highlighted {
+            Some(index) => {
+                self.virtualizer.stick(index);
+                self.virtualizer
+                    .scroll_to_index(index, ScrollAlignment::Auto)
+            }
+            None => self.virtualizer.scroll_offset(),
+        }
+    }
+
+    /// Options to mount for the current scroll position, highlight pin
+    /// included.
+    pub fn window(&self) -> VirtualWindow {
+        self.virtualizer.window()
+    }
+
+    /// Total scrollable height backing the popup's spacer element.
+    pub fn total_size(&self) -> f64 {
+        self.virtualizer.total_size()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn large_popup() -> ComboboxVirtualizer {
+        // 10k options, 32px rows, eight rows visible.
+        ComboboxVirtualizer::new(10_000, 32.0).with_viewport(256.0)
+    }
+
+    #[test]
+    fn typeahead_jumps_land_the_highlight_in_view() {
+        let mut popup = large_popup();
+        let offset = popup.follow_highlight(Some(7_500));
+        assert!(offset > 0.0);
+        assert!(popup.window().indices().any(|index| index == 7_500));
+    }
+
+    #[test]
+    fn active_descendant_survives_pointer_scrolling_away() {
+        let mut popup = large_popup();
+        popup.follow_highlight(Some(42));
+        popup.set_scroll_offset(200_000.0);
+        // The highlighted option must stay mounted so the id referenced by
+        // aria-activedescendant keeps resolving.
+        assert!(popup.window().indices().any(|index| index == 42));
+    }
+
+    #[test]
+    fn arrow_navigation_scrolls_minimally() {
+        let mut popup = large_popup();
+        popup.follow_highlight(Some(0));
+        // Stepping within the viewport leaves the offset untouched.
+        assert_eq!(popup.follow_highlight(Some(3)), 0.0);
+        // Stepping past the last visible row scrolls exactly one row.
+        assert_eq!(popup.follow_highlight(Some(8)), 32.0);
+    }
+
+    #[test]
+    fn filtering_releases_out_of_range_pins() {
+        let mut popup = large_popup();
+        popup.follow_highlight(Some(9_000));
+        popup.set_option_count(50);
+        assert_eq!(popup.highlighted(), None);
+        assert!(popup.window().indices().all(|index| index < 50));
+    }
+}
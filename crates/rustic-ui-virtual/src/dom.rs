@@ -0,0 +1,83 @@
+//! Inline style and automation attribute builders.
+//!
+//! Every framework hook renders the same three-layer structure:
+//!
+//! 1. a scroll container whose `scroll` events feed
+//!    [`Virtualizer::set_scroll_offset`](crate::Virtualizer::set_scroll_offset),
+//! 2. an inner element sized to [`VirtualWindow::total_size`] so the
+//!    scrollbar reflects the full collection,
+//! 3. absolutely positioned items from [`VirtualWindow::items`].
+//!
+//! Centralizing the style strings keeps SSR output byte-identical across
+//! adapters and gives QA pipelines stable `data-rustic-virtual-*` hooks.
+
+use crate::window::{VirtualItem, VirtualWindow};
+
+/// Inline style for the inner element that establishes the full scroll
+/// height and the positioning context for items.
+#[must_use]
+pub fn content_style(window: &VirtualWindow) -> String {
+    format!(
+        "position:relative;height:{}px;width:100%;",
+        window.total_size
+    )
+}
+
+/// Inline style placing one window item at its virtual offset.  Pinned items
+/// switch to `position: sticky` so they track the viewport.
+#[must_use]
+pub fn item_style(item: &VirtualItem) -> String {
+    if item.sticky {
+        format!(
+            "position:sticky;top:0;height:{}px;width:100%;z-index:1;",
+            item.size
+        )
+    } else {
+        format!(
+            "position:absolute;top:{}px;height:{}px;width:100%;",
+            item.offset, item.size
+        )
+    }
+}
+
+/// Automation attributes for one window item.
+#[must_use]
+pub fn item_attributes(item: &VirtualItem) -> Vec<(String, String)> {
+    let mut attrs = vec![(
+        "data-rustic-virtual-index".to_string(),
+        item.index.to_string(),
+    )];
+    if item.sticky {
+        attrs.push(("data-rustic-virtual-sticky".into(), "true".into()));
+    }
+    attrs
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::window::Virtualizer;
+
+    #[test]
+    fn styles_and_attributes_reflect_the_window() {
+        let mut virtualizer = Virtualizer::new(10, 40.0).with_viewport(80.0);
+        virtualizer.stick(0);
+        virtualizer.set_scroll_offset(virtualizer.max_scroll_offset());
+        let window = virtualizer.window();
+
+        assert_eq!(
+            content_style(&window),
+            "position:relative;height:400px;width:100%;"
+        );
+        let pinned = &window.items[0];
+        assert!(item_style(pinned).starts_with("position:sticky;"));
+        assert!(item_attributes(pinned)
+            .iter()
+            .any(|(key, value)| key == "data-rustic-virtual-sticky" && value == "true"));
+        let flowing = window.items.last().unwrap();
+        assert_eq!(
+            item_style(flowing),
+            "position:absolute;top:360px;height:40px;width:100%;"
+        );
+    }
+}
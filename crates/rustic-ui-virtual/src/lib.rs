@@ -0,0 +1,74 @@
+#![forbid(unsafe_code)]
+//! Framework agnostic virtualization for long lists, tables and grids.
+//!
+//! The crate splits cleanly into pure math and thin presentation glue:
+//!
+//! * [`window`] - the [`Virtualizer`] state machine answering "which items
+//!   are visible and where do they sit".
+//! * [`sticky`] - pinned rows that stay mounted regardless of scroll
+//!   position (table headers, section headers).
+//! * [`scroll`] - [`ScrollAlignment`] and the scroll-to-index math used by
+//!   keyboard navigation and deep links.
+//! * [`dom`] - inline style and automation attribute builders shared by the
+//!   framework hooks so SSR output stays identical everywhere.
+//!
+//! Material's list, table and data grid renderers consume the same APIs as
+//! application code; nothing in this crate depends on a framework or on the
+//! styling engine.
+//!
+//! # Examples
+//! ```
+//! use rustic_ui_virtual::{ScrollAlignment, Virtualizer};
+//!
+//! let mut virtualizer = Virtualizer::new(10_000, 32.0).with_viewport(480.0);
+//! virtualizer.scroll_to_index(5_000, ScrollAlignment::Center);
+//! let window = virtualizer.window();
+//! assert!(window.indices().any(|index| index == 5_000));
+//! ```
+
+pub mod combobox;
+pub mod dom;
+pub mod scroll;
+pub mod sticky;
+pub mod window;
+
+pub use combobox::ComboboxVirtualizer;
+pub use scroll::ScrollAlignment;
+pub use window::{VirtualItem, VirtualWindow, Virtualizer};
+
+/// Hook for Yew applications.
+///
+/// Hold the [`Virtualizer`] in a `use_mut_ref`/`use_state` pair, forward the
+/// container's `onscroll` into [`Virtualizer::set_scroll_offset`] and re-render
+/// with the fresh [`Virtualizer::window`].  Styles come straight from
+/// [`dom::content_style`]/[`dom::item_style`].  Kept behind the `yew` feature
+/// so server-only consumers avoid the extra surface.
+#[cfg(feature = "yew")]
+pub mod yew {
+    pub use crate::dom::{content_style, item_attributes, item_style};
+    pub use crate::{ScrollAlignment, VirtualWindow, Virtualizer};
+}
+
+/// Hook for Leptos applications; mirrors the [`yew`](self::yew) module with
+/// the virtualizer stored in a signal and the window derived from it.
+#[cfg(feature = "leptos")]
+pub mod leptos {
+    pub use crate::dom::{content_style, item_attributes, item_style};
+    pub use crate::{ScrollAlignment, VirtualWindow, Virtualizer};
+}
+
+/// Hook for Dioxus applications; mirrors the [`yew`](self::yew) module with
+/// the virtualizer held in `use_ref`.
+#[cfg(feature = "dioxus")]
+pub mod dioxus {
+    pub use crate::dom::{content_style, item_attributes, item_style};
+    pub use crate::{ScrollAlignment, VirtualWindow, Virtualizer};
+}
+
+/// Hook for Sycamore applications; mirrors the [`yew`](self::yew) module
+/// with the virtualizer behind a reactive signal.
+#[cfg(feature = "sycamore")]
+pub mod sycamore {
+    pub use crate::dom::{content_style, item_attributes, item_style};
+    pub use crate::{ScrollAlignment, VirtualWindow, Virtualizer};
+}
@@ -0,0 +1,96 @@
+//! Scroll-to-index helpers.
+//!
+//! Keyboard navigation, "jump to row" pickers and deep links all need to
+//! translate an item index into a scroll offset.  The math lives here so
+//! every framework hook (and the Material data grid's focus-follow logic)
+//! produces identical positions.
+
+use crate::window::Virtualizer;
+
+/// Where the target item should land inside the viewport.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ScrollAlignment {
+    /// Leading edge of the item meets the leading edge of the viewport.
+    Start,
+    /// Item is centered in the viewport.
+    Center,
+    /// Trailing edge of the item meets the trailing edge of the viewport.
+    End,
+    /// Scroll the minimum distance that makes the item fully visible; items
+    /// already in view leave the offset untouched.  This is what focus
+    /// management wants and therefore the default.
+    #[default]
+    Auto,
+}
+
+impl Virtualizer {
+    /// Compute and apply the scroll offset that brings `index` into view.
+    ///
+    /// Returns the resulting offset so callers can forward it to the real
+    /// scroll container (`element.scroll_to` or framework equivalent).
+    /// Out-of-bounds indices are clamped to the last item.
+    pub fn scroll_to_index(&mut self, index: usize, alignment: ScrollAlignment) -> f64 {
+        if self.item_count() == 0 {
+            return 0.0;
+        }
+        let index = index.min(self.item_count() - 1);
+        let top = self.offset_of(index);
+        let size = self.size_of(index);
+        let viewport = self.viewport();
+        let target = match alignment {
+            ScrollAlignment::Start => top,
+            ScrollAlignment::Center => top - (viewport - size) / 2.0,
+            ScrollAlignment::End => top + size - viewport,
+            ScrollAlignment::Auto => {
+                let current = self.scroll_offset();
+                if top < current {
+                    top
+                } else if top + size > current + viewport {
+                    top + size - viewport
+                } else {
+                    current
+                }
+            }
+        };
+        self.set_scroll_offset(target);
+        self.scroll_offset()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn virtualizer() -> Virtualizer {
+        Virtualizer::new(100, 40.0).with_viewport(400.0)
+    }
+
+    #[test]
+    fn start_center_and_end_alignments() {
+        let mut v = virtualizer();
+        assert_eq!(v.scroll_to_index(50, ScrollAlignment::Start), 2_000.0);
+        assert_eq!(v.scroll_to_index(50, ScrollAlignment::Center), 1_820.0);
+        assert_eq!(v.scroll_to_index(50, ScrollAlignment::End), 1_640.0);
+    }
+
+    #[test]
+    fn auto_scrolls_the_minimum_distance() {
+        let mut v = virtualizer();
+        // Item 5 is already visible from offset 0: nothing moves.
+        assert_eq!(v.scroll_to_index(5, ScrollAlignment::Auto), 0.0);
+        // Item 20 ends at 840px: viewport slides just far enough.
+        assert_eq!(v.scroll_to_index(20, ScrollAlignment::Auto), 440.0);
+        // Scrolling back up to item 2 aligns its leading edge.
+        assert_eq!(v.scroll_to_index(2, ScrollAlignment::Auto), 80.0);
+    }
+
+    #[test]
+    fn targets_clamp_to_the_scrollable_range() {
+        let mut v = virtualizer();
+        assert_eq!(v.scroll_to_index(0, ScrollAlignment::End), 0.0);
+        assert_eq!(
+            v.scroll_to_index(usize::MAX, ScrollAlignment::Start),
+            v.max_scroll_offset()
+        );
+    }
+}
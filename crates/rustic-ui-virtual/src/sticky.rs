@@ -0,0 +1,73 @@
+//! Sticky item support layered on the windowing math.
+//!
+//! Tables pin header rows, grouped lists pin section headers.  A pinned
+//! index is always present in the window produced by
+//! [`Virtualizer::window`](crate::Virtualizer::window) — even when scrolled
+//! far away — and is flagged via [`VirtualItem::sticky`](crate::VirtualItem)
+//! so renderers can switch it to `position: sticky` while everything else
+//! stays absolutely positioned.
+
+use crate::window::Virtualizer;
+
+impl Virtualizer {
+    /// Pin an item so it is always included in the rendered window.
+    pub fn stick(&mut self, index: usize) {
+        if index < self.item_count() {
+            self.sticky.insert(index);
+        }
+    }
+
+    /// Release a previously pinned item.
+    pub fn unstick(&mut self, index: usize) {
+        self.sticky.remove(&index);
+    }
+
+    /// Whether the item is currently pinned.
+    pub fn is_sticky(&self, index: usize) -> bool {
+        self.sticky.contains(&index)
+    }
+
+    /// Pinned indices in ascending order.
+    pub fn sticky_indices(&self) -> impl Iterator<Item = usize> + '_ {
+        self.sticky.iter().copied()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pinned_items_survive_scrolling_out_of_range() {
+        let mut virtualizer = Virtualizer::new(1_000, 40.0).with_viewport(400.0);
+        virtualizer.stick(0);
+        virtualizer.set_scroll_offset(20_000.0);
+        let window = virtualizer.window();
+        let first = window.items.first().expect("window is not empty");
+        assert_eq!(first.index, 0);
+        assert!(first.sticky);
+        // The pinned row must not displace the naturally visible range.
+        assert!(window.indices().any(|index| index >= 500));
+    }
+
+    #[test]
+    fn in_range_pins_are_flagged_not_duplicated() {
+        let mut virtualizer = Virtualizer::new(50, 40.0).with_viewport(400.0);
+        virtualizer.stick(3);
+        let window = virtualizer.window();
+        let occurrences = window.indices().filter(|index| *index == 3).count();
+        assert_eq!(occurrences, 1);
+        assert!(window
+            .items
+            .iter()
+            .any(|item| item.index == 3 && item.sticky));
+    }
+
+    #[test]
+    fn out_of_bounds_pins_are_ignored() {
+        let mut virtualizer = Virtualizer::new(5, 40.0);
+        virtualizer.stick(17);
+        assert!(!virtualizer.is_sticky(17));
+        assert_eq!(virtualizer.sticky_indices().count(), 0);
+    }
+}
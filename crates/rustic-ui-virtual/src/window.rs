@@ -0,0 +1,280 @@
+//! Core windowing math shared by every consumer.
+//!
+//! The [`Virtualizer`] is a pure state machine: feed it the item count, the
+//! viewport size and the current scroll offset, and it answers which items
+//! must be rendered plus where to absolutely position them.  Nothing here
+//! touches the DOM, so Material's list/table/data-grid renderers and
+//! application code drive the exact same logic during SSR, hydration and
+//! interactive scrolling.
+//!
+//! Items default to a uniform extent; rows that measure differently (wrapped
+//! text, expanded detail panels) register an override via
+//! [`Virtualizer::set_item_size`].  Sticky rows are handled in
+//! [`crate::sticky`] and folded into the window produced here so consumers
+//! render a single flat slice.
+
+use std::collections::{BTreeMap, BTreeSet};
+
+/// One item the consumer must render this frame.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct VirtualItem {
+    /// Index into the backing collection.
+    pub index: usize,
+    /// Offset of the item's leading edge from the start of the scroll
+    /// content, in pixels.
+    pub offset: f64,
+    /// Extent of the item along the scroll axis, in pixels.
+    pub size: f64,
+    /// Whether the item is rendered because it is pinned rather than because
+    /// it intersects the viewport.
+    pub sticky: bool,
+}
+
+/// Snapshot of the items to render for the current scroll position.
+#[derive(Clone, Debug, PartialEq, Default)]
+pub struct VirtualWindow {
+    /// Items intersecting the viewport (plus overscan and pinned rows), in
+    /// index order.
+    pub items: Vec<VirtualItem>,
+    /// Total extent of the scroll content in pixels; consumers size the
+    /// scrollable inner element with this so the scrollbar stays honest.
+    pub total_size: f64,
+}
+
+impl VirtualWindow {
+    /// Indices covered by the window, primarily for assertions and logging.
+    pub fn indices(&self) -> impl Iterator<Item = usize> + '_ {
+        self.items.iter().map(|item| item.index)
+    }
+}
+
+/// Deterministic windowing state machine.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Virtualizer {
+    item_count: usize,
+    item_size: f64,
+    overrides: BTreeMap<usize, f64>,
+    viewport: f64,
+    overscan: usize,
+    scroll_offset: f64,
+    pub(crate) sticky: BTreeSet<usize>,
+}
+
+impl Virtualizer {
+    /// Create a virtualizer over `item_count` items with a uniform extent.
+    ///
+    /// The viewport defaults to zero — callers set it from their container
+    /// measurement via [`Virtualizer::set_viewport`] (or the builder-style
+    /// [`Virtualizer::with_viewport`]) before asking for a window.
+    pub fn new(item_count: usize, item_size: f64) -> Self {
+        Self {
+            item_count,
+            item_size: item_size.max(0.0),
+            overrides: BTreeMap::new(),
+            viewport: 0.0,
+            overscan: 2,
+            scroll_offset: 0.0,
+            sticky: BTreeSet::new(),
+        }
+    }
+
+    /// Builder-style viewport initialisation for construction sites.
+    pub fn with_viewport(mut self, viewport: f64) -> Self {
+        self.set_viewport(viewport);
+        self
+    }
+
+    /// Builder-style overscan initialisation.  Overscan renders extra items
+    /// on both sides of the viewport so fast scrolling never flashes blank
+    /// rows; the default of two suits typical row heights.
+    pub fn with_overscan(mut self, overscan: usize) -> Self {
+        self.overscan = overscan;
+        self
+    }
+
+    /// Update the measured viewport extent (e.g. on container resize).
+    pub fn set_viewport(&mut self, viewport: f64) {
+        self.viewport = viewport.max(0.0);
+        self.clamp_offset();
+    }
+
+    /// Update the backing collection length, dropping overrides and pins
+    /// that now point past the end.
+    pub fn set_item_count(&mut self, item_count: usize) {
+        self.item_count = item_count;
+        self.overrides.retain(|index, _| *index < item_count);
+        self.sticky.retain(|index| *index < item_count);
+        self.clamp_offset();
+    }
+
+    /// Number of items currently virtualized.
+    pub fn item_count(&self) -> usize {
+        self.item_count
+    }
+
+    /// Record a measured extent for one item, overriding the uniform size.
+    pub fn set_item_size(&mut self, index: usize, size: f64) {
+        if index < self.item_count {
+            self.overrides.insert(index, size.max(0.0));
+            self.clamp_offset();
+        }
+    }
+
+    /// Extent of one item along the scroll axis.
+    pub fn size_of(&self, index: usize) -> f64 {
+        self.overrides
+            .get(&index)
+            .copied()
+            .unwrap_or(self.item_size)
+    }
+
+    /// Offset of an item's leading edge from the start of the content.
+    pub fn offset_of(&self, index: usize) -> f64 {
+        // Fast path: uniform extents reduce to a multiplication; overrides
+        // only force a walk over the (sorted, sparse) override map.
+        let uniform = index as f64 * self.item_size;
+        let correction: f64 = self
+            .overrides
+            .range(..index)
+            .map(|(_, size)| size - self.item_size)
+            .sum();
+        uniform + correction
+    }
+
+    /// Total extent of the scroll content.
+    pub fn total_size(&self) -> f64 {
+        self.offset_of(self.item_count)
+    }
+
+    /// Largest meaningful scroll offset for the current content/viewport.
+    pub fn max_scroll_offset(&self) -> f64 {
+        (self.total_size() - self.viewport).max(0.0)
+    }
+
+    /// Current scroll offset.
+    pub fn scroll_offset(&self) -> f64 {
+        self.scroll_offset
+    }
+
+    /// Record the container's scroll position, clamped to the valid range.
+    pub fn set_scroll_offset(&mut self, offset: f64) {
+        self.scroll_offset = offset.clamp(0.0, self.max_scroll_offset());
+    }
+
+    fn clamp_offset(&mut self) {
+        self.scroll_offset = self.scroll_offset.clamp(0.0, self.max_scroll_offset());
+    }
+
+    /// Index of the first item intersecting the given offset.
+    fn index_at(&self, offset: f64) -> usize {
+        if self.item_count == 0 {
+            return 0;
+        }
+        if self.overrides.is_empty() && self.item_size > 0.0 {
+            return ((offset / self.item_size) as usize).min(self.item_count - 1);
+        }
+        let mut cursor = 0.0;
+        for index in 0..self.item_count {
+            cursor += self.size_of(index);
+            if cursor > offset {
+                return index;
+            }
+        }
+        self.item_count - 1
+    }
+
+    /// Compute the items to render for the current scroll position.
+    ///
+    /// Pinned indices outside the natural range are prepended/appended (see
+    /// [`crate::sticky`]), so the returned slice is everything the consumer
+    /// mounts — no second bookkeeping pass required.
+    pub fn window(&self) -> VirtualWindow {
+        if self.item_count == 0 {
+            return VirtualWindow::default();
+        }
+        let first = self.index_at(self.scroll_offset);
+        let last = self.index_at(self.scroll_offset + self.viewport);
+        let start = first.saturating_sub(self.overscan);
+        let end = (last + self.overscan).min(self.item_count - 1);
+
+        let mut items = Vec::with_capacity(end - start + 1 + self.sticky.len());
+        for &index in self.sticky.range(..start) {
+            items.push(self.item(index, true));
+        }
+        for index in start..=end {
+            items.push(self.item(index, self.sticky.contains(&index)));
+        }
+        for &index in self.sticky.range(end + 1..) {
+            items.push(self.item(index, true));
+        }
+        VirtualWindow {
+            items,
+            total_size: self.total_size(),
+        }
+    }
+
+    fn item(&self, index: usize, sticky: bool) -> VirtualItem {
+        VirtualItem {
+            index,
+            offset: self.offset_of(index),
+            size: self.size_of(index),
+            sticky,
+        }
+    }
+
+    /// Viewport extent currently in effect.
+    pub fn viewport(&self) -> f64 {
+        self.viewport
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn uniform_window_covers_viewport_plus_overscan() {
+        let mut virtualizer = Virtualizer::new(1_000, 40.0).with_viewport(400.0);
+        virtualizer.set_scroll_offset(800.0);
+        let window = virtualizer.window();
+        let indices: Vec<_> = window.indices().collect();
+        // 800/40 = 20 .. (800+400)/40 = 30, plus two overscan each side.
+        assert_eq!(indices.first(), Some(&18));
+        assert_eq!(indices.last(), Some(&32));
+        assert_eq!(window.total_size, 40_000.0);
+    }
+
+    #[test]
+    fn size_overrides_shift_following_offsets() {
+        let mut virtualizer = Virtualizer::new(10, 40.0).with_viewport(200.0);
+        virtualizer.set_item_size(2, 100.0);
+        assert_eq!(virtualizer.offset_of(2), 80.0);
+        assert_eq!(virtualizer.offset_of(3), 180.0);
+        assert_eq!(virtualizer.total_size(), 460.0);
+    }
+
+    #[test]
+    fn scroll_offset_clamps_to_content() {
+        let mut virtualizer = Virtualizer::new(5, 40.0).with_viewport(100.0);
+        virtualizer.set_scroll_offset(9_999.0);
+        assert_eq!(virtualizer.scroll_offset(), 100.0);
+        virtualizer.set_scroll_offset(-5.0);
+        assert_eq!(virtualizer.scroll_offset(), 0.0);
+    }
+
+    #[test]
+    fn shrinking_the_collection_drops_stale_state() {
+        let mut virtualizer = Virtualizer::new(10, 40.0).with_viewport(100.0);
+        virtualizer.set_item_size(8, 90.0);
+        virtualizer.set_scroll_offset(virtualizer.max_scroll_offset());
+        virtualizer.set_item_count(3);
+        assert_eq!(virtualizer.total_size(), 120.0);
+        assert!(virtualizer.scroll_offset() <= virtualizer.max_scroll_offset());
+    }
+
+    #[test]
+    fn empty_collections_yield_an_empty_window() {
+        let virtualizer = Virtualizer::new(0, 40.0).with_viewport(100.0);
+        assert_eq!(virtualizer.window(), VirtualWindow::default());
+    }
+}
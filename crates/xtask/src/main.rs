@@ -104,6 +104,11 @@ enum Commands {
         /// sections of the canonical Material theme before serialization.
         #[arg(long)]
         overrides: Option<PathBuf>,
+        /// Start from a named preset from `rustic_ui_system::theme::presets`
+        /// (e.g. `high-contrast`, `dense-enterprise`) instead of the defaults.
+        /// Overrides still layer on top of the selected preset.
+        #[arg(long)]
+        preset: Option<String>,
         /// Output format written to disk.
         #[arg(long, value_enum, default_value_t = ThemeFormat::Json)]
         format: ThemeFormat,
@@ -130,6 +135,34 @@ enum Commands {
         #[arg(long = "out-dir")]
         out_dir: Option<PathBuf>,
     },
+    /// Convert an exported JavaScript MUI `createTheme()` JSON into a
+    /// RusticUI theme.
+    ///
+    /// Teams migrating off `@mui/material` run
+    /// `JSON.stringify(createTheme(...))` in their existing app, feed the
+    /// file to this command and receive a serialized [`Theme`] plus a
+    /// report of every key that has no Rust counterpart, so nothing is
+    /// silently dropped during the migration.
+    #[command(name = "import-mui-theme")]
+    ImportMuiTheme {
+        /// Path to the exported `createTheme()` JSON.
+        input: PathBuf,
+        /// Output path for the converted theme
+        /// (defaults to `target/themes/imported_theme.json`).
+        #[arg(long)]
+        out: Option<PathBuf>,
+    },
+    /// Render the registered component stories into static HTML galleries.
+    ///
+    /// Walks the `rustic_ui_material::stories` registry and bakes one page per
+    /// component (plus an index) with the generated CSS baseline and a color
+    /// scheme toggle, giving visual regression tooling deterministic markup to
+    /// diff and contributors a zero-build component explorer.
+    Stories {
+        /// Override the output directory (defaults to `target/stories`).
+        #[arg(long = "out-dir")]
+        out_dir: Option<PathBuf>,
+    },
     /// Recompute the RusticUI Material component parity dashboard.
     MaterialParity,
     /// Recompute the RusticUI Joy inventory to highlight missing Rust bindings.
@@ -156,9 +189,10 @@ fn main() -> Result<()> {
         Commands::BuildDocs => build_docs(),
         Commands::GenerateTheme {
             overrides,
+            preset,
             format,
             joy,
-        } => generate_theme(overrides, format, joy),
+        } => generate_theme(overrides, preset, format, joy),
         Commands::ThemesBundle {
             overrides,
             format,
@@ -166,6 +200,8 @@ fn main() -> Result<()> {
             compat,
             out_dir,
         } => themes_bundle(overrides, format, joy, compat, out_dir),
+        Commands::ImportMuiTheme { input, out } => import_mui_theme(input, out),
+        Commands::Stories { out_dir } => stories(out_dir),
         Commands::MaterialParity => material_parity(),
         Commands::JoyParity => joy_parity(),
     }
@@ -507,7 +543,12 @@ fn coverage() -> Result<()> {
     run(cmd)
 }
 
-fn generate_theme(overrides: Option<PathBuf>, format: ThemeFormat, joy: bool) -> Result<()> {
+fn generate_theme(
+    overrides: Option<PathBuf>,
+    preset: Option<String>,
+    format: ThemeFormat,
+    joy: bool,
+) -> Result<()> {
     println!(
         "[xtask] generating Material theme artifacts (format: {format:?}, joy fixtures: {joy})"
     );
@@ -530,8 +571,20 @@ fn generate_theme(overrides: Option<PathBuf>, format: ThemeFormat, joy: bool) ->
         }
     };
 
-    // Always start from the canonical Material theme before layering user supplied overrides.
-    let base_theme: Theme = Theme::default();
+    // Start from either the canonical Material theme or a named preset from
+    // the gallery before layering user supplied overrides.
+    let base_theme: Theme = match preset {
+        Some(name) => {
+            println!("[xtask] starting from preset `{name}`");
+            rustic_ui_system::theme::presets::preset(&name).ok_or_else(|| {
+                anyhow!(
+                    "unknown theme preset `{name}`; available presets: {}",
+                    rustic_ui_system::theme::presets::PRESET_NAMES.join(", ")
+                )
+            })?
+        }
+        None => Theme::default(),
+    };
 
     // Split overrides into the portions that apply to all color schemes and the
     // scheme-specific fragments.  We intentionally keep this logic explicit so
@@ -702,6 +755,279 @@ fn generate_theme(overrides: Option<PathBuf>, format: ThemeFormat, joy: bool) ->
     Ok(())
 }
 
+fn import_mui_theme(input: PathBuf, out: Option<PathBuf>) -> Result<()> {
+    println!("[xtask] importing MUI theme from {}", input.display());
+    let raw = fs::read_to_string(&input)
+        .with_context(|| format!("failed to read MUI theme export at {}", input.display()))?;
+    let source: Value = serde_json::from_str(&raw)
+        .with_context(|| format!("{} is not valid JSON", input.display()))?;
+    let object = source
+        .as_object()
+        .ok_or_else(|| anyhow!("expected the export to be a JSON object"))?;
+
+    let mut theme = Theme::default();
+    let mut unmapped: Vec<String> = Vec::new();
+
+    for (key, value) in object {
+        match key.as_str() {
+            "palette" => map_mui_palette(value, &mut theme, &mut unmapped),
+            "typography" => map_mui_typography(value, &mut theme, &mut unmapped),
+            "spacing" => match value.as_f64() {
+                // `createTheme` serializes numeric spacing directly; the
+                // function/array forms cannot be expressed as a single unit.
+                Some(unit) => theme.spacing = unit.round() as u16,
+                None => unmapped.push("spacing (non-numeric)".into()),
+            },
+            "shape" => {
+                if let Some(radius) = value.get("borderRadius").and_then(Value::as_f64) {
+                    theme.joy.radius = radius.round() as u8;
+                } else {
+                    unmapped.push("shape".into());
+                }
+            }
+            "breakpoints" => {
+                let values = value.get("values").and_then(Value::as_object);
+                for (name, slot) in [
+                    ("xs", &mut theme.breakpoints.xs),
+                    ("sm", &mut theme.breakpoints.sm),
+                    ("md", &mut theme.breakpoints.md),
+                    ("lg", &mut theme.breakpoints.lg),
+                    ("xl", &mut theme.breakpoints.xl),
+                ] {
+                    if let Some(px) = values.and_then(|map| map.get(name)).and_then(Value::as_f64) {
+                        *slot = px.round() as u32;
+                    }
+                }
+            }
+            // Animation tokens live in the motion scheme; only the easing
+            // and duration shapes translate.
+            "transitions" => map_mui_transitions(value, &mut theme, &mut unmapped),
+            other => unmapped.push(other.to_string()),
+        }
+    }
+
+    let out_path =
+        out.unwrap_or_else(|| workspace_root().join("target/themes/imported_theme.json"));
+    if let Some(parent) = out_path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    let serialized = serde_json::to_string_pretty(&theme)?;
+    fs::write(&out_path, format!("{serialized}\n"))?;
+    println!("[xtask] wrote {}", out_path.display());
+
+    if unmapped.is_empty() {
+        println!("[xtask] every key mapped onto the Rust theme");
+    } else {
+        unmapped.sort();
+        println!(
+            "[xtask] {} keys have no Rust counterpart and were skipped:",
+            unmapped.len()
+        );
+        for key in &unmapped {
+            println!("[xtask]   {key}");
+        }
+    }
+    Ok(())
+}
+
+/// Maps the single-scheme MUI palette onto the scheme selected by its
+/// `mode` flag, leaving the other scheme at the Rust defaults.
+fn map_mui_palette(value: &Value, theme: &mut Theme, unmapped: &mut Vec<String>) {
+    let Some(map) = value.as_object() else {
+        unmapped.push("palette".into());
+        return;
+    };
+    let dark_mode = map.get("mode").and_then(Value::as_str) == Some("dark");
+    if dark_mode {
+        theme.palette.initial_color_scheme = ColorScheme::Dark;
+    }
+    for (key, entry) in map {
+        let scheme = if dark_mode {
+            &mut theme.palette.dark
+        } else {
+            &mut theme.palette.light
+        };
+        let slot = match key.as_str() {
+            "mode" => continue,
+            "primary" => Some(&mut scheme.primary),
+            "secondary" => Some(&mut scheme.secondary),
+            // MUI's `error` channel is Joy's `danger`.
+            "error" => Some(&mut scheme.danger),
+            "warning" => Some(&mut scheme.warning),
+            "info" => Some(&mut scheme.info),
+            "success" => Some(&mut scheme.success),
+            _ => None,
+        };
+        match slot {
+            Some(slot) => match entry.get("main").and_then(Value::as_str) {
+                Some(main) => {
+                    *slot = main.to_string();
+                    // Shade variants have no Rust counterpart yet; surface
+                    // them so brand teams know to re-derive hover states.
+                    if let Some(entries) = entry.as_object() {
+                        for shade in entries.keys().filter(|shade| *shade != "main") {
+                            unmapped.push(format!("palette.{key}.{shade}"));
+                        }
+                    }
+                }
+                None => unmapped.push(format!("palette.{key}")),
+            },
+            None => match key.as_str() {
+                "background" => {
+                    if let Some(default) = entry.get("default").and_then(Value::as_str) {
+                        scheme.background_default = default.to_string();
+                    }
+                    if let Some(paper) = entry.get("paper").and_then(Value::as_str) {
+                        scheme.background_paper = paper.to_string();
+                    }
+                }
+                "text" => {
+                    if let Some(primary) = entry.get("primary").and_then(Value::as_str) {
+                        scheme.text_primary = primary.to_string();
+                    }
+                    if let Some(secondary) = entry.get("secondary").and_then(Value::as_str) {
+                        scheme.text_secondary = secondary.to_string();
+                    }
+                    if entry.get("disabled").is_some() {
+                        unmapped.push("palette.text.disabled".into());
+                    }
+                }
+                other => unmapped.push(format!("palette.{other}")),
+            },
+        }
+    }
+}
+
+/// Maps the MUI typography ramp: weights and sizes translate directly,
+/// per-variant line heights and letter spacing collapse onto the shared
+/// tokens or get reported.
+fn map_mui_typography(value: &Value, theme: &mut Theme, unmapped: &mut Vec<String>) {
+    let Some(map) = value.as_object() else {
+        unmapped.push("typography".into());
+        return;
+    };
+    let typography = &mut theme.typography;
+    for (key, entry) in map {
+        match key.as_str() {
+            "fontFamily" => {
+                if let Some(family) = entry.as_str() {
+                    typography.font_family = family.to_string();
+                }
+            }
+            "fontSize" => {
+                if let Some(size) = entry.as_f64() {
+                    typography.font_size = size as f32;
+                }
+            }
+            "htmlFontSize" => {
+                if let Some(size) = entry.as_f64() {
+                    typography.html_font_size = size as f32;
+                }
+            }
+            "fontWeightLight" | "fontWeightRegular" | "fontWeightMedium" | "fontWeightBold" => {
+                if let Some(weight) = entry.as_f64() {
+                    let weight = weight.round() as u16;
+                    match key.as_str() {
+                        "fontWeightLight" => typography.font_weight_light = weight,
+                        "fontWeightRegular" => typography.font_weight_regular = weight,
+                        "fontWeightBold" => typography.font_weight_bold = weight,
+                        _ => typography.font_weight_medium = weight,
+                    }
+                }
+            }
+            "h1" | "h2" | "h3" | "h4" | "h5" | "h6" | "subtitle1" | "subtitle2" | "body1"
+            | "body2" | "button" | "caption" | "overline" => {
+                match entry.get("fontSize").and_then(mui_font_size_rem) {
+                    Some(rem) => {
+                        let slot = match key.as_str() {
+                            "h1" => &mut typography.h1,
+                            "h2" => &mut typography.h2,
+                            "h3" => &mut typography.h3,
+                            "h4" => &mut typography.h4,
+                            "h5" => &mut typography.h5,
+                            "h6" => &mut typography.h6,
+                            "subtitle1" => &mut typography.subtitle1,
+                            "subtitle2" => &mut typography.subtitle2,
+                            "body1" => &mut typography.body1,
+                            "body2" => &mut typography.body2,
+                            "button" => &mut typography.button,
+                            "caption" => &mut typography.caption,
+                            _ => &mut typography.overline,
+                        };
+                        *slot = rem;
+                    }
+                    None => unmapped.push(format!("typography.{key}.fontSize")),
+                }
+                // Only the size translates; per-variant weights, line
+                // heights and letter spacing collapse onto the shared
+                // ramp tokens, so report them.
+                if let Some(entries) = entry.as_object() {
+                    for name in entries.keys().filter(|name| *name != "fontSize") {
+                        unmapped.push(format!("typography.{key}.{name}"));
+                    }
+                }
+            }
+            other => unmapped.push(format!("typography.{other}")),
+        }
+    }
+}
+
+/// Parses MUI font sizes, which serialize as rem strings (`"6rem"`),
+/// pixel strings or bare numbers (treated as px against the 16px root).
+fn mui_font_size_rem(value: &Value) -> Option<f32> {
+    if let Some(px) = value.as_f64() {
+        return Some((px / 16.0) as f32);
+    }
+    let text = value.as_str()?.trim();
+    if let Some(rem) = text.strip_suffix("rem") {
+        return rem.trim().parse().ok();
+    }
+    if let Some(px) = text.strip_suffix("px") {
+        return px.trim().parse::<f32>().ok().map(|px| px / 16.0);
+    }
+    None
+}
+
+/// Maps the MUI transitions section onto the motion scheme.
+fn map_mui_transitions(value: &Value, theme: &mut Theme, unmapped: &mut Vec<String>) {
+    let Some(map) = value.as_object() else {
+        unmapped.push("transitions".into());
+        return;
+    };
+    for (key, entry) in map {
+        match key.as_str() {
+            "duration" => {
+                for (name, slot) in [
+                    ("shortest", None),
+                    ("shorter", None),
+                    ("short", Some(&mut theme.motion.duration_short_ms)),
+                    ("standard", Some(&mut theme.motion.duration_standard_ms)),
+                    ("complex", Some(&mut theme.motion.duration_long_ms)),
+                ] {
+                    match (slot, entry.get(name).and_then(Value::as_f64)) {
+                        (Some(slot), Some(ms)) => *slot = ms.round() as u16,
+                        (None, Some(_)) => {
+                            unmapped.push(format!("transitions.duration.{name}"));
+                        }
+                        _ => {}
+                    }
+                }
+            }
+            "easing" => {
+                if let Some(easing) = entry.get("easeInOut").and_then(Value::as_str) {
+                    theme.motion.easing = easing.to_string();
+                }
+                if let Some(entries) = entry.as_object() {
+                    for name in entries.keys().filter(|name| *name != "easeInOut") {
+                        unmapped.push(format!("transitions.easing.{name}"));
+                    }
+                }
+            }
+            other => unmapped.push(format!("transitions.{other}")),
+        }
+    }
+}
+
 fn themes_bundle(
     overrides: Option<PathBuf>,
     format: ThemeFormat,
@@ -714,7 +1040,7 @@ fn themes_bundle(
         format.as_str()
     );
     let overrides_snapshot = overrides.clone();
-    generate_theme(overrides, format, joy)?;
+    generate_theme(overrides, None, format, joy)?;
 
     let workspace = workspace_root();
     let artifact_root = out_dir.unwrap_or_else(|| workspace.join("target/artifacts/themes"));
@@ -881,6 +1207,66 @@ fn relative_display(root: &Path, target: &Path) -> String {
         .unwrap_or_else(|_| target.display().to_string())
 }
 
+fn stories(out_dir: Option<PathBuf>) -> Result<()> {
+    use rustic_ui_material::stories::all;
+    use std::collections::BTreeMap;
+
+    let output_dir = out_dir.unwrap_or_else(|| workspace_root().join("target/stories"));
+    fs::create_dir_all(&output_dir)
+        .with_context(|| format!("failed to create {}", output_dir.display()))?;
+
+    let baseline = theme_provider::material_css_baseline();
+    fs::write(output_dir.join("baseline.css"), &baseline)?;
+
+    // Group the registry by component so each one gets its own gallery page.
+    let mut by_component: BTreeMap<&'static str, Vec<_>> = BTreeMap::new();
+    for story in all() {
+        by_component.entry(story.component).or_default().push(story);
+    }
+
+    // Small inline toggle flipping the data attribute the generated baseline
+    // already understands; no JS framework required in the gallery shell.
+    let toggle = "<button onclick=\"const root = document.documentElement; \
+                  root.dataset.rustic_ui_color_scheme = \
+                  root.dataset.rustic_ui_color_scheme === 'dark' ? 'light' : 'dark';\">\
+                  Toggle color scheme</button>";
+
+    let mut index_links = String::new();
+    for (component, stories) in &by_component {
+        let mut sections = String::new();
+        for story in stories {
+            let markup = (story.render)();
+            sections.push_str(&format!(
+                "<section data-story=\"{component}/{name}\"><h2>{name}</h2>{markup}</section>\n",
+                name = story.name,
+            ));
+        }
+        let page = format!(
+            "<!DOCTYPE html><html data-rustic_ui_color_scheme=\"light\"><head>\
+             <meta charset=\"utf-8\"><title>{component} stories</title>\
+             <link rel=\"stylesheet\" href=\"baseline.css\"></head>\
+             <body><h1>{component}</h1>{toggle}\n{sections}</body></html>\n"
+        );
+        let page_path = output_dir.join(format!("{component}.html"));
+        fs::write(&page_path, page)?;
+        println!("[xtask] wrote {}", page_path.display());
+        index_links.push_str(&format!(
+            "<li><a href=\"{component}.html\">{component}</a> ({count} stories)</li>\n",
+            count = stories.len(),
+        ));
+    }
+
+    let index = format!(
+        "<!DOCTYPE html><html data-rustic_ui_color_scheme=\"light\"><head>\
+         <meta charset=\"utf-8\"><title>RusticUI component stories</title>\
+         <link rel=\"stylesheet\" href=\"baseline.css\"></head>\
+         <body><h1>Component stories</h1>{toggle}<ul>\n{index_links}</ul></body></html>\n"
+    );
+    fs::write(output_dir.join("index.html"), index)?;
+    println!("[xtask] wrote {}", output_dir.join("index.html").display());
+    Ok(())
+}
+
 fn material_parity() -> Result<()> {
     // Keep the parity snapshot fresh so enterprise adopters can track adoption progress
     // without spelunking through multiple repositories.
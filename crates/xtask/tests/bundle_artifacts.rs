@@ -67,10 +67,6 @@ fn workspace_root() -> PathBuf {
 #[test]
 fn icons_bundle_emits_single_manifest_and_unique_entries() -> Result<()> {
     let workspace = workspace_root();
-    let fixture_dir = workspace.join("crates/rustic-ui-icons/icons/__xtask_fixture");
-    if fixture_dir.exists() {
-        fs::remove_dir_all(&fixture_dir)?;
-    }
     let temp = tempdir()?;
     let out_dir = temp.path().join("icons-artifacts");
 
@@ -130,10 +126,6 @@ fn icons_bundle_emits_single_manifest_and_unique_entries() -> Result<()> {
         assert_eq!(entry["media_type"].as_str(), Some("image/svg+xml"));
     }
 
-    if fixture_dir.exists() {
-        fs::remove_dir_all(&fixture_dir)?;
-    }
-
     Ok(())
 }
 
@@ -145,6 +137,18 @@ fn themes_bundle_emits_css_and_json_with_overrides() -> Result<()> {
     let overrides = workspace.join("crates/xtask/tests/fixtures/material_overrides.json");
     assert!(overrides.exists(), "fixture missing: {overrides:?}");
 
+    // Snapshot the tracked templates BEFORE invoking the command: the bundle
+    // pipeline regenerates them in place with the fixture overrides applied,
+    // and snapshotting afterwards would "restore" the overridden artifacts
+    // into the working tree.
+    let templates_dir = workspace.join("crates/rustic-ui-system/templates");
+    let _snapshots: Vec<FileSnapshot> = WalkDir::new(&templates_dir)
+        .into_iter()
+        .filter_map(|entry| entry.ok())
+        .filter(|entry| entry.file_type().is_file())
+        .map(|entry| FileSnapshot::new(entry.path().to_path_buf()))
+        .collect();
+
     let mut cmd = Command::new("cargo");
     cmd.current_dir(&workspace)
         .arg("xtask")
@@ -160,13 +164,6 @@ fn themes_bundle_emits_css_and_json_with_overrides() -> Result<()> {
         .stdout(predicates::str::contains("[xtask][themes-bundle] summary="));
 
     let bundle_dir = out_dir.join("themes");
-    let templates_dir = workspace.join("crates/rustic-ui-system/templates");
-    let _snapshots: Vec<FileSnapshot> = WalkDir::new(&templates_dir)
-        .into_iter()
-        .filter_map(|entry| entry.ok())
-        .filter(|entry| entry.file_type().is_file())
-        .map(|entry| FileSnapshot::new(entry.path().to_path_buf()))
-        .collect();
     let manifest_path = bundle_dir.join("themes.manifest.json");
     assert!(
         manifest_path.exists(),
@@ -1,89 +1,21 @@
 //! Integration coverage for the icon feature manifest generator. The scenarios
 //! emulate contributors adding a brand new icon set to ensure our automation
 //! rewrites `Cargo.toml` deterministically and logs actionable context for CI.
+//!
+//! Everything runs against a temporary copy of the crate manifest and a
+//! throwaway icons directory so the tracked source tree is never mutated, even
+//! when assertions fail mid-test.
 
 use anyhow::Result;
 use assert_cmd::Command;
 use predicates::prelude::*;
 use std::fs;
-use std::io;
 use std::path::{Path, PathBuf};
+use tempfile::tempdir;
 
 const START_MARKER: &str = "# BEGIN AUTO-GENERATED ICON FEATURES -- DO NOT EDIT.";
 const END_MARKER: &str = "# END AUTO-GENERATED ICON FEATURES";
 
-/// Captures and restores a file so repository state remains pristine after tests.
-#[derive(Debug)]
-struct FileSnapshot {
-    path: PathBuf,
-    original_bytes: Option<Vec<u8>>,
-}
-
-impl FileSnapshot {
-    fn new(path: PathBuf) -> Self {
-        let original_bytes = fs::read(&path).ok();
-        Self {
-            path,
-            original_bytes,
-        }
-    }
-
-    fn restore(&self) {
-        match &self.original_bytes {
-            Some(bytes) => {
-                if let Err(error) = fs::write(&self.path, bytes) {
-                    eprintln!("[test] failed to restore {}: {error}", self.path.display());
-                }
-            }
-            None => match fs::remove_file(&self.path) {
-                Ok(()) => {}
-                Err(error) if error.kind() == io::ErrorKind::NotFound => {}
-                Err(error) => {
-                    eprintln!(
-                        "[test] failed to remove {} during cleanup: {error}",
-                        self.path.display()
-                    );
-                }
-            },
-        }
-    }
-}
-
-impl Drop for FileSnapshot {
-    fn drop(&mut self) {
-        self.restore();
-    }
-}
-
-/// Removes a directory created for test isolation.
-struct DirGuard {
-    path: PathBuf,
-    existed: bool,
-}
-
-impl DirGuard {
-    fn new(path: PathBuf) -> Self {
-        let existed = path.exists();
-        Self { path, existed }
-    }
-}
-
-impl Drop for DirGuard {
-    fn drop(&mut self) {
-        if self.existed {
-            return;
-        }
-        if let Err(error) = fs::remove_dir_all(&self.path) {
-            if error.kind() != io::ErrorKind::NotFound {
-                eprintln!(
-                    "[test] failed to remove fixture directory {}: {error}",
-                    self.path.display()
-                );
-            }
-        }
-    }
-}
-
 /// Computes the workspace root using the same logic as the production binary.
 fn workspace_root() -> PathBuf {
     Path::new(env!("CARGO_MANIFEST_DIR"))
@@ -107,16 +39,20 @@ fn feature_block(manifest: &str) -> &str {
 #[test]
 fn new_icon_sets_update_the_manifest_deterministically() -> Result<()> {
     let workspace = workspace_root();
-    let manifest_path = workspace.join("crates/rustic-ui-icons/Cargo.toml");
-    let icons_root = workspace.join("crates/rustic-ui-icons/icons");
-    let fixture_set = icons_root.join("__xtask_fixture");
 
-    assert!(
-        !fixture_set.exists(),
-        "fixture set should not collide with real icon sets"
-    );
+    // Operate on copies inside a temporary directory so the generator never
+    // touches the real `icons/` tree or `Cargo.toml`. The binary exposes
+    // `--icons-dir`/`--manifest-path` overrides for exactly this purpose.
+    let temp = tempdir()?;
+    let icons_root = temp.path().join("icons");
+    let fixture_set = icons_root.join("__xtask_fixture");
     fs::create_dir_all(&fixture_set)?;
-    let _dir_guard = DirGuard::new(fixture_set.clone());
+
+    let manifest_path = temp.path().join("Cargo.toml");
+    fs::copy(
+        workspace.join("crates/rustic-ui-icons/Cargo.toml"),
+        &manifest_path,
+    )?;
 
     // Author the icons in reverse alphabetical order to ensure the generator
     // performs its own sorting and does not depend on filesystem iteration
@@ -124,8 +60,6 @@ fn new_icon_sets_update_the_manifest_deterministically() -> Result<()> {
     fs::write(fixture_set.join("zeta.svg"), "<svg></svg>")?;
     fs::write(fixture_set.join("alpha.svg"), "<svg></svg>")?;
 
-    let snapshot = FileSnapshot::new(manifest_path.clone());
-
     // Execute the generator through Cargo the same way the xtask pipeline does.
     let mut cmd = Command::new("cargo");
     let assertion = cmd
@@ -135,6 +69,11 @@ fn new_icon_sets_update_the_manifest_deterministically() -> Result<()> {
         .arg("rustic-ui-icons")
         .arg("--bin")
         .arg("update_features")
+        .arg("--")
+        .arg("--icons-dir")
+        .arg(&icons_root)
+        .arg("--manifest-path")
+        .arg(&manifest_path)
         .assert()
         .success()
         .stdout(predicate::str::contains("__xtask_fixture"))
@@ -179,6 +118,11 @@ fn new_icon_sets_update_the_manifest_deterministically() -> Result<()> {
         .arg("rustic-ui-icons")
         .arg("--bin")
         .arg("update_features")
+        .arg("--")
+        .arg("--icons-dir")
+        .arg(&icons_root)
+        .arg("--manifest-path")
+        .arg(&manifest_path)
         .assert()
         .success()
         .stdout(predicate::str::contains("already up to date"));
@@ -189,6 +133,5 @@ fn new_icon_sets_update_the_manifest_deterministically() -> Result<()> {
         "second invocation should not rewrite the manifest"
     );
 
-    drop(snapshot);
     Ok(())
 }
@@ -0,0 +1,414 @@
+//! Shared app shell state powering the multi-page navigation demos.
+//!
+//! Framework routers (yew-router, leptos_router, dioxus-router, sycamore's
+//! own router) all want to own URL parsing, which historically meant each
+//! example re-implemented active-nav highlighting, breadcrumb assembly and
+//! page transition bookkeeping in four slightly different ways.  This crate
+//! centralises the logic so adapters only translate URLs in and markup out:
+//!
+//! * [`RouteDefinition`] describes the route table once, including `:param`
+//!   segments and the parent links breadcrumbs are derived from.
+//! * [`AppShellMachine`] tracks the matched route, exposes which primary nav
+//!   entry is active (nested routes highlight their top-level ancestor), and
+//!   derives the breadcrumb trail with parameters substituted back into the
+//!   concrete paths.
+//! * Page transitions follow the same explicit phase pattern as the headless
+//!   dialog machine: [`TransitionPhase::Exiting`] while the old page animates
+//!   out, [`TransitionPhase::Entering`] while the new one animates in, and a
+//!   final settle back to [`TransitionPhase::Idle`].  Adapters that skip
+//!   animations simply call the two `finish_*` hooks back to back.
+
+/// Stable automation prefix applied to nav related selectors.
+pub const AUTOMATION_ID: &str = "rusticui-app-shell";
+
+/// Declarative description of a single route.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RouteDefinition {
+    /// Stable identifier referenced by [`RouteDefinition::parent`] links.
+    pub id: &'static str,
+    /// Path pattern; segments starting with `:` capture parameters
+    /// (e.g. `/projects/:project_id`).
+    pub path: &'static str,
+    /// Human readable label rendered in nav items and breadcrumbs.
+    pub label: &'static str,
+    /// Identifier of the parent route, used for breadcrumb derivation and
+    /// active-nav roll-up.  `None` marks a top level route.
+    pub parent: Option<&'static str>,
+    /// Whether the route appears in the primary navigation rail.
+    pub nav: bool,
+}
+
+/// Result of matching a concrete path against the route table.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RouteMatch {
+    /// Identifier of the matched [`RouteDefinition`].
+    pub route_id: &'static str,
+    /// Captured `:param` values in declaration order.
+    pub params: Vec<(&'static str, String)>,
+}
+
+impl RouteMatch {
+    /// Look up a captured parameter by name (without the `:` prefix).
+    pub fn param(&self, name: &str) -> Option<&str> {
+        self.params
+            .iter()
+            .find(|(key, _)| *key == name)
+            .map(|(_, value)| value.as_str())
+    }
+}
+
+/// Entry rendered in the primary navigation rail.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct NavItem {
+    /// Route identifier backing the entry.
+    pub route_id: &'static str,
+    /// Label shown to the user.
+    pub label: &'static str,
+    /// Concrete path the entry links to.
+    pub path: &'static str,
+    /// Whether the current route lives under this entry.
+    pub active: bool,
+    /// Automation id applied to the anchor for QA tooling.
+    pub automation_id: String,
+}
+
+/// Single link in the breadcrumb trail, ordered root first.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Breadcrumb {
+    /// Route identifier backing the crumb.
+    pub route_id: &'static str,
+    /// Label shown to the user.
+    pub label: &'static str,
+    /// Concrete path with captured parameters substituted back in.
+    pub path: String,
+}
+
+/// Explicit page transition lifecycle mirroring the headless dialog phases.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum TransitionPhase {
+    /// No navigation in flight.
+    #[default]
+    Idle,
+    /// The outgoing page is animating out; the old route is still rendered.
+    Exiting,
+    /// The incoming page is animating in; the new route is rendered.
+    Entering,
+}
+
+/// Deterministic state machine behind every app shell adapter.
+#[derive(Clone, Debug)]
+pub struct AppShellMachine {
+    routes: Vec<RouteDefinition>,
+    current: RouteMatch,
+    pending: Option<RouteMatch>,
+    phase: TransitionPhase,
+}
+
+impl AppShellMachine {
+    /// Build a machine over `routes`, starting at `initial_path`.
+    ///
+    /// Returns `None` when the initial path does not match any route so
+    /// adapters can surface their 404 page instead of panicking.
+    pub fn new(routes: Vec<RouteDefinition>, initial_path: &str) -> Option<Self> {
+        let current = match_path(&routes, initial_path)?;
+        Some(Self {
+            routes,
+            current,
+            pending: None,
+            phase: TransitionPhase::Idle,
+        })
+    }
+
+    /// Route match currently rendered (the outgoing page while exiting).
+    #[inline]
+    pub fn current(&self) -> &RouteMatch {
+        &self.current
+    }
+
+    /// Definition of the currently rendered route.
+    pub fn current_route(&self) -> &RouteDefinition {
+        self.definition(self.current.route_id)
+            .expect("current match references a known route")
+    }
+
+    /// Current transition phase.
+    #[inline]
+    pub fn phase(&self) -> TransitionPhase {
+        self.phase
+    }
+
+    /// Begin navigating to `path`.
+    ///
+    /// Returns `false` (leaving the machine untouched) when the path does not
+    /// match any route or already matches the rendered route.  On success the
+    /// machine enters [`TransitionPhase::Exiting`] with the target parked
+    /// until [`finish_exit`](Self::finish_exit) swaps it in.
+    pub fn navigate(&mut self, path: &str) -> bool {
+        let Some(target) = match_path(&self.routes, path) else {
+            return false;
+        };
+        if target == self.current && self.pending.is_none() {
+            return false;
+        }
+        self.pending = Some(target);
+        self.phase = TransitionPhase::Exiting;
+        true
+    }
+
+    /// Swap the pending route in once the outgoing page finished animating.
+    pub fn finish_exit(&mut self) {
+        if let Some(next) = self.pending.take() {
+            self.current = next;
+            self.phase = TransitionPhase::Entering;
+        }
+    }
+
+    /// Settle back to [`TransitionPhase::Idle`] once the incoming page landed.
+    pub fn finish_enter(&mut self) {
+        if self.phase == TransitionPhase::Entering {
+            self.phase = TransitionPhase::Idle;
+        }
+    }
+
+    /// Identifier of the top level nav entry the current route rolls up to.
+    pub fn active_nav_id(&self) -> &'static str {
+        let mut id = self.current.route_id;
+        while let Some(route) = self.definition(id) {
+            match route.parent {
+                Some(parent) => id = parent,
+                None => break,
+            }
+        }
+        id
+    }
+
+    /// Primary navigation entries with the active flag resolved.
+    pub fn nav_items(&self) -> Vec<NavItem> {
+        let active = self.active_nav_id();
+        self.routes
+            .iter()
+            .filter(|route| route.nav)
+            .map(|route| NavItem {
+                route_id: route.id,
+                label: route.label,
+                path: route.path,
+                active: route.id == active,
+                automation_id: format!("{AUTOMATION_ID}-nav-{}", route.id),
+            })
+            .collect()
+    }
+
+    /// Breadcrumb trail from the root ancestor down to the current route.
+    ///
+    /// Parameters captured by the current match are substituted back into the
+    /// ancestor patterns so every crumb links to a concrete path.
+    pub fn breadcrumbs(&self) -> Vec<Breadcrumb> {
+        let mut chain = Vec::new();
+        let mut id = Some(self.current.route_id);
+        while let Some(route) = id.and_then(|id| self.definition(id)) {
+            chain.push(Breadcrumb {
+                route_id: route.id,
+                label: route.label,
+                path: substitute_params(route.path, &self.current.params),
+            });
+            id = route.parent;
+        }
+        chain.reverse();
+        chain
+    }
+
+    fn definition(&self, id: &str) -> Option<&RouteDefinition> {
+        self.routes.iter().find(|route| route.id == id)
+    }
+}
+
+/// Match `path` against the route table, capturing `:param` segments.
+///
+/// Matching is exact on the segment count; the first route whose pattern
+/// accepts every segment wins, so order the table from specific to general.
+pub fn match_path(routes: &[RouteDefinition], path: &str) -> Option<RouteMatch> {
+    let segments: Vec<&str> = split_segments(path);
+    routes.iter().find_map(|route| {
+        let pattern: Vec<&str> = split_segments(route.path);
+        if pattern.len() != segments.len() {
+            return None;
+        }
+        let mut params = Vec::new();
+        for (expected, actual) in pattern.iter().zip(&segments) {
+            if let Some(name) = expected.strip_prefix(':') {
+                params.push((name, (*actual).to_string()));
+            } else if expected != actual {
+                return None;
+            }
+        }
+        // `:param` names borrow from the route table which lives as long as
+        // the machine, so promote them to the `'static` definitions.
+        let params = params
+            .into_iter()
+            .map(|(name, value)| {
+                let name = pattern_param_name(route.path, name)
+                    .expect("parameter name originates from the pattern");
+                (name, value)
+            })
+            .collect();
+        Some(RouteMatch {
+            route_id: route.id,
+            params,
+        })
+    })
+}
+
+/// Recover the `'static` parameter name slice from the original pattern.
+fn pattern_param_name(pattern: &'static str, name: &str) -> Option<&'static str> {
+    pattern
+        .split('/')
+        .filter_map(|segment| segment.strip_prefix(':'))
+        .find(|candidate| *candidate == name)
+}
+
+fn split_segments(path: &str) -> Vec<&str> {
+    path.split('/')
+        .filter(|segment| !segment.is_empty())
+        .collect()
+}
+
+/// Replace `:param` segments in `pattern` with their captured values.
+fn substitute_params(pattern: &str, params: &[(&'static str, String)]) -> String {
+    let substituted: Vec<String> = pattern
+        .split('/')
+        .filter(|segment| !segment.is_empty())
+        .map(|segment| match segment.strip_prefix(':') {
+            Some(name) => params
+                .iter()
+                .find(|(key, _)| *key == name)
+                .map(|(_, value)| value.clone())
+                .unwrap_or_else(|| segment.to_string()),
+            None => segment.to_string(),
+        })
+        .collect();
+    format!("/{}", substituted.join("/"))
+}
+
+/// Route table shared by every app shell demo.
+///
+/// The hierarchy intentionally mixes flat nav entries (dashboard, reports)
+/// with a nested detail page so the adapters exercise breadcrumb derivation
+/// and active-nav roll-up without inventing their own fixtures.
+pub fn enterprise_routes() -> Vec<RouteDefinition> {
+    vec![
+        RouteDefinition {
+            id: "dashboard",
+            path: "/",
+            label: "Dashboard",
+            parent: None,
+            nav: true,
+        },
+        RouteDefinition {
+            id: "projects",
+            path: "/projects",
+            label: "Projects",
+            parent: None,
+            nav: true,
+        },
+        RouteDefinition {
+            id: "project-detail",
+            path: "/projects/:project_id",
+            label: "Project detail",
+            parent: Some("projects"),
+            nav: false,
+        },
+        RouteDefinition {
+            id: "project-settings",
+            path: "/projects/:project_id/settings",
+            label: "Project settings",
+            parent: Some("project-detail"),
+            nav: false,
+        },
+        RouteDefinition {
+            id: "reports",
+            path: "/reports",
+            label: "Reports",
+            parent: None,
+            nav: true,
+        },
+        RouteDefinition {
+            id: "settings",
+            path: "/settings",
+            label: "Settings",
+            parent: None,
+            nav: true,
+        },
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn machine_at(path: &str) -> AppShellMachine {
+        AppShellMachine::new(enterprise_routes(), path).expect("path matches the route table")
+    }
+
+    #[test]
+    fn matches_routes_and_captures_params() {
+        let routes = enterprise_routes();
+        let matched = match_path(&routes, "/projects/atlas-7").expect("detail route matches");
+        assert_eq!(matched.route_id, "project-detail");
+        assert_eq!(matched.param("project_id"), Some("atlas-7"));
+        assert!(match_path(&routes, "/missing/route/depth").is_none());
+    }
+
+    #[test]
+    fn nested_routes_highlight_their_top_level_nav_entry() {
+        let machine = machine_at("/projects/atlas-7/settings");
+        assert_eq!(machine.active_nav_id(), "projects");
+
+        let nav = machine.nav_items();
+        let active: Vec<_> = nav.iter().filter(|item| item.active).collect();
+        assert_eq!(active.len(), 1);
+        assert_eq!(active[0].route_id, "projects");
+        assert_eq!(active[0].automation_id, "rusticui-app-shell-nav-projects");
+    }
+
+    #[test]
+    fn breadcrumbs_substitute_captured_params() {
+        let machine = machine_at("/projects/atlas-7/settings");
+        let crumbs = machine.breadcrumbs();
+        let paths: Vec<&str> = crumbs.iter().map(|crumb| crumb.path.as_str()).collect();
+        assert_eq!(
+            paths,
+            [
+                "/projects",
+                "/projects/atlas-7",
+                "/projects/atlas-7/settings"
+            ]
+        );
+        assert_eq!(crumbs[1].label, "Project detail");
+    }
+
+    #[test]
+    fn navigation_walks_the_transition_phases() {
+        let mut machine = machine_at("/");
+        assert_eq!(machine.phase(), TransitionPhase::Idle);
+
+        assert!(machine.navigate("/reports"));
+        assert_eq!(machine.phase(), TransitionPhase::Exiting);
+        // The outgoing page is still rendered while exiting.
+        assert_eq!(machine.current().route_id, "dashboard");
+
+        machine.finish_exit();
+        assert_eq!(machine.phase(), TransitionPhase::Entering);
+        assert_eq!(machine.current().route_id, "reports");
+
+        machine.finish_enter();
+        assert_eq!(machine.phase(), TransitionPhase::Idle);
+    }
+
+    #[test]
+    fn navigate_rejects_unknown_and_redundant_targets() {
+        let mut machine = machine_at("/reports");
+        assert!(!machine.navigate("/does-not-exist"));
+        assert!(!machine.navigate("/reports"));
+        assert_eq!(machine.phase(), TransitionPhase::Idle);
+    }
+}
@@ -0,0 +1,472 @@
+//! Shared CRUD table state powering the data heavy demos.
+//!
+//! The crate layers the enterprise flows a real admin screen needs on top of
+//! the experimental [`DataGrid`] core so every framework adapter renders the
+//! same deterministic machine:
+//!
+//! * **Server-style pagination** — [`CrudTable::page_view`] exposes one page
+//!   at a time with the same page/count metadata a backend would return.
+//! * **Inline editing** — [`RowEditor`] wraps one headless [`TextFieldState`]
+//!   per editable column, so dirty tracking and error storage behave exactly
+//!   like the standalone text field demos.
+//! * **Delete confirmation** — removals route through a headless
+//!   [`DialogState`] so adapters reuse the standard modal phases.
+//! * **Optimistic updates** — edits and deletes apply immediately while the
+//!   previous row is parked in a [`PendingMutation`]; when the simulated
+//!   server rejects the call [`CrudTable::resolve_mutation`] rolls the grid
+//!   back to the exact prior state (including row position).
+
+use rustic_ui_headless::dialog::DialogState;
+use rustic_ui_headless::text_field::TextFieldState;
+use rustic_ui_lab::data_grid::DataGrid;
+
+/// Stable automation prefix applied to table selectors.
+pub const AUTOMATION_ID: &str = "rusticui-crud-table";
+
+/// Row model shared by every adapter.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct EmployeeRow {
+    /// Server assigned identifier; never recycled.
+    pub id: u64,
+    /// Display name, editable inline.
+    pub name: String,
+    /// Contact email, editable inline.
+    pub email: String,
+    /// Team the employee belongs to.
+    pub team: String,
+}
+
+/// One page of rows plus the metadata a server response would carry.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PageView {
+    /// Rows visible on the current page, in grid order.
+    pub rows: Vec<EmployeeRow>,
+    /// Zero based index of the current page.
+    pub page: usize,
+    /// Total number of pages (at least one, even when empty).
+    pub page_count: usize,
+    /// Total number of rows across all pages.
+    pub total_rows: usize,
+}
+
+/// Inline editor over one row, backed by headless text field machines.
+pub struct RowEditor {
+    /// Identifier of the row being edited.
+    pub row_id: u64,
+    /// Editable name column.
+    pub name: TextFieldState,
+    /// Editable email column.
+    pub email: TextFieldState,
+}
+
+/// Optimistically applied mutation awaiting the (simulated) server verdict.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum PendingMutation {
+    /// A row was edited in place; `previous` restores the old values.
+    Update {
+        /// Identifier of the mutated row.
+        row_id: u64,
+        /// Row contents before the edit.
+        previous: EmployeeRow,
+    },
+    /// A row was removed; `index` restores the original grid position.
+    Delete {
+        /// Row contents before the delete.
+        previous: EmployeeRow,
+        /// Position the row occupied in the grid.
+        index: usize,
+    },
+}
+
+/// Deterministic CRUD machine shared by the framework adapters.
+pub struct CrudTable {
+    grid: DataGrid<EmployeeRow>,
+    page: usize,
+    page_size: usize,
+    editor: Option<RowEditor>,
+    delete_dialog: DialogState,
+    delete_target: Option<u64>,
+    pending: Option<PendingMutation>,
+}
+
+impl CrudTable {
+    /// Build a table over the seed data with the given page size.
+    pub fn new(page_size: usize) -> Self {
+        Self::with_rows(seed_rows(), page_size)
+    }
+
+    /// Build a table over custom rows, useful for empty-state demos.
+    pub fn with_rows(rows: Vec<EmployeeRow>, page_size: usize) -> Self {
+        Self {
+            grid: DataGrid::new(rows),
+            page: 0,
+            page_size: page_size.max(1),
+            editor: None,
+            delete_dialog: DialogState::uncontrolled(false),
+            delete_target: None,
+            pending: None,
+        }
+    }
+
+    // --- Pagination -------------------------------------------------------
+
+    /// Snapshot of the current page in server response shape.
+    pub fn page_view(&self) -> PageView {
+        let total_rows = self.grid.rows.len();
+        let page_count = total_rows.div_ceil(self.page_size).max(1);
+        let page = self.page.min(page_count - 1);
+        let start = page * self.page_size;
+        let end = (start + self.page_size).min(total_rows);
+        PageView {
+            rows: self.grid.rows[start.min(total_rows)..end].to_vec(),
+            page,
+            page_count,
+            total_rows,
+        }
+    }
+
+    /// Jump to a page, clamping to the valid range.
+    pub fn set_page(&mut self, page: usize) {
+        let page_count = self.page_view().page_count;
+        self.page = page.min(page_count - 1);
+    }
+
+    /// Advance one page if another exists.
+    pub fn next_page(&mut self) {
+        self.set_page(self.page + 1);
+    }
+
+    /// Return to the previous page if one exists.
+    pub fn previous_page(&mut self) {
+        self.set_page(self.page.saturating_sub(1));
+    }
+
+    // --- Inline editing ---------------------------------------------------
+
+    /// Open the inline editor for a row.  Returns `false` when the row does
+    /// not exist or another mutation is still awaiting its server verdict.
+    pub fn begin_edit(&mut self, row_id: u64) -> bool {
+        if self.pending.is_some() {
+            return false;
+        }
+        let Some(row) = self.grid.rows.iter().find(|row| row.id == row_id) else {
+            return false;
+        };
+        self.editor = Some(RowEditor {
+            row_id,
+            name: TextFieldState::uncontrolled(row.name.clone(), None),
+            email: TextFieldState::uncontrolled(row.email.clone(), None),
+        });
+        true
+    }
+
+    /// Borrow the active editor so adapters can render values and errors.
+    pub fn editor(&self) -> Option<&RowEditor> {
+        self.editor.as_ref()
+    }
+
+    /// Route a keystroke into the name column.
+    pub fn edit_name(&mut self, value: impl Into<String>) {
+        if let Some(editor) = self.editor.as_mut() {
+            editor.name.change(value, |_| {});
+        }
+    }
+
+    /// Route a keystroke into the email column.
+    pub fn edit_email(&mut self, value: impl Into<String>) {
+        if let Some(editor) = self.editor.as_mut() {
+            editor.email.change(value, |_| {});
+        }
+    }
+
+    /// Validate the editor and optimistically apply the edit.
+    ///
+    /// On success the grid mutates immediately, the previous row is parked in
+    /// [`PendingMutation::Update`] and the editor closes.  On validation
+    /// failure the errors land on the text field machines and the editor
+    /// stays open.
+    pub fn commit_edit(&mut self) -> bool {
+        let Some(editor) = self.editor.as_mut() else {
+            return false;
+        };
+        let mut valid = true;
+        if editor.name.value().trim().is_empty() {
+            editor.name.set_errors(vec!["Name is required.".into()]);
+            valid = false;
+        } else {
+            editor.name.clear_errors();
+        }
+        let email = editor.email.value().trim().to_string();
+        if !email.contains('@') || email.starts_with('@') || email.ends_with('@') {
+            editor
+                .email
+                .set_errors(vec!["Enter a valid email address.".into()]);
+            valid = false;
+        } else {
+            editor.email.clear_errors();
+        }
+        if !valid {
+            return false;
+        }
+
+        let row_id = editor.row_id;
+        let name = editor.name.value().trim().to_string();
+        let Some(row) = self.grid.rows.iter_mut().find(|row| row.id == row_id) else {
+            self.editor = None;
+            return false;
+        };
+        self.pending = Some(PendingMutation::Update {
+            row_id,
+            previous: row.clone(),
+        });
+        row.name = name;
+        row.email = email;
+        self.editor = None;
+        true
+    }
+
+    /// Close the editor without touching the grid.
+    pub fn cancel_edit(&mut self) {
+        self.editor = None;
+    }
+
+    // --- Delete confirmation ---------------------------------------------
+
+    /// Headless dialog backing the delete confirmation modal.
+    pub fn delete_dialog(&self) -> &DialogState {
+        &self.delete_dialog
+    }
+
+    /// Row currently awaiting delete confirmation.
+    pub fn delete_target(&self) -> Option<&EmployeeRow> {
+        self.delete_target
+            .and_then(|id| self.grid.rows.iter().find(|row| row.id == id))
+    }
+
+    /// Ask for confirmation before deleting a row.
+    pub fn request_delete(&mut self, row_id: u64) -> bool {
+        if self.pending.is_some() || !self.grid.rows.iter().any(|row| row.id == row_id) {
+            return false;
+        }
+        self.delete_target = Some(row_id);
+        self.delete_dialog.open(|_| {});
+        self.delete_dialog.finish_open();
+        true
+    }
+
+    /// Confirm the pending delete, removing the row optimistically.
+    pub fn confirm_delete(&mut self) -> bool {
+        let Some(row_id) = self.delete_target.take() else {
+            return false;
+        };
+        self.close_delete_dialog();
+        let Some(index) = self.grid.rows.iter().position(|row| row.id == row_id) else {
+            return false;
+        };
+        let previous = self.grid.rows.remove(index);
+        self.pending = Some(PendingMutation::Delete { previous, index });
+        // Deleting the last row of the last page must not strand the user on
+        // an empty page.
+        self.set_page(self.page);
+        true
+    }
+
+    /// Dismiss the confirmation dialog without deleting.
+    pub fn cancel_delete(&mut self) {
+        self.delete_target = None;
+        self.close_delete_dialog();
+    }
+
+    /// Walk the dialog through its closing phases.  The demos do not animate
+    /// the confirmation modal so both transitions settle synchronously.
+    fn close_delete_dialog(&mut self) {
+        self.delete_dialog.close(|_| {});
+        self.delete_dialog.finish_close();
+    }
+
+    // --- Optimistic updates ----------------------------------------------
+
+    /// Mutation currently awaiting its server verdict.
+    pub fn pending_mutation(&self) -> Option<&PendingMutation> {
+        self.pending.as_ref()
+    }
+
+    /// Settle the in-flight mutation with the (simulated) server verdict.
+    ///
+    /// On `success` the optimistic state simply becomes permanent.  On
+    /// failure the previous row is restored — updates swap the old values
+    /// back in, deletes re-insert at the original index.  Returns `true` when
+    /// a rollback happened.
+    pub fn resolve_mutation(&mut self, success: bool) -> bool {
+        let Some(mutation) = self.pending.take() else {
+            return false;
+        };
+        if success {
+            return false;
+        }
+        match mutation {
+            PendingMutation::Update { row_id, previous } => {
+                if let Some(row) = self.grid.rows.iter_mut().find(|row| row.id == row_id) {
+                    *row = previous;
+                }
+            }
+            PendingMutation::Delete { previous, index } => {
+                let index = index.min(self.grid.rows.len());
+                self.grid.rows.insert(index, previous);
+            }
+        }
+        true
+    }
+
+    /// Automation id for a row, stable across SSR and hydration.
+    pub fn row_automation_id(&self, row_id: u64) -> String {
+        format!("{AUTOMATION_ID}-row-{row_id}")
+    }
+}
+
+/// Seed data shared by every CRUD demo: enough rows to paginate three deep
+/// with the default page size of five.
+pub fn seed_rows() -> Vec<EmployeeRow> {
+    const SEED: [(&str, &str, &str); 12] = [
+        ("Imogen Vale", "imogen.vale@example.com", "Platform"),
+        ("Ravi Chandrasekhar", "ravi.c@example.com", "Platform"),
+        ("Lena Okafor", "lena.okafor@example.com", "Payments"),
+        ("Marcus Thorne", "marcus.thorne@example.com", "Payments"),
+        ("Yuki Tanaka", "yuki.tanaka@example.com", "Observability"),
+        ("Sofia Reyes", "sofia.reyes@example.com", "Observability"),
+        ("Piotr Nowak", "piotr.nowak@example.com", "Identity"),
+        ("Amara Diallo", "amara.diallo@example.com", "Identity"),
+        ("Theo Lindqvist", "theo.lindqvist@example.com", "Growth"),
+        ("Hana Suzuki", "hana.suzuki@example.com", "Growth"),
+        (
+            "Gabriel Costa",
+            "gabriel.costa@example.com",
+            "Infrastructure",
+        ),
+        (
+            "Nadia Petrova",
+            "nadia.petrova@example.com",
+            "Infrastructure",
+        ),
+    ];
+    SEED.iter()
+        .enumerate()
+        .map(|(index, (name, email, team))| EmployeeRow {
+            id: index as u64 + 1,
+            name: (*name).to_string(),
+            email: (*email).to_string(),
+            team: (*team).to_string(),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rustic_ui_headless::dialog::DialogPhase;
+
+    #[test]
+    fn pagination_slices_like_a_server_response() {
+        let mut table = CrudTable::new(5);
+        let first = table.page_view();
+        assert_eq!(first.rows.len(), 5);
+        assert_eq!(first.page_count, 3);
+        assert_eq!(first.total_rows, 12);
+
+        table.next_page();
+        table.next_page();
+        let last = table.page_view();
+        assert_eq!(last.page, 2);
+        assert_eq!(last.rows.len(), 2);
+
+        // Requests past the end clamp instead of rendering an empty page.
+        table.set_page(99);
+        assert_eq!(table.page_view().page, 2);
+    }
+
+    #[test]
+    fn inline_edit_validates_before_applying() {
+        let mut table = CrudTable::new(5);
+        assert!(table.begin_edit(3));
+        table.edit_email("not-an-email");
+        assert!(!table.commit_edit());
+        let editor = table.editor().expect("editor stays open on failure");
+        assert!(!editor.email.errors().is_empty());
+
+        table.edit_email("lena@example.com");
+        assert!(table.commit_edit());
+        assert!(table.editor().is_none());
+        let row = &table.page_view().rows[2];
+        assert_eq!(row.email, "lena@example.com");
+    }
+
+    #[test]
+    fn rejected_edit_rolls_back_to_previous_values() {
+        let mut table = CrudTable::new(5);
+        table.begin_edit(1);
+        table.edit_name("Renamed Person");
+        assert!(table.commit_edit());
+        assert!(matches!(
+            table.pending_mutation(),
+            Some(PendingMutation::Update { row_id: 1, .. })
+        ));
+
+        assert!(table.resolve_mutation(false));
+        assert_eq!(table.page_view().rows[0].name, "Imogen Vale");
+        assert!(table.pending_mutation().is_none());
+    }
+
+    #[test]
+    fn delete_flows_through_the_confirmation_dialog() {
+        let mut table = CrudTable::new(5);
+        assert!(table.request_delete(2));
+        assert_eq!(table.delete_dialog().phase(), DialogPhase::Open);
+        assert_eq!(table.delete_target().map(|row| row.id), Some(2));
+
+        assert!(table.confirm_delete());
+        assert_eq!(table.page_view().total_rows, 11);
+
+        // The server rejects the delete: the row returns to its old slot.
+        assert!(table.resolve_mutation(false));
+        let view = table.page_view();
+        assert_eq!(view.total_rows, 12);
+        assert_eq!(view.rows[1].id, 2);
+    }
+
+    #[test]
+    fn cancelling_the_dialog_keeps_the_row() {
+        let mut table = CrudTable::new(5);
+        table.request_delete(2);
+        table.cancel_delete();
+        assert!(table.delete_target().is_none());
+        assert_eq!(table.page_view().total_rows, 12);
+        assert!(!table.confirm_delete());
+    }
+
+    #[test]
+    fn mutations_are_serialized_until_resolved() {
+        let mut table = CrudTable::new(5);
+        table.begin_edit(1);
+        table.edit_name("Renamed");
+        assert!(table.commit_edit());
+        // A second mutation cannot start while one is in flight.
+        assert!(!table.begin_edit(2));
+        assert!(!table.request_delete(2));
+
+        table.resolve_mutation(true);
+        assert!(table.begin_edit(2));
+    }
+
+    #[test]
+    fn deleting_the_last_row_of_the_last_page_clamps_the_page() {
+        let mut table = CrudTable::new(5);
+        table.set_page(2);
+        table.request_delete(11);
+        table.confirm_delete();
+        table.resolve_mutation(true);
+        table.request_delete(12);
+        table.confirm_delete();
+        table.resolve_mutation(true);
+        assert_eq!(table.page_view().page, 1);
+    }
+}
@@ -0,0 +1,400 @@
+//! Shared responsive dashboard shell powering the enterprise layout demos.
+//!
+//! The crate composes pieces that already exist elsewhere in the workspace so
+//! every framework adapter renders the same shell from one machine:
+//!
+//! * the route table, breadcrumbs and active-nav logic come from
+//!   `app-shell-core`,
+//! * the navigation drawer is the headless [`DrawerState`] — a mini variant
+//!   persistent sheet on desktop and a modal temporary sheet on mobile,
+//! * breakpoints resolve through the system [`Responsive`] helper against the
+//!   shared [`Theme`], and card gaps derive from the theme spacing unit so
+//!   density changes restyle the whole grid.
+//!
+//! Adapters feed [`DashboardShell::set_viewport`] from their resize observer
+//! (or the SSR user agent hint) and re-render from the computed
+//! [`DrawerPresentation`] and [`CardLayout`] snapshots.
+
+use app_shell_core::{enterprise_routes, AppShellMachine, Breadcrumb, NavItem};
+use rustic_ui_headless::drawer::{DrawerAnchor, DrawerState, DrawerVariant};
+use rustic_ui_headless::ControlStrategy;
+use rustic_ui_system::responsive::{grid_span_to_percent, Responsive};
+use rustic_ui_system::theme::Theme;
+
+/// Stable automation prefix applied to shell selectors.
+pub const AUTOMATION_ID: &str = "rusticui-dashboard";
+
+/// Number of columns in the card grid, matching the Material default.
+pub const GRID_COLUMNS: u16 = 12;
+
+/// Coarse viewport classification derived from the theme breakpoints.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ViewportClass {
+    /// Below the `md` breakpoint: temporary drawer, single column cards.
+    Mobile,
+    /// At or above the `md` breakpoint: mini variant drawer, multi column grid.
+    Desktop,
+}
+
+/// How the drawer should be presented for the current viewport.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DrawerPresentation {
+    /// Modal sheet overlaying the content; only rendered while open.
+    Temporary,
+    /// Persistent rail collapsed to icons (the mini variant).
+    Mini,
+    /// Persistent rail expanded to icons plus labels.
+    Expanded,
+}
+
+/// Spacing density applied to the card grid and list surfaces.
+///
+/// The multipliers feed [`Theme::spacing`] so changing the base spacing unit
+/// (or the density) restyles every gap in one place.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Density {
+    /// Default enterprise spacing.
+    #[default]
+    Comfortable,
+    /// Tightened spacing for data heavy screens.
+    Compact,
+}
+
+impl Density {
+    /// Spacing factor multiplied with the theme base unit.
+    #[inline]
+    fn gap_factor(self) -> u16 {
+        match self {
+            Self::Comfortable => 3,
+            Self::Compact => 1,
+        }
+    }
+}
+
+/// Declarative description of a dashboard card.
+#[derive(Clone, Debug, PartialEq)]
+pub struct DashboardCard {
+    /// Stable identifier used for automation hooks.
+    pub id: &'static str,
+    /// Title rendered in the card header.
+    pub title: &'static str,
+    /// Headline metric rendered in the card body.
+    pub metric: &'static str,
+    /// Responsive grid span (out of [`GRID_COLUMNS`]).
+    pub span: Responsive<u16>,
+}
+
+/// Resolved layout for one card at the current viewport.
+#[derive(Clone, Debug, PartialEq)]
+pub struct CardLayout {
+    /// Identifier of the originating [`DashboardCard`].
+    pub id: &'static str,
+    /// Title rendered in the card header.
+    pub title: &'static str,
+    /// Headline metric rendered in the card body.
+    pub metric: &'static str,
+    /// Percentage width the card occupies in the grid row.
+    pub width_percent: f32,
+    /// Automation id applied to the card surface.
+    pub automation_id: String,
+}
+
+/// Deterministic shell machine shared by the framework adapters.
+#[derive(Clone, Debug)]
+pub struct DashboardShell {
+    theme: Theme,
+    viewport_width: u32,
+    nav: AppShellMachine,
+    drawer: DrawerState,
+    /// Whether the desktop mini drawer is expanded to full width.
+    mini_expanded: bool,
+    density: Density,
+    cards: Vec<DashboardCard>,
+}
+
+impl DashboardShell {
+    /// Build a shell over the shared route table and default card set.
+    pub fn new(theme: Theme, viewport_width: u32) -> Self {
+        let nav = AppShellMachine::new(enterprise_routes(), "/")
+            .expect("the enterprise route table matches the root path");
+        let mut shell = Self {
+            theme,
+            viewport_width,
+            nav,
+            drawer: DrawerState::new(
+                true,
+                ControlStrategy::Uncontrolled,
+                DrawerVariant::Persistent,
+                DrawerAnchor::Start,
+            ),
+            mini_expanded: false,
+            density: Density::default(),
+            cards: default_cards(),
+        };
+        shell.set_viewport(viewport_width);
+        shell
+    }
+
+    /// Theme shared with the rendering layer.
+    #[inline]
+    pub fn theme(&self) -> &Theme {
+        &self.theme
+    }
+
+    /// Classify the current viewport against the theme breakpoints.
+    pub fn viewport_class(&self) -> ViewportClass {
+        if self.viewport_width >= self.theme.breakpoints.md {
+            ViewportClass::Desktop
+        } else {
+            ViewportClass::Mobile
+        }
+    }
+
+    /// Update the viewport width, re-seating the drawer when the breakpoint
+    /// class changes: desktop gets a persistent rail that stays open, mobile
+    /// falls back to a closed temporary sheet.
+    pub fn set_viewport(&mut self, width: u32) {
+        self.viewport_width = width;
+        match self.viewport_class() {
+            ViewportClass::Desktop => {
+                self.drawer = DrawerState::new(
+                    true,
+                    ControlStrategy::Uncontrolled,
+                    DrawerVariant::Persistent,
+                    DrawerAnchor::Start,
+                );
+            }
+            ViewportClass::Mobile => {
+                self.drawer = DrawerState::new(
+                    false,
+                    ControlStrategy::Uncontrolled,
+                    DrawerVariant::Modal,
+                    DrawerAnchor::Start,
+                );
+            }
+        }
+    }
+
+    /// The app bar menu button: expands the mini rail on desktop, opens the
+    /// temporary sheet on mobile.
+    pub fn toggle_drawer(&mut self) {
+        match self.viewport_class() {
+            ViewportClass::Desktop => self.mini_expanded = !self.mini_expanded,
+            ViewportClass::Mobile => self.drawer.toggle(|_| {}),
+        }
+    }
+
+    /// Presentation the drawer should render with right now.
+    pub fn drawer_presentation(&self) -> DrawerPresentation {
+        match self.viewport_class() {
+            ViewportClass::Mobile => DrawerPresentation::Temporary,
+            ViewportClass::Desktop if self.mini_expanded => DrawerPresentation::Expanded,
+            ViewportClass::Desktop => DrawerPresentation::Mini,
+        }
+    }
+
+    /// Headless drawer state for ARIA attribute generation.
+    #[inline]
+    pub fn drawer(&self) -> &DrawerState {
+        &self.drawer
+    }
+
+    /// Whether the drawer surface is visible (persistent rails always are).
+    pub fn drawer_visible(&self) -> bool {
+        match self.viewport_class() {
+            ViewportClass::Desktop => true,
+            ViewportClass::Mobile => self.drawer.is_open(),
+        }
+    }
+
+    /// Navigate the shell, closing a temporary drawer the way mobile apps do.
+    pub fn navigate(&mut self, path: &str) -> bool {
+        let moved = self.nav.navigate(path);
+        if moved {
+            self.nav.finish_exit();
+            self.nav.finish_enter();
+            if self.viewport_class() == ViewportClass::Mobile {
+                self.drawer.close(|_| {});
+            }
+        }
+        moved
+    }
+
+    /// Primary navigation entries with active flags resolved.
+    pub fn nav_items(&self) -> Vec<NavItem> {
+        self.nav.nav_items()
+    }
+
+    /// Breadcrumb trail rendered under the app bar.
+    pub fn breadcrumbs(&self) -> Vec<Breadcrumb> {
+        self.nav.breadcrumbs()
+    }
+
+    /// Current spacing density.
+    #[inline]
+    pub fn density(&self) -> Density {
+        self.density
+    }
+
+    /// Switch the grid density; the next [`grid_gap_px`](Self::grid_gap_px)
+    /// call reflects the new value immediately.
+    pub fn set_density(&mut self, density: Density) {
+        self.density = density;
+    }
+
+    /// Gap between grid cards in pixels, derived from the theme spacing unit.
+    pub fn grid_gap_px(&self) -> u16 {
+        self.theme.spacing(self.density.gap_factor())
+    }
+
+    /// Resolve every card against the current viewport width.
+    pub fn card_layout(&self) -> Vec<CardLayout> {
+        self.cards
+            .iter()
+            .map(|card| {
+                let span = card
+                    .span
+                    .resolve(self.viewport_width, &self.theme.breakpoints);
+                CardLayout {
+                    id: card.id,
+                    title: card.title,
+                    metric: card.metric,
+                    width_percent: grid_span_to_percent(span, GRID_COLUMNS),
+                    automation_id: format!("{AUTOMATION_ID}-card-{}", card.id),
+                }
+            })
+            .collect()
+    }
+}
+
+/// Metric cards shared by every dashboard demo.
+///
+/// Spans collapse to full width on mobile and split the grid on desktop so
+/// the responsive resolution is actually observable in tests and demos.
+pub fn default_cards() -> Vec<DashboardCard> {
+    let half = Responsive {
+        xs: GRID_COLUMNS,
+        sm: None,
+        md: Some(6),
+        lg: None,
+        xl: None,
+    };
+    let third = Responsive {
+        xs: GRID_COLUMNS,
+        sm: None,
+        md: Some(4),
+        lg: None,
+        xl: None,
+    };
+    vec![
+        DashboardCard {
+            id: "active-projects",
+            title: "Active projects",
+            metric: "38",
+            span: half.clone(),
+        },
+        DashboardCard {
+            id: "open-incidents",
+            title: "Open incidents",
+            metric: "4",
+            span: half,
+        },
+        DashboardCard {
+            id: "deploy-frequency",
+            title: "Deploys this week",
+            metric: "57",
+            span: third.clone(),
+        },
+        DashboardCard {
+            id: "error-budget",
+            title: "Error budget left",
+            metric: "82%",
+            span: third.clone(),
+        },
+        DashboardCard {
+            id: "slo-attainment",
+            title: "SLO attainment",
+            metric: "99.7%",
+            span: third,
+        },
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn desktop_shell() -> DashboardShell {
+        DashboardShell::new(Theme::default(), 1280)
+    }
+
+    #[test]
+    fn viewport_class_follows_theme_breakpoints() {
+        let shell = desktop_shell();
+        assert_eq!(shell.viewport_class(), ViewportClass::Desktop);
+        let mut shell = shell;
+        shell.set_viewport(599);
+        assert_eq!(shell.viewport_class(), ViewportClass::Mobile);
+    }
+
+    #[test]
+    fn desktop_drawer_is_a_persistent_mini_rail() {
+        let mut shell = desktop_shell();
+        assert_eq!(shell.drawer().variant(), DrawerVariant::Persistent);
+        assert_eq!(shell.drawer_presentation(), DrawerPresentation::Mini);
+        shell.toggle_drawer();
+        assert_eq!(shell.drawer_presentation(), DrawerPresentation::Expanded);
+        assert!(shell.drawer_visible());
+    }
+
+    #[test]
+    fn mobile_drawer_is_temporary_and_closes_on_navigation() {
+        let mut shell = DashboardShell::new(Theme::default(), 420);
+        assert_eq!(shell.drawer().variant(), DrawerVariant::Modal);
+        assert!(!shell.drawer_visible());
+
+        shell.toggle_drawer();
+        assert!(shell.drawer_visible());
+
+        assert!(shell.navigate("/reports"));
+        assert!(!shell.drawer_visible());
+        assert_eq!(shell.nav_items()[2].route_id, "reports");
+        assert!(shell.nav_items()[2].active);
+    }
+
+    #[test]
+    fn card_spans_collapse_on_mobile() {
+        let mut shell = desktop_shell();
+        let desktop = shell.card_layout();
+        assert!((desktop[0].width_percent - 50.0).abs() < f32::EPSILON);
+        assert!(
+            (desktop[2].width_percent - grid_span_to_percent(4, GRID_COLUMNS)).abs() < f32::EPSILON
+        );
+
+        shell.set_viewport(420);
+        let mobile = shell.card_layout();
+        assert!(mobile
+            .iter()
+            .all(|card| (card.width_percent - 100.0).abs() < f32::EPSILON));
+    }
+
+    #[test]
+    fn density_drives_grid_gap_from_theme_spacing() {
+        let mut shell = desktop_shell();
+        let comfortable = shell.grid_gap_px();
+        shell.set_density(Density::Compact);
+        assert!(shell.grid_gap_px() < comfortable);
+        assert_eq!(shell.grid_gap_px(), shell.theme().spacing(1));
+    }
+
+    #[test]
+    fn breadcrumbs_follow_navigation() {
+        let mut shell = desktop_shell();
+        shell.navigate("/projects/atlas-7");
+        let crumbs = shell.breadcrumbs();
+        assert_eq!(
+            crumbs.last().map(|crumb| crumb.path.as_str()),
+            Some("/projects/atlas-7")
+        );
+    }
+}
@@ -0,0 +1,223 @@
+//! Shared rating-gated feedback flow powering the cross-framework demos.
+//!
+//! "How was your experience?" widgets look trivial until the conditional
+//! logic arrives: a low score must open a "tell us more" text field, the
+//! field is required only in that branch, and submission confirms through a
+//! snackbar rather than a page change.  This crate wires three machines the
+//! workspace already ships into one deterministic blueprint so the Yew,
+//! Leptos, Dioxus and Sycamore adapters only render snapshots:
+//!
+//! * a clamped star rating owned by [`FeedbackFlow`] itself,
+//! * a headless [`TextFieldState`] for the conditional comment, validated
+//!   through the composable [`RuleSet`]s from `shared-dialog-state-core`,
+//! * the headless [`SnackbarState`] queue for the post-submit confirmation.
+//!
+//! The comment requirement flips as the rating moves across
+//! [`COMMENT_THRESHOLD`], demonstrating conditional form orchestration
+//! without any framework specific code.
+
+use rustic_ui_headless::snackbar::{SnackbarConfig, SnackbarState};
+use rustic_ui_headless::text_field::TextFieldState;
+use shared_dialog_state_core::validation::RuleSet;
+
+/// Stable automation prefix applied to feedback flow selectors.
+pub const AUTOMATION_ID: &str = "rusticui-feedback-flow";
+
+/// Highest selectable star.
+pub const MAX_STARS: u8 = 5;
+
+/// Ratings strictly below this many stars require the "tell us more"
+/// comment before the flow submits.
+pub const COMMENT_THRESHOLD: u8 = 3;
+
+/// Final payload handed to the host application on submit.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FeedbackSubmission {
+    /// Selected star rating, `1..=MAX_STARS`.
+    pub rating: u8,
+    /// Comment text; empty when the rating did not require one.
+    pub comment: String,
+}
+
+/// Deterministic feedback machine shared by the framework adapters.
+pub struct FeedbackFlow {
+    rating: Option<u8>,
+    comment: TextFieldState,
+    comment_rules: RuleSet,
+    snackbar: SnackbarState<String>,
+}
+
+impl FeedbackFlow {
+    /// Build the flow with the default validation (required, at least ten
+    /// characters) for low-score comments.
+    pub fn new() -> Self {
+        Self {
+            rating: None,
+            comment: TextFieldState::uncontrolled("", None),
+            comment_rules: RuleSet::new().required().min_length(10),
+            snackbar: SnackbarState::new(SnackbarConfig::enterprise_defaults()),
+        }
+    }
+
+    /// Currently selected rating, if any.
+    pub fn rating(&self) -> Option<u8> {
+        self.rating
+    }
+
+    /// Select a star.  Values clamp into `1..=MAX_STARS`; raising the score
+    /// above the threshold clears any stale comment errors so the hidden
+    /// field never blocks submission.
+    pub fn set_rating(&mut self, stars: u8) {
+        self.rating = Some(stars.clamp(1, MAX_STARS));
+        if !self.comment_required() {
+            self.comment.set_errors(Vec::new());
+        }
+    }
+
+    /// Whether the conditional "tell us more" field is currently shown and
+    /// required.
+    pub fn comment_required(&self) -> bool {
+        self.rating
+            .map(|stars| stars < COMMENT_THRESHOLD)
+            .unwrap_or(false)
+    }
+
+    /// Current raw comment text.
+    pub fn comment_value(&self) -> &str {
+        self.comment.value()
+    }
+
+    /// Validation errors currently attached to the comment field.
+    pub fn comment_errors(&self) -> &[String] {
+        self.comment.errors()
+    }
+
+    /// Update the comment as the user types.  Errors are only recomputed on
+    /// commit, mirroring the blur-driven validation of the wizard demos.
+    pub fn update_comment(&mut self, value: impl Into<String>) {
+        self.comment.change(value, |_| {});
+    }
+
+    /// Commit the comment (blur), running the conditional rules.  When the
+    /// rating does not require a comment the field always validates.
+    pub fn commit_comment(&mut self) -> bool {
+        let errors = if self.comment_required() {
+            self.comment_rules.evaluate(self.comment.value()).errors
+        } else {
+            Vec::new()
+        };
+        self.comment.commit(|_| {});
+        let valid = errors.is_empty();
+        self.comment.set_errors(errors);
+        valid
+    }
+
+    /// Submit the flow.  Requires a rating, re-validates the conditional
+    /// comment, and enqueues the snackbar confirmation on success.  Returns
+    /// `None` while the form is incomplete.
+    pub fn submit(&mut self) -> Option<FeedbackSubmission> {
+        let rating = self.rating?;
+        if !self.commit_comment() {
+            return None;
+        }
+        self.snackbar
+            .enqueue(format!("Thanks for the {rating}-star feedback!"));
+        Some(FeedbackSubmission {
+            rating,
+            comment: self.comment.value().to_string(),
+        })
+    }
+
+    /// Confirmation queue; adapters read the current message and drive
+    /// auto-hide through [`SnackbarState::tick`].
+    pub fn snackbar(&self) -> &SnackbarState<String> {
+        &self.snackbar
+    }
+
+    /// Mutable access for adapters wiring dismiss buttons and timers.
+    pub fn snackbar_mut(&mut self) -> &mut SnackbarState<String> {
+        &mut self.snackbar
+    }
+
+    /// Accessible label for one star control.
+    pub fn star_label(star: u8) -> String {
+        if star == 1 {
+            "1 star".to_string()
+        } else {
+            format!("{star} stars")
+        }
+    }
+
+    /// Automation id for a star control, stable across SSR and hydration.
+    pub fn star_automation_id(star: u8) -> String {
+        format!("{AUTOMATION_ID}-star-{star}")
+    }
+}
+
+impl Default for FeedbackFlow {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ratings_clamp_into_the_star_range() {
+        let mut flow = FeedbackFlow::new();
+        flow.set_rating(0);
+        assert_eq!(flow.rating(), Some(1));
+        flow.set_rating(9);
+        assert_eq!(flow.rating(), Some(MAX_STARS));
+    }
+
+    #[test]
+    fn low_ratings_require_the_comment_before_submitting() {
+        let mut flow = FeedbackFlow::new();
+        flow.set_rating(2);
+        assert!(flow.comment_required());
+        assert!(flow.submit().is_none());
+        assert!(!flow.comment_errors().is_empty());
+
+        flow.update_comment("too short");
+        assert!(!flow.commit_comment());
+
+        flow.update_comment("The checkout kept timing out.");
+        let submission = flow.submit().expect("comment satisfies the rules");
+        assert_eq!(submission.rating, 2);
+        assert_eq!(submission.comment, "The checkout kept timing out.");
+    }
+
+    #[test]
+    fn high_ratings_submit_without_a_comment() {
+        let mut flow = FeedbackFlow::new();
+        assert!(flow.submit().is_none(), "a rating is always required");
+        flow.set_rating(5);
+        assert!(!flow.comment_required());
+        let submission = flow.submit().expect("no comment needed");
+        assert_eq!(submission.comment, "");
+    }
+
+    #[test]
+    fn raising_the_rating_clears_stale_comment_errors() {
+        let mut flow = FeedbackFlow::new();
+        flow.set_rating(1);
+        assert!(flow.submit().is_none());
+        assert!(!flow.comment_errors().is_empty());
+
+        flow.set_rating(4);
+        assert!(flow.comment_errors().is_empty());
+        assert!(flow.submit().is_some());
+    }
+
+    #[test]
+    fn submission_enqueues_the_snackbar_confirmation() {
+        let mut flow = FeedbackFlow::new();
+        flow.set_rating(4);
+        flow.submit().expect("clean submission");
+        let message = flow.snackbar().current().expect("confirmation visible");
+        assert!(message.payload.contains("4-star"));
+    }
+}
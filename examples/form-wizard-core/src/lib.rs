@@ -0,0 +1,412 @@
+//! Shared multi-step signup wizard powering the cross-framework form demos.
+//!
+//! The crate glues three pieces the workspace already ships into one
+//! deterministic machine so the Yew, Leptos, Dioxus and Sycamore adapters only
+//! render snapshots:
+//!
+//! * the headless [`StepperState`] drives which wizard step is active and
+//!   which are completed,
+//! * a headless [`TextFieldState`] per field owns dirty/visited tracking and
+//!   error storage,
+//! * the composable [`RuleSet`]s from `shared-dialog-state-core` supply the
+//!   per-step validation (required fields, minimum lengths, email shape).
+//!
+//! Steps are linear: [`FormWizard::try_advance`] validates every field of the
+//! active step and refuses to move on while errors remain, mirroring how the
+//! demos gate their "Continue" buttons.  The final step is a review screen
+//! backed by [`FormWizard::review_summary`] and [`FormWizard::submit`].
+
+use rustic_ui_headless::stepper::{StepStatus, StepperConfig, StepperState};
+use rustic_ui_headless::text_field::TextFieldState;
+use shared_dialog_state_core::validation::RuleSet;
+
+/// Stable automation prefix applied to wizard selectors.
+pub const AUTOMATION_ID: &str = "rusticui-form-wizard";
+
+/// Declarative description of one input within a step.
+pub struct FieldDescriptor {
+    /// Stable identifier used for lookups and automation hooks.
+    pub id: &'static str,
+    /// Label rendered next to the input.
+    pub label: &'static str,
+    /// Validation applied when the field is committed or the step advances.
+    pub rules: RuleSet,
+    /// Whether renderers should mask the value (passwords).
+    pub masked: bool,
+}
+
+/// Declarative description of one wizard step.
+pub struct StepDescriptor {
+    /// Stable identifier used for automation hooks.
+    pub id: &'static str,
+    /// Title rendered in the stepper rail.
+    pub title: &'static str,
+    /// Fields collected on this step.  The review step carries none.
+    pub fields: Vec<FieldDescriptor>,
+}
+
+/// Entry of the review screen summarising a collected value.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ReviewEntry {
+    /// Identifier of the originating field.
+    pub field_id: &'static str,
+    /// Label of the originating field.
+    pub label: &'static str,
+    /// Collected value; masked fields are redacted to bullet characters.
+    pub value: String,
+}
+
+/// Final payload handed to the host application on submit.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SignupSubmission {
+    /// Raw field values keyed by field identifier.
+    pub values: Vec<(&'static str, String)>,
+}
+
+/// The default three step signup blueprint used by every adapter.
+///
+/// Account credentials first, profile details second, and a field-free review
+/// step last so the demos exercise both data entry and summary rendering.
+pub fn signup_blueprint() -> Vec<StepDescriptor> {
+    vec![
+        StepDescriptor {
+            id: "account",
+            title: "Account",
+            fields: vec![
+                FieldDescriptor {
+                    id: "email",
+                    label: "Work email",
+                    rules: RuleSet::new()
+                        .required()
+                        .require_match("email", r"^[^@\s]+@[^@\s]+\.[^@\s]+$"),
+                    masked: false,
+                },
+                FieldDescriptor {
+                    id: "password",
+                    label: "Password",
+                    rules: RuleSet::new().required().min_length(8),
+                    masked: true,
+                },
+            ],
+        },
+        StepDescriptor {
+            id: "profile",
+            title: "Profile",
+            fields: vec![
+                FieldDescriptor {
+                    id: "full_name",
+                    label: "Full name",
+                    rules: RuleSet::new().required(),
+                    masked: false,
+                },
+                FieldDescriptor {
+                    id: "company",
+                    label: "Company",
+                    rules: RuleSet::new().required().min_length(3),
+                    masked: false,
+                },
+            ],
+        },
+        StepDescriptor {
+            id: "review",
+            title: "Review",
+            fields: Vec::new(),
+        },
+    ]
+}
+
+/// Deterministic wizard machine shared by the framework adapters.
+pub struct FormWizard {
+    steps: Vec<StepDescriptor>,
+    stepper: StepperState,
+    /// One text field machine per field, flattened across steps and keyed by
+    /// the descriptor id.  Declaration order matches the blueprint so review
+    /// summaries stay stable.
+    fields: Vec<(&'static str, TextFieldState)>,
+}
+
+impl FormWizard {
+    /// Build a wizard over the default [`signup_blueprint`].
+    pub fn new() -> Self {
+        Self::with_steps(signup_blueprint())
+    }
+
+    /// Build a wizard over a custom blueprint.
+    pub fn with_steps(steps: Vec<StepDescriptor>) -> Self {
+        let fields = steps
+            .iter()
+            .flat_map(|step| step.fields.iter())
+            .map(|field| (field.id, TextFieldState::uncontrolled("", None)))
+            .collect();
+        let stepper = StepperState::new(StepperConfig::enterprise_defaults(steps.len()));
+        Self {
+            steps,
+            stepper,
+            fields,
+        }
+    }
+
+    /// Index of the active step.
+    pub fn active_step(&self) -> usize {
+        self.stepper.active().unwrap_or(0)
+    }
+
+    /// Descriptor of the active step.
+    pub fn active_step_descriptor(&self) -> &StepDescriptor {
+        &self.steps[self.active_step()]
+    }
+
+    /// Status of the given step as reported by the headless stepper, letting
+    /// adapters reuse the standard completed/active/pending styling.
+    pub fn step_status(&self, index: usize) -> StepStatus {
+        self.stepper.step_status(index)
+    }
+
+    /// Total number of steps.
+    pub fn step_count(&self) -> usize {
+        self.steps.len()
+    }
+
+    /// Whether the active step is the trailing review screen.
+    pub fn on_review_step(&self) -> bool {
+        self.active_step() + 1 == self.steps.len()
+    }
+
+    /// Current raw value of a field.
+    pub fn field_value(&self, field_id: &str) -> &str {
+        self.field(field_id).value()
+    }
+
+    /// Validation errors currently attached to a field.
+    pub fn field_errors(&self, field_id: &str) -> &[String] {
+        self.field(field_id).errors()
+    }
+
+    /// Update a field as the user types.  Errors are only recomputed on
+    /// commit so keystrokes stay cheap and the demos can show blur-driven
+    /// validation instead of flashing errors mid-word.
+    pub fn update_field(&mut self, field_id: &str, value: impl Into<String>) {
+        self.field_mut(field_id).change(value, |_| {});
+    }
+
+    /// Commit a field (blur), running its rules and storing the outcome on
+    /// the underlying text field machine.
+    pub fn commit_field(&mut self, field_id: &str) -> bool {
+        let errors = self.evaluate(field_id);
+        let state = self.field_mut(field_id);
+        state.commit(|_| {});
+        let valid = errors.is_empty();
+        state.set_errors(errors);
+        valid
+    }
+
+    /// Validate every field on the active step, storing errors as commits do.
+    ///
+    /// Returns `true` when the step is clean.  Used by `try_advance` and by
+    /// adapters that want to enable/disable their continue button eagerly.
+    pub fn validate_active_step(&mut self) -> bool {
+        let ids: Vec<&'static str> = self
+            .active_step_descriptor()
+            .fields
+            .iter()
+            .map(|field| field.id)
+            .collect();
+        let mut valid = true;
+        for id in ids {
+            valid &= self.commit_field(id);
+        }
+        valid
+    }
+
+    /// Validate the active step and advance when it is clean.
+    ///
+    /// Returns `true` when the wizard moved to the next step.  The headless
+    /// stepper records the step as completed so the rail renders the check
+    /// mark, matching Joy's linear semantics.
+    pub fn try_advance(&mut self) -> bool {
+        if self.on_review_step() || !self.validate_active_step() {
+            return false;
+        }
+        self.stepper.complete_active();
+        true
+    }
+
+    /// Move back one step.  Values and completion flags are retained so users
+    /// can revisit earlier answers without re-entering everything.
+    pub fn previous(&mut self) {
+        self.stepper.previous();
+    }
+
+    /// Entries rendered on the review screen, in blueprint order.  Masked
+    /// fields (passwords) are redacted so the summary can be rendered and
+    /// snapshotted safely.
+    pub fn review_summary(&self) -> Vec<ReviewEntry> {
+        self.steps
+            .iter()
+            .flat_map(|step| step.fields.iter())
+            .map(|field| ReviewEntry {
+                field_id: field.id,
+                label: field.label,
+                value: if field.masked {
+                    "•".repeat(self.field_value(field.id).chars().count())
+                } else {
+                    self.field_value(field.id).to_string()
+                },
+            })
+            .collect()
+    }
+
+    /// Submit the wizard from the review step.
+    ///
+    /// Re-validates every field (guarding against adapters mutating values
+    /// after the last advance) and returns the collected payload, or `None`
+    /// when submission is not currently allowed.
+    pub fn submit(&mut self) -> Option<SignupSubmission> {
+        if !self.on_review_step() {
+            return None;
+        }
+        let ids: Vec<&'static str> = self.fields.iter().map(|(id, _)| *id).collect();
+        let mut valid = true;
+        for id in ids {
+            valid &= self.commit_field(id);
+        }
+        if !valid {
+            return None;
+        }
+        Some(SignupSubmission {
+            values: self
+                .fields
+                .iter()
+                .map(|(id, state)| (*id, state.value().to_string()))
+                .collect(),
+        })
+    }
+
+    /// Automation id for a field input, stable across SSR and hydration.
+    pub fn field_automation_id(&self, field_id: &str) -> String {
+        format!("{AUTOMATION_ID}-field-{field_id}")
+    }
+
+    fn descriptor(&self, field_id: &str) -> &FieldDescriptor {
+        self.steps
+            .iter()
+            .flat_map(|step| step.fields.iter())
+            .find(|field| field.id == field_id)
+            .unwrap_or_else(|| panic!("unknown wizard field `{field_id}`"))
+    }
+
+    fn field(&self, field_id: &str) -> &TextFieldState {
+        self.fields
+            .iter()
+            .find(|(id, _)| *id == field_id)
+            .map(|(_, state)| state)
+            .unwrap_or_else(|| panic!("unknown wizard field `{field_id}`"))
+    }
+
+    fn field_mut(&mut self, field_id: &str) -> &mut TextFieldState {
+        self.fields
+            .iter_mut()
+            .find(|(id, _)| *id == field_id)
+            .map(|(_, state)| state)
+            .unwrap_or_else(|| panic!("unknown wizard field `{field_id}`"))
+    }
+
+    fn evaluate(&self, field_id: &str) -> Vec<String> {
+        let report = self
+            .descriptor(field_id)
+            .rules
+            .evaluate(self.field_value(field_id));
+        report.errors
+    }
+}
+
+impl Default for FormWizard {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn filled_wizard() -> FormWizard {
+        let mut wizard = FormWizard::new();
+        wizard.update_field("email", "ada@example.com");
+        wizard.update_field("password", "correct-horse");
+        wizard
+    }
+
+    #[test]
+    fn invalid_step_blocks_advance() {
+        let mut wizard = FormWizard::new();
+        wizard.update_field("email", "not-an-email");
+        assert!(!wizard.try_advance());
+        assert_eq!(wizard.active_step(), 0);
+        assert!(!wizard.field_errors("email").is_empty());
+        assert!(!wizard.field_errors("password").is_empty());
+    }
+
+    #[test]
+    fn valid_steps_advance_and_complete() {
+        let mut wizard = filled_wizard();
+        assert!(wizard.try_advance());
+        assert_eq!(wizard.active_step(), 1);
+        assert_eq!(wizard.step_status(0), StepStatus::Completed);
+
+        wizard.update_field("full_name", "Ada Lovelace");
+        wizard.update_field("company", "Analytical Engines Ltd");
+        assert!(wizard.try_advance());
+        assert!(wizard.on_review_step());
+    }
+
+    #[test]
+    fn commit_runs_blur_validation() {
+        let mut wizard = FormWizard::new();
+        wizard.update_field("password", "short");
+        assert!(!wizard.commit_field("password"));
+        wizard.update_field("password", "long-enough-now");
+        assert!(wizard.commit_field("password"));
+        assert!(wizard.field_errors("password").is_empty());
+    }
+
+    #[test]
+    fn review_summary_redacts_masked_fields() {
+        let mut wizard = filled_wizard();
+        wizard.try_advance();
+        wizard.update_field("full_name", "Ada Lovelace");
+        wizard.update_field("company", "Analytical Engines Ltd");
+        wizard.try_advance();
+
+        let summary = wizard.review_summary();
+        assert_eq!(summary.len(), 4);
+        assert_eq!(summary[0].value, "ada@example.com");
+        assert_eq!(summary[1].value, "•".repeat("correct-horse".len()));
+    }
+
+    #[test]
+    fn submit_only_succeeds_from_a_clean_review_step() {
+        let mut wizard = filled_wizard();
+        assert!(wizard.submit().is_none());
+
+        wizard.try_advance();
+        wizard.update_field("full_name", "Ada Lovelace");
+        wizard.update_field("company", "Analytical Engines Ltd");
+        wizard.try_advance();
+
+        let submission = wizard.submit().expect("review step is clean");
+        assert!(submission
+            .values
+            .iter()
+            .any(|(id, value)| *id == "email" && value == "ada@example.com"));
+    }
+
+    #[test]
+    fn previous_preserves_entered_values() {
+        let mut wizard = filled_wizard();
+        wizard.try_advance();
+        wizard.previous();
+        assert_eq!(wizard.active_step(), 0);
+        assert_eq!(wizard.field_value("email"), "ada@example.com");
+    }
+}
@@ -0,0 +1,423 @@
+//! Shared internationalization demo state built on the lab localization
+//! engine.
+//!
+//! The demo renders an invoice table whose dates, amounts and pagination
+//! copy all react to a locale switcher and an RTL toggle.  Everything that
+//! can be computed deterministically lives here so the framework adapters
+//! only render the snapshots:
+//!
+//! * Locale packs register with the lab [`LocalePack`] registry and reformat
+//!   ISO dates into the locale's preferred order.
+//! * Pagination copy flows through the ICU style [`MessageResolver`] so the
+//!   plural categories (`one`/`other`, `=0`) are exercised per locale.
+//! * [`I18nDemo::direction`] resolves the writing direction from the locale
+//!   (with a manual override for testing mirrored layouts) and exposes the
+//!   `dir` attribute plus a `direction:` style fragment, proving the
+//!   direction-aware styling path end to end.
+
+use chrono::NaiveDate;
+use rustic_ui_lab::adapters::chrono::AdapterChrono;
+use rustic_ui_lab::localization::{
+    register_locale, LocalePack, LocalizationProvider, MessageArg, MessageArgs, MessageBundle,
+    MessageResolver,
+};
+
+/// Stable automation prefix applied to demo selectors.
+pub const AUTOMATION_ID: &str = "rusticui-i18n";
+
+/// Writing direction of a locale.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Direction {
+    /// Left-to-right scripts (Latin, Cyrillic, ...).
+    Ltr,
+    /// Right-to-left scripts (Arabic, Hebrew, ...).
+    Rtl,
+}
+
+impl Direction {
+    /// Value for the HTML `dir` attribute.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Ltr => "ltr",
+            Self::Rtl => "rtl",
+        }
+    }
+}
+
+/// Static description of one selectable locale.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct LocaleDescriptor {
+    /// BCP-47 code matching the registered [`LocalePack`].
+    pub code: &'static str,
+    /// Native label rendered in the locale switcher.
+    pub label: &'static str,
+    /// Default writing direction.
+    pub direction: Direction,
+    /// Decimal separator used by [`I18nDemo::format_amount`].
+    pub decimal_separator: char,
+    /// Thousands separator used by [`I18nDemo::format_amount`].
+    pub group_separator: char,
+    /// Currency symbol appended (or prepended) to amounts.
+    pub currency: &'static str,
+}
+
+/// Locales shipped with the demo.
+pub const LOCALES: [LocaleDescriptor; 3] = [
+    LocaleDescriptor {
+        code: "en-US",
+        label: "English (US)",
+        direction: Direction::Ltr,
+        decimal_separator: '.',
+        group_separator: ',',
+        currency: "$",
+    },
+    LocaleDescriptor {
+        code: "de-DE",
+        label: "Deutsch",
+        direction: Direction::Ltr,
+        decimal_separator: ',',
+        group_separator: '.',
+        currency: "€",
+    },
+    LocaleDescriptor {
+        code: "ar-EG",
+        label: "العربية",
+        direction: Direction::Rtl,
+        decimal_separator: '٫',
+        group_separator: '٬',
+        currency: "ج.م",
+    },
+];
+
+/// German locale pack reordering ISO dates into `DD.MM.YYYY`.
+struct DeDe;
+
+impl LocalePack for DeDe {
+    fn code(&self) -> &'static str {
+        "de-DE"
+    }
+
+    fn format_date(&self, iso: &str) -> String {
+        reorder_iso(iso, '.', &[2, 1, 0])
+    }
+}
+
+/// Egyptian Arabic locale pack rendering `DD/MM/YYYY`.
+struct ArEg;
+
+impl LocalePack for ArEg {
+    fn code(&self) -> &'static str {
+        "ar-EG"
+    }
+
+    fn format_date(&self, iso: &str) -> String {
+        reorder_iso(iso, '/', &[2, 1, 0])
+    }
+}
+
+/// Reassemble an ISO `YYYY-MM-DD` string using the given separator and
+/// segment order.  Falls back to the input when it is not ISO shaped.
+fn reorder_iso(iso: &str, separator: char, order: &[usize; 3]) -> String {
+    let parts: Vec<&str> = iso.split('-').collect();
+    if parts.len() != 3 {
+        return iso.to_string();
+    }
+    order
+        .iter()
+        .map(|&index| parts[index])
+        .collect::<Vec<_>>()
+        .join(&separator.to_string())
+}
+
+/// Row model rendered in the localized table.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct InvoiceRow {
+    /// Invoice number.
+    pub id: u32,
+    /// Customer the invoice was issued to.
+    pub customer: &'static str,
+    /// Issue date, localized per row in [`I18nDemo::table_view`].
+    pub issued: NaiveDate,
+    /// Amount in cents, localized via [`I18nDemo::format_amount`].
+    pub amount_cents: i64,
+}
+
+/// One fully localized table row.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LocalizedRow {
+    /// Invoice number.
+    pub id: u32,
+    /// Customer the invoice was issued to.
+    pub customer: &'static str,
+    /// Issue date formatted for the active locale.
+    pub issued: String,
+    /// Amount formatted for the active locale.
+    pub amount: String,
+}
+
+/// Deterministic i18n demo machine shared by the framework adapters.
+pub struct I18nDemo {
+    locale: LocaleDescriptor,
+    resolver: MessageResolver,
+    rtl_override: Option<Direction>,
+    rows: Vec<InvoiceRow>,
+    page: usize,
+    page_size: usize,
+}
+
+impl I18nDemo {
+    /// Build the demo with the seed invoices and the `en-US` locale active.
+    ///
+    /// Registers the bundled locale packs with the lab registry; repeated
+    /// construction simply overwrites the registrations.
+    pub fn new() -> Self {
+        rustic_ui_lab::localization::init_default_locales();
+        register_locale(DeDe);
+        register_locale(ArEg);
+
+        let mut resolver = MessageResolver::new();
+        resolver.set_default_locale("en-US");
+        for (locale, pagination) in [
+            (
+                "en-US",
+                "{count, plural, =0 {No invoices} one {# invoice} other {# invoices}} — page {page} of {pages}",
+            ),
+            (
+                "de-DE",
+                "{count, plural, =0 {Keine Rechnungen} one {# Rechnung} other {# Rechnungen}} — Seite {page} von {pages}",
+            ),
+            (
+                "ar-EG",
+                "{count, plural, =0 {لا فواتير} one {فاتورة واحدة} other {# فواتير}} — صفحة {page} من {pages}",
+            ),
+        ] {
+            let bundle = MessageBundle::from_pairs(
+                locale,
+                [("pagination".to_string(), pagination.to_string())],
+            )
+            .expect("bundled pagination patterns parse");
+            resolver.register(bundle);
+        }
+
+        Self {
+            locale: LOCALES[0],
+            resolver,
+            rtl_override: None,
+            rows: seed_invoices(),
+            page: 0,
+            page_size: 4,
+        }
+    }
+
+    /// Descriptor of the active locale.
+    #[inline]
+    pub fn locale(&self) -> &LocaleDescriptor {
+        &self.locale
+    }
+
+    /// Switch the active locale by BCP-47 code.  Unknown codes are ignored
+    /// so a stale persisted preference cannot break the demo.
+    pub fn set_locale(&mut self, code: &str) -> bool {
+        match LOCALES.iter().find(|descriptor| descriptor.code == code) {
+            Some(descriptor) => {
+                self.locale = *descriptor;
+                self.rtl_override = None;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Force a direction regardless of the locale default (the demo's "RTL
+    /// toggle").  Passing the locale's own direction clears the override.
+    pub fn set_direction_override(&mut self, direction: Direction) {
+        if direction == self.locale.direction {
+            self.rtl_override = None;
+        } else {
+            self.rtl_override = Some(direction);
+        }
+    }
+
+    /// Effective writing direction.
+    pub fn direction(&self) -> Direction {
+        self.rtl_override.unwrap_or(self.locale.direction)
+    }
+
+    /// `dir` attribute for the demo root element.
+    pub fn dir_attribute(&self) -> (&'static str, &'static str) {
+        ("dir", self.direction().as_str())
+    }
+
+    /// Inline style fragment flipping the layout direction.
+    pub fn direction_style(&self) -> String {
+        format!("direction: {};", self.direction().as_str())
+    }
+
+    /// Format an amount in cents using the locale separators.
+    pub fn format_amount(&self, cents: i64) -> String {
+        let negative = cents < 0;
+        let cents = cents.abs();
+        let whole = cents / 100;
+        let fraction = cents % 100;
+
+        let digits = whole.to_string();
+        let mut grouped = String::new();
+        for (index, ch) in digits.chars().enumerate() {
+            if index > 0 && (digits.len() - index).is_multiple_of(3) {
+                grouped.push(self.locale.group_separator);
+            }
+            grouped.push(ch);
+        }
+
+        let sign = if negative { "-" } else { "" };
+        format!(
+            "{sign}{grouped}{}{fraction:02} {}",
+            self.locale.decimal_separator, self.locale.currency
+        )
+    }
+
+    /// Localize one date through the registered lab locale pack.
+    pub fn format_date(&self, date: &NaiveDate) -> String {
+        LocalizationProvider::new(self.locale.code)
+            .expect("demo locales are registered in I18nDemo::new")
+            .format_date(date, &AdapterChrono)
+    }
+
+    /// Rows of the current page with every cell localized.
+    pub fn table_view(&self) -> Vec<LocalizedRow> {
+        let start = self.page * self.page_size;
+        self.rows
+            .iter()
+            .skip(start)
+            .take(self.page_size)
+            .map(|row| LocalizedRow {
+                id: row.id,
+                customer: row.customer,
+                issued: self.format_date(&row.issued),
+                amount: self.format_amount(row.amount_cents),
+            })
+            .collect()
+    }
+
+    /// Pluralized pagination summary for the current page.
+    pub fn pagination_label(&self) -> String {
+        let pages = self.rows.len().div_ceil(self.page_size).max(1);
+        let mut args = MessageArgs::new();
+        args.insert("count", MessageArg::Number(self.rows.len() as i64));
+        args.insert("page", MessageArg::Number(self.page as i64 + 1));
+        args.insert("pages", MessageArg::Number(pages as i64));
+        self.resolver
+            .format(self.locale.code, "pagination", &args)
+            .expect("pagination pattern registered for every demo locale")
+    }
+
+    /// Advance one page if another exists.
+    pub fn next_page(&mut self) {
+        let pages = self.rows.len().div_ceil(self.page_size).max(1);
+        if self.page + 1 < pages {
+            self.page += 1;
+        }
+    }
+
+    /// Return to the previous page if one exists.
+    pub fn previous_page(&mut self) {
+        self.page = self.page.saturating_sub(1);
+    }
+
+    /// Zero based index of the current page.
+    #[inline]
+    pub fn page(&self) -> usize {
+        self.page
+    }
+}
+
+impl Default for I18nDemo {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Seed row before the issue date is parsed; `(id, customer, iso date,
+/// amount in cents)`.
+type SeedInvoice = (u32, &'static str, &'static str, i64);
+
+/// Seed invoices shared by every adapter; enough rows for two pages.
+pub fn seed_invoices() -> Vec<InvoiceRow> {
+    const SEED: [SeedInvoice; 6] = [
+        (4201, "Aurora Logistics", "2026-01-14", 128450),
+        (4202, "Beacon Health", "2026-02-02", 98000),
+        (4203, "Cinder Analytics", "2026-02-19", 1234567),
+        (4204, "Drift Maritime", "2026-03-08", 45509),
+        (4205, "Ember Grid", "2026-04-01", 777700),
+        (4206, "Flux Retail", "2026-04-23", 6420),
+    ];
+    SEED.iter()
+        .map(|&(id, customer, issued, amount_cents)| InvoiceRow {
+            id,
+            customer,
+            issued: issued.parse().expect("seed dates are valid ISO"),
+            amount_cents,
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn locale_switch_relocalizes_dates_and_amounts() {
+        let mut demo = I18nDemo::new();
+        let en = demo.table_view();
+        assert_eq!(en[0].issued, "2026-01-14");
+        assert_eq!(en[0].amount, "1,284.50 $");
+
+        assert!(demo.set_locale("de-DE"));
+        let de = demo.table_view();
+        assert_eq!(de[0].issued, "14.01.2026");
+        assert_eq!(de[0].amount, "1.284,50 €");
+    }
+
+    #[test]
+    fn pagination_copy_pluralizes_per_locale() {
+        let mut demo = I18nDemo::new();
+        assert_eq!(demo.pagination_label(), "6 invoices — page 1 of 2");
+
+        demo.set_locale("de-DE");
+        demo.next_page();
+        assert_eq!(demo.pagination_label(), "6 Rechnungen — Seite 2 von 2");
+    }
+
+    #[test]
+    fn rtl_locale_flips_the_direction_attribute() {
+        let mut demo = I18nDemo::new();
+        assert_eq!(demo.dir_attribute(), ("dir", "ltr"));
+
+        demo.set_locale("ar-EG");
+        assert_eq!(demo.dir_attribute(), ("dir", "rtl"));
+        assert_eq!(demo.direction_style(), "direction: rtl;");
+    }
+
+    #[test]
+    fn direction_override_mirrors_and_clears() {
+        let mut demo = I18nDemo::new();
+        demo.set_direction_override(Direction::Rtl);
+        assert_eq!(demo.direction(), Direction::Rtl);
+
+        // Overriding back to the locale default clears the override so a
+        // later locale switch picks up that locale's direction again.
+        demo.set_direction_override(Direction::Ltr);
+        demo.set_locale("ar-EG");
+        assert_eq!(demo.direction(), Direction::Rtl);
+    }
+
+    #[test]
+    fn pagination_clamps_at_the_last_page() {
+        let mut demo = I18nDemo::new();
+        demo.next_page();
+        demo.next_page();
+        assert_eq!(demo.page(), 1);
+        demo.previous_page();
+        assert_eq!(demo.page(), 0);
+    }
+}
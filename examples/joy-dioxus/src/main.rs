@@ -1,19 +1,9 @@
 use dioxus::events::FormEvent;
 use dioxus::prelude::*;
-use joy_workflows_core::{JoyWorkflowEvent, JoyWorkflowMachine, SnackbarSeverity};
-use rustic_ui_headless::stepper::StepStatus;
+use joy_workflows_core::{JoyWorkflowEvent, JoyWorkflowMachine};
 use rustic_ui_joy::helpers::resolve_surface_tokens;
-use rustic_ui_joy::{Color, Variant};
 use rustic_ui_system::theme::Theme;
 
-fn snackbar_tokens(severity: &SnackbarSeverity) -> (Color, Variant) {
-    match severity {
-        SnackbarSeverity::Info => (Color::Neutral, Variant::Soft),
-        SnackbarSeverity::Success => (Color::Primary, Variant::Solid),
-        SnackbarSeverity::Warning => (Color::Danger, Variant::Soft),
-    }
-}
-
 fn App(cx: Scope) -> Element {
     let machine = use_ref(cx, JoyWorkflowMachine::new);
     let snapshot = use_state(cx, || machine.read().snapshot());
@@ -121,39 +111,24 @@ fn App(cx: Scope) -> Element {
 
     let card_shell = "max-width:960px;margin:48px auto;box-shadow:0 30px 70px rgba(15,23,42,0.35);border-radius:12px;padding:24px;display:flex;flex-direction:column;gap:16px;background:#0b1120;color:#e2e8f0;";
 
-    let snackbar_view = snapshot
-        .get()
-        .snackbar
-        .as_ref()
-        .map(|payload| {
-            let (color, variant) = snackbar_tokens(&payload.severity);
-            let style = resolve_surface_tokens(&theme, color, variant).compose([
-                ("padding", "12px 16px".to_string()),
-                ("border-radius", "8px".to_string()),
-                ("display", "flex".to_string()),
-                ("align-items", "center".to_string()),
-                ("justify-content", "space-between".to_string()),
-                ("gap", "16px".to_string()),
-            ]);
-            rsx! {
-                div {
-                    style: "{style}",
-                    "data-analytics-id": "{blueprint.automation.snackbar_id}",
-                    role: "status",
-                    "aria-live": "polite",
-                    span {{format!("{} — {}", blueprint.snackbar.success_label, payload.message)}}
-                    button {
-                        style: "background:rgba(15,23,42,0.35);border:none;color:inherit;padding:6px 12px;border-radius:6px;cursor:pointer;",
-                        onclick: move |_| dismiss_snackbar(()),
-                        "Dismiss"
-                    }
-                }
+    let snackbar_view = snapshot.get().snackbar.as_ref().map(|payload| {
+        // The shared renderer owns the surface; clicks on the embedded
+        // dismiss affordance bubble up to this wrapper's handler.
+        let markup = payload.joy_snackbar_html(&theme, blueprint.snackbar.success_label);
+        rsx! {
+            div {
+                "data-analytics-id": "{blueprint.automation.snackbar_id}",
+                onclick: move |_| dismiss_snackbar(()),
+                dangerous_inner_html: "{markup}"
             }
-        });
+        }
+    });
 
     let metrics = blueprint.metrics.clone();
-    let steps = blueprint.steps.clone();
-    let marks = blueprint.capacity.marks.clone();
+    let slider_markup = blueprint
+        .capacity
+        .joy_slider_html(&theme, snapshot.get().capacity_value);
+    let stepper_markup = blueprint.joy_stepper_html(&snapshot.get().step_status);
 
     cx.render(rsx! {
         main {
@@ -192,24 +167,23 @@ fn App(cx: Scope) -> Element {
                 section {
                     style: "display:flex;flex-direction:column;gap:12px;",
                     label { r#for: "joy-capacity", style: "font-weight:600;", "Deployment capacity" }
-                    input {
-                        id: "joy-capacity",
-                        r#type: "range",
-                        min: "{blueprint.capacity.min}",
-                        max: "{blueprint.capacity.max}",
-                        step: "{blueprint.capacity.step}",
-                        value: format_args!("{:.0}", snapshot.get().capacity_value),
-                        style: format_args!(
-                            "width:100%;appearance:none;background:linear-gradient(90deg,#38bdf8 0%,#3b82f6 {:.2}%,rgba(148,163,184,0.3) {:.2}%);height:8px;border-radius:6px;outline:none;",
-                            snapshot.get().capacity_percent,
-                            snapshot.get().capacity_percent
-                        ),
-                        "data-analytics-id": "{blueprint.automation.capacity_slider_id}",
-                        oninput: on_capacity.clone()
-                    }
+                    // The Joy slider renderer owns the visual surface (rail, track,
+                    // marks, value label); the transparent range input stacked on top
+                    // only exists to capture pointer/keyboard events.
                     div {
-                        style: "display:flex;justify-content:space-between;font-size:0.85rem;color:#94a3b8;",
-                        {marks.iter().map(|mark| rsx! { span {{format!("{}% — {}", mark.value, mark.label)}} })}
+                        style: "position:relative;",
+                        "data-analytics-id": "{blueprint.automation.capacity_slider_id}",
+                        div { dangerous_inner_html: "{slider_markup}" }
+                        input {
+                            id: "joy-capacity",
+                            r#type: "range",
+                            min: "{blueprint.capacity.min}",
+                            max: "{blueprint.capacity.max}",
+                            step: "{blueprint.capacity.step}",
+                            value: format_args!("{:.0}", snapshot.get().capacity_value),
+                            style: "position:absolute;inset:0;width:100%;height:100%;opacity:0;cursor:pointer;",
+                            oninput: on_capacity.clone()
+                        }
                     }
                     p {
                         style: "margin:0;font-weight:500;",
@@ -220,33 +194,7 @@ fn App(cx: Scope) -> Element {
                 section {
                     style: "display:flex;flex-direction:column;gap:12px;",
                     h2 { style: "margin:0;font-size:1.25rem;", "Release checklist" }
-                    ol {
-                        style: "margin:0;padding-left:18px;display:flex;flex-direction:column;gap:8px;",
-                        {steps.iter().enumerate().map(|(index, step)| {
-                            let status = snapshot
-                                .get()
-                                .step_status
-                                .get(index)
-                                .cloned()
-                                .unwrap_or(StepStatus::Pending);
-                            let status_label = match status {
-                                StepStatus::Completed => "Completed",
-                                StepStatus::Active => "In progress",
-                                _ => "Pending",
-                            };
-                            rsx! {
-                                li {
-                                    div { style: "display:flex;flex-direction:column;gap:4px;",
-                                        div { style: "display:flex;align-items:center;gap:8px;",
-                                            span { style: "font-weight:600;", {step.title} }
-                                            span { style: "font-size:0.8rem;color:#94a3b8;", {status_label} }
-                                        }
-                                        p { style: "margin:0;color:#cbd5f5;", {step.detail} }
-                                    }
-                                }
-                            }
-                        })}
-                    }
+                    div { dangerous_inner_html: "{stepper_markup}" }
                 }
 
                 section {
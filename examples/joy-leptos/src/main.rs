@@ -1,23 +1,10 @@
 use std::{cell::RefCell, rc::Rc};
 
-use joy_workflows_core::{
-    JoyWorkflowEvent, JoyWorkflowMachine, JoyWorkflowSnapshot, SnackbarSeverity,
-};
+use joy_workflows_core::{JoyWorkflowEvent, JoyWorkflowMachine, JoyWorkflowSnapshot};
 use leptos::ev::{Event, MouseEvent};
 use leptos::*;
 use leptos::{event_target_value, IntoView};
-use rustic_ui_headless::stepper::StepStatus;
 use rustic_ui_joy::helpers::resolve_surface_tokens;
-use rustic_ui_joy::{Color, Variant};
-
-/// Map the shared snackbar severity into Joy color/variant pairs for styling.
-fn snackbar_tokens(severity: &SnackbarSeverity) -> (Color, Variant) {
-    match severity {
-        SnackbarSeverity::Info => (Color::Neutral, Variant::Soft),
-        SnackbarSeverity::Success => (Color::Primary, Variant::Solid),
-        SnackbarSeverity::Warning => (Color::Danger, Variant::Soft),
-    }
-}
 
 /// Render the shared Joy workflow using Leptos signals.
 #[component]
@@ -151,35 +138,20 @@ pub fn App() -> impl IntoView {
                         let snapshot: JoyWorkflowSnapshot = snapshot.get();
                         match snapshot.snackbar {
                             Some(payload) => {
-                                let (color, variant) = snackbar_tokens(&payload.severity);
-                                let style = snackbar_theme.with_value(|theme| {
-                                    resolve_surface_tokens(
+                                // The shared renderer owns the surface; clicks on the
+                                // embedded dismiss affordance bubble up to the wrapper.
+                                let markup = snackbar_theme.with_value(|theme| {
+                                    payload.joy_snackbar_html(
                                         theme.as_ref(),
-                                        color.clone(),
-                                        variant.clone(),
+                                        blueprint.snackbar.success_label,
                                     )
-                                    .compose([
-                                        ("padding", "12px 16px".to_string()),
-                                        ("border-radius", "8px".to_string()),
-                                        ("display", "flex".to_string()),
-                                        ("align-items", "center".to_string()),
-                                        ("justify-content", "space-between".to_string()),
-                                        ("gap", "16px".to_string()),
-                                    ])
                                 });
                                 view! {
                                     <div
-                                        style=style
                                         data-analytics-id={blueprint.automation.snackbar_id}
-                                        role="status"
-                                        aria-live="polite"
-                                    >
-                                        <span>{format!("{} — {}", blueprint.snackbar.success_label, payload.message)}</span>
-                                        <button
-                                            style="background:rgba(15,23,42,0.35);border:none;color:inherit;padding:6px 12px;border-radius:6px;cursor:pointer;"
-                                            on:click=move |event| dismiss_snackbar.with_value(|handler| handler(event))
-                                        >{"Dismiss"}</button>
-                                    </div>
+                                        on:click=move |event| dismiss_snackbar.with_value(|handler| handler(event))
+                                        inner_html=markup
+                                    ></div>
                                 }
                             }
                             None => view! { <div style="display:none;"></div> },
@@ -205,66 +177,35 @@ pub fn App() -> impl IntoView {
 
                 <section style="display:flex;flex-direction:column;gap:12px;">
                     <label for="joy-capacity" style="font-weight:600;">{"Deployment capacity"}</label>
-                    <input
-                        id="joy-capacity"
-                        type="range"
-                        min={blueprint.capacity.min}
-                        max={blueprint.capacity.max}
-                        step={blueprint.capacity.step}
-                        prop:value=move || format!("{:.0}", snapshot.get().capacity_value)
-                        style=move || format!(
-                            "width:100%;appearance:none;background:linear-gradient(90deg,#38bdf8 0%,#3b82f6 {:.2}%,rgba(148,163,184,0.3) {:.2}%);height:8px;border-radius:6px;outline:none;",
-                            snapshot.get().capacity_percent,
-                            snapshot.get().capacity_percent
-                        )
-                        data-analytics-id={blueprint.automation.capacity_slider_id}
-                        on:input=on_capacity_change.clone()
-                    />
-                    <div style="display:flex;justify-content:space-between;font-size:0.85rem;color:#94a3b8;">
-                        {blueprint
-                            .capacity
-                            .marks
-                            .iter()
-                            .map(|mark| view! { <span>{format!("{}% — {}", mark.value, mark.label)}</span> })
-                            .collect::<Vec<_>>()}
+                    // The Joy slider renderer owns the visual surface (rail, track,
+                    // marks, value label); the transparent range input stacked on top
+                    // only exists to capture pointer/keyboard events.
+                    <div style="position:relative;" data-analytics-id={blueprint.automation.capacity_slider_id}>
+                        <div inner_html={
+                            let capacity = blueprint.capacity.clone();
+                            let theme = theme.clone();
+                            move || capacity.joy_slider_html(&theme, snapshot.get().capacity_value)
+                        }></div>
+                        <input
+                            id="joy-capacity"
+                            type="range"
+                            min={blueprint.capacity.min}
+                            max={blueprint.capacity.max}
+                            step={blueprint.capacity.step}
+                            prop:value=move || format!("{:.0}", snapshot.get().capacity_value)
+                            style="position:absolute;inset:0;width:100%;height:100%;opacity:0;cursor:pointer;"
+                            on:input=on_capacity_change.clone()
+                        />
                     </div>
                     <p style="margin:0;font-weight:500;">{move || format!("Current allocation: {:.0}% ({})", snapshot.get().capacity_value, capacity_profile.get())}</p>
                 </section>
 
                 <section style="display:flex;flex-direction:column;gap:12px;">
                     <h2 style="margin:0;font-size:1.25rem;">{"Release checklist"}</h2>
-                    <ol style="margin:0;padding-left:18px;display:flex;flex-direction:column;gap:8px;">
-                        {blueprint
-                            .steps
-                            .iter()
-                            .enumerate()
-                            .map(|(index, step)| {
-                                view! {
-                                    <li>
-                                        <div style="display:flex;flex-direction:column;gap:4px;">
-                                            <div style="display:flex;align-items:center;gap:8px;">
-                                                <span style="font-weight:600;">{step.title}</span>
-                                            <span style="font-size:0.8rem;color:#94a3b8;">{move || {
-                                                snapshot
-                                                    .get()
-                                                    .step_status
-                                                    .get(index)
-                                                    .map(|status| match status {
-                                                        StepStatus::Completed => "Completed",
-                                                        StepStatus::Active => "In progress",
-                                                        _ => "Pending",
-                                                    })
-                                                    .unwrap_or("Pending")
-                                                    .to_string()
-                                            }}</span>
-                                            </div>
-                                            <p style="margin:0;color:#cbd5f5;">{step.detail}</p>
-                                        </div>
-                                    </li>
-                                }
-                            })
-                            .collect::<Vec<_>>()}
-                    </ol>
+                    <div inner_html={
+                        let blueprint = blueprint.clone();
+                        move || blueprint.joy_stepper_html(&snapshot.get().step_status)
+                    }></div>
                 </section>
 
                 <section style="display:flex;flex-wrap:wrap;gap:12px;align-items:flex-start;">
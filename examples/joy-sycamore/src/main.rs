@@ -1,23 +1,12 @@
 use std::{cell::RefCell, rc::Rc};
 
-use joy_workflows_core::{JoyWorkflowEvent, JoyWorkflowMachine, SnackbarSeverity};
-use rustic_ui_headless::stepper::StepStatus;
+use joy_workflows_core::{JoyWorkflowEvent, JoyWorkflowMachine};
 use rustic_ui_joy::helpers::resolve_surface_tokens;
-use rustic_ui_joy::{Color, Variant};
 use rustic_ui_system::theme::Theme;
 use sycamore::prelude::*;
 use wasm_bindgen::JsCast;
 use web_sys::HtmlInputElement;
 
-/// Translate the workflow snackbar severity into Joy color + variant tokens.
-fn snackbar_tokens(severity: &SnackbarSeverity) -> (Color, Variant) {
-    match severity {
-        SnackbarSeverity::Info => (Color::Neutral, Variant::Soft),
-        SnackbarSeverity::Success => (Color::Primary, Variant::Solid),
-        SnackbarSeverity::Warning => (Color::Danger, Variant::Soft),
-    }
-}
-
 /// Root component rendered by the Sycamore example.  The implementation keeps
 /// the business logic and design tokens centralised so we only focus on binding
 /// signals + events to the Joy workflow machine.  Comments are intentionally
@@ -98,8 +87,12 @@ fn App() -> View {
 
     // List data copied locally to avoid re-borrowing inside reactive closures.
     let metrics = Rc::as_ref(&blueprint).metrics.clone();
-    let steps = Rc::as_ref(&blueprint).steps.clone();
-    let marks = Rc::as_ref(&blueprint).capacity.marks.clone();
+
+    // Per-section handles for the dynamic views below; each generated closure
+    // takes ownership of its own clone instead of fighting over one `Rc`.
+    let slider_theme = Rc::clone(&theme);
+    let slider_capacity = Rc::as_ref(&blueprint).capacity.clone();
+    let stepper_blueprint = Rc::clone(&blueprint);
 
     // Helper that advances the workflow and refreshes all reactive signals.
     let on_advance = {
@@ -184,36 +177,17 @@ fn App() -> View {
                     let dismiss_snackbar = Rc::clone(&dismiss_snackbar);
                     move || {
                         snapshot.with(|snap| snap.snackbar.clone()).map(|payload| {
-                            let (color, variant) = snackbar_tokens(&payload.severity);
-                            let surface = resolve_surface_tokens(theme.as_ref(), color, variant).compose([
-                                ("padding", "12px 16px".to_string()),
-                                ("border-radius", "8px".to_string()),
-                                ("display", "flex".to_string()),
-                                ("align-items", "center".to_string()),
-                                ("justify-content", "space-between".to_string()),
-                            ("gap", "16px".to_string()),
-                            ]);
+                            // The shared renderer owns the surface; clicks on the
+                            // embedded dismiss affordance bubble up to the wrapper.
+                            let markup = payload
+                                .joy_snackbar_html(theme.as_ref(), snackbar_descriptor.success_label);
                             let dismiss = Rc::clone(&dismiss_snackbar);
-                            let success_label = snackbar_descriptor.success_label;
                             view! {
                                 div(
-                                    style=surface,
                                     data-analytics-id=snackbar_id,
-                                    role="status",
-                                    aria-live="polite"
-                                ) {
-                                    span {
-                                        (format!(
-                                            "{} — {}",
-                                            success_label,
-                                            payload.message
-                                        ))
-                                    }
-                                    button(
-                                        style="background:rgba(15,23,42,0.35);border:none;color:inherit;padding:6px 12px;border-radius:6px;cursor:pointer;",
-                                        on:click=move |_| dismiss()
-                                    ) { "Dismiss" }
-                                }
+                                    dangerously_set_inner_html=markup,
+                                    on:click=move |_| dismiss()
+                                )
                             }
                         })
                     }
@@ -233,24 +207,38 @@ fn App() -> View {
 
                 section(style="display:flex;flex-direction:column;gap:12px;") {
                     label(r#for="joy-capacity", style="font-weight:600;") { "Deployment capacity" }
-                    input(
-                        id="joy-capacity",
-                        r#type="range",
-                        min=slider_min.clone(),
-                        max=slider_max.clone(),
-                        step=slider_step.clone(),
-                        value=move || snapshot.with(|snap| format!("{:.0}", snap.capacity_value)),
-                        style=move || snapshot.with(|snap| format!("width:100%;appearance:none;background:linear-gradient(90deg,#38bdf80%,#3b82f6 {:.2}%,rgba(148,163,184,0.3) {:.2}%);height:8px;border-radius:6px;outline:none;", snap.capacity_percent, snap.capacity_percent)),
-                        data-analytics-id=capacity_slider_id,
-                        on:input={
-                            let handler = Rc::clone(&on_capacity);
-                            move |event: web_sys::Event| handler(event)
-                        }
-                    )
-                    div(style="display:flex;justify-content:space-between;font-size:0.85rem;color:#94a3b8;") {
-                        (View::from(marks.iter().cloned().map(|mark| {
-                            view! { span { (format!("{}% — {}", mark.value, mark.label)) } }
-                        }).collect::<Vec<_>>()))
+                    // The Joy slider renderer owns the visual surface (rail, track,
+                    // marks, value label); the transparent range input stacked on top
+                    // only exists to capture pointer/keyboard events.
+                    div(style="position:relative;", data-analytics-id=capacity_slider_id) {
+                        // `dangerously_set_inner_html` takes a string, not a
+                        // closure, so the markup is recomputed inside a dynamic
+                        // view (mirroring the snackbar above) to stay reactive.
+                        (View::from({
+                            let snapshot = snapshot.clone();
+                            let slider_theme = Rc::clone(&slider_theme);
+                            let slider_capacity = slider_capacity.clone();
+                            move || {
+                                let markup = snapshot.with(|snap| {
+                                    slider_capacity
+                                        .joy_slider_html(slider_theme.as_ref(), snap.capacity_value)
+                                });
+                                view! { div(dangerously_set_inner_html=markup) }
+                            }
+                        }))
+                        input(
+                            id="joy-capacity",
+                            r#type="range",
+                            min=slider_min.clone(),
+                            max=slider_max.clone(),
+                            step=slider_step.clone(),
+                            value=move || snapshot.with(|snap| format!("{:.0}", snap.capacity_value)),
+                            style="position:absolute;inset:0;width:100%;height:100%;opacity:0;cursor:pointer;",
+                            on:input={
+                                let handler = Rc::clone(&on_capacity);
+                                move |event: web_sys::Event| handler(event)
+                            }
+                        )
                     }
                     p(style="margin:0;font-weight:500;") {
                         (move || {
@@ -268,43 +256,18 @@ fn App() -> View {
 
                 section(style="display:flex;flex-direction:column;gap:12px;") {
                     h2(style="margin:0;font-size:1.25rem;") { "Release checklist" }
-                    ol(style="margin:0;padding-left:18px;display:flex;flex-direction:column;gap:8px;") {
-                        (View::from({
-                            let snapshot = snapshot.clone();
-                            let steps = steps.clone();
-                            move || {
-                                let snapshot = snapshot.get_clone();
-                                steps
-                                    .iter()
-                                    .enumerate()
-                                    .map(|(index, step)| {
-                                        let status_label = snapshot
-                                            .step_status
-                                            .get(index)
-                                            .cloned()
-                                            .map(|status| match status {
-                                                StepStatus::Completed => "Completed",
-                                                StepStatus::Active => "In progress",
-                                                StepStatus::Pending => "Pending",
-                                                StepStatus::Disabled => "Pending",
-                                            })
-                                            .unwrap_or("Pending");
-                                        view! {
-                                            li {
-                                                div(style="display:flex;flex-direction:column;gap:4px;") {
-                                                    div(style="display:flex;align-items:center;gap:8px;") {
-                                                        span(style="font-weight:600;") { (step.title) }
-                                                        span(style="font-size:0.8rem;color:#94a3b8;") { (status_label) }
-                                                    }
-                                                    p(style="margin:0;color:#cbd5f5;") { (step.detail) }
-                                                }
-                                            }
-                                        }
-                                    })
-                                    .collect::<Vec<_>>()
-                            }
-                        }))
-                    }
+                    // Same pattern as the slider: render the stepper markup to a
+                    // string inside a dynamic view so the attribute receives the
+                    // `Cow<'static, str>` it expects while staying reactive.
+                    (View::from({
+                        let snapshot = snapshot.clone();
+                        let stepper_blueprint = Rc::clone(&stepper_blueprint);
+                        move || {
+                            let markup = snapshot
+                                .with(|snap| stepper_blueprint.joy_stepper_html(&snap.step_status));
+                            view! { div(dangerously_set_inner_html=markup) }
+                        }
+                    }))
                 }
 
                 section(style="display:flex;flex-wrap:wrap;gap:12px;align-items:flex-start;") {
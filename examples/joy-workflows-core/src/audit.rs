@@ -0,0 +1,285 @@
+//! Reusable audit trail behind the workflow lifecycle log.
+//!
+//! Earlier revisions kept the lifecycle log as a bare `Vec<String>`, which
+//! was fine for rendering a sidebar but useless for the QA dashboards and
+//! compliance exports that consume the demos downstream: there was no
+//! severity, no timestamps, and no way to attach structured context such as
+//! gate attempt counters.  [`AuditLog`] upgrades the log into a bounded,
+//! serialisable trail of [`AuditEntry`] records while the clock stays
+//! injectable so tests (and SSR snapshots) remain fully deterministic.
+
+use std::collections::BTreeMap;
+use std::sync::Arc;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use serde::{Deserialize, Serialize};
+
+/// Severity classification attached to every audit entry.
+///
+/// The ladder intentionally mirrors [`crate::SnackbarSeverity`] so dashboards
+/// can colour log rows with the same Joy tokens used for toasts.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum AuditSeverity {
+    /// Routine lifecycle update (initialisation, capacity changes).
+    Info,
+    /// Forward progress such as a completed step or passed gate.
+    Success,
+    /// Recoverable deviation such as a rollback.
+    Warning,
+    /// Failure requiring operator attention (failed gate checks).
+    Danger,
+}
+
+impl AuditSeverity {
+    /// Stable identifier used in CSV exports and `data-*` hooks.
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Info => "info",
+            Self::Success => "success",
+            Self::Warning => "warning",
+            Self::Danger => "danger",
+        }
+    }
+}
+
+/// One record in the audit trail.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AuditEntry {
+    /// Milliseconds since the Unix epoch as reported by the log's clock.
+    pub timestamp_ms: u64,
+    /// Severity classification for filtering and row colouring.
+    pub severity: AuditSeverity,
+    /// Human readable message, identical to the legacy lifecycle log lines.
+    pub message: String,
+    /// Structured context (step labels, attempt counters, …).  A `BTreeMap`
+    /// keeps export order deterministic across runs.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub fields: BTreeMap<String, String>,
+}
+
+/// Clock injected into [`AuditLog`] so entries carry timestamps without the
+/// log reaching for wall-clock time directly.  Tests supply fixed or
+/// monotonically increasing closures; production uses [`system_clock`].
+pub type AuditClock = Arc<dyn Fn() -> u64 + Send + Sync>;
+
+/// Default clock reporting wall-clock milliseconds since the Unix epoch.
+pub fn system_clock() -> AuditClock {
+    Arc::new(|| {
+        SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|elapsed| elapsed.as_millis() as u64)
+            .unwrap_or_default()
+    })
+}
+
+/// Bounded, serialisable audit trail with JSON/CSV export helpers.
+#[derive(Clone)]
+pub struct AuditLog {
+    entries: Vec<AuditEntry>,
+    capacity: usize,
+    clock: AuditClock,
+}
+
+impl std::fmt::Debug for AuditLog {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AuditLog")
+            .field("entries", &self.entries)
+            .field("capacity", &self.capacity)
+            .finish_non_exhaustive()
+    }
+}
+
+impl AuditLog {
+    /// Creates an empty log retaining at most `capacity` entries and stamping
+    /// records with the wall clock.
+    pub fn new(capacity: usize) -> Self {
+        Self::with_clock(capacity, system_clock())
+    }
+
+    /// Creates an empty log with an injected clock, keeping tests and SSR
+    /// snapshots deterministic.
+    pub fn with_clock(capacity: usize, clock: AuditClock) -> Self {
+        Self {
+            entries: Vec::new(),
+            capacity,
+            clock,
+        }
+    }
+
+    /// Rebuilds a log from previously exported entries (e.g. a persisted
+    /// workflow snapshot), trimming to capacity from the oldest side.
+    pub fn from_entries(capacity: usize, entries: Vec<AuditEntry>) -> Self {
+        let mut log = Self::new(capacity);
+        log.entries = entries;
+        log.trim();
+        log
+    }
+
+    /// Appends a plain entry at the given severity.
+    pub fn record(&mut self, severity: AuditSeverity, message: impl Into<String>) {
+        self.record_with_fields(severity, message, std::iter::empty::<(String, String)>());
+    }
+
+    /// Appends an entry carrying structured fields alongside the message.
+    pub fn record_with_fields<I, K, V>(
+        &mut self,
+        severity: AuditSeverity,
+        message: impl Into<String>,
+        fields: I,
+    ) where
+        I: IntoIterator<Item = (K, V)>,
+        K: Into<String>,
+        V: Into<String>,
+    {
+        self.entries.push(AuditEntry {
+            timestamp_ms: (self.clock)(),
+            severity,
+            message: message.into(),
+            fields: fields
+                .into_iter()
+                .map(|(key, value)| (key.into(), value.into()))
+                .collect(),
+        });
+        self.trim();
+    }
+
+    /// Entries in chronological order, oldest first.
+    pub fn entries(&self) -> &[AuditEntry] {
+        &self.entries
+    }
+
+    /// Messages only, matching the legacy `lifecycle_log` line format so
+    /// existing renderers keep working unchanged.
+    pub fn messages(&self) -> Vec<String> {
+        self.entries
+            .iter()
+            .map(|entry| entry.message.clone())
+            .collect()
+    }
+
+    /// Number of retained entries.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Whether the log holds no entries.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Serialises the trail as a JSON array, the format QA dashboards ingest.
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(&self.entries).expect("audit entries serialise to JSON")
+    }
+
+    /// Serialises the trail as CSV with a header row, the format compliance
+    /// exports hand to spreadsheet tooling.  Structured fields collapse into
+    /// a `key=value; key=value` column and cells are quoted per RFC 4180.
+    pub fn to_csv(&self) -> String {
+        let mut csv = String::from("timestamp_ms,severity,message,fields\n");
+        for entry in &self.entries {
+            let fields = entry
+                .fields
+                .iter()
+                .map(|(key, value)| format!("{key}={value}"))
+                .collect::<Vec<_>>()
+                .join("; ");
+            csv.push_str(&format!(
+                "{},{},{},{}\n",
+                entry.timestamp_ms,
+                entry.severity.as_str(),
+                csv_cell(&entry.message),
+                csv_cell(&fields),
+            ));
+        }
+        csv
+    }
+
+    /// Drops the oldest entries once the ring exceeds its capacity.
+    fn trim(&mut self) {
+        if self.entries.len() > self.capacity {
+            let excess = self.entries.len() - self.capacity;
+            self.entries.drain(0..excess);
+        }
+    }
+}
+
+/// Quotes a CSV cell when it contains a delimiter, quote, or newline.
+fn csv_cell(raw: &str) -> String {
+    if raw.contains([',', '"', '\n']) {
+        format!("\"{}\"", raw.replace('"', "\"\""))
+    } else {
+        raw.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicU64, Ordering};
+
+    /// Deterministic clock ticking one millisecond per call.
+    fn ticking_clock() -> AuditClock {
+        let tick = AtomicU64::new(0);
+        Arc::new(move || tick.fetch_add(1, Ordering::SeqCst))
+    }
+
+    #[test]
+    fn injected_clock_stamps_entries_deterministically() {
+        let mut log = AuditLog::with_clock(8, ticking_clock());
+        log.record(AuditSeverity::Info, "boot");
+        log.record(AuditSeverity::Success, "step complete");
+        let stamps: Vec<u64> = log.entries().iter().map(|e| e.timestamp_ms).collect();
+        assert_eq!(stamps, vec![0, 1]);
+    }
+
+    #[test]
+    fn capacity_drops_the_oldest_entries() {
+        let mut log = AuditLog::with_clock(2, ticking_clock());
+        log.record(AuditSeverity::Info, "first");
+        log.record(AuditSeverity::Info, "second");
+        log.record(AuditSeverity::Warning, "third");
+        assert_eq!(log.messages(), vec!["second", "third"]);
+    }
+
+    #[test]
+    fn json_export_round_trips_entries_with_fields() {
+        let mut log = AuditLog::with_clock(8, ticking_clock());
+        log.record_with_fields(
+            AuditSeverity::Danger,
+            "gate failed",
+            [("step", "Sign release"), ("attempt", "2")],
+        );
+        let parsed: Vec<AuditEntry> = serde_json::from_str(&log.to_json()).unwrap();
+        assert_eq!(parsed, log.entries());
+        assert_eq!(parsed[0].fields["attempt"], "2");
+    }
+
+    #[test]
+    fn csv_export_quotes_cells_containing_delimiters() {
+        let mut log = AuditLog::with_clock(8, ticking_clock());
+        log.record_with_fields(
+            AuditSeverity::Warning,
+            "rolled back, remediation \"urgent\"",
+            [("step", "Deploy")],
+        );
+        let csv = log.to_csv();
+        assert!(csv.starts_with("timestamp_ms,severity,message,fields\n"));
+        assert!(csv.contains("\"rolled back, remediation \"\"urgent\"\"\""));
+        assert!(csv.contains("step=Deploy"));
+    }
+
+    #[test]
+    fn from_entries_trims_to_capacity_from_the_oldest_side() {
+        let entries = (0..4)
+            .map(|index| AuditEntry {
+                timestamp_ms: index,
+                severity: AuditSeverity::Info,
+                message: format!("entry {index}"),
+                fields: BTreeMap::new(),
+            })
+            .collect();
+        let log = AuditLog::from_entries(2, entries);
+        assert_eq!(log.messages(), vec!["entry 2", "entry 3"]);
+    }
+}
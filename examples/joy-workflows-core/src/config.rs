@@ -0,0 +1,305 @@
+//! Data-driven blueprint loading.
+//!
+//! Earlier revisions hard-coded the enterprise release workflow inside
+//! [`JoyWorkflowBlueprint::enterprise_release`].  Teams that wanted their own
+//! checklist had to fork the crate, which defeated the "single source of
+//! truth" goal.  This module introduces a declarative configuration format
+//! (JSON or TOML) describing steps, metrics, actions, and automation ids.
+//! The built-in enterprise defaults are now themselves expressed as an
+//! embedded JSON document so the loader path is exercised by every demo.
+//!
+//! Strings from validated configurations are interned via `Box::leak`.
+//! Blueprints are loaded once during application start-up, so the one-time
+//! leak keeps the descriptor structs zero-copy (`&'static str`) for renderers
+//! without threading lifetimes through every adapter.
+
+use rustic_ui_joy::{Color, Variant};
+use rustic_ui_system::theme::Theme;
+use serde::Deserialize;
+
+use crate::{
+    ActionDescriptor, CardMetric, ChipDescriptor, JoyWorkflowBlueprint, SliderDescriptor,
+    SliderMark, SnackbarDescriptor, StepDescriptor, WorkflowAutomationIds,
+};
+
+/// Errors reported while loading or validating a blueprint configuration.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BlueprintConfigError {
+    /// The document could not be deserialised at all.
+    Parse(String),
+    /// The document deserialised but failed a semantic validation rule.
+    Validation(String),
+}
+
+impl core::fmt::Display for BlueprintConfigError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Parse(detail) => write!(f, "blueprint config parse error: {detail}"),
+            Self::Validation(detail) => write!(f, "blueprint config invalid: {detail}"),
+        }
+    }
+}
+
+impl std::error::Error for BlueprintConfigError {}
+
+/// Chip descriptor as it appears in configuration documents.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct ChipConfig {
+    pub label: String,
+    pub description: String,
+    pub color: String,
+    pub variant: String,
+}
+
+/// Action button descriptor as it appears in configuration documents.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct ActionConfig {
+    pub label: String,
+    pub description: String,
+    pub color: String,
+    pub variant: String,
+    pub analytics_id: String,
+    #[serde(default)]
+    pub throttle_ms: Option<u64>,
+}
+
+/// Slider mark as it appears in configuration documents.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct SliderMarkConfig {
+    pub value: f64,
+    pub label: String,
+}
+
+/// Capacity slider as it appears in configuration documents.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct SliderConfig {
+    pub min: f64,
+    pub max: f64,
+    pub step: f64,
+    pub default: f64,
+    #[serde(default)]
+    pub marks: Vec<SliderMarkConfig>,
+}
+
+/// Checklist step as it appears in configuration documents.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct StepConfig {
+    pub title: String,
+    pub detail: String,
+}
+
+/// Card metric as it appears in configuration documents.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct MetricConfig {
+    pub label: String,
+    pub value: String,
+    pub detail: String,
+}
+
+/// Snackbar descriptor as it appears in configuration documents.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct SnackbarConfig {
+    pub success_label: String,
+    pub analytics_id: String,
+}
+
+/// Automation id bundle as it appears in configuration documents.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct AutomationConfig {
+    pub card_id: String,
+    pub environment_chip_id: String,
+    pub capacity_slider_id: String,
+    pub snackbar_id: String,
+}
+
+/// Root configuration document.  The theme is intentionally not part of the
+/// format: design tokens always come from the running binary so configuration
+/// files cannot drift away from the design system.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct BlueprintConfig {
+    pub release_title: String,
+    pub release_summary: String,
+    pub environment: ChipConfig,
+    pub approve_action: ActionConfig,
+    pub rollback_action: ActionConfig,
+    pub capacity: SliderConfig,
+    pub steps: Vec<StepConfig>,
+    #[serde(default)]
+    pub metrics: Vec<MetricConfig>,
+    pub snackbar: SnackbarConfig,
+    pub automation: AutomationConfig,
+}
+
+impl BlueprintConfig {
+    /// Parse a JSON document, the format produced by most internal tooling.
+    pub fn from_json(json: &str) -> Result<Self, BlueprintConfigError> {
+        serde_json::from_str(json).map_err(|err| BlueprintConfigError::Parse(err.to_string()))
+    }
+
+    /// Parse a TOML document for teams that keep workflow definitions next to
+    /// other human-edited configuration.
+    pub fn from_toml(toml_src: &str) -> Result<Self, BlueprintConfigError> {
+        toml::from_str(toml_src).map_err(|err| BlueprintConfigError::Parse(err.to_string()))
+    }
+
+    /// Validate the document and build a render-ready blueprint.  All rules
+    /// run eagerly so misconfigured deployments fail fast at start-up rather
+    /// than panicking mid-session.
+    pub fn into_blueprint(self) -> Result<JoyWorkflowBlueprint, BlueprintConfigError> {
+        self.validate()?;
+        let environment = ChipDescriptor {
+            label: intern(self.environment.label),
+            description: intern(self.environment.description),
+            color: parse_color(&self.environment.color)?,
+            variant: parse_variant(&self.environment.variant)?,
+        };
+        let approve_action = build_action(self.approve_action)?;
+        let rollback_action = build_action(self.rollback_action)?;
+        Ok(JoyWorkflowBlueprint {
+            theme: Theme::default(),
+            release_title: intern(self.release_title),
+            release_summary: intern(self.release_summary),
+            environment,
+            approve_action,
+            rollback_action,
+            capacity: SliderDescriptor {
+                min: self.capacity.min,
+                max: self.capacity.max,
+                step: self.capacity.step,
+                default: self.capacity.default,
+                marks: self
+                    .capacity
+                    .marks
+                    .into_iter()
+                    .map(|mark| SliderMark {
+                        value: mark.value,
+                        label: intern(mark.label),
+                    })
+                    .collect(),
+            },
+            steps: self
+                .steps
+                .into_iter()
+                .map(|step| StepDescriptor {
+                    title: intern(step.title),
+                    detail: intern(step.detail),
+                })
+                .collect(),
+            metrics: self
+                .metrics
+                .into_iter()
+                .map(|metric| CardMetric {
+                    label: intern(metric.label),
+                    value: intern(metric.value),
+                    detail: intern(metric.detail),
+                })
+                .collect(),
+            snackbar: SnackbarDescriptor {
+                success_label: intern(self.snackbar.success_label),
+                analytics_id: intern(self.snackbar.analytics_id),
+            },
+            automation: WorkflowAutomationIds {
+                card_id: intern(self.automation.card_id),
+                environment_chip_id: intern(self.automation.environment_chip_id),
+                capacity_slider_id: intern(self.automation.capacity_slider_id),
+                snackbar_id: intern(self.automation.snackbar_id),
+            },
+        })
+    }
+
+    /// Semantic validation shared by every loader entry point.
+    fn validate(&self) -> Result<(), BlueprintConfigError> {
+        let fail = |detail: String| Err(BlueprintConfigError::Validation(detail));
+        if self.release_title.trim().is_empty() {
+            return fail("release_title must not be empty".into());
+        }
+        if self.steps.is_empty() {
+            return fail("at least one checklist step is required".into());
+        }
+        for (index, step) in self.steps.iter().enumerate() {
+            if step.title.trim().is_empty() {
+                return fail(format!("steps[{index}].title must not be empty"));
+            }
+        }
+        let capacity = &self.capacity;
+        if capacity.min >= capacity.max {
+            return fail("capacity.min must be below capacity.max".into());
+        }
+        if capacity.step <= 0.0 {
+            return fail("capacity.step must be positive".into());
+        }
+        if capacity.default < capacity.min || capacity.default > capacity.max {
+            return fail("capacity.default must sit within [min, max]".into());
+        }
+        for mark in &capacity.marks {
+            if mark.value < capacity.min || mark.value > capacity.max {
+                return fail(format!(
+                    "capacity mark '{}' ({}) is outside the slider range",
+                    mark.label, mark.value
+                ));
+            }
+        }
+        for (name, id) in [
+            ("automation.card_id", &self.automation.card_id),
+            (
+                "automation.environment_chip_id",
+                &self.automation.environment_chip_id,
+            ),
+            (
+                "automation.capacity_slider_id",
+                &self.automation.capacity_slider_id,
+            ),
+            ("automation.snackbar_id", &self.automation.snackbar_id),
+        ] {
+            if id.trim().is_empty() {
+                return fail(format!("{name} must not be empty"));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Intern a validated configuration string.  See the module docs for why the
+/// one-time leak is acceptable here.
+fn intern(value: String) -> &'static str {
+    Box::leak(value.into_boxed_str())
+}
+
+fn build_action(config: ActionConfig) -> Result<ActionDescriptor, BlueprintConfigError> {
+    Ok(ActionDescriptor {
+        label: intern(config.label),
+        description: intern(config.description),
+        color: parse_color(&config.color)?,
+        variant: parse_variant(&config.variant)?,
+        analytics_id: intern(config.analytics_id),
+        throttle_ms: config.throttle_ms,
+    })
+}
+
+/// Map a configuration string onto the Joy palette.
+fn parse_color(raw: &str) -> Result<Color, BlueprintConfigError> {
+    match raw.to_ascii_lowercase().as_str() {
+        "primary" => Ok(Color::Primary),
+        "neutral" => Ok(Color::Neutral),
+        "danger" => Ok(Color::Danger),
+        "success" => Ok(Color::Success),
+        "warning" => Ok(Color::Warning),
+        "info" => Ok(Color::Info),
+        other => Err(BlueprintConfigError::Validation(format!(
+            "unknown Joy color '{other}'"
+        ))),
+    }
+}
+
+/// Map a configuration string onto the Joy surface variants.
+fn parse_variant(raw: &str) -> Result<Variant, BlueprintConfigError> {
+    match raw.to_ascii_lowercase().as_str() {
+        "solid" => Ok(Variant::Solid),
+        "soft" => Ok(Variant::Soft),
+        "outlined" => Ok(Variant::Outlined),
+        "plain" => Ok(Variant::Plain),
+        other => Err(BlueprintConfigError::Validation(format!(
+            "unknown Joy variant '{other}'"
+        ))),
+    }
+}
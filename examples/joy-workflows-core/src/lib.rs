@@ -18,9 +18,17 @@
 //!   data attribute helpers so QA pipelines can assert parity across SSR and
 //!   hydrated runs.
 
+pub mod audit;
+pub mod config;
+
+pub use audit::{AuditClock, AuditEntry, AuditLog, AuditSeverity};
+pub use config::{BlueprintConfig, BlueprintConfigError};
+
+use once_cell::sync::Lazy;
 use rustic_ui_headless::stepper::StepStatus;
 use rustic_ui_joy::{Color, Variant};
 use rustic_ui_system::theme::Theme;
+use serde::{Deserialize, Serialize};
 
 /// Maximum number of lifecycle entries retained by the machine.
 const MAX_LOG_ENTRIES: usize = 32;
@@ -69,90 +77,55 @@ pub struct JoyWorkflowBlueprint {
 
 impl JoyWorkflowBlueprint {
     /// Returns the enterprise defaults used by all demos.
+    ///
+    /// The defaults are no longer hard-coded Rust literals: they live in
+    /// `blueprints/enterprise-release.json` and flow through the same
+    /// [`BlueprintConfig`] loader that teams use for their own workflow
+    /// definitions.  Parsing happens once and the validated blueprint is
+    /// cached for the lifetime of the process.
     pub fn enterprise_release() -> Self {
-        Self {
-            theme: Theme::default(),
-            release_title: "RusticUI Joy deployment pipeline",
-            release_summary: "This dashboard mirrors the production change management flow. Every interaction flows through the shared machine so SSR and hydration stay in lockstep across frameworks.",
-            environment: ChipDescriptor {
-                label: "Production window",
-                description: "Change freeze ends in 2h",
-                color: Color::Neutral,
-                variant: Variant::Soft,
-            },
-            approve_action: ActionDescriptor {
-                label: "Approve next gate",
-                description: "Confirms the current checklist item and unlocks the subsequent step.",
-                color: Color::Primary,
-                variant: Variant::Solid,
-                analytics_id: "joy-approve-gate",
-                throttle_ms: Some(500),
-            },
-            rollback_action: ActionDescriptor {
-                label: "Rollback gate",
-                description: "Reverts to the previous checklist item so issues can be triaged without bypassing controls.",
-                color: Color::Danger,
-                variant: Variant::Outlined,
-                analytics_id: "joy-rollback-gate",
-                throttle_ms: Some(500),
-            },
-            capacity: SliderDescriptor {
-                min: 50.0,
-                max: 150.0,
-                step: 5.0,
-                default: 100.0,
-                marks: vec![
-                    SliderMark { value: 60.0, label: "Canary" },
-                    SliderMark { value: 100.0, label: "Baseline" },
-                    SliderMark { value: 140.0, label: "Burst" },
-                ],
-            },
-            steps: vec![
-                StepDescriptor {
-                    title: "Artifact integrity",
-                    detail: "SBOM + signature validation mirrors supply chain policies.",
-                },
-                StepDescriptor {
-                    title: "Security review",
-                    detail: "Static analysis gates must succeed before scheduling a window.",
-                },
-                StepDescriptor {
-                    title: "Schedule deployment",
-                    detail: "Capacity slider is locked at 100% once this stage completes.",
-                },
-                StepDescriptor {
-                    title: "Launch + telemetry",
-                    detail: "Open the blast radius gradually and stream metrics into the SRE bridge.",
-                },
-            ],
-            metrics: vec![
-                CardMetric {
-                    label: "Automation coverage",
-                    value: "98.4%",
-                    detail: "Unit + integration suites automatically enforce Joy parity across adapters.",
-                },
-                CardMetric {
-                    label: "Pending approvals",
-                    value: "2",
-                    detail: "Design + security teams must approve before production rollout.",
-                },
-                CardMetric {
-                    label: "Last parity audit",
-                    value: "4h ago",
-                    detail: "CI inventory report refreshed via `cargo xtask joy-parity`.",
-                },
-            ],
-            snackbar: SnackbarDescriptor {
-                success_label: "Workflow updated",
-                analytics_id: "joy-workflow-snackbar",
-            },
-            automation: WorkflowAutomationIds {
-                card_id: "joy-workflow-card",
-                environment_chip_id: "joy-workflow-environment",
-                capacity_slider_id: "joy-workflow-capacity",
-                snackbar_id: "joy-workflow-snackbar",
-            },
+        static ENTERPRISE: Lazy<JoyWorkflowBlueprint> = Lazy::new(|| {
+            BlueprintConfig::from_json(include_str!("../blueprints/enterprise-release.json"))
+                .expect("embedded enterprise blueprint parses")
+                .into_blueprint()
+                .expect("embedded enterprise blueprint validates")
+        });
+        ENTERPRISE.clone()
+    }
+
+    /// Render the release checklist through the shared Joy stepper renderer.
+    ///
+    /// The snapshot's per-step statuses are replayed into a non-linear
+    /// [`rustic_ui_joy::StepperState`] so adapters inject one markup string
+    /// instead of hand-rolling `<ol>` lists with status labels.
+    pub fn joy_stepper_html(&self, statuses: &[StepStatus]) -> String {
+        let steps = self
+            .steps
+            .iter()
+            .map(|step| rustic_ui_joy::StepProps::new(step.title).with_description(step.detail))
+            .collect();
+        let props = rustic_ui_joy::StepperProps::new(steps)
+            .with_orientation(rustic_ui_joy::StepperOrientation::Vertical);
+        let mut state = rustic_ui_joy::StepperState::new(rustic_ui_joy::StepperConfig {
+            step_count: self.steps.len(),
+            linear: false,
+            initial_active: None,
+        });
+        for (index, status) in statuses.iter().enumerate() {
+            match status {
+                StepStatus::Completed => {
+                    state.set_step_completed(index, true);
+                }
+                StepStatus::Disabled => state.set_step_disabled(index, true),
+                _ => {}
+            }
         }
+        state.set_active(
+            statuses
+                .iter()
+                .position(|status| *status == StepStatus::Active),
+        );
+        rustic_ui_joy::stepper::stepper(&self.theme, &props, &state)
     }
 }
 
@@ -226,6 +199,34 @@ impl SliderDescriptor {
         }
         ((value - self.min) / range).clamp(0.0, 1.0) * 100.0
     }
+
+    /// Render the capacity control through the shared Joy slider renderer.
+    ///
+    /// Every adapter injects this markup (rail, filled track, marks and the
+    /// value label) and overlays its own transparent `<input type="range">`
+    /// purely for event capture, so the visual surface stays byte-identical
+    /// across Yew, Leptos, Dioxus and Sycamore.
+    pub fn joy_slider_html(&self, theme: &Theme, value: f64) -> String {
+        let mut state = rustic_ui_joy::SliderState::new(rustic_ui_joy::slider::SliderConfig {
+            min: self.min,
+            max: self.max,
+            step: self.step,
+            page: self.step * 10.0,
+            default_value: self.clamp(value),
+            disabled: false,
+            orientation: rustic_ui_joy::SliderOrientation::Horizontal,
+        });
+        state.set_value(value);
+        let props = rustic_ui_joy::slider::SliderProps::new()
+            .with_always_show_value_label(true)
+            .with_marks(
+                self.marks
+                    .iter()
+                    .map(|mark| rustic_ui_joy::SliderMark::new(mark.value).with_label(mark.label))
+                    .collect(),
+            );
+        rustic_ui_joy::slider::slider(theme, &props, &state)
+    }
 }
 
 /// Annotated slider mark containing a helper label.
@@ -256,7 +257,7 @@ pub struct SnackbarDescriptor {
 }
 
 /// Severity classification for snackbar messages.
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub enum SnackbarSeverity {
     /// Informational update (for example slider changes).
     Info,
@@ -264,10 +265,27 @@ pub enum SnackbarSeverity {
     Success,
     /// Warning when rolling back.
     Warning,
+    /// Failure surfaced by an asynchronous gate check.
+    Danger,
+}
+
+impl SnackbarSeverity {
+    /// Joy color/variant pairing used when rendering this severity.
+    ///
+    /// Centralising the mapping keeps every adapter (and future severities)
+    /// in sync instead of each example maintaining its own `match`.
+    pub fn joy_tokens(&self) -> (Color, Variant) {
+        match self {
+            Self::Info => (Color::Neutral, Variant::Soft),
+            Self::Success => (Color::Primary, Variant::Solid),
+            Self::Warning => (Color::Danger, Variant::Soft),
+            Self::Danger => (Color::Danger, Variant::Solid),
+        }
+    }
 }
 
 /// Payload delivered to renderers whenever a snackbar is shown.
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub struct SnackbarPayload {
     /// Severity classification (maps to color/variant decisions in renderers).
     pub severity: SnackbarSeverity,
@@ -275,6 +293,33 @@ pub struct SnackbarPayload {
     pub message: String,
 }
 
+impl SnackbarPayload {
+    /// Render this payload through the shared Joy snackbar renderer.
+    ///
+    /// Adapters inject the returned markup and delegate clicks on their
+    /// wrapper element to the dismiss handler — the embedded
+    /// `data-joy-snackbar-dismiss` button exists purely as the visual
+    /// affordance so the shared string stays free of framework callbacks.
+    pub fn joy_snackbar_html(&self, theme: &Theme, success_label: &str) -> String {
+        let (color, variant) = self.severity.joy_tokens();
+        let props = rustic_ui_joy::SnackbarProps::new()
+            .with_color(color)
+            .with_variant(variant)
+            .with_show_progress(false)
+            .with_end_decorator(
+                "<button type=\"button\" data-joy-snackbar-dismiss=\"true\">Dismiss</button>",
+            );
+        // The workflow machine already owns message lifecycles, so the
+        // renderer receives a one-shot state holding the visible payload.
+        let mut state = rustic_ui_joy::SnackbarState::new(rustic_ui_joy::SnackbarConfig {
+            auto_hide: std::time::Duration::ZERO,
+            max_queue: 1,
+        });
+        state.enqueue(format!("{} — {}", success_label, self.message));
+        rustic_ui_joy::snackbar::snackbar(theme, &props, &state)
+    }
+}
+
 /// Snapshot of the workflow emitted after every state transition.  The snapshot
 /// is intentionally serialisable/clonable so adapters can store it directly in
 /// framework signals or state hooks.
@@ -292,10 +337,84 @@ pub struct JoyWorkflowSnapshot {
     pub step_status: Vec<StepStatus>,
     /// Snackbar payload visible after the latest transition (if any).
     pub snackbar: Option<SnackbarPayload>,
-    /// Lifecycle log retained for QA dashboards.
+    /// Lifecycle log messages retained for QA dashboards.  Mirrors the
+    /// message column of [`Self::audit`] for renderers that only show text.
     pub lifecycle_log: Vec<String>,
+    /// Full audit trail with severity, timestamps and structured fields.
+    pub audit: Vec<AuditEntry>,
     /// Whether every step has been completed.
     pub completed: bool,
+    /// Per-step gate statuses and attempt counters.
+    pub gates: Vec<GateSlot>,
+}
+
+/// Status of the asynchronous approval gate guarding each checklist step.
+/// Gates model CI pipelines or human approvals that run outside the UI: the
+/// demos dispatch [`JoyWorkflowEvent::BeginGateCheck`] when the async work
+/// starts and [`JoyWorkflowEvent::GateResult`] when it settles.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum GateStatus {
+    /// No check has been requested for this step yet.
+    #[default]
+    Pending,
+    /// A check is currently in flight; renderers show a spinner.
+    Running,
+    /// The most recent check failed; the step can be retried.
+    Failed,
+    /// The check succeeded and the step was completed.
+    Passed,
+}
+
+/// Per-step gate bookkeeping exposed through snapshots so dashboards can show
+/// both the live status and how many attempts were needed.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GateSlot {
+    /// Current lifecycle status of the gate.
+    pub status: GateStatus,
+    /// Number of checks started for this step (retries included).
+    pub attempts: u32,
+}
+
+/// Serialisable subset of the machine state used for persistence.  The
+/// blueprint itself is intentionally **not** serialised: it contains design
+/// tokens and copy that must always come from the running binary so resumed
+/// sessions pick up content updates automatically.  Only the user-driven
+/// progress (capacity, completed steps, snackbar, log) crosses process
+/// boundaries.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct JoyWorkflowPersistedState {
+    /// Logical value of the capacity slider.
+    pub capacity_value: f64,
+    /// Number of checklist steps already completed.
+    pub completed_steps: usize,
+    /// Snackbar payload visible when the snapshot was taken.
+    pub snackbar: Option<SnackbarPayload>,
+    /// Lifecycle log messages retained for QA dashboards.  Kept alongside
+    /// [`Self::audit`] so payloads stay readable in raw storage dumps.
+    pub lifecycle_log: Vec<String>,
+    /// Full audit trail.  Defaults to empty when resuming payloads persisted
+    /// before the structured log shipped; [`JoyWorkflowMachine::from_snapshot`]
+    /// then rebuilds informational entries from `lifecycle_log`.
+    #[serde(default)]
+    pub audit: Vec<AuditEntry>,
+    /// Gate bookkeeping per step.  Defaults to pending gates when resuming
+    /// payloads persisted before gate support shipped.
+    #[serde(default)]
+    pub gates: Vec<GateSlot>,
+}
+
+impl JoyWorkflowPersistedState {
+    /// Serialise the state to JSON, the format embedded in SSR payloads and
+    /// browser storage by the framework adapters.
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(self).expect("persisted state serialises to JSON")
+    }
+
+    /// Parse a previously serialised snapshot.  Errors bubble up so callers
+    /// can fall back to [`JoyWorkflowMachine::new`] when storage is corrupt.
+    pub fn from_json(json: &str) -> Result<Self, serde_json::Error> {
+        serde_json::from_str(json)
+    }
 }
 
 /// Events recognised by the workflow machine.
@@ -309,6 +428,17 @@ pub enum JoyWorkflowEvent {
     SetCapacity(f64),
     /// Clear the currently visible snackbar message.
     DismissSnackbar,
+    /// Start the asynchronous gate check for the active step.
+    BeginGateCheck,
+    /// Deliver the outcome of the in-flight gate check.  Successful gates
+    /// complete the active step automatically; failures leave the step
+    /// retryable via another [`Self::BeginGateCheck`].
+    GateResult {
+        /// Whether the external check succeeded.
+        success: bool,
+        /// Human readable detail forwarded into the lifecycle log.
+        detail: String,
+    },
 }
 
 /// Deterministic workflow state machine consumed by every demo.
@@ -318,7 +448,8 @@ pub struct JoyWorkflowMachine {
     capacity_value: f64,
     completed_steps: usize,
     snackbar: Option<SnackbarPayload>,
-    lifecycle_log: Vec<String>,
+    audit: AuditLog,
+    gates: Vec<GateSlot>,
 }
 
 impl JoyWorkflowMachine {
@@ -329,13 +460,88 @@ impl JoyWorkflowMachine {
             capacity_value: blueprint.capacity.default,
             completed_steps: 0,
             snackbar: None,
-            lifecycle_log: Vec::new(),
+            audit: AuditLog::new(MAX_LOG_ENTRIES),
+            gates: vec![GateSlot::default(); blueprint.steps.len()],
             blueprint,
         };
         machine.push_log("Workflow initialised using enterprise defaults.");
         machine
     }
 
+    /// Construct the workflow from a custom blueprint, typically produced by
+    /// [`BlueprintConfig::into_blueprint`] after loading a team-specific JSON
+    /// or TOML definition.
+    pub fn with_blueprint(blueprint: JoyWorkflowBlueprint) -> Self {
+        let mut machine = Self {
+            capacity_value: blueprint.capacity.default,
+            completed_steps: 0,
+            snackbar: None,
+            audit: AuditLog::new(MAX_LOG_ENTRIES),
+            gates: vec![GateSlot::default(); blueprint.steps.len()],
+            blueprint,
+        };
+        machine.push_log("Workflow initialised from custom blueprint.");
+        machine
+    }
+
+    /// Resume a workflow from a previously persisted snapshot.  Values are
+    /// re-validated against the current blueprint: the capacity is clamped to
+    /// the configured slider range and the completed step count can never
+    /// exceed the checklist length, so stale or tampered payloads degrade
+    /// gracefully instead of panicking downstream.
+    pub fn from_snapshot(state: JoyWorkflowPersistedState) -> Self {
+        let blueprint = JoyWorkflowBlueprint::enterprise_release();
+        let mut gates = state.gates;
+        gates.resize(blueprint.steps.len(), GateSlot::default());
+        // Payloads persisted before the structured audit log shipped only
+        // carry message lines; rebuild them as informational entries so the
+        // trail stays contiguous across the upgrade.
+        let entries = if state.audit.is_empty() {
+            state
+                .lifecycle_log
+                .into_iter()
+                .map(|message| AuditEntry {
+                    timestamp_ms: 0,
+                    severity: AuditSeverity::Info,
+                    message,
+                    fields: Default::default(),
+                })
+                .collect()
+        } else {
+            state.audit
+        };
+        let mut machine = Self {
+            capacity_value: blueprint.capacity.clamp(state.capacity_value),
+            completed_steps: state.completed_steps.min(blueprint.steps.len()),
+            snackbar: state.snackbar,
+            audit: AuditLog::from_entries(MAX_LOG_ENTRIES, entries),
+            gates,
+            blueprint,
+        };
+        machine.push_log("Workflow resumed from persisted snapshot.");
+        machine
+    }
+
+    /// Capture the serialisable portion of the machine for storage or SSR
+    /// embedding.  Pair with [`Self::from_snapshot`] to resume later.
+    pub fn persisted_state(&self) -> JoyWorkflowPersistedState {
+        JoyWorkflowPersistedState {
+            capacity_value: self.capacity_value,
+            completed_steps: self.completed_steps,
+            snackbar: self.snackbar.clone(),
+            lifecycle_log: self.audit.messages(),
+            audit: self.audit.entries().to_vec(),
+            gates: self.gates.clone(),
+        }
+    }
+
+    /// Full audit trail backing the lifecycle log.  QA dashboards and
+    /// compliance jobs call [`AuditLog::to_json`] / [`AuditLog::to_csv`] on
+    /// the returned reference instead of re-parsing rendered markup.
+    pub fn audit_log(&self) -> &AuditLog {
+        &self.audit
+    }
+
     /// Access the shared blueprint. Renderers typically clone individual
     /// descriptors from this structure so templates remain declarative.
     pub fn blueprint(&self) -> &JoyWorkflowBlueprint {
@@ -352,6 +558,8 @@ impl JoyWorkflowMachine {
                 self.snackbar = None;
                 self.snapshot()
             }
+            JoyWorkflowEvent::BeginGateCheck => self.begin_gate_check(),
+            JoyWorkflowEvent::GateResult { success, detail } => self.gate_result(success, detail),
         }
     }
 
@@ -389,8 +597,10 @@ impl JoyWorkflowMachine {
             active_step_label,
             step_status,
             snackbar: self.snackbar.clone(),
-            lifecycle_log: self.lifecycle_log.clone(),
+            lifecycle_log: self.audit.messages(),
+            audit: self.audit.entries().to_vec(),
             completed: self.completed_steps >= self.blueprint.steps.len(),
+            gates: self.gates.clone(),
         }
     }
 
@@ -399,13 +609,21 @@ impl JoyWorkflowMachine {
         self.resolve_capacity_profile()
     }
 
-    /// Append an entry to the lifecycle log while keeping the ring buffer small.
+    /// Append an informational entry to the audit trail; the [`AuditLog`]
+    /// ring buffer handles retention.
     fn push_log(&mut self, message: impl Into<String>) {
-        self.lifecycle_log.push(message.into());
-        if self.lifecycle_log.len() > MAX_LOG_ENTRIES {
-            let excess = self.lifecycle_log.len() - MAX_LOG_ENTRIES;
-            self.lifecycle_log.drain(0..excess);
-        }
+        self.audit.record(AuditSeverity::Info, message);
+    }
+
+    /// Append an entry at an explicit severity with structured context so
+    /// compliance exports can filter without parsing message text.
+    fn push_audit<'a>(
+        &mut self,
+        severity: AuditSeverity,
+        message: impl Into<String>,
+        fields: impl IntoIterator<Item = (&'a str, String)>,
+    ) {
+        self.audit.record_with_fields(severity, message, fields);
     }
 
     /// Update the snackbar payload with the provided severity + message.
@@ -417,7 +635,11 @@ impl JoyWorkflowMachine {
         if self.completed_steps < self.blueprint.steps.len() {
             let label = self.blueprint.steps[self.completed_steps].title;
             self.completed_steps += 1;
-            self.push_log(format!("Completed step: {label}"));
+            self.push_audit(
+                AuditSeverity::Success,
+                format!("Completed step: {label}"),
+                [("step", label.to_string())],
+            );
             if self.completed_steps < self.blueprint.steps.len() {
                 let next = self.blueprint.steps[self.completed_steps].title;
                 self.set_snackbar(SnackbarSeverity::Success, format!("Advanced to '{next}'."));
@@ -437,7 +659,11 @@ impl JoyWorkflowMachine {
         if self.completed_steps > 0 {
             self.completed_steps -= 1;
             let label = self.blueprint.steps[self.completed_steps].title;
-            self.push_log(format!("Rolled back to step: {label}"));
+            self.push_audit(
+                AuditSeverity::Warning,
+                format!("Rolled back to step: {label}"),
+                [("step", label.to_string())],
+            );
             self.set_snackbar(
                 SnackbarSeverity::Warning,
                 format!("Returned to '{label}' for remediation."),
@@ -452,6 +678,79 @@ impl JoyWorkflowMachine {
         self.snapshot()
     }
 
+    fn begin_gate_check(&mut self) -> JoyWorkflowSnapshot {
+        let Some(index) = self.active_step_index() else {
+            self.push_log("Gate check ignored – workflow already completed.");
+            return self.snapshot();
+        };
+        if self.gates[index].status == GateStatus::Running {
+            self.push_log("Gate check ignored – a check is already running.");
+            return self.snapshot();
+        }
+        let retrying = self.gates[index].status == GateStatus::Failed;
+        self.gates[index].status = GateStatus::Running;
+        self.gates[index].attempts += 1;
+        let label = self.blueprint.steps[index].title;
+        let attempt = self.gates[index].attempts;
+        let message = if retrying {
+            format!("Gate retry started for '{label}' (attempt {attempt}).")
+        } else {
+            format!("Gate check started for '{label}' (attempt {attempt}).")
+        };
+        self.push_audit(
+            AuditSeverity::Info,
+            message,
+            [
+                ("step", label.to_string()),
+                ("attempt", attempt.to_string()),
+            ],
+        );
+        self.set_snackbar(
+            SnackbarSeverity::Info,
+            format!("Running checks for '{label}'…"),
+        );
+        self.snapshot()
+    }
+
+    fn gate_result(&mut self, success: bool, detail: String) -> JoyWorkflowSnapshot {
+        let Some(index) = self.active_step_index() else {
+            self.push_log("Gate result ignored – workflow already completed.");
+            return self.snapshot();
+        };
+        if self.gates[index].status != GateStatus::Running {
+            self.push_log("Gate result ignored – no check is in flight.");
+            return self.snapshot();
+        }
+        let label = self.blueprint.steps[index].title;
+        if success {
+            self.gates[index].status = GateStatus::Passed;
+            self.push_audit(
+                AuditSeverity::Success,
+                format!("Gate passed for '{label}': {detail}"),
+                [("step", label.to_string())],
+            );
+            // Completing the gate approves the step, mirroring CI pipelines
+            // where a green check unlocks the next stage automatically.
+            return self.advance_step();
+        }
+        self.gates[index].status = GateStatus::Failed;
+        self.push_audit(
+            AuditSeverity::Danger,
+            format!("Gate failed for '{label}': {detail}"),
+            [("step", label.to_string())],
+        );
+        self.set_snackbar(
+            SnackbarSeverity::Danger,
+            format!("Checks for '{label}' failed. Retry once the issue is resolved."),
+        );
+        self.snapshot()
+    }
+
+    /// Index of the step currently awaiting completion, if any.
+    fn active_step_index(&self) -> Option<usize> {
+        (self.completed_steps < self.blueprint.steps.len()).then_some(self.completed_steps)
+    }
+
     fn update_capacity(&mut self, raw: f64) -> JoyWorkflowSnapshot {
         let clamped = self.blueprint.capacity.clamp(raw);
         self.capacity_value =
@@ -482,3 +781,322 @@ impl JoyWorkflowMachine {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn persisted_state_round_trips_through_json() {
+        let mut machine = JoyWorkflowMachine::new();
+        machine.apply(JoyWorkflowEvent::Advance);
+        machine.apply(JoyWorkflowEvent::SetCapacity(120.0));
+
+        let json = machine.persisted_state().to_json();
+        let restored =
+            JoyWorkflowMachine::from_snapshot(JoyWorkflowPersistedState::from_json(&json).unwrap());
+
+        let before = machine.snapshot();
+        let after = restored.snapshot();
+        assert_eq!(after.capacity_value, before.capacity_value);
+        assert_eq!(after.active_step, before.active_step);
+        assert_eq!(after.snackbar, before.snackbar);
+        assert!(after
+            .lifecycle_log
+            .last()
+            .unwrap()
+            .contains("resumed from persisted snapshot"));
+    }
+
+    #[test]
+    fn enterprise_release_loads_through_config_pipeline() {
+        let blueprint = JoyWorkflowBlueprint::enterprise_release();
+        assert_eq!(blueprint.release_title, "RusticUI Joy deployment pipeline");
+        assert_eq!(blueprint.steps.len(), 4);
+        assert_eq!(blueprint.approve_action.color, Color::Primary);
+        assert_eq!(blueprint.rollback_action.variant, Variant::Outlined);
+    }
+
+    #[test]
+    fn custom_toml_blueprint_drives_the_machine() {
+        let toml_src = r#"
+            release_title = "Docs deploy"
+            release_summary = "Two step docs workflow."
+
+            [environment]
+            label = "Staging"
+            description = "Always open"
+            color = "info"
+            variant = "soft"
+
+            [approve_action]
+            label = "Ship"
+            description = "Publish the docs."
+            color = "success"
+            variant = "solid"
+            analytics_id = "docs-ship"
+
+            [rollback_action]
+            label = "Revert"
+            description = "Restore the previous docs build."
+            color = "danger"
+            variant = "plain"
+            analytics_id = "docs-revert"
+
+            [capacity]
+            min = 0.0
+            max = 100.0
+            step = 10.0
+            default = 50.0
+
+            [[steps]]
+            title = "Build"
+            detail = "Render the static site."
+
+            [[steps]]
+            title = "Publish"
+            detail = "Sync to the CDN."
+
+            [snackbar]
+            success_label = "Docs updated"
+            analytics_id = "docs-snackbar"
+
+            [automation]
+            card_id = "docs-card"
+            environment_chip_id = "docs-environment"
+            capacity_slider_id = "docs-capacity"
+            snackbar_id = "docs-snackbar"
+        "#;
+        let blueprint = BlueprintConfig::from_toml(toml_src)
+            .unwrap()
+            .into_blueprint()
+            .unwrap();
+        let mut machine = JoyWorkflowMachine::with_blueprint(blueprint);
+        let snapshot = machine.apply(JoyWorkflowEvent::Advance);
+        assert_eq!(snapshot.active_step_label, Some("Publish"));
+    }
+
+    #[test]
+    fn blueprint_validation_rejects_bad_documents() {
+        let json = include_str!("../blueprints/enterprise-release.json");
+
+        let mut config = BlueprintConfig::from_json(json).unwrap();
+        config.steps.clear();
+        assert!(matches!(
+            config.into_blueprint(),
+            Err(BlueprintConfigError::Validation(detail)) if detail.contains("step")
+        ));
+
+        let mut config = BlueprintConfig::from_json(json).unwrap();
+        config.capacity.default = 9000.0;
+        assert!(config.into_blueprint().is_err());
+
+        let mut config = BlueprintConfig::from_json(json).unwrap();
+        config.environment.color = "magenta".into();
+        assert!(matches!(
+            config.into_blueprint(),
+            Err(BlueprintConfigError::Validation(detail)) if detail.contains("magenta")
+        ));
+
+        assert!(matches!(
+            BlueprintConfig::from_json("not json"),
+            Err(BlueprintConfigError::Parse(_))
+        ));
+    }
+
+    #[test]
+    fn gate_checks_drive_pending_running_failed_states() {
+        let mut machine = JoyWorkflowMachine::new();
+        assert!(machine
+            .snapshot()
+            .gates
+            .iter()
+            .all(|gate| gate.status == GateStatus::Pending));
+
+        let snapshot = machine.apply(JoyWorkflowEvent::BeginGateCheck);
+        assert_eq!(snapshot.gates[0].status, GateStatus::Running);
+        assert_eq!(snapshot.gates[0].attempts, 1);
+
+        let snapshot = machine.apply(JoyWorkflowEvent::GateResult {
+            success: false,
+            detail: "signature mismatch".into(),
+        });
+        assert_eq!(snapshot.gates[0].status, GateStatus::Failed);
+        assert_eq!(
+            snapshot.snackbar.as_ref().map(|s| s.severity.clone()),
+            Some(SnackbarSeverity::Danger)
+        );
+        assert!(snapshot
+            .lifecycle_log
+            .iter()
+            .any(|line| line.contains("signature mismatch")));
+
+        // Retrying bumps the attempt counter and a green result advances.
+        let snapshot = machine.apply(JoyWorkflowEvent::BeginGateCheck);
+        assert_eq!(snapshot.gates[0].attempts, 2);
+        assert!(snapshot
+            .lifecycle_log
+            .iter()
+            .any(|line| line.contains("retry")));
+        let snapshot = machine.apply(JoyWorkflowEvent::GateResult {
+            success: true,
+            detail: "all checks green".into(),
+        });
+        assert_eq!(snapshot.gates[0].status, GateStatus::Passed);
+        assert_eq!(snapshot.active_step, Some(1));
+    }
+
+    #[test]
+    fn audit_trail_carries_severities_and_structured_fields() {
+        let mut machine = JoyWorkflowMachine::new();
+        machine.apply(JoyWorkflowEvent::Advance);
+        machine.apply(JoyWorkflowEvent::Rollback);
+        let snapshot = machine.snapshot();
+        // Messages stay aligned with the legacy lifecycle log lines.
+        assert_eq!(
+            snapshot.lifecycle_log,
+            snapshot
+                .audit
+                .iter()
+                .map(|entry| entry.message.clone())
+                .collect::<Vec<_>>()
+        );
+        let completed = snapshot
+            .audit
+            .iter()
+            .find(|entry| entry.message.starts_with("Completed step"))
+            .expect("advance recorded");
+        assert_eq!(completed.severity, AuditSeverity::Success);
+        assert_eq!(
+            completed.fields.get("step").map(String::as_str),
+            Some(machine.blueprint().steps[0].title)
+        );
+        let rollback = snapshot.audit.last().expect("rollback recorded");
+        assert_eq!(rollback.severity, AuditSeverity::Warning);
+
+        // Compliance exports consume the same entries without re-parsing.
+        let csv = machine.audit_log().to_csv();
+        assert!(csv.starts_with("timestamp_ms,severity,message,fields"));
+        assert!(csv.contains(",success,"));
+    }
+
+    #[test]
+    fn audit_entries_survive_persistence_and_legacy_payloads_degrade() {
+        let mut machine = JoyWorkflowMachine::new();
+        machine.apply(JoyWorkflowEvent::Advance);
+        let json = machine.persisted_state().to_json();
+        let restored =
+            JoyWorkflowMachine::from_snapshot(JoyWorkflowPersistedState::from_json(&json).unwrap());
+        assert!(restored
+            .audit_log()
+            .entries()
+            .iter()
+            .any(|entry| entry.severity == AuditSeverity::Success));
+
+        // Payloads written before the structured log shipped have no `audit`
+        // key; their message lines resurface as informational entries.
+        let legacy = r#"{"capacity_value":100.0,"completed_steps":1,"snackbar":null,"lifecycle_log":["legacy line"]}"#;
+        let restored = JoyWorkflowMachine::from_snapshot(
+            JoyWorkflowPersistedState::from_json(legacy).unwrap(),
+        );
+        let first = &restored.audit_log().entries()[0];
+        assert_eq!(first.message, "legacy line");
+        assert_eq!(first.severity, AuditSeverity::Info);
+    }
+
+    #[test]
+    fn gate_results_without_running_check_are_ignored() {
+        let mut machine = JoyWorkflowMachine::new();
+        let snapshot = machine.apply(JoyWorkflowEvent::GateResult {
+            success: true,
+            detail: "spurious".into(),
+        });
+        assert_eq!(snapshot.gates[0].status, GateStatus::Pending);
+        assert_eq!(snapshot.active_step, Some(0));
+        assert!(snapshot
+            .lifecycle_log
+            .iter()
+            .any(|line| line.contains("no check is in flight")));
+    }
+
+    #[test]
+    fn from_snapshot_clamps_stale_payloads() {
+        let state = JoyWorkflowPersistedState {
+            capacity_value: 900.0,
+            completed_steps: 99,
+            snackbar: None,
+            lifecycle_log: Vec::new(),
+            audit: Vec::new(),
+            gates: Vec::new(),
+        };
+        let machine = JoyWorkflowMachine::from_snapshot(state);
+        let snapshot = machine.snapshot();
+        assert_eq!(snapshot.capacity_value, machine.blueprint().capacity.max);
+        assert!(snapshot.completed);
+        assert_eq!(snapshot.active_step, None);
+    }
+
+    #[test]
+    fn blueprint_renders_the_joy_stepper_from_snapshot_statuses() {
+        let mut machine = JoyWorkflowMachine::new();
+        machine.apply(JoyWorkflowEvent::Advance);
+        let snapshot = machine.snapshot();
+        let html = machine.blueprint().joy_stepper_html(&snapshot.step_status);
+        assert!(html.contains("data-joy-stepper=\"true\""));
+        assert!(html.contains("data-status=\"completed\""));
+        assert!(html.contains("data-status=\"active\""));
+        assert!(html.contains(machine.blueprint().steps[0].title));
+    }
+
+    #[test]
+    fn every_snackbar_severity_maps_to_joy_tokens() {
+        // The adapters used to carry their own `match` over the severity enum
+        // and broke whenever a variant was added; the mapping now lives here
+        // so extending the enum forces this crate (and only this crate) to
+        // decide the presentation in the same change.
+        assert_eq!(
+            SnackbarSeverity::Info.joy_tokens(),
+            (Color::Neutral, Variant::Soft)
+        );
+        assert_eq!(
+            SnackbarSeverity::Success.joy_tokens(),
+            (Color::Primary, Variant::Solid)
+        );
+        assert_eq!(
+            SnackbarSeverity::Warning.joy_tokens(),
+            (Color::Danger, Variant::Soft)
+        );
+        assert_eq!(
+            SnackbarSeverity::Danger.joy_tokens(),
+            (Color::Danger, Variant::Solid)
+        );
+    }
+
+    #[test]
+    fn snackbar_payload_renders_the_joy_surface() {
+        let payload = SnackbarPayload {
+            severity: SnackbarSeverity::Success,
+            message: "Step complete".into(),
+        };
+        let html = payload.joy_snackbar_html(&Theme::default(), "Release");
+        assert!(html.contains("data-joy-snackbar=\"true\""));
+        assert!(html.contains("Release — Step complete"));
+        assert!(html.contains("data-joy-snackbar-dismiss=\"true\""));
+    }
+
+    #[test]
+    fn capacity_descriptor_renders_the_joy_slider() {
+        let machine = JoyWorkflowMachine::new();
+        let blueprint = machine.blueprint();
+        let html = blueprint
+            .capacity
+            .joy_slider_html(&blueprint.theme, machine.snapshot().capacity_value);
+        assert!(html.contains("data-joy-slider=\"true\""));
+        assert!(html.contains("role=\"slider\""));
+        // Marks from the blueprint surface with their labels so the legends
+        // previously duplicated by every adapter live in one place.
+        for mark in &blueprint.capacity.marks {
+            assert!(html.contains(mark.label));
+        }
+    }
+}
@@ -1,6 +1,4 @@
-use joy_workflows_core::{
-    JoyWorkflowEvent, JoyWorkflowMachine, JoyWorkflowSnapshot, SnackbarSeverity,
-};
+use joy_workflows_core::{JoyWorkflowEvent, JoyWorkflowMachine, JoyWorkflowSnapshot};
 use rustic_ui_joy::helpers::resolve_surface_tokens;
 use rustic_ui_joy::{Button, ButtonProps, Card, Color, Variant};
 use rustic_ui_system::theme_provider::{CssBaseline, ThemeProvider};
@@ -8,26 +6,6 @@ use wasm_bindgen::JsCast;
 use web_sys::HtmlInputElement;
 use yew::prelude::*;
 
-/// Translate the Joy stepper status into a short label for the demo UI.
-fn status_label(status: &rustic_ui_joy::stepper::StepStatus) -> &'static str {
-    use rustic_ui_joy::stepper::StepStatus::{Active, Completed, Pending};
-    match status {
-        Completed => "Completed",
-        Active => "In progress",
-        Pending => "Pending",
-        _ => "Disabled",
-    }
-}
-
-/// Determine the Joy color/variant pairing to use for snackbar payloads.
-fn snackbar_tokens(severity: &SnackbarSeverity) -> (Color, Variant) {
-    match severity {
-        SnackbarSeverity::Info => (Color::Neutral, Variant::Soft),
-        SnackbarSeverity::Success => (Color::Primary, Variant::Solid),
-        SnackbarSeverity::Warning => (Color::Danger, Variant::Soft),
-    }
-}
-
 /// Yew component rendering the shared Joy workflow.
 #[function_component(App)]
 fn app() -> Html {
@@ -112,24 +90,15 @@ fn app() -> Html {
     ]);
 
     let snackbar_view = snapshot.snackbar.as_ref().map(|payload| {
-        let (color, variant) = snackbar_tokens(&payload.severity);
-        let style = resolve_surface_tokens(&blueprint.theme, color, variant).compose([
-            ("padding", "12px 16px".to_string()),
-            ("border-radius", "8px".to_string()),
-            ("display", "flex".to_string()),
-            ("align-items", "center".to_string()),
-            ("justify-content", "space-between".to_string()),
-            ("gap", "16px".to_string()),
-        ]);
+        let markup = payload.joy_snackbar_html(&blueprint.theme, blueprint.snackbar.success_label);
+        // The shared renderer owns the surface; clicks on the embedded dismiss
+        // affordance bubble up to this wrapper's handler.
         html! {
             <div
-                style={style}
+                onclick={dismiss_snackbar.clone()}
                 data-analytics-id={blueprint.automation.snackbar_id}
-                role="status"
-                aria-live="polite"
             >
-                <span>{format!("{} — {}", blueprint.snackbar.success_label, payload.message)}</span>
-                <button type="button" onclick={dismiss_snackbar.clone()}>{"Dismiss"}</button>
+                { Html::from_html_unchecked(AttrValue::from(markup)) }
             </div>
         }
     });
@@ -189,44 +158,35 @@ fn app() -> Html {
 
                         <section style="display:flex;flex-direction:column;gap:12px;">
                             <label for="joy-capacity" style="font-weight:600;">{"Deployment capacity"}</label>
-                            <input
-                                id="joy-capacity"
-                                type="range"
-                                min={blueprint.capacity.min.to_string()}
-                                max={blueprint.capacity.max.to_string()}
-                                step={blueprint.capacity.step.to_string()}
-                                value={format!("{:.0}", snapshot.capacity_value)}
-                                style={format!("width:100%;appearance:none;background:linear-gradient(90deg,#38bdf8 0%,#3b82f6 {:.2}%,rgba(148,163,184,0.3) {:.2}%);
-                                    height:8px;border-radius:6px;outline:none;", snapshot.capacity_percent, snapshot.capacity_percent)}
-                                oninput={on_capacity_change.clone()}
+                            // The Joy slider renderer owns the visual surface (rail, track,
+                            // marks, value label); the transparent range input stacked on top
+                            // only exists to capture pointer/keyboard events.
+                            <div
+                                style="position:relative;"
                                 data-analytics-id={blueprint.automation.capacity_slider_id}
-                            />
-                            <div style="display:flex;justify-content:space-between;font-size:0.85rem;color:#94a3b8;">
-                                { for blueprint.capacity.marks.iter().map(|mark| html! {
-                                    <span>{format!("{}% — {}", mark.value, mark.label)}</span>
-                                }) }
+                            >
+                                { Html::from_html_unchecked(AttrValue::from(
+                                    blueprint.capacity.joy_slider_html(&blueprint.theme, snapshot.capacity_value),
+                                )) }
+                                <input
+                                    id="joy-capacity"
+                                    type="range"
+                                    min={blueprint.capacity.min.to_string()}
+                                    max={blueprint.capacity.max.to_string()}
+                                    step={blueprint.capacity.step.to_string()}
+                                    value={format!("{:.0}", snapshot.capacity_value)}
+                                    style="position:absolute;inset:0;width:100%;height:100%;opacity:0;cursor:pointer;"
+                                    oninput={on_capacity_change.clone()}
+                                />
                             </div>
                             <p style="margin:0;font-weight:500;">{format!("Current allocation: {:.0}% ({})", snapshot.capacity_value, capacity_profile)}</p>
                         </section>
 
                         <section style="display:flex;flex-direction:column;gap:12px;">
                             <h2 style="margin:0;font-size:1.25rem;">{"Release checklist"}</h2>
-                            <ol style="margin:0;padding-left:18px;display:flex;flex-direction:column;gap:8px;">
-                                { for blueprint.steps.iter().enumerate().map(|(index, step)| {
-                                    let status = snapshot.step_status.get(index).cloned().unwrap_or(rustic_ui_joy::stepper::StepStatus::Pending);
-                                    html! {
-                                        <li>
-                                            <div style="display:flex;flex-direction:column;gap:4px;">
-                                                <div style="display:flex;align-items:center;gap:8px;">
-                                                    <span style="font-weight:600;">{step.title}</span>
-                                                    <span style="font-size:0.8rem;color:#94a3b8;">{status_label(&status)}</span>
-                                                </div>
-                                                <p style="margin:0;color:#cbd5f5;">{step.detail}</p>
-                                            </div>
-                                        </li>
-                                    }
-                                }) }
-                            </ol>
+                            { Html::from_html_unchecked(AttrValue::from(
+                                blueprint.joy_stepper_html(&snapshot.step_status),
+                            )) }
                         </section>
 
                         <section style="display:flex;flex-wrap:wrap;gap:12px;align-items:flex-start;">
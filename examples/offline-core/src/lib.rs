@@ -0,0 +1,519 @@
+//! Shared offline-first application blueprint.
+//!
+//! The demo models the state an installable PWA juggles when the network
+//! drops: edits keep landing in the local copy, an outbox queues the pending
+//! mutations, and reconnecting replays the queue against the remote store.
+//! Replays that race a remote edit surface as conflicts the user resolves
+//! explicitly.  Every sync transition feeds the headless
+//! [`SnackbarState`] queue so adapters render toast notifications without
+//! owning any timing logic:
+//!
+//! * [`OfflineApp::edit`] applies optimistically and either pushes straight
+//!   to the remote store or queues in the outbox depending on connectivity.
+//! * [`OfflineApp::reconnect`] drains the outbox in order, collecting
+//!   conflicts instead of aborting so one stale edit never blocks the rest
+//!   of the queue.
+//! * [`RemoteStore`] stands in for the server with revision-checked writes,
+//!   letting tests provoke conflicts deterministically.
+
+use std::collections::VecDeque;
+
+use rustic_ui_headless::snackbar::{SnackbarConfig, SnackbarState};
+
+/// Stable automation prefix applied to demo selectors.
+pub const AUTOMATION_ID: &str = "rusticui-offline";
+
+/// Connectivity phases surfaced in the app bar.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ConnectivityState {
+    /// Network available; mutations push immediately.
+    Online,
+    /// Network lost; mutations queue in the outbox.
+    Offline,
+    /// Network regained; the outbox is replaying.
+    Reconnecting,
+}
+
+impl ConnectivityState {
+    /// Status label rendered next to the connectivity indicator.
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Online => "Online",
+            Self::Offline => "Offline",
+            Self::Reconnecting => "Syncing…",
+        }
+    }
+}
+
+/// One record in the demo's note collection.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct NoteRecord {
+    /// Stable record identifier.
+    pub id: u64,
+    /// Short title rendered in the list.
+    pub title: &'static str,
+    /// Editable body text.
+    pub body: String,
+    /// Revision the local copy is based on.
+    pub revision: u64,
+}
+
+/// Pending mutation waiting in the outbox.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PendingMutation {
+    /// Queue position identifier, useful for outbox list keys.
+    pub queued_id: u64,
+    /// Record the mutation targets.
+    pub record_id: u64,
+    /// New body text.
+    pub body: String,
+    /// Revision the edit was made against; the remote store rejects the
+    /// write when the record moved on in the meantime.
+    pub base_revision: u64,
+}
+
+/// Conflict produced when a replayed mutation raced a remote edit.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SyncConflict {
+    /// Record the conflict concerns.
+    pub record_id: u64,
+    /// The body the user wrote while offline.
+    pub local_body: String,
+    /// The body currently stored remotely.
+    pub remote_body: String,
+    /// Revision of the remote body; resolving in favour of the local copy
+    /// re-submits against this revision.
+    pub remote_revision: u64,
+}
+
+/// How the user resolves a [`SyncConflict`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ConflictChoice {
+    /// Re-submit the offline edit on top of the remote revision.
+    KeepLocal,
+    /// Discard the offline edit and adopt the remote body.
+    TakeRemote,
+}
+
+/// Sync transitions announced through the snackbar queue.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SyncEvent {
+    /// Connectivity dropped.
+    WentOffline,
+    /// A mutation was queued while offline.
+    QueuedOffline { record_id: u64 },
+    /// Outbox replay finished; `synced` mutations landed cleanly.
+    SyncComplete { synced: usize },
+    /// A replayed mutation raced a remote edit and needs a decision.
+    ConflictDetected { record_id: u64 },
+    /// A conflict was resolved.
+    ConflictResolved { record_id: u64 },
+}
+
+impl SyncEvent {
+    /// User-facing snackbar copy.
+    pub fn message(&self) -> String {
+        match self {
+            Self::WentOffline => "You are offline — edits will sync later".into(),
+            Self::QueuedOffline { record_id } => {
+                format!("Saved locally; note {record_id} will sync when online")
+            }
+            Self::SyncComplete { synced } => format!("Back online — {synced} change(s) synced"),
+            Self::ConflictDetected { record_id } => {
+                format!("Note {record_id} changed remotely — review the conflict")
+            }
+            Self::ConflictResolved { record_id } => format!("Note {record_id} conflict resolved"),
+        }
+    }
+}
+
+/// Error returned by revision-checked remote writes.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RemoteConflict {
+    /// Body currently stored remotely.
+    pub remote_body: String,
+    /// Revision of that body.
+    pub remote_revision: u64,
+}
+
+/// In-memory stand-in for the server, with optimistic-concurrency writes.
+#[derive(Clone, Debug, Default)]
+pub struct RemoteStore {
+    records: Vec<(u64, String, u64)>,
+}
+
+impl RemoteStore {
+    /// Seed the store from the initial records.
+    fn from_records(records: &[NoteRecord]) -> Self {
+        Self {
+            records: records
+                .iter()
+                .map(|record| (record.id, record.body.clone(), record.revision))
+                .collect(),
+        }
+    }
+
+    /// Apply a mutation, returning the new revision or the conflicting
+    /// remote state when `base_revision` is stale.
+    pub fn push(&mut self, mutation: &PendingMutation) -> Result<u64, RemoteConflict> {
+        let entry = self
+            .records
+            .iter_mut()
+            .find(|(id, _, _)| *id == mutation.record_id)
+            .expect("mutations target seeded records");
+        if entry.2 != mutation.base_revision {
+            return Err(RemoteConflict {
+                remote_body: entry.1.clone(),
+                remote_revision: entry.2,
+            });
+        }
+        entry.1 = mutation.body.clone();
+        entry.2 += 1;
+        Ok(entry.2)
+    }
+
+    /// Simulate another client editing a record while this one is offline.
+    pub fn edit_remotely(&mut self, record_id: u64, body: impl Into<String>) {
+        let entry = self
+            .records
+            .iter_mut()
+            .find(|(id, _, _)| *id == record_id)
+            .expect("remote edits target seeded records");
+        entry.1 = body.into();
+        entry.2 += 1;
+    }
+}
+
+/// Offline-first demo machine shared by every framework adapter.
+pub struct OfflineApp {
+    connectivity: ConnectivityState,
+    records: Vec<NoteRecord>,
+    remote: RemoteStore,
+    outbox: VecDeque<PendingMutation>,
+    conflicts: Vec<SyncConflict>,
+    snackbar: SnackbarState<SyncEvent>,
+    next_queue_id: u64,
+}
+
+impl OfflineApp {
+    /// Build the demo online with the seed notes mirrored remotely.
+    pub fn new() -> Self {
+        let records = seed_notes();
+        let remote = RemoteStore::from_records(&records);
+        Self {
+            connectivity: ConnectivityState::Online,
+            records,
+            remote,
+            outbox: VecDeque::new(),
+            conflicts: Vec::new(),
+            snackbar: SnackbarState::new(SnackbarConfig::enterprise_defaults()),
+            next_queue_id: 0,
+        }
+    }
+
+    /// Current connectivity phase.
+    #[inline]
+    pub fn connectivity(&self) -> ConnectivityState {
+        self.connectivity
+    }
+
+    /// Local copy of the note collection.
+    #[inline]
+    pub fn records(&self) -> &[NoteRecord] {
+        &self.records
+    }
+
+    /// Mutations waiting for connectivity, in submission order.
+    #[inline]
+    pub fn outbox(&self) -> &VecDeque<PendingMutation> {
+        &self.outbox
+    }
+
+    /// Conflicts awaiting a user decision.
+    #[inline]
+    pub fn conflicts(&self) -> &[SyncConflict] {
+        &self.conflicts
+    }
+
+    /// Snackbar queue driving the sync notifications.
+    #[inline]
+    pub fn snackbar(&mut self) -> &mut SnackbarState<SyncEvent> {
+        &mut self.snackbar
+    }
+
+    /// Simulated server, exposed so adapters (and tests) can inject remote
+    /// edits that provoke conflicts.
+    #[inline]
+    pub fn remote_mut(&mut self) -> &mut RemoteStore {
+        &mut self.remote
+    }
+
+    /// Drop connectivity; subsequent edits queue in the outbox.
+    pub fn go_offline(&mut self) {
+        if self.connectivity == ConnectivityState::Offline {
+            return;
+        }
+        self.connectivity = ConnectivityState::Offline;
+        self.snackbar.enqueue(SyncEvent::WentOffline);
+    }
+
+    /// Edit a note's body.  The local copy always updates immediately; the
+    /// mutation pushes straight to the remote store while online and queues
+    /// in the outbox otherwise.  Repeated offline edits of the same record
+    /// coalesce into the existing outbox entry so the replay sends one write
+    /// per record instead of a stale chain.
+    pub fn edit(&mut self, record_id: u64, body: impl Into<String>) {
+        let body = body.into();
+        let Some(record) = self
+            .records
+            .iter_mut()
+            .find(|record| record.id == record_id)
+        else {
+            return;
+        };
+        record.body = body.clone();
+        let base_revision = record.revision;
+
+        match self.connectivity {
+            ConnectivityState::Online => {
+                // Online writes surface conflicts the same way replays do so
+                // the adapters only need one resolution flow.
+                let mutation = PendingMutation {
+                    queued_id: self.next_queue_id,
+                    record_id,
+                    body,
+                    base_revision,
+                };
+                self.next_queue_id += 1;
+                self.apply_mutation(mutation);
+            }
+            _ => {
+                if let Some(pending) = self
+                    .outbox
+                    .iter_mut()
+                    .find(|pending| pending.record_id == record_id)
+                {
+                    pending.body = body;
+                } else {
+                    self.outbox.push_back(PendingMutation {
+                        queued_id: self.next_queue_id,
+                        record_id,
+                        body,
+                        base_revision,
+                    });
+                    self.next_queue_id += 1;
+                }
+                self.snackbar
+                    .enqueue(SyncEvent::QueuedOffline { record_id });
+            }
+        }
+    }
+
+    /// Regain connectivity and replay the outbox in order.  Conflicting
+    /// mutations are set aside for [`resolve_conflict`](Self::resolve_conflict)
+    /// rather than blocking the rest of the queue.
+    pub fn reconnect(&mut self) {
+        if self.connectivity == ConnectivityState::Online {
+            return;
+        }
+        self.connectivity = ConnectivityState::Reconnecting;
+
+        let mut synced = 0;
+        while let Some(mutation) = self.outbox.pop_front() {
+            if self.apply_mutation(mutation) {
+                synced += 1;
+            }
+        }
+
+        self.connectivity = ConnectivityState::Online;
+        self.snackbar.enqueue(SyncEvent::SyncComplete { synced });
+    }
+
+    /// Resolve a pending conflict.
+    pub fn resolve_conflict(&mut self, record_id: u64, choice: ConflictChoice) {
+        let Some(position) = self
+            .conflicts
+            .iter()
+            .position(|conflict| conflict.record_id == record_id)
+        else {
+            return;
+        };
+        let conflict = self.conflicts.remove(position);
+
+        match choice {
+            ConflictChoice::KeepLocal => {
+                // Re-submit the offline edit on top of the revision we lost
+                // to; the remote store accepts it as an ordinary update.
+                let mutation = PendingMutation {
+                    queued_id: self.next_queue_id,
+                    record_id,
+                    body: conflict.local_body.clone(),
+                    base_revision: conflict.remote_revision,
+                };
+                self.next_queue_id += 1;
+                let revision = self
+                    .remote
+                    .push(&mutation)
+                    .expect("resolution targets the latest remote revision");
+                if let Some(record) = self
+                    .records
+                    .iter_mut()
+                    .find(|record| record.id == record_id)
+                {
+                    record.body = conflict.local_body;
+                    record.revision = revision;
+                }
+            }
+            ConflictChoice::TakeRemote => {
+                if let Some(record) = self
+                    .records
+                    .iter_mut()
+                    .find(|record| record.id == record_id)
+                {
+                    record.body = conflict.remote_body;
+                    record.revision = conflict.remote_revision;
+                }
+            }
+        }
+        self.snackbar
+            .enqueue(SyncEvent::ConflictResolved { record_id });
+    }
+
+    /// Push one mutation, recording a conflict on a stale revision.
+    /// Returns whether the write landed cleanly.
+    fn apply_mutation(&mut self, mutation: PendingMutation) -> bool {
+        match self.remote.push(&mutation) {
+            Ok(revision) => {
+                if let Some(record) = self
+                    .records
+                    .iter_mut()
+                    .find(|record| record.id == mutation.record_id)
+                {
+                    record.revision = revision;
+                }
+                true
+            }
+            Err(remote) => {
+                self.conflicts.push(SyncConflict {
+                    record_id: mutation.record_id,
+                    local_body: mutation.body,
+                    remote_body: remote.remote_body,
+                    remote_revision: remote.remote_revision,
+                });
+                self.snackbar.enqueue(SyncEvent::ConflictDetected {
+                    record_id: mutation.record_id,
+                });
+                false
+            }
+        }
+    }
+}
+
+impl Default for OfflineApp {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Seed notes shared by every adapter.
+pub fn seed_notes() -> Vec<NoteRecord> {
+    [
+        (1, "Standup notes", "Review sprint goals"),
+        (2, "Release checklist", "Verify changelog entries"),
+        (3, "Retro actions", "Schedule pairing sessions"),
+    ]
+    .into_iter()
+    .map(|(id, title, body)| NoteRecord {
+        id,
+        title,
+        body: body.to_string(),
+        revision: 1,
+    })
+    .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn online_edits_push_immediately() {
+        let mut app = OfflineApp::new();
+        app.edit(1, "Updated goals");
+        assert!(app.outbox().is_empty());
+        assert_eq!(app.records()[0].revision, 2);
+    }
+
+    #[test]
+    fn offline_edits_queue_and_notify() {
+        let mut app = OfflineApp::new();
+        app.go_offline();
+        app.edit(1, "Draft while offline");
+        app.edit(2, "Another draft");
+
+        assert_eq!(app.outbox().len(), 2);
+        assert_eq!(app.records()[0].body, "Draft while offline");
+        // WentOffline shows immediately; the two queue notices wait behind it.
+        assert_eq!(
+            app.snackbar().current().map(|m| m.payload.clone()),
+            Some(SyncEvent::WentOffline)
+        );
+        assert_eq!(app.snackbar().queue_len(), 2);
+    }
+
+    #[test]
+    fn repeated_offline_edits_coalesce_and_replay_cleanly() {
+        let mut app = OfflineApp::new();
+        app.go_offline();
+        app.edit(1, "First");
+        app.edit(1, "Second");
+        assert_eq!(app.outbox().len(), 1);
+
+        app.reconnect();
+        assert!(app.outbox().is_empty());
+        assert!(app.conflicts().is_empty());
+        assert_eq!(app.connectivity(), ConnectivityState::Online);
+        assert_eq!(app.records()[0].body, "Second");
+        assert_eq!(app.records()[0].revision, 2);
+    }
+
+    #[test]
+    fn remote_edit_during_outage_produces_a_conflict() {
+        let mut app = OfflineApp::new();
+        app.go_offline();
+        app.edit(1, "Local body");
+        app.remote_mut().edit_remotely(1, "Remote body");
+        app.reconnect();
+
+        let conflict = &app.conflicts()[0];
+        assert_eq!(conflict.local_body, "Local body");
+        assert_eq!(conflict.remote_body, "Remote body");
+    }
+
+    #[test]
+    fn keep_local_resubmits_over_the_remote_revision() {
+        let mut app = OfflineApp::new();
+        app.go_offline();
+        app.edit(1, "Local body");
+        app.remote_mut().edit_remotely(1, "Remote body");
+        app.reconnect();
+
+        app.resolve_conflict(1, ConflictChoice::KeepLocal);
+        assert!(app.conflicts().is_empty());
+        assert_eq!(app.records()[0].body, "Local body");
+        assert_eq!(app.records()[0].revision, 3);
+    }
+
+    #[test]
+    fn take_remote_adopts_the_server_copy() {
+        let mut app = OfflineApp::new();
+        app.go_offline();
+        app.edit(1, "Local body");
+        app.remote_mut().edit_remotely(1, "Remote body");
+        app.reconnect();
+
+        app.resolve_conflict(1, ConflictChoice::TakeRemote);
+        assert_eq!(app.records()[0].body, "Remote body");
+        assert_eq!(app.records()[0].revision, 2);
+    }
+}
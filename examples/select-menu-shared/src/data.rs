@@ -0,0 +1,260 @@
+//! Pluggable async data sources backing the select menu examples.
+//!
+//! Earlier iterations of the demos fetched a hard-coded region array which
+//! meant every framework adapter could only ever show the happy path.  This
+//! module models the remote catalog behind a [`RegionSource`] trait so the
+//! examples can demonstrate the full lifecycle enterprises actually ship:
+//!
+//! * **Pagination** — sources hand back [`RegionPage`]s with an explicit
+//!   `next_page` cursor instead of a single monolithic vector.
+//! * **Simulated failures** — [`FlakyRegionSource`] wraps any source and fails
+//!   a configurable number of calls so loading spinners, retry toasts and
+//!   error banners can be exercised deterministically in tests and demos.
+//! * **Exponential backoff** — [`load_regions`] drives a source to completion
+//!   under a [`RetryPolicy`], surfacing the terminal [`SelectDataPhase`]
+//!   (ready/empty/failed) that adapters translate into markup.
+
+use std::fmt;
+
+use crate::{builtin_regions, sleep_ms, Region};
+
+/// One page of regions returned by a [`RegionSource`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RegionPage {
+    /// Records contained in this page, in catalog order.
+    pub regions: Vec<Region>,
+    /// Cursor of the next page, or `None` when the catalog is exhausted.
+    pub next_page: Option<usize>,
+}
+
+/// Error surfaced by a failed fetch.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RegionFetchError {
+    /// Human readable description rendered in error banners.
+    pub message: String,
+    /// Whether the retry loop should attempt the call again.  Non-retryable
+    /// errors (authorization failures, malformed requests) short-circuit the
+    /// backoff loop immediately.
+    pub retryable: bool,
+}
+
+impl RegionFetchError {
+    /// Convenience constructor for transient errors worth retrying.
+    pub fn transient(message: impl Into<String>) -> Self {
+        Self {
+            message: message.into(),
+            retryable: true,
+        }
+    }
+
+    /// Convenience constructor for terminal errors.
+    pub fn fatal(message: impl Into<String>) -> Self {
+        Self {
+            message: message.into(),
+            retryable: false,
+        }
+    }
+}
+
+impl fmt::Display for RegionFetchError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+impl std::error::Error for RegionFetchError {}
+
+/// Abstraction over the remote region catalog.
+///
+/// Sources take `&mut self` so wrappers like [`FlakyRegionSource`] can keep
+/// per-call bookkeeping (failure budgets, request counters) without interior
+/// mutability.  The examples run single threaded (wasm or a current-thread
+/// tokio runtime) so the returned futures do not need to be `Send`.
+#[allow(async_fn_in_trait)]
+pub trait RegionSource {
+    /// Fetch the page identified by the zero based `page` cursor.
+    async fn fetch_page(&mut self, page: usize) -> Result<RegionPage, RegionFetchError>;
+}
+
+/// In-memory source serving a fixed catalog with simulated latency.
+#[derive(Clone, Debug)]
+pub struct StaticRegionSource {
+    regions: Vec<Region>,
+    page_size: usize,
+}
+
+impl StaticRegionSource {
+    /// Source backed by the built-in demo catalog.
+    pub fn new(page_size: usize) -> Self {
+        Self::with_regions(builtin_regions(), page_size)
+    }
+
+    /// Source backed by an arbitrary catalog.  Passing an empty vector lets
+    /// demos exercise the empty state without a bespoke source type.
+    pub fn with_regions(regions: Vec<Region>, page_size: usize) -> Self {
+        Self {
+            regions,
+            page_size: page_size.max(1),
+        }
+    }
+}
+
+impl RegionSource for StaticRegionSource {
+    async fn fetch_page(&mut self, page: usize) -> Result<RegionPage, RegionFetchError> {
+        sleep_ms(40).await;
+        let start = page.saturating_mul(self.page_size);
+        if start >= self.regions.len() {
+            return Ok(RegionPage {
+                regions: Vec::new(),
+                next_page: None,
+            });
+        }
+        let end = (start + self.page_size).min(self.regions.len());
+        Ok(RegionPage {
+            regions: self.regions[start..end].to_vec(),
+            next_page: (end < self.regions.len()).then_some(page + 1),
+        })
+    }
+}
+
+/// Wrapper failing the first `failures` calls before delegating to the inner
+/// source.  Deterministic by construction so demos and tests can script the
+/// exact number of retries a flow observes.
+#[derive(Clone, Debug)]
+pub struct FlakyRegionSource<S> {
+    inner: S,
+    failures_remaining: usize,
+    /// Total calls observed, exposed for assertions on retry behaviour.
+    calls: usize,
+}
+
+impl<S> FlakyRegionSource<S> {
+    /// Fail the first `failures` fetches with a transient error.
+    pub fn new(inner: S, failures: usize) -> Self {
+        Self {
+            inner,
+            failures_remaining: failures,
+            calls: 0,
+        }
+    }
+
+    /// Number of fetches attempted against this source so far.
+    #[inline]
+    pub fn calls(&self) -> usize {
+        self.calls
+    }
+}
+
+impl<S: RegionSource> RegionSource for FlakyRegionSource<S> {
+    async fn fetch_page(&mut self, page: usize) -> Result<RegionPage, RegionFetchError> {
+        self.calls += 1;
+        if self.failures_remaining > 0 {
+            self.failures_remaining -= 1;
+            return Err(RegionFetchError::transient(
+                "simulated region catalog outage",
+            ));
+        }
+        self.inner.fetch_page(page).await
+    }
+}
+
+/// Exponential backoff schedule applied between retries.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct RetryPolicy {
+    /// Maximum attempts per page, including the initial call.
+    pub max_attempts: usize,
+    /// Delay before the first retry; subsequent retries double it.
+    pub base_delay_ms: u64,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 3,
+            base_delay_ms: 50,
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Backoff delay applied after the given failed attempt (1-based), i.e.
+    /// `base`, `2 * base`, `4 * base`, … capped by the shift width.
+    #[inline]
+    pub fn delay_for(&self, failed_attempt: usize) -> u64 {
+        let exponent = failed_attempt.saturating_sub(1).min(16) as u32;
+        self.base_delay_ms.saturating_mul(1 << exponent)
+    }
+}
+
+/// Terminal outcome of a catalog load, mirrored by the example adapters as
+/// loading spinner → (options | empty notice | error banner).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SelectDataPhase {
+    /// No load has been started yet.
+    Idle,
+    /// A load is in flight; adapters render their spinner.
+    Loading,
+    /// The catalog resolved with at least one region.
+    Ready(Vec<Region>),
+    /// The catalog resolved but contained no regions.
+    Empty,
+    /// Every retry was exhausted (or a fatal error occurred).
+    Failed(String),
+}
+
+impl SelectDataPhase {
+    /// Regions when the phase is [`SelectDataPhase::Ready`].
+    pub fn regions(&self) -> Option<&[Region]> {
+        match self {
+            Self::Ready(regions) => Some(regions),
+            _ => None,
+        }
+    }
+}
+
+/// Drain every page from `source`, retrying transient failures with
+/// exponential backoff, and collapse the outcome into a [`SelectDataPhase`].
+///
+/// The function never returns [`SelectDataPhase::Idle`]/`Loading`; callers
+/// flip their own state to `Loading` before awaiting so the UI reflects the
+/// in-flight request.
+pub async fn load_regions<S: RegionSource>(source: &mut S, policy: RetryPolicy) -> SelectDataPhase {
+    let mut regions = Vec::new();
+    let mut page = 0usize;
+    loop {
+        match fetch_page_with_retry(source, page, policy).await {
+            Ok(mut fetched) => {
+                regions.append(&mut fetched.regions);
+                match fetched.next_page {
+                    Some(next) => page = next,
+                    None => break,
+                }
+            }
+            Err(error) => return SelectDataPhase::Failed(error.message),
+        }
+    }
+    if regions.is_empty() {
+        SelectDataPhase::Empty
+    } else {
+        SelectDataPhase::Ready(regions)
+    }
+}
+
+/// Fetch a single page, honouring the retry policy for transient failures.
+async fn fetch_page_with_retry<S: RegionSource>(
+    source: &mut S,
+    page: usize,
+    policy: RetryPolicy,
+) -> Result<RegionPage, RegionFetchError> {
+    let mut attempt = 0usize;
+    loop {
+        attempt += 1;
+        match source.fetch_page(page).await {
+            Ok(fetched) => return Ok(fetched),
+            Err(error) if error.retryable && attempt < policy.max_attempts.max(1) => {
+                sleep_ms(policy.delay_for(attempt)).await;
+            }
+            Err(error) => return Err(error),
+        }
+    }
+}
@@ -6,6 +6,16 @@
 //! examples focused on framework specific wiring while still demonstrating how
 //! enterprises can share core behaviour across SSR and CSR entry points.
 
+pub mod data;
+
+pub use data::{
+    load_regions, FlakyRegionSource, RegionFetchError, RegionPage, RegionSource, RetryPolicy,
+    SelectDataPhase, StaticRegionSource,
+};
+
+use rustic_ui_headless::interaction::ControlKey;
+use rustic_ui_headless::select::SelectState;
+use rustic_ui_headless::ControlStrategy;
 use rustic_ui_material::select::{SelectOption, SelectProps};
 use rustic_ui_system::theme::{ColorScheme, Theme};
 
@@ -20,22 +30,48 @@ pub const AUTOMATION_ID: &str = "rusticui-select-menu";
 const COMPONENT_PREFIX: &str = "rustic-select";
 
 /// Domain model representing an option returned from the mock async API.
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq, Eq)]
 pub struct Region {
     /// Short code used when persisting the value to backends.
-    pub code: &'static str,
+    pub code: String,
     /// Human readable label shown inside the select popover.
-    pub name: &'static str,
+    pub name: String,
 }
 
-/// Fetch a list of regions with simulated network latency.
+impl Region {
+    /// Convenience constructor mirroring `SelectOption::new`.
+    pub fn new(code: impl Into<String>, name: impl Into<String>) -> Self {
+        Self {
+            code: code.into(),
+            name: name.into(),
+        }
+    }
+}
+
+/// The built-in demo catalog served by [`StaticRegionSource::new`].
 ///
-/// The helper yields to the current runtime (wasm timers in the browser and
-/// `tokio` on the server) so examples can exercise async loading spinners and
-/// controlled state updates once data arrives.
+/// Exposed so tests and adapters that only need the happy path can skip the
+/// async plumbing entirely.
+pub fn builtin_regions() -> Vec<Region> {
+    BUILTIN_REGIONS
+        .iter()
+        .map(|(code, name)| Region::new(*code, *name))
+        .collect()
+}
+
+/// Fetch the full region catalog with simulated network latency.
+///
+/// Happy-path shortcut retained for adapters that predate [`RegionSource`]:
+/// it drives a [`StaticRegionSource`] through [`load_regions`] with the
+/// default [`RetryPolicy`] and flattens the terminal phase into a vector
+/// (empty on failure).  New code should consume [`load_regions`] directly so
+/// loading, empty and error states stay observable.
 pub async fn fetch_regions() -> Vec<Region> {
-    wait_for_data().await;
-    REGIONS.iter().cloned().collect()
+    let mut source = StaticRegionSource::new(3);
+    match load_regions(&mut source, RetryPolicy::default()).await {
+        SelectDataPhase::Ready(regions) => regions,
+        _ => Vec::new(),
+    }
 }
 
 /// Convert the domain records into `SelectOption`s understood by
@@ -43,12 +79,16 @@ pub async fn fetch_regions() -> Vec<Region> {
 pub fn to_select_options(regions: &[Region]) -> Vec<SelectOption> {
     regions
         .iter()
-        .map(|region| SelectOption::new(region.name, region.code))
+        .map(|region| SelectOption::new(region.name.clone(), region.code.clone()))
         .collect()
 }
 
 /// Build select props with a consistent automation identifier.
-pub fn props_from_options(label: &str, automation_id: &str, options: &[SelectOption]) -> SelectProps {
+pub fn props_from_options(
+    label: &str,
+    automation_id: &str,
+    options: &[SelectOption],
+) -> SelectProps {
     let mut props = SelectProps::new(label, options.to_vec());
     props.automation_id = Some(automation_id.to_string());
     props
@@ -72,13 +112,235 @@ pub fn enterprise_theme() -> Theme {
     theme
 }
 
+/// Keyboard input understood by [`SelectInteraction::handle_key`].
+///
+/// The variants mirror the DOM `KeyboardEvent.key` values the framework
+/// adapters listen for, so wiring an event handler is a straight `match` over
+/// `event.key()` instead of duplicated key-code parsing in every example.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SelectKey {
+    /// `ArrowDown` — move the highlight forward (opens a closed listbox).
+    ArrowDown,
+    /// `ArrowUp` — move the highlight backward (opens a closed listbox).
+    ArrowUp,
+    /// `Home` — jump the highlight to the first enabled option.
+    Home,
+    /// `End` — jump the highlight to the last enabled option.
+    End,
+    /// `Enter` — commit the highlighted option and collapse the popover.
+    Enter,
+    /// `Space` — same commit semantics as [`SelectKey::Enter`].
+    Space,
+    /// `Escape` — collapse the popover without changing the selection.
+    Escape,
+    /// Any printable character routed into the typeahead buffer.
+    Char(char),
+}
+
+/// Bridges the headless [`SelectState`] machine with the example markup.
+///
+/// The examples historically cycled the selection with a button click which
+/// never exercised the keyboard orchestration the headless crate ships.  This
+/// wrapper owns an uncontrolled `SelectState` plus the option labels so
+/// adapters can forward raw key presses and re-render from the returned
+/// snapshot: highlight for `aria-activedescendant`, open flag for the popover
+/// and the committed selection for the summary line.
+#[derive(Debug, Clone)]
+pub struct SelectInteraction {
+    state: SelectState,
+    labels: Vec<String>,
+}
+
+impl SelectInteraction {
+    /// Build an interaction helper for the given props.  The state machine is
+    /// uncontrolled on both axes because the examples render directly from it.
+    pub fn new(props: &SelectProps) -> Self {
+        Self {
+            state: SelectState::new(
+                props.options.len(),
+                None,
+                false,
+                ControlStrategy::Uncontrolled,
+                ControlStrategy::Uncontrolled,
+            ),
+            labels: props
+                .options
+                .iter()
+                .map(|option| option.label.clone())
+                .collect(),
+        }
+    }
+
+    /// Whether the listbox popover is currently expanded.
+    #[inline]
+    pub fn is_open(&self) -> bool {
+        self.state.is_open()
+    }
+
+    /// Index of the option the keyboard highlight currently rests on.
+    #[inline]
+    pub fn highlighted(&self) -> Option<usize> {
+        self.state.highlighted()
+    }
+
+    /// Index of the committed selection, if any.
+    #[inline]
+    pub fn selected(&self) -> Option<usize> {
+        self.state.selected()
+    }
+
+    /// Expand the popover (e.g. trigger click).
+    pub fn open(&mut self) {
+        self.state.open(|_| {});
+    }
+
+    /// Collapse the popover without touching the selection.
+    pub fn close(&mut self) {
+        self.state.close(|_| {});
+    }
+
+    /// Toggle the popover, mirroring a trigger click.
+    pub fn toggle(&mut self) {
+        self.state.toggle(|_| {});
+    }
+
+    /// DOM id of the highlighted option, suitable for `aria-activedescendant`.
+    ///
+    /// Only meaningful while the popover is open; a collapsed listbox has no
+    /// active descendant so `None` is returned to keep the attribute off the
+    /// markup entirely.
+    pub fn active_descendant_id(&self, props: &SelectProps) -> Option<String> {
+        if !self.state.is_open() {
+            return None;
+        }
+        self.state.highlighted().map(|index| {
+            automation_value(props.automation_id.as_deref(), [format!("option-{index}")])
+        })
+    }
+
+    /// Route a key press through the headless state machine.
+    ///
+    /// Returns the index committed by this key press (via `Enter`/`Space` or a
+    /// typeahead match) so callers can persist the value without diffing
+    /// snapshots.  Navigation keys only move the highlight and return `None`.
+    pub fn handle_key(&mut self, key: SelectKey) -> Option<usize> {
+        match key {
+            SelectKey::Escape => {
+                self.state.close(|_| {});
+                None
+            }
+            SelectKey::ArrowDown | SelectKey::ArrowUp if !self.state.is_open() => {
+                // Per the WAI-ARIA pattern, arrows on a collapsed trigger
+                // expand the popover with the highlight untouched.
+                self.state.open(|_| {});
+                None
+            }
+            SelectKey::Enter | SelectKey::Space if !self.state.is_open() => {
+                self.state.open(|_| {});
+                None
+            }
+            SelectKey::Enter | SelectKey::Space => {
+                let mut committed = None;
+                self.state
+                    .on_key(ControlKey::Enter, |index| committed = Some(index));
+                if committed.is_some() {
+                    self.state.close(|_| {});
+                }
+                committed
+            }
+            SelectKey::ArrowDown => {
+                self.state.on_key(ControlKey::ArrowDown, |_| {});
+                None
+            }
+            SelectKey::ArrowUp => {
+                self.state.on_key(ControlKey::ArrowUp, |_| {});
+                None
+            }
+            SelectKey::Home => {
+                self.state.on_key(ControlKey::Home, |_| {});
+                None
+            }
+            SelectKey::End => {
+                self.state.on_key(ControlKey::End, |_| {});
+                None
+            }
+            SelectKey::Char(ch) => {
+                let labels = self.labels.clone();
+                let mut committed = None;
+                self.state.on_typeahead(
+                    ch,
+                    move |query, highlighted, count| {
+                        typeahead_match(&labels, query, highlighted, count)
+                    },
+                    |index| committed = Some(index),
+                );
+                committed
+            }
+        }
+    }
+}
+
+/// Case-insensitive prefix matcher used by the typeahead buffer.
+///
+/// Mirrors native `<select>` behaviour: the search starts *after* the current
+/// highlight and wraps, so repeatedly typing the same letter cycles through
+/// every option sharing that prefix.
+fn typeahead_match(
+    labels: &[String],
+    query: &str,
+    highlighted: Option<usize>,
+    count: usize,
+) -> Option<usize> {
+    if count == 0 || query.is_empty() {
+        return None;
+    }
+    let needle = query.to_lowercase();
+    let start = highlighted.map(|index| index + 1).unwrap_or(0);
+    (0..count)
+        .map(|offset| (start + offset) % count)
+        .find(|&index| {
+            labels
+                .get(index)
+                .map(|label| label.to_lowercase().starts_with(&needle))
+                .unwrap_or(false)
+        })
+}
+
 /// Render Material inspired markup for the select trigger and option list.
 ///
-/// The helper keeps the HTML consistent across SSR and CSR entry points without
-/// pulling in the private `ControlStrategy` types from `rustic_ui_headless`.
+/// Thin wrapper over [`render_select_markup_with_interaction`] preserved for
+/// callers that manage state manually; the highlight mirrors the selection so
+/// the markup stays stable for the legacy click-to-cycle flows.
 pub fn render_select_markup(props: &SelectProps, open: bool, selected: Option<usize>) -> String {
+    render_markup(props, open, selected, selected)
+}
+
+/// Render the select markup from a live [`SelectInteraction`].
+///
+/// On top of [`render_select_markup`] this surfaces the keyboard highlight:
+/// the listbox carries `aria-activedescendant` pointing at the highlighted
+/// option id and each option exposes a `data-highlighted` flag so automation
+/// suites (and CSS) can observe roving focus without a real DOM focus model.
+pub fn render_select_markup_with_interaction(
+    props: &SelectProps,
+    interaction: &SelectInteraction,
+) -> String {
+    render_markup(
+        props,
+        interaction.is_open(),
+        interaction.selected(),
+        interaction.highlighted(),
+    )
+}
+
+fn render_markup(
+    props: &SelectProps,
+    open: bool,
+    selected: Option<usize>,
+    highlighted: Option<usize>,
+) -> String {
     let user_id = props.automation_id.as_deref();
-    let base = automation_value(user_id, []);
+    let base = automation_value(user_id, None::<String>);
     let trigger_id = automation_value(user_id, ["trigger"]);
     let list_id = automation_value(user_id, ["list"]);
     let open_flag = open.then_some("true").unwrap_or("false");
@@ -97,22 +359,41 @@ pub fn render_select_markup(props: &SelectProps, open: bool, selected: Option<us
         automation_value(user_id, ["list"])
     );
 
+    // Only an expanded listbox has an active descendant; omitting the
+    // attribute while closed keeps screen readers from announcing a stale
+    // option the next time the popover opens.
+    let active_descendant = if open {
+        highlighted
+            .map(|index| {
+                format!(
+                    " aria-activedescendant=\"{}\"",
+                    automation_value(user_id, [format!("option-{index}")])
+                )
+            })
+            .unwrap_or_default()
+    } else {
+        String::new()
+    };
+
     let mut options_markup = String::new();
     for (index, option) in props.options.iter().enumerate() {
         let is_selected = selected == Some(index);
         let selected_flag = is_selected.then_some("true").unwrap_or("false");
+        let highlighted_flag = (highlighted == Some(index))
+            .then_some("true")
+            .unwrap_or("false");
         let option_marker = automation_value(user_id, [format!("option-{index}")]);
         let option_id = option_marker.clone();
         let automation_option = format!(" data-rustic-select-option=\"{option_marker}\"");
         options_markup.push_str(&format!(
-            "<li id=\"{option_id}\" role=\"option\" aria-selected=\"{selected_flag}\" data-selected=\"{selected_flag}\" data-index=\"{index}\" data-value=\"{}\"{automation_option}>{}</li>",
+            "<li id=\"{option_id}\" role=\"option\" aria-selected=\"{selected_flag}\" data-selected=\"{selected_flag}\" data-highlighted=\"{highlighted_flag}\" data-index=\"{index}\" data-value=\"{}\"{automation_option}>{}</li>",
             option.value,
             option.label
         ));
     }
 
     format!(
-        "<div class=\"rustic_ui_select_root\" data-component=\"rustic-select\" data-open=\"{open_flag}\"{automation_root}{automation_root_marker}><button id=\"{trigger_id}\" role=\"button\" aria-haspopup=\"listbox\" aria-expanded=\"{open_flag}\" aria-controls=\"{list_id}\" data-open=\"{open_flag}\"{automation_trigger}>{}</button><ul id=\"{list_id}\" role=\"listbox\" aria-hidden=\"{}\" data-open=\"{open_flag}\"{automation_list}>{options_markup}</ul></div>",
+        "<div class=\"rustic_ui_select_root\" data-component=\"rustic-select\" data-open=\"{open_flag}\"{automation_root}{automation_root_marker}><button id=\"{trigger_id}\" role=\"button\" aria-haspopup=\"listbox\" aria-expanded=\"{open_flag}\" aria-controls=\"{list_id}\" data-open=\"{open_flag}\"{automation_trigger}>{}</button><ul id=\"{list_id}\" role=\"listbox\" aria-hidden=\"{}\" data-open=\"{open_flag}\"{active_descendant}{automation_list}>{options_markup}</ul></div>",
         props.label,
         (!open).then_some("true").unwrap_or("false")
     )
@@ -225,47 +506,199 @@ mod tests {
         assert!(html.contains("data-rustic-select-trigger=\"rustic-select-custom-id-trigger\""));
         assert!(html.contains("data-rustic-select-list=\"rustic-select-custom-id-list\""));
         assert!(html.contains("data-rustic-select-option=\"rustic-select-custom-id-option-0\""));
+        // A closed listbox must not advertise an active descendant.
+        assert!(!html.contains("aria-activedescendant"));
+    }
+
+    fn region_props() -> SelectProps {
+        props_from_options(
+            "Region",
+            AUTOMATION_ID,
+            &to_select_options(&builtin_regions()),
+        )
+    }
+
+    /// Minimal single threaded executor for the data source tests.  Without
+    /// the `csr`/`ssr` features every `sleep_ms` resolves immediately, so a
+    /// busy poll loop with a no-op waker is sufficient.
+    fn block_on<F: std::future::Future>(mut future: F) -> F::Output {
+        use std::task::{Context, Poll, RawWaker, RawWakerVTable, Waker};
+
+        fn noop_raw_waker() -> RawWaker {
+            fn clone(_: *const ()) -> RawWaker {
+                noop_raw_waker()
+            }
+            fn noop(_: *const ()) {}
+            RawWaker::new(
+                std::ptr::null(),
+                &RawWakerVTable::new(clone, noop, noop, noop),
+            )
+        }
+
+        let waker = unsafe { Waker::from_raw(noop_raw_waker()) };
+        let mut context = Context::from_waker(&waker);
+        let mut future = unsafe { std::pin::Pin::new_unchecked(&mut future) };
+        loop {
+            if let Poll::Ready(output) = future.as_mut().poll(&mut context) {
+                return output;
+            }
+        }
+    }
+
+    #[test]
+    fn static_source_paginates_the_catalog() {
+        let phase = block_on(async {
+            let mut source = StaticRegionSource::new(2);
+            load_regions(&mut source, RetryPolicy::default()).await
+        });
+        assert_eq!(phase.regions().map(<[Region]>::len), Some(6));
+        assert_eq!(phase.regions().unwrap()[2].code, "eu-central-1");
+    }
+
+    #[test]
+    fn empty_catalog_resolves_to_the_empty_phase() {
+        let phase = block_on(async {
+            let mut source = StaticRegionSource::with_regions(Vec::new(), 3);
+            load_regions(&mut source, RetryPolicy::default()).await
+        });
+        assert_eq!(phase, SelectDataPhase::Empty);
+    }
+
+    #[test]
+    fn transient_failures_are_retried_with_backoff() {
+        let mut source = FlakyRegionSource::new(StaticRegionSource::new(10), 2);
+        let phase = block_on(load_regions(&mut source, RetryPolicy::default()));
+        assert!(matches!(phase, SelectDataPhase::Ready(_)));
+        // Two failed attempts plus the successful third call.
+        assert_eq!(source.calls(), 3);
+    }
+
+    #[test]
+    fn exhausted_retries_surface_the_failure() {
+        let mut source = FlakyRegionSource::new(StaticRegionSource::new(10), 5);
+        let phase = block_on(load_regions(&mut source, RetryPolicy::default()));
+        assert_eq!(
+            phase,
+            SelectDataPhase::Failed("simulated region catalog outage".into())
+        );
+        assert_eq!(source.calls(), RetryPolicy::default().max_attempts);
+    }
+
+    #[test]
+    fn retry_delays_grow_exponentially() {
+        let policy = RetryPolicy {
+            max_attempts: 4,
+            base_delay_ms: 50,
+        };
+        assert_eq!(policy.delay_for(1), 50);
+        assert_eq!(policy.delay_for(2), 100);
+        assert_eq!(policy.delay_for(3), 200);
+    }
+
+    #[test]
+    fn arrow_keys_open_and_move_the_highlight() {
+        let props = region_props();
+        let mut interaction = SelectInteraction::new(&props);
+
+        // First ArrowDown only expands the popover; the highlight stays put.
+        assert_eq!(interaction.handle_key(SelectKey::ArrowDown), None);
+        assert!(interaction.is_open());
+        assert_eq!(interaction.highlighted(), Some(0));
+
+        interaction.handle_key(SelectKey::ArrowDown);
+        interaction.handle_key(SelectKey::ArrowDown);
+        assert_eq!(interaction.highlighted(), Some(2));
+
+        interaction.handle_key(SelectKey::End);
+        assert_eq!(interaction.highlighted(), Some(builtin_regions().len() - 1));
+        interaction.handle_key(SelectKey::Home);
+        assert_eq!(interaction.highlighted(), Some(0));
+    }
+
+    #[test]
+    fn enter_commits_the_highlight_and_collapses() {
+        let props = region_props();
+        let mut interaction = SelectInteraction::new(&props);
+
+        interaction.handle_key(SelectKey::ArrowDown);
+        interaction.handle_key(SelectKey::ArrowDown);
+        let committed = interaction.handle_key(SelectKey::Enter);
+
+        assert_eq!(committed, Some(1));
+        assert_eq!(interaction.selected(), Some(1));
+        assert!(!interaction.is_open());
+    }
+
+    #[test]
+    fn escape_collapses_without_touching_the_selection() {
+        let props = region_props();
+        let mut interaction = SelectInteraction::new(&props);
+
+        interaction.handle_key(SelectKey::ArrowDown);
+        interaction.handle_key(SelectKey::ArrowDown);
+        interaction.handle_key(SelectKey::Enter);
+        interaction.handle_key(SelectKey::ArrowDown);
+        interaction.handle_key(SelectKey::Escape);
+
+        assert!(!interaction.is_open());
+        assert_eq!(interaction.selected(), Some(1));
+    }
+
+    #[test]
+    fn typeahead_jumps_to_matching_label() {
+        let props = region_props();
+        let mut interaction = SelectInteraction::new(&props);
+        interaction.open();
+
+        // "EU Central (Frankfurt)" is the only label starting with "e".
+        let committed = interaction.handle_key(SelectKey::Char('e'));
+        assert_eq!(committed, Some(2));
+        assert_eq!(interaction.highlighted(), Some(2));
+    }
+
+    #[test]
+    fn open_markup_exposes_active_descendant_and_highlight() {
+        let props = region_props();
+        let mut interaction = SelectInteraction::new(&props);
+        interaction.handle_key(SelectKey::ArrowDown);
+        interaction.handle_key(SelectKey::ArrowDown);
+
+        let html = render_select_markup_with_interaction(&props, &interaction);
+        let expected_id = example_automation_value(["option-1"]);
+        assert!(html.contains(&format!(" aria-activedescendant=\"{expected_id}\"")));
+        assert!(html.contains("data-highlighted=\"true\""));
+
+        interaction.handle_key(SelectKey::Escape);
+        let html = render_select_markup_with_interaction(&props, &interaction);
+        assert!(!html.contains("aria-activedescendant"));
     }
 }
 
+/// Yield to the active runtime for `ms` milliseconds.
+///
+/// Wasm builds use `gloo` timers, server builds use `tokio`, and the bare
+/// configuration (unit tests) resolves immediately so the async plumbing stays
+/// deterministic without an executor.
 #[cfg(feature = "csr")]
-async fn wait_for_data() {
+pub(crate) async fn sleep_ms(ms: u64) {
     use gloo_timers::future::TimeoutFuture;
-    TimeoutFuture::new(120).await;
+    TimeoutFuture::new(ms as u32).await;
 }
 
 #[cfg(all(feature = "ssr", not(feature = "csr")))]
-async fn wait_for_data() {
+pub(crate) async fn sleep_ms(ms: u64) {
     use tokio::time::{sleep, Duration};
-    sleep(Duration::from_millis(120)).await;
+    sleep(Duration::from_millis(ms)).await;
 }
 
 #[cfg(not(any(feature = "csr", feature = "ssr")))]
-async fn wait_for_data() {}
-
-const REGIONS: [Region; 6] = [
-    Region {
-        code: "us-east-1",
-        name: "US East (N. Virginia)",
-    },
-    Region {
-        code: "us-west-2",
-        name: "US West (Oregon)",
-    },
-    Region {
-        code: "eu-central-1",
-        name: "EU Central (Frankfurt)",
-    },
-    Region {
-        code: "ap-southeast-2",
-        name: "AP Southeast (Sydney)",
-    },
-    Region {
-        code: "sa-east-1",
-        name: "South America (São Paulo)",
-    },
-    Region {
-        code: "me-central-1",
-        name: "Middle East (UAE)",
-    },
+pub(crate) async fn sleep_ms(_ms: u64) {}
+
+const BUILTIN_REGIONS: [(&str, &str); 6] = [
+    ("us-east-1", "US East (N. Virginia)"),
+    ("us-west-2", "US West (Oregon)"),
+    ("eu-central-1", "EU Central (Frankfurt)"),
+    ("ap-southeast-2", "AP Southeast (Sydney)"),
+    ("sa-east-1", "South America (São Paulo)"),
+    ("me-central-1", "Middle East (UAE)"),
 ];
@@ -11,6 +11,12 @@
 //! [`SharedOverlayState`] into a UI specific signal/`use_state` handle and call
 //! the intent helpers when user events fire.
 
+pub mod validation;
+
+pub use validation::{
+    RuleOutcome, RuleSet, UniquenessProbe, ValidationError, ValidationReport, ValidationRule,
+};
+
 use std::time::Duration;
 
 use rustic_ui_headless::dialog::{DialogPhase, DialogState, DialogTransition};
@@ -79,6 +85,14 @@ pub struct SharedOverlaySnapshot {
     pub text_field_visited: bool,
     /// Validation errors currently applied to the text field.
     pub text_field_errors: Vec<String>,
+    /// Whether an asynchronous validation rule is still in flight.
+    pub text_field_pending: bool,
+    /// Lifecycle phase for every dialog in the stack, root first.  Nested
+    /// dialogs (e.g. the confirm-on-close prompt) appear after their parent.
+    pub dialog_stack: Vec<DialogPhase>,
+    /// Index into `dialog_stack` of the dialog currently owning the focus
+    /// trap (the top-most dialog with an engaged trap), if any.
+    pub focus_trap_owner: Option<usize>,
 }
 
 impl SharedOverlaySnapshot {
@@ -117,8 +131,12 @@ impl LifecycleLog {
 #[derive(Debug, Clone)]
 pub struct SharedOverlayState {
     dialog: DialogState,
+    /// Nested dialogs layered above the root dialog, inner-most last.
+    nested_dialogs: Vec<DialogState>,
     popover: PopoverState,
     text_field: TextFieldState,
+    rules: RuleSet,
+    validation_pending: bool,
 }
 
 impl SharedOverlayState {
@@ -149,11 +167,31 @@ impl SharedOverlayState {
 
         Self {
             dialog,
+            nested_dialogs: Vec::new(),
             popover,
             text_field,
+            rules: Self::default_rules(),
+            validation_pending: false,
         }
     }
 
+    /// Canonical rule set mirroring the validation copy the demos have always
+    /// shipped.  Kept in one place so every adapter (and the README prose)
+    /// stays in sync.
+    pub fn default_rules() -> RuleSet {
+        RuleSet::new()
+            .required()
+            .min_length(3)
+            .forbid_match("no_punctuation", r"[[:punct:]]")
+    }
+
+    /// Replaces the validation rules, e.g. to add an async uniqueness probe
+    /// or plug in a localized message catalog.
+    pub fn with_rules(mut self, rules: RuleSet) -> Self {
+        self.rules = rules;
+        self
+    }
+
     /// Returns the current snapshot for analytics or read-only rendering.
     pub fn snapshot(&self) -> SharedOverlaySnapshot {
         SharedOverlaySnapshot {
@@ -169,9 +207,37 @@ impl SharedOverlayState {
             text_field_dirty: self.text_field.dirty(),
             text_field_visited: self.text_field.visited(),
             text_field_errors: self.text_field.errors().to_vec(),
+            text_field_pending: self.validation_pending,
+            dialog_stack: self.dialog_phases(),
+            focus_trap_owner: self.focus_trap_owner(),
         }
     }
 
+    /// Lifecycle phases for the whole dialog stack, root dialog first.
+    fn dialog_phases(&self) -> Vec<DialogPhase> {
+        core::iter::once(self.dialog.phase())
+            .chain(self.nested_dialogs.iter().map(|dialog| dialog.phase()))
+            .collect()
+    }
+
+    /// The top-most dialog with an engaged focus trap owns keyboard focus.
+    /// Parents keep their traps configured but cede ownership while a child
+    /// is stacked above them, mirroring how browsers treat nested modals.
+    fn focus_trap_owner(&self) -> Option<usize> {
+        self.nested_dialogs
+            .iter()
+            .enumerate()
+            .rev()
+            .find(|(_, dialog)| dialog.focus_trap_engaged())
+            .map(|(index, _)| index + 1)
+            .or_else(|| self.dialog.focus_trap_engaged().then_some(0))
+    }
+
+    /// Number of dialogs currently stacked (root + nested).
+    pub fn dialog_depth(&self) -> usize {
+        1 + self.nested_dialogs.len()
+    }
+
     /// Immutable accessors used by framework adapters to pull attribute builders.
     pub fn dialog(&self) -> &DialogState {
         &self.dialog
@@ -236,6 +302,74 @@ impl SharedOverlayState {
         (self, log)
     }
 
+    /// Push a nested dialog (for example a confirm-on-close prompt) above the
+    /// current stack.  The new dialog immediately takes focus trap ownership
+    /// while its parents remain open underneath.
+    pub fn push_nested_dialog(mut self) -> (Self, LifecycleLog) {
+        let mut log = LifecycleLog::default();
+        let mut dialog = DialogState::controlled();
+        dialog.set_modal(true);
+        dialog.set_escape_closes(true);
+        let mut desired = false;
+        dialog.open(|next| desired = next);
+        dialog.sync_open(desired);
+        dialog.finish_open();
+        self.nested_dialogs.push(dialog);
+        log.record(format!(
+            "nested dialog opened (depth {}, focus trap owner -> {:?})",
+            self.dialog_depth(),
+            self.focus_trap_owner()
+        ));
+        (self, log)
+    }
+
+    /// Close the top-most nested dialog, returning focus trap ownership to
+    /// the dialog underneath.  A no-op when only the root dialog is open.
+    pub fn pop_nested_dialog(mut self) -> (Self, LifecycleLog) {
+        let mut log = LifecycleLog::default();
+        if let Some(mut dialog) = self.nested_dialogs.pop() {
+            let mut desired = true;
+            dialog.close(|next| desired = next);
+            if !desired {
+                dialog.sync_open(false);
+                dialog.finish_close();
+            }
+            log.record(format!(
+                "nested dialog closed (depth {}, focus trap owner -> {:?})",
+                self.dialog_depth(),
+                self.focus_trap_owner()
+            ));
+        } else {
+            log.record("nested dialog close ignored - stack only holds the root dialog");
+        }
+        (self, log)
+    }
+
+    /// Route an Escape key press through the stack.  Only the top-most dialog
+    /// reacts; propagation stops there so pressing Escape inside the
+    /// confirm-on-close prompt never tears down the underlying dialog.
+    pub fn handle_escape(mut self) -> (Self, LifecycleLog) {
+        let mut log = LifecycleLog::default();
+        if !self.nested_dialogs.is_empty() {
+            log.record("escape captured by top-most nested dialog");
+            let (state, pop_log) = self.pop_nested_dialog();
+            self = state;
+            log.extend(pop_log);
+            return (self, log);
+        }
+        let mut desired = true;
+        let handled = self.dialog.handle_escape(|next| desired = next);
+        if handled && !desired {
+            self.dialog.sync_open(false);
+            self.dialog.finish_close();
+            log.record("escape captured by root dialog");
+            log.record("dialog phase -> closed (focus trap released)");
+        } else {
+            log.record("escape ignored - no dialog open or escape disabled");
+        }
+        (self, log)
+    }
+
     /// Toggle the popover visibility and perform a lightweight collision
     /// resolution that mirrors the SSR logic used in the blueprints.
     pub fn toggle_popover(mut self) -> (Self, LifecycleLog) {
@@ -332,26 +466,14 @@ impl SharedOverlayState {
     }
 
     fn recompute_validation(&mut self) -> Option<String> {
-        let value = self.text_field.value().trim();
-        let mut errors = Vec::new();
-        if value.is_empty() {
-            errors.push("Company name is required.".to_string());
-        }
-        if value.len() < 3 {
-            errors.push("Company name must be at least 3 characters.".to_string());
-        }
-        if value.chars().all(|c| c.is_ascii_alphabetic()) {
-            // Accept purely alphabetic strings; automation users often paste
-            // identifiers containing spaces and digits.
-        } else if value.chars().any(|c| c.is_ascii_punctuation()) {
-            errors.push("Remove punctuation before submitting.".to_string());
-        }
-        if errors.is_empty() {
+        let report = self.rules.evaluate(self.text_field.value());
+        self.validation_pending = report.pending;
+        if report.errors.is_empty() {
             self.text_field.set_errors(Vec::new());
             None
         } else {
-            let joined = errors.join(" ");
-            self.text_field.set_errors(errors);
+            let joined = report.errors.join(" ");
+            self.text_field.set_errors(report.errors);
             Some(joined)
         }
     }
@@ -381,6 +503,49 @@ mod tests {
             .any(|line| line.contains("dialog phase -> closed")));
     }
 
+    #[test]
+    fn nested_dialog_stack_transfers_focus_trap_ownership() {
+        let state = SharedOverlayState::enterprise_defaults();
+        let (state, _) = state.request_dialog_open();
+        assert_eq!(state.snapshot().focus_trap_owner, Some(0));
+
+        let (state, log) = state.push_nested_dialog();
+        let snapshot = state.snapshot();
+        assert_eq!(snapshot.dialog_stack.len(), 2);
+        assert_eq!(snapshot.focus_trap_owner, Some(1));
+        assert!(log.entries.iter().any(|line| line.contains("depth 2")));
+
+        let (state, _) = state.pop_nested_dialog();
+        let snapshot = state.snapshot();
+        assert_eq!(snapshot.dialog_stack.len(), 1);
+        assert_eq!(snapshot.focus_trap_owner, Some(0));
+        assert!(state.dialog().is_open());
+    }
+
+    #[test]
+    fn escape_stops_at_the_top_most_dialog() {
+        let state = SharedOverlayState::enterprise_defaults();
+        let (state, _) = state.request_dialog_open();
+        let (state, _) = state.push_nested_dialog();
+
+        // First escape only dismisses the nested confirm prompt.
+        let (state, log) = state.handle_escape();
+        assert!(log
+            .entries
+            .iter()
+            .any(|line| line.contains("captured by top-most nested dialog")));
+        assert!(state.dialog().is_open());
+        assert_eq!(state.snapshot().dialog_stack.len(), 1);
+
+        // Second escape reaches the root dialog.
+        let (state, log) = state.handle_escape();
+        assert!(log
+            .entries
+            .iter()
+            .any(|line| line.contains("captured by root dialog")));
+        assert!(!state.dialog().is_open());
+    }
+
     #[test]
     fn popover_toggle_updates_snapshot() {
         let state = SharedOverlayState::enterprise_defaults();
@@ -393,6 +558,65 @@ mod tests {
         );
     }
 
+    #[test]
+    fn rule_engine_composes_sync_and_async_rules() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+        use std::sync::Arc;
+
+        let calls = Arc::new(AtomicUsize::new(0));
+        let counter = Arc::clone(&calls);
+        let rules = RuleSet::new()
+            .required()
+            .min_length(3)
+            .forbid_match("no_punctuation", r"[[:punct:]]")
+            .unique_via(move |value| {
+                counter.fetch_add(1, Ordering::SeqCst);
+                match value {
+                    "taken" => UniquenessProbe::Taken,
+                    "loading" => UniquenessProbe::Pending,
+                    _ => UniquenessProbe::Unique,
+                }
+            });
+
+        let report = rules.evaluate("");
+        assert!(report.errors.iter().any(|m| m.contains("required")));
+
+        let report = rules.evaluate("taken");
+        assert!(report.errors.iter().any(|m| m.contains("already in use")));
+
+        let report = rules.evaluate("loading");
+        assert!(report.pending);
+        assert!(report.errors.is_empty());
+        assert!(!report.is_valid());
+
+        let report = rules.evaluate("Fresh Name");
+        assert!(report.is_valid());
+        assert!(calls.load(Ordering::SeqCst) >= 4);
+    }
+
+    #[test]
+    fn localizer_hook_overrides_default_copy() {
+        let rules = RuleSet::new().min_length(5).with_localizer(|error| {
+            (error.code == "min_length")
+                .then(|| format!("Mindestens {} Zeichen.", error.param("min").unwrap()))
+        });
+        let report = rules.evaluate("ab");
+        assert_eq!(report.errors, vec!["Mindestens 5 Zeichen.".to_string()]);
+        assert_eq!(report.raw_errors[0].code, "min_length");
+    }
+
+    #[test]
+    fn async_uniqueness_flags_snapshot_pending() {
+        let state = SharedOverlayState::enterprise_defaults().with_rules(
+            SharedOverlayState::default_rules().unique_via(|_| UniquenessProbe::Pending),
+        );
+        let (state, _) = state.change_text("Automation ready company");
+        let (state, _) = state.commit_text();
+        let snapshot = state.snapshot();
+        assert!(snapshot.text_field_pending);
+        assert!(!snapshot.text_field_has_errors());
+    }
+
     #[test]
     fn text_validation_marks_errors() {
         let state = SharedOverlayState::enterprise_defaults();
@@ -0,0 +1,308 @@
+//! Composable, framework-agnostic validation rules.
+//!
+//! The original examples hand-wrote a `recompute_validation` routine that
+//! mixed rule logic, error copy, and state mutation in one function.  Every
+//! additional example core (and eventually the Material text field) would
+//! have had to copy/paste those branches.  This module extracts the logic
+//! into small [`ValidationRule`] values that can be composed into a
+//! [`RuleSet`]:
+//!
+//! * `required`, `min_length`, and regex based `require_match`/`forbid_match`
+//!   cover the synchronous rules in use today.
+//! * `unique_via` models asynchronous uniqueness checks (e.g. "is this
+//!   company name taken?").  The probe returns [`UniquenessProbe::Pending`]
+//!   while the lookup is in flight which surfaces as
+//!   [`ValidationReport::pending`] instead of an error, so UIs can render a
+//!   spinner without blocking the keystroke path.
+//! * Error copy flows through a replaceable localizer hook.  Rules emit
+//!   machine readable [`ValidationError`] codes; the default localizer
+//!   renders the English strings the demos shipped with, and applications
+//!   can inject a hook backed by their own message catalogs.
+
+use std::fmt;
+use std::sync::Arc;
+
+use regex::Regex;
+
+/// Outcome of an asynchronous uniqueness lookup.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum UniquenessProbe {
+    /// The value is free to use.
+    Unique,
+    /// The value is already taken; an error is emitted.
+    Taken,
+    /// The lookup has not settled yet; the report is flagged pending.
+    Pending,
+}
+
+/// Machine readable description of a failed rule.  The `code` identifies the
+/// rule, while `params` carries values (limits, patterns) the localizer can
+/// interpolate into user facing copy.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ValidationError {
+    /// Stable identifier such as `required` or `min_length`.
+    pub code: &'static str,
+    /// Rule parameters, e.g. `[("min", "3")]` for `min_length`.
+    pub params: Vec<(&'static str, String)>,
+}
+
+impl ValidationError {
+    fn new(code: &'static str) -> Self {
+        Self {
+            code,
+            params: Vec::new(),
+        }
+    }
+
+    fn with(mut self, key: &'static str, value: impl ToString) -> Self {
+        self.params.push((key, value.to_string()));
+        self
+    }
+
+    /// Look up a parameter by key.
+    pub fn param(&self, key: &str) -> Option<&str> {
+        self.params
+            .iter()
+            .find(|(k, _)| *k == key)
+            .map(|(_, v)| v.as_str())
+    }
+}
+
+/// Hook mapping validation errors onto localized copy.  Returning `None`
+/// falls back to the built-in English strings so partial catalogs degrade
+/// gracefully.
+pub type Localizer = Arc<dyn Fn(&ValidationError) -> Option<String> + Send + Sync>;
+
+/// A single validation rule evaluated against the raw input string.
+pub trait ValidationRule: Send + Sync {
+    /// Stable identifier used in debug output and localization catalogs.
+    fn id(&self) -> &'static str;
+
+    /// Returns the error when the rule fails, or a pending marker for async
+    /// rules that have not settled.
+    fn evaluate(&self, value: &str) -> RuleOutcome;
+}
+
+/// Result of evaluating one rule.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum RuleOutcome {
+    /// The rule passed.
+    Pass,
+    /// The rule failed with the given error payload.
+    Fail(ValidationError),
+    /// An asynchronous rule is still waiting for its answer.
+    Pending,
+}
+
+/// Aggregated result of running every rule in a [`RuleSet`].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ValidationReport {
+    /// Localized error messages, in rule registration order.
+    pub errors: Vec<String>,
+    /// Machine readable error payloads mirroring `errors`.
+    pub raw_errors: Vec<ValidationError>,
+    /// Whether any asynchronous rule is still in flight.
+    pub pending: bool,
+}
+
+impl ValidationReport {
+    /// Convenience helper mirroring `SharedOverlaySnapshot::text_field_has_errors`.
+    #[inline]
+    pub fn is_valid(&self) -> bool {
+        self.errors.is_empty() && !self.pending
+    }
+}
+
+struct Required;
+
+impl ValidationRule for Required {
+    fn id(&self) -> &'static str {
+        "required"
+    }
+
+    fn evaluate(&self, value: &str) -> RuleOutcome {
+        if value.trim().is_empty() {
+            RuleOutcome::Fail(ValidationError::new("required"))
+        } else {
+            RuleOutcome::Pass
+        }
+    }
+}
+
+struct MinLength(usize);
+
+impl ValidationRule for MinLength {
+    fn id(&self) -> &'static str {
+        "min_length"
+    }
+
+    fn evaluate(&self, value: &str) -> RuleOutcome {
+        if value.trim().chars().count() < self.0 {
+            RuleOutcome::Fail(ValidationError::new("min_length").with("min", self.0))
+        } else {
+            RuleOutcome::Pass
+        }
+    }
+}
+
+struct PatternRule {
+    code: &'static str,
+    regex: Regex,
+    /// When `true` the value must match; when `false` it must not.
+    must_match: bool,
+}
+
+impl ValidationRule for PatternRule {
+    fn id(&self) -> &'static str {
+        self.code
+    }
+
+    fn evaluate(&self, value: &str) -> RuleOutcome {
+        if self.regex.is_match(value.trim()) == self.must_match {
+            RuleOutcome::Pass
+        } else {
+            RuleOutcome::Fail(
+                ValidationError::new(self.code).with("pattern", self.regex.as_str()),
+            )
+        }
+    }
+}
+
+struct Uniqueness {
+    probe: Arc<dyn Fn(&str) -> UniquenessProbe + Send + Sync>,
+}
+
+impl ValidationRule for Uniqueness {
+    fn id(&self) -> &'static str {
+        "unique"
+    }
+
+    fn evaluate(&self, value: &str) -> RuleOutcome {
+        match (self.probe)(value.trim()) {
+            UniquenessProbe::Unique => RuleOutcome::Pass,
+            UniquenessProbe::Taken => RuleOutcome::Fail(ValidationError::new("unique")),
+            UniquenessProbe::Pending => RuleOutcome::Pending,
+        }
+    }
+}
+
+/// Ordered collection of rules plus the localization hook used to render
+/// error copy.  Rule sets are cheap to clone (rules are `Arc`ed) so they can
+/// live inside state containers that framework adapters copy around.
+#[derive(Clone, Default)]
+pub struct RuleSet {
+    rules: Vec<Arc<dyn ValidationRule>>,
+    localizer: Option<Localizer>,
+}
+
+impl fmt::Debug for RuleSet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RuleSet")
+            .field(
+                "rules",
+                &self.rules.iter().map(|r| r.id()).collect::<Vec<_>>(),
+            )
+            .field("localized", &self.localizer.is_some())
+            .finish()
+    }
+}
+
+impl RuleSet {
+    /// Creates an empty rule set.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a custom rule.  The builder style keeps call sites declarative.
+    pub fn rule(mut self, rule: impl ValidationRule + 'static) -> Self {
+        self.rules.push(Arc::new(rule));
+        self
+    }
+
+    /// The value must contain non-whitespace content.
+    pub fn required(self) -> Self {
+        self.rule(Required)
+    }
+
+    /// The trimmed value must contain at least `min` characters.
+    pub fn min_length(self, min: usize) -> Self {
+        self.rule(MinLength(min))
+    }
+
+    /// The trimmed value must match `pattern`.  Patterns are compiled once at
+    /// construction so evaluation never pays the compile cost; an invalid
+    /// pattern is a programmer error and panics eagerly.
+    pub fn require_match(self, code: &'static str, pattern: &str) -> Self {
+        self.rule(PatternRule {
+            code,
+            regex: Regex::new(pattern).expect("validation pattern compiles"),
+            must_match: true,
+        })
+    }
+
+    /// The trimmed value must *not* match `pattern`.
+    pub fn forbid_match(self, code: &'static str, pattern: &str) -> Self {
+        self.rule(PatternRule {
+            code,
+            regex: Regex::new(pattern).expect("validation pattern compiles"),
+            must_match: false,
+        })
+    }
+
+    /// Registers an asynchronous uniqueness check.  The probe is consulted on
+    /// every evaluation; while it reports [`UniquenessProbe::Pending`] the
+    /// report is flagged pending instead of erroring.
+    pub fn unique_via(self, probe: impl Fn(&str) -> UniquenessProbe + Send + Sync + 'static) -> Self {
+        self.rule(Uniqueness {
+            probe: Arc::new(probe),
+        })
+    }
+
+    /// Installs a localization hook consulted before the built-in English
+    /// copy.  Typically backed by the application's message catalog.
+    pub fn with_localizer(
+        mut self,
+        localizer: impl Fn(&ValidationError) -> Option<String> + Send + Sync + 'static,
+    ) -> Self {
+        self.localizer = Some(Arc::new(localizer));
+        self
+    }
+
+    /// Runs every rule against `value`, rendering error copy through the
+    /// localizer (falling back to the defaults).
+    pub fn evaluate(&self, value: &str) -> ValidationReport {
+        let mut report = ValidationReport::default();
+        for rule in &self.rules {
+            match rule.evaluate(value) {
+                RuleOutcome::Pass => {}
+                RuleOutcome::Pending => report.pending = true,
+                RuleOutcome::Fail(error) => {
+                    let message = self
+                        .localizer
+                        .as_ref()
+                        .and_then(|hook| hook(&error))
+                        .unwrap_or_else(|| default_message(&error));
+                    report.errors.push(message);
+                    report.raw_errors.push(error);
+                }
+            }
+        }
+        report
+    }
+}
+
+/// Built-in English copy matching the strings historically emitted by the
+/// dialog examples.  Unknown codes fall back to a generic message so custom
+/// rules remain usable without a localizer.
+fn default_message(error: &ValidationError) -> String {
+    match error.code {
+        "required" => "Company name is required.".to_string(),
+        "min_length" => format!(
+            "Company name must be at least {} characters.",
+            error.param("min").unwrap_or("?")
+        ),
+        "no_punctuation" => "Remove punctuation before submitting.".to_string(),
+        "unique" => "That name is already in use.".to_string(),
+        code => format!("Validation failed: {code}."),
+    }
+}
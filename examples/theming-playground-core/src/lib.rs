@@ -0,0 +1,344 @@
+//! Shared live theming playground powering the cross-framework demos.
+//!
+//! The playground lets users edit palette, typography and radius tokens at
+//! runtime through ordinary Material inputs while a preview tree restyles
+//! instantly.  All of the logic lives here so the framework adapters only
+//! wire `<input>` events to [`ThemingPlayground`] mutators and re-inject the
+//! CSS returned by [`ThemingPlayground::preview_css_variables`]:
+//!
+//! * Previews restyle through CSS custom properties rather than regenerating
+//!   scoped styles — swapping one `<style>` tag's text is cheap enough to run
+//!   on every keystroke.
+//! * [`ThemingPlayground::export_overrides_json`] emits the exact override
+//!   fixture format consumed by `cargo xtask generate-theme`, so a tuned
+//!   playground session feeds straight into the CI theme pipeline.  Only
+//!   values that differ from the canonical defaults are exported, keeping
+//!   fixtures reviewable.
+
+use rustic_ui_system::theme::{ColorScheme, Theme};
+use rustic_ui_system::theme_provider::material_css_baseline_from_theme;
+use serde_json::{json, Map, Value};
+
+/// Stable automation prefix applied to playground selectors.
+pub const AUTOMATION_ID: &str = "rusticui-theming-playground";
+
+/// Palette slots editable through the playground inputs.
+///
+/// The enum mirrors the fields of `PaletteScheme` so adapters can render one
+/// color input per slot without string-typed field names leaking into UI code.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PaletteSlot {
+    /// Primary accent color.
+    Primary,
+    /// Secondary accent color.
+    Secondary,
+    /// App shell background.
+    BackgroundDefault,
+    /// Elevated surface background.
+    BackgroundPaper,
+    /// Primary body text color.
+    TextPrimary,
+    /// Secondary/disabled text color.
+    TextSecondary,
+}
+
+impl PaletteSlot {
+    /// Every editable slot, in the order the playground renders them.
+    pub const ALL: [PaletteSlot; 6] = [
+        Self::Primary,
+        Self::Secondary,
+        Self::BackgroundDefault,
+        Self::BackgroundPaper,
+        Self::TextPrimary,
+        Self::TextSecondary,
+    ];
+
+    /// Label rendered next to the color input.
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Primary => "Primary",
+            Self::Secondary => "Secondary",
+            Self::BackgroundDefault => "Background",
+            Self::BackgroundPaper => "Surface",
+            Self::TextPrimary => "Text",
+            Self::TextSecondary => "Muted text",
+        }
+    }
+
+    /// CSS custom property suffix and fixture key for this slot.
+    fn token(self) -> &'static str {
+        match self {
+            Self::Primary => "primary",
+            Self::Secondary => "secondary",
+            Self::BackgroundDefault => "background_default",
+            Self::BackgroundPaper => "background_paper",
+            Self::TextPrimary => "text_primary",
+            Self::TextSecondary => "text_secondary",
+        }
+    }
+}
+
+/// Live theming state shared by every framework adapter.
+#[derive(Clone, Debug)]
+pub struct ThemingPlayground {
+    theme: Theme,
+    baseline: Theme,
+}
+
+impl ThemingPlayground {
+    /// Start from the canonical Material defaults.
+    pub fn new() -> Self {
+        Self::from_theme(Theme::default())
+    }
+
+    /// Start from an existing theme, e.g. a previously exported fixture.
+    /// The provided theme also becomes the diffing baseline for exports.
+    pub fn from_theme(theme: Theme) -> Self {
+        Self {
+            baseline: theme.clone(),
+            theme,
+        }
+    }
+
+    /// The theme in its currently edited state.
+    #[inline]
+    pub fn theme(&self) -> &Theme {
+        &self.theme
+    }
+
+    /// Read a palette slot for the given scheme, for seeding input values.
+    pub fn palette_value(&self, scheme: ColorScheme, slot: PaletteSlot) -> &str {
+        let palette = self.theme.palette.scheme(scheme);
+        match slot {
+            PaletteSlot::Primary => &palette.primary,
+            PaletteSlot::Secondary => &palette.secondary,
+            PaletteSlot::BackgroundDefault => &palette.background_default,
+            PaletteSlot::BackgroundPaper => &palette.background_paper,
+            PaletteSlot::TextPrimary => &palette.text_primary,
+            PaletteSlot::TextSecondary => &palette.text_secondary,
+        }
+    }
+
+    /// Update a palette slot for the given scheme.
+    pub fn set_palette_value(
+        &mut self,
+        scheme: ColorScheme,
+        slot: PaletteSlot,
+        value: impl Into<String>,
+    ) {
+        let palette = self.theme.palette.scheme_mut(scheme);
+        let target = match slot {
+            PaletteSlot::Primary => &mut palette.primary,
+            PaletteSlot::Secondary => &mut palette.secondary,
+            PaletteSlot::BackgroundDefault => &mut palette.background_default,
+            PaletteSlot::BackgroundPaper => &mut palette.background_paper,
+            PaletteSlot::TextPrimary => &mut palette.text_primary,
+            PaletteSlot::TextSecondary => &mut palette.text_secondary,
+        };
+        *target = value.into();
+    }
+
+    /// Switch which color scheme the preview (and exports) treat as active.
+    pub fn set_active_scheme(&mut self, scheme: ColorScheme) {
+        self.theme.palette.initial_color_scheme = scheme;
+    }
+
+    /// Update the body font family.
+    pub fn set_font_family(&mut self, family: impl Into<String>) {
+        self.theme.typography.font_family = family.into();
+    }
+
+    /// Update the base font size in pixels.
+    pub fn set_font_size(&mut self, size: f32) {
+        self.theme.typography.font_size = size;
+    }
+
+    /// Update the Joy corner radius in pixels.
+    pub fn set_radius(&mut self, radius: u8) {
+        self.theme.joy.radius = radius;
+    }
+
+    /// Update the base spacing unit in pixels.
+    pub fn set_spacing(&mut self, spacing: u16) {
+        self.theme.spacing = spacing;
+    }
+
+    /// Discard every edit, returning to the baseline theme.
+    pub fn reset(&mut self) {
+        self.theme = self.baseline.clone();
+    }
+
+    /// CSS custom property block the preview tree consumes.
+    ///
+    /// The preview styles reference `var(--rustic-*)` exclusively, so pushing
+    /// an updated copy of this block into the page restyles every node
+    /// without touching the scoped component styles.
+    pub fn preview_css_variables(&self) -> String {
+        let palette = self.theme.palette.active();
+        let mut css = String::from(":root {\n");
+        for slot in PaletteSlot::ALL {
+            css.push_str(&format!(
+                "    --rustic-{}: {};\n",
+                slot.token().replace('_', "-"),
+                self.palette_value(self.theme.palette.initial_color_scheme, slot)
+            ));
+        }
+        css.push_str(&format!("    --rustic-neutral: {};\n", palette.neutral));
+        css.push_str(&format!(
+            "    --rustic-font-family: {};\n",
+            self.theme.typography.font_family
+        ));
+        css.push_str(&format!(
+            "    --rustic-font-size: {}px;\n",
+            self.theme.typography.font_size
+        ));
+        css.push_str(&format!(
+            "    --rustic-radius: {}px;\n",
+            self.theme.joy.radius
+        ));
+        css.push_str(&format!(
+            "    --rustic-spacing: {}px;\n",
+            self.theme.spacing
+        ));
+        css.push_str("}\n");
+        css
+    }
+
+    /// Full global baseline for the preview iframe, regenerated on demand.
+    pub fn preview_baseline_css(&self) -> String {
+        material_css_baseline_from_theme(&self.theme)
+    }
+
+    /// Export the edits as a `cargo xtask generate-theme` override fixture.
+    ///
+    /// The generator accepts shared top-level keys plus a `schemes` section
+    /// holding per-scheme palette fragments; only values differing from the
+    /// baseline are included so the fixture documents intent, not the entire
+    /// theme.
+    pub fn export_overrides_json(&self) -> Value {
+        let mut root = Map::new();
+
+        if self.theme.spacing != self.baseline.spacing {
+            root.insert("spacing".into(), json!(self.theme.spacing));
+        }
+        let typography = diff_values(
+            &serde_json::to_value(&self.baseline.typography).expect("typography serializes"),
+            &serde_json::to_value(&self.theme.typography).expect("typography serializes"),
+        );
+        if !typography.is_empty() {
+            root.insert("typography".into(), Value::Object(typography));
+        }
+        let joy = diff_values(
+            &serde_json::to_value(&self.baseline.joy).expect("joy tokens serialize"),
+            &serde_json::to_value(&self.theme.joy).expect("joy tokens serialize"),
+        );
+        if !joy.is_empty() {
+            root.insert("joy".into(), Value::Object(joy));
+        }
+
+        let mut schemes = Map::new();
+        for scheme in [ColorScheme::Light, ColorScheme::Dark] {
+            let fragment = diff_values(
+                &serde_json::to_value(self.baseline.palette.scheme(scheme))
+                    .expect("palette serializes"),
+                &serde_json::to_value(self.theme.palette.scheme(scheme))
+                    .expect("palette serializes"),
+            );
+            if !fragment.is_empty() {
+                schemes.insert(scheme.as_str().to_string(), json!({ "palette": fragment }));
+            }
+        }
+        if !schemes.is_empty() {
+            root.insert("schemes".into(), Value::Object(schemes));
+        }
+
+        Value::Object(root)
+    }
+
+    /// TOML rendering of [`export_overrides_json`](Self::export_overrides_json)
+    /// for teams that keep their fixtures in `generate-theme --format toml`.
+    pub fn export_overrides_toml(&self) -> String {
+        let value = self.export_overrides_json();
+        toml::to_string_pretty(&value).expect("override fixture converts to TOML")
+    }
+}
+
+impl Default for ThemingPlayground {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Object diff keeping only the keys whose values changed.
+fn diff_values(baseline: &Value, edited: &Value) -> Map<String, Value> {
+    let mut out = Map::new();
+    if let (Some(base), Some(next)) = (baseline.as_object(), edited.as_object()) {
+        for (key, value) in next {
+            if base.get(key) != Some(value) {
+                out.insert(key.clone(), value.clone());
+            }
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn css_variables_track_palette_edits() {
+        let mut playground = ThemingPlayground::new();
+        playground.set_palette_value(ColorScheme::Light, PaletteSlot::Primary, "#123456");
+        let css = playground.preview_css_variables();
+        assert!(css.contains("--rustic-primary: #123456;"));
+        assert!(css.contains("--rustic-font-family:"));
+    }
+
+    #[test]
+    fn switching_scheme_changes_the_preview_values() {
+        let mut playground = ThemingPlayground::new();
+        playground.set_palette_value(ColorScheme::Dark, PaletteSlot::Primary, "#abcdef");
+        playground.set_active_scheme(ColorScheme::Dark);
+        assert!(playground
+            .preview_css_variables()
+            .contains("--rustic-primary: #abcdef;"));
+    }
+
+    #[test]
+    fn export_only_contains_edited_values() {
+        let mut playground = ThemingPlayground::new();
+        playground.set_palette_value(ColorScheme::Light, PaletteSlot::Primary, "#123456");
+        playground.set_radius(12);
+
+        let fixture = playground.export_overrides_json();
+        assert_eq!(fixture["schemes"]["light"]["palette"]["primary"], "#123456");
+        assert_eq!(fixture["joy"]["radius"], 12);
+        // Untouched sections stay out of the fixture entirely.
+        assert!(fixture.get("typography").is_none());
+        assert!(fixture["schemes"].get("dark").is_none());
+    }
+
+    #[test]
+    fn pristine_playground_exports_an_empty_fixture() {
+        let playground = ThemingPlayground::new();
+        assert_eq!(playground.export_overrides_json(), serde_json::json!({}));
+    }
+
+    #[test]
+    fn reset_discards_edits() {
+        let mut playground = ThemingPlayground::new();
+        playground.set_font_size(18.0);
+        playground.reset();
+        assert_eq!(playground.export_overrides_json(), serde_json::json!({}));
+    }
+
+    #[test]
+    fn toml_export_round_trips_through_the_fixture_parser_shape() {
+        let mut playground = ThemingPlayground::new();
+        playground.set_spacing(4);
+        let toml_fixture = playground.export_overrides_toml();
+        let parsed: toml::Value = toml::from_str(&toml_fixture).expect("fixture parses");
+        assert_eq!(parsed["spacing"].as_integer(), Some(4));
+    }
+}